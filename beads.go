@@ -156,6 +156,7 @@ const (
 	SortPolicyHybrid   = types.SortPolicyHybrid
 	SortPolicyPriority = types.SortPolicyPriority
 	SortPolicyOldest   = types.SortPolicyOldest
+	SortPolicyDue      = types.SortPolicyDue
 )
 
 // EventType constants
@@ -170,5 +171,9 @@ const (
 	EventDependencyRemoved = types.EventDependencyRemoved
 	EventLabelAdded        = types.EventLabelAdded
 	EventLabelRemoved      = types.EventLabelRemoved
+	EventAssigneeAdded     = types.EventAssigneeAdded
+	EventAssigneeRemoved   = types.EventAssigneeRemoved
 	EventCompacted         = types.EventCompacted
+	EventCommentEdited     = types.EventCommentEdited
+	EventCommentDeleted    = types.EventCommentDeleted
 )
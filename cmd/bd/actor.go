@@ -0,0 +1,280 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// actorConfigPrefix prefixes the config keys registered actors are stored
+// under (actors.<name>), so every clone of the database shares the registry.
+const actorConfigPrefix = "actors."
+
+// Actor kinds.
+const (
+	actorKindHuman = "human"
+	actorKindAgent = "agent"
+)
+
+// actorProfile is a registered identity that issues and events can be
+// attributed to.
+type actorProfile struct {
+	Name         string    `json:"name"`
+	Kind         string    `json:"kind"`
+	Model        string    `json:"model,omitempty"`
+	Contact      string    `json:"contact,omitempty"`
+	RegisteredAt time.Time `json:"registered_at"`
+}
+
+// loadActors returns the registry keyed by actor name.
+func loadActors(ctx context.Context) (map[string]*actorProfile, error) {
+	all, err := getStore().GetAllConfig(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("loading actors: %w", err)
+	}
+	actors := make(map[string]*actorProfile)
+	for key, value := range all {
+		name, ok := strings.CutPrefix(key, actorConfigPrefix)
+		if !ok || value == "" {
+			continue
+		}
+		var p actorProfile
+		if err := json.Unmarshal([]byte(value), &p); err != nil {
+			return nil, fmt.Errorf("actor %s: invalid config value: %w", name, err)
+		}
+		p.Name = name
+		actors[name] = &p
+	}
+	return actors, nil
+}
+
+// checkRegisteredActor applies validation.on-actor to a write command: with
+// "warn" or "error", an actor missing from the registry is reported. Nothing
+// is checked until at least one actor is registered.
+func checkRegisteredActor(ctx context.Context, name string) {
+	mode := config.GetString("validation.on-actor")
+	if mode != "warn" && mode != "error" {
+		return
+	}
+	actors, err := loadActors(ctx)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "warning: %v\n", err)
+		return
+	}
+	if len(actors) == 0 || actors[name] != nil {
+		return
+	}
+	msg := fmt.Sprintf("actor %q is not registered (register it with 'bd actor register %s --kind human|agent', or set --actor / BEADS_ACTOR)", name, name)
+	if mode == "error" {
+		FatalError("%s", msg)
+	}
+	fmt.Fprintf(os.Stderr, "%s %s\n", ui.RenderWarn("⚠"), msg)
+}
+
+// annotateActorKinds sets ActorKind on events whose actor is registered.
+func annotateActorKinds(ctx context.Context, events []*types.Event) {
+	if len(events) == 0 {
+		return
+	}
+	actors, err := loadActors(ctx)
+	if err != nil {
+		return
+	}
+	for _, e := range events {
+		if p := actors[e.Actor]; p != nil {
+			e.ActorKind = p.Kind
+		}
+	}
+}
+
+// formatActor renders an actor name with its kind, e.g. "claude [agent]".
+func formatActor(name, kind string) string {
+	if kind == "" {
+		return name
+	}
+	return name + " " + ui.RenderMuted("["+kind+"]")
+}
+
+var actorCmd = &cobra.Command{
+	Use:     "actor",
+	GroupID: "setup",
+	Short:   "Register humans and agents that work on issues",
+	Long: `Keep a registry of who works in this database, so audit trails can tell
+humans from agents.
+
+The current actor comes from --actor, then BEADS_ACTOR, then the actor config
+setting, then git user.name. Registering it records its kind (human or agent)
+and, optionally, the model it runs and how to reach whoever operates it.
+
+Set validation.on-actor to "warn" or "error" in config.yaml to have write
+commands check the current actor against the registry. 'bd inbox' and 'bd
+handoff' mark each event with the kind of actor that made it.
+
+Examples:
+  bd actor register alice --kind human --contact alice@example.com
+  bd actor register --kind agent --model claude-sonnet-4   # registers the current actor
+  bd actor list
+  bd actor show                                             # who am I?`,
+}
+
+var actorRegisterCmd = &cobra.Command{
+	Use:   "register [name]",
+	Short: "Register or update an actor (default: the current actor)",
+	Args:  cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("actor register")
+		name := actor
+		if len(args) > 0 {
+			name = strings.TrimSpace(args[0])
+		}
+		if name == "" || strings.ContainsAny(name, "\r\n") {
+			FatalErrorRespectJSON("invalid actor name %q", name)
+		}
+		kind, _ := cmd.Flags().GetString("kind")
+		if kind != actorKindHuman && kind != actorKindAgent {
+			FatalErrorRespectJSON("invalid --kind %q: use human or agent", kind)
+		}
+		p := &actorProfile{Name: name, Kind: kind, RegisteredAt: time.Now().UTC()}
+		p.Model, _ = cmd.Flags().GetString("model")
+		p.Contact, _ = cmd.Flags().GetString("contact")
+
+		data, err := json.Marshal(p)
+		if err != nil {
+			FatalErrorRespectJSON("encoding actor: %v", err)
+		}
+		if err := getStore().SetConfig(rootCtx, actorConfigPrefix+name, string(data)); err != nil {
+			FatalErrorRespectJSON("saving actor %s: %v", name, err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(p)
+			return
+		}
+		fmt.Printf("%s Registered %s\n", ui.RenderPass("✓"), formatActor(ui.RenderBold(name), kind))
+	},
+}
+
+var actorListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List registered actors",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		actors, err := loadActors(rootCtx)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		list := make([]*actorProfile, 0, len(actors))
+		for _, p := range actors {
+			list = append(list, p)
+		}
+		slices.SortFunc(list, func(a, b *actorProfile) int { return strings.Compare(a.Name, b.Name) })
+
+		if jsonOutput {
+			outputJSON(list)
+			return
+		}
+		if len(list) == 0 {
+			fmt.Println("No actors registered. Register one with: bd actor register <name> --kind human|agent")
+			return
+		}
+		for _, p := range list {
+			var details []string
+			if p.Model != "" {
+				details = append(details, p.Model)
+			}
+			if p.Contact != "" {
+				details = append(details, p.Contact)
+			}
+			fmt.Printf("%-20s %-6s %s\n", p.Name, p.Kind, ui.RenderMuted(strings.Join(details, "  ")))
+		}
+	},
+}
+
+var actorShowCmd = &cobra.Command{
+	Use:   "show [name]",
+	Short: "Show an actor's profile (default: the current actor)",
+	Args:  cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		name := actor
+		if len(args) > 0 {
+			name = args[0]
+		}
+		actors, err := loadActors(rootCtx)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		p := actors[name]
+
+		if jsonOutput {
+			if p == nil {
+				outputJSON(map[string]interface{}{"name": name, "registered": false})
+				return
+			}
+			outputJSON(p)
+			return
+		}
+		if p == nil {
+			fmt.Printf("%s is not registered\n", ui.RenderBold(name))
+			return
+		}
+		fmt.Printf("%s\n", formatActor(ui.RenderBold(p.Name), p.Kind))
+		if p.Model != "" {
+			fmt.Printf("  Model:      %s\n", p.Model)
+		}
+		if p.Contact != "" {
+			fmt.Printf("  Contact:    %s\n", p.Contact)
+		}
+		fmt.Printf("  Registered: %s\n", p.RegisteredAt.Local().Format("2006-01-02 15:04"))
+	},
+}
+
+var actorRemoveCmd = &cobra.Command{
+	Use:     "remove <name>",
+	Aliases: []string{"rm"},
+	Short:   "Remove an actor from the registry",
+	Args:    cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("actor remove")
+		name := args[0]
+		value, err := getStore().GetConfig(rootCtx, actorConfigPrefix+name)
+		if err != nil {
+			FatalErrorRespectJSON("loading actor %s: %v", name, err)
+		}
+		if value == "" {
+			FatalErrorRespectJSON("no actor named %q (see 'bd actor list')", name)
+		}
+		if err := getStore().DeleteConfig(rootCtx, actorConfigPrefix+name); err != nil {
+			FatalErrorRespectJSON("removing actor %s: %v", name, err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"name": name, "removed": true})
+			return
+		}
+		fmt.Printf("%s Removed actor %s\n", ui.RenderPass("✓"), name)
+	},
+}
+
+func init() {
+	actorRegisterCmd.Flags().String("kind", "", "Actor kind: human or agent (required)")
+	actorRegisterCmd.Flags().String("model", "", "Model an agent runs (e.g. claude-sonnet-4)")
+	actorRegisterCmd.Flags().String("contact", "", "How to reach the actor or its operator")
+	_ = actorRegisterCmd.MarkFlagRequired("kind")
+
+	actorCmd.AddCommand(actorRegisterCmd)
+	actorCmd.AddCommand(actorListCmd)
+	actorCmd.AddCommand(actorShowCmd)
+	actorCmd.AddCommand(actorRemoveCmd)
+	rootCmd.AddCommand(actorCmd)
+}
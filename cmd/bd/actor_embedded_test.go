@@ -0,0 +1,69 @@
+//go:build cgo
+
+package main
+
+import (
+	"bytes"
+	"encoding/json"
+	"os"
+	"os/exec"
+	"strings"
+	"testing"
+)
+
+func TestEmbeddedActor(t *testing.T) {
+	if os.Getenv("BEADS_TEST_EMBEDDED_DOLT") != "1" {
+		t.Skip("set BEADS_TEST_EMBEDDED_DOLT=1 to run embedded dolt integration tests")
+	}
+	t.Parallel()
+
+	bd := buildEmbeddedBD(t)
+	dir, _, _ := bdInit(t, bd, "--prefix", "ac")
+
+	run := func(t *testing.T, actorName string, args ...string) (string, error) {
+		t.Helper()
+		cmd := exec.Command(bd, args...)
+		cmd.Dir = dir
+		cmd.Env = append(bdEnv(dir), "BEADS_ACTOR="+actorName, "BD_VALIDATION_ON_ACTOR=error")
+		out, err := cmd.CombinedOutput()
+		return string(out), err
+	}
+
+	t.Run("register_and_list", func(t *testing.T) {
+		if out, err := run(t, "alice", "actor", "register", "--kind", "human", "--contact", "alice@example.com"); err != nil {
+			t.Fatalf("register current actor: %v\n%s", err, out)
+		}
+		if out, err := run(t, "alice", "actor", "register", "builder", "--kind", "agent", "--model", "test-model"); err != nil {
+			t.Fatalf("register builder: %v\n%s", err, out)
+		}
+		if out, err := run(t, "alice", "actor", "register", "x", "--kind", "robot"); err == nil {
+			t.Fatalf("expected invalid --kind to fail:\n%s", out)
+		}
+
+		out, err := run(t, "alice", "actor", "list", "--json")
+		if err != nil {
+			t.Fatalf("actor list: %v\n%s", err, out)
+		}
+		var actors []actorProfile
+		if err := json.Unmarshal(bytes.TrimSpace([]byte(out)), &actors); err != nil {
+			t.Fatalf("parse actor list: %v\n%s", err, out)
+		}
+		if len(actors) != 2 || actors[0].Name != "alice" || actors[0].Kind != actorKindHuman ||
+			actors[1].Name != "builder" || actors[1].Model != "test-model" {
+			t.Fatalf("actor list = %+v", actors)
+		}
+	})
+
+	t.Run("validation_on_actor", func(t *testing.T) {
+		if out, err := run(t, "builder", "create", "Registered actor", "--type", "task"); err != nil {
+			t.Fatalf("create as registered actor: %v\n%s", err, out)
+		}
+		out, err := run(t, "stranger", "create", "Unregistered actor", "--type", "task")
+		if err == nil || !strings.Contains(out, "not registered") {
+			t.Fatalf("expected create as unregistered actor to fail, err=%v:\n%s", err, out)
+		}
+		if out, err := run(t, "stranger", "list"); err != nil {
+			t.Fatalf("read-only command should not be checked: %v\n%s", err, out)
+		}
+	})
+}
@@ -0,0 +1,263 @@
+package main
+
+import (
+	"cmp"
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/configfile"
+	"github.com/steveyegge/beads/internal/routing"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+// repoStore is one repository an --all-repos command reads.
+type repoStore struct {
+	name  string
+	store storage.DoltStorage
+}
+
+// openAllRepoStores returns the current repository's store followed by a
+// read-only store for every other workspace in the registry (see 'bd
+// workspace'). Workspaces that can't be opened are skipped with a
+// warning. The returned function closes the stores opened here.
+func openAllRepoStores(ctx context.Context) ([]repoStore, func()) {
+	reg, err := configfile.LoadWorkspaces(configfile.WorkspacesPath())
+	if err != nil {
+		FatalErrorRespectJSON("%v", err)
+	}
+
+	var repos []repoStore
+	var opened []storage.DoltStorage
+	currentDir := beads.FindBeadsDir()
+	if store != nil && currentDir != "" {
+		repos = append(repos, repoStore{name: currentWorkspaceName(reg, currentDir), store: store})
+	}
+	for _, w := range reg.Workspaces {
+		beadsDir := filepath.Join(routing.ExpandPath(w.Path), ".beads")
+		if currentDir != "" && utils.PathsEqual(beadsDir, currentDir) {
+			continue
+		}
+		if _, err := os.Stat(beadsDir); err != nil {
+			fmt.Fprintf(os.Stderr, "warning: skipping workspace %s: no beads workspace at %s\n", w.Name, w.Path)
+			continue
+		}
+		st, err := newReadOnlyStoreFromConfig(ctx, beadsDir)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "warning: skipping workspace %s: %v\n", w.Name, err)
+			continue
+		}
+		opened = append(opened, st)
+		repos = append(repos, repoStore{name: w.Name, store: st})
+	}
+	return repos, func() {
+		for _, st := range opened {
+			_ = st.Close()
+		}
+	}
+}
+
+// repoIssue is an issue tagged with the repository it came from.
+type repoIssue struct {
+	*types.IssueWithCounts
+	SourceRepo string `json:"source_repo"`
+}
+
+// queryAllRepos runs query against every repository and tags the results.
+// A repository whose query fails is skipped with a warning.
+func queryAllRepos(repos []repoStore, query func(storage.DoltStorage) ([]*types.IssueWithCounts, error)) []repoIssue {
+	items := []repoIssue{}
+	for _, r := range repos {
+		results, err := query(r.store)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "warning: skipping workspace %s: %v\n", r.name, err)
+			continue
+		}
+		for _, iwc := range results {
+			items = append(items, repoIssue{IssueWithCounts: iwc, SourceRepo: r.name})
+		}
+	}
+	return items
+}
+
+// sortRepoIssues orders issues from several repositories like one
+// repository's: by sortBy, or by priority when it is empty, keeping each
+// repository's own order among equals.
+func sortRepoIssues(items []repoIssue, sortBy string, reverse bool) {
+	if sortBy == "" {
+		slices.SortStableFunc(items, func(a, b repoIssue) int { return cmp.Compare(a.Priority, b.Priority) })
+		return
+	}
+	iwc := make([]*types.IssueWithCounts, len(items))
+	repoOf := make(map[*types.IssueWithCounts]string, len(items))
+	for i, it := range items {
+		iwc[i] = it.IssueWithCounts
+		repoOf[it.IssueWithCounts] = it.SourceRepo
+	}
+	sortIssuesWithCounts(iwc, sortBy, reverse)
+	for i, x := range iwc {
+		items[i] = repoIssue{IssueWithCounts: x, SourceRepo: repoOf[x]}
+	}
+}
+
+// repoTag renders the source repository prefix of a result line.
+func repoTag(name string) string {
+	return ui.RenderMuted("[" + name + "]")
+}
+
+// listAllRepos is 'bd list --all-repos'.
+func listAllRepos(ctx context.Context, filter types.IssueFilter, ready bool, sortBy string, reverse bool, limit int) {
+	repos, closeAll := openAllRepoStores(ctx)
+	defer closeAll()
+
+	items := queryAllRepos(repos, func(st storage.DoltStorage) ([]*types.IssueWithCounts, error) {
+		if ready {
+			return getReadyWorkWithCounts(ctx, st, readyWorkFilterFromIssueFilter(filter))
+		}
+		return st.SearchIssuesWithCounts(ctx, "", filter)
+	})
+	sortRepoIssues(items, sortBy, reverse)
+	truncated := limit > 0 && len(items) > limit
+	if truncated {
+		items = items[:limit]
+	}
+
+	if jsonOutput {
+		outputJSON(items)
+		printTruncationHint(truncated, limit, "")
+		return
+	}
+
+	// Blocking info comes from the repository each issue lives in.
+	blockedBy := make(map[string][]string)
+	blocks := make(map[string][]string)
+	parents := make(map[string]string)
+	for _, r := range repos {
+		var ids []string
+		for _, it := range items {
+			if it.SourceRepo == r.name {
+				ids = append(ids, it.ID)
+			}
+		}
+		if len(ids) == 0 {
+			continue
+		}
+		// Best effort: display gracefully degrades with empty data
+		b, bl, p, _ := r.store.GetBlockingInfoForIssues(ctx, ids)
+		for _, id := range ids {
+			key := r.name + "\x00" + id
+			blockedBy[key], blocks[key], parents[key] = b[id], bl[id], p[id]
+		}
+	}
+
+	var buf strings.Builder
+	for _, it := range items {
+		key := it.SourceRepo + "\x00" + it.ID
+		buf.WriteString(repoTag(it.SourceRepo) + " ")
+		formatIssueCompact(&buf, it.Issue, it.Labels, blockedBy[key], blocks[key], parents[key])
+	}
+	fmt.Print(buf.String())
+	printTruncationHint(truncated, limit, "")
+}
+
+// readyAllRepos is 'bd ready --all-repos'.
+func readyAllRepos(ctx context.Context, filter types.WorkFilter) {
+	repos, closeAll := openAllRepoStores(ctx)
+	defer closeAll()
+
+	limit := filter.Limit
+	items := queryAllRepos(repos, func(st storage.DoltStorage) ([]*types.IssueWithCounts, error) {
+		return getReadyWorkWithCounts(ctx, st, filter)
+	})
+	sortRepoIssues(items, "", false)
+	if limit > 0 && len(items) > limit {
+		items = items[:limit]
+	}
+
+	if jsonOutput {
+		outputJSON(items)
+		return
+	}
+	if len(items) == 0 {
+		fmt.Printf("\n%s No ready work in %d repositories\n\n", ui.RenderPass("✨"), len(repos))
+		return
+	}
+	fmt.Printf("\n%s Ready work across %d repositories (%d issues with no active blockers):\n\n", ui.RenderAccent("📋"), len(repos), len(items))
+	for i, it := range items {
+		fmt.Printf("%d. %s [%s] [%s] %s: %s\n", i+1, repoTag(it.SourceRepo),
+			ui.RenderPriority(it.Priority),
+			ui.RenderType(string(it.IssueType)),
+			ui.RenderID(it.ID), it.Title)
+		if it.Assignee != "" {
+			fmt.Printf("   Assignee: %s\n", it.Assignee)
+		}
+	}
+	fmt.Println()
+}
+
+// repoStatistics is one repository's summary in 'bd status --all-repos'.
+type repoStatistics struct {
+	SourceRepo string            `json:"source_repo"`
+	Summary    *types.Statistics `json:"summary"`
+}
+
+// allReposStatus is the output of 'bd status --all-repos'.
+type allReposStatus struct {
+	Repos []repoStatistics  `json:"repos"`
+	Total *types.Statistics `json:"total"`
+}
+
+// statusAllRepos is 'bd status --all-repos'.
+func statusAllRepos(ctx context.Context) {
+	repos, closeAll := openAllRepoStores(ctx)
+	defer closeAll()
+
+	out := allReposStatus{Repos: []repoStatistics{}, Total: &types.Statistics{}}
+	var leadTimeWeight float64
+	for _, r := range repos {
+		stats, err := r.store.GetStatistics(ctx)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "warning: skipping workspace %s: %v\n", r.name, err)
+			continue
+		}
+		out.Repos = append(out.Repos, repoStatistics{SourceRepo: r.name, Summary: stats})
+		t := out.Total
+		t.TotalIssues += stats.TotalIssues
+		t.OpenIssues += stats.OpenIssues
+		t.InProgressIssues += stats.InProgressIssues
+		t.ClosedIssues += stats.ClosedIssues
+		t.BlockedIssues += stats.BlockedIssues
+		t.DeferredIssues += stats.DeferredIssues
+		t.ReadyIssues += stats.ReadyIssues
+		t.PinnedIssues += stats.PinnedIssues
+		t.EpicsEligibleForClosure += stats.EpicsEligibleForClosure
+		// Average lead times weighted by the closed issues they cover.
+		t.AverageLeadTime += stats.AverageLeadTime * float64(stats.ClosedIssues)
+		leadTimeWeight += float64(stats.ClosedIssues)
+	}
+	if leadTimeWeight > 0 {
+		out.Total.AverageLeadTime /= leadTimeWeight
+	}
+
+	if jsonOutput {
+		outputJSON(out)
+		return
+	}
+	fmt.Printf("\n%s Issue Database Status (%d repositories)\n\n", ui.RenderAccent("📊"), len(out.Repos))
+	fmt.Printf("  %-20s %7s %7s %11s %7s %7s %7s\n", "Repository", "Total", "Open", "In Progress", "Blocked", "Closed", "Ready")
+	row := func(name string, s *types.Statistics) {
+		fmt.Printf("  %-20s %7d %7d %11d %7d %7d %7d\n", name, s.TotalIssues, s.OpenIssues, s.InProgressIssues, s.BlockedIssues, s.ClosedIssues, s.ReadyIssues)
+	}
+	for _, r := range out.Repos {
+		row(r.SourceRepo, r.Summary)
+	}
+	row("total", out.Total)
+	fmt.Println()
+}
@@ -1,29 +1,48 @@
 package main
 
 import (
+	"context"
 	"fmt"
+	"slices"
+	"strings"
 
 	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/ui"
 )
 
 var assignCmd = &cobra.Command{
-	Use:     "assign <id> <name>",
+	Use:     "assign <id> [name]",
 	GroupID: "issues",
 	Short:   "Assign an issue to someone",
 	Long: `Assign an issue to someone.
 
-Shorthand for 'bd update <id> --assignee <name>'.
+With a name, shorthand for 'bd update <id> --assignee <name>'.
+
+Use --add and --remove to share an issue between several people, e.g. for
+pairing or human+agent co-ownership. The first assignee stays the primary
+one ('assignee' in JSON); the rest are co-assignees ('assignees'). Removing
+the primary assignee promotes the first co-assignee. Assignee filters such
+as 'bd list --assignee' and 'bd ready --assignee' match co-assignees too.
 
 Examples:
   bd assign bd-123 alice
-  bd assign bd-123 ""      # unassign`,
-	Args: cobra.ExactArgs(2),
+  bd assign bd-123 ""               # unassign
+  bd assign bd-123 --add bob        # alice and bob share bd-123
+  bd assign bd-123 --remove alice   # bob becomes the primary assignee`,
+	Args: cobra.RangeArgs(1, 2),
 	Run: func(cmd *cobra.Command, args []string) {
 		CheckReadonly("assign")
 
+		addNames, _ := cmd.Flags().GetStringArray("add")
+		removeNames, _ := cmd.Flags().GetStringArray("remove")
+		editing := len(addNames) > 0 || len(removeNames) > 0
+		if editing == (len(args) == 2) {
+			FatalErrorRespectJSON("give either a name or --add/--remove")
+		}
+
 		id := args[0]
-		assignee := args[1]
 
 		ctx := rootCtx
 
@@ -48,11 +67,15 @@ Examples:
 			FatalErrorRespectJSON("%s", err)
 		}
 
-		updates := map[string]interface{}{
-			"assignee": assignee,
-		}
-		if err := issueStore.UpdateIssue(ctx, result.ResolvedID, updates, actor); err != nil {
-			FatalErrorRespectJSON("updating %s: %v", id, err)
+		if editing {
+			if err := editAssignees(ctx, issueStore, result.Issue, addNames, removeNames); err != nil {
+				FatalErrorRespectJSON("updating %s: %v", id, err)
+			}
+		} else {
+			update := storage.IssueUpdate{Assignee: &args[1]}
+			if err := storage.ApplyIssueUpdate(ctx, issueStore, result.ResolvedID, update, actor); err != nil {
+				FatalErrorRespectJSON("updating %s: %v", id, err)
+			}
 		}
 
 		commandDidWrite.Store(true)
@@ -64,22 +87,110 @@ Examples:
 		title := ""
 		if updatedIssue != nil {
 			title = updatedIssue.Title
+			loadCoAssignees(ctx, issueStore, updatedIssue)
 		}
 		if jsonOutput {
 			if updatedIssue != nil {
 				outputJSON(updatedIssue)
 			}
+			return
+		}
+		var everyone []string
+		if updatedIssue != nil {
+			everyone = allAssignees(updatedIssue)
+		}
+		if len(everyone) == 0 {
+			fmt.Printf("%s Unassigned %s\n", ui.RenderPass("✓"), formatFeedbackID(result.ResolvedID, title))
 		} else {
-			if assignee == "" {
-				fmt.Printf("%s Unassigned %s\n", ui.RenderPass("✓"), formatFeedbackID(result.ResolvedID, title))
-			} else {
-				fmt.Printf("%s Assigned %s to %s\n", ui.RenderPass("✓"), formatFeedbackID(result.ResolvedID, title), assignee)
-			}
+			fmt.Printf("%s Assigned %s to %s\n", ui.RenderPass("✓"), formatFeedbackID(result.ResolvedID, title), strings.Join(everyone, ", "))
 		}
 	},
 }
 
+// planAssignees applies --add and --remove to an issue's primary assignee
+// and co-assignees. The first name added to an unassigned issue becomes
+// its primary assignee; removing the primary promotes the first remaining
+// co-assignee.
+func planAssignees(primary string, co, add, remove []string) (string, []string) {
+	next := slices.Clone(co)
+	for _, name := range add {
+		name = strings.TrimSpace(name)
+		if name == "" || name == primary || slices.Contains(next, name) {
+			continue
+		}
+		if primary == "" {
+			primary = name
+		} else {
+			next = append(next, name)
+		}
+	}
+	for _, name := range remove {
+		name = strings.TrimSpace(name)
+		if name == primary {
+			primary = ""
+		}
+		next = slices.DeleteFunc(next, func(n string) bool { return n == name })
+	}
+	if primary == "" && len(next) > 0 {
+		primary, next = next[0], next[1:]
+	}
+	slices.Sort(next)
+	return primary, next
+}
+
+// editAssignees brings issue's assignees in line with planAssignees,
+// recording an event for each change.
+func editAssignees(ctx context.Context, s storage.DoltStorage, issue *types.Issue, add, remove []string) error {
+	current, err := s.GetAssigneesForIssues(ctx, []string{issue.ID})
+	if err != nil {
+		return err
+	}
+	co := current[issue.ID]
+	primary, next := planAssignees(issue.Assignee, co, add, remove)
+
+	for _, name := range co {
+		if !slices.Contains(next, name) {
+			if _, err := s.RemoveAssignee(ctx, issue.ID, name, actor); err != nil {
+				return err
+			}
+		}
+	}
+	if primary != issue.Assignee {
+		update := storage.IssueUpdate{Assignee: &primary}
+		if err := storage.ApplyIssueUpdate(ctx, s, issue.ID, update, actor); err != nil {
+			return err
+		}
+	}
+	for _, name := range next {
+		if !slices.Contains(co, name) {
+			if err := s.AddAssignee(ctx, issue.ID, name, actor); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
+}
+
+// loadCoAssignees fills in issue.Assignees. Best effort: an issue whose
+// co-assignees cannot be read is shown with its primary assignee only.
+func loadCoAssignees(ctx context.Context, s storage.DoltStorage, issue *types.Issue) {
+	if assignees, err := s.GetAssigneesForIssues(ctx, []string{issue.ID}); err == nil {
+		issue.Assignees = assignees[issue.ID]
+	}
+}
+
+// allAssignees lists the primary assignee followed by any co-assignees.
+func allAssignees(issue *types.Issue) []string {
+	var all []string
+	if issue.Assignee != "" {
+		all = append(all, issue.Assignee)
+	}
+	return append(all, issue.Assignees...)
+}
+
 func init() {
+	assignCmd.Flags().StringArray("add", nil, "Add an assignee, keeping the existing ones (repeatable)")
+	assignCmd.Flags().StringArray("remove", nil, "Remove an assignee (repeatable)")
 	assignCmd.ValidArgsFunction = issueIDCompletion
 	rootCmd.AddCommand(assignCmd)
 }
@@ -0,0 +1,32 @@
+package main
+
+import (
+	"slices"
+	"testing"
+)
+
+func TestPlanAssignees(t *testing.T) {
+	tests := []struct {
+		name        string
+		primary     string
+		co          []string
+		add, remove []string
+		wantPrimary string
+		wantCo      []string
+	}{
+		{name: "first add becomes primary", add: []string{"alice", "bob"}, wantPrimary: "alice", wantCo: []string{"bob"}},
+		{name: "add keeps primary", primary: "alice", add: []string{"carol", "bob"}, wantPrimary: "alice", wantCo: []string{"bob", "carol"}},
+		{name: "duplicates ignored", primary: "alice", co: []string{"bob"}, add: []string{"alice", "bob", " "}, wantPrimary: "alice", wantCo: []string{"bob"}},
+		{name: "remove co-assignee", primary: "alice", co: []string{"bob", "carol"}, remove: []string{"carol"}, wantPrimary: "alice", wantCo: []string{"bob"}},
+		{name: "remove primary promotes", primary: "alice", co: []string{"bob", "carol"}, remove: []string{"alice"}, wantPrimary: "bob", wantCo: []string{"carol"}},
+		{name: "remove everyone", primary: "alice", co: []string{"bob"}, remove: []string{"alice", "bob"}, wantPrimary: ""},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			primary, co := planAssignees(tt.primary, tt.co, tt.add, tt.remove)
+			if primary != tt.wantPrimary || !slices.Equal(co, tt.wantCo) {
+				t.Errorf("planAssignees() = %q, %v; want %q, %v", primary, co, tt.wantPrimary, tt.wantCo)
+			}
+		})
+	}
+}
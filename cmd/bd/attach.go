@@ -0,0 +1,359 @@
+package main
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+// issueAttachmentsKey is the metadata key holding an issue's attachments.
+// Only the references live in metadata (and so in JSONL); the content is
+// stored once per SHA-256 under .beads/blobs/.
+const issueAttachmentsKey = "attachments"
+
+// issueAttachment is a file attached to an issue.
+type issueAttachment struct {
+	Name      string    `json:"name"`
+	SHA256    string    `json:"sha256"`
+	Size      int64     `json:"size"`
+	MediaType string    `json:"media_type,omitempty"`
+	AddedBy   string    `json:"added_by,omitempty"`
+	AddedAt   time.Time `json:"added_at"`
+}
+
+var attachCmd = &cobra.Command{
+	Use:     "attach <issue-id> <file>",
+	GroupID: "issues",
+	Short:   "Attach a file to an issue",
+	Long: `Attach a file to an issue.
+
+The content is stored by SHA-256 under .beads/blobs/, so attaching the same
+file twice (to any issue) stores it once. The issue records the name, hash,
+size and type of the file in its metadata, which is what JSONL export
+carries; commit .beads/blobs/ to share the content itself.
+
+'bd show' lists attachments with the path of each stored blob.`,
+	Example: `  bd attach bd-12 screenshot.png
+  bd attach bd-12 /tmp/trace.log --name crash-trace.log`,
+	Args: cobra.ExactArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("attach")
+		ctx := rootCtx
+		issueID, err := utils.ResolvePartialID(ctx, store, args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		blobDir := attachmentBlobDir()
+		if blobDir == "" {
+			FatalErrorRespectJSON("no .beads directory found")
+		}
+
+		att, err := storeAttachmentBlob(blobDir, args[1])
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if name, _ := cmd.Flags().GetString("name"); name != "" {
+			att.Name = name
+		}
+		att.AddedBy = getActor()
+		att.AddedAt = time.Now().UTC()
+
+		added, err := attachToIssue(ctx, store, issueID, att)
+		if err != nil {
+			FatalErrorRespectJSON("attaching to %s: %v", issueID, err)
+		}
+		if added {
+			commandDidWrite.Store(true)
+		}
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{
+				"issue_id":   issueID,
+				"attachment": att,
+				"path":       attachmentBlobPath(blobDir, att.SHA256),
+				"added":      added,
+			})
+			return
+		}
+		if !added {
+			fmt.Printf("%s already has %s attached\n", issueID, att.Name)
+			return
+		}
+		fmt.Printf("%s Attached %s (%s) to %s\n", ui.RenderPass("✓"), att.Name, formatAttachmentSize(att.Size), issueID)
+	},
+}
+
+var detachCmd = &cobra.Command{
+	Use:     "detach <issue-id> <name|sha256>",
+	GroupID: "issues",
+	Short:   "Remove an attachment from an issue",
+	Long: `Remove an attachment from an issue, by name or by (a prefix of) its SHA-256.
+
+The stored blob is left in .beads/blobs/, since other issues may share it.`,
+	Args: cobra.ExactArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("detach")
+		ctx := rootCtx
+		issueID, err := utils.ResolvePartialID(ctx, store, args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		issue, err := store.GetIssue(ctx, issueID)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		metadata, removed, err := withoutIssueAttachment(issue.Metadata, args[1])
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if len(removed) == 0 {
+			FatalErrorRespectJSON("%s has no attachment matching %q", issueID, args[1])
+		}
+		if err := store.UpdateIssue(ctx, issueID, map[string]interface{}{"metadata": metadata}, getActor()); err != nil {
+			FatalErrorRespectJSON("detaching from %s: %v", issueID, err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{
+				"issue_id": issueID,
+				"removed":  removed,
+			})
+			return
+		}
+		for _, att := range removed {
+			fmt.Printf("%s Removed %s from %s\n", ui.RenderPass("✓"), att.Name, issueID)
+		}
+	},
+}
+
+// attachmentBlobDir returns the .beads/blobs directory of the current
+// workspace, or "" outside one.
+func attachmentBlobDir() string {
+	beadsDir := beads.FindBeadsDir()
+	if beadsDir == "" {
+		return ""
+	}
+	return filepath.Join(beadsDir, "blobs")
+}
+
+// attachmentBlobPath is where the blob with the given hash is stored,
+// sharded by its first two hex digits.
+func attachmentBlobPath(blobDir, sum string) string {
+	return filepath.Join(blobDir, sum[:2], sum)
+}
+
+// storeAttachmentBlob copies the file at path into blobDir under its
+// SHA-256 and describes it. Content already stored is not rewritten.
+func storeAttachmentBlob(blobDir, path string) (*issueAttachment, error) {
+	// #nosec G304 -- user explicitly names the file to attach
+	src, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer func() { _ = src.Close() }()
+	info, err := src.Stat()
+	if err != nil {
+		return nil, err
+	}
+	if info.IsDir() {
+		return nil, fmt.Errorf("%s is a directory", path)
+	}
+
+	if err := os.MkdirAll(blobDir, 0o750); err != nil {
+		return nil, fmt.Errorf("creating blob directory: %w", err)
+	}
+	tmp, err := os.CreateTemp(blobDir, ".incoming-*")
+	if err != nil {
+		return nil, fmt.Errorf("creating blob: %w", err)
+	}
+	defer func() { _ = os.Remove(tmp.Name()) }()
+
+	h := sha256.New()
+	sniff := make([]byte, 512)
+	n, _ := io.ReadFull(src, sniff)
+	sniff = sniff[:n]
+	size, err := io.Copy(io.MultiWriter(tmp, h), io.MultiReader(bytes.NewReader(sniff), src))
+	if closeErr := tmp.Close(); err == nil {
+		err = closeErr
+	}
+	if err != nil {
+		return nil, fmt.Errorf("copying %s: %w", path, err)
+	}
+
+	sum := hex.EncodeToString(h.Sum(nil))
+	dest := attachmentBlobPath(blobDir, sum)
+	if _, err := os.Stat(dest); os.IsNotExist(err) {
+		if err := os.MkdirAll(filepath.Dir(dest), 0o750); err != nil {
+			return nil, fmt.Errorf("creating blob directory: %w", err)
+		}
+		if err := os.Rename(tmp.Name(), dest); err != nil {
+			return nil, fmt.Errorf("storing blob: %w", err)
+		}
+	}
+
+	return &issueAttachment{
+		Name:      filepath.Base(path),
+		SHA256:    sum,
+		Size:      size,
+		MediaType: http.DetectContentType(sniff),
+	}, nil
+}
+
+// issueAttachments returns the files attached to issue, in attach order.
+func issueAttachments(issue *types.Issue) []issueAttachment {
+	if issue == nil || len(issue.Metadata) == 0 {
+		return nil
+	}
+	var data struct {
+		Attachments []issueAttachment `json:"attachments"`
+	}
+	if json.Unmarshal(issue.Metadata, &data) != nil {
+		return nil
+	}
+	return data.Attachments
+}
+
+// editIssueAttachments applies edit to the attachments recorded in
+// metadata and returns the updated metadata.
+func editIssueAttachments(metadata json.RawMessage, edit func([]issueAttachment) []issueAttachment) (json.RawMessage, error) {
+	data := make(map[string]json.RawMessage)
+	if trimmed := strings.TrimSpace(string(metadata)); trimmed != "" && trimmed != "null" {
+		if err := json.Unmarshal(metadata, &data); err != nil {
+			return nil, fmt.Errorf("existing metadata is not a JSON object: %w", err)
+		}
+	}
+	var attachments []issueAttachment
+	if raw, ok := data[issueAttachmentsKey]; ok {
+		if err := json.Unmarshal(raw, &attachments); err != nil {
+			return nil, fmt.Errorf("metadata %q is not a list of attachments: %w", issueAttachmentsKey, err)
+		}
+	}
+	attachments = edit(attachments)
+	if len(attachments) == 0 {
+		delete(data, issueAttachmentsKey)
+	} else {
+		raw, err := json.Marshal(attachments)
+		if err != nil {
+			return nil, err
+		}
+		data[issueAttachmentsKey] = raw
+	}
+	result, err := json.Marshal(data)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal metadata: %w", err)
+	}
+	return result, nil
+}
+
+// withIssueAttachment returns metadata with att added, or false if the same
+// content is already attached under the same name.
+func withIssueAttachment(metadata json.RawMessage, att *issueAttachment) (json.RawMessage, bool, error) {
+	added := false
+	result, err := editIssueAttachments(metadata, func(atts []issueAttachment) []issueAttachment {
+		if slices.ContainsFunc(atts, func(a issueAttachment) bool { return a.SHA256 == att.SHA256 && a.Name == att.Name }) {
+			return atts
+		}
+		added = true
+		return append(atts, *att)
+	})
+	return result, added, err
+}
+
+// withoutIssueAttachment returns metadata without the attachments named
+// ref, or whose hash starts with ref, and the attachments removed.
+func withoutIssueAttachment(metadata json.RawMessage, ref string) (json.RawMessage, []issueAttachment, error) {
+	var removed []issueAttachment
+	result, err := editIssueAttachments(metadata, func(atts []issueAttachment) []issueAttachment {
+		return slices.DeleteFunc(atts, func(a issueAttachment) bool {
+			match := a.Name == ref || (len(ref) >= 7 && strings.HasPrefix(a.SHA256, strings.ToLower(ref)))
+			if match {
+				removed = append(removed, a)
+			}
+			return match
+		})
+	})
+	return result, removed, err
+}
+
+// attachToIssue records att on the issue id in st. It reports false if
+// it was already attached.
+func attachToIssue(ctx context.Context, st storage.DoltStorage, id string, att *issueAttachment) (bool, error) {
+	issue, err := st.GetIssue(ctx, id)
+	if err != nil {
+		return false, err
+	}
+	metadata, added, err := withIssueAttachment(issue.Metadata, att)
+	if err != nil || !added {
+		return false, err
+	}
+	if err := st.UpdateIssue(ctx, id, map[string]interface{}{"metadata": metadata}, getActor()); err != nil {
+		return false, err
+	}
+	return true, nil
+}
+
+// formatIssueAttachments renders the ATTACHMENTS section of bd show, or ""
+// if nothing is attached. Blobs missing from this clone are flagged.
+func formatIssueAttachments(issue *types.Issue) string {
+	attachments := issueAttachments(issue)
+	if len(attachments) == 0 {
+		return ""
+	}
+	blobDir := attachmentBlobDir()
+	lines := []string{ui.RenderBold("ATTACHMENTS")}
+	for _, a := range attachments {
+		line := fmt.Sprintf("  %s %s", a.Name, ui.RenderMuted("("+formatAttachmentSize(a.Size)+")"))
+		if blobDir != "" && len(a.SHA256) > 2 {
+			path := attachmentBlobPath(blobDir, a.SHA256)
+			if _, err := os.Stat(path); err == nil {
+				if cwd, err := os.Getwd(); err == nil {
+					if rel, err := filepath.Rel(cwd, path); err == nil {
+						path = rel
+					}
+				}
+				line += " " + ui.RenderMuted(path)
+			} else {
+				line += " " + ui.RenderWarn("(content not in this clone)")
+			}
+		}
+		lines = append(lines, line)
+	}
+	return strings.Join(lines, "\n")
+}
+
+func formatAttachmentSize(n int64) string {
+	switch {
+	case n < 1024:
+		return fmt.Sprintf("%d B", n)
+	case n < 1024*1024:
+		return fmt.Sprintf("%.1f KB", float64(n)/1024)
+	default:
+		return fmt.Sprintf("%.1f MB", float64(n)/(1024*1024))
+	}
+}
+
+func init() {
+	attachCmd.Flags().String("name", "", "Name to record for the attachment (default: the file's base name)")
+	attachCmd.ValidArgsFunction = issueIDCompletion
+	detachCmd.ValidArgsFunction = issueIDCompletion
+	rootCmd.AddCommand(attachCmd)
+	rootCmd.AddCommand(detachCmd)
+}
@@ -0,0 +1,74 @@
+package main
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestStoreAttachmentBlob(t *testing.T) {
+	dir := t.TempDir()
+	blobDir := filepath.Join(dir, ".beads", "blobs")
+	content := []byte("panic: runtime error\n")
+	src := filepath.Join(dir, "trace.log")
+	if err := os.WriteFile(src, content, 0o600); err != nil {
+		t.Fatal(err)
+	}
+	sum := sha256.Sum256(content)
+	want := hex.EncodeToString(sum[:])
+
+	att, err := storeAttachmentBlob(blobDir, src)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if att.SHA256 != want || att.Name != "trace.log" || att.Size != int64(len(content)) {
+		t.Errorf("attachment = %+v, want sha %s, name trace.log, size %d", att, want, len(content))
+	}
+	if att.MediaType != "text/plain; charset=utf-8" {
+		t.Errorf("MediaType = %q", att.MediaType)
+	}
+	stored, err := os.ReadFile(filepath.Join(blobDir, want[:2], want))
+	if err != nil || string(stored) != string(content) {
+		t.Fatalf("stored blob = %q, %v", stored, err)
+	}
+
+	// Storing the same content again reuses the blob and leaves no temp files.
+	if _, err := storeAttachmentBlob(blobDir, src); err != nil {
+		t.Fatal(err)
+	}
+	entries, err := os.ReadDir(blobDir)
+	if err != nil || len(entries) != 1 {
+		t.Errorf("blob dir entries = %v, %v; want only the shard directory", entries, err)
+	}
+}
+
+func TestIssueAttachmentMetadata(t *testing.T) {
+	att := &issueAttachment{Name: "a.png", SHA256: "0123456789abcdef", Size: 10}
+	metadata, added, err := withIssueAttachment(json.RawMessage(`{"team":"core"}`), att)
+	if err != nil || !added {
+		t.Fatalf("withIssueAttachment = %s, %v, %v", metadata, added, err)
+	}
+	if _, added, err := withIssueAttachment(metadata, att); err != nil || added {
+		t.Errorf("re-attaching = %v, %v; want not added", added, err)
+	}
+	issue := &types.Issue{Metadata: metadata}
+	if got := issueAttachments(issue); len(got) != 1 || got[0].Name != "a.png" {
+		t.Errorf("issueAttachments = %+v", got)
+	}
+
+	if _, removed, _ := withoutIssueAttachment(metadata, "0123"); len(removed) != 0 {
+		t.Errorf("a hash prefix shorter than 7 characters matched %+v", removed)
+	}
+	metadata, removed, err := withoutIssueAttachment(metadata, "0123456")
+	if err != nil || len(removed) != 1 {
+		t.Fatalf("withoutIssueAttachment = %v, %v", removed, err)
+	}
+	if string(metadata) != `{"team":"core"}` {
+		t.Errorf("metadata after detach = %s, want the attachments key dropped", metadata)
+	}
+}
@@ -133,6 +133,7 @@ func maybeAutoImportJSONL(ctx context.Context, s storage.DoltStorage, beadsDir s
 		}
 		if imported > 0 {
 			writeAutoImportStamp(beadsDir, info)
+			recordJSONLFileHash(ctx, s, jsonlPath)
 			// Signal PersistentPostRun to auto-commit (no explicit DOLT_COMMIT here).
 			commandDidWrite.Store(true)
 			fmt.Fprintf(os.Stderr, "auto-imported %d issues", imported)
@@ -169,6 +170,7 @@ func maybeAutoImportJSONL(ctx context.Context, s storage.DoltStorage, beadsDir s
 	}
 	if result.Issues > 0 || result.Memories > 0 {
 		writeAutoImportStamp(beadsDir, info)
+		recordJSONLFileHash(ctx, s, jsonlPath)
 	}
 
 	if result.Memories > 0 {
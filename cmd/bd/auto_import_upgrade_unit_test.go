@@ -313,7 +313,8 @@ func TestMaybeAutoImportJSONL_ChangedJSONLBypassesSuccessStamp(t *testing.T) {
 // captureOptsStore is a storage.DoltStorage that records the
 // BatchCreateOptions handed to CreateIssuesWithFullOptions. Every other
 // method panics (embedded nil interface); the import plumbing under test
-// only touches CreateIssuesWithFullOptions, GetConfig and SetConfig.
+// only touches CreateIssuesWithFullOptions, GetConfig, GetAllConfig and
+// SetConfig.
 type captureOptsStore struct {
 	storage.DoltStorage // nil — panics on any non-overridden method
 	prefix              string
@@ -333,6 +334,10 @@ func (c *captureOptsStore) SetConfig(_ context.Context, _, _ string) error {
 	return nil
 }
 
+func (c *captureOptsStore) GetAllConfig(_ context.Context) (map[string]string, error) {
+	return map[string]string{}, nil
+}
+
 // GetIssuesByIDs reports no existing rows so importIssuesCore's stale-import
 // filter (filterStaleImportIssues) is a no-op and the import proceeds to
 // CreateIssuesWithFullOptions, where the options under test are recorded.
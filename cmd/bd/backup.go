@@ -22,9 +22,11 @@ and interoperability.
 Commands:
   bd backup init <path>    Set up a backup destination (filesystem or DoltHub)
   bd backup sync           Push to configured backup destination
-  bd backup restore [path] Restore from a backup directory
+  bd backup restore [path] Restore from a backup directory or snapshot
   bd backup remove         Remove backup destination
   bd backup status         Show backup status
+  bd backup snapshot       Take a timestamped snapshot in .beads/backups/
+  bd backup list           List snapshots
 
 DoltHub is recommended for cloud backup:
   bd backup init https://doltremoteapi.dolthub.com/<user>/<repo>
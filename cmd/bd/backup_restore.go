@@ -14,12 +14,13 @@ import (
 )
 
 var backupRestoreCmd = &cobra.Command{
-	Use:   "restore [path]",
+	Use:   "restore [path|snapshot]",
 	Short: "Restore database from a Dolt backup",
 	Long: `Restore the beads database from a Dolt-native backup.
 
 By default, reads from .beads/backup/ (or the configured backup directory).
-Optionally specify a path to a directory containing a Dolt backup.
+Optionally specify a path to a directory containing a Dolt backup, or the
+timestamp of a snapshot in .beads/backups/ (see 'bd backup list').
 
 This restores a full database backup created by 'bd backup sync' or an
 equivalent Dolt backup. JSONL files produced by 'bd export' are issue exports,
@@ -36,6 +37,9 @@ To initialize and restore in one step, use: bd init && bd backup restore`,
 		var dir string
 		if len(args) > 0 {
 			dir = args[0]
+			if snap := snapshotRestoreDir(args[0]); snap != "" {
+				dir = snap
+			}
 		} else {
 			var err error
 			dir, err = backupDir()
@@ -0,0 +1,303 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// --- Timestamped local snapshots ---
+//
+// A snapshot is a point-in-time copy kept under .beads/backups/<timestamp>/:
+//
+//	dolt/          Dolt-native backup (restorable with 'bd backup restore')
+//	issues.jsonl   issue and memory export, readable without bd
+//	snapshot.json  when and why it was taken
+//
+// Unlike 'bd backup sync', which keeps one continuously updated copy, each
+// snapshot is kept until pruned, so a bad import can be rolled back to the
+// state just before it.
+
+const snapshotTimeFormat = "20060102T150405Z"
+
+// snapshotInfo is the snapshot.json written into each snapshot.
+type snapshotInfo struct {
+	Name       string    `json:"name"`
+	CreatedAt  time.Time `json:"created_at"`
+	Reason     string    `json:"reason,omitempty"`
+	DoltCommit string    `json:"dolt_commit,omitempty"`
+	Issues     int       `json:"issues"`
+	Memories   int       `json:"memories"`
+}
+
+var backupSnapshotCmd = &cobra.Command{
+	Use:   "snapshot",
+	Short: "Take a timestamped snapshot in .beads/backups/",
+	Long: `Take a timestamped snapshot of the database in .beads/backups/<timestamp>/.
+
+Each snapshot holds a Dolt-native backup of the database and a JSONL export of
+its issues and memories. Snapshots are also taken automatically before 'bd
+import' and 'bd migrate issues' change an existing database, and before
+pending schema migrations run on an upgrade or in 'bd migrate schema'
+(disable with backup.snapshot-before-write=false).
+
+The newest backup.snapshot-keep snapshots are kept (default 10); older ones
+are removed when a new snapshot is taken.
+
+Restore one with: bd backup restore <timestamp> --force`,
+	Args: cobra.NoArgs,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		if store == nil {
+			return fmt.Errorf("no store available")
+		}
+		reason, _ := cmd.Flags().GetString("reason")
+		info, err := takeSnapshot(rootCtx, reason)
+		if err != nil {
+			return err
+		}
+		if jsonOutput {
+			outputJSON(info)
+			return nil
+		}
+		fmt.Printf("%s Snapshot %s (%d issues, %d memories)\n", ui.RenderPass("✓"), info.Name, info.Issues, info.Memories)
+		return nil
+	},
+}
+
+var backupListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List snapshots in .beads/backups/",
+	Args:  cobra.NoArgs,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		dir, err := snapshotsDir()
+		if err != nil {
+			return err
+		}
+		snapshots, err := listSnapshots(dir)
+		if err != nil {
+			return err
+		}
+		if jsonOutput {
+			if snapshots == nil {
+				snapshots = []*snapshotInfo{}
+			}
+			outputJSON(snapshots)
+			return nil
+		}
+		if len(snapshots) == 0 {
+			fmt.Println("No snapshots. Take one with: bd backup snapshot")
+			return nil
+		}
+		for _, s := range snapshots {
+			line := fmt.Sprintf("%s  %5d issues", s.Name, s.Issues)
+			if s.Reason != "" {
+				line += "  " + s.Reason
+			}
+			fmt.Println(line)
+		}
+		return nil
+	},
+}
+
+func init() {
+	backupSnapshotCmd.Flags().String("reason", "", "Note recorded with the snapshot")
+	backupCmd.AddCommand(backupSnapshotCmd)
+	backupCmd.AddCommand(backupListCmd)
+}
+
+// snapshotsDir returns .beads/backups, creating it if needed.
+func snapshotsDir() (string, error) {
+	beadsDir := beads.FindBeadsDir()
+	if beadsDir == "" {
+		return "", fmt.Errorf("%s; %s", activeWorkspaceNotFoundError(), diagHint())
+	}
+	dir := filepath.Join(beadsDir, "backups")
+	if err := os.MkdirAll(dir, 0700); err != nil {
+		return "", fmt.Errorf("failed to create snapshot directory: %w", err)
+	}
+	return dir, nil
+}
+
+// takeSnapshot writes a new snapshot of the current database and prunes
+// old ones.
+func takeSnapshot(ctx context.Context, reason string) (*snapshotInfo, error) {
+	dir, err := snapshotsDir()
+	if err != nil {
+		return nil, err
+	}
+	bs, ok := storage.UnwrapStore(store).(storage.BackupStore)
+	if !ok {
+		return nil, fmt.Errorf("storage backend does not support backup operations")
+	}
+
+	now := time.Now().UTC()
+	name := now.Format(snapshotTimeFormat)
+	// Two snapshots in the same second (e.g. back-to-back imports) get a
+	// numeric suffix rather than overwriting each other.
+	for i := 2; ; i++ {
+		if _, err := os.Stat(filepath.Join(dir, name)); os.IsNotExist(err) {
+			break
+		}
+		name = fmt.Sprintf("%s-%d", now.Format(snapshotTimeFormat), i)
+	}
+	path := filepath.Join(dir, name)
+	doltDir := filepath.Join(path, "dolt")
+	if err := os.MkdirAll(doltDir, 0700); err != nil {
+		return nil, fmt.Errorf("failed to create snapshot: %w", err)
+	}
+
+	info := &snapshotInfo{Name: name, CreatedAt: now, Reason: reason}
+	if err := bs.BackupDatabase(ctx, doltDir); err != nil {
+		_ = os.RemoveAll(path)
+		return nil, fmt.Errorf("snapshot database: %w", err)
+	}
+	info.Issues, info.Memories, _, err = exportToFile(ctx, filepath.Join(path, "issues.jsonl"), true)
+	if err != nil {
+		_ = os.RemoveAll(path)
+		return nil, fmt.Errorf("snapshot JSONL: %w", err)
+	}
+	info.DoltCommit, _ = store.GetCurrentCommit(ctx)
+
+	data, err := json.MarshalIndent(info, "", "  ")
+	if err != nil {
+		return nil, err
+	}
+	if err := atomicWriteFile(filepath.Join(path, "snapshot.json"), data); err != nil {
+		return nil, err
+	}
+
+	keep := config.GetInt("backup.snapshot-keep")
+	if err := pruneSnapshots(dir, keep); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: failed to prune old snapshots: %v\n", err)
+	}
+	return info, nil
+}
+
+// listSnapshots returns the snapshots in dir, oldest first. Directories
+// without a readable snapshot.json (e.g. an interrupted snapshot) are
+// skipped.
+func listSnapshots(dir string) ([]*snapshotInfo, error) {
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		if os.IsNotExist(err) {
+			return nil, nil
+		}
+		return nil, fmt.Errorf("failed to read snapshot directory: %w", err)
+	}
+	var snapshots []*snapshotInfo
+	for _, e := range entries {
+		if !e.IsDir() {
+			continue
+		}
+		data, err := os.ReadFile(filepath.Join(dir, e.Name(), "snapshot.json")) //nolint:gosec // path is constructed internally
+		if err != nil {
+			continue
+		}
+		var info snapshotInfo
+		if err := json.Unmarshal(data, &info); err != nil {
+			continue
+		}
+		info.Name = e.Name()
+		snapshots = append(snapshots, &info)
+	}
+	sort.Slice(snapshots, func(i, j int) bool {
+		if !snapshots[i].CreatedAt.Equal(snapshots[j].CreatedAt) {
+			return snapshots[i].CreatedAt.Before(snapshots[j].CreatedAt)
+		}
+		return snapshots[i].Name < snapshots[j].Name
+	})
+	return snapshots, nil
+}
+
+// pruneSnapshots removes all but the newest keep snapshots. keep <= 0
+// keeps everything.
+func pruneSnapshots(dir string, keep int) error {
+	if keep <= 0 {
+		return nil
+	}
+	snapshots, err := listSnapshots(dir)
+	if err != nil {
+		return err
+	}
+	for len(snapshots) > keep {
+		if err := os.RemoveAll(filepath.Join(dir, snapshots[0].Name)); err != nil {
+			return err
+		}
+		snapshots = snapshots[1:]
+	}
+	return nil
+}
+
+// snapshotRestoreDir returns the Dolt backup inside the snapshot named
+// name, or "" if there is no such snapshot.
+func snapshotRestoreDir(name string) string {
+	if name == "" || filepath.Base(name) != name {
+		return ""
+	}
+	beadsDir := beads.FindBeadsDir()
+	if beadsDir == "" {
+		return ""
+	}
+	doltDir := filepath.Join(beadsDir, "backups", name, "dolt")
+	if info, err := os.Stat(doltDir); err != nil || !info.IsDir() {
+		return ""
+	}
+	return doltDir
+}
+
+// snapshotBeforeWrite takes a snapshot before a command rewrites existing
+// issues in bulk, so its effect can be undone with 'bd backup restore'.
+// Nothing is taken for an empty database or when
+// backup.snapshot-before-write is off. A failed snapshot is reported and
+// the command goes ahead.
+func snapshotBeforeWrite(ctx context.Context, reason string) {
+	if store == nil || !config.GetBool("backup.snapshot-before-write") {
+		return
+	}
+	if n, err := store.CountIssues(ctx, "", types.IssueFilter{}); err != nil || n == 0 {
+		return
+	}
+	info, err := takeSnapshot(ctx, reason)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: snapshot before %s failed: %v\n", reason, err)
+		return
+	}
+	if !isQuiet() && !jsonOutput {
+		fmt.Fprintf(os.Stderr, "Snapshot %s taken (undo with: bd backup restore %s --force)\n", info.Name, info.Name)
+	}
+}
+
+// snapshotBeforeSchemaMigration is snapshotBeforeWrite for the schema
+// migrations that run before the main store is open: those a version bump
+// or 'bd migrate schema' applies. It opens the database in beadsDir
+// read-only and takes a snapshot only if migrations are pending. Only a
+// SQL server exposes the migration state, so embedded mode is skipped.
+func snapshotBeforeSchemaMigration(ctx context.Context, beadsDir, reason string) {
+	if beadsDir == "" || store != nil || !usesSQLServer() || !config.GetBool("backup.snapshot-before-write") {
+		return
+	}
+	ro, err := newReadOnlyStoreFromConfig(ctx, beadsDir)
+	if err != nil {
+		debug.Logf("snapshot before schema migration: %v\n", err)
+		return
+	}
+	defer func() { _ = ro.Close() }()
+	if state, err := readSchemaMigrationState(ctx, ro); err != nil || len(state.Pending) == 0 {
+		return
+	}
+	store = ro
+	defer func() { store = nil }()
+	snapshotBeforeWrite(ctx, reason)
+}
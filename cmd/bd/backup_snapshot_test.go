@@ -0,0 +1,53 @@
+package main
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+func writeTestSnapshot(t *testing.T, dir, name string, created time.Time) {
+	t.Helper()
+	if err := os.MkdirAll(filepath.Join(dir, name, "dolt"), 0o700); err != nil {
+		t.Fatal(err)
+	}
+	data, _ := json.Marshal(snapshotInfo{Name: name, CreatedAt: created})
+	if err := os.WriteFile(filepath.Join(dir, name, "snapshot.json"), data, 0o600); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestListAndPruneSnapshots(t *testing.T) {
+	t.Parallel()
+	dir := t.TempDir()
+	base := time.Date(2026, 3, 1, 12, 0, 0, 0, time.UTC)
+	for _, name := range []string{"20260301T120002Z", "20260301T120000Z", "20260301T120001Z"} {
+		created, _ := time.Parse(snapshotTimeFormat, name)
+		writeTestSnapshot(t, dir, name, created)
+	}
+	// An interrupted snapshot has no snapshot.json and is ignored.
+	if err := os.MkdirAll(filepath.Join(dir, "20260301T115959Z"), 0o700); err != nil {
+		t.Fatal(err)
+	}
+
+	snapshots, err := listSnapshots(dir)
+	if err != nil {
+		t.Fatalf("listSnapshots: %v", err)
+	}
+	if len(snapshots) != 3 || !snapshots[0].CreatedAt.Equal(base) || snapshots[2].Name != "20260301T120002Z" {
+		t.Fatalf("snapshots not listed oldest first: %+v", snapshots)
+	}
+
+	if err := pruneSnapshots(dir, 2); err != nil {
+		t.Fatalf("pruneSnapshots: %v", err)
+	}
+	if _, err := os.Stat(filepath.Join(dir, "20260301T120000Z")); !os.IsNotExist(err) {
+		t.Error("oldest snapshot was not pruned")
+	}
+	snapshots, _ = listSnapshots(dir)
+	if len(snapshots) != 2 {
+		t.Errorf("got %d snapshots after pruning, want 2", len(snapshots))
+	}
+}
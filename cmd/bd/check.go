@@ -0,0 +1,137 @@
+package main
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+var checkCmd = &cobra.Command{
+	Use:     "check <id>",
+	GroupID: "issues",
+	Short:   "Check off acceptance criteria items",
+	Long: `Show or tick off an issue's acceptance criteria checklist.
+
+Acceptance criteria written as a Markdown task list are tracked item by
+item:
+
+  bd update bd-123 --acceptance $'- [ ] parses input\n- [ ] handles errors'
+
+Progress shows in 'bd show' and 'bd list', and 'bd close' refuses to close
+an issue while items remain unchecked (use --force to override).
+
+Examples:
+  bd check bd-123                   # list items with their numbers
+  bd check bd-123 --item 2 --done   # tick item 2
+  bd check bd-123 --item 2 --undo   # untick item 2`,
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		id := args[0]
+		item, _ := cmd.Flags().GetInt("item")
+		done, _ := cmd.Flags().GetBool("done")
+		undo, _ := cmd.Flags().GetBool("undo")
+		if item != 0 && done == undo {
+			FatalErrorRespectJSON("give exactly one of --done or --undo with --item")
+		}
+		if item == 0 && (done || undo) {
+			FatalErrorRespectJSON("--done and --undo need --item")
+		}
+
+		result, err := resolveAndGetIssueWithRouting(ctx, store, id)
+		if err != nil || result == nil || result.Issue == nil {
+			if result != nil {
+				result.Close()
+			}
+			if err == nil {
+				err = fmt.Errorf("issue %s not found", id)
+			}
+			FatalErrorRespectJSON("resolving %s: %v", id, err)
+		}
+		defer result.Close()
+		issue := result.Issue
+
+		if item != 0 {
+			CheckReadonly("check")
+			if err := validateIssueUpdatable(id, issue); err != nil {
+				FatalErrorRespectJSON("%s", err)
+			}
+			updated, err := types.SetChecklistItem(issue.AcceptanceCriteria, item, done)
+			if err != nil {
+				FatalErrorRespectJSON("%s: %v", result.ResolvedID, err)
+			}
+			update := storage.IssueUpdate{AcceptanceCriteria: &updated}
+			if err := storage.ApplyIssueUpdate(ctx, result.Store, result.ResolvedID, update, actor); err != nil {
+				FatalErrorRespectJSON("updating %s: %v", id, err)
+			}
+			commandDidWrite.Store(true)
+			SetLastTouchedID(result.ResolvedID)
+			issue.AcceptanceCriteria = updated
+		}
+
+		items := types.ParseChecklist(issue.AcceptanceCriteria)
+		if jsonOutput {
+			if items == nil {
+				items = []types.ChecklistItem{}
+			}
+			outputJSON(map[string]interface{}{
+				"id":    result.ResolvedID,
+				"items": items,
+			})
+			return
+		}
+		if len(items) == 0 {
+			fmt.Printf("%s has no acceptance checklist (write criteria as \"- [ ] item\" lines)\n", result.ResolvedID)
+			return
+		}
+		completed, total := issue.ChecklistProgress()
+		fmt.Printf("%s %s\n", formatFeedbackID(result.ResolvedID, issue.Title),
+			ui.RenderMuted(fmt.Sprintf("(%d/%d done)", completed, total)))
+		for i, it := range items {
+			box := "[ ]"
+			if it.Done {
+				box = ui.RenderPass("[x]")
+			}
+			fmt.Printf("  %d. %s %s\n", i+1, box, it.Text)
+		}
+	},
+}
+
+// acceptanceCriteriaHeader titles the ACCEPTANCE CRITERIA section of bd
+// show, with checklist progress when the criteria are a task list.
+func acceptanceCriteriaHeader(issue *types.Issue) string {
+	if done, total := issue.ChecklistProgress(); total > 0 {
+		return fmt.Sprintf("ACCEPTANCE CRITERIA (%d/%d done)", done, total)
+	}
+	return "ACCEPTANCE CRITERIA"
+}
+
+// checkChecklistComplete returns an error naming the first unchecked
+// acceptance criterion, if any, for the close guards.
+func checkChecklistComplete(issue *types.Issue) error {
+	if issue == nil {
+		return nil
+	}
+	done, total := issue.ChecklistProgress()
+	if done == total {
+		return nil
+	}
+	for i, item := range types.ParseChecklist(issue.AcceptanceCriteria) {
+		if !item.Done {
+			return fmt.Errorf("%d of %d acceptance criteria unchecked, starting with #%d %q (use --force to override)",
+				total-done, total, i+1, item.Text)
+		}
+	}
+	return nil
+}
+
+func init() {
+	checkCmd.Flags().Int("item", 0, "Checklist item number (1-based, as listed by 'bd check <id>')")
+	checkCmd.Flags().Bool("done", false, "Mark the item done")
+	checkCmd.Flags().Bool("undo", false, "Mark the item not done")
+	checkCmd.ValidArgsFunction = issueIDCompletion
+	rootCmd.AddCommand(checkCmd)
+}
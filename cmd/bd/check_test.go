@@ -0,0 +1,25 @@
+package main
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestCheckChecklistComplete(t *testing.T) {
+	issue := &types.Issue{AcceptanceCriteria: "- [x] parses input\n- [ ] handles errors\n- [ ] documented"}
+	err := checkChecklistComplete(issue)
+	if err == nil || !strings.Contains(err.Error(), `2 of 3`) || !strings.Contains(err.Error(), `#2 "handles errors"`) {
+		t.Errorf("checkChecklistComplete = %v, want 2 of 3 unchecked starting with #2", err)
+	}
+
+	issue.AcceptanceCriteria = "- [x] parses input\n- [x] handles errors"
+	if err := checkChecklistComplete(issue); err != nil {
+		t.Errorf("complete checklist: %v", err)
+	}
+	issue.AcceptanceCriteria = "Free-form criteria without a checklist"
+	if err := checkChecklistComplete(issue); err != nil {
+		t.Errorf("no checklist: %v", err)
+	}
+}
@@ -0,0 +1,59 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// parseLeaseFlag parses bd update --lease: a Go-style duration such as
+// 30m or 2h. Empty means no lease.
+func parseLeaseFlag(raw string) (time.Duration, error) {
+	if raw == "" {
+		return 0, nil
+	}
+	lease, err := time.ParseDuration(raw)
+	if err != nil {
+		return 0, fmt.Errorf("invalid --lease %q: use a duration such as 30m or 2h", raw)
+	}
+	if lease <= 0 {
+		return 0, fmt.Errorf("invalid --lease %q: must be positive", raw)
+	}
+	return lease, nil
+}
+
+// currentClaimLease returns the lease behind issue's claim, or nil when the
+// issue is not claimed under a lease. Best effort: read errors yield nil.
+func currentClaimLease(ctx context.Context, s storage.DoltStorage, issue *types.Issue) *types.ClaimLease {
+	if issue.Status != types.StatusInProgress || issue.Assignee == "" {
+		return nil
+	}
+	leases, err := s.GetClaimLeases(ctx, []string{issue.ID})
+	if err != nil {
+		return nil
+	}
+	if lease := leases[issue.ID]; lease != nil && lease.Holder == issue.Assignee {
+		return lease
+	}
+	return nil
+}
+
+// formatClaimLease describes a claim lease for bd show.
+func formatClaimLease(lease *types.ClaimLease, now time.Time) string {
+	if lease.Expired(now) {
+		return fmt.Sprintf("Lease: %s's claim lapsed %s; open to re-claim", lease.Holder, formatTimeAgo(lease.ExpiresAt))
+	}
+	left := lease.ExpiresAt.Sub(now).Round(time.Minute)
+	if left < time.Minute {
+		left = time.Minute
+	}
+	remaining := strings.TrimSuffix(left.String(), "0s") // 1h30m0s -> 1h30m
+	if strings.HasSuffix(remaining, "h0m") {
+		remaining = strings.TrimSuffix(remaining, "0m")
+	}
+	return fmt.Sprintf("Lease: held by %s, expires in %s", lease.Holder, remaining)
+}
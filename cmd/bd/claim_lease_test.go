@@ -0,0 +1,39 @@
+package main
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseLeaseFlag(t *testing.T) {
+	if lease, err := parseLeaseFlag("2h"); err != nil || lease != 2*time.Hour {
+		t.Errorf("parseLeaseFlag(2h) = %v, %v", lease, err)
+	}
+	if lease, err := parseLeaseFlag(""); err != nil || lease != 0 {
+		t.Errorf("parseLeaseFlag(\"\") = %v, %v", lease, err)
+	}
+	for _, bad := range []string{"soon", "-30m", "0s"} {
+		if _, err := parseLeaseFlag(bad); err == nil {
+			t.Errorf("parseLeaseFlag(%q) succeeded, want an error", bad)
+		}
+	}
+}
+
+func TestFormatClaimLease(t *testing.T) {
+	now := time.Now()
+	lease := &types.ClaimLease{IssueID: "bd-1", Holder: "agent-a", ExpiresAt: now.Add(90 * time.Minute)}
+	if got := formatClaimLease(lease, now); got != "Lease: held by agent-a, expires in 1h30m" {
+		t.Errorf("active lease = %q", got)
+	}
+	lease.ExpiresAt = now.Add(2 * time.Hour)
+	if got := formatClaimLease(lease, now); got != "Lease: held by agent-a, expires in 2h" {
+		t.Errorf("active lease = %q", got)
+	}
+	lease.ExpiresAt = now.Add(-10 * time.Minute)
+	if got := formatClaimLease(lease, now); !strings.Contains(got, "agent-a's claim lapsed") {
+		t.Errorf("lapsed lease = %q", got)
+	}
+}
@@ -103,60 +103,16 @@ the flags appear in the command line.`,
 			// Get issue for checks (nil issue is handled by validateIssueClosable)
 			issue := result.Issue
 
-			if err := validateIssueClosable(id, issue, force); err != nil {
+			after, err := closeIssueChecked(ctx, activeStore, activeStore, id, issue, reason, actor, session, force)
+			if err != nil {
 				fmt.Fprintf(os.Stderr, "%s\n", err)
 				continue
 			}
-
-			// Epic close guard: prevent closing epics with open children (mw-local-4so.5.2)
-			if !force && issue != nil && issue.IssueType == types.TypeEpic {
-				openChildren := countEpicOpenChildren(ctx, activeStore, id)
-				if openChildren > 0 {
-					fmt.Fprintf(os.Stderr, "cannot close epic %s: %d open child issue(s); close children first or use --force to override\n", id, openChildren)
-					continue
-				}
-			}
-
-			// Check gate satisfaction for machine-checkable gates (GH#1467)
-			if !force {
-				if err := checkGateSatisfaction(issue); err != nil {
-					fmt.Fprintf(os.Stderr, "cannot close %s: %s\n", id, err)
-					continue
-				}
-			}
-
-			// Check if issue has open blockers (GH#962)
-			if !force {
-				blocked, blockers, err := activeStore.IsBlocked(ctx, id)
-				if err != nil {
-					fmt.Fprintf(os.Stderr, "Error checking blockers for %s: %v\n", id, err)
-					continue
-				}
-				if blocked && len(blockers) > 0 {
-					fmt.Fprintf(os.Stderr, "cannot close %s: blocked by open issues %v (use --force to override)\n", id, blockers)
-					continue
-				}
-			}
-
-			if err := activeStore.CloseIssue(ctx, id, reason, actor, session); err != nil {
-				fmt.Fprintf(os.Stderr, "Error closing %s: %v\n", id, err)
-				continue
-			}
 			mutatedStores[activeStore] = struct{}{}
-
-			// Audit log the close (survives Dolt GC flatten)
-			oldStatus := "open"
-			if issue != nil {
-				oldStatus = string(issue.Status)
-			}
-			audit.LogFieldChange(id, "status", oldStatus, "closed", actor, reason)
+			after()
 
 			closedCount++
 
-			// Auto-close parent molecule if all steps are now complete.
-			// Runs against the same store the step was closed in.
-			autoCloseCompletedMolecule(ctx, activeStore, id, actor, session)
-
 			// Re-fetch for display
 			closedIssue, _ := activeStore.GetIssue(ctx, id)
 
@@ -300,7 +256,7 @@ func init() {
 	closeCmd.Flags().String("comment", "", "Alias for --reason")
 	_ = closeCmd.Flags().MarkHidden("comment") // Hidden alias for agent/CLI ergonomics
 	closeCmd.Flags().String("reason-file", "", "Read close reason from file (use - for stdin)")
-	closeCmd.Flags().BoolP("force", "f", false, "Force close pinned issues or unsatisfied gates")
+	closeCmd.Flags().BoolP("force", "f", false, "Force close pinned issues, unsatisfied gates or unchecked acceptance criteria")
 	closeCmd.Flags().Bool("continue", false, "Auto-advance to next step in molecule")
 	closeCmd.Flags().Bool("no-auto", false, "With --continue, show next step but don't claim it")
 	closeCmd.Flags().Bool("suggest-next", false, "Show newly unblocked issues after closing")
@@ -424,6 +380,72 @@ func validateCloseReasons(reasons []string) error {
 	return nil
 }
 
+// checkIssueClosable applies the guards of 'bd close' to one issue: pinned
+// and template issues can't be closed, and unless force neither can an
+// epic with open children, a gate whose condition isn't met, an issue with
+// unchecked acceptance criteria, or one with open blockers. q answers the
+// child and blocker queries.
+func checkIssueClosable(ctx context.Context, q storage.DoltStorage, id string, issue *types.Issue, force bool) error {
+	if err := validateIssueClosable(id, issue, force); err != nil {
+		return err
+	}
+	if force {
+		return nil
+	}
+	// Epic close guard: prevent closing epics with open children (mw-local-4so.5.2)
+	if issue != nil && issue.IssueType == types.TypeEpic {
+		if n := countEpicOpenChildren(ctx, q, id); n > 0 {
+			return fmt.Errorf("cannot close epic %s: %d open child issue(s); close children first or use --force to override", id, n)
+		}
+	}
+	// Check gate satisfaction for machine-checkable gates (GH#1467)
+	if err := checkGateSatisfaction(issue); err != nil {
+		return fmt.Errorf("cannot close %s: %w", id, err)
+	}
+	// Acceptance criteria checklists must be fully ticked off
+	if err := checkChecklistComplete(issue); err != nil {
+		return fmt.Errorf("cannot close %s: %w", id, err)
+	}
+	// Check if issue has open blockers (GH#962)
+	blocked, blockers, err := q.IsBlocked(ctx, id)
+	if err != nil {
+		return fmt.Errorf("checking blockers for %s: %w", id, err)
+	}
+	if blocked && len(blockers) > 0 {
+		return fmt.Errorf("cannot close %s: blocked by open issues %v (use --force to override)", id, blockers)
+	}
+	return nil
+}
+
+// closeIssueChecked closes one issue as 'bd close' does, after
+// checkIssueClosable. issue is its state before the close. w takes the
+// write; q answers the guard queries and is w itself, or the store behind
+// w when w is a transaction, in which case the guards see what q has
+// committed.
+//
+// The returned function records the close in the audit log and auto-closes
+// a molecule the close completed; call it once the close is committed.
+func closeIssueChecked(ctx context.Context, w issueWriteStore, q storage.DoltStorage, id string, issue *types.Issue, reason, actorName, session string, force bool) (func(), error) {
+	if err := checkIssueClosable(ctx, q, id, issue, force); err != nil {
+		return nil, err
+	}
+	if err := w.CloseIssue(ctx, id, reason, actorName, session); err != nil {
+		return nil, fmt.Errorf("closing %s: %w", id, err)
+	}
+	return func() {
+		// Audit log the close (survives Dolt GC flatten)
+		oldStatus := "open"
+		if issue != nil {
+			oldStatus = string(issue.Status)
+		}
+		audit.LogFieldChange(id, "status", oldStatus, "closed", actorName, reason)
+
+		// Auto-close parent molecule if all steps are now complete.
+		// Runs against the store the step was closed in.
+		autoCloseCompletedMolecule(ctx, q, id, actorName, session)
+	}, nil
+}
+
 // isMachineCheckableGate returns true if the issue is a gate with a machine-checkable await type.
 func isMachineCheckableGate(issue *types.Issue) bool {
 	if issue == nil || issue.IssueType != "gate" {
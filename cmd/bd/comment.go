@@ -7,6 +7,7 @@ import (
 	"strings"
 
 	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/ui"
 )
 
@@ -16,7 +17,8 @@ var commentCmd = &cobra.Command{
 	Short:   "Add a comment to an issue",
 	Long: `Add a comment to an issue.
 
-Shorthand for 'bd comments add <id> "text"'.
+Shorthand for 'bd comments add <id> "text"'. Use 'bd comment edit' and
+'bd comment delete' to change a comment later.
 
 Examples:
   bd comment bd-123 "Working on this now"
@@ -28,35 +30,7 @@ Examples:
 		CheckReadonly("comment")
 
 		id := args[0]
-		textArgs := args[1:]
-
-		// Determine comment text from args, stdin, or file
-		stdinFlag, _ := cmd.Flags().GetBool("stdin")
-		fileFlag, _ := cmd.Flags().GetString("file")
-
-		var commentText string
-		switch {
-		case stdinFlag:
-			content, err := io.ReadAll(os.Stdin)
-			if err != nil {
-				FatalErrorRespectJSON("reading from stdin: %v", err)
-			}
-			commentText = strings.TrimRight(string(content), "\n")
-		case fileFlag != "":
-			content, err := readBodyFile(fileFlag)
-			if err != nil {
-				FatalErrorRespectJSON("reading file: %v", err)
-			}
-			commentText = content
-		case len(textArgs) > 0:
-			commentText = strings.Join(textArgs, " ")
-		default:
-			FatalErrorRespectJSON("no comment text provided (use positional args, --stdin, or --file)")
-		}
-
-		if strings.TrimSpace(commentText) == "" {
-			FatalErrorRespectJSON("comment text cannot be empty")
-		}
+		commentText := readCommentText(cmd, args[1:])
 
 		author := getActorWithGit()
 
@@ -100,10 +74,111 @@ Examples:
 	},
 }
 
+var commentEditCmd = &cobra.Command{
+	Use:   "edit <comment-id> [text...]",
+	Short: "Edit a comment",
+	Long: `Replace the text of a comment.
+
+The comment ID is the short ID 'bd show' and 'bd comments' print next to each
+comment, or the full ID from --json output. The prior text is kept in the
+issue's event log.
+
+Examples:
+  bd comment edit 1e0fa9b8 "Fixed in the parser, not the lexer"
+  bd comment edit 1e0fa9b8 --file notes.txt`,
+	Args: cobra.MinimumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("comment edit")
+		ctx := rootCtx
+		text := readCommentText(cmd, args[1:])
+
+		comment, err := store.EditComment(ctx, args[0], text, actor)
+		if err != nil {
+			FatalErrorRespectJSON("editing comment %s: %v", args[0], err)
+		}
+		commandDidWrite.Store(true)
+		SetLastTouchedID(comment.IssueID)
+
+		if jsonOutput {
+			outputJSON(comment)
+			return
+		}
+		fmt.Printf("%s Edited comment %s on %s\n", ui.RenderPass("✓"),
+			types.ShortCommentID(comment.ID), ui.RenderID(comment.IssueID))
+	},
+}
+
+var commentDeleteCmd = &cobra.Command{
+	Use:   "delete <comment-id>",
+	Short: "Delete a comment",
+	Long: `Delete a comment.
+
+The comment ID is the short ID 'bd show' and 'bd comments' print next to each
+comment, or the full ID from --json output. The deleted text is kept in the
+issue's event log.
+
+Examples:
+  bd comment delete 1e0fa9b8`,
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("comment delete")
+		comment, err := store.DeleteComment(rootCtx, args[0], actor)
+		if err != nil {
+			FatalErrorRespectJSON("deleting comment %s: %v", args[0], err)
+		}
+		commandDidWrite.Store(true)
+		SetLastTouchedID(comment.IssueID)
+
+		if jsonOutput {
+			outputJSON(comment)
+			return
+		}
+		fmt.Printf("%s Deleted comment %s from %s\n", ui.RenderPass("✓"),
+			types.ShortCommentID(comment.ID), ui.RenderID(comment.IssueID))
+	},
+}
+
+// readCommentText returns the comment text given by --stdin, --file, or
+// the remaining arguments, exiting if there is none.
+func readCommentText(cmd *cobra.Command, textArgs []string) string {
+	stdinFlag, _ := cmd.Flags().GetBool("stdin")
+	fileFlag, _ := cmd.Flags().GetString("file")
+
+	var commentText string
+	switch {
+	case stdinFlag:
+		content, err := io.ReadAll(os.Stdin)
+		if err != nil {
+			FatalErrorRespectJSON("reading from stdin: %v", err)
+		}
+		commentText = strings.TrimRight(string(content), "\n")
+	case fileFlag != "":
+		content, err := readBodyFile(fileFlag)
+		if err != nil {
+			FatalErrorRespectJSON("reading file: %v", err)
+		}
+		commentText = content
+	case len(textArgs) > 0:
+		commentText = strings.Join(textArgs, " ")
+	default:
+		FatalErrorRespectJSON("no comment text provided (use positional args, --stdin, or --file)")
+	}
+
+	if strings.TrimSpace(commentText) == "" {
+		FatalErrorRespectJSON("comment text cannot be empty")
+	}
+	return commentText
+}
+
 func init() {
 	commentCmd.Flags().Bool("stdin", false, "Read comment text from stdin")
 	commentCmd.Flags().String("file", "", "Read comment text from file")
 	commentCmd.MarkFlagsMutuallyExclusive("stdin", "file")
 	commentCmd.ValidArgsFunction = issueIDCompletion
+	commentEditCmd.Flags().Bool("stdin", false, "Read comment text from stdin")
+	commentEditCmd.Flags().String("file", "", "Read comment text from file")
+	commentEditCmd.MarkFlagsMutuallyExclusive("stdin", "file")
+	commentCmd.AddCommand(commentEditCmd)
+	commentCmd.AddCommand(commentDeleteCmd)
 	rootCmd.AddCommand(commentCmd)
 }
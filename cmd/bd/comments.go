@@ -81,7 +81,7 @@ Examples:
 			if localTime {
 				ts = ts.Local()
 			}
-			fmt.Printf("[%s] at %s\n", comment.Author, ts.Format("2006-01-02 15:04"))
+			fmt.Printf("[%s] at %s (%s)\n", comment.Author, ts.Format("2006-01-02 15:04"), types.ShortCommentID(comment.ID))
 			rendered := uimd.RenderMarkdown(comment.Text)
 			// TrimRight removes trailing newlines that Glamour adds, preventing extra blank lines
 			for _, line := range strings.Split(strings.TrimRight(rendered, "\n"), "\n") {
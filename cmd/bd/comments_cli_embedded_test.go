@@ -182,6 +182,46 @@ func TestEmbeddedCommentsCLI(t *testing.T) {
 			}
 		}
 	})
+
+	// ===== edit / delete =====
+
+	t.Run("comment_edit_and_delete_by_short_id", func(t *testing.T) {
+		issue := bdCreate(t, bd, dir, "Edit comments", "--type", "task")
+		bdComments(t, bd, dir, "add", issue.ID, "Frist draft")
+
+		listed := bdComments(t, bd, dir, issue.ID, "--json")
+		var comments []map[string]interface{}
+		if err := json.Unmarshal([]byte(listed[strings.Index(listed, "["):]), &comments); err != nil || len(comments) != 1 {
+			t.Fatalf("parse comments JSON: %v\n%s", err, listed)
+		}
+		shortID := comments[0]["id"].(string)
+		shortID = strings.ReplaceAll(shortID, "-", "")
+		shortID = shortID[len(shortID)-8:]
+
+		if out := bdComments(t, bd, dir, issue.ID); !strings.Contains(out, shortID) {
+			t.Errorf("expected short ID %s in list: %s", shortID, out)
+		}
+
+		cmd := exec.Command(bd, "comment", "edit", shortID, "First draft")
+		cmd.Dir = dir
+		cmd.Env = bdEnv(dir)
+		if out, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("bd comment edit failed: %v\n%s", err, out)
+		}
+		if out := bdComments(t, bd, dir, issue.ID); !strings.Contains(out, "First draft") || strings.Contains(out, "Frist") {
+			t.Errorf("expected edited text in list: %s", out)
+		}
+
+		cmd = exec.Command(bd, "comment", "delete", shortID)
+		cmd.Dir = dir
+		cmd.Env = bdEnv(dir)
+		if out, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("bd comment delete failed: %v\n%s", err, out)
+		}
+		if out := bdComments(t, bd, dir, issue.ID); strings.Contains(out, "First draft") {
+			t.Errorf("expected comment to be gone: %s", out)
+		}
+	})
 }
 
 // TestEmbeddedCommentsCLIConcurrent exercises comments concurrently.
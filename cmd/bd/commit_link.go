@@ -0,0 +1,384 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"regexp"
+	"slices"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// issueCommitsKey is the metadata key holding the commits linked to an
+// issue, so they travel with it through export, import, and sync.
+const issueCommitsKey = "commits"
+
+// issueCommit is a git commit linked to an issue.
+type issueCommit struct {
+	SHA      string    `json:"sha"`
+	Subject  string    `json:"subject,omitempty"`
+	Trailer  string    `json:"trailer,omitempty"` // "Fixes" or "Refs" when linked from a commit message
+	LinkedAt time.Time `json:"linked_at"`
+}
+
+// issueCommits returns the commits linked to issue, oldest link first.
+func issueCommits(issue *types.Issue) []issueCommit {
+	if issue == nil || len(issue.Metadata) == 0 {
+		return nil
+	}
+	var data struct {
+		Commits []issueCommit `json:"commits"`
+	}
+	if json.Unmarshal(issue.Metadata, &data) != nil {
+		return nil
+	}
+	return data.Commits
+}
+
+// withIssueCommit returns metadata with c added to its commits, or false
+// if the commit is already linked.
+func withIssueCommit(metadata json.RawMessage, c issueCommit) (json.RawMessage, bool, error) {
+	data := make(map[string]json.RawMessage)
+	if trimmed := strings.TrimSpace(string(metadata)); trimmed != "" && trimmed != "null" {
+		if err := json.Unmarshal(metadata, &data); err != nil {
+			return nil, false, fmt.Errorf("existing metadata is not a JSON object: %w", err)
+		}
+	}
+	var commits []issueCommit
+	if raw, ok := data[issueCommitsKey]; ok {
+		if err := json.Unmarshal(raw, &commits); err != nil {
+			return nil, false, fmt.Errorf("metadata %q is not a list of commits: %w", issueCommitsKey, err)
+		}
+	}
+	if slices.ContainsFunc(commits, func(e issueCommit) bool { return e.SHA == c.SHA }) {
+		return metadata, false, nil
+	}
+	raw, err := json.Marshal(append(commits, c))
+	if err != nil {
+		return nil, false, err
+	}
+	data[issueCommitsKey] = raw
+	result, err := json.Marshal(data)
+	if err != nil {
+		return nil, false, fmt.Errorf("failed to marshal metadata: %w", err)
+	}
+	return result, true, nil
+}
+
+// linkIssueCommit links c to the issue id in st. It reports false if the
+// commit was already linked.
+func linkIssueCommit(ctx context.Context, st storage.DoltStorage, id string, c issueCommit) (bool, error) {
+	issue, err := st.GetIssue(ctx, id)
+	if err != nil {
+		return false, err
+	}
+	metadata, added, err := withIssueCommit(issue.Metadata, c)
+	if err != nil || !added {
+		return false, err
+	}
+	if err := st.UpdateIssue(ctx, id, map[string]interface{}{"metadata": metadata}, actor); err != nil {
+		return false, err
+	}
+	return true, nil
+}
+
+var commitSHAPattern = regexp.MustCompile(`^[0-9a-fA-F]{7,40}$`)
+
+// looksLikeCommitSHA reports whether s could be an abbreviated or full
+// commit hash.
+func looksLikeCommitSHA(s string) bool {
+	return commitSHAPattern.MatchString(s)
+}
+
+// resolveGitCommit returns the full hash and subject of the commit ref
+// names in the current repository.
+func resolveGitCommit(ref string) (sha, subject string, err error) {
+	if strings.HasPrefix(ref, "-") {
+		return "", "", fmt.Errorf("invalid commit %q", ref)
+	}
+	out, err := exec.Command("git", "log", "-1", "--format=%H%n%s", ref+"^{commit}", "--").Output() // #nosec G204 -- ref can't be an option
+	if err != nil {
+		return "", "", fmt.Errorf("%s is not a commit in this repository", ref)
+	}
+	sha, subject, _ = strings.Cut(strings.TrimSpace(string(out)), "\n")
+	return sha, subject, nil
+}
+
+// linkCommitTarget reports whether the second argument of bd link names a
+// commit rather than an issue: it looks like a hash, is no issue, and is a
+// commit of the current repository.
+func linkCommitTarget(ctx context.Context, arg string) (sha, subject string, ok bool) {
+	if !looksLikeCommitSHA(arg) {
+		return "", "", false
+	}
+	if _, _, cleanup, err := resolveIDWithRouting(ctx, store, arg); err == nil {
+		cleanup()
+		return "", "", false
+	}
+	sha, subject, err := resolveGitCommit(arg)
+	if err != nil {
+		return "", "", false
+	}
+	return sha, subject, true
+}
+
+// runLinkCommit is 'bd link <id> <sha>': it links the commit to the
+// issue.
+func runLinkCommit(ctx context.Context, st storage.DoltStorage, id, sha, subject, trailer string) {
+	c := issueCommit{SHA: sha, Subject: subject, Trailer: trailer, LinkedAt: time.Now().UTC()}
+	added, err := linkIssueCommit(ctx, st, id, c)
+	if err != nil {
+		FatalErrorRespectJSON("%v", err)
+	}
+	if added && !usesSQLServer() {
+		if err := st.Commit(ctx, formatDoltAutoCommitMessage("link", actor, []string{id})); err != nil && !isDoltNothingToCommit(err) {
+			FatalErrorRespectJSON("failed to commit: %v", err)
+		}
+	}
+	SetLastTouchedID(id)
+
+	status := "linked"
+	if !added {
+		status = "already linked"
+	}
+	if jsonOutput {
+		outputJSON(map[string]interface{}{
+			"status":   status,
+			"issue_id": id,
+			"commit":   sha,
+			"subject":  subject,
+		})
+		return
+	}
+	if !added {
+		fmt.Printf("%s %s is already linked to %s\n", ui.RenderMuted("·"), shortSHA(sha), id)
+		return
+	}
+	fmt.Printf("%s Linked commit %s (%s) to %s\n", ui.RenderPass("✓"), shortSHA(sha), subject, formatFeedbackIDParen(id, lookupTitle(id)))
+}
+
+func shortSHA(sha string) string {
+	if len(sha) > 12 {
+		return sha[:12]
+	}
+	return sha
+}
+
+// formatIssueCommits renders the COMMITS section of bd show, or "" if no
+// commit is linked.
+func formatIssueCommits(issue *types.Issue) string {
+	commits := issueCommits(issue)
+	if len(commits) == 0 {
+		return ""
+	}
+	lines := []string{ui.RenderBold("COMMITS")}
+	for _, c := range commits {
+		line := "  " + shortSHA(c.SHA)
+		if c.Trailer != "" {
+			line += " " + ui.RenderMuted("("+c.Trailer+")")
+		}
+		if c.Subject != "" {
+			line += " " + c.Subject
+		}
+		lines = append(lines, line)
+	}
+	return strings.Join(lines, "\n")
+}
+
+// issueTrailerPattern matches a commit message line naming issues, such as
+// "Fixes: bd-12" or "Refs: bd-34, bd-35".
+var issueTrailerPattern = regexp.MustCompile(`(?im)^[ \t]*(fixes|fixed|closes|closed|resolves|resolved|refs|ref|references|see)[ \t]*:[ \t]*(.+)$`)
+
+var trailerIssueIDPattern = regexp.MustCompile(`^[A-Za-z0-9_]+-[A-Za-z0-9._-]+$`)
+
+// parseIssueTrailers returns the issues a commit message says it fixes
+// (Fixes:, Closes:, Resolves:) and refers to (Refs:, References:, See:).
+// An issue in both is only in fixes.
+func parseIssueTrailers(msg string) (fixes, refs []string) {
+	for _, m := range issueTrailerPattern.FindAllStringSubmatch(msg, -1) {
+		kind := strings.ToLower(m[1])
+		for _, tok := range strings.FieldsFunc(m[2], func(r rune) bool { return r == ',' || r == ' ' || r == '\t' }) {
+			id := strings.TrimRight(strings.TrimPrefix(tok, "#"), ".;")
+			if !trailerIssueIDPattern.MatchString(id) {
+				continue
+			}
+			switch kind {
+			case "refs", "ref", "references", "see":
+				if !slices.Contains(refs, id) {
+					refs = append(refs, id)
+				}
+			default:
+				if !slices.Contains(fixes, id) {
+					fixes = append(fixes, id)
+				}
+			}
+		}
+	}
+	refs = slices.DeleteFunc(refs, func(id string) bool { return slices.Contains(fixes, id) })
+	return fixes, refs
+}
+
+// linkCommitTrailers links HEAD to the issues its message names in
+// trailers, and with git.close-on-fixes closes those it fixes. Called from
+// the post-commit hook. Like the other hooks, it shells out to bd, which
+// opens its own store, and only warns on failure.
+func linkCommitTrailers() {
+	if isRebaseInProgress() {
+		// Rebased commits were linked when first made.
+		return
+	}
+	out, err := exec.Command("git", "log", "-1", "--format=%H%n%B", "HEAD").Output()
+	if err != nil {
+		debug.Logf("post-commit: failed to read HEAD: %v\n", err)
+		return
+	}
+	sha, msg, _ := strings.Cut(string(out), "\n")
+	fixes, refs := parseIssueTrailers(msg)
+	if len(fixes) == 0 && len(refs) == 0 {
+		return
+	}
+
+	bd := func(args ...string) {
+		cmd := exec.Command("bd", args...) // #nosec G204 -- fixed subcommands; IDs and hash from the commit
+		cmd.Env = filterEnv(os.Environ(), "BD_GIT_HOOK")
+		if out, err := cmd.CombinedOutput(); err != nil {
+			fmt.Fprintf(os.Stderr, "beads: post-commit warning: bd %s: %v\n%s", strings.Join(args, " "), err, out)
+		}
+	}
+	for _, id := range fixes {
+		bd("link", id, sha, "--trailer", "Fixes", "--quiet")
+		if config.GetBool("git.close-on-fixes") {
+			bd("close", id, "--reason", "Fixed in commit "+shortSHA(sha), "--quiet")
+		}
+	}
+	for _, id := range refs {
+		bd("link", id, sha, "--trailer", "Refs", "--quiet")
+	}
+}
+
+// issueHistoryCommit is one commit in an issue's history, for
+// 'bd show --commits': a commit linked to the issue, one whose message
+// mentions it, or both.
+type issueHistoryCommit struct {
+	SHA       string `json:"sha"`
+	Author    string `json:"author,omitempty"`
+	Date      string `json:"date,omitempty"` // author date, RFC 3339
+	Subject   string `json:"subject"`
+	Linked    bool   `json:"linked"`
+	Trailer   string `json:"trailer,omitempty"`
+	Mentioned bool   `json:"mentioned"`
+}
+
+// gitHistoryFormat separates a commit's fields with US and commits with RS,
+// since the message body spans lines.
+const gitHistoryFormat = "--format=%H%x1f%an%x1f%aI%x1f%s%x1f%B%x1e"
+
+// parseGitHistory parses git log output in gitHistoryFormat, returning each
+// commit with its full message.
+func parseGitHistory(out string) (commits []issueHistoryCommit, messages []string) {
+	for _, rec := range strings.Split(out, "\x1e") {
+		fields := strings.SplitN(strings.TrimLeft(rec, "\n"), "\x1f", 5)
+		if len(fields) < 5 || fields[0] == "" {
+			continue
+		}
+		commits = append(commits, issueHistoryCommit{SHA: fields[0], Author: fields[1], Date: fields[2], Subject: fields[3]})
+		messages = append(messages, fields[4])
+	}
+	return commits, messages
+}
+
+// mentionsIssue reports whether msg names id as a whole word, so bd-1
+// does not match bd-12 or the child bd-1.2.
+func mentionsIssue(msg, id string) bool {
+	pattern := `(?i)(^|[^A-Za-z0-9_.-])` + regexp.QuoteMeta(id) + `($|[^A-Za-z0-9_.-]|\.($|[^0-9]))`
+	return regexp.MustCompile(pattern).MatchString(msg)
+}
+
+// issueCommitHistory returns the commits relevant to issue, newest first:
+// those linked to it and those on the current branch whose message
+// mentions its ID (found with git log --grep). When git cannot be asked,
+// it returns the linked commits as recorded, with the error.
+func issueCommitHistory(issue *types.Issue) ([]issueHistoryCommit, error) {
+	linked := issueCommits(issue)
+	out, err := exec.Command("git", "log", "-F", "-i", "--grep="+issue.ID, gitHistoryFormat).Output() // #nosec G204 -- fixed git subcommand; the ID is a --grep value
+	if err != nil {
+		history := make([]issueHistoryCommit, 0, len(linked))
+		for _, c := range linked {
+			history = append(history, issueHistoryCommit{SHA: c.SHA, Subject: c.Subject, Linked: true, Trailer: c.Trailer})
+		}
+		return history, fmt.Errorf("git log: %w", err)
+	}
+
+	var history []issueHistoryCommit
+	index := make(map[string]int)
+	commits, messages := parseGitHistory(string(out))
+	for i, c := range commits {
+		if !mentionsIssue(messages[i], issue.ID) {
+			continue
+		}
+		c.Mentioned = true
+		index[c.SHA] = len(history)
+		history = append(history, c)
+	}
+	for _, l := range linked {
+		if i, ok := index[l.SHA]; ok {
+			history[i].Linked, history[i].Trailer = true, l.Trailer
+			continue
+		}
+		c := issueHistoryCommit{SHA: l.SHA, Subject: l.Subject, Linked: true, Trailer: l.Trailer}
+		// A linked commit off the current branch: look it up directly.
+		if out, err := exec.Command("git", "log", "-1", gitHistoryFormat, l.SHA, "--").Output(); err == nil { // #nosec G204 -- SHA was validated when linked
+			if found, _ := parseGitHistory(string(out)); len(found) == 1 {
+				found[0].Linked, found[0].Trailer = true, l.Trailer
+				c = found[0]
+			}
+		}
+		index[c.SHA] = len(history)
+		history = append(history, c)
+	}
+	sort.SliceStable(history, func(i, j int) bool { return history[i].authored().After(history[j].authored()) })
+	return history, nil
+}
+
+// authored is the commit's author date, zero when unknown.
+func (c issueHistoryCommit) authored() time.Time {
+	t, _ := time.Parse(time.RFC3339, c.Date)
+	return t
+}
+
+// formatIssueCommitHistory renders the COMMIT HISTORY section of
+// 'bd show --commits'.
+func formatIssueCommitHistory(history []issueHistoryCommit) string {
+	lines := []string{ui.RenderBold("COMMIT HISTORY")}
+	if len(history) == 0 {
+		lines = append(lines, "  "+ui.RenderMuted("(no linked or mentioning commits)"))
+	}
+	for _, c := range history {
+		line := "  " + shortSHA(c.SHA)
+		if len(c.Date) >= len("2006-01-02") {
+			line += " " + ui.RenderMuted(c.Date[:len("2006-01-02")])
+		}
+		if c.Author != "" {
+			line += " " + c.Author
+		}
+		line += "  " + c.Subject
+		switch {
+		case c.Trailer != "":
+			line += " " + ui.RenderMuted("("+c.Trailer+")")
+		case c.Linked:
+			line += " " + ui.RenderMuted("(linked)")
+		}
+		lines = append(lines, line)
+	}
+	return strings.Join(lines, "\n")
+}
@@ -0,0 +1,76 @@
+package main
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseIssueTrailers(t *testing.T) {
+	msg := `Fix the flaky export test
+
+The fixes: line in this sentence is not a trailer.
+
+Fixes: bd-12
+refs: bd-34, bd-35
+Closes: #bd-7.
+See: bd-12 and nothing-else
+Signed-off-by: Someone <someone@example.com>
+`
+	fixes, refs := parseIssueTrailers(msg)
+	if got := strings.Join(fixes, ","); got != "bd-12,bd-7" {
+		t.Errorf("fixes = %q, want bd-12,bd-7", got)
+	}
+	if got := strings.Join(refs, ","); got != "bd-34,bd-35,nothing-else" {
+		t.Errorf("refs = %q, want bd-34,bd-35,nothing-else", got)
+	}
+
+	if fixes, refs := parseIssueTrailers("Just a subject\n"); fixes != nil || refs != nil {
+		t.Errorf("parseIssueTrailers(no trailers) = %v, %v", fixes, refs)
+	}
+}
+
+func TestWithIssueCommit(t *testing.T) {
+	c := issueCommit{SHA: "4f2a9c1e", Subject: "Fix it", Trailer: "Fixes"}
+
+	metadata, added, err := withIssueCommit(json.RawMessage(`{"team":"core"}`), c)
+	if err != nil || !added {
+		t.Fatalf("withIssueCommit = %s, %v, %v", metadata, added, err)
+	}
+	issue := &types.Issue{Metadata: metadata}
+	if commits := issueCommits(issue); len(commits) != 1 || commits[0].SHA != c.SHA {
+		t.Errorf("issueCommits = %+v", commits)
+	}
+	if shown := formatIssueCustomMetadata(issue); !strings.Contains(shown, "team") || strings.Contains(shown, "commits") {
+		t.Errorf("METADATA section = %q, want team without commits", shown)
+	}
+
+	if _, added, err := withIssueCommit(metadata, c); err != nil || added {
+		t.Errorf("linking the same commit again: added = %v, err = %v", added, err)
+	}
+	if _, _, err := withIssueCommit(json.RawMessage(`{"commits":"nope"}`), c); err == nil {
+		t.Error("expected an error for a non-list commits key")
+	}
+}
+
+func TestParseGitHistoryAndMentions(t *testing.T) {
+	out := "aaa\x1fAda\x1f2026-03-01T10:00:00+02:00\x1fFix export (bd-1)\x1fFix export (bd-1)\n\nFixes: bd-1\n\x1e\n" +
+		"bbb\x1fLin\x1f2026-02-01T10:00:00Z\x1fTouch bd-12\x1fTouch bd-12\n\x1e\n"
+	commits, messages := parseGitHistory(out)
+	if len(commits) != 2 || commits[0].SHA != "aaa" || commits[0].Author != "Ada" || commits[1].Subject != "Touch bd-12" {
+		t.Fatalf("parseGitHistory = %+v", commits)
+	}
+	if !mentionsIssue(messages[0], "bd-1") {
+		t.Error("mentionsIssue missed bd-1")
+	}
+	for _, msg := range []string{messages[1], "Split bd-1.2 out", "see xbd-1"} {
+		if mentionsIssue(msg, "bd-1") {
+			t.Errorf("mentionsIssue(%q, bd-1) = true", msg)
+		}
+	}
+	if !mentionsIssue("Done with bd-1.", "bd-1") {
+		t.Error("mentionsIssue missed bd-1 at the end of a sentence")
+	}
+}
@@ -14,8 +14,10 @@ import (
 	"github.com/steveyegge/beads/internal/beads"
 	"github.com/steveyegge/beads/internal/config"
 	"github.com/steveyegge/beads/internal/git"
+	"github.com/steveyegge/beads/internal/idgen"
 	"github.com/steveyegge/beads/internal/remotecache"
 	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/validation"
 )
 
 var configCmd = &cobra.Command{
@@ -98,7 +100,7 @@ var configSetCmd = &cobra.Command{
 	Short: "Set a configuration value",
 	Args:  cobra.ExactArgs(2),
 	Run: func(_ *cobra.Command, args []string) {
-		key := args[0]
+		key := canonicalConfigKey(args[0])
 		value := args[1]
 
 		// Reject keys that look like init-only state so the user does not
@@ -140,6 +142,13 @@ var configSetCmd = &cobra.Command{
 			}
 		}
 
+		// Reject malformed validation rules (strictness, title length,
+		// priorities, required fields) before they reach config.yaml.
+		if err := validation.ValidateRuleConfig(key, value); err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			os.Exit(1)
+		}
+
 		// Check if this is a yaml-only key (startup settings like no-db, etc.)
 		// These must be written to config.yaml, not SQLite, because they're read
 		// before the database is opened. (GH#536)
@@ -202,6 +211,18 @@ var configSetCmd = &cobra.Command{
 				os.Exit(1)
 			}
 		}
+		if key == "status.guards" {
+			if _, err := validation.ParseGuards(value); err != nil {
+				fmt.Fprintf(os.Stderr, "Error: invalid status.guards value: %v\n", err)
+				os.Exit(1)
+			}
+		}
+		if key == "issue_id_mode" {
+			if _, err := idgen.ParseScheme(value); err != nil || value == "" {
+				fmt.Fprintf(os.Stderr, "Error: invalid issue_id_mode %q (valid values: hash, counter, ulid, actor)\n", value)
+				os.Exit(1)
+			}
+		}
 
 		if err := store.SetConfig(ctx, key, value); err != nil {
 			fmt.Fprintf(os.Stderr, "Error setting config: %v\n", err)
@@ -226,7 +247,7 @@ var configGetCmd = &cobra.Command{
 	Short: "Get a configuration value",
 	Args:  cobra.ExactArgs(1),
 	Run: func(cmd *cobra.Command, args []string) {
-		key := args[0]
+		key := canonicalConfigKey(args[0])
 
 		// Check if this is a yaml-only key (startup settings)
 		// These are read from config.yaml via viper, not SQLite. (GH#536)
@@ -321,6 +342,12 @@ var configListCmd = &cobra.Command{
 			fmt.Fprintf(os.Stderr, "Error listing config: %v\n", err)
 			os.Exit(1)
 		}
+		// Deletion records live in the config table but are not settings.
+		for k := range config {
+			if strings.HasPrefix(k, tombstoneConfigPrefix) {
+				delete(config, k)
+			}
+		}
 
 		if jsonOutput {
 			outputJSON(config)
@@ -427,7 +454,7 @@ var configUnsetCmd = &cobra.Command{
 	Short: "Delete a configuration value",
 	Args:  cobra.ExactArgs(1),
 	Run: func(cmd *cobra.Command, args []string) {
-		key := args[0]
+		key := canonicalConfigKey(args[0])
 
 		// Check if this is a yaml-only key (startup settings like backup.*, routing.*, etc.)
 		// These must be removed from config.yaml, not the database. (GH#2727)
@@ -791,7 +818,22 @@ var recognizedConfigKeys = map[string]bool{
 	"identity": true, "no-push": true, "no-git-ops": true,
 	"create.require-description": true, "beads.role": true,
 	"auto_compact_enabled": true, "schema_version": true,
-	"output.title-length": true,
+	"output.title-length": true, "issue_id_mode": true,
+}
+
+// configKeyAliases maps alternative names for database config keys to the
+// key bd reads.
+var configKeyAliases = map[string]string{
+	"id_scheme": "issue_id_mode",
+}
+
+// canonicalConfigKey resolves a config key alias, returning other keys
+// unchanged.
+func canonicalConfigKey(key string) string {
+	if canonical, ok := configKeyAliases[key]; ok {
+		return canonical
+	}
+	return key
 }
 
 func isRecognizedConfigKey(key string) bool {
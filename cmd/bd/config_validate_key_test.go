@@ -10,7 +10,7 @@ func TestIsRecognizedConfigKey(t *testing.T) {
 		"export.auto", "dolt.auto-push", "jira.url", "custom.anything",
 		"doctor.suppress.git-hooks", "no-git-ops", "beads.role",
 		"status.custom", "ai.model", "backup.enabled", "import.path",
-		"dolt.local-only",
+		"dolt.local-only", "issue_id_mode",
 	}
 	for _, key := range recognized {
 		if !isRecognizedConfigKey(key) {
@@ -28,6 +28,15 @@ func TestIsRecognizedConfigKey(t *testing.T) {
 	}
 }
 
+func TestCanonicalConfigKey(t *testing.T) {
+	if got := canonicalConfigKey("id_scheme"); got != "issue_id_mode" {
+		t.Errorf("canonicalConfigKey(id_scheme) = %q, want issue_id_mode", got)
+	}
+	if got := canonicalConfigKey("export.auto"); got != "export.auto" {
+		t.Errorf("canonicalConfigKey(export.auto) = %q, want it unchanged", got)
+	}
+}
+
 func TestConfigHelpMentionsDoltLocalOnly(t *testing.T) {
 	if !strings.Contains(configCmd.Long, "bd config set dolt.local-only true") {
 		t.Fatalf("config help missing dolt.local-only example:\n%s", configCmd.Long)
@@ -43,6 +43,8 @@ Examples:
 		titleContains, _ := cmd.Flags().GetString("title-contains")
 		descContains, _ := cmd.Flags().GetString("desc-contains")
 		notesContains, _ := cmd.Flags().GetString("notes-contains")
+		titleRegex, _ := cmd.Flags().GetString("title-regex")
+		descRegex, _ := cmd.Flags().GetString("description-regex")
 
 		// Date range flags
 		createdAfter, _ := cmd.Flags().GetString("created-after")
@@ -139,50 +141,53 @@ Examples:
 		filter.TitleContains = titleContains
 		filter.DescriptionContains = descContains
 		filter.NotesContains = notesContains
+		filter.TitleRegex = titleRegex
+		filter.DescriptionRegex = descRegex
 
 		// Date ranges
 		if createdAfter != "" {
-			t, err := parseTimeFlag(createdAfter)
+			t, err := parseDateFilterFlag(createdAfter)
 			if err != nil {
 				FatalError("parsing --created-after: %v", err)
 			}
 			filter.CreatedAfter = &t
 		}
 		if createdBefore != "" {
-			t, err := parseTimeFlag(createdBefore)
+			t, err := parseDateFilterFlag(createdBefore)
 			if err != nil {
 				FatalError("parsing --created-before: %v", err)
 			}
 			filter.CreatedBefore = &t
 		}
 		if updatedAfter != "" {
-			t, err := parseTimeFlag(updatedAfter)
+			t, err := parseDateFilterFlag(updatedAfter)
 			if err != nil {
 				FatalError("parsing --updated-after: %v", err)
 			}
 			filter.UpdatedAfter = &t
 		}
 		if updatedBefore != "" {
-			t, err := parseTimeFlag(updatedBefore)
+			t, err := parseDateFilterFlag(updatedBefore)
 			if err != nil {
 				FatalError("parsing --updated-before: %v", err)
 			}
 			filter.UpdatedBefore = &t
 		}
 		if closedAfter != "" {
-			t, err := parseTimeFlag(closedAfter)
+			t, err := parseDateFilterFlag(closedAfter)
 			if err != nil {
 				FatalError("parsing --closed-after: %v", err)
 			}
 			filter.ClosedAfter = &t
 		}
 		if closedBefore != "" {
-			t, err := parseTimeFlag(closedBefore)
+			t, err := parseDateFilterFlag(closedBefore)
 			if err != nil {
 				FatalError("parsing --closed-before: %v", err)
 			}
 			filter.ClosedBefore = &t
 		}
+		applyUpdatedWindowFlags(cmd, &filter)
 
 		// Empty/null checks
 		filter.EmptyDescription = emptyDesc
@@ -267,7 +272,7 @@ func init() {
 	countCmd.Flags().IntP("priority", "p", 0, "Filter by priority (0-4: 0=critical, 1=high, 2=medium, 3=low, 4=backlog)")
 	countCmd.Flags().StringP("assignee", "a", "", "Filter by assignee")
 	countCmd.Flags().StringP("type", "t", "", "Filter by type (bug, feature, task, epic, chore, decision, merge-request, molecule, gate)")
-	countCmd.Flags().StringSliceP("label", "l", []string{}, "Filter by labels (AND: must have ALL)")
+	countCmd.Flags().StringSliceP("label", "l", []string{}, "Filter by labels (AND: must have ALL; 'area/*' matches a namespace)")
 	countCmd.Flags().StringSlice("label-any", []string{}, "Filter by labels (OR: must have AT LEAST ONE)")
 	countCmd.Flags().String("title", "", "Filter by title text (case-insensitive substring match)")
 	countCmd.Flags().String("id", "", "Filter by specific issue IDs (comma-separated)")
@@ -276,14 +281,17 @@ func init() {
 	countCmd.Flags().String("title-contains", "", "Filter by title substring")
 	countCmd.Flags().String("desc-contains", "", "Filter by description substring")
 	countCmd.Flags().String("notes-contains", "", "Filter by notes substring")
+	countCmd.Flags().String("title-regex", "", "Filter by title regular expression (case-insensitive)")
+	countCmd.Flags().String("description-regex", "", "Filter by description regular expression (case-insensitive)")
 
 	// Date ranges
-	countCmd.Flags().String("created-after", "", "Filter issues created after date (YYYY-MM-DD or RFC3339)")
-	countCmd.Flags().String("created-before", "", "Filter issues created before date (YYYY-MM-DD or RFC3339)")
-	countCmd.Flags().String("updated-after", "", "Filter issues updated after date (YYYY-MM-DD or RFC3339)")
-	countCmd.Flags().String("updated-before", "", "Filter issues updated before date (YYYY-MM-DD or RFC3339)")
-	countCmd.Flags().String("closed-after", "", "Filter issues closed after date (YYYY-MM-DD or RFC3339)")
-	countCmd.Flags().String("closed-before", "", "Filter issues closed before date (YYYY-MM-DD or RFC3339)")
+	countCmd.Flags().String("created-after", "", "Filter issues created after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	countCmd.Flags().String("created-before", "", "Filter issues created before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	countCmd.Flags().String("updated-after", "", "Filter issues updated after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	countCmd.Flags().String("updated-before", "", "Filter issues updated before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	countCmd.Flags().String("closed-after", "", "Filter issues closed after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	countCmd.Flags().String("closed-before", "", "Filter issues closed before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	registerUpdatedWindowFlags(countCmd)
 
 	// Empty/null checks
 	countCmd.Flags().Bool("empty-description", false, "Filter issues with empty description")
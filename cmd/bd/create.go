@@ -22,6 +22,7 @@ import (
 	"github.com/steveyegge/beads/internal/timeparsing"
 	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
 	"github.com/steveyegge/beads/internal/validation"
 )
 
@@ -155,6 +156,11 @@ var createCmd = &cobra.Command{
 		}
 
 		issueType, _ := cmd.Flags().GetString("type")
+		severityStr, _ := cmd.Flags().GetString("severity")
+		severity, err := parseSeverityFlag(severityStr, issueType)
+		if err != nil {
+			FatalError("%v", err)
+		}
 		assignee, _ := cmd.Flags().GetString("assignee")
 
 		labels, _ := cmd.Flags().GetStringSlice("labels")
@@ -256,24 +262,24 @@ var createCmd = &cobra.Command{
 			}
 			metadata = json.RawMessage(metadataJSON)
 		}
+		// Custom fields live in their own metadata object (--field points=3)
+		if fieldFlags, _ := cmd.Flags().GetStringArray("field"); len(fieldFlags) > 0 {
+			merged, err := applyCustomFields(metadata, fieldFlags)
+			if err != nil {
+				FatalError("%v", err)
+			}
+			metadata = merged
+		}
 
 		// Validate template based on --validate flag or config
 		// Uses LintIssue for field-aware validation: checks --acceptance field too (GH#2468 parity)
 		validateTemplate, _ := cmd.Flags().GetBool("validate")
-		validationMode := config.GetString("validation.on-create")
-		if validateTemplate || validationMode == "error" || validationMode == "warn" {
-			lintIssue := &types.Issue{
-				IssueType:          types.IssueType(issueType).Normalize(),
-				Description:        description,
-				AcceptanceCriteria: acceptance,
-			}
-			if err := validation.LintIssue(lintIssue); err != nil {
-				if validateTemplate || validationMode == "error" {
-					FatalError("%v", err)
-				}
-				// warn mode: print warning but proceed
-				fmt.Fprintf(os.Stderr, "%s %v\n", ui.RenderWarn("⚠"), err)
-			}
+		if err := lintNewIssue(&types.Issue{
+			IssueType:          types.IssueType(issueType).Normalize(),
+			Description:        description,
+			AcceptanceCriteria: acceptance,
+		}, validateTemplate); err != nil {
+			FatalError("%v", err)
 		}
 
 		dryRun, _ := cmd.Flags().GetBool("dry-run")
@@ -328,6 +334,23 @@ var createCmd = &cobra.Command{
 			repoPath = routing.DetermineTargetRepo(routingConfig, userRole, ".")
 		}
 
+		// --from links the new issue to the one being worked on when it was
+		// found, and carries over that issue's labels.
+		if fromID, _ := cmd.Flags().GetString("from"); fromID != "" {
+			resolvedFrom, err := utils.ResolvePartialID(rootCtx, store, fromID)
+			if err != nil {
+				FatalError("resolving --from %s: %v", fromID, err)
+			}
+			deps = append(deps, string(types.DepDiscoveredFrom)+":"+resolvedFrom)
+			if noInheritLabels, _ := cmd.Flags().GetBool("no-inherit-labels"); !noInheritLabels {
+				fromLabels, err := store.GetLabels(rootCtx, resolvedFrom)
+				if err != nil {
+					FatalError("failed to get labels of %s: %v", resolvedFrom, err)
+				}
+				labels = mergeCreateLabels(labels, fromLabels)
+			}
+		}
+
 		renderDryRun := func() {
 			previewIssue := buildCreateIssue(createIssueParams{
 				ID:                 explicitID,
@@ -339,6 +362,7 @@ var createCmd = &cobra.Command{
 				SpecID:             specID,
 				Priority:           priority,
 				IssueType:          types.IssueType(issueType).Normalize(),
+				Severity:           severity,
 				Assignee:           assignee,
 				ExternalRef:        externalRef,
 				EstimatedMinutes:   estimatedMinutes,
@@ -465,6 +489,18 @@ var createCmd = &cobra.Command{
 			return
 		}
 
+		if checkDuplicates, _ := cmd.Flags().GetBool("check-duplicates"); checkDuplicates {
+			threshold, _ := cmd.Flags().GetFloat64("duplicate-threshold")
+			candidates, err := findTitleDuplicates(rootCtx, store, title, threshold)
+			if err != nil {
+				FatalError("checking for duplicates: %v", err)
+			}
+			if len(candidates) > 0 && !createDespiteDuplicates(candidates) {
+				fmt.Fprintln(os.Stderr, "Aborted.")
+				return
+			}
+		}
+
 		createCtx := rootCtx
 		if parentID != "" {
 			childID, err := store.GetNextChildID(rootCtx, parentID)
@@ -477,33 +513,19 @@ var createCmd = &cobra.Command{
 
 		// Validate explicit ID format if provided
 		if explicitID != "" {
-			// Basic format validation for all issue types.
-			// Note: Orchestrator-specific agent ID validation (mayor, polecat, witness, etc.)
-			// is handled by the orchestrator, not beads core.
-			_, err := validation.ValidateIDFormat(explicitID)
-			if err != nil {
+			if err := checkNewIssueID(createCtx, store, explicitID, forceCreate); err != nil {
 				FatalError("%v", err)
 			}
+		}
 
-			// Validate prefix matches database prefix
-			ctx := createCtx
-
-			// Get database prefix and allowed prefixes from config.
-			// YAML config takes precedence over DB — in shared-server mode the DB
-			// may belong to a different project (GH#2469).
-			var dbPrefix, allowedPrefixes string
-			if yamlPrefix := config.GetString("issue-prefix"); yamlPrefix != "" {
-				dbPrefix = yamlPrefix
-			} else {
-				dbPrefix, _ = store.GetConfig(ctx, "issue_prefix") // Best effort: empty prefix is a valid fallback
+		depSpecs := make([]createDepSpec, 0, len(deps))
+		for _, spec := range deps {
+			parsed, ok, err := parseCreateDepSpec(spec)
+			if err != nil {
+				FatalErrorRespectJSON("%v", err)
 			}
-			allowedPrefixes, _ = store.GetConfig(ctx, "allowed_prefixes") // Best effort: empty means no prefix restriction
-
-			// Use ValidateIDPrefixAllowed which handles multi-hyphen prefixes correctly (GH#1135)
-			// This checks if the ID starts with an allowed prefix, rather than extracting
-			// the prefix first (which can fail for IDs like "hq-cv-test" where "test" looks like a word)
-			if err := validation.ValidateIDPrefixAllowed(explicitID, dbPrefix, allowedPrefixes, forceCreate); err != nil {
-				FatalError("%v", err)
+			if ok {
+				depSpecs = append(depSpecs, parsed)
 			}
 		}
 
@@ -517,6 +539,7 @@ var createCmd = &cobra.Command{
 			SpecID:             specID,
 			Priority:           priority,
 			IssueType:          types.IssueType(issueType).Normalize(),
+			Severity:           severity,
 			Assignee:           assignee,
 			ExternalRef:        externalRef,
 			EstimatedMinutes:   estimatedMinutes,
@@ -535,43 +558,12 @@ var createCmd = &cobra.Command{
 			DeferUntil:         deferUntil,
 			Metadata:           metadata,
 		})
+		enforceValidationRules(issue)
 
 		ctx := createCtx
 
-		// Check if any dependencies are discovered-from type
-		// If so, inherit source_repo from the parent issue
-		var discoveredFromParentID string
-		for _, depSpec := range deps {
-			depSpec = strings.TrimSpace(depSpec)
-			if depSpec == "" {
-				continue
-			}
-
-			var depType types.DependencyType
-			var dependsOnID string
-
-			if strings.Contains(depSpec, ":") {
-				parts := strings.SplitN(depSpec, ":", 2)
-				if len(parts) == 2 {
-					depType = types.DependencyType(strings.TrimSpace(parts[0]))
-					dependsOnID = strings.TrimSpace(parts[1])
-
-					if depType == types.DepDiscoveredFrom && dependsOnID != "" {
-						discoveredFromParentID = dependsOnID
-						break
-					}
-				}
-			}
-		}
-
-		// If we found a discovered-from dependency, inherit source_repo from parent
-		if discoveredFromParentID != "" {
-			parentIssue, err := store.GetIssue(ctx, discoveredFromParentID)
-			if err == nil && parentIssue.SourceRepo != "" {
-				issue.SourceRepo = parentIssue.SourceRepo
-			}
-			// If error getting parent or parent has no source_repo, continue with default
-		}
+		// Discovered work inherits source_repo from the issue it was found in
+		inheritSourceRepo(ctx, store, issue, depSpecs)
 
 		if err := store.CreateIssue(ctx, issue, actor); err != nil {
 			FatalError("%v", err)
@@ -598,59 +590,9 @@ var createCmd = &cobra.Command{
 		}
 
 		// Add dependencies if specified (format: type:id or just id for default "blocks" type)
-		for _, depSpec := range deps {
-			depSpec = strings.TrimSpace(depSpec)
-			if depSpec == "" {
-				continue
-			}
-
-			var depType types.DependencyType
-			var dependsOnID string
-			swapDirection := false
-
-			if strings.Contains(depSpec, ":") {
-				parts := strings.SplitN(depSpec, ":", 2)
-				if len(parts) != 2 {
-					WarnError("invalid dependency format '%s', expected 'type:id' or 'id'", depSpec)
-					continue
-				}
-				rawType := types.DependencyType(strings.TrimSpace(parts[0]))
-				dependsOnID = strings.TrimSpace(parts[1])
-
-				switch rawType {
-				case "depends-on", "blocked-by":
-					// Alias: the new issue depends on the target. Store as a blocks edge.
-					depType = types.DepBlocks
-				case types.DepBlocks:
-					// Explicit "blocks:X" means the new issue blocks X, so store X -> new issue.
-					depType = types.DepBlocks
-					swapDirection = true
-				default:
-					depType = rawType
-				}
-			} else {
-				depType = types.DepBlocks
-				dependsOnID = depSpec
-			}
-
-			if !depType.IsValid() {
-				FatalErrorRespectJSON("invalid dependency type %q (must be non-empty, max 50 chars); valid types: %s", depType, createDepsAcceptedTypeList())
-			}
-			if !depType.IsWellKnown() {
-				FatalErrorRespectJSON("unknown dependency type %q; valid types: %s", depType, createDepsAcceptedTypeList())
-			}
-
-			dep := &types.Dependency{
-				IssueID:     issue.ID,
-				DependsOnID: dependsOnID,
-				Type:        depType,
-			}
-			if swapDirection {
-				dep.IssueID = dependsOnID
-				dep.DependsOnID = issue.ID
-			}
-			if err := store.AddDependency(ctx, dep, actor); err != nil {
-				WarnError("failed to add dependency %s -> %s: %v", issue.ID, dependsOnID, err)
+		for _, spec := range depSpecs {
+			if err := store.AddDependency(ctx, spec.dependency(issue.ID), actor); err != nil {
+				WarnError("failed to add dependency %s -> %s: %v", issue.ID, spec.Target, err)
 			} else {
 				postCreateWrites = true
 			}
@@ -748,6 +690,7 @@ type createIssueParams struct {
 	SpecID             string
 	Priority           int
 	IssueType          types.IssueType
+	Severity           types.Severity
 	Assignee           string
 	ExternalRef        string
 	EstimatedMinutes   *int
@@ -789,6 +732,7 @@ func buildCreateIssue(params createIssueParams) *types.Issue {
 		Status:             status,
 		Priority:           params.Priority,
 		IssueType:          params.IssueType,
+		Severity:           params.Severity,
 		Assignee:           params.Assignee,
 		ExternalRef:        externalRefPtr,
 		EstimatedMinutes:   params.EstimatedMinutes,
@@ -809,6 +753,138 @@ func buildCreateIssue(params createIssueParams) *types.Issue {
 	}
 }
 
+// lintNewIssue checks a new issue's description and acceptance criteria
+// against its type's template when strict or validation.on-create asks for
+// it: a failure is an error when strict or under "error", and a warning
+// under "warn".
+func lintNewIssue(issue *types.Issue, strict bool) error {
+	mode := config.GetString("validation.on-create")
+	if !strict && mode != "error" && mode != "warn" {
+		return nil
+	}
+	if err := validation.LintIssue(issue); err != nil {
+		if strict || mode == "error" {
+			return err
+		}
+		// warn mode: print warning but proceed
+		fmt.Fprintf(os.Stderr, "%s %v\n", ui.RenderWarn("⚠"), err)
+	}
+	return nil
+}
+
+// createConfigReader is where checkNewIssueID reads the prefix config.
+type createConfigReader interface {
+	GetConfig(ctx context.Context, key string) (string, error)
+}
+
+// checkNewIssueID checks an explicit ID for a new issue: its format, and
+// that it starts with the database prefix or an allowed one, unless force.
+func checkNewIssueID(ctx context.Context, cfg createConfigReader, id string, force bool) error {
+	// Basic format validation for all issue types.
+	// Note: Orchestrator-specific agent ID validation (mayor, polecat, witness, etc.)
+	// is handled by the orchestrator, not beads core.
+	if _, err := validation.ValidateIDFormat(id); err != nil {
+		return err
+	}
+
+	// Get database prefix and allowed prefixes from config.
+	// YAML config takes precedence over DB — in shared-server mode the DB
+	// may belong to a different project (GH#2469).
+	var dbPrefix, allowedPrefixes string
+	if yamlPrefix := config.GetString("issue-prefix"); yamlPrefix != "" {
+		dbPrefix = yamlPrefix
+	} else {
+		dbPrefix, _ = cfg.GetConfig(ctx, "issue_prefix") // Best effort: empty prefix is a valid fallback
+	}
+	allowedPrefixes, _ = cfg.GetConfig(ctx, "allowed_prefixes") // Best effort: empty means no prefix restriction
+
+	// Use ValidateIDPrefixAllowed which handles multi-hyphen prefixes correctly (GH#1135)
+	// This checks if the ID starts with an allowed prefix, rather than extracting
+	// the prefix first (which can fail for IDs like "hq-cv-test" where "test" looks like a word)
+	return validation.ValidateIDPrefixAllowed(id, dbPrefix, allowedPrefixes, force)
+}
+
+// checkNewIssue applies the checks 'bd create' makes of an issue before
+// writing it: the template lint, the validation rules, and an explicit ID's
+// format and prefix.
+func checkNewIssue(ctx context.Context, cfg createConfigReader, issue *types.Issue) error {
+	if err := lintNewIssue(issue, false); err != nil {
+		return err
+	}
+	if err := checkValidationRules(issue); err != nil {
+		return err
+	}
+	if issue.ID != "" {
+		return checkNewIssueID(ctx, cfg, issue.ID, false)
+	}
+	return nil
+}
+
+// createDepSpec is one parsed --deps entry of a new issue.
+type createDepSpec struct {
+	Type   types.DependencyType
+	Target string
+	Swap   bool // the new issue blocks Target instead of depending on it
+}
+
+// parseCreateDepSpec parses a --deps entry, "type:id" or "id" for a
+// blocks dependency. "depends-on:X" and "blocked-by:X" are aliases for
+// "X", and "blocks:X" makes the new issue block X. ok is false for an
+// empty entry.
+func parseCreateDepSpec(spec string) (parsed createDepSpec, ok bool, err error) {
+	spec = strings.TrimSpace(spec)
+	if spec == "" {
+		return createDepSpec{}, false, nil
+	}
+	rawType, target, typed := strings.Cut(spec, ":")
+	if !typed {
+		return createDepSpec{Type: types.DepBlocks, Target: spec}, true, nil
+	}
+	parsed.Target = strings.TrimSpace(target)
+	switch depType := types.DependencyType(strings.TrimSpace(rawType)); depType {
+	case "depends-on", "blocked-by":
+		// Alias: the new issue depends on the target. Store as a blocks edge.
+		parsed.Type = types.DepBlocks
+	case types.DepBlocks:
+		// Explicit "blocks:X" means the new issue blocks X, so store X -> new issue.
+		parsed.Type = types.DepBlocks
+		parsed.Swap = true
+	default:
+		parsed.Type = depType
+	}
+	if !parsed.Type.IsValid() {
+		return createDepSpec{}, false, fmt.Errorf("invalid dependency type %q (must be non-empty, max 50 chars); valid types: %s", parsed.Type, createDepsAcceptedTypeList())
+	}
+	if !parsed.Type.IsWellKnown() {
+		return createDepSpec{}, false, fmt.Errorf("unknown dependency type %q; valid types: %s", parsed.Type, createDepsAcceptedTypeList())
+	}
+	return parsed, true, nil
+}
+
+// dependency returns the dependency the entry adds for the new issue.
+func (d createDepSpec) dependency(issueID string) *types.Dependency {
+	if d.Swap {
+		return &types.Dependency{IssueID: d.Target, DependsOnID: issueID, Type: d.Type}
+	}
+	return &types.Dependency{IssueID: issueID, DependsOnID: d.Target, Type: d.Type}
+}
+
+// inheritSourceRepo gives a new issue the source_repo of the issue it was
+// discovered from, if that has one.
+func inheritSourceRepo(ctx context.Context, r interface {
+	GetIssue(ctx context.Context, id string) (*types.Issue, error)
+}, issue *types.Issue, deps []createDepSpec) {
+	for _, d := range deps {
+		if d.Type != types.DepDiscoveredFrom || d.Target == "" {
+			continue
+		}
+		if from, err := r.GetIssue(ctx, d.Target); err == nil && from.SourceRepo != "" {
+			issue.SourceRepo = from.SourceRepo
+		}
+		return
+	}
+}
+
 func mergeCreateLabels(labels, inheritedLabels []string) []string {
 	merged := make([]string, 0, len(labels)+len(inheritedLabels))
 	seen := make(map[string]struct{}, len(labels)+len(inheritedLabels))
@@ -875,7 +951,10 @@ func init() {
 	createCmd.Flags().String("title", "", "Issue title (alternative to positional argument)")
 	createCmd.Flags().Bool("silent", false, "Output only the issue ID (for scripting)")
 	createCmd.Flags().Bool("dry-run", false, "Preview what would be created without actually creating")
+	createCmd.Flags().Bool("check-duplicates", false, "Look for open issues with a similar title first: warn, ask on a terminal, or with --json list them and create nothing")
+	createCmd.Flags().Float64("duplicate-threshold", 0.5, "Title similarity (0.0-1.0) at which --check-duplicates reports an issue")
 	registerPriorityFlag(createCmd, "2")
+	createCmd.Flags().String("severity", "", "Bug severity (S0-S3, S0=most severe); bugs only")
 	createCmd.Flags().StringP("type", "t", "task", "Issue type (bug|feature|task|epic|chore|decision); custom types require types.custom config; aliases: enhancement/feat→feature, dec/adr→decision")
 	registerCommonIssueFlags(createCmd)
 	createCmd.Flags().String("spec-id", "", "Link to specification document")
@@ -886,7 +965,8 @@ func init() {
 	_ = createCmd.Flags().MarkHidden("label") // Only fails if flag missing (caught in tests)
 	createCmd.Flags().String("id", "", "Explicit issue ID (e.g., 'bd-42' for partitioning)")
 	createCmd.Flags().String("parent", "", "Parent issue ID for hierarchical child (e.g., 'bd-a3f8e9')")
-	createCmd.Flags().Bool("no-inherit-labels", false, "Don't inherit labels from parent issue")
+	createCmd.Flags().Bool("no-inherit-labels", false, "Don't inherit labels from the --parent or --from issue")
+	createCmd.Flags().String("from", "", "Issue you were working on when you found this one: adds a discovered-from dependency and inherits its labels")
 	createCmd.Flags().StringSlice("deps", []string{}, "Dependencies in format 'type:id' or 'id' (e.g., 'discovered-from:bd-20,blocks:bd-15' or 'bd-20')")
 	createCmd.Flags().String("waits-for", "", "Spawner issue ID to wait for (creates waits-for dependency for fanout gate)")
 	createCmd.Flags().String("waits-for-gate", "all-children", "Gate type: all-children (wait for all) or any-children (wait for first)")
@@ -914,6 +994,7 @@ func init() {
 	createCmd.Flags().String("due", "", "Due date/time. Formats: +6h, +1d, +2w, tomorrow, next monday, 2025-01-15")
 	createCmd.Flags().String("defer", "", "Defer until date (issue hidden from bd ready until then). Same formats as --due")
 	createCmd.Flags().String("metadata", "", "Set custom metadata (JSON string or @file.json to read from file)")
+	createCmd.Flags().StringArray("field", nil, "Set a custom field, stored under metadata.custom_fields (repeatable, e.g., --field points=3)")
 	// Note: --json flag is defined as a persistent flag in main.go, not here
 	rootCmd.AddCommand(createCmd)
 }
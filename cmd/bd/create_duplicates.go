@@ -0,0 +1,135 @@
+package main
+
+import (
+	"bufio"
+	"context"
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+	"unicode"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"golang.org/x/term"
+)
+
+// maxDuplicateCandidates caps how many similar issues
+// 'bd create --check-duplicates' reports.
+const maxDuplicateCandidates = 5
+
+// duplicateCandidate is an open issue whose title resembles the title of an
+// issue about to be created.
+type duplicateCandidate struct {
+	ID         string       `json:"id"`
+	Title      string       `json:"title"`
+	Status     types.Status `json:"status"`
+	Similarity float64      `json:"similarity"`
+}
+
+// duplicateSearchFilter selects the issues a new one is checked against:
+// open, persistent, and not templates.
+func duplicateSearchFilter() types.IssueFilter {
+	notTemplate := false
+	notEphemeral := false
+	return types.IssueFilter{
+		ExcludeStatus: []types.Status{types.StatusClosed},
+		IsTemplate:    &notTemplate,
+		Ephemeral:     &notEphemeral,
+	}
+}
+
+// findTitleDuplicates returns the open issues in st whose titles are at
+// least threshold similar to title, most similar first.
+func findTitleDuplicates(ctx context.Context, st storage.DoltStorage, title string, threshold float64) ([]duplicateCandidate, error) {
+	issues, err := st.SearchIssues(ctx, "", duplicateSearchFilter())
+	if err != nil {
+		return nil, err
+	}
+	return rankDuplicateCandidates(title, issues, threshold), nil
+}
+
+// rankDuplicateCandidates scores issues by title trigram similarity to
+// title and keeps the best ones at or above threshold.
+func rankDuplicateCandidates(title string, issues []*types.Issue, threshold float64) []duplicateCandidate {
+	want := titleTrigrams(title)
+	var candidates []duplicateCandidate
+	for _, issue := range issues {
+		similarity := trigramSimilarity(want, titleTrigrams(issue.Title))
+		if similarity >= threshold {
+			candidates = append(candidates, duplicateCandidate{
+				ID:         issue.ID,
+				Title:      issue.Title,
+				Status:     issue.Status,
+				Similarity: similarity,
+			})
+		}
+	}
+	sort.SliceStable(candidates, func(i, j int) bool {
+		return candidates[i].Similarity > candidates[j].Similarity
+	})
+	if len(candidates) > maxDuplicateCandidates {
+		candidates = candidates[:maxDuplicateCandidates]
+	}
+	return candidates
+}
+
+// titleTrigrams returns the character trigrams of a title after lowercasing
+// it and collapsing punctuation and whitespace, so "Fix: login crash" and
+// "fix login-crash" compare equal. Each word is padded so short words still
+// contribute and word boundaries count.
+func titleTrigrams(title string) map[string]bool {
+	words := strings.FieldsFunc(strings.ToLower(title), func(r rune) bool {
+		return !unicode.IsLetter(r) && !unicode.IsDigit(r)
+	})
+	trigrams := make(map[string]bool)
+	for _, w := range words {
+		padded := []rune("  " + w + " ")
+		for i := 0; i+3 <= len(padded); i++ {
+			trigrams[string(padded[i:i+3])] = true
+		}
+	}
+	return trigrams
+}
+
+// trigramSimilarity is the Jaccard similarity of two trigram sets.
+func trigramSimilarity(a, b map[string]bool) float64 {
+	if len(a) == 0 || len(b) == 0 {
+		return 0
+	}
+	shared := 0
+	for t := range a {
+		if b[t] {
+			shared++
+		}
+	}
+	return float64(shared) / float64(len(a)+len(b)-shared)
+}
+
+// createDespiteDuplicates reports possible duplicates of an issue about to
+// be created and decides whether to create it anyway. With --json it prints
+// the candidates and exits without creating; on a terminal it asks;
+// otherwise it warns and goes ahead.
+func createDespiteDuplicates(candidates []duplicateCandidate) bool {
+	if jsonOutput {
+		outputJSON(map[string]interface{}{
+			"error":      "possible duplicates found; issue not created (re-run without --check-duplicates to create it anyway)",
+			"code":       "possible_duplicates",
+			"duplicates": candidates,
+		})
+		os.Exit(1)
+	}
+
+	fmt.Fprintf(os.Stderr, "%s Possible duplicates:\n", ui.RenderWarn("⚠"))
+	for _, c := range candidates {
+		fmt.Fprintf(os.Stderr, "  %s %s (%s, %.0f%% similar)\n", ui.RenderID(c.ID), c.Title, c.Status, c.Similarity*100)
+	}
+	if !term.IsTerminal(int(os.Stdin.Fd())) {
+		return true
+	}
+	fmt.Fprint(os.Stderr, "Create it anyway? [y/N] ")
+	line, _ := bufio.NewReader(os.Stdin).ReadString('\n')
+	line = strings.ToLower(strings.TrimSpace(line))
+	return line == "y" || line == "yes"
+}
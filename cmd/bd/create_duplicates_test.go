@@ -0,0 +1,40 @@
+package main
+
+import (
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestTrigramSimilarity(t *testing.T) {
+	same := trigramSimilarity(titleTrigrams("Fix: login crash"), titleTrigrams("fix login-crash"))
+	if same != 1 {
+		t.Errorf("punctuation and case should not matter, got %.2f", same)
+	}
+	similar := trigramSimilarity(titleTrigrams("Login crashes on Safari"), titleTrigrams("Crash on login in Safari"))
+	far := trigramSimilarity(titleTrigrams("Login crashes on Safari"), titleTrigrams("Update release notes"))
+	if similar <= far || similar < 0.5 || far > 0.2 {
+		t.Errorf("similar titles = %.2f, unrelated = %.2f", similar, far)
+	}
+	if got := trigramSimilarity(titleTrigrams(""), titleTrigrams("anything")); got != 0 {
+		t.Errorf("empty title similarity = %.2f, want 0", got)
+	}
+}
+
+func TestRankDuplicateCandidates(t *testing.T) {
+	issues := []*types.Issue{
+		{ID: "bd-1", Title: "Update release notes", Status: types.StatusOpen},
+		{ID: "bd-2", Title: "Login crash on Safari", Status: types.StatusInProgress},
+		{ID: "bd-3", Title: "Login crashes on Safari 17", Status: types.StatusOpen},
+	}
+	got := rankDuplicateCandidates("Login crashes on Safari", issues, 0.5)
+	if len(got) != 2 || got[0].ID != "bd-3" || got[1].ID != "bd-2" {
+		t.Fatalf("candidates = %+v, want bd-3 then bd-2", got)
+	}
+	if got[1].Status != types.StatusInProgress || got[0].Similarity <= got[1].Similarity {
+		t.Errorf("candidates = %+v", got)
+	}
+	if got := rankDuplicateCandidates("Login crashes on Safari", issues, 0.99); len(got) != 0 {
+		t.Errorf("threshold 0.99: candidates = %+v, want none", got)
+	}
+}
@@ -8,6 +8,7 @@ import (
 	"os"
 	"os/exec"
 	"path/filepath"
+	"slices"
 	"strings"
 	"sync"
 	"testing"
@@ -664,6 +665,29 @@ func TestEmbeddedCreate(t *testing.T) {
 		assertDepExists(t, beadsDir, "di", child.ID, parent.ID)
 	})
 
+	t.Run("from_flag", func(t *testing.T) {
+		dir, beadsDir, _ := bdInit(t, bd, "--prefix", "fr")
+
+		working := bdCreate(t, bd, dir, "Working issue", "-l", "area:auth")
+		found := bdCreate(t, bd, dir, "Found while working", "--from", working.ID, "-l", "bug")
+		assertDepExistsWithType(t, beadsDir, "fr", found.ID, working.ID, "discovered-from")
+
+		store := openStore(t, beadsDir, "fr")
+		labels, err := store.GetLabels(t.Context(), found.ID)
+		if err != nil {
+			t.Fatalf("GetLabels: %v", err)
+		}
+		if !slices.Contains(labels, "area:auth") || !slices.Contains(labels, "bug") {
+			t.Errorf("expected own and inherited labels, got %v", labels)
+		}
+		store.Close()
+
+		out := bdCreateFail(t, bd, dir, "Bad source", "--from", "fr-nonexistent")
+		if !strings.Contains(out, "--from") {
+			t.Errorf("expected --from error, got: %s", out)
+		}
+	})
+
 	t.Run("markdown_bulk_create", func(t *testing.T) {
 		dir, beadsDir, _ := bdInit(t, bd, "--prefix", "mk")
 
@@ -23,6 +23,7 @@ type createInput struct {
 	explicitID         string
 	parentID           string
 	issueType          string
+	severity           types.Severity
 	priority           int
 	assignee           string
 	externalRef        string
@@ -34,11 +35,14 @@ type createInput struct {
 	appendNotes        string
 	labels             []string
 	noInheritLabels    bool
+	from               string
 	deps               []string
 	waitsFor           string
 	waitsForGate       string
 	silent             bool
 	dryRun             bool
+	checkDuplicates    bool
+	duplicateThreshold float64
 	force              bool
 	validate           bool
 	ephemeral          bool
@@ -68,6 +72,8 @@ func gatherCreateInput(cmd *cobra.Command, args []string) createInput {
 	in.markdownFile, _ = cmd.Flags().GetString("file")
 	in.graphFile, _ = cmd.Flags().GetString("graph")
 	in.dryRun, _ = cmd.Flags().GetBool("dry-run")
+	in.checkDuplicates, _ = cmd.Flags().GetBool("check-duplicates")
+	in.duplicateThreshold, _ = cmd.Flags().GetFloat64("duplicate-threshold")
 
 	if in.markdownFile != "" && in.graphFile != "" {
 		FatalError("cannot specify both --file and --graph")
@@ -140,6 +146,10 @@ func gatherCreateInput(cmd *cobra.Command, args []string) createInput {
 	in.priority = priority
 
 	in.issueType, _ = cmd.Flags().GetString("type")
+	severityStr, _ := cmd.Flags().GetString("severity")
+	if in.severity, err = parseSeverityFlag(severityStr, in.issueType); err != nil {
+		FatalError("%v", err)
+	}
 	in.assignee, _ = cmd.Flags().GetString("assignee")
 	in.externalRef, _ = cmd.Flags().GetString("external-ref")
 	in.explicitID, _ = cmd.Flags().GetString("id")
@@ -157,6 +167,10 @@ func gatherCreateInput(cmd *cobra.Command, args []string) createInput {
 		in.labels = append(in.labels, labelAlias...)
 	}
 	in.deps, _ = cmd.Flags().GetStringSlice("deps")
+	in.from, _ = cmd.Flags().GetString("from")
+	if in.from != "" {
+		in.deps = append(in.deps, string(types.DepDiscoveredFrom)+":"+in.from)
+	}
 
 	in.repoOverride, _ = cmd.Flags().GetString("repo")
 	in.repoOverrideSet = cmd.Flags().Changed("repo")
@@ -249,9 +263,9 @@ func gatherCreateInput(cmd *cobra.Command, args []string) createInput {
 
 var singleIssueOnlyFlags = []string{
 	"title",
-	"id", "parent", "no-inherit-labels",
+	"id", "parent", "no-inherit-labels", "from",
 	"deps", "waits-for", "waits-for-gate",
-	"type", "priority", "assignee", "external-ref", "spec-id",
+	"type", "priority", "severity", "assignee", "external-ref", "spec-id",
 	"description", "body", "message", "body-file", "description-file", "stdin",
 	"design", "design-file", "acceptance", "notes", "append-notes",
 	"labels", "label", "skills", "context",
@@ -55,6 +55,19 @@ func proxiedOpenUOW(ctx context.Context) (uow.UnitOfWork, domain.CreateContext)
 	return uw, cctx
 }
 
+// proxiedFromLabels returns the labels of the --from issue, failing when it
+// does not exist.
+func proxiedFromLabels(ctx context.Context, uw uow.UnitOfWork, fromID string) []string {
+	if _, err := uw.IssueUseCase().GetIssue(ctx, fromID); err != nil {
+		FatalError("--from issue %s not found: %v", fromID, err)
+	}
+	labels, err := uw.LabelUseCase().GetLabels(ctx, fromID)
+	if err != nil {
+		FatalError("failed to get labels of %s: %v", fromID, err)
+	}
+	return labels
+}
+
 func runCreateProxiedSingle(_ *cobra.Command, ctx context.Context, in createInput) {
 	runCreateLintIssue(in)
 	if in.explicitID != "" {
@@ -95,6 +108,17 @@ func runCreateProxiedSingle(_ *cobra.Command, ctx context.Context, in createInpu
 			}
 			dryUW.Close(ctx)
 		}
+		if in.from != "" && !in.noInheritLabels {
+			if uowProvider == nil {
+				FatalError("proxied-server UOW provider not initialized")
+			}
+			dryUW, err := uowProvider.NewUOW(ctx)
+			if err != nil {
+				FatalError("open unit of work: %v", err)
+			}
+			previewLabels = mergeCreateLabels(previewLabels, proxiedFromLabels(ctx, dryUW, in.from))
+			dryUW.Close(ctx)
+		}
 		previewIssue := buildCreateIssueFromInput(in)
 		if in.jsonOutput {
 			outputJSON(previewIssue)
@@ -107,6 +131,10 @@ func runCreateProxiedSingle(_ *cobra.Command, ctx context.Context, in createInpu
 	uw, cctx := proxiedOpenUOW(ctx)
 	defer uw.Close(ctx)
 
+	if in.from != "" && !in.noInheritLabels {
+		in.labels = mergeCreateLabels(in.labels, proxiedFromLabels(ctx, uw, in.from))
+	}
+
 	customTypes := resolveProxiedCustomTypes(cctx.CustomTypes)
 	if in.issueType != "" {
 		it := types.IssueType(in.issueType).Normalize()
@@ -121,7 +149,22 @@ func runCreateProxiedSingle(_ *cobra.Command, ctx context.Context, in createInpu
 		}
 	}
 
+	if in.checkDuplicates {
+		existing, err := uw.IssueUseCase().SearchIssues(ctx, "", duplicateSearchFilter())
+		if err != nil {
+			FatalError("checking for duplicates: %v", err)
+		}
+		candidates := rankDuplicateCandidates(in.title, existing, in.duplicateThreshold)
+		if len(candidates) > 0 && !createDespiteDuplicates(candidates) {
+			fmt.Fprintln(os.Stderr, "Aborted.")
+			return
+		}
+	}
+
 	issue := buildCreateIssueFromInput(in)
+	ruleIssue := *issue
+	ruleIssue.Labels = in.labels
+	enforceValidationRules(&ruleIssue)
 	params := domain.CreateIssueParams{
 		Issue:                   issue,
 		ExplicitID:              in.explicitID,
@@ -188,6 +231,7 @@ func buildCreateIssueFromInput(in createInput) *types.Issue {
 		SpecID:             in.specID,
 		Priority:           in.priority,
 		IssueType:          types.IssueType(in.issueType).Normalize(),
+		Severity:           in.severity,
 		Assignee:           in.assignee,
 		ExternalRef:        in.externalRef,
 		EstimatedMinutes:   in.estimatedMinutes,
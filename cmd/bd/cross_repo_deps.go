@@ -0,0 +1,280 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/configfile"
+	"github.com/steveyegge/beads/internal/routing"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+// parseCrossRepoRef splits a cross-repository dependency reference of the
+// form <workspace>:<issue-id>, where <workspace> is a name from the
+// workspace registry (see 'bd workspace'). external:<project>:<capability>
+// references are not cross-repository references.
+func parseCrossRepoRef(ref string) (repo, id string, ok bool) {
+	if IsExternalRef(ref) {
+		return "", "", false
+	}
+	repo, id, ok = strings.Cut(ref, ":")
+	if !ok || repo == "" || id == "" || strings.ContainsAny(repo, " /\\") {
+		return "", "", false
+	}
+	return repo, id, true
+}
+
+// crossRepoResolver looks up issues in registered sibling repositories,
+// opening each repository's store read-only at most once.
+type crossRepoResolver struct {
+	reg    *configfile.WorkspaceRegistry
+	stores map[string]storage.DoltStorage
+	failed map[string]error
+}
+
+func newCrossRepoResolver() (*crossRepoResolver, error) {
+	reg, err := configfile.LoadWorkspaces(configfile.WorkspacesPath())
+	if err != nil {
+		return nil, err
+	}
+	return &crossRepoResolver{
+		reg:    reg,
+		stores: make(map[string]storage.DoltStorage),
+		failed: make(map[string]error),
+	}, nil
+}
+
+// registered reports whether ref names an issue in a registered workspace.
+func (r *crossRepoResolver) registered(ref string) bool {
+	repo, _, ok := parseCrossRepoRef(ref)
+	return ok && r.reg.Find(repo) != nil
+}
+
+func (r *crossRepoResolver) storeFor(ctx context.Context, repo string) (storage.DoltStorage, error) {
+	if st, ok := r.stores[repo]; ok {
+		return st, nil
+	}
+	if err, ok := r.failed[repo]; ok {
+		return nil, err
+	}
+	w := r.reg.Find(repo)
+	if w == nil {
+		return nil, fmt.Errorf("no workspace named %q is registered (see 'bd workspace add')", repo)
+	}
+	beadsDir := filepath.Join(routing.ExpandPath(w.Path), ".beads")
+	st, err := newReadOnlyStoreFromConfig(ctx, beadsDir)
+	if err != nil {
+		err = fmt.Errorf("opening workspace %s: %w", repo, err)
+		r.failed[repo] = err
+		return nil, err
+	}
+	r.stores[repo] = st
+	return st, nil
+}
+
+// lookup resolves ref, which may use a partial issue ID, to the issue in
+// its repository.
+func (r *crossRepoResolver) lookup(ctx context.Context, ref string) (*types.Issue, error) {
+	repo, id, ok := parseCrossRepoRef(ref)
+	if !ok {
+		return nil, fmt.Errorf("invalid cross-repository reference %q: expected <workspace>:<issue-id>", ref)
+	}
+	st, err := r.storeFor(ctx, repo)
+	if err != nil {
+		return nil, err
+	}
+	fullID, err := utils.ResolvePartialID(ctx, st, id)
+	if err != nil {
+		return nil, fmt.Errorf("resolving %s in workspace %s: %w", id, repo, err)
+	}
+	issue, err := st.GetIssue(ctx, fullID)
+	if err != nil {
+		return nil, fmt.Errorf("resolving %s in workspace %s: %w", id, repo, err)
+	}
+	return issue, nil
+}
+
+func (r *crossRepoResolver) Close() {
+	for _, st := range r.stores {
+		_ = st.Close()
+	}
+}
+
+// crossRepoBlockers returns, for each of ids, the cross-repository
+// blockers that are still open in their own repository. The storage layer
+// can't see other repositories, so it treats these dependencies as
+// satisfied; callers use this to correct ready and blocked results. A
+// blocker whose repository can't be read counts as open.
+func crossRepoBlockers(ctx context.Context, s storage.DoltStorage, ids []string) map[string][]string {
+	if len(ids) == 0 {
+		return nil
+	}
+	deps, err := s.GetDependencyRecordsForIssues(ctx, ids)
+	if err != nil {
+		return nil
+	}
+	var resolver *crossRepoResolver
+	defer func() {
+		if resolver != nil {
+			resolver.Close()
+		}
+	}()
+
+	blockers := make(map[string][]string)
+	status := make(map[string]bool) // ref -> still open
+	warned := make(map[string]bool)
+	for _, id := range ids {
+		for _, dep := range deps[id] {
+			if !dep.Type.IsBlockingEdge() {
+				continue
+			}
+			if _, _, ok := parseCrossRepoRef(dep.DependsOnID); !ok {
+				continue
+			}
+			if resolver == nil {
+				if resolver, err = newCrossRepoResolver(); err != nil {
+					fmt.Fprintf(os.Stderr, "warning: cross-repository dependencies not checked: %v\n", err)
+					return nil
+				}
+			}
+			if !resolver.registered(dep.DependsOnID) {
+				continue
+			}
+			open, seen := status[dep.DependsOnID]
+			if !seen {
+				issue, err := resolver.lookup(ctx, dep.DependsOnID)
+				if err != nil {
+					repo, _, _ := parseCrossRepoRef(dep.DependsOnID)
+					if !warned[repo] {
+						warned[repo] = true
+						fmt.Fprintf(os.Stderr, "warning: treating %s as open: %v\n", dep.DependsOnID, err)
+					}
+					open = true
+				} else {
+					open = issue.Status != types.StatusClosed && issue.Status != types.StatusPinned
+				}
+				status[dep.DependsOnID] = open
+			}
+			if open {
+				blockers[id] = append(blockers[id], dep.DependsOnID)
+			}
+		}
+	}
+	return blockers
+}
+
+// withoutCrossRepoBlocked drops the issues that crossRepoBlockers reports
+// as blocked from a ready list.
+func withoutCrossRepoBlocked[T any](ctx context.Context, s storage.DoltStorage, issues []T, idOf func(T) string) []T {
+	ids := make([]string, len(issues))
+	for i, issue := range issues {
+		ids[i] = idOf(issue)
+	}
+	blockers := crossRepoBlockers(ctx, s, ids)
+	if len(blockers) == 0 {
+		return issues
+	}
+	ready := issues[:0]
+	for _, issue := range issues {
+		if _, blocked := blockers[idOf(issue)]; !blocked {
+			ready = append(ready, issue)
+		}
+	}
+	return ready
+}
+
+// readyWithinLimit returns up to limit issues from fetch, a ready query
+// taking a limit, without the ones still blocked across repositories. The
+// store applies the limit before they are dropped, so while that leaves
+// fewer than limit and the store had more, fetch runs again with a larger
+// limit. A limit of 0 means no limit.
+func readyWithinLimit[T any](ctx context.Context, s storage.DoltStorage, limit int, fetch func(limit int) ([]T, error), idOf func(T) string) ([]T, error) {
+	for n := limit; ; n *= 2 {
+		issues, err := fetch(n)
+		if err != nil {
+			return nil, err
+		}
+		ready := withoutCrossRepoBlocked(ctx, s, issues, idOf)
+		switch {
+		case limit <= 0 || len(issues) < n:
+			return ready, nil
+		case len(ready) >= limit:
+			return ready[:limit], nil
+		}
+	}
+}
+
+// getReadyWork is GetReadyWork without the issues still blocked by an open
+// cross-repository dependency, which the store counts as ready. It still
+// returns up to filter.Limit issues.
+func getReadyWork(ctx context.Context, s storage.DoltStorage, filter types.WorkFilter) ([]*types.Issue, error) {
+	return readyWithinLimit(ctx, s, filter.Limit, func(limit int) ([]*types.Issue, error) {
+		filter.Limit = limit
+		return s.GetReadyWork(ctx, filter)
+	}, readyIssueID)
+}
+
+// getReadyWorkWithCounts is getReadyWork for GetReadyWorkWithCounts.
+func getReadyWorkWithCounts(ctx context.Context, s storage.DoltStorage, filter types.WorkFilter) ([]*types.IssueWithCounts, error) {
+	return readyWithinLimit(ctx, s, filter.Limit, func(limit int) ([]*types.IssueWithCounts, error) {
+		filter.Limit = limit
+		return s.GetReadyWorkWithCounts(ctx, filter)
+	}, readyIssueWithCountsID)
+}
+
+// claimReadyIssue claims the first ready issue matching filter, like
+// ClaimReadyIssue, but skips the issues still blocked by an open
+// cross-repository dependency, which the store counts as ready.
+func claimReadyIssue(ctx context.Context, s storage.DoltStorage, filter types.WorkFilter, actor string) (*types.Issue, error) {
+	candidates := filter
+	candidates.Status = types.StatusOpen
+	candidates.Unassigned = true
+	candidates.Assignee = nil
+	candidates.Limit = 0
+	ready, err := s.GetReadyWork(ctx, candidates)
+	if err != nil {
+		return nil, err
+	}
+	ids := make([]string, len(ready))
+	for i, issue := range ready {
+		ids[i] = issue.ID
+	}
+	for id := range crossRepoBlockers(ctx, s, ids) {
+		filter.ExcludeIDs = append(filter.ExcludeIDs, id)
+	}
+	return s.ClaimReadyIssue(ctx, filter, actor)
+}
+
+// addCrossRepoBlocked merges issues blocked only by open cross-repository
+// dependencies into a blocked list. Candidates are the issues the store
+// considers ready, which is every open issue with no local blocker.
+func addCrossRepoBlocked(ctx context.Context, s storage.DoltStorage, blocked []*types.BlockedIssue, filter types.WorkFilter) []*types.BlockedIssue {
+	candidates, err := s.GetReadyWork(ctx, types.WorkFilter{ParentID: filter.ParentID})
+	if err != nil || len(candidates) == 0 {
+		return blocked
+	}
+	ids := make([]string, len(candidates))
+	for i, issue := range candidates {
+		ids[i] = issue.ID
+	}
+	blockers := crossRepoBlockers(ctx, s, ids)
+	for _, issue := range candidates {
+		if refs, ok := blockers[issue.ID]; ok {
+			blocked = append(blocked, &types.BlockedIssue{
+				Issue:          *issue,
+				BlockedByCount: len(refs),
+				BlockedBy:      refs,
+			})
+		}
+	}
+	return blocked
+}
+
+func readyIssueID(issue *types.Issue) string { return issue.ID }
+
+func readyIssueWithCountsID(issue *types.IssueWithCounts) string { return issue.ID }
@@ -0,0 +1,156 @@
+package main
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"slices"
+	"strconv"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseCrossRepoRef(t *testing.T) {
+	tests := []struct {
+		ref      string
+		wantRepo string
+		wantID   string
+		wantOK   bool
+	}{
+		{"api:xy-7", "api", "xy-7", true},
+		{"my-service:ms-a1b.2", "my-service", "ms-a1b.2", true},
+		{"bd-12", "", "", false},
+		{"external:beads:mol-run-assignee", "", "", false},
+		{":xy-7", "", "", false},
+		{"api:", "", "", false},
+		{"../api:xy-7", "", "", false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.ref, func(t *testing.T) {
+			repo, id, ok := parseCrossRepoRef(tt.ref)
+			if repo != tt.wantRepo || id != tt.wantID || ok != tt.wantOK {
+				t.Errorf("parseCrossRepoRef(%q) = (%q, %q, %v), want (%q, %q, %v)",
+					tt.ref, repo, id, ok, tt.wantRepo, tt.wantID, tt.wantOK)
+			}
+		})
+	}
+}
+
+// depRecordsStore serves dependency records from a map; any other store
+// method panics.
+type depRecordsStore struct {
+	storage.DoltStorage
+	deps map[string][]*types.Dependency
+}
+
+func (s *depRecordsStore) GetDependencyRecordsForIssues(_ context.Context, ids []string) (map[string][]*types.Dependency, error) {
+	out := make(map[string][]*types.Dependency)
+	for _, id := range ids {
+		out[id] = s.deps[id]
+	}
+	return out, nil
+}
+
+func TestWithoutCrossRepoBlocked_IgnoresUnregisteredWorkspaces(t *testing.T) {
+	t.Setenv("BEADS_WORKSPACES_FILE", filepath.Join(t.TempDir(), "workspaces.toml"))
+	st := &depRecordsStore{deps: map[string][]*types.Dependency{
+		"bd-1": {{IssueID: "bd-1", DependsOnID: "gone:xy-7", Type: types.DepBlocks}},
+		"bd-2": {{IssueID: "bd-2", DependsOnID: "bd-1", Type: types.DepRelated}},
+	}}
+	issues := []*types.Issue{{ID: "bd-1"}, {ID: "bd-2"}, {ID: "bd-3"}}
+
+	got := withoutCrossRepoBlocked(context.Background(), st, issues, readyIssueID)
+	if len(got) != 3 {
+		t.Errorf("withoutCrossRepoBlocked dropped issues: %v", got)
+	}
+}
+
+// claimRecordStore serves ready work and dependency records and records the
+// filter ClaimReadyIssue is called with.
+type claimRecordStore struct {
+	depRecordsStore
+	ready   []*types.Issue
+	claimed types.WorkFilter
+}
+
+func (s *claimRecordStore) GetReadyWork(context.Context, types.WorkFilter) ([]*types.Issue, error) {
+	return s.ready, nil
+}
+
+func (s *claimRecordStore) ClaimReadyIssue(_ context.Context, filter types.WorkFilter, _ string) (*types.Issue, error) {
+	s.claimed = filter
+	return nil, nil
+}
+
+// registerUnreadableWorkspace registers a workspace named api whose path is
+// a file, so its database can't be opened and its blockers count as open.
+func registerUnreadableWorkspace(t *testing.T) {
+	t.Helper()
+	registry := filepath.Join(t.TempDir(), "workspaces.toml")
+	content := "[[workspace]]\nname = \"api\"\npath = " + strconv.Quote(registry) + "\n"
+	if err := os.WriteFile(registry, []byte(content), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv("BEADS_WORKSPACES_FILE", registry)
+}
+
+func TestClaimReadyIssue_SkipsCrossRepoBlocked(t *testing.T) {
+	registerUnreadableWorkspace(t)
+	st := &claimRecordStore{
+		depRecordsStore: depRecordsStore{deps: map[string][]*types.Dependency{
+			"bd-1": {{IssueID: "bd-1", DependsOnID: "api:xy-7", Type: types.DepBlocks}},
+		}},
+		ready: []*types.Issue{{ID: "bd-1"}, {ID: "bd-2"}},
+	}
+
+	if _, err := claimReadyIssue(context.Background(), st, types.WorkFilter{}, "alice"); err != nil {
+		t.Fatalf("claimReadyIssue: %v", err)
+	}
+	if !slices.Equal(st.claimed.ExcludeIDs, []string{"bd-1"}) {
+		t.Errorf("ExcludeIDs = %v, want [bd-1]", st.claimed.ExcludeIDs)
+	}
+}
+
+// limitedReadyStore serves copies of a ready list, honoring the limit.
+type limitedReadyStore struct {
+	depRecordsStore
+	ready []*types.Issue
+}
+
+func (s *limitedReadyStore) GetReadyWork(_ context.Context, filter types.WorkFilter) ([]*types.Issue, error) {
+	if filter.Limit > 0 && filter.Limit < len(s.ready) {
+		return slices.Clone(s.ready[:filter.Limit]), nil
+	}
+	return slices.Clone(s.ready), nil
+}
+
+func TestGetReadyWork_LimitAppliesAfterCrossRepoBlocked(t *testing.T) {
+	registerUnreadableWorkspace(t)
+	blocker := []*types.Dependency{{DependsOnID: "api:xy-7", Type: types.DepBlocks}}
+	st := &limitedReadyStore{
+		depRecordsStore: depRecordsStore{deps: map[string][]*types.Dependency{"bd-1": blocker, "bd-3": blocker}},
+		ready:           []*types.Issue{{ID: "bd-1"}, {ID: "bd-2"}, {ID: "bd-3"}, {ID: "bd-4"}, {ID: "bd-5"}, {ID: "bd-6"}},
+	}
+
+	// Two of the first three are blocked, so the store is asked for more.
+	got, err := getReadyWork(context.Background(), st, types.WorkFilter{Limit: 3})
+	if err != nil {
+		t.Fatalf("getReadyWork: %v", err)
+	}
+	ids := make([]string, len(got))
+	for i, issue := range got {
+		ids[i] = issue.ID
+	}
+	if want := []string{"bd-2", "bd-4", "bd-5"}; !slices.Equal(ids, want) {
+		t.Errorf("getReadyWork = %v, want %v", ids, want)
+	}
+
+	// Fewer unblocked issues than the limit: all of them.
+	got, err = getReadyWork(context.Background(), st, types.WorkFilter{Limit: 10})
+	if err != nil || len(got) != 4 {
+		t.Errorf("getReadyWork(limit 10) = %d issues, %v; want 4", len(got), err)
+	}
+}
@@ -0,0 +1,1304 @@
+package main
+
+import (
+	"bufio"
+	"context"
+	"crypto/rand"
+	"crypto/sha256"
+	"crypto/subtle"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net"
+	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/utils"
+	"github.com/steveyegge/beads/internal/validation"
+)
+
+// daemonSocketName is the socket 'bd daemon' listens on, inside .beads/.
+const daemonSocketName = "bd.sock"
+
+// daemonDiscoveryName is the file in .beads/ telling clients where the
+// running daemon listens and, for TCP, the token it requires.
+const daemonDiscoveryName = "daemon.json"
+
+var daemonCmd = &cobra.Command{
+	Use:     "daemon",
+	GroupID: "advanced",
+	Short:   "Serve bd operations over a Unix socket or localhost TCP",
+	Long: `Run in the foreground, serving bd operations on .beads/bd.sock so that
+long-running clients (editors, MCP servers, agents) reuse one open database
+instead of starting bd for every call.
+
+Clients send newline-delimited JSON-RPC 2.0 requests and get one response
+line per request (or per batch):
+
+  {"jsonrpc": "2.0", "id": 1, "method": "list", "params": {"status": "open", "cwd": "/path/to/repo"}}
+  {"jsonrpc": "2.0", "id": 1, "result": [...]}
+  {"jsonrpc": "2.0", "id": 2, "error": {"code": -32004, "message": "bd-9: not found: issue bd-9"}}
+
+Besides the standard codes, errors use -32001 (bad token), -32002 (cwd in
+another workspace), -32003 (busy), and -32004 (not found). An unknown method's error data lists the methods the daemon has;
+the original framing reports the code and data as "code" and "details".
+
+Clients should start with hello, which answers the protocol version both
+sides speak (currently 1 to 3), the bd version, and the supported methods
+and features; capabilities answers the same without negotiating. The original framing,
+{"operation": "list", "args": {...}, "cwd": "..."} answered with
+{"success": ..., "data": ..., "error": ...}, is still accepted.
+
+Methods (operations) and their params:
+
+  hello   client, protocol_version (the newest the client speaks)
+  capabilities
+  ping
+  list    status, priority, type, assignee, labels, query, limit, all
+  show    id (or ids), details
+  create  title, description, type, priority, assignee, labels, parent, deps, id
+  update  id, title, description, status, priority, assignee, notes, design,
+          acceptance_criteria, claim, force
+  close   id (or ids), reason, force
+  ready   type, priority, assignee, unassigned, labels, labels_any, sort,
+          limit, counts
+  dep     action (add|remove), issue_id, depends_on_id, type
+  label   action (add|remove), issue_id, label (or labels)
+  batch   ops (a list of {"operation", "args"} of the writes above), message:
+          runs them in order in one transaction and one Dolt commit and
+          answers {"results": [{index, operation, success, data,
+          issue_ids}]}. In an op's id, ids, parent, deps, issue_id, and
+          depends_on_id, "$N" stands for the issue ops[N] created or
+          touched, e.g. {"operation": "dep", "args": {"issue_id": "$0",
+          "depends_on_id": "bd-7"}}. If any op fails nothing is written;
+          the error's data holds the results up to the failure. Claims
+          commit on their own and can't be batched.
+  subscribe  types (optional: created, updated, closed, claimed, imported)
+  status  (PID, address, uptime, protocol version, workspaces, request
+          counts, last error; also 'bd daemon status')
+  shutdown  (exit after answering in-flight requests; also 'bd daemon stop')
+  metrics  format (json or prometheus): request and error counts by
+          operation, JSONL import/export runs, and open, in-progress,
+          ready, and blocked issues per workspace; --metrics-listen also
+          serves them for Prometheus to scrape
+  log     by (actor), operation, issue_id, since, limit: the request log,
+          newest first; also 'bd daemon log'
+
+After a successful subscribe the connection streams one line per event
+until the client disconnects: a JSON-RPC notification
+{"jsonrpc": "2.0", "method": "event", "params": {"type": "closed",
+"issue_ids": ["bd-1"], "time": "..."}}, or {"success": true, "data": {...}}
+for the original framing. Dependency and label changes are "updated";
+"imported" reports issues taken from the JSONL export by the watcher.
+
+Params may also set "actor", "cwd", and "token". A request whose "cwd"
+belongs to a different beads workspace is refused. Writes are committed to
+Dolt and auto-exported as after the matching CLI command.
+
+On Windows the daemon listens on a named pipe, \\.\pipe\beads-<hash>,
+instead (--socket still selects a Unix socket); clients find it through
+.beads/daemon.json like the socket. Where neither works (some container
+setups), --listen serves the same protocol over TCP, e.g. --listen
+127.0.0.1:0 for a free port. TCP requests must carry the daemon's token:
+
+  {"jsonrpc": "2.0", "id": 1, "method": "ping", "params": {"token": "..."}}
+
+While running, the daemon records how to reach it in .beads/daemon.json
+(network, address, and for TCP the token); the file is readable only by
+its owner and removed on exit.
+
+When the JSONL export changes outside bd (git pull, checkout, merge), the
+daemon imports the issues that differ from the database within a few
+seconds; --no-watch turns this off.
+
+The daemon records each request other than ping, hello, capabilities,
+status, metrics, and log in the workspace's dolt-ignored daemon_log table:
+when, the operation and the issues it wrote, the declared actor, the
+connection's peer (pid and uid for a Unix socket on Linux, the remote
+address otherwise), a fingerprint of its token, and any error. Entries
+older than --log-retention, and the oldest beyond --log-max-rows, are
+deleted; --no-request-log turns the log off.
+
+Each connection is served concurrently. Reads run in parallel and writes
+one at a time; at most --max-concurrent requests are handled at once, and
+a request still waiting for its turn after --queue-timeout gets a busy
+error while the connection stays open. A request that has started is
+never cut short, so a write always completes.
+
+With --idle-timeout the daemon exits once no request has arrived for that
+long; otherwise stop it with 'bd daemon stop', Ctrl+C, or SIGTERM. It
+stops reading requests, answers the ones it is handling (waiting up to
+10s), and removes its socket and discovery file. Files left behind by a
+daemon that crashed are cleaned up by the next 'bd daemon' or 'bd daemon
+stop'.
+
+bd starts a daemon on demand when run with --use-daemon or with
+daemon.auto set in config.yaml: 'bd show' and 'bd ready' with --json are
+then answered by the daemon (started with --idle-timeout from
+daemon.idle_timeout, default 30m), and every other command runs directly
+as usual.
+
+With --global one daemon serves every workspace from ~/.beads/bd-global.sock
+(discovery file ~/.beads/daemon-global.json). Each request is routed by
+its "cwd" to the workspace containing it, whose database is opened on
+first use and kept open; requests without a cwd are refused, except ping
+and:
+
+  workspaces   list the open workspaces (beads_dir, opened_at, last_active)
+
+A global daemon commits writes to Dolt but neither auto-exports JSONL nor
+watches it, since both follow one workspace's config. --use-daemon and
+daemon.auto use a running global daemon in preference to starting one per
+workspace.`,
+	Args: cobra.NoArgs,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		global, _ := cmd.Flags().GetBool("global")
+		socketPath, _ := cmd.Flags().GetString("socket")
+		listenAddr, _ := cmd.Flags().GetString("listen")
+		if socketPath != "" && listenAddr != "" {
+			return fmt.Errorf("--socket and --listen are mutually exclusive")
+		}
+
+		idleTimeout, _ := cmd.Flags().GetDuration("idle-timeout")
+		auto, _ := cmd.Flags().GetBool("auto")
+		var srv *daemonServer
+		var stateDir, discoveryName, socketName string
+		if global {
+			dir, err := globalDaemonDir()
+			if err != nil {
+				return err
+			}
+			if err := os.MkdirAll(dir, 0o700); err != nil {
+				return fmt.Errorf("failed to create %s: %w", dir, err)
+			}
+			srv = &daemonServer{global: true, idleTimeout: idleTimeout}
+			stateDir, discoveryName, socketName = dir, globalDaemonDiscoveryName, globalDaemonSocketName
+		} else {
+			if store == nil {
+				return fmt.Errorf("no store available")
+			}
+			beadsDir := beads.FindBeadsDir()
+			if beadsDir == "" {
+				return fmt.Errorf("no .beads directory found")
+			}
+			srv = &daemonServer{beadsDir: beadsDir, store: store, idleTimeout: idleTimeout}
+			if noWatch, _ := cmd.Flags().GetBool("no-watch"); !noWatch {
+				srv.watcher = newJSONLWatcher(configuredImportJSONLPath(beadsDir))
+			}
+			stateDir, discoveryName, socketName = beadsDir, daemonDiscoveryName, daemonSocketName
+		}
+
+		srv.queueTimeout, _ = cmd.Flags().GetDuration("queue-timeout")
+		srv.requestTimeout, _ = cmd.Flags().GetDuration("request-timeout")
+		if noLog, _ := cmd.Flags().GetBool("no-request-log"); !noLog {
+			cfg := daemonLogConfig{}
+			cfg.retention, _ = cmd.Flags().GetDuration("log-retention")
+			cfg.maxRows, _ = cmd.Flags().GetInt64("log-max-rows")
+			if global {
+				srv.logConfig = &cfg
+			} else {
+				srv.log = newDaemonLog(rootCtx, srv.store, cfg)
+			}
+		}
+		if workers, _ := cmd.Flags().GetInt("max-concurrent"); workers > 0 {
+			srv.workers = make(chan struct{}, workers)
+		}
+
+		discoveryPath := filepath.Join(stateDir, discoveryName)
+		if running, _ := readDaemonDiscovery(discoveryPath); running != nil {
+			if daemonAnswers(running) {
+				return fmt.Errorf("a daemon is already listening on %s %s", running.Network, running.Address)
+			}
+			// Left behind by a daemon that crashed or was killed.
+			cleanStaleDaemon(discoveryPath, running)
+			if !isQuiet() {
+				fmt.Fprintf(os.Stderr, "Removed stale daemon files from pid %d\n", running.PID)
+			}
+		}
+
+		var ln net.Listener
+		var err error
+		if listenAddr != "" {
+			if srv.token, err = newDaemonToken(); err != nil {
+				return err
+			}
+			if ln, err = listenDaemonTCP(listenAddr); err != nil {
+				return err
+			}
+		} else if socketPath == "" && runtime.GOOS == "windows" {
+			if ln, err = listenDaemonPipe(daemonPipeName(discoveryPath)); err != nil {
+				return err
+			}
+		} else {
+			if socketPath == "" {
+				socketPath = filepath.Join(stateDir, socketName)
+			}
+			if ln, err = listenDaemonSocket(socketPath); err != nil {
+				return err
+			}
+			defer func() { _ = os.Remove(socketPath) }()
+		}
+
+		discovery := &daemonDiscovery{
+			Network:   ln.Addr().Network(),
+			Address:   ln.Addr().String(),
+			Token:     srv.token,
+			PID:       os.Getpid(),
+			StartedAt: time.Now().UTC(),
+			Auto:      auto,
+		}
+		if err := writeDaemonDiscovery(discoveryPath, discovery); err != nil {
+			_ = ln.Close()
+			return err
+		}
+		defer removeDaemonDiscovery(discoveryPath)
+
+		if metricsAddr, _ := cmd.Flags().GetString("metrics-listen"); metricsAddr != "" {
+			stopMetrics, err := listenDaemonMetrics(srv, metricsAddr)
+			if err != nil {
+				return err
+			}
+			defer stopMetrics()
+		}
+
+		if !isQuiet() {
+			fmt.Fprintf(os.Stderr, "bd daemon listening on %s %s (Ctrl+C to stop)\n", discovery.Network, discovery.Address)
+		}
+		return srv.serve(rootCtx, ln)
+	},
+}
+
+func init() {
+	daemonCmd.Flags().String("socket", "", "Socket path (default: .beads/bd.sock, or ~/.beads/bd-global.sock with --global; a named pipe on Windows)")
+	daemonCmd.Flags().Bool("global", false, "Serve every workspace, routing each request by its cwd")
+	daemonCmd.Flags().String("listen", "", "Serve over TCP at host:port instead of a Unix socket (port 0 picks a free port)")
+	daemonCmd.Flags().Duration("idle-timeout", 0, "Exit after this long without a request (0 = run until stopped)")
+	daemonCmd.Flags().Int("max-concurrent", defaultDaemonWorkers, "Requests handled at once; others wait up to --queue-timeout (0 = no limit)")
+	daemonCmd.Flags().Duration("queue-timeout", defaultDaemonQueueTimeout, "Turn away a request that waits longer than this for its turn (0 = wait indefinitely)")
+	daemonCmd.Flags().Duration("request-timeout", defaultDaemonRequestTimeout, "Cancel a read (list, show, ready, ...) that runs longer than this; writes always finish (0 = no limit)")
+	daemonCmd.Flags().Bool("no-request-log", false, "Don't record who made each request in the daemon_log table")
+	daemonCmd.Flags().Duration("log-retention", defaultDaemonLogRetention, "Delete request log entries older than this (0 = keep)")
+	daemonCmd.Flags().Int64("log-max-rows", defaultDaemonLogMaxRows, "Keep at most this many request log entries per workspace (0 = no limit)")
+	daemonCmd.Flags().String("metrics-listen", "", "Also serve Prometheus metrics over HTTP at host:port/metrics")
+	daemonCmd.Flags().Bool("no-watch", false, "Don't import changes to the JSONL export made outside bd")
+	daemonCmd.Flags().Bool("auto", false, "Started on demand by --use-daemon or daemon.auto")
+	_ = daemonCmd.Flags().MarkHidden("auto")
+	rootCmd.AddCommand(daemonCmd)
+}
+
+// daemonDiscovery is .beads/daemon.json.
+type daemonDiscovery struct {
+	Network   string    `json:"network"` // "unix", "pipe" (Windows), or "tcp"
+	Address   string    `json:"address"`
+	Token     string    `json:"token,omitempty"`
+	PID       int       `json:"pid"`
+	StartedAt time.Time `json:"started_at"`
+	Auto      bool      `json:"auto,omitempty"` // started on demand by the CLI
+}
+
+func writeDaemonDiscovery(path string, info *daemonDiscovery) error {
+	data, err := json.MarshalIndent(info, "", "  ")
+	if err != nil {
+		return err
+	}
+	if err := os.WriteFile(path, append(data, '\n'), 0o600); err != nil {
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	return nil
+}
+
+// readDaemonDiscovery reads .beads/daemon.json; it returns nil if there is
+// no running daemon recorded.
+func readDaemonDiscovery(path string) (*daemonDiscovery, error) {
+	data, err := os.ReadFile(path) //nolint:gosec // G304: path is inside .beads/
+	if errors.Is(err, os.ErrNotExist) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	var info daemonDiscovery
+	if err := json.Unmarshal(data, &info); err != nil {
+		return nil, fmt.Errorf("invalid %s: %w", path, err)
+	}
+	return &info, nil
+}
+
+// removeDaemonDiscovery removes the discovery file if this process wrote
+// it; a second daemon that failed to start must not remove the first's.
+func removeDaemonDiscovery(path string) {
+	if info, err := readDaemonDiscovery(path); err == nil && info != nil && info.PID == os.Getpid() {
+		_ = os.Remove(path)
+	}
+}
+
+// daemonPipeName is the Windows named pipe of the daemon whose discovery
+// file is discoveryPath: \\.\pipe\beads-<hash>, unique per workspace.
+func daemonPipeName(discoveryPath string) string {
+	if abs, err := filepath.Abs(discoveryPath); err == nil {
+		discoveryPath = abs
+	}
+	sum := sha256.Sum256([]byte(strings.ToLower(filepath.Clean(discoveryPath))))
+	return `\\.\pipe\beads-` + hex.EncodeToString(sum[:8])
+}
+
+func newDaemonToken() (string, error) {
+	b := make([]byte, 32)
+	if _, err := rand.Read(b); err != nil {
+		return "", fmt.Errorf("failed to generate daemon token: %w", err)
+	}
+	return hex.EncodeToString(b), nil
+}
+
+// listenDaemonTCP listens on addr. Any client that can reach the port and
+// read .beads/daemon.json can use the daemon, so a non-loopback address
+// gets a warning.
+func listenDaemonTCP(addr string) (net.Listener, error) {
+	host, _, err := net.SplitHostPort(addr)
+	if err != nil {
+		return nil, fmt.Errorf("invalid --listen address %q: %w", addr, err)
+	}
+	if ip := net.ParseIP(host); host != "localhost" && (ip == nil || !ip.IsLoopback()) {
+		fmt.Fprintf(os.Stderr, "Warning: %s is reachable from other machines; requests still need the token in .beads/%s\n", addr, daemonDiscoveryName)
+	}
+	ln, err := net.Listen("tcp", addr)
+	if err != nil {
+		return nil, fmt.Errorf("failed to listen on %s: %w", addr, err)
+	}
+	return ln, nil
+}
+
+// listenDaemonSocket listens on path, replacing a socket left behind by a
+// daemon that exited without cleaning up. A socket that still answers
+// belongs to a running daemon and is left alone.
+func listenDaemonSocket(path string) (net.Listener, error) {
+	if _, err := os.Stat(path); err == nil {
+		if conn, err := net.DialTimeout("unix", path, time.Second); err == nil {
+			_ = conn.Close()
+			return nil, fmt.Errorf("a daemon is already listening on %s", path)
+		}
+		if err := os.Remove(path); err != nil {
+			return nil, fmt.Errorf("failed to remove stale socket %s: %w", path, err)
+		}
+	}
+	ln, err := net.Listen("unix", path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to listen on %s: %w", path, err)
+	}
+	return ln, nil
+}
+
+// daemonRequest is one request line.
+type daemonRequest struct {
+	Operation string          `json:"operation"`
+	Args      json.RawMessage `json:"args,omitempty"`
+	Cwd       string          `json:"cwd,omitempty"`
+	Actor     string          `json:"actor,omitempty"`
+	Token     string          `json:"token,omitempty"`
+}
+
+// daemonResponse is one response line.
+type daemonResponse struct {
+	Success bool        `json:"success"`
+	Data    interface{} `json:"data,omitempty"`
+	Error   string      `json:"error,omitempty"`
+	Code    int         `json:"code,omitempty"`    // JSON-RPC error code of Error
+	Details interface{} `json:"details,omitempty"` // JSON-RPC error data of Error
+}
+
+// daemonServer dispatches requests to the store. Each connection is
+// served on its own goroutine and reads run concurrently, but writes are
+// handled one at a time, like successive CLI invocations, so a write and
+// the Dolt commit that follows it are never interleaved with another
+// request.
+type daemonServer struct {
+	beadsDir       string
+	store          storage.DoltStorage
+	token          string        // required of every request when set (TCP)
+	idleTimeout    time.Duration // exit after this long without a request; 0 = never
+	queueTimeout   time.Duration // longest wait for a worker; 0 = none
+	requestTimeout time.Duration // longest a read may run once it has a worker; 0 = none
+	workers        chan struct{} // bounds concurrent requests when set; see run
+	watcher        *jsonlWatcher // auto-imports JSONL changes when set
+	log            *daemonLog    // records who made each request when set; see daemon_log.go
+	events         daemonEvents
+	mu             sync.RWMutex // held for reading by reads, for writing by writes
+
+	// global routes requests by cwd to the workspaces table instead of
+	// serving store; see daemon_global.go.
+	global       bool
+	workspaces   daemonWorkspaces
+	noAutoExport bool             // a workspace of the global daemon
+	logConfig    *daemonLogConfig // request log of each workspace; nil = none
+
+	startedAt  time.Time
+	addr       net.Addr           // where serve listens
+	stop       context.CancelFunc // ends serve
+	stats      daemonStats
+	lastActive atomic.Int64 // unix nanoseconds of the last request
+	inflight   atomic.Int32
+}
+
+// serve accepts connections until ctx is canceled or the daemon has been
+// idle for idleTimeout.
+func (d *daemonServer) serve(ctx context.Context, ln net.Listener) error {
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+	d.startedAt, d.addr, d.stop = time.Now().UTC(), ln.Addr(), cancel
+	d.lastActive.Store(time.Now().UnixNano())
+	if d.idleTimeout > 0 {
+		go d.exitWhenIdle(ctx, cancel)
+	}
+	if d.watcher != nil {
+		go d.watchJSONL(ctx)
+	}
+	go func() {
+		<-ctx.Done()
+		_ = ln.Close()
+	}()
+	if d.global {
+		defer d.closeWorkspaces()
+	}
+	var (
+		wg     sync.WaitGroup
+		connMu sync.Mutex
+		conns  = make(map[net.Conn]struct{})
+	)
+	err := func() error {
+		for {
+			conn, err := ln.Accept()
+			if err != nil {
+				if ctx.Err() != nil || errors.Is(err, net.ErrClosed) {
+					return nil
+				}
+				return fmt.Errorf("accept: %w", err)
+			}
+			connMu.Lock()
+			conns[conn] = struct{}{}
+			connMu.Unlock()
+			wg.Add(1)
+			go func() {
+				defer wg.Done()
+				defer func() {
+					connMu.Lock()
+					delete(conns, conn)
+					connMu.Unlock()
+				}()
+				d.serveConn(ctx, conn)
+			}()
+		}
+	}()
+
+	// Stop reading new requests, but let the ones being handled finish
+	// and be answered, for up to daemonShutdownGrace.
+	cancel()
+	drained := make(chan struct{})
+	go func() {
+		wg.Wait()
+		close(drained)
+	}()
+	select {
+	case <-drained:
+	case <-time.After(daemonShutdownGrace):
+		connMu.Lock()
+		fmt.Fprintf(os.Stderr, "bd daemon: %d connection(s) still busy after %s; closing them\n", len(conns), daemonShutdownGrace)
+		for conn := range conns {
+			_ = conn.Close()
+		}
+		connMu.Unlock()
+	}
+	return err
+}
+
+// exitWhenIdle cancels the daemon once it has gone idleTimeout without a
+// request. A request still being handled keeps it alive.
+func (d *daemonServer) exitWhenIdle(ctx context.Context, cancel context.CancelFunc) {
+	ticker := time.NewTicker(max(min(d.idleTimeout/4, time.Minute), 10*time.Millisecond))
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			idle := time.Since(time.Unix(0, d.lastActive.Load()))
+			if d.inflight.Load() == 0 && idle >= d.idleTimeout {
+				debug.Logf("daemon: idle for %s, exiting", idle.Round(time.Second))
+				cancel()
+				return
+			}
+		}
+	}
+}
+
+// serveConn answers each request line on conn until the client
+// disconnects or ctx is canceled. Cancellation stops reading; a request
+// already being handled is still answered.
+func (d *daemonServer) serveConn(ctx context.Context, conn net.Conn) {
+	defer func() { _ = conn.Close() }()
+	done := make(chan struct{})
+	defer close(done)
+	go func() {
+		select {
+		case <-ctx.Done():
+			_ = conn.SetReadDeadline(time.Now())
+		case <-done:
+		}
+	}()
+	ctx = withDaemonPeer(ctx, daemonPeer(conn))
+	reqCtx := context.WithoutCancel(ctx)
+	scanner := bufio.NewScanner(conn)
+	scanner.Buffer(make([]byte, 0, 64*1024), 64*1024*1024)
+	enc := json.NewEncoder(conn)
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text())
+		if line == "" {
+			continue
+		}
+		if sub, rpc, ok := parseDaemonSubscribe([]byte(line)); ok {
+			if d.subscribeConn(ctx, []byte(line), scanner, enc, sub, rpc) {
+				return
+			}
+			continue
+		}
+		resp := d.dispatchLine(reqCtx, []byte(line))
+		if resp == nil {
+			continue
+		}
+		if err := enc.Encode(resp); err != nil {
+			debug.Logf("daemon: write response: %v", err)
+			return
+		}
+	}
+	if err := scanner.Err(); errors.Is(err, bufio.ErrTooLong) {
+		_ = enc.Encode(daemonResponse{Error: "invalid request: line too long"})
+	}
+}
+
+// subscribeConn answers a subscribe request and, if it succeeded, writes
+// each event sub asks for to the connection until the client disconnects
+// or the daemon stops; it then reports true. Anything the client sends
+// while subscribed is ignored.
+func (d *daemonServer) subscribeConn(ctx context.Context, line []byte, scanner *bufio.Scanner, enc *json.Encoder, sub daemonSubscription, rpc bool) bool {
+	// Subscribe before answering so that no event falls in between. A
+	// global daemon subscribes to the workspace of the request's cwd; if
+	// that fails, so does the request, and nothing is streamed.
+	source := &d.events
+	if d.global {
+		if ws, err := d.workspaceFor(ctx, sub.Cwd); err == nil {
+			source = &ws.events
+		}
+	}
+	events, unsubscribe := source.subscribe()
+	defer unsubscribe()
+	resp := d.dispatchLine(ctx, line)
+	if resp != nil {
+		if err := enc.Encode(resp); err != nil {
+			debug.Logf("daemon: write response: %v", err)
+			return true
+		}
+	}
+	if !daemonResponseOK(resp) {
+		return false
+	}
+	// A subscriber keeps an idle-timeout daemon alive.
+	d.inflight.Add(1)
+	defer d.inflight.Add(-1)
+
+	gone := make(chan struct{})
+	go func() {
+		for scanner.Scan() {
+		}
+		close(gone)
+	}()
+	for {
+		select {
+		case <-ctx.Done():
+			return true
+		case <-gone:
+			return true
+		case ev := <-events:
+			if !sub.wants(ev.Type) {
+				continue
+			}
+			var msg interface{} = daemonResponse{Success: true, Data: ev}
+			if rpc {
+				msg = rpcNotification{JSONRPC: "2.0", Method: "event", Params: ev}
+			}
+			if err := enc.Encode(msg); err != nil {
+				debug.Logf("daemon: write event: %v", err)
+				return true
+			}
+		}
+	}
+}
+
+// handle runs one request and records it in the request log.
+func (d *daemonServer) handle(ctx context.Context, req *daemonRequest) (data interface{}, err error) {
+	d.inflight.Add(1)
+	defer func() {
+		d.lastActive.Store(time.Now().UnixNano())
+		d.inflight.Add(-1)
+	}()
+	actorName := req.Actor
+	if actorName == "" {
+		actorName = getActor()
+	}
+	var ids []string // the issues a write touched
+	start := time.Now()
+	defer func() { d.audit(ctx, req, actorName, ids, start, err) }()
+
+	if d.token != "" && subtle.ConstantTimeCompare([]byte(req.Token), []byte(d.token)) != 1 {
+		return nil, newDaemonError(rpcUnauthorized, "invalid or missing token (see .beads/%s)", daemonDiscoveryName)
+	}
+	if d.global {
+		return d.routeGlobal(ctx, req)
+	}
+	if req.Cwd != "" {
+		if dir := beads.FindBeadsDirFrom(req.Cwd); dir != "" && !sameDaemonPath(dir, d.beadsDir) {
+			return nil, newDaemonError(rpcWrongWorkspace, "this daemon serves %s, not %s", d.beadsDir, dir)
+		}
+	}
+	args := req.Args
+	if len(args) == 0 {
+		args = json.RawMessage("{}")
+	}
+
+	if req.Operation == "metrics" {
+		return d.metricsOp(ctx, args) // locks each workspace itself
+	}
+	if daemonWriteOps[req.Operation] {
+		d.mu.Lock()
+		defer d.mu.Unlock()
+		// Hold .beads/lock for the write and its export, like a CLI writer.
+		lock, err := acquireWriteLock(d.beadsDir, writeLockTimeout())
+		if err != nil {
+			return nil, err
+		}
+		defer lock.Release()
+	} else {
+		d.mu.RLock()
+		defer d.mu.RUnlock()
+	}
+	switch req.Operation {
+	case "ping":
+		return map[string]interface{}{"pong": true, "version": Version, "beads_dir": d.beadsDir}, nil
+	case "hello":
+		return d.hello(args)
+	case "capabilities":
+		return d.capabilities(), nil
+	case "status":
+		return d.status(), nil
+	case "shutdown":
+		return d.shutdown(), nil
+	case "list":
+		return d.list(ctx, args)
+	case "show":
+		return d.show(ctx, args)
+	case "ready":
+		return d.ready(ctx, args)
+	case "log":
+		return d.logOp(ctx, args)
+	case "subscribe":
+		// The connection switches to streaming events once this is
+		// answered; see serveConn.
+		var sub daemonSubscription
+		if err := decodeDaemonArgs(args, &sub); err != nil {
+			return nil, err
+		}
+		return map[string]interface{}{"subscribed": true, "types": sub.Types}, nil
+	case "create", "update", "close", "dep", "label", "batch":
+		data, ids, err = d.write(ctx, req.Operation, args, actorName)
+		if err != nil {
+			return nil, err
+		}
+		if err := maybeAutoCommitStore(ctx, d.store, doltAutoCommitParams{Command: req.Operation, IssueIDs: ids}); err != nil {
+			return nil, fmt.Errorf("dolt auto-commit failed: %w", err)
+		}
+		if !d.noAutoExport {
+			err := maybeAutoExport(ctx, req.Operation, serverMode, false)
+			d.stats.recordExport(err)
+			if err != nil {
+				debug.Logf("daemon: auto-export: %v", err)
+			}
+		}
+		if d.watcher != nil {
+			d.watcher.changed() // our own export is not a change to import
+		}
+		if batch, ok := data.(*daemonBatchResult); ok {
+			for _, item := range batch.Results {
+				d.events.publish(daemonEvent{Type: daemonWriteEvent(item.Operation, item.args), IssueIDs: item.IssueIDs})
+			}
+		} else {
+			d.events.publish(daemonEvent{Type: daemonWriteEvent(req.Operation, args), IssueIDs: ids})
+		}
+		return data, nil
+	case "":
+		return nil, newDaemonError(rpcInvalidRequest, "missing operation")
+	default:
+		return nil, d.unknownDaemonOperation(req.Operation)
+	}
+}
+
+// daemonWriteOps are the operations that change the store.
+var daemonWriteOps = map[string]bool{"create": true, "update": true, "close": true, "dep": true, "label": true, "batch": true}
+
+// daemonWriteEvent names the event a successful write publishes.
+func daemonWriteEvent(op string, args json.RawMessage) string {
+	switch op {
+	case "create":
+		return "created"
+	case "close":
+		return "closed"
+	case "update":
+		var claim struct {
+			Claim bool `json:"claim"`
+		}
+		if json.Unmarshal(args, &claim) == nil && claim.Claim {
+			return "claimed"
+		}
+	}
+	return "updated"
+}
+
+// daemonWriteStore is what the write operations change: the store, or a
+// daemonBatchTx for the items of a batch.
+type daemonWriteStore interface {
+	issueWriteStore
+}
+
+// resolveID resolves a partial issue ID as the CLI does. An ID st already
+// has is taken as is, so a batch item can name an issue an earlier item
+// created, which only the batch's transaction sees.
+func (d *daemonServer) resolveID(ctx context.Context, st daemonWriteStore, id string) (string, error) {
+	if _, err := st.GetIssue(ctx, id); err == nil {
+		return id, nil
+	}
+	resolved, err := utils.ResolvePartialID(ctx, d.store, id)
+	if err != nil {
+		if isNotFoundErr(err) {
+			return "", newDaemonError(rpcNotFound, "%v", err)
+		}
+		return "", err
+	}
+	return resolved, nil
+}
+
+// write runs a write operation against the store. It returns the
+// operation's result and the issues it touched.
+func (d *daemonServer) write(ctx context.Context, op string, args json.RawMessage, actorName string) (interface{}, []string, error) {
+	if op == "batch" {
+		return d.batch(ctx, args, actorName)
+	}
+	return d.writeTo(ctx, d.store, op, args, actorName)
+}
+
+func (d *daemonServer) writeTo(ctx context.Context, st daemonWriteStore, op string, args json.RawMessage, actorName string) (interface{}, []string, error) {
+	switch op {
+	case "create":
+		return d.create(ctx, st, args, actorName)
+	case "update":
+		return d.update(ctx, st, args, actorName)
+	case "close":
+		return d.close(ctx, st, args, actorName)
+	case "dep":
+		return d.dep(ctx, st, args, actorName)
+	default:
+		return d.label(ctx, st, args, actorName)
+	}
+}
+
+func sameDaemonPath(a, b string) bool {
+	if ra, err := filepath.EvalSymlinks(a); err == nil {
+		a = ra
+	}
+	if rb, err := filepath.EvalSymlinks(b); err == nil {
+		b = rb
+	}
+	return filepath.Clean(a) == filepath.Clean(b)
+}
+
+func decodeDaemonArgs(args json.RawMessage, v interface{}) error {
+	if err := json.Unmarshal(args, v); err != nil {
+		return newDaemonError(rpcInvalidParams, "invalid args: %v", err)
+	}
+	return nil
+}
+
+// daemonIDs returns the "id" or "ids" of a request.
+func daemonIDs(id string, ids []string) ([]string, error) {
+	if id != "" {
+		ids = append([]string{id}, ids...)
+	}
+	if len(ids) == 0 {
+		return nil, newDaemonError(rpcInvalidParams, "missing id")
+	}
+	return ids, nil
+}
+
+func (d *daemonServer) list(ctx context.Context, raw json.RawMessage) (interface{}, error) {
+	var args struct {
+		Status   string   `json:"status"`
+		Priority *int     `json:"priority"`
+		Type     string   `json:"type"`
+		Assignee string   `json:"assignee"`
+		Labels   []string `json:"labels"`
+		Query    string   `json:"query"`
+		Limit    int      `json:"limit"`
+		All      bool     `json:"all"`
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, err
+	}
+	filter := types.IssueFilter{Priority: args.Priority, Labels: args.Labels, Limit: args.Limit}
+	if args.Status != "" {
+		for _, s := range strings.Split(args.Status, ",") {
+			filter.Statuses = append(filter.Statuses, types.Status(strings.TrimSpace(s)))
+		}
+	} else if !args.All {
+		// Same default as 'bd list'.
+		filter.ExcludeStatus = []types.Status{types.StatusClosed, types.StatusPinned}
+	}
+	if args.Type != "" {
+		t := types.IssueType(args.Type)
+		filter.IssueType = &t
+	}
+	if args.Assignee != "" {
+		filter.Assignee = &args.Assignee
+	}
+	issues, err := d.store.SearchIssues(ctx, args.Query, filter)
+	if err != nil {
+		return nil, err
+	}
+	if issues == nil {
+		issues = []*types.Issue{}
+	}
+	return issues, nil
+}
+
+func (d *daemonServer) show(ctx context.Context, raw json.RawMessage) (interface{}, error) {
+	var args struct {
+		ID      string   `json:"id"`
+		IDs     []string `json:"ids"`
+		Details bool     `json:"details"`
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, err
+	}
+	ids, err := daemonIDs(args.ID, args.IDs)
+	if err != nil {
+		return nil, err
+	}
+	for i, id := range ids {
+		if ids[i], err = d.resolveID(ctx, d.store, id); err != nil {
+			return nil, err
+		}
+	}
+	if args.Details {
+		// The records 'bd show --json' prints.
+		details := make([]*types.IssueDetails, 0, len(ids))
+		for _, id := range ids {
+			issue, err := d.store.GetIssue(ctx, id)
+			if err != nil {
+				return nil, fmt.Errorf("%s: %w", id, err)
+			}
+			details = append(details, showIssueDetails(ctx, d.store, issue))
+		}
+		return details, nil
+	}
+	var issues []*types.Issue
+	for _, id := range ids {
+		issue, err := d.store.GetIssue(ctx, id)
+		if err != nil {
+			return nil, fmt.Errorf("%s: %w", id, err)
+		}
+		if issue.Labels, err = d.store.GetLabels(ctx, id); err != nil {
+			return nil, err
+		}
+		deps, err := d.store.GetDependencyRecordsForIssues(ctx, []string{id})
+		if err != nil {
+			return nil, err
+		}
+		issue.Dependencies = deps[id]
+		if issue.Comments, err = d.store.GetIssueComments(ctx, id); err != nil {
+			return nil, err
+		}
+		issues = append(issues, issue)
+	}
+	if args.ID != "" && len(args.IDs) == 0 {
+		return issues[0], nil
+	}
+	return issues, nil
+}
+
+func (d *daemonServer) ready(ctx context.Context, raw json.RawMessage) (interface{}, error) {
+	var args struct {
+		Type       string   `json:"type"`
+		Priority   *int     `json:"priority"`
+		Assignee   string   `json:"assignee"`
+		Unassigned bool     `json:"unassigned"`
+		Labels     []string `json:"labels"`
+		LabelsAny  []string `json:"labels_any"`
+		Sort       string   `json:"sort"`
+		Limit      int      `json:"limit"`
+		Counts     bool     `json:"counts"`
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, err
+	}
+	// Same defaults as 'bd ready'.
+	filter := types.WorkFilter{
+		Status:        "open",
+		Type:          args.Type,
+		Priority:      args.Priority,
+		Unassigned:    args.Unassigned,
+		Labels:        args.Labels,
+		LabelsAny:     args.LabelsAny,
+		Limit:         args.Limit,
+		SortPolicy:    types.SortPolicyPriority,
+		PriorityAging: priorityAgingInterval(),
+	}
+	if args.Sort != "" {
+		filter.SortPolicy = types.SortPolicy(args.Sort)
+		if !filter.SortPolicy.IsValid() {
+			return nil, newDaemonError(rpcInvalidParams, "invalid sort policy %q", args.Sort)
+		}
+	}
+	if args.Assignee != "" && !args.Unassigned {
+		filter.Assignee = &args.Assignee
+	}
+	// Contributor auto-routing, as in 'bd ready'.
+	activeStore := d.store
+	routedStore, routed, err := openRoutedReadStore(ctx, d.store)
+	if err != nil {
+		return nil, err
+	}
+	if routed {
+		defer func() { _ = routedStore.Close() }()
+		activeStore = routedStore
+	}
+	if args.Counts {
+		// The records 'bd ready --json' prints.
+		issues, err := getReadyWorkWithCounts(ctx, activeStore, filter)
+		if err != nil {
+			return nil, err
+		}
+		if issues == nil {
+			issues = []*types.IssueWithCounts{}
+		}
+		return issues, nil
+	}
+	issues, err := getReadyWork(ctx, activeStore, filter)
+	if err != nil {
+		return nil, err
+	}
+	if issues == nil {
+		issues = []*types.Issue{}
+	}
+	return issues, nil
+}
+
+func (d *daemonServer) create(ctx context.Context, st daemonWriteStore, raw json.RawMessage, actorName string) (interface{}, []string, error) {
+	var args struct {
+		ID          string   `json:"id"`
+		Title       string   `json:"title"`
+		Description string   `json:"description"`
+		Type        string   `json:"type"`
+		Priority    *string  `json:"priority"`
+		Assignee    string   `json:"assignee"`
+		Labels      []string `json:"labels"`
+		Parent      string   `json:"parent"`
+		Deps        []string `json:"deps"`
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, nil, err
+	}
+	if strings.TrimSpace(args.Title) == "" {
+		return nil, nil, newDaemonError(rpcInvalidParams, "title is required")
+	}
+	issue := &types.Issue{
+		ID:          args.ID,
+		Title:       args.Title,
+		Description: args.Description,
+		Status:      types.StatusOpen,
+		Priority:    2,
+		IssueType:   types.TypeTask,
+		Assignee:    args.Assignee,
+		Labels:      args.Labels,
+		CreatedBy:   actorName,
+	}
+	if args.Type != "" {
+		issue.IssueType = types.IssueType(args.Type).Normalize()
+	}
+	if args.Priority != nil {
+		p, err := validation.ValidatePriority(*args.Priority)
+		if err != nil {
+			return nil, nil, newDaemonError(rpcInvalidParams, "%v", err)
+		}
+		issue.Priority = p
+	}
+	if err := checkNewIssue(ctx, st, issue); err != nil {
+		return nil, nil, newDaemonError(rpcInvalidParams, "%v", err)
+	}
+
+	parent := args.Parent
+	if parent != "" {
+		var err error
+		if parent, err = d.resolveID(ctx, st, parent); err != nil {
+			return nil, nil, fmt.Errorf("parent %s: %w", args.Parent, err)
+		}
+	}
+	// Same "type:id" or "id" form as 'bd create --deps'.
+	var deps []createDepSpec
+	for _, spec := range args.Deps {
+		parsed, ok, err := parseCreateDepSpec(spec)
+		if err != nil {
+			return nil, nil, newDaemonError(rpcInvalidParams, "%v", err)
+		}
+		if !ok {
+			continue
+		}
+		if parsed.Target, err = d.resolveID(ctx, st, parsed.Target); err != nil {
+			return nil, nil, fmt.Errorf("dependency %s: %w", spec, err)
+		}
+		deps = append(deps, parsed)
+	}
+	inheritSourceRepo(ctx, st, issue, deps)
+
+	if err := st.CreateIssue(ctx, issue, actorName); err != nil {
+		return nil, nil, err
+	}
+	if parent != "" {
+		dep := &types.Dependency{IssueID: issue.ID, DependsOnID: parent, Type: types.DepParentChild}
+		if err := st.AddDependency(ctx, dep, actorName); err != nil {
+			return nil, nil, fmt.Errorf("created %s but failed to add parent %s: %w", issue.ID, parent, err)
+		}
+	}
+	for _, spec := range deps {
+		if err := st.AddDependency(ctx, spec.dependency(issue.ID), actorName); err != nil {
+			return nil, nil, fmt.Errorf("created %s but failed to add dependency on %s: %w", issue.ID, spec.Target, err)
+		}
+	}
+	return issue, []string{issue.ID}, nil
+}
+
+func (d *daemonServer) update(ctx context.Context, st daemonWriteStore, raw json.RawMessage, actorName string) (interface{}, []string, error) {
+	var args struct {
+		ID                 string  `json:"id"`
+		Title              *string `json:"title"`
+		Description        *string `json:"description"`
+		Status             *string `json:"status"`
+		Priority           *string `json:"priority"`
+		Assignee           *string `json:"assignee"`
+		Notes              *string `json:"notes"`
+		Design             *string `json:"design"`
+		AcceptanceCriteria *string `json:"acceptance_criteria"`
+		Claim              bool    `json:"claim"`
+		Force              bool    `json:"force"`
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, nil, err
+	}
+	if args.ID == "" {
+		return nil, nil, newDaemonError(rpcInvalidParams, "missing id")
+	}
+	updates := map[string]interface{}{}
+	for key, v := range map[string]*string{
+		"title":               args.Title,
+		"description":         args.Description,
+		"status":              args.Status,
+		"assignee":            args.Assignee,
+		"notes":               args.Notes,
+		"design":              args.Design,
+		"acceptance_criteria": args.AcceptanceCriteria,
+	} {
+		if v != nil {
+			updates[key] = *v
+		}
+	}
+	if args.Title != nil && strings.TrimSpace(*args.Title) == "" {
+		return nil, nil, newDaemonError(rpcInvalidParams, "title cannot be empty")
+	}
+	if args.Status != nil {
+		custom, err := d.store.GetCustomStatuses(ctx)
+		if err != nil {
+			return nil, nil, err
+		}
+		if !types.Status(*args.Status).IsValidWithCustom(custom) {
+			return nil, nil, newDaemonError(rpcInvalidParams, "invalid status %q", *args.Status)
+		}
+	}
+	if args.Priority != nil {
+		p, err := validation.ValidatePriority(*args.Priority)
+		if err != nil {
+			return nil, nil, newDaemonError(rpcInvalidParams, "%v", err)
+		}
+		updates["priority"] = p
+	}
+	if len(updates) == 0 && !args.Claim {
+		return nil, nil, newDaemonError(rpcInvalidParams, "no fields to update")
+	}
+	// A claim commits on its own, so a batch can't make it.
+	if _, inBatch := st.(*daemonBatchTx); inBatch && args.Claim {
+		return nil, nil, newDaemonError(rpcInvalidParams, "claim can't be part of a batch; claim before or after it")
+	}
+	id, err := d.resolveID(ctx, st, args.ID)
+	if err != nil {
+		return nil, nil, err
+	}
+	issue, err := st.GetIssue(ctx, id)
+	if err != nil {
+		return nil, nil, fmt.Errorf("%s: %w", id, err)
+	}
+	// Like 'bd update': claim first, atomically, then apply the other
+	// fields.
+	after, err := updateIssueChecked(ctx, st, d.store, issue, updates, issueUpdateOptions{
+		Claim: args.Claim,
+		Force: args.Force,
+	}, actorName)
+	if err != nil {
+		return nil, nil, err
+	}
+	afterDaemonWrite(st, after)
+	if issue, err = st.GetIssue(ctx, id); err != nil {
+		return nil, nil, err
+	}
+	return issue, []string{id}, nil
+}
+
+func (d *daemonServer) close(ctx context.Context, st daemonWriteStore, raw json.RawMessage, actorName string) (interface{}, []string, error) {
+	var args struct {
+		ID     string   `json:"id"`
+		IDs    []string `json:"ids"`
+		Reason string   `json:"reason"`
+		Force  bool     `json:"force"`
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, nil, err
+	}
+	ids, err := daemonIDs(args.ID, args.IDs)
+	if err != nil {
+		return nil, nil, err
+	}
+	reason := args.Reason
+	if reason == "" {
+		reason = "Closed"
+	}
+	if err := validateCloseReasons([]string{reason}); err != nil {
+		return nil, nil, newDaemonError(rpcInvalidParams, "%v", err)
+	}
+	var closed []*types.Issue
+	for i, id := range ids {
+		if ids[i], err = d.resolveID(ctx, st, id); err != nil {
+			return nil, nil, err
+		}
+		id = ids[i]
+		issue, err := st.GetIssue(ctx, id)
+		if err != nil {
+			return nil, nil, fmt.Errorf("%s: %w", id, err)
+		}
+		// Like 'bd close', but any refusal fails the request.
+		after, err := closeIssueChecked(ctx, st, d.store, id, issue, reason, actorName, "", args.Force)
+		if err != nil {
+			return nil, nil, err
+		}
+		afterDaemonWrite(st, after)
+		if issue, err = st.GetIssue(ctx, id); err != nil {
+			return nil, nil, err
+		}
+		closed = append(closed, issue)
+	}
+	return closed, ids, nil
+}
+
+func (d *daemonServer) dep(ctx context.Context, st daemonWriteStore, raw json.RawMessage, actorName string) (interface{}, []string, error) {
+	var args struct {
+		Action      string `json:"action"`
+		IssueID     string `json:"issue_id"`
+		DependsOnID string `json:"depends_on_id"`
+		Type        string `json:"type"`
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, nil, err
+	}
+	if args.IssueID == "" || args.DependsOnID == "" {
+		return nil, nil, newDaemonError(rpcInvalidParams, "issue_id and depends_on_id are required")
+	}
+	var err error
+	if args.IssueID, err = d.resolveID(ctx, st, args.IssueID); err != nil {
+		return nil, nil, err
+	}
+	if args.DependsOnID, err = d.resolveID(ctx, st, args.DependsOnID); err != nil {
+		return nil, nil, err
+	}
+	switch args.Action {
+	case "", "add":
+		dep := &types.Dependency{IssueID: args.IssueID, DependsOnID: args.DependsOnID, Type: types.DepBlocks}
+		if args.Type != "" {
+			dep.Type = types.DependencyType(args.Type)
+		}
+		if err := st.AddDependency(ctx, dep, actorName); err != nil {
+			return nil, nil, err
+		}
+		return dep, []string{args.IssueID}, nil
+	case "remove":
+		if err := st.RemoveDependency(ctx, args.IssueID, args.DependsOnID, actorName); err != nil {
+			return nil, nil, err
+		}
+		return map[string]string{"issue_id": args.IssueID, "depends_on_id": args.DependsOnID, "status": "removed"}, []string{args.IssueID}, nil
+	default:
+		return nil, nil, newDaemonError(rpcInvalidParams, "unknown dep action %q (want add or remove)", args.Action)
+	}
+}
+
+func (d *daemonServer) label(ctx context.Context, st daemonWriteStore, raw json.RawMessage, actorName string) (interface{}, []string, error) {
+	var args struct {
+		Action  string   `json:"action"`
+		IssueID string   `json:"issue_id"`
+		Label   string   `json:"label"`
+		Labels  []string `json:"labels"`
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, nil, err
+	}
+	labels := args.Labels
+	if args.Label != "" {
+		labels = append([]string{args.Label}, labels...)
+	}
+	if args.IssueID == "" || len(labels) == 0 {
+		return nil, nil, newDaemonError(rpcInvalidParams, "issue_id and label are required")
+	}
+	issueID, err := d.resolveID(ctx, st, args.IssueID)
+	if err != nil {
+		return nil, nil, err
+	}
+	args.IssueID = issueID
+	for _, label := range labels {
+		switch args.Action {
+		case "", "add":
+			err = st.AddLabel(ctx, args.IssueID, label, actorName)
+		case "remove":
+			err = st.RemoveLabel(ctx, args.IssueID, label, actorName)
+		default:
+			return nil, nil, newDaemonError(rpcInvalidParams, "unknown label action %q (want add or remove)", args.Action)
+		}
+		if err != nil {
+			return nil, nil, err
+		}
+	}
+	current, err := st.GetLabels(ctx, args.IssueID)
+	if err != nil {
+		return nil, nil, err
+	}
+	return map[string]interface{}{"issue_id": args.IssueID, "labels": current}, []string{args.IssueID}, nil
+}
@@ -0,0 +1,204 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/storage"
+)
+
+// maxDaemonBatchItems bounds the operations of one batch.
+const maxDaemonBatchItems = 500
+
+// daemonBatchItem is one operation of a batch: a write operation and its
+// args, as they would be sent on their own.
+type daemonBatchItem struct {
+	Operation string          `json:"operation"`
+	Args      json.RawMessage `json:"args"`
+}
+
+// daemonBatchItemResult is the outcome of one item of a batch.
+type daemonBatchItemResult struct {
+	Index     int         `json:"index"`
+	Operation string      `json:"operation"`
+	Success   bool        `json:"success"`
+	Data      interface{} `json:"data,omitempty"`
+	Error     string      `json:"error,omitempty"`
+	IssueIDs  []string    `json:"issue_ids,omitempty"`
+
+	args json.RawMessage // with references resolved, for its write event
+}
+
+// daemonBatchResult is the result of a batch that was committed.
+type daemonBatchResult struct {
+	Results []*daemonBatchItemResult `json:"results"`
+}
+
+// daemonBatchRefKeys are the args in which "$N" stands for the first
+// issue of item N, e.g. the ID of an issue created earlier in the batch.
+var daemonBatchRefKeys = map[string]bool{
+	"id": true, "ids": true, "parent": true, "deps": true, "issue_id": true, "depends_on_id": true,
+}
+
+// batch runs the write operations in args.ops in one transaction and one
+// Dolt commit, in order. If any fails, none is written: the error names
+// the item, and its data holds the results up to and including it.
+func (d *daemonServer) batch(ctx context.Context, raw json.RawMessage, actorName string) (interface{}, []string, error) {
+	var args struct {
+		Ops     []daemonBatchItem `json:"ops"`
+		Message string            `json:"message"` // Dolt commit message
+	}
+	if err := decodeDaemonArgs(raw, &args); err != nil {
+		return nil, nil, err
+	}
+	if len(args.Ops) == 0 {
+		return nil, nil, newDaemonError(rpcInvalidParams, "ops is required")
+	}
+	if len(args.Ops) > maxDaemonBatchItems {
+		return nil, nil, newDaemonError(rpcInvalidParams, "too many ops: %d (at most %d)", len(args.Ops), maxDaemonBatchItems)
+	}
+	for i, item := range args.Ops {
+		if !daemonWriteOps[item.Operation] || item.Operation == "batch" {
+			return nil, nil, newDaemonError(rpcInvalidParams, "ops[%d]: %q can't be batched (want create, update, close, dep, or label)", i, item.Operation)
+		}
+	}
+	msg := args.Message
+	if msg == "" {
+		msg = fmt.Sprintf("bd: daemon batch of %d operations", len(args.Ops))
+	}
+
+	var results []*daemonBatchItemResult
+	var batchTx *daemonBatchTx
+	err := d.store.RunInTransaction(ctx, msg, func(tx storage.Transaction) error {
+		results = nil // a retried transaction starts over
+		batchTx = &daemonBatchTx{Transaction: tx}
+		for i, item := range args.Ops {
+			r := &daemonBatchItemResult{Index: i, Operation: item.Operation}
+			results = append(results, r)
+			itemArgs, err := resolveDaemonBatchRefs(item.Args, results[:i])
+			if err == nil {
+				r.args = itemArgs
+				r.Data, r.IssueIDs, err = d.writeTo(ctx, batchTx, item.Operation, itemArgs, actorName)
+			}
+			if err != nil {
+				r.Error = err.Error()
+				return &daemonBatchItemError{index: i, err: err}
+			}
+			r.Success = true
+		}
+		return nil
+	})
+	var itemErr *daemonBatchItemError
+	if errors.As(err, &itemErr) {
+		return nil, nil, &daemonError{
+			code: rpcErrorCode(itemErr.err),
+			msg:  fmt.Sprintf("ops[%d] (%s): %v; nothing was written", itemErr.index, args.Ops[itemErr.index].Operation, itemErr.err),
+			data: map[string]interface{}{"index": itemErr.index, "results": results},
+		}
+	}
+	if err != nil {
+		return nil, nil, fmt.Errorf("batch: %w", err)
+	}
+	for _, after := range batchTx.afterCommit {
+		after()
+	}
+
+	var ids []string
+	seen := make(map[string]bool)
+	for _, r := range results {
+		for _, id := range r.IssueIDs {
+			if !seen[id] {
+				seen[id] = true
+				ids = append(ids, id)
+			}
+		}
+	}
+	return &daemonBatchResult{Results: results}, ids, nil
+}
+
+// daemonBatchTx is the transaction the items of a batch write to. It holds
+// back what the shared write helpers do once a write is committed, such
+// as audit logging, until the batch commits.
+type daemonBatchTx struct {
+	storage.Transaction
+	afterCommit []func()
+}
+
+// afterDaemonWrite runs after, the follow-up a shared write helper
+// returned, once st's writes are committed: now, or when st is a batch,
+// after it commits.
+func afterDaemonWrite(st daemonWriteStore, after func()) {
+	if tx, ok := st.(*daemonBatchTx); ok {
+		tx.afterCommit = append(tx.afterCommit, after)
+		return
+	}
+	after()
+}
+
+// daemonBatchItemError is the failure of one item, which rolls back the
+// batch.
+type daemonBatchItemError struct {
+	index int
+	err   error
+}
+
+func (e *daemonBatchItemError) Error() string { return e.err.Error() }
+func (e *daemonBatchItemError) Unwrap() error { return e.err }
+
+// resolveDaemonBatchRefs replaces "$N" (or "type:$N" in deps) in the ID
+// args of an item with the first issue of the done item N.
+func resolveDaemonBatchRefs(raw json.RawMessage, done []*daemonBatchItemResult) (json.RawMessage, error) {
+	if len(raw) == 0 {
+		return json.RawMessage("{}"), nil
+	}
+	var args map[string]interface{}
+	if err := json.Unmarshal(raw, &args); err != nil {
+		return nil, newDaemonError(rpcInvalidParams, "invalid args: %v", err)
+	}
+	var resolveErr error
+	resolve := func(v interface{}) interface{} {
+		s, ok := v.(string)
+		if !ok {
+			return v
+		}
+		prefix, ref := "", s
+		if i := strings.LastIndex(s, ":$"); i >= 0 {
+			prefix, ref = s[:i+1], s[i+1:]
+		}
+		if !strings.HasPrefix(ref, "$") {
+			return v
+		}
+		n, err := strconv.Atoi(ref[1:])
+		switch {
+		case err != nil:
+			return v
+		case n < 0 || n >= len(done):
+			resolveErr = newDaemonError(rpcInvalidParams, "%s refers to no earlier op", ref)
+		case len(done[n].IssueIDs) == 0:
+			resolveErr = newDaemonError(rpcInvalidParams, "%s: ops[%d] touched no issue", ref, n)
+		default:
+			return prefix + done[n].IssueIDs[0]
+		}
+		return v
+	}
+	for key, v := range args {
+		if !daemonBatchRefKeys[key] {
+			continue
+		}
+		if list, ok := v.([]interface{}); ok {
+			for i := range list {
+				list[i] = resolve(list[i])
+			}
+		} else {
+			args[key] = resolve(v)
+		}
+	}
+	if resolveErr != nil {
+		return nil, resolveErr
+	}
+	return json.Marshal(args)
+}
@@ -0,0 +1,226 @@
+package main
+
+import (
+	"encoding/json"
+	"fmt"
+	"net"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"slices"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/spf13/pflag"
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+// daemonStartTimeout is how long the CLI waits for an auto-started daemon
+// to write its discovery file and accept connections.
+const daemonStartTimeout = 10 * time.Second
+
+// daemonForwardFlags lists the commands the daemon can answer for the CLI
+// (--use-daemon / daemon.auto) and the flags each may be given. An
+// invocation using any other flag runs directly.
+var daemonForwardFlags = map[string][]string{
+	"show":  nil,
+	"ready": {"limit", "priority", "assignee", "unassigned", "sort", "label", "type"},
+}
+
+// daemonForward is a CLI invocation translated into a daemon request.
+type daemonForward struct {
+	method string
+	params map[string]interface{}
+	out    interface{} // decoded result, printed as the command's --json output
+}
+
+// forwardToDaemon answers cmd from the workspace daemon, starting one if
+// none is running, and reports whether it did. On any failure it logs the
+// reason and returns false so that the command runs directly.
+func forwardToDaemon(cmd *cobra.Command, args []string, beadsDir string) bool {
+	if !jsonOutput {
+		return false
+	}
+	fwd := daemonForwardFor(cmd, args)
+	if fwd == nil {
+		return false
+	}
+	info, err := ensureDaemon(beadsDir)
+	if err != nil {
+		debug.Logf("daemon: %v; running %s directly", err, cmd.Name())
+		return false
+	}
+	if err := callDaemon(info, fwd.method, fwd.params, fwd.out); err != nil {
+		debug.Logf("daemon: %s: %v; running %s directly", fwd.method, err, cmd.Name())
+		return false
+	}
+	outputJSON(fwd.out)
+	if cmd.Name() == "show" {
+		SetLastTouchedID(args[0])
+	}
+	return true
+}
+
+// daemonForwardFor translates a top-level show or ready invocation into a
+// daemon request whose result matches the command's --json output. It
+// returns nil for anything else.
+func daemonForwardFor(cmd *cobra.Command, args []string) *daemonForward {
+	allowed, ok := daemonForwardFlags[cmd.Name()]
+	if !ok || cmd.Parent() != cmd.Root() {
+		return nil
+	}
+	supported := true
+	cmd.LocalNonPersistentFlags().Visit(func(f *pflag.Flag) {
+		if !slices.Contains(allowed, f.Name) {
+			supported = false
+		}
+	})
+	if !supported {
+		return nil
+	}
+
+	switch cmd.Name() {
+	case "show":
+		if len(args) == 0 {
+			return nil
+		}
+		return &daemonForward{
+			method: "show",
+			params: map[string]interface{}{"ids": args, "details": true},
+			out:    &[]*types.IssueDetails{},
+		}
+	default:
+		limit, _ := cmd.Flags().GetInt("limit")
+		sortPolicy, _ := cmd.Flags().GetString("sort")
+		assignee, _ := cmd.Flags().GetString("assignee")
+		unassigned, _ := cmd.Flags().GetBool("unassigned")
+		labels, _ := cmd.Flags().GetStringSlice("label")
+		issueType, _ := cmd.Flags().GetString("type")
+		params := map[string]interface{}{
+			"counts":     true,
+			"limit":      limit,
+			"sort":       sortPolicy,
+			"assignee":   assignee,
+			"unassigned": unassigned,
+			"labels":     utils.NormalizeLabels(labels),
+			"type":       utils.NormalizeIssueType(issueType),
+		}
+		if cmd.Flags().Changed("priority") {
+			params["priority"], _ = cmd.Flags().GetInt("priority")
+		}
+		// Directory-aware label scoping, as in 'bd ready' (GH#541).
+		if len(labels) == 0 {
+			if dirLabels := config.GetDirectoryLabels(); len(dirLabels) > 0 {
+				params["labels_any"] = dirLabels
+			}
+		}
+		return &daemonForward{method: "ready", params: params, out: &[]*types.IssueWithCounts{}}
+	}
+}
+
+// ensureDaemon returns the discovery record of the daemon to ask: a
+// running global daemon ('bd daemon --global'), else the workspace's own,
+// which is started in the background if none answers.
+func ensureDaemon(beadsDir string) (*daemonDiscovery, error) {
+	if dir, err := globalDaemonDir(); err == nil {
+		if info, _ := readDaemonDiscovery(filepath.Join(dir, globalDaemonDiscoveryName)); info != nil && daemonAnswers(info) {
+			return info, nil
+		}
+	}
+	path := filepath.Join(beadsDir, daemonDiscoveryName)
+	if info, _ := readDaemonDiscovery(path); info != nil && daemonAnswers(info) {
+		return info, nil
+	}
+	if err := startDaemon(beadsDir); err != nil {
+		return nil, err
+	}
+	deadline := time.Now().Add(daemonStartTimeout)
+	for time.Now().Before(deadline) {
+		time.Sleep(50 * time.Millisecond)
+		if info, _ := readDaemonDiscovery(path); info != nil && daemonAnswers(info) {
+			return info, nil
+		}
+	}
+	return nil, fmt.Errorf("daemon did not start within %s (see %s)", daemonStartTimeout, filepath.Join(beadsDir, "daemon.log"))
+}
+
+func daemonAnswers(info *daemonDiscovery) bool {
+	conn, err := dialDaemon(info, time.Second)
+	if err != nil {
+		return false
+	}
+	_ = conn.Close()
+	return true
+}
+
+// startDaemon launches 'bd daemon' detached from this process, logging to
+// .beads/daemon.log. It exits after daemon.idle_timeout without requests.
+func startDaemon(beadsDir string) error {
+	exe, err := os.Executable()
+	if err != nil {
+		return fmt.Errorf("failed to locate bd executable: %w", err)
+	}
+	logFile, err := os.OpenFile(filepath.Join(beadsDir, "daemon.log"), os.O_CREATE|os.O_WRONLY|os.O_APPEND, 0o600) //nolint:gosec // G304: path is inside .beads/
+	if err != nil {
+		return fmt.Errorf("failed to open daemon log: %w", err)
+	}
+	defer func() { _ = logFile.Close() }()
+
+	c := exec.Command(exe, "daemon", "--auto", "--idle-timeout", config.GetDuration("daemon.idle_timeout").String()) //nolint:gosec // G204: exe is this binary
+	c.Dir = filepath.Dir(beadsDir)
+	c.Env = append(os.Environ(), "BEADS_DIR="+beadsDir)
+	c.Stdout = logFile
+	c.Stderr = logFile
+	c.SysProcAttr = daemonProcAttr()
+	if err := c.Start(); err != nil {
+		return fmt.Errorf("failed to start daemon: %w", err)
+	}
+	debug.Logf("daemon: started pid %d for %s", c.Process.Pid, beadsDir)
+	return c.Process.Release()
+}
+
+// dialDaemon connects to the daemon described by info, over whichever
+// transport it listens on.
+func dialDaemon(info *daemonDiscovery, timeout time.Duration) (net.Conn, error) {
+	if info.Network == "pipe" {
+		return dialDaemonPipe(info.Address, timeout)
+	}
+	return net.DialTimeout(info.Network, info.Address, timeout)
+}
+
+// callDaemon sends one JSON-RPC request and decodes its result into out.
+func callDaemon(info *daemonDiscovery, method string, params map[string]interface{}, out interface{}) error {
+	conn, err := dialDaemon(info, time.Second)
+	if err != nil {
+		return err
+	}
+	defer func() { _ = conn.Close() }()
+	_ = conn.SetDeadline(time.Now().Add(time.Minute))
+
+	if info.Token != "" {
+		params["token"] = info.Token
+	}
+	if cwd, err := os.Getwd(); err == nil {
+		params["cwd"] = cwd
+	}
+	params["actor"] = getActor()
+	rawParams, err := json.Marshal(params)
+	if err != nil {
+		return err
+	}
+	req := rpcRequest{JSONRPC: "2.0", Method: method, Params: rawParams, ID: json.RawMessage("1")}
+	if err := json.NewEncoder(conn).Encode(req); err != nil {
+		return err
+	}
+	var resp rpcResponse
+	if err := json.NewDecoder(conn).Decode(&resp); err != nil {
+		return fmt.Errorf("invalid response: %w", err)
+	}
+	if resp.Error != nil {
+		return fmt.Errorf("%s (code %d)", resp.Error.Message, resp.Error.Code)
+	}
+	return json.Unmarshal(resp.Result, out)
+}
@@ -0,0 +1,113 @@
+package main
+
+import (
+	"encoding/json"
+	"slices"
+	"sync"
+	"time"
+)
+
+// daemonEvent is something that happened to issues in the daemon's
+// workspace.
+type daemonEvent struct {
+	Type     string    `json:"type"` // created, updated, closed, claimed, imported
+	IssueIDs []string  `json:"issue_ids,omitempty"`
+	Time     time.Time `json:"time"`
+}
+
+// daemonEvents fans events out to subscribers. The zero value is ready to
+// use.
+type daemonEvents struct {
+	mu   sync.Mutex
+	subs map[chan daemonEvent]struct{}
+}
+
+// subscribe returns a channel receiving every event published from now on,
+// and a function that ends the subscription.
+func (e *daemonEvents) subscribe() (<-chan daemonEvent, func()) {
+	ch := make(chan daemonEvent, 64)
+	e.mu.Lock()
+	if e.subs == nil {
+		e.subs = make(map[chan daemonEvent]struct{})
+	}
+	e.subs[ch] = struct{}{}
+	e.mu.Unlock()
+	return ch, func() {
+		e.mu.Lock()
+		delete(e.subs, ch)
+		e.mu.Unlock()
+	}
+}
+
+// publish delivers ev to every subscriber. A subscriber that has fallen a
+// full buffer behind misses the event rather than stalling the daemon.
+func (e *daemonEvents) publish(ev daemonEvent) {
+	if ev.Time.IsZero() {
+		ev.Time = time.Now().UTC()
+	}
+	e.mu.Lock()
+	defer e.mu.Unlock()
+	for ch := range e.subs {
+		select {
+		case ch <- ev:
+		default:
+		}
+	}
+}
+
+// daemonSubscription is the params of a subscribe request.
+type daemonSubscription struct {
+	Types []string `json:"types"` // empty means every type
+	Cwd   string   `json:"cwd"`   // picks the workspace of a global daemon
+}
+
+func (s daemonSubscription) wants(eventType string) bool {
+	return len(s.Types) == 0 || slices.Contains(s.Types, eventType)
+}
+
+// parseDaemonSubscribe reports whether line is a subscribe request, in
+// which framing, and what it subscribes to. Params that don't decode are
+// left for handle to reject.
+func parseDaemonSubscribe(line []byte) (daemonSubscription, bool, bool) {
+	var req struct {
+		JSONRPC   string          `json:"jsonrpc"`
+		Method    string          `json:"method"`
+		Params    json.RawMessage `json:"params"`
+		Operation string          `json:"operation"`
+		Args      json.RawMessage `json:"args"`
+		Cwd       string          `json:"cwd"`
+	}
+	var sub daemonSubscription
+	if err := json.Unmarshal(line, &req); err != nil {
+		return sub, false, false
+	}
+	rpc := req.JSONRPC != ""
+	params := req.Args
+	if rpc {
+		if req.Method != "subscribe" {
+			return sub, false, false
+		}
+		params = req.Params
+	} else if req.Operation != "subscribe" {
+		return sub, false, false
+	}
+	if len(params) > 0 {
+		_ = json.Unmarshal(params, &sub)
+	}
+	if !rpc {
+		sub.Cwd = req.Cwd
+	}
+	return sub, rpc, true
+}
+
+// daemonResponseOK reports whether a dispatchLine result is a success.
+func daemonResponseOK(resp interface{}) bool {
+	switch r := resp.(type) {
+	case daemonResponse:
+		return r.Success
+	case *rpcResponse:
+		return r.Error == nil
+	default:
+		return false
+	}
+}
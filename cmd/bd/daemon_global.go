@@ -0,0 +1,160 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"sync"
+	"time"
+
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/storage"
+)
+
+// The global daemon ('bd daemon --global') serves every workspace of the
+// user from one socket. Each request is routed by its cwd to the .beads
+// directory it belongs to; the workspace's store is opened on first use
+// and kept open until the daemon exits.
+//
+// JSONL auto-export and the JSONL watcher follow the configuration bd
+// loaded at startup, which belongs to at most one workspace, so they are
+// off in global mode: writes are committed to Dolt only.
+
+const (
+	globalDaemonSocketName    = "bd-global.sock"
+	globalDaemonDiscoveryName = "daemon-global.json"
+)
+
+// globalDaemonDir is ~/.beads, where the global daemon keeps its socket
+// and discovery file.
+func globalDaemonDir() (string, error) {
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return "", fmt.Errorf("failed to locate home directory: %w", err)
+	}
+	return filepath.Join(home, ".beads"), nil
+}
+
+// daemonWorkspaces is the global daemon's table of open workspaces.
+type daemonWorkspaces struct {
+	mu     sync.Mutex
+	byDir  map[string]*daemonServer
+	opened map[string]time.Time
+	// open opens a workspace's store; newDoltStoreFromConfig unless a
+	// test replaces it.
+	open   func(ctx context.Context, beadsDir string) (storage.DoltStorage, error)
+}
+
+// workspaceFor returns the server for the workspace containing cwd,
+// opening its store if this is the first request for it.
+func (d *daemonServer) workspaceFor(ctx context.Context, cwd string) (*daemonServer, error) {
+	if cwd == "" {
+		return nil, newDaemonError(rpcInvalidParams, "the global daemon needs a cwd to pick the workspace")
+	}
+	dir := beads.FindBeadsDirFrom(cwd)
+	if dir == "" {
+		return nil, newDaemonError(rpcWrongWorkspace, "no beads workspace found from %s", cwd)
+	}
+	if resolved, err := filepath.EvalSymlinks(dir); err == nil {
+		dir = resolved
+	}
+
+	w := &d.workspaces
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	if ws, ok := w.byDir[dir]; ok {
+		return ws, nil
+	}
+	open := w.open
+	if open == nil {
+		open = newDoltStoreFromConfig
+	}
+	st, err := open(ctx, dir)
+	if err != nil {
+		return nil, fmt.Errorf("failed to open %s: %w", dir, err)
+	}
+	ws := &daemonServer{beadsDir: dir, store: st, noAutoExport: true}
+	if d.logConfig != nil {
+		ws.log = newDaemonLog(ctx, st, *d.logConfig)
+	}
+	ws.lastActive.Store(time.Now().UnixNano())
+	if w.byDir == nil {
+		w.byDir = make(map[string]*daemonServer)
+		w.opened = make(map[string]time.Time)
+	}
+	w.byDir[dir] = ws
+	w.opened[dir] = time.Now().UTC()
+	debug.Logf("daemon: opened workspace %s", dir)
+	return ws, nil
+}
+
+// daemonWorkspaceInfo is one entry of the workspaces operation.
+type daemonWorkspaceInfo struct {
+	BeadsDir   string    `json:"beads_dir"`
+	OpenedAt   time.Time `json:"opened_at"`
+	LastActive time.Time `json:"last_active"`
+}
+
+// listWorkspaces reports the workspaces the global daemon has open.
+func (d *daemonServer) listWorkspaces() []daemonWorkspaceInfo {
+	w := &d.workspaces
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	out := make([]daemonWorkspaceInfo, 0, len(w.byDir))
+	for dir, ws := range w.byDir {
+		out = append(out, daemonWorkspaceInfo{
+			BeadsDir:   dir,
+			OpenedAt:   w.opened[dir],
+			LastActive: time.Unix(0, ws.lastActive.Load()).UTC(),
+		})
+	}
+	sort.Slice(out, func(i, j int) bool { return out[i].BeadsDir < out[j].BeadsDir })
+	return out
+}
+
+// closeWorkspaces closes every store the global daemon opened.
+func (d *daemonServer) closeWorkspaces() {
+	w := &d.workspaces
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	for dir, ws := range w.byDir {
+		if ws.store == nil {
+			continue
+		}
+		if err := ws.store.Close(); err != nil {
+			fmt.Fprintf(os.Stderr, "bd daemon: failed to close %s: %v\n", dir, err)
+		}
+	}
+	w.byDir, w.opened = nil, nil
+}
+
+// routeGlobal answers a request to the global daemon: hello,
+// capabilities, status, metrics, shutdown, workspaces, and ping without a
+// cwd are answered here, everything else by the workspace the
+// request's cwd is in.
+func (d *daemonServer) routeGlobal(ctx context.Context, req *daemonRequest) (interface{}, error) {
+	switch {
+	case req.Operation == "workspaces":
+		return d.listWorkspaces(), nil
+	case req.Operation == "status":
+		return d.status(), nil
+	case req.Operation == "shutdown":
+		return d.shutdown(), nil
+	case req.Operation == "hello":
+		return d.hello(req.Args)
+	case req.Operation == "capabilities":
+		return d.capabilities(), nil
+	case req.Operation == "metrics":
+		return d.metricsOp(ctx, req.Args)
+	case req.Operation == "ping" && req.Cwd == "":
+		return map[string]interface{}{"pong": true, "version": Version, "global": true}, nil
+	}
+	ws, err := d.workspaceFor(ctx, req.Cwd)
+	if err != nil {
+		return nil, err
+	}
+	return ws.handle(ctx, req)
+}
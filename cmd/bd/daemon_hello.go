@@ -0,0 +1,95 @@
+package main
+
+import (
+	"encoding/json"
+	"fmt"
+	"slices"
+)
+
+// daemonMinProtocolVersion is the oldest protocol the daemon still speaks:
+// the original framing is always accepted.
+const daemonMinProtocolVersion = 1
+
+// daemonOperations are the operations every daemon answers.
+var daemonOperations = []string{
+	"hello", "capabilities", "ping", "status", "metrics", "log", "shutdown",
+	"list", "show", "create", "update", "close", "ready", "dep", "label", "batch",
+	"subscribe",
+}
+
+// daemonFeatures are protocol features a client may rely on.
+var daemonFeatures = []string{"jsonrpc", "batch", "notifications", "subscribe", "structured-errors"}
+
+// daemonCapabilities is the result of hello and capabilities.
+type daemonCapabilities struct {
+	ProtocolVersion    int      `json:"protocol_version"` // negotiated, for hello
+	MinProtocolVersion int      `json:"min_protocol_version"`
+	MaxProtocolVersion int      `json:"max_protocol_version"`
+	Version            string   `json:"version"`
+	Global             bool     `json:"global,omitempty"`
+	Operations         []string `json:"operations"`
+	Features           []string `json:"features"`
+}
+
+// operations lists what this daemon answers; a global daemon adds
+// workspaces.
+func (d *daemonServer) operations() []string {
+	if d.global {
+		return append(slices.Clone(daemonOperations), "workspaces")
+	}
+	return daemonOperations
+}
+
+func (d *daemonServer) capabilities() *daemonCapabilities {
+	return &daemonCapabilities{
+		ProtocolVersion:    daemonProtocolVersion,
+		MinProtocolVersion: daemonMinProtocolVersion,
+		MaxProtocolVersion: daemonProtocolVersion,
+		Version:            Version,
+		Global:             d.global,
+		Operations:         d.operations(),
+		Features:           daemonFeatures,
+	}
+}
+
+// hello negotiates the protocol version: the client names the newest it
+// speaks (default: the daemon's) and gets the newest both speak, along
+// with the daemon's capabilities. A client too old for the daemon is
+// refused with the supported range in the error data.
+func (d *daemonServer) hello(args json.RawMessage) (interface{}, error) {
+	var req struct {
+		Client          string `json:"client"`
+		ProtocolVersion int    `json:"protocol_version"`
+	}
+	if err := decodeDaemonArgs(args, &req); err != nil {
+		return nil, err
+	}
+	caps := d.capabilities()
+	if req.ProtocolVersion == 0 {
+		return caps, nil
+	}
+	if req.ProtocolVersion < daemonMinProtocolVersion {
+		return nil, &daemonError{
+			code: rpcInvalidParams,
+			msg:  "protocol version too old for this daemon",
+			data: map[string]int{"min_protocol_version": daemonMinProtocolVersion, "max_protocol_version": daemonProtocolVersion},
+		}
+	}
+	caps.ProtocolVersion = min(req.ProtocolVersion, daemonProtocolVersion)
+	return caps, nil
+}
+
+// unknownDaemonOperation is the error for an operation the daemon doesn't
+// have. Its data lists the ones it does, so that a client written for a
+// newer release can fall back.
+func (d *daemonServer) unknownDaemonOperation(op string) error {
+	return &daemonError{
+		code: rpcMethodNotFound,
+		msg:  fmt.Sprintf("unknown operation %q", op),
+		data: map[string]interface{}{
+			"operation":        op,
+			"operations":       d.operations(),
+			"protocol_version": daemonProtocolVersion,
+		},
+	}
+}
@@ -0,0 +1,73 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"runtime/debug"
+	"time"
+)
+
+// Defaults for 'bd daemon --max-concurrent', '--queue-timeout' and
+// '--request-timeout'.
+const (
+	defaultDaemonWorkers        = 8
+	defaultDaemonQueueTimeout   = 30 * time.Second
+	defaultDaemonRequestTimeout = time.Minute
+)
+
+// run handles req within the daemon's limits: at most cap(d.workers)
+// requests use the store at once. A request that can't get a worker within
+// d.queueTimeout is answered with a busy error; the connection stays open.
+// Once it has its worker, a read is cancelled after d.requestTimeout so a
+// runaway query gives its worker back. A write is never cancelled, even if
+// the connection or daemon is going away, so a started write always
+// finishes with its Dolt commit and export.
+func (d *daemonServer) run(ctx context.Context, req *daemonRequest) (data interface{}, err error) {
+	defer func() { d.stats.record(req.Operation, err) }()
+	if d.workers != nil {
+		wait := ctx
+		if d.queueTimeout > 0 {
+			var cancel context.CancelFunc
+			wait, cancel = context.WithTimeout(ctx, d.queueTimeout)
+			defer cancel()
+		}
+		select {
+		case d.workers <- struct{}{}:
+			defer func() { <-d.workers }()
+		case <-wait.Done():
+			return nil, daemonLimitError(wait, rpcServerBusy, "daemon busy: all %d workers in use for %s", cap(d.workers), d.queueTimeout)
+		}
+	}
+	readDeadline := false
+	if daemonWriteOps[req.Operation] {
+		ctx = context.WithoutCancel(ctx)
+	} else if d.requestTimeout > 0 {
+		var cancel context.CancelFunc
+		ctx, cancel = context.WithTimeout(ctx, d.requestTimeout)
+		defer cancel()
+		readDeadline = true
+	}
+
+	defer func() {
+		if r := recover(); r != nil {
+			fmt.Fprintf(os.Stderr, "bd daemon: panic in %s: %v\n%s", req.Operation, r, debug.Stack())
+			data, err = nil, newDaemonError(rpcInternalError, "internal error in %s: %v", req.Operation, r)
+		}
+	}()
+	data, err = d.handle(ctx, req)
+	if err != nil && readDeadline && errors.Is(ctx.Err(), context.DeadlineExceeded) {
+		return nil, newDaemonError(rpcServerBusy, "%s did not finish within %s", req.Operation, d.requestTimeout)
+	}
+	return data, err
+}
+
+// daemonLimitError reports why ctx ended: the wait for a worker timing out,
+// or the daemon shutting down.
+func daemonLimitError(ctx context.Context, code int, format string, args ...interface{}) error {
+	if errors.Is(ctx.Err(), context.DeadlineExceeded) {
+		return newDaemonError(code, format, args...)
+	}
+	return newDaemonError(rpcInternalError, "daemon is shutting down")
+}
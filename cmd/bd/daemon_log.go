@@ -0,0 +1,320 @@
+package main
+
+import (
+	"context"
+	"crypto/sha256"
+	"database/sql"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"net"
+	"strings"
+	"sync/atomic"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/storage"
+)
+
+// The daemon records who made each request in the workspace's daemon_log
+// table: the connection's peer (process and user for a Unix socket, the
+// remote address otherwise), a fingerprint of the token it presented, and
+// the actor it declared. The table is dolt-ignored, so the log stays with
+// the clone and is never committed or pushed. Rows older than the
+// retention, and the oldest beyond the row limit, are deleted when the
+// daemon opens the workspace and every daemonLogRotateEvery rows after.
+
+// Defaults for 'bd daemon --log-retention' and '--log-max-rows'.
+const (
+	defaultDaemonLogRetention = 30 * 24 * time.Hour
+	defaultDaemonLogMaxRows   = 100000
+	daemonLogRotateEvery      = 1000
+)
+
+// daemonUnloggedOps are the operations left out of the request log: they
+// read nothing from the workspace, and clients poll them.
+var daemonUnloggedOps = map[string]bool{
+	"ping": true, "hello": true, "capabilities": true, "status": true, "metrics": true, "log": true,
+}
+
+// daemonLogConfig is how much of the request log to keep.
+type daemonLogConfig struct {
+	retention time.Duration // delete rows older than this; 0 = keep
+	maxRows   int64         // keep at most this many rows; 0 = no limit
+}
+
+// daemonLog writes a workspace's request log.
+type daemonLog struct {
+	daemonLogConfig
+	db      *sql.DB
+	written atomic.Int64
+}
+
+// daemonLogEntry is one row of daemon_log.
+type daemonLogEntry struct {
+	ID         int64     `json:"id"`
+	Time       time.Time `json:"time"`
+	Operation  string    `json:"operation"`
+	Actor      string    `json:"actor"`
+	Peer       string    `json:"peer"`
+	Token      string    `json:"token,omitempty"` // fingerprint; see daemonTokenID
+	Cwd        string    `json:"cwd,omitempty"`
+	IssueIDs   []string  `json:"issue_ids,omitempty"`
+	Success    bool      `json:"success"`
+	Error      string    `json:"error,omitempty"`
+	DurationMS int64     `json:"duration_ms"`
+}
+
+// newDaemonLog returns the request log of the workspace served from st,
+// rotated once, or nil if the store has no SQL access.
+func newDaemonLog(ctx context.Context, st storage.DoltStorage, cfg daemonLogConfig) *daemonLog {
+	accessor, ok := storage.UnwrapStore(st).(storage.RawDBAccessor)
+	if !ok || accessor.DB() == nil {
+		debug.Logf("daemon: store has no SQL access; request log disabled")
+		return nil
+	}
+	l := &daemonLog{daemonLogConfig: cfg, db: accessor.DB()}
+	if err := l.rotate(ctx); err != nil {
+		debug.Logf("daemon: rotate request log: %v", err)
+	}
+	return l
+}
+
+// write appends e to the log. A failure is only logged: auditing must not
+// fail the request it describes.
+func (l *daemonLog) write(ctx context.Context, e *daemonLogEntry) {
+	ctx, cancel := context.WithTimeout(context.WithoutCancel(ctx), 5*time.Second)
+	defer cancel()
+	_, err := l.db.ExecContext(ctx, `INSERT INTO daemon_log
+		(created_at, operation, actor, peer, token, cwd, issue_ids, success, error, duration_ms)
+		VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)`,
+		e.Time, e.Operation, e.Actor, e.Peer, e.Token, e.Cwd, strings.Join(e.IssueIDs, ","), e.Success, e.Error, e.DurationMS)
+	if err != nil {
+		debug.Logf("daemon: write request log: %v", err)
+		return
+	}
+	if l.written.Add(1)%daemonLogRotateEvery == 0 {
+		if err := l.rotate(ctx); err != nil {
+			debug.Logf("daemon: rotate request log: %v", err)
+		}
+	}
+}
+
+// rotate deletes the rows past the retention and row limit.
+func (l *daemonLog) rotate(ctx context.Context) error {
+	if l.retention > 0 {
+		cutoff := time.Now().UTC().Add(-l.retention)
+		if _, err := l.db.ExecContext(ctx, "DELETE FROM daemon_log WHERE created_at < ?", cutoff); err != nil {
+			return fmt.Errorf("failed to expire request log: %w", err)
+		}
+	}
+	if l.maxRows > 0 {
+		var maxID sql.NullInt64
+		if err := l.db.QueryRowContext(ctx, "SELECT MAX(id) FROM daemon_log").Scan(&maxID); err != nil {
+			return fmt.Errorf("failed to read request log: %w", err)
+		}
+		if maxID.Valid && maxID.Int64 > l.maxRows {
+			if _, err := l.db.ExecContext(ctx, "DELETE FROM daemon_log WHERE id <= ?", maxID.Int64-l.maxRows); err != nil {
+				return fmt.Errorf("failed to trim request log: %w", err)
+			}
+		}
+	}
+	return nil
+}
+
+// daemonLogFilter selects entries for the log operation.
+type daemonLogFilter struct {
+	By        string    `json:"by"` // actor; "actor" names the requester
+	Operation string    `json:"operation"`
+	IssueID   string    `json:"issue_id"`
+	Since     time.Time `json:"since"`
+	Limit     int       `json:"limit"` // default 100
+}
+
+// query returns the newest entries matching f, newest first.
+func (l *daemonLog) query(ctx context.Context, f daemonLogFilter) ([]daemonLogEntry, error) {
+	var where []string
+	var args []interface{}
+	if f.By != "" {
+		where, args = append(where, "actor = ?"), append(args, f.By)
+	}
+	if f.Operation != "" {
+		where, args = append(where, "operation = ?"), append(args, f.Operation)
+	}
+	if f.IssueID != "" {
+		where, args = append(where, "FIND_IN_SET(?, issue_ids) > 0"), append(args, f.IssueID)
+	}
+	if !f.Since.IsZero() {
+		where, args = append(where, "created_at >= ?"), append(args, f.Since.UTC())
+	}
+	q := "SELECT id, created_at, operation, actor, peer, token, cwd, issue_ids, success, error, duration_ms FROM daemon_log"
+	if len(where) > 0 {
+		q += " WHERE " + strings.Join(where, " AND ")
+	}
+	if f.Limit <= 0 {
+		f.Limit = 100
+	}
+	q += " ORDER BY id DESC LIMIT ?"
+	args = append(args, f.Limit)
+
+	rows, err := l.db.QueryContext(ctx, q, args...)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read request log: %w", err)
+	}
+	defer rows.Close()
+	entries := []daemonLogEntry{}
+	for rows.Next() {
+		var e daemonLogEntry
+		var ids string
+		if err := rows.Scan(&e.ID, &e.Time, &e.Operation, &e.Actor, &e.Peer, &e.Token, &e.Cwd, &ids, &e.Success, &e.Error, &e.DurationMS); err != nil {
+			return nil, fmt.Errorf("failed to read request log: %w", err)
+		}
+		if ids != "" {
+			e.IssueIDs = strings.Split(ids, ",")
+		}
+		entries = append(entries, e)
+	}
+	return entries, rows.Err()
+}
+
+// audit records req in d's request log, unless there is none or req is
+// one of daemonUnloggedOps.
+func (d *daemonServer) audit(ctx context.Context, req *daemonRequest, actorName string, ids []string, start time.Time, err error) {
+	if d.log == nil || daemonUnloggedOps[req.Operation] {
+		return
+	}
+	e := &daemonLogEntry{
+		Time:       start.UTC(),
+		Operation:  req.Operation,
+		Actor:      actorName,
+		Peer:       daemonPeerFrom(ctx),
+		Token:      daemonTokenID(req.Token),
+		Cwd:        req.Cwd,
+		IssueIDs:   ids,
+		Success:    err == nil,
+		DurationMS: time.Since(start).Milliseconds(),
+	}
+	if err != nil {
+		e.Error = err.Error()
+	}
+	d.log.write(ctx, e)
+}
+
+// logOp answers the log operation with the entries its args select.
+func (d *daemonServer) logOp(ctx context.Context, args json.RawMessage) (interface{}, error) {
+	if d.log == nil {
+		return nil, newDaemonError(rpcInvalidRequest, "this daemon keeps no request log")
+	}
+	var f daemonLogFilter
+	if err := decodeDaemonArgs(args, &f); err != nil {
+		return nil, err
+	}
+	return d.log.query(ctx, f)
+}
+
+type daemonPeerKey struct{}
+
+// withDaemonPeer records in ctx the peer of the connection a request
+// arrived on.
+func withDaemonPeer(ctx context.Context, peer string) context.Context {
+	return context.WithValue(ctx, daemonPeerKey{}, peer)
+}
+
+func daemonPeerFrom(ctx context.Context) string {
+	peer, _ := ctx.Value(daemonPeerKey{}).(string)
+	return peer
+}
+
+// daemonPeer describes who is on the other end of conn: the process and
+// user where the OS reports them for a Unix socket, else the remote
+// address.
+func daemonPeer(conn net.Conn) string {
+	if cred := daemonPeerCred(conn); cred != "" {
+		return cred
+	}
+	addr := conn.RemoteAddr()
+	if addr == nil {
+		return ""
+	}
+	if s := addr.String(); s != "" && s != addr.Network() {
+		return addr.Network() + " " + s
+	}
+	return addr.Network()
+}
+
+// daemonTokenID fingerprints a token, so that the log tells tokens apart
+// without storing them.
+func daemonTokenID(token string) string {
+	if token == "" {
+		return ""
+	}
+	sum := sha256.Sum256([]byte(token))
+	return hex.EncodeToString(sum[:6])
+}
+
+var daemonLogCmd = &cobra.Command{
+	Use:   "log",
+	Short: "Show who made which requests to the daemon",
+	Long: `Show the daemon's request log, newest first: when each request was made,
+its operation and issues, the actor it declared, the connection's peer
+(pid and uid for a Unix socket on Linux, the remote address for TCP), a
+fingerprint of its token, and whether it failed.
+
+The log is the workspace's dolt-ignored daemon_log table, so it can also
+be queried with 'bd sql'. Introspection requests (ping, hello,
+capabilities, status, metrics, log) are not logged.`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		global, _ := cmd.Flags().GetBool("global")
+		info, err := readDaemonDiscovery(daemonDiscoveryPathFor(global))
+		if err != nil {
+			FatalError("%v", err)
+		}
+		if info == nil || !daemonAnswers(info) {
+			FatalError("no daemon is running")
+		}
+		params := map[string]interface{}{}
+		params["by"], _ = cmd.Flags().GetString("actor")
+		params["operation"], _ = cmd.Flags().GetString("operation")
+		params["issue_id"], _ = cmd.Flags().GetString("issue")
+		if since, _ := cmd.Flags().GetDuration("since"); since > 0 {
+			params["since"] = time.Now().Add(-since).UTC()
+		}
+		params["limit"], _ = cmd.Flags().GetInt("limit")
+
+		var entries []daemonLogEntry
+		if err := callDaemon(info, "log", params, &entries); err != nil {
+			FatalError("%v", err)
+		}
+		if jsonOutput {
+			outputJSON(entries)
+			return
+		}
+		if len(entries) == 0 {
+			fmt.Println("No requests logged")
+			return
+		}
+		for _, e := range entries {
+			status := "ok"
+			if !e.Success {
+				status = "FAILED: " + e.Error
+			}
+			line := fmt.Sprintf("%s  %-8s %-20s %s", e.Time.Local().Format("2006-01-02 15:04:05"), e.Operation, e.Actor, e.Peer)
+			if len(e.IssueIDs) > 0 {
+				line += "  " + strings.Join(e.IssueIDs, ",")
+			}
+			fmt.Printf("%s  %s\n", line, status)
+		}
+	},
+}
+
+func init() {
+	daemonLogCmd.Flags().Bool("global", false, "Ask the global daemon (for the workspace of the current directory)")
+	daemonLogCmd.Flags().String("actor", "", "Only requests by this actor")
+	daemonLogCmd.Flags().String("operation", "", "Only this operation")
+	daemonLogCmd.Flags().String("issue", "", "Only requests touching this issue")
+	daemonLogCmd.Flags().Duration("since", 0, "Only requests within this long (e.g. 2h)")
+	daemonLogCmd.Flags().Int("limit", 100, "Show at most this many requests")
+	daemonCmd.AddCommand(daemonLogCmd)
+}
@@ -0,0 +1,186 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"maps"
+	"net"
+	"net/http"
+	"os"
+	"slices"
+	"strings"
+	"time"
+)
+
+// daemonMetrics is the result of the metrics operation and the content of
+// /metrics.
+type daemonMetrics struct {
+	Requests       map[string]int64         `json:"requests"` // by operation
+	Errors         map[string]int64         `json:"errors"`   // by operation
+	Imports        int64                    `json:"imports"`
+	ImportedIssues int64                    `json:"imported_issues"`
+	ImportErrors   int64                    `json:"import_errors"`
+	Exports        int64                    `json:"exports"`
+	ExportErrors   int64                    `json:"export_errors"`
+	InFlight       int32                    `json:"in_flight"`
+	UptimeSeconds  float64                  `json:"uptime_seconds"`
+	Workspaces     []daemonWorkspaceMetrics `json:"workspaces"`
+}
+
+// daemonWorkspaceMetrics are the issue gauges of one workspace.
+type daemonWorkspaceMetrics struct {
+	BeadsDir   string `json:"beads_dir"`
+	Open       int    `json:"open"`
+	InProgress int    `json:"in_progress"`
+	Ready      int    `json:"ready"`
+	Blocked    int    `json:"blocked"`
+}
+
+// metrics snapshots the daemon's counters and reads the issue gauges of
+// every workspace it has open, taking each one's read lock in turn.
+func (d *daemonServer) metrics(ctx context.Context) (*daemonMetrics, error) {
+	m := &daemonMetrics{
+		InFlight:      d.inflight.Load(),
+		UptimeSeconds: time.Since(d.startedAt).Seconds(),
+	}
+	d.stats.mu.Lock()
+	m.Requests, m.Errors = maps.Clone(d.stats.byOperation), maps.Clone(d.stats.errorsByOp)
+	m.Imports, m.ImportedIssues, m.ImportErrors = d.stats.imports, d.stats.importedIssues, d.stats.importErrors
+	m.Exports, m.ExportErrors = d.stats.exports, d.stats.exportErrors
+	d.stats.mu.Unlock()
+
+	servers := []*daemonServer{d}
+	if d.global {
+		d.workspaces.mu.Lock()
+		servers = slices.Collect(maps.Values(d.workspaces.byDir))
+		d.workspaces.mu.Unlock()
+		slices.SortFunc(servers, func(a, b *daemonServer) int { return strings.Compare(a.beadsDir, b.beadsDir) })
+	}
+	for _, s := range servers {
+		if s.store == nil {
+			continue
+		}
+		s.mu.RLock()
+		stats, err := s.store.GetStatistics(ctx)
+		s.mu.RUnlock()
+		if err != nil {
+			return nil, fmt.Errorf("%s: failed to get statistics: %w", s.beadsDir, err)
+		}
+		m.Workspaces = append(m.Workspaces, daemonWorkspaceMetrics{
+			BeadsDir:   s.beadsDir,
+			Open:       stats.OpenIssues,
+			InProgress: stats.InProgressIssues,
+			Ready:      stats.ReadyIssues,
+			Blocked:    stats.BlockedIssues,
+		})
+	}
+	return m, nil
+}
+
+// metricsOp answers the metrics operation: the metrics as JSON, or with
+// "format": "prometheus" as the text /metrics serves.
+func (d *daemonServer) metricsOp(ctx context.Context, args json.RawMessage) (interface{}, error) {
+	var req struct {
+		Format string `json:"format"`
+	}
+	if err := decodeDaemonArgs(args, &req); err != nil {
+		return nil, err
+	}
+	m, err := d.metrics(ctx)
+	if err != nil {
+		return nil, err
+	}
+	switch req.Format {
+	case "", "json":
+		return m, nil
+	case "prometheus":
+		var b strings.Builder
+		m.writePrometheus(&b)
+		return b.String(), nil
+	default:
+		return nil, newDaemonError(rpcInvalidParams, "unknown format %q (want json or prometheus)", req.Format)
+	}
+}
+
+// writePrometheus writes m in the Prometheus text exposition format.
+func (m *daemonMetrics) writePrometheus(w io.Writer) {
+	family := func(name, kind, help string) {
+		fmt.Fprintf(w, "# HELP %s %s\n# TYPE %s %s\n", name, help, name, kind)
+	}
+	byOperation := func(name, help string, counts map[string]int64) {
+		family(name, "counter", help)
+		for _, op := range slices.Sorted(maps.Keys(counts)) {
+			fmt.Fprintf(w, "%s{operation=%s} %d\n", name, promLabel(op), counts[op])
+		}
+	}
+	single := func(name, kind, help string, value interface{}) {
+		family(name, kind, help)
+		fmt.Fprintf(w, "%s %v\n", name, value)
+	}
+	byWorkspace := func(name, help string, value func(daemonWorkspaceMetrics) int) {
+		family(name, "gauge", help)
+		for _, ws := range m.Workspaces {
+			fmt.Fprintf(w, "%s{beads_dir=%s} %d\n", name, promLabel(ws.BeadsDir), value(ws))
+		}
+	}
+
+	byOperation("bd_daemon_requests_total", "Requests handled, by operation.", m.Requests)
+	byOperation("bd_daemon_request_errors_total", "Requests that failed, by operation.", m.Errors)
+	single("bd_daemon_imports_total", "counter", "JSONL auto-imports run.", m.Imports)
+	single("bd_daemon_imported_issues_total", "counter", "Issues taken from the JSONL export by auto-import.", m.ImportedIssues)
+	single("bd_daemon_import_errors_total", "counter", "JSONL auto-imports that failed.", m.ImportErrors)
+	single("bd_daemon_exports_total", "counter", "JSONL auto-exports run after writes.", m.Exports)
+	single("bd_daemon_export_errors_total", "counter", "JSONL auto-exports that failed.", m.ExportErrors)
+	single("bd_daemon_in_flight_requests", "gauge", "Requests being handled.", m.InFlight)
+	single("bd_daemon_uptime_seconds", "gauge", "Seconds since the daemon started.", fmt.Sprintf("%.0f", m.UptimeSeconds))
+	byWorkspace("bd_issues_open", "Open issues.", func(ws daemonWorkspaceMetrics) int { return ws.Open })
+	byWorkspace("bd_issues_in_progress", "In-progress issues.", func(ws daemonWorkspaceMetrics) int { return ws.InProgress })
+	byWorkspace("bd_issues_ready", "Ready issues (open and unblocked).", func(ws daemonWorkspaceMetrics) int { return ws.Ready })
+	byWorkspace("bd_issues_blocked", "Blocked issues.", func(ws daemonWorkspaceMetrics) int { return ws.Blocked })
+}
+
+// promLabel quotes a Prometheus label value.
+func promLabel(v string) string {
+	return `"` + strings.NewReplacer(`\`, `\\`, `"`, `\"`, "\n", `\n`).Replace(v) + `"`
+}
+
+// serveMetrics answers GET /metrics from d.
+func serveMetrics(d *daemonServer) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		m, err := d.metrics(r.Context())
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+		w.Header().Set("Content-Type", "text/plain; version=0.0.4; charset=utf-8")
+		m.writePrometheus(w)
+	}
+}
+
+// listenDaemonMetrics serves /metrics for d at addr until the returned
+// function is called.
+func listenDaemonMetrics(d *daemonServer, addr string) (func(), error) {
+	host, _, err := net.SplitHostPort(addr)
+	if err != nil {
+		return nil, fmt.Errorf("invalid --metrics-listen address %q: %w", addr, err)
+	}
+	if ip := net.ParseIP(host); host != "localhost" && (ip == nil || !ip.IsLoopback()) {
+		fmt.Fprintf(os.Stderr, "Warning: metrics on %s are readable by anyone who can reach it\n", addr)
+	}
+	ln, err := net.Listen("tcp", addr)
+	if err != nil {
+		return nil, fmt.Errorf("failed to listen on %s: %w", addr, err)
+	}
+	mux := http.NewServeMux()
+	mux.HandleFunc("GET /metrics", serveMetrics(d))
+	srv := &http.Server{Handler: mux, ReadHeaderTimeout: 10 * time.Second}
+	go func() {
+		if err := srv.Serve(ln); err != nil && !errors.Is(err, http.ErrServerClosed) {
+			fmt.Fprintf(os.Stderr, "bd daemon: metrics server: %v\n", err)
+		}
+	}()
+	return func() { _ = srv.Close() }, nil
+}
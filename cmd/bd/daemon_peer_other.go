@@ -0,0 +1,11 @@
+//go:build !linux
+
+package main
+
+import "net"
+
+// daemonPeerCred reports nothing outside Linux, where SO_PEERCRED is not
+// available; daemonPeer falls back to the remote address.
+func daemonPeerCred(conn net.Conn) string {
+	return ""
+}
@@ -0,0 +1,206 @@
+package main
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"github.com/steveyegge/beads/internal/storage"
+)
+
+// The daemon speaks JSON-RPC 2.0 (https://www.jsonrpc.org/specification):
+// the method is the operation name, params are its args, and a request
+// without an id is a notification that gets no response. Batches (a JSON
+// array of requests) are answered with an array of responses. The
+// connection-level fields of the original protocol (cwd, actor, token) are
+// taken from params.
+//
+// Lines without a "jsonrpc" member are served with the original
+// {"operation": ...} framing, so existing clients keep working.
+
+// JSON-RPC error codes. -32768 to -32000 are reserved by the spec; the
+// daemon's own codes are in the -32000 to -32099 server-error range.
+const (
+	rpcParseError     = -32700
+	rpcInvalidRequest = -32600
+	rpcMethodNotFound = -32601
+	rpcInvalidParams  = -32602
+	rpcInternalError  = -32603
+	rpcUnauthorized   = -32001
+	rpcWrongWorkspace = -32002
+	rpcServerBusy     = -32003
+	rpcNotFound       = -32004
+)
+
+// daemonError is an error with a JSON-RPC error code and, optionally,
+// data telling the client more (the error object's "data" member).
+type daemonError struct {
+	code int
+	msg  string
+	data interface{}
+}
+
+func (e *daemonError) Error() string { return e.msg }
+
+func newDaemonError(code int, format string, args ...interface{}) error {
+	return &daemonError{code: code, msg: fmt.Sprintf(format, args...)}
+}
+
+// rpcErrorData returns the data attached to err, if any.
+func rpcErrorData(err error) interface{} {
+	var de *daemonError
+	if errors.As(err, &de) {
+		return de.data
+	}
+	return nil
+}
+
+// rpcErrorCode classifies err for a JSON-RPC error response.
+func rpcErrorCode(err error) int {
+	var de *daemonError
+	switch {
+	case errors.As(err, &de):
+		return de.code
+	case errors.Is(err, storage.ErrNotFound):
+		return rpcNotFound
+	default:
+		return rpcInternalError
+	}
+}
+
+type rpcRequest struct {
+	JSONRPC string          `json:"jsonrpc"`
+	Method  string          `json:"method"`
+	Params  json.RawMessage `json:"params,omitempty"`
+	ID      json.RawMessage `json:"id,omitempty"` // nil for a notification
+}
+
+type rpcResponse struct {
+	JSONRPC string          `json:"jsonrpc"`
+	Result  json.RawMessage `json:"result,omitempty"`
+	Error   *rpcError       `json:"error,omitempty"`
+	ID      json.RawMessage `json:"id"`
+}
+
+// rpcNotification is a message the daemon sends without a request, such
+// as an event to a subscriber.
+type rpcNotification struct {
+	JSONRPC string      `json:"jsonrpc"`
+	Method  string      `json:"method"`
+	Params  interface{} `json:"params"`
+}
+
+type rpcError struct {
+	Code    int         `json:"code"`
+	Message string      `json:"message"`
+	Data    interface{} `json:"data,omitempty"`
+}
+
+func rpcErrorResponse(id json.RawMessage, code int, msg string) *rpcResponse {
+	return &rpcResponse{JSONRPC: "2.0", Error: &rpcError{Code: code, Message: msg}, ID: id}
+}
+
+// dispatchLine answers one request line in whichever protocol it uses. It
+// returns nil when nothing should be sent back (notifications).
+func (d *daemonServer) dispatchLine(ctx context.Context, line []byte) interface{} {
+	var probe interface{}
+	if err := json.Unmarshal(line, &probe); err != nil {
+		if looksLikeRPC(line) {
+			return rpcErrorResponse(nil, rpcParseError, fmt.Sprintf("parse error: %v", err))
+		}
+		return daemonResponse{Error: fmt.Sprintf("invalid request: %v", err)}
+	}
+	switch v := probe.(type) {
+	case []interface{}:
+		return d.dispatchBatch(ctx, line, len(v))
+	case map[string]interface{}:
+		if _, ok := v["jsonrpc"]; ok {
+			if resp := d.dispatchRPC(ctx, line); resp != nil {
+				return resp
+			}
+			return nil
+		}
+	}
+
+	var resp daemonResponse
+	var req daemonRequest
+	if err := json.Unmarshal(line, &req); err != nil {
+		resp.Error = fmt.Sprintf("invalid request: %v", err)
+	} else if data, err := d.run(ctx, &req); err != nil {
+		resp.Error, resp.Code, resp.Details = err.Error(), rpcErrorCode(err), rpcErrorData(err)
+	} else {
+		resp.Success, resp.Data = true, data
+	}
+	return resp
+}
+
+// looksLikeRPC reports whether an unparseable line was meant as JSON-RPC,
+// so that the parse error is reported in the framing the client expects.
+func looksLikeRPC(line []byte) bool {
+	return bytes.HasPrefix(bytes.TrimSpace(line), []byte("[")) || bytes.Contains(line, []byte(`"jsonrpc"`))
+}
+
+func (d *daemonServer) dispatchBatch(ctx context.Context, line []byte, n int) interface{} {
+	if n == 0 {
+		return rpcErrorResponse(nil, rpcInvalidRequest, "invalid request: empty batch")
+	}
+	var items []json.RawMessage
+	if err := json.Unmarshal(line, &items); err != nil {
+		return rpcErrorResponse(nil, rpcParseError, fmt.Sprintf("parse error: %v", err))
+	}
+	var out []*rpcResponse
+	for _, item := range items {
+		if resp := d.dispatchRPC(ctx, item); resp != nil {
+			out = append(out, resp)
+		}
+	}
+	if len(out) == 0 {
+		return nil
+	}
+	return out
+}
+
+// dispatchRPC runs one JSON-RPC request. It returns nil for a
+// notification.
+func (d *daemonServer) dispatchRPC(ctx context.Context, raw json.RawMessage) *rpcResponse {
+	var req rpcRequest
+	if err := json.Unmarshal(raw, &req); err != nil {
+		return rpcErrorResponse(nil, rpcInvalidRequest, fmt.Sprintf("invalid request: %v", err))
+	}
+	if req.JSONRPC != "2.0" || req.Method == "" {
+		return rpcErrorResponse(req.ID, rpcInvalidRequest, `invalid request: want "jsonrpc": "2.0" and a method`)
+	}
+	var conn struct {
+		Cwd   string `json:"cwd"`
+		Actor string `json:"actor"`
+		Token string `json:"token"`
+	}
+	if len(req.Params) > 0 && string(req.Params) != "null" {
+		if err := json.Unmarshal(req.Params, &conn); err != nil {
+			return rpcErrorResponse(req.ID, rpcInvalidParams, "invalid params: want an object")
+		}
+	}
+
+	data, err := d.run(ctx, &daemonRequest{
+		Operation: req.Method,
+		Args:      req.Params,
+		Cwd:       conn.Cwd,
+		Actor:     conn.Actor,
+		Token:     conn.Token,
+	})
+	if req.ID == nil {
+		return nil
+	}
+	if err != nil {
+		resp := rpcErrorResponse(req.ID, rpcErrorCode(err), err.Error())
+		resp.Error.Data = rpcErrorData(err)
+		return resp
+	}
+	result, err := json.Marshal(data)
+	if err != nil {
+		return rpcErrorResponse(req.ID, rpcInternalError, fmt.Sprintf("failed to encode result: %v", err))
+	}
+	return &rpcResponse{JSONRPC: "2.0", Result: result, ID: req.ID}
+}
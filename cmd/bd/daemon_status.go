@@ -0,0 +1,215 @@
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sync"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+)
+
+// daemonProtocolVersion is the newest protocol the daemon speaks: 1 was
+// the original {"operation": ...} framing, 2 added JSON-RPC 2.0, and 3
+// added hello/capabilities and error data.
+const daemonProtocolVersion = 3
+
+// daemonStats counts the requests a daemon has answered and the JSONL
+// imports and exports it has run.
+type daemonStats struct {
+	mu        sync.Mutex
+	requests  int64
+	failures  int64
+	lastError *daemonLastError
+
+	byOperation    map[string]int64
+	errorsByOp     map[string]int64
+	imports        int64
+	importedIssues int64
+	importErrors   int64
+	exports        int64
+	exportErrors   int64
+}
+
+// daemonLastError is the most recent request that failed.
+type daemonLastError struct {
+	Operation string    `json:"operation"`
+	Message   string    `json:"message"`
+	Time      time.Time `json:"time"`
+}
+
+func (s *daemonStats) record(op string, err error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if s.byOperation == nil {
+		s.byOperation = make(map[string]int64)
+		s.errorsByOp = make(map[string]int64)
+	}
+	s.requests++
+	s.byOperation[op]++
+	if err != nil {
+		s.failures++
+		s.errorsByOp[op]++
+		s.lastError = &daemonLastError{Operation: op, Message: err.Error(), Time: time.Now().UTC()}
+	}
+}
+
+// recordImport counts a watcher import of n issues.
+func (s *daemonStats) recordImport(n int, err error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.imports++
+	s.importedIssues += int64(n)
+	if err != nil {
+		s.importErrors++
+	}
+}
+
+// recordExport counts an auto-export after a write.
+func (s *daemonStats) recordExport(err error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.exports++
+	if err != nil {
+		s.exportErrors++
+	}
+}
+
+// daemonStatus is the result of the status operation.
+type daemonStatus struct {
+	PID        int                   `json:"pid"`
+	Network    string                `json:"network"`
+	Address    string                `json:"address"`
+	Version    string                `json:"version"`
+	Protocol   int                   `json:"protocol_version"`
+	StartedAt  time.Time             `json:"started_at"`
+	Uptime     string                `json:"uptime"`
+	Global     bool                  `json:"global,omitempty"`
+	Workspaces []daemonWorkspaceInfo `json:"workspaces"`
+	Requests   int64                 `json:"requests"`
+	Failures   int64                 `json:"failures"`
+	InFlight   int32                 `json:"in_flight"`
+	LastError  *daemonLastError      `json:"last_error,omitempty"`
+}
+
+func (d *daemonServer) status() *daemonStatus {
+	st := &daemonStatus{
+		PID:       os.Getpid(),
+		Version:   Version,
+		Protocol:  daemonProtocolVersion,
+		StartedAt: d.startedAt,
+		Uptime:    time.Since(d.startedAt).Round(time.Second).String(),
+		Global:    d.global,
+		InFlight:  d.inflight.Load(),
+	}
+	if d.addr != nil {
+		st.Network, st.Address = d.addr.Network(), d.addr.String()
+	}
+	if d.global {
+		st.Workspaces = d.listWorkspaces()
+	} else {
+		st.Workspaces = []daemonWorkspaceInfo{{
+			BeadsDir:   d.beadsDir,
+			OpenedAt:   d.startedAt,
+			LastActive: time.Unix(0, d.lastActive.Load()).UTC(),
+		}}
+	}
+	d.stats.mu.Lock()
+	st.Requests, st.Failures, st.LastError = d.stats.requests, d.stats.failures, d.stats.lastError
+	d.stats.mu.Unlock()
+	return st
+}
+
+var daemonStatusCmd = &cobra.Command{
+	Use:   "status",
+	Short: "Show whether a daemon is running and what it serves",
+	Long: `Ask the workspace's daemon (or with --global, the global daemon) for its
+PID, socket, uptime, protocol version, open workspaces, request counts,
+and last error. Reports "not running" when no daemon answers.`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		global, _ := cmd.Flags().GetBool("global")
+		info, err := readDaemonDiscovery(daemonDiscoveryPathFor(global))
+		if err != nil {
+			FatalError("%v", err)
+		}
+		var st daemonStatus
+		if info == nil || !daemonAnswers(info) {
+			renderDaemonStatus(nil, info)
+			return
+		}
+		if err := callDaemon(info, "status", map[string]interface{}{}, &st); err != nil {
+			FatalError("daemon at %s %s did not answer status: %v", info.Network, info.Address, err)
+		}
+		renderDaemonStatus(&st, info)
+	},
+}
+
+func init() {
+	daemonStatusCmd.Flags().Bool("global", false, "Ask the global daemon")
+	daemonCmd.AddCommand(daemonStatusCmd)
+}
+
+// daemonDiscoveryPathFor is the discovery file of the global daemon, or of
+// the current workspace's daemon; it exits if there is no workspace.
+func daemonDiscoveryPathFor(global bool) string {
+	if global {
+		dir, err := globalDaemonDir()
+		if err != nil {
+			FatalError("%v", err)
+		}
+		return filepath.Join(dir, globalDaemonDiscoveryName)
+	}
+	beadsDir := beads.FindBeadsDir()
+	if beadsDir == "" {
+		FatalErrorWithHint(activeWorkspaceNotFoundError(), diagHint())
+	}
+	return filepath.Join(beadsDir, daemonDiscoveryName)
+}
+
+// renderDaemonStatus writes the status of a daemon, or that none is
+// running when st is nil, honoring jsonOutput. info is the discovery
+// record, if any.
+func renderDaemonStatus(st *daemonStatus, info *daemonDiscovery) {
+	if jsonOutput {
+		out := struct {
+			Running bool `json:"running"`
+			*daemonStatus
+		}{st != nil, st}
+		outputJSON(out)
+		return
+	}
+	if st == nil {
+		fmt.Println("Daemon: not running")
+		if info != nil {
+			fmt.Printf("  Stale discovery file from pid %d (%s %s)\n", info.PID, info.Network, info.Address)
+		}
+		return
+	}
+	fmt.Println("Daemon: running")
+	fmt.Printf("  PID:      %d\n", st.PID)
+	switch st.Network {
+	case "unix":
+		fmt.Printf("  Socket:   %s\n", st.Address)
+	case "pipe":
+		fmt.Printf("  Pipe:     %s\n", st.Address)
+	default:
+		fmt.Printf("  Address:  %s %s\n", st.Network, st.Address)
+	}
+	fmt.Printf("  Uptime:   %s\n", st.Uptime)
+	fmt.Printf("  Version:  %s (protocol %d)\n", st.Version, st.Protocol)
+	fmt.Printf("  Requests: %d (%d failed, %d in flight)\n", st.Requests, st.Failures, st.InFlight)
+	if st.LastError != nil {
+		fmt.Printf("  Last error: %s: %s (%s)\n", st.LastError.Operation, st.LastError.Message, formatTimeAgo(st.LastError.Time))
+	}
+	if st.Global {
+		fmt.Printf("  Workspaces (%d):\n", len(st.Workspaces))
+	} else {
+		fmt.Println("  Workspace:")
+	}
+	for _, ws := range st.Workspaces {
+		fmt.Printf("    %s (last active %s)\n", ws.BeadsDir, formatTimeAgo(ws.LastActive))
+	}
+}
@@ -0,0 +1,94 @@
+package main
+
+import (
+	"fmt"
+	"net"
+	"os"
+	"time"
+
+	"github.com/spf13/cobra"
+)
+
+// daemonShutdownGrace is how long a stopping daemon waits for requests it
+// is handling to be answered before closing their connections.
+const daemonShutdownGrace = 10 * time.Second
+
+// shutdown stops the daemon as SIGTERM does. The request asking for it is
+// still answered.
+func (d *daemonServer) shutdown() interface{} {
+	if d.stop != nil {
+		d.stop()
+	}
+	return map[string]interface{}{"stopping": true, "pid": os.Getpid()}
+}
+
+// cleanStaleDaemon removes what a daemon that no longer answers left
+// behind: its discovery file and, for a Unix socket, the socket file.
+func cleanStaleDaemon(discoveryPath string, info *daemonDiscovery) {
+	if info.Network == "unix" {
+		if conn, err := net.DialTimeout("unix", info.Address, time.Second); err == nil {
+			_ = conn.Close()
+		} else {
+			_ = os.Remove(info.Address)
+		}
+	}
+	_ = os.Remove(discoveryPath)
+}
+
+var daemonStopCmd = &cobra.Command{
+	Use:   "stop",
+	Short: "Stop a running daemon",
+	Long: `Ask the workspace's daemon (or with --global, the global daemon) to exit,
+and wait until it has. Requests it is handling are answered first. If the
+daemon crashed, its leftover discovery file and socket are removed.`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		global, _ := cmd.Flags().GetBool("global")
+		discoveryPath := daemonDiscoveryPathFor(global)
+		info, err := readDaemonDiscovery(discoveryPath)
+		if err != nil {
+			FatalError("%v", err)
+		}
+		if info == nil || !daemonAnswers(info) {
+			if info != nil {
+				cleanStaleDaemon(discoveryPath, info)
+			}
+			if jsonOutput {
+				outputJSON(map[string]interface{}{"running": false, "stale_removed": info != nil})
+				return
+			}
+			fmt.Println("Daemon: not running")
+			if info != nil {
+				fmt.Printf("  Removed stale discovery file from pid %d\n", info.PID)
+			}
+			return
+		}
+
+		var ack map[string]interface{}
+		if err := callDaemon(info, "shutdown", map[string]interface{}{}, &ack); err != nil {
+			FatalError("daemon at %s %s did not accept shutdown: %v", info.Network, info.Address, err)
+		}
+		// The daemon removes its discovery file once it has drained.
+		wait := daemonShutdownGrace + 5*time.Second
+		deadline := time.Now().Add(wait)
+		for {
+			if cur, _ := readDaemonDiscovery(discoveryPath); cur == nil || cur.PID != info.PID {
+				break
+			}
+			if time.Now().After(deadline) {
+				FatalError("daemon (pid %d) is still running after %s", info.PID, wait)
+			}
+			time.Sleep(50 * time.Millisecond)
+		}
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"stopped": true, "pid": info.PID})
+			return
+		}
+		fmt.Printf("Daemon stopped (pid %d)\n", info.PID)
+	},
+}
+
+func init() {
+	daemonStopCmd.Flags().Bool("global", false, "Stop the global daemon")
+	daemonCmd.AddCommand(daemonStopCmd)
+}
@@ -0,0 +1,983 @@
+package main
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"fmt"
+	"maps"
+	"net"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/DATA-DOG/go-sqlmock"
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestDaemonServeConn(t *testing.T) {
+	t.Parallel()
+	server, client := net.Pipe()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads"}
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+	go d.serveConn(ctx, server)
+	defer func() { _ = client.Close() }()
+
+	reader := bufio.NewReader(client)
+	roundTrip := func(line string) daemonResponse {
+		t.Helper()
+		if _, err := client.Write([]byte(line + "\n")); err != nil {
+			t.Fatalf("write: %v", err)
+		}
+		data, err := reader.ReadBytes('\n')
+		if err != nil {
+			t.Fatalf("read: %v", err)
+		}
+		var resp daemonResponse
+		if err := json.Unmarshal(data, &resp); err != nil {
+			t.Fatalf("response %q: %v", data, err)
+		}
+		return resp
+	}
+
+	resp := roundTrip(`{"operation":"ping"}`)
+	if pong, _ := resp.Data.(map[string]interface{}); !resp.Success || pong["pong"] != true || pong["beads_dir"] != d.beadsDir {
+		t.Errorf("ping = %+v", resp)
+	}
+	if resp := roundTrip(`{"operation":"frobnicate"}`); resp.Success || !strings.Contains(resp.Error, "unknown operation") {
+		t.Errorf("unknown operation = %+v", resp)
+	}
+	if resp := roundTrip(`not json`); resp.Success || !strings.Contains(resp.Error, "invalid request") {
+		t.Errorf("malformed request = %+v", resp)
+	}
+	// The connection stays usable after an error.
+	if resp := roundTrip(`{"operation":"ping","args":{}}`); !resp.Success {
+		t.Errorf("ping after errors = %+v", resp)
+	}
+}
+
+func TestDaemonIDs(t *testing.T) {
+	t.Parallel()
+	ids, err := daemonIDs("bd-1", []string{"bd-2"})
+	if err != nil || strings.Join(ids, ",") != "bd-1,bd-2" {
+		t.Errorf("daemonIDs = %v, %v", ids, err)
+	}
+	if _, err := daemonIDs("", nil); err == nil {
+		t.Error("expected an error without an id")
+	}
+}
+
+func TestDaemonTokenAuth(t *testing.T) {
+	t.Parallel()
+	server, client := net.Pipe()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", token: "s3cret"}
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+	go d.serveConn(ctx, server)
+	defer func() { _ = client.Close() }()
+
+	reader := bufio.NewReader(client)
+	for _, tc := range []struct {
+		line string
+		ok   bool
+	}{
+		{`{"operation":"ping"}`, false},
+		{`{"operation":"ping","token":"wrong"}`, false},
+		{`{"operation":"ping","token":"s3cret"}`, true},
+	} {
+		if _, err := client.Write([]byte(tc.line + "\n")); err != nil {
+			t.Fatalf("write: %v", err)
+		}
+		data, err := reader.ReadBytes('\n')
+		if err != nil {
+			t.Fatalf("read: %v", err)
+		}
+		var resp daemonResponse
+		_ = json.Unmarshal(data, &resp)
+		if resp.Success != tc.ok {
+			t.Errorf("%s: success = %v, want %v (%s)", tc.line, resp.Success, tc.ok, resp.Error)
+		}
+	}
+}
+
+func TestDaemonDiscoveryFile(t *testing.T) {
+	t.Parallel()
+	path := filepath.Join(t.TempDir(), daemonDiscoveryName)
+	if info, err := readDaemonDiscovery(path); info != nil || err != nil {
+		t.Fatalf("missing discovery file = %+v, %v", info, err)
+	}
+
+	want := &daemonDiscovery{Network: "tcp", Address: "127.0.0.1:7421", Token: "abc", PID: os.Getpid()}
+	if err := writeDaemonDiscovery(path, want); err != nil {
+		t.Fatalf("writeDaemonDiscovery: %v", err)
+	}
+	got, err := readDaemonDiscovery(path)
+	if err != nil || got.Address != want.Address || got.Token != want.Token {
+		t.Fatalf("readDaemonDiscovery = %+v, %v", got, err)
+	}
+
+	// Another process's file is left in place.
+	other := *want
+	other.PID = os.Getpid() + 1
+	_ = writeDaemonDiscovery(path, &other)
+	removeDaemonDiscovery(path)
+	if _, err := os.Stat(path); err != nil {
+		t.Errorf("discovery file of another daemon was removed: %v", err)
+	}
+	_ = writeDaemonDiscovery(path, want)
+	removeDaemonDiscovery(path)
+	if _, err := os.Stat(path); !os.IsNotExist(err) {
+		t.Errorf("own discovery file not removed: %v", err)
+	}
+}
+
+func TestDaemonJSONRPC(t *testing.T) {
+	t.Parallel()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads"}
+	ctx := context.Background()
+	dispatch := func(line string) string {
+		t.Helper()
+		resp := d.dispatchLine(ctx, []byte(line))
+		if resp == nil {
+			return ""
+		}
+		data, err := json.Marshal(resp)
+		if err != nil {
+			t.Fatalf("marshal: %v", err)
+		}
+		return string(data)
+	}
+
+	tests := []struct {
+		line string
+		want string
+	}{
+		{`{"jsonrpc":"2.0","id":1,"method":"ping"}`, `"id":1`},
+		{`{"jsonrpc":"2.0","id":"a","method":"frobnicate"}`, `"code":-32601`},
+		{`{"jsonrpc":"2.0","id":2,"method":"show","params":{}}`, `"code":-32602`},
+		{`{"jsonrpc":"2.0","id":3,"method":"show","params":[1]}`, `"code":-32602`},
+		{`{"jsonrpc":"1.0","id":4,"method":"ping"}`, `"code":-32600`},
+		{`{"jsonrpc":"2.0","id":5,"method":"ping","params":{"cwd":"/"}}`, `"result"`},
+		{`{"jsonrpc": "2.0", "method"`, `"code":-32700`},
+		{`[]`, `"code":-32600`},
+		{`{"operation":"ping"}`, `"success":true`},
+	}
+	for _, tt := range tests {
+		if got := dispatch(tt.line); !strings.Contains(got, tt.want) {
+			t.Errorf("%s -> %s, want %s", tt.line, got, tt.want)
+		}
+	}
+
+	// Notifications get no response, also inside a batch.
+	if got := dispatch(`{"jsonrpc":"2.0","method":"ping"}`); got != "" {
+		t.Errorf("notification answered with %s", got)
+	}
+	var batch []rpcResponse
+	got := dispatch(`[{"jsonrpc":"2.0","id":1,"method":"ping"},{"jsonrpc":"2.0","method":"ping"},{"jsonrpc":"2.0","id":2,"method":"nope"}]`)
+	if err := json.Unmarshal([]byte(got), &batch); err != nil || len(batch) != 2 {
+		t.Fatalf("batch -> %s (%v)", got, err)
+	}
+	if batch[0].Error != nil || string(batch[1].ID) != "2" || batch[1].Error.Code != rpcMethodNotFound {
+		t.Errorf("batch = %+v", batch)
+	}
+}
+
+func TestDaemonIdleTimeout(t *testing.T) {
+	t.Parallel()
+	ln, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		t.Fatalf("listen: %v", err)
+	}
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", idleTimeout: 100 * time.Millisecond}
+	done := make(chan error, 1)
+	go func() { done <- d.serve(context.Background(), ln) }()
+	select {
+	case err := <-done:
+		if err != nil {
+			t.Errorf("serve: %v", err)
+		}
+	case <-time.After(5 * time.Second):
+		t.Fatal("daemon did not exit after its idle timeout")
+	}
+}
+
+func TestDaemonForwardFor(t *testing.T) {
+	t.Parallel()
+	root := &cobra.Command{Use: "bd"}
+	show := &cobra.Command{Use: "show"}
+	show.Flags().Bool("thread", false, "")
+	ready := &cobra.Command{Use: "ready"}
+	ready.Flags().IntP("limit", "n", 100, "")
+	ready.Flags().IntP("priority", "p", 0, "")
+	ready.Flags().String("sort", "priority", "")
+	ready.Flags().String("assignee", "", "")
+	ready.Flags().Bool("unassigned", false, "")
+	ready.Flags().StringSlice("label", nil, "")
+	ready.Flags().String("type", "", "")
+	ready.Flags().Bool("claim", false, "")
+	root.AddCommand(show, ready)
+
+	if fwd := daemonForwardFor(show, []string{"bd-1", "bd-2"}); fwd == nil || fwd.method != "show" || fwd.params["details"] != true {
+		t.Errorf("show = %+v", fwd)
+	}
+	if fwd := daemonForwardFor(show, nil); fwd != nil {
+		t.Errorf("show without ids = %+v, want nil", fwd)
+	}
+
+	_ = ready.Flags().Set("priority", "0")
+	_ = ready.Flags().Set("type", "mr")
+	fwd := daemonForwardFor(ready, nil)
+	if fwd == nil || fwd.method != "ready" || fwd.params["priority"] != 0 || fwd.params["type"] != "merge-request" || fwd.params["limit"] != 100 {
+		t.Fatalf("ready = %+v", fwd)
+	}
+	if _, ok := fwd.out.(*[]*types.IssueWithCounts); !ok {
+		t.Errorf("ready result type = %T", fwd.out)
+	}
+
+	// A flag the daemon doesn't handle means the command runs directly.
+	_ = ready.Flags().Set("claim", "true")
+	if fwd := daemonForwardFor(ready, nil); fwd != nil {
+		t.Errorf("ready --claim = %+v, want nil", fwd)
+	}
+	_ = show.Flags().Set("thread", "true")
+	if fwd := daemonForwardFor(show, []string{"bd-1"}); fwd != nil {
+		t.Errorf("show --thread = %+v, want nil", fwd)
+	}
+}
+
+func TestDaemonSubscribe(t *testing.T) {
+	t.Parallel()
+	server, client := net.Pipe()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads"}
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+	go d.serveConn(ctx, server)
+	defer func() { _ = client.Close() }()
+	reader := bufio.NewReader(client)
+	readLine := func() map[string]interface{} {
+		t.Helper()
+		data, err := reader.ReadBytes('\n')
+		if err != nil {
+			t.Fatalf("read: %v", err)
+		}
+		var msg map[string]interface{}
+		if err := json.Unmarshal(data, &msg); err != nil {
+			t.Fatalf("message %q: %v", data, err)
+		}
+		return msg
+	}
+
+	if _, err := client.Write([]byte(`{"jsonrpc":"2.0","id":1,"method":"subscribe","params":{"types":["closed"]}}` + "\n")); err != nil {
+		t.Fatalf("write: %v", err)
+	}
+	if ack := readLine(); ack["error"] != nil || ack["id"] != float64(1) {
+		t.Fatalf("subscribe = %v", ack)
+	}
+
+	// The subscription is registered before the ack; only the closed
+	// event is delivered.
+	d.events.publish(daemonEvent{Type: "created", IssueIDs: []string{"bd-1"}})
+	d.events.publish(daemonEvent{Type: "closed", IssueIDs: []string{"bd-1"}})
+	msg := readLine()
+	params, _ := msg["params"].(map[string]interface{})
+	if msg["method"] != "event" || params["type"] != "closed" {
+		t.Errorf("event = %v", msg)
+	}
+}
+
+func TestDaemonWriteEvent(t *testing.T) {
+	t.Parallel()
+	tests := map[string]string{
+		"create": "created",
+		"close":  "closed",
+		"update": "updated",
+		"label":  "updated",
+	}
+	for op, want := range tests {
+		if got := daemonWriteEvent(op, json.RawMessage(`{"id":"bd-1"}`)); got != want {
+			t.Errorf("daemonWriteEvent(%s) = %s, want %s", op, got, want)
+		}
+	}
+	if got := daemonWriteEvent("update", json.RawMessage(`{"id":"bd-1","claim":true}`)); got != "claimed" {
+		t.Errorf("claim = %s, want claimed", got)
+	}
+}
+
+func TestDaemonGlobalRouting(t *testing.T) {
+	t.Parallel()
+	root := t.TempDir()
+	var repos []string
+	for _, name := range []string{"alpha", "beta"} {
+		repo := filepath.Join(root, name)
+		if err := os.MkdirAll(filepath.Join(repo, ".beads"), 0o750); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(filepath.Join(repo, ".beads", "metadata.json"), []byte("{}\n"), 0o600); err != nil {
+			t.Fatal(err)
+		}
+		repos = append(repos, repo)
+	}
+	opens := 0
+	d := &daemonServer{global: true}
+	d.workspaces.open = func(ctx context.Context, beadsDir string) (storage.DoltStorage, error) {
+		opens++
+		return nil, nil
+	}
+	ctx := context.Background()
+	call := func(op, cwd string) (interface{}, error) {
+		t.Helper()
+		return d.handle(ctx, &daemonRequest{Operation: op, Cwd: cwd})
+	}
+
+	if data, err := call("ping", ""); err != nil || data.(map[string]interface{})["global"] != true {
+		t.Errorf("ping without cwd = %v, %v", data, err)
+	}
+	if _, err := call("list", ""); rpcErrorCode(err) != rpcInvalidParams {
+		t.Errorf("list without cwd: err = %v, want invalid params", err)
+	}
+	if _, err := call("ping", root); rpcErrorCode(err) != rpcWrongWorkspace {
+		t.Errorf("ping outside a workspace: err = %v, want wrong workspace", err)
+	}
+	for _, repo := range repos {
+		data, err := call("ping", repo)
+		if err != nil {
+			t.Fatalf("ping %s: %v", repo, err)
+		}
+		if dir := data.(map[string]interface{})["beads_dir"].(string); !sameDaemonPath(dir, filepath.Join(repo, ".beads")) {
+			t.Errorf("ping %s answered by %s", repo, dir)
+		}
+	}
+	if _, err := call("ping", filepath.Join(repos[0], ".beads")); err != nil {
+		t.Errorf("second ping: %v", err)
+	}
+	if opens != 2 {
+		t.Errorf("opened %d stores, want one per workspace", opens)
+	}
+	data, err := call("workspaces", "")
+	if err != nil {
+		t.Fatalf("workspaces: %v", err)
+	}
+	if ws := data.([]daemonWorkspaceInfo); len(ws) != 2 || !strings.HasSuffix(ws[0].BeadsDir, filepath.Join("alpha", ".beads")) {
+		t.Errorf("workspaces = %+v", ws)
+	}
+}
+
+func TestDaemonRunLimits(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", queueTimeout: 50 * time.Millisecond, workers: make(chan struct{}, 1)}
+
+	if _, err := d.run(ctx, &daemonRequest{Operation: "ping"}); err != nil {
+		t.Fatalf("ping: %v", err)
+	}
+
+	// A panicking handler (list without a store) is an error response, and
+	// its worker is released.
+	if _, err := d.run(ctx, &daemonRequest{Operation: "list"}); rpcErrorCode(err) != rpcInternalError {
+		t.Errorf("panic: err = %v, want internal error", err)
+	}
+
+	// A read that has its worker waits behind a write for as long as it
+	// takes; the timeout only covers getting a worker.
+	d.mu.Lock()
+	done := make(chan error, 1)
+	go func() {
+		_, err := d.run(ctx, &daemonRequest{Operation: "ping"})
+		done <- err
+	}()
+	time.Sleep(2 * d.queueTimeout)
+	d.mu.Unlock()
+	if err := <-done; err != nil {
+		t.Errorf("blocked read: err = %v, want it answered", err)
+	}
+
+	// With every worker taken, a request is turned away as busy.
+	d.workers <- struct{}{}
+	if _, err := d.run(ctx, &daemonRequest{Operation: "ping"}); rpcErrorCode(err) != rpcServerBusy {
+		t.Errorf("no free worker: err = %v, want busy", err)
+	}
+}
+
+// hangingReadStore is a store whose searches run until their context ends.
+type hangingReadStore struct {
+	storage.DoltStorage
+}
+
+func (s *hangingReadStore) SearchIssues(ctx context.Context, _ string, _ types.IssueFilter) ([]*types.Issue, error) {
+	<-ctx.Done()
+	return nil, ctx.Err()
+}
+
+func TestDaemonRunReadTimeout(t *testing.T) {
+	t.Parallel()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", store: &hangingReadStore{}, requestTimeout: 50 * time.Millisecond, workers: make(chan struct{}, 1)}
+
+	_, err := d.run(context.Background(), &daemonRequest{Operation: "list"})
+	if rpcErrorCode(err) != rpcServerBusy || !strings.Contains(err.Error(), "did not finish within 50ms") {
+		t.Errorf("hanging list: err = %v, want it cut off", err)
+	}
+	// Its worker is free again.
+	if _, err := d.run(context.Background(), &daemonRequest{Operation: "ping"}); err != nil {
+		t.Errorf("ping after timeout: %v", err)
+	}
+}
+
+// slowWriteStore is a batchTestStore whose transactions take a while,
+// reporting when one starts and how its context looked when it ended.
+type slowWriteStore struct {
+	batchTestStore
+	delay   time.Duration
+	started chan struct{}
+	ctxErr  error
+}
+
+func (s *slowWriteStore) RunInTransaction(ctx context.Context, commitMsg string, fn func(tx storage.Transaction) error) error {
+	close(s.started)
+	time.Sleep(s.delay)
+	s.ctxErr = ctx.Err()
+	if s.ctxErr != nil {
+		return s.ctxErr
+	}
+	return s.batchTestStore.RunInTransaction(ctx, commitMsg, fn)
+}
+
+func (s *slowWriteStore) Commit(ctx context.Context, message string) error {
+	return nil
+}
+
+func TestDaemonRunWriteOutlivesTimeout(t *testing.T) {
+	t.Parallel()
+	st := &slowWriteStore{
+		batchTestStore: batchTestStore{committed: map[string]*types.Issue{}},
+		delay:          200 * time.Millisecond,
+		started:        make(chan struct{}),
+	}
+	d := &daemonServer{beadsDir: t.TempDir(), store: st, noAutoExport: true, queueTimeout: 50 * time.Millisecond, workers: make(chan struct{}, 1)}
+
+	// The write runs well past the queue timeout, and its caller goes away
+	// while it is in progress.
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan error, 1)
+	go func() {
+		_, err := d.run(ctx, &daemonRequest{Operation: "batch", Args: json.RawMessage(`{"ops": [{"operation": "create", "args": {"title": "Slow"}}]}`)})
+		done <- err
+	}()
+	<-st.started
+	cancel()
+
+	// Meanwhile a request that can't get a worker in time is turned away.
+	if _, err := d.run(context.Background(), &daemonRequest{Operation: "ping"}); rpcErrorCode(err) != rpcServerBusy {
+		t.Errorf("queued request: err = %v, want busy", err)
+	}
+
+	if err := <-done; err != nil {
+		t.Fatalf("write: err = %v, want it to complete", err)
+	}
+	if st.ctxErr != nil {
+		t.Errorf("write context ended with %v", st.ctxErr)
+	}
+	if len(st.committed) != 1 {
+		t.Errorf("write left %d issues, want 1", len(st.committed))
+	}
+}
+
+func TestDaemonStatus(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", startedAt: time.Now().UTC()}
+	if _, err := d.run(ctx, &daemonRequest{Operation: "ping"}); err != nil {
+		t.Fatalf("ping: %v", err)
+	}
+	if _, err := d.run(ctx, &daemonRequest{Operation: "frobnicate"}); err == nil {
+		t.Fatal("expected an error for an unknown operation")
+	}
+	data, err := d.run(ctx, &daemonRequest{Operation: "status"})
+	if err != nil {
+		t.Fatalf("status: %v", err)
+	}
+	st := data.(*daemonStatus)
+	if st.PID != os.Getpid() || st.Protocol != daemonProtocolVersion || st.Global {
+		t.Errorf("status = %+v", st)
+	}
+	// The status request itself is counted once it has been answered.
+	if st.Requests != 2 || st.Failures != 1 || st.LastError == nil || st.LastError.Operation != "frobnicate" {
+		t.Errorf("counts = %d/%d, last error = %+v", st.Requests, st.Failures, st.LastError)
+	}
+	if len(st.Workspaces) != 1 || st.Workspaces[0].BeadsDir != d.beadsDir {
+		t.Errorf("workspaces = %+v", st.Workspaces)
+	}
+}
+
+func TestDaemonGracefulShutdown(t *testing.T) {
+	t.Parallel()
+	ln, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		t.Fatalf("listen: %v", err)
+	}
+	d := &daemonServer{beadsDir: "/tmp/project/.beads"}
+	done := make(chan error, 1)
+	go func() { done <- d.serve(context.Background(), ln) }()
+
+	conn, err := net.Dial("tcp", ln.Addr().String())
+	if err != nil {
+		t.Fatalf("dial: %v", err)
+	}
+	defer func() { _ = conn.Close() }()
+
+	// Hold the write lock so the ping is still being handled when the
+	// daemon is told to stop.
+	d.mu.Lock()
+	if _, err := conn.Write([]byte(`{"operation":"ping"}` + "\n")); err != nil {
+		t.Fatalf("write: %v", err)
+	}
+	for deadline := time.Now().Add(5 * time.Second); d.inflight.Load() == 0; {
+		if time.Now().After(deadline) {
+			t.Fatal("ping never reached the handler")
+		}
+		time.Sleep(5 * time.Millisecond)
+	}
+	d.shutdown()
+	time.Sleep(20 * time.Millisecond)
+	d.mu.Unlock()
+
+	var resp daemonResponse
+	if err := json.NewDecoder(conn).Decode(&resp); err != nil || !resp.Success {
+		t.Errorf("in-flight ping = %+v, %v; want it answered", resp, err)
+	}
+	select {
+	case err := <-done:
+		if err != nil {
+			t.Errorf("serve: %v", err)
+		}
+	case <-time.After(5 * time.Second):
+		t.Fatal("daemon did not exit after shutdown")
+	}
+}
+
+func TestCleanStaleDaemon(t *testing.T) {
+	t.Parallel()
+	dir := t.TempDir()
+	discoveryPath := filepath.Join(dir, daemonDiscoveryName)
+	socketPath := filepath.Join(dir, daemonSocketName)
+	if err := os.WriteFile(socketPath, nil, 0o600); err != nil {
+		t.Fatal(err)
+	}
+	info := &daemonDiscovery{Network: "unix", Address: socketPath, PID: 1}
+	if err := writeDaemonDiscovery(discoveryPath, info); err != nil {
+		t.Fatal(err)
+	}
+	cleanStaleDaemon(discoveryPath, info)
+	for _, path := range []string{discoveryPath, socketPath} {
+		if _, err := os.Stat(path); !os.IsNotExist(err) {
+			t.Errorf("%s not removed: %v", filepath.Base(path), err)
+		}
+	}
+}
+
+func TestDaemonPipeName(t *testing.T) {
+	t.Parallel()
+	a := daemonPipeName(filepath.Join("/tmp", "alpha", ".beads", daemonDiscoveryName))
+	b := daemonPipeName(filepath.Join("/tmp", "beta", ".beads", daemonDiscoveryName))
+	if !strings.HasPrefix(a, `\\.\pipe\beads-`) || len(a) != len(`\\.\pipe\beads-`)+16 {
+		t.Errorf("pipe name = %q", a)
+	}
+	if a == b {
+		t.Errorf("two workspaces share pipe %q", a)
+	}
+	if again := daemonPipeName(filepath.Join("/tmp", "alpha", ".beads", daemonDiscoveryName)); again != a {
+		t.Errorf("pipe name not stable: %q, then %q", a, again)
+	}
+}
+
+func TestDaemonHello(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads"}
+	hello := func(args string) (*daemonCapabilities, error) {
+		t.Helper()
+		data, err := d.handle(ctx, &daemonRequest{Operation: "hello", Args: json.RawMessage(args)})
+		if err != nil {
+			return nil, err
+		}
+		return data.(*daemonCapabilities), nil
+	}
+
+	caps, err := hello(`{"client":"test"}`)
+	if err != nil || caps.ProtocolVersion != daemonProtocolVersion || !slices.Contains(caps.Operations, "subscribe") {
+		t.Errorf("hello = %+v, %v", caps, err)
+	}
+	if caps, err := hello(`{"protocol_version":2}`); err != nil || caps.ProtocolVersion != 2 {
+		t.Errorf("hello from a v2 client = %+v, %v", caps, err)
+	}
+	if caps, err := hello(`{"protocol_version":99}`); err != nil || caps.ProtocolVersion != daemonProtocolVersion {
+		t.Errorf("hello from a newer client = %+v, %v", caps, err)
+	}
+	if _, err := hello(`{"protocol_version":-1}`); rpcErrorCode(err) != rpcInvalidParams || rpcErrorData(err) == nil {
+		t.Errorf("hello from an unsupported client: err = %v", err)
+	}
+
+	// An unknown method's error lists the supported ones, in both framings.
+	var rpcResp rpcResponse
+	data, _ := json.Marshal(d.dispatchLine(ctx, []byte(`{"jsonrpc":"2.0","id":1,"method":"frobnicate"}`)))
+	_ = json.Unmarshal(data, &rpcResp)
+	if rpcResp.Error == nil || rpcResp.Error.Code != rpcMethodNotFound || !strings.Contains(string(data), `"operations":[`) {
+		t.Errorf("unknown method = %s", data)
+	}
+	legacy, _ := d.dispatchLine(ctx, []byte(`{"operation":"frobnicate"}`)).(daemonResponse)
+	if legacy.Code != rpcMethodNotFound || legacy.Details == nil {
+		t.Errorf("unknown operation = %+v", legacy)
+	}
+}
+
+func TestDaemonMetrics(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", startedAt: time.Now().UTC()}
+	_, _ = d.run(ctx, &daemonRequest{Operation: "ping"})
+	_, _ = d.run(ctx, &daemonRequest{Operation: "frobnicate"})
+	d.stats.recordImport(3, nil)
+
+	data, err := d.handle(ctx, &daemonRequest{Operation: "metrics", Args: json.RawMessage(`{"format":"prometheus"}`)})
+	if err != nil {
+		t.Fatalf("metrics: %v", err)
+	}
+	text := data.(string)
+	for _, want := range []string{
+		"# TYPE bd_daemon_requests_total counter\n",
+		`bd_daemon_requests_total{operation="ping"} 1` + "\n",
+		`bd_daemon_request_errors_total{operation="frobnicate"} 1` + "\n",
+		"bd_daemon_imported_issues_total 3\n",
+		"# TYPE bd_issues_ready gauge\n",
+	} {
+		if !strings.Contains(text, want) {
+			t.Errorf("metrics missing %q:\n%s", want, text)
+		}
+	}
+	if _, err := d.handle(ctx, &daemonRequest{Operation: "metrics", Args: json.RawMessage(`{"format":"xml"}`)}); rpcErrorCode(err) != rpcInvalidParams {
+		t.Errorf("unknown format: err = %v", err)
+	}
+	if got := promLabel("a\"b\\c\nd"); got != `"a\"b\\c\nd"` {
+		t.Errorf("promLabel = %s", got)
+	}
+}
+
+func TestDaemonRequestLog(t *testing.T) {
+	t.Parallel()
+	db, mock, err := sqlmock.New()
+	if err != nil {
+		t.Fatalf("sqlmock: %v", err)
+	}
+	defer db.Close()
+	ctx := withDaemonPeer(context.Background(), "tcp 127.0.0.1:5000")
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", token: "s3cret", log: &daemonLog{db: db}}
+
+	mock.ExpectExec("INSERT INTO daemon_log").
+		WithArgs(sqlmock.AnyArg(), "frobnicate", "alice", "tcp 127.0.0.1:5000", daemonTokenID("s3cret"), "", "", false, `unknown operation "frobnicate"`, sqlmock.AnyArg()).
+		WillReturnResult(sqlmock.NewResult(1, 1))
+	mock.ExpectExec("INSERT INTO daemon_log").
+		WithArgs(sqlmock.AnyArg(), "list", "mallory", "tcp 127.0.0.1:5000", daemonTokenID("guess"), "", "", false, sqlmock.AnyArg(), sqlmock.AnyArg()).
+		WillReturnResult(sqlmock.NewResult(2, 1))
+	_, _ = d.handle(ctx, &daemonRequest{Operation: "ping", Token: "s3cret"}) // not logged
+	_, _ = d.handle(ctx, &daemonRequest{Operation: "frobnicate", Actor: "alice", Token: "s3cret"})
+	_, _ = d.handle(ctx, &daemonRequest{Operation: "list", Actor: "mallory", Token: "guess"})
+	if err := mock.ExpectationsWereMet(); err != nil {
+		t.Error(err)
+	}
+
+	l := &daemonLog{daemonLogConfig: daemonLogConfig{retention: time.Hour, maxRows: 10}, db: db}
+	mock.ExpectExec("DELETE FROM daemon_log WHERE created_at").WillReturnResult(sqlmock.NewResult(0, 3))
+	mock.ExpectQuery("SELECT MAX").WillReturnRows(sqlmock.NewRows([]string{"max"}).AddRow(25))
+	mock.ExpectExec("DELETE FROM daemon_log WHERE id").WithArgs(int64(15)).WillReturnResult(sqlmock.NewResult(0, 15))
+	if err := l.rotate(context.Background()); err != nil {
+		t.Errorf("rotate: %v", err)
+	}
+	if err := mock.ExpectationsWereMet(); err != nil {
+		t.Error(err)
+	}
+
+	server, client := net.Pipe()
+	defer func() { _ = server.Close(); _ = client.Close() }()
+	if got := daemonPeer(server); got != "pipe" {
+		t.Errorf("daemonPeer(net.Pipe) = %q", got)
+	}
+	if daemonTokenID("") != "" || len(daemonTokenID("s3cret")) != 12 {
+		t.Errorf("daemonTokenID = %q", daemonTokenID("s3cret"))
+	}
+}
+
+// batchTestStore runs transactions against an in-memory batchTestTx,
+// keeping its writes only if the transaction succeeds.
+type batchTestStore struct {
+	storage.DoltStorage
+	committed map[string]*types.Issue
+}
+
+func (s *batchTestStore) RunInTransaction(ctx context.Context, commitMsg string, fn func(tx storage.Transaction) error) error {
+	tx := &batchTestTx{issues: maps.Clone(s.committed)}
+	if err := fn(tx); err != nil {
+		return err
+	}
+	s.committed = tx.issues
+	return nil
+}
+
+// SearchIssues and GetConfig answer the partial ID lookups of the
+// handlers from the committed issues.
+func (s *batchTestStore) SearchIssues(ctx context.Context, query string, filter types.IssueFilter) ([]*types.Issue, error) {
+	var found []*types.Issue
+	for id, issue := range s.committed {
+		if slices.Contains(filter.IDs, id) || (query != "" && strings.Contains(id, query)) {
+			found = append(found, issue)
+		}
+	}
+	return found, nil
+}
+
+func (s *batchTestStore) GetConfig(ctx context.Context, key string) (string, error) {
+	return "", nil
+}
+
+type batchTestTx struct {
+	storage.Transaction
+	issues map[string]*types.Issue
+}
+
+func (tx *batchTestTx) CreateIssue(ctx context.Context, issue *types.Issue, actor string) error {
+	if issue.ID == "" {
+		issue.ID = fmt.Sprintf("bd-%d", len(tx.issues)+1)
+	}
+	tx.issues[issue.ID] = issue
+	return nil
+}
+
+func (tx *batchTestTx) GetIssue(ctx context.Context, id string) (*types.Issue, error) {
+	if issue, ok := tx.issues[id]; ok {
+		return issue, nil
+	}
+	return nil, fmt.Errorf("%w: issue %s", storage.ErrNotFound, id)
+}
+
+func (tx *batchTestTx) AddDependency(ctx context.Context, dep *types.Dependency, actor string) error {
+	issue, err := tx.GetIssue(ctx, dep.IssueID)
+	if err != nil {
+		return err
+	}
+	if _, err := tx.GetIssue(ctx, dep.DependsOnID); err != nil {
+		return err
+	}
+	issue.Dependencies = append(issue.Dependencies, dep)
+	return nil
+}
+
+func (tx *batchTestTx) AddLabel(ctx context.Context, issueID, label, actor string) error {
+	issue, err := tx.GetIssue(ctx, issueID)
+	if err != nil {
+		return err
+	}
+	issue.Labels = append(issue.Labels, label)
+	return nil
+}
+
+func (tx *batchTestTx) GetLabels(ctx context.Context, issueID string) ([]string, error) {
+	issue, err := tx.GetIssue(ctx, issueID)
+	if err != nil {
+		return nil, err
+	}
+	return issue.Labels, nil
+}
+
+func TestDaemonBatch(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	st := &batchTestStore{committed: map[string]*types.Issue{}}
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", store: st}
+
+	data, ids, err := d.batch(ctx, json.RawMessage(`{"ops": [
+		{"operation": "create", "args": {"title": "Parent"}},
+		{"operation": "create", "args": {"title": "Child", "parent": "$0"}},
+		{"operation": "dep", "args": {"issue_id": "$1", "depends_on_id": "$0", "type": "related"}},
+		{"operation": "label", "args": {"issue_id": "$1", "labels": ["agent", "triage"]}}
+	]}`), "alice")
+	if err != nil {
+		t.Fatalf("batch: %v", err)
+	}
+	results := data.(*daemonBatchResult).Results
+	if len(results) != 4 || !results[3].Success || strings.Join(ids, ",") != "bd-1,bd-2" {
+		t.Fatalf("batch = %+v, ids %v", results, ids)
+	}
+	child := st.committed["bd-2"]
+	if len(child.Dependencies) != 2 || child.Dependencies[1].DependsOnID != "bd-1" || strings.Join(child.Labels, ",") != "agent,triage" {
+		t.Errorf("child = %+v", child)
+	}
+
+	// A failing op rolls back the ones before it.
+	_, _, err = d.batch(ctx, json.RawMessage(`{"ops": [
+		{"operation": "create", "args": {"title": "Orphan"}},
+		{"operation": "dep", "args": {"issue_id": "$0", "depends_on_id": "bd-99"}}
+	]}`), "alice")
+	if rpcErrorCode(err) != rpcNotFound || !strings.Contains(err.Error(), "ops[1] (dep)") {
+		t.Errorf("failing batch: err = %v", err)
+	}
+	if data, _ := rpcErrorData(err).(map[string]interface{}); data["index"] != 1 {
+		t.Errorf("failing batch data = %+v", rpcErrorData(err))
+	}
+	if len(st.committed) != 2 {
+		t.Errorf("failed batch left %d issues, want 2", len(st.committed))
+	}
+
+	for name, args := range map[string]string{
+		"empty":         `{"ops": []}`,
+		"read op":       `{"ops": [{"operation": "list"}]}`,
+		"nested":        `{"ops": [{"operation": "batch"}]}`,
+		"forward ref":   `{"ops": [{"operation": "label", "args": {"issue_id": "$0", "label": "x"}}]}`,
+		"claim":         `{"ops": [{"operation": "update", "args": {"id": "bd-1", "claim": true}}]}`,
+		"invalid items": `{"ops": {}}`,
+	} {
+		if _, _, err := d.batch(ctx, json.RawMessage(args), "alice"); rpcErrorCode(err) != rpcInvalidParams {
+			t.Errorf("%s: err = %v, want invalid params", name, err)
+		}
+	}
+}
+
+// guardTestStore holds open issues, each blocked by the ones listed in
+// blockers, for the guards the daemon's write handlers apply.
+type guardTestStore struct {
+	storage.DoltStorage
+	issues   map[string]*types.Issue
+	blockers map[string][]string
+	config   map[string]string
+	closed   []string
+}
+
+func (s *guardTestStore) GetIssue(ctx context.Context, id string) (*types.Issue, error) {
+	if issue, ok := s.issues[id]; ok {
+		return issue, nil
+	}
+	return nil, fmt.Errorf("%w: issue %s", storage.ErrNotFound, id)
+}
+
+func (s *guardTestStore) SearchIssues(ctx context.Context, query string, filter types.IssueFilter) ([]*types.Issue, error) {
+	var found []*types.Issue
+	for id, issue := range s.issues {
+		if slices.Contains(filter.IDs, id) || (query != "" && strings.Contains(id, query)) {
+			found = append(found, issue)
+		}
+	}
+	return found, nil
+}
+
+func (s *guardTestStore) GetConfig(ctx context.Context, key string) (string, error) {
+	return s.config[key], nil
+}
+
+func (s *guardTestStore) GetCustomStatuses(ctx context.Context) ([]string, error) {
+	return nil, nil
+}
+
+func (s *guardTestStore) IsBlocked(ctx context.Context, id string) (bool, []string, error) {
+	return len(s.blockers[id]) > 0, s.blockers[id], nil
+}
+
+func (s *guardTestStore) CloseIssue(ctx context.Context, id, reason, actor, session string) error {
+	s.closed = append(s.closed, id)
+	return nil
+}
+
+// RunInTransaction runs a batch against a transaction that only reads.
+func (s *guardTestStore) RunInTransaction(ctx context.Context, commitMsg string, fn func(tx storage.Transaction) error) error {
+	return fn(&guardTestTx{store: s})
+}
+
+type guardTestTx struct {
+	storage.Transaction
+	store *guardTestStore
+}
+
+func (tx *guardTestTx) GetIssue(ctx context.Context, id string) (*types.Issue, error) {
+	return tx.store.GetIssue(ctx, id)
+}
+
+func TestDaemonCloseGuards(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	st := &guardTestStore{
+		issues: map[string]*types.Issue{
+			"bd-a1": {ID: "bd-a1", Title: "Blocked", Status: types.StatusOpen, IssueType: types.TypeTask},
+			"bd-b2": {ID: "bd-b2", Title: "Blocker", Status: types.StatusOpen, IssueType: types.TypeTask},
+		},
+		blockers: map[string][]string{"bd-a1": {"bd-b2"}},
+	}
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", store: st}
+
+	// The partial ID resolves, and the open blocker refuses the close.
+	_, _, err := d.close(ctx, st, json.RawMessage(`{"id": "a1"}`), "alice")
+	if err == nil || !strings.Contains(err.Error(), "cannot close bd-a1: blocked by open issues") {
+		t.Errorf("close of a blocked issue: err = %v", err)
+	}
+	// Closing through update is refused the same way.
+	_, _, err = d.update(ctx, st, json.RawMessage(`{"id": "bd-a1", "status": "closed"}`), "alice")
+	if err == nil || !strings.Contains(err.Error(), "blocked by open issues") {
+		t.Errorf("update of a blocked issue to closed: err = %v", err)
+	}
+	if _, _, err := d.close(ctx, st, json.RawMessage(`{"id": "bd-zz"}`), "alice"); rpcErrorCode(err) != rpcNotFound {
+		t.Errorf("close of a missing issue: err = %v", err)
+	}
+	if len(st.closed) != 0 {
+		t.Errorf("closed %v despite the guards", st.closed)
+	}
+}
+
+func TestDaemonStatusGuards(t *testing.T) {
+	t.Parallel()
+	ctx := context.Background()
+	st := &guardTestStore{
+		issues: map[string]*types.Issue{
+			"bd-a1": {ID: "bd-a1", Title: "Unassigned", Status: types.StatusOpen, IssueType: types.TypeTask},
+		},
+		config: map[string]string{"status.guards": "in_progress-needs-assignee"},
+	}
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", store: st}
+
+	_, _, err := d.update(ctx, st, json.RawMessage(`{"id": "bd-a1", "status": "in_progress"}`), "alice")
+	if err == nil || !strings.Contains(err.Error(), "status guard in_progress-needs-assignee") {
+		t.Errorf("update: err = %v, want the status guard", err)
+	}
+	// A batch item is held to it too.
+	_, _, err = d.batch(ctx, json.RawMessage(`{"ops": [
+		{"operation": "update", "args": {"id": "bd-a1", "status": "in_progress"}}
+	]}`), "alice")
+	if err == nil || !strings.Contains(err.Error(), "status guard in_progress-needs-assignee") {
+		t.Errorf("batch update: err = %v, want the status guard", err)
+	}
+}
+
+func TestDaemonValidationRules(t *testing.T) {
+	config.ResetForTesting()
+	t.Cleanup(config.ResetForTesting)
+	if err := config.Initialize(); err != nil {
+		t.Fatalf("config.Initialize: %v", err)
+	}
+	config.Set("validation.title-max-length", 10)
+
+	ctx := context.Background()
+	st := &guardTestStore{issues: map[string]*types.Issue{
+		"bd-a1": {ID: "bd-a1", Title: "Short", Status: types.StatusOpen, IssueType: types.TypeTask},
+	}}
+	d := &daemonServer{beadsDir: "/tmp/project/.beads", store: st}
+
+	_, _, err := d.create(ctx, st, json.RawMessage(`{"title": "A title well over the limit"}`), "alice")
+	if err == nil || !strings.Contains(err.Error(), "characters or less") {
+		t.Errorf("create: err = %v, want the title length rule", err)
+	}
+	_, _, err = d.update(ctx, st, json.RawMessage(`{"id": "bd-a1", "title": "A title well over the limit"}`), "alice")
+	if err == nil || !strings.Contains(err.Error(), "characters or less") {
+		t.Errorf("update: err = %v, want the title length rule", err)
+	}
+}
@@ -0,0 +1,28 @@
+//go:build unix
+
+package main
+
+import (
+	"errors"
+	"net"
+	"syscall"
+	"time"
+)
+
+// daemonProcAttr detaches an auto-started daemon from the terminal session,
+// so it outlives the command that started it.
+func daemonProcAttr() *syscall.SysProcAttr {
+	return &syscall.SysProcAttr{Setsid: true}
+}
+
+// errNoNamedPipes is returned for a discovery file naming a Windows named
+// pipe, e.g. one read through a shared filesystem.
+var errNoNamedPipes = errors.New("named pipes are only available on Windows")
+
+func listenDaemonPipe(name string) (net.Listener, error) {
+	return nil, errNoNamedPipes
+}
+
+func dialDaemonPipe(name string, timeout time.Duration) (net.Conn, error) {
+	return nil, errNoNamedPipes
+}
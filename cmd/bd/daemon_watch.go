@@ -0,0 +1,133 @@
+package main
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// daemonWatchInterval is how often the daemon checks the JSONL export for
+// changes made outside bd (git pull, checkout, merge).
+const daemonWatchInterval = 2 * time.Second
+
+// jsonlWatcher detects changes to the JSONL export. It polls rather than
+// using fsnotify, like 'bd show --watch': git replaces the file by rename,
+// and polling also works on network and bind-mounted filesystems. The
+// size and mtime are checked first; the content hash only when they moved.
+type jsonlWatcher struct {
+	path    string
+	size    int64
+	modTime time.Time
+	sum     [sha256.Size]byte
+}
+
+func newJSONLWatcher(path string) *jsonlWatcher {
+	w := &jsonlWatcher{path: path}
+	w.changed()
+	return w
+}
+
+// changed reports whether the file's content differs from the last call
+// (or from construction). A missing file is never a change.
+func (w *jsonlWatcher) changed() bool {
+	info, err := os.Stat(w.path)
+	if err != nil {
+		return false
+	}
+	if info.Size() == w.size && info.ModTime().Equal(w.modTime) {
+		return false
+	}
+	data, err := os.ReadFile(w.path)
+	if err != nil {
+		return false
+	}
+	w.size, w.modTime = info.Size(), info.ModTime()
+	sum := sha256.Sum256(data)
+	if bytes.Equal(sum[:], w.sum[:]) {
+		return false
+	}
+	w.sum = sum
+	return true
+}
+
+// watchJSONL imports the JSONL export whenever it changes on disk, until
+// ctx is canceled.
+func (d *daemonServer) watchJSONL(ctx context.Context) {
+	ticker := time.NewTicker(daemonWatchInterval)
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			d.mu.Lock()
+			if d.watcher.changed() {
+				ids, err := d.importJSONLDelta(ctx)
+				d.stats.recordImport(len(ids), err)
+				if err != nil {
+					fmt.Fprintf(os.Stderr, "bd daemon: auto-import of %s failed: %v\n", d.watcher.path, err)
+				} else if len(ids) > 0 {
+					debug.Logf("daemon: auto-imported %d changed issue(s) from %s", len(ids), d.watcher.path)
+					d.events.publish(daemonEvent{Type: "imported", IssueIDs: ids})
+				}
+			}
+			d.mu.Unlock()
+		}
+	}
+}
+
+// importJSONLDelta imports the issues of the JSONL export that are new or
+// differ from the database, applies its deletion records, and returns the
+// IDs of the issues changed. Issues the database has a newer copy of are
+// left alone, as in 'bd import'.
+func (d *daemonServer) importJSONLDelta(ctx context.Context) ([]string, error) {
+	fileIssues, entries, err := parseJSONLFile(d.watcher.path)
+	if err != nil {
+		return nil, err
+	}
+	deleted, err := applyTombstones(ctx, d.store, tombstonesIn(entries))
+	if err != nil {
+		return nil, err
+	}
+	if fileIssues, _, err = withoutTombstoned(ctx, d.store, fileIssues); err != nil {
+		return nil, err
+	}
+	for _, issue := range fileIssues {
+		canonicalizeExportIssue(issue)
+	}
+	live, err := loadRoundTripIssues(ctx, d.store, fileIssues)
+	if err != nil {
+		return nil, err
+	}
+	changed := make(map[string]bool)
+	for _, m := range compareRoundTrip(fileIssues, live) {
+		changed[m.ID] = true
+	}
+	var delta []*types.Issue
+	for _, issue := range fileIssues {
+		if changed[issue.ID] {
+			delta = append(delta, issue)
+		}
+	}
+	changedIDs := deleted
+	if len(delta) > 0 {
+		result, err := importIssuesCore(ctx, "", d.store, delta, ImportOptions{SkipPrefixValidation: true})
+		if err != nil {
+			return nil, err
+		}
+		changedIDs = append(changedIDs, result.ImportedIDs...)
+	}
+	if len(changedIDs) == 0 {
+		return nil, nil
+	}
+	if err := maybeAutoCommitStore(ctx, d.store, doltAutoCommitParams{Command: "auto-import", IssueIDs: changedIDs}); err != nil {
+		return nil, fmt.Errorf("dolt auto-commit failed: %w", err)
+	}
+	return changedIDs, nil
+}
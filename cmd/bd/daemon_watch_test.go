@@ -0,0 +1,44 @@
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+func TestJSONLWatcher(t *testing.T) {
+	t.Parallel()
+	path := filepath.Join(t.TempDir(), "issues.jsonl")
+	w := newJSONLWatcher(path)
+	if w.changed() {
+		t.Error("missing file reported as changed")
+	}
+
+	write := func(content string, mtime time.Time) {
+		t.Helper()
+		if err := os.WriteFile(path, []byte(content), 0o600); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.Chtimes(path, mtime, mtime); err != nil {
+			t.Fatal(err)
+		}
+	}
+	base := time.Now().Add(-time.Hour)
+	write(`{"id":"bd-1"}`+"\n", base)
+	if !w.changed() {
+		t.Error("new file not reported as changed")
+	}
+	if w.changed() {
+		t.Error("unchanged file reported as changed")
+	}
+	// Rewritten with the same content (e.g. git checkout): not a change.
+	write(`{"id":"bd-1"}`+"\n", base.Add(time.Minute))
+	if w.changed() {
+		t.Error("same content with a new mtime reported as changed")
+	}
+	write(`{"id":"bd-2"}`+"\n", base.Add(2*time.Minute))
+	if !w.changed() {
+		t.Error("new content not reported as changed")
+	}
+}
@@ -0,0 +1,40 @@
+//go:build windows
+
+package main
+
+import (
+	"fmt"
+	"net"
+	"syscall"
+	"time"
+
+	"github.com/Microsoft/go-winio"
+	"golang.org/x/sys/windows"
+)
+
+// daemonProcAttr detaches an auto-started daemon from the console, so it
+// outlives the command that started it.
+func daemonProcAttr() *syscall.SysProcAttr {
+	return &syscall.SysProcAttr{
+		CreationFlags: windows.DETACHED_PROCESS | windows.CREATE_NEW_PROCESS_GROUP,
+	}
+}
+
+// daemonPipeSDDL limits the pipe to its owner and SYSTEM, as the 0600
+// socket does on Unix.
+const daemonPipeSDDL = "D:P(A;;GA;;;OW)(A;;GA;;;SY)"
+
+// listenDaemonPipe listens on the named pipe name. Unlike a Unix socket, a
+// pipe disappears with the process that created it, so there is never a
+// stale one to remove.
+func listenDaemonPipe(name string) (net.Listener, error) {
+	ln, err := winio.ListenPipe(name, &winio.PipeConfig{SecurityDescriptor: daemonPipeSDDL})
+	if err != nil {
+		return nil, fmt.Errorf("failed to listen on %s: %w", name, err)
+	}
+	return ln, nil
+}
+
+func dialDaemonPipe(name string, timeout time.Duration) (net.Conn, error) {
+	return winio.DialPipe(name, &timeout)
+}
@@ -0,0 +1,124 @@
+package main
+
+import (
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+)
+
+// explainShapes are representative versions of the hot queries behind common
+// commands. They mirror the WHERE/ORDER BY shapes built in issueops so that
+// filter changes can be checked against the index set without tracing a live
+// command. Keep them in sync when a command's query shape changes.
+var explainShapes = map[string][]string{
+	"list": {
+		`SELECT issues.id FROM issues WHERE issues.status = 'open' ORDER BY issues.priority ASC, issues.created_at DESC, issues.id ASC LIMIT 51`,
+		`SELECT issues.id FROM issues WHERE issues.assignee = 'someone' ORDER BY issues.priority ASC, issues.created_at DESC, issues.id ASC LIMIT 51`,
+		`SELECT DISTINCT issues.id FROM issues JOIN labels label_filter_0 ON label_filter_0.issue_id = issues.id WHERE label_filter_0.label = 'backend' ORDER BY issues.priority ASC, issues.created_at DESC, issues.id ASC LIMIT 51`,
+	},
+	"ready": {
+		`SELECT id FROM ready_issues LIMIT 51`,
+	},
+	"stale": {
+		`SELECT id FROM issues WHERE status IN ('open', 'in_progress') AND updated_at < NOW() ORDER BY updated_at ASC LIMIT 50`,
+	},
+	"dependents": {
+		`SELECT issue_id, type FROM dependencies WHERE depends_on_issue_id = 'bd-1'`,
+	},
+	"labels": {
+		`SELECT issue_id FROM labels WHERE label = 'backend'`,
+	},
+}
+
+var debugCmd = &cobra.Command{
+	Use:    "debug",
+	Hidden: true,
+	Short:  "Internal debugging helpers",
+}
+
+var debugExplainCmd = &cobra.Command{
+	Use:   "explain <command>",
+	Short: "Print query plans for the queries behind a command",
+	Long: `Print the database query plans for representative queries issued by a
+command, so filter and schema changes can be checked for index use.
+
+Run without arguments to list the available commands.`,
+	Args: cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		names := make([]string, 0, len(explainShapes))
+		for name := range explainShapes {
+			names = append(names, name)
+		}
+		sort.Strings(names)
+
+		if len(args) == 0 {
+			fmt.Println(strings.Join(names, "\n"))
+			return
+		}
+		queries, ok := explainShapes[args[0]]
+		if !ok {
+			FatalErrorRespectJSON("unknown command %q (available: %s)", args[0], strings.Join(names, ", "))
+		}
+
+		if !usesSQLServer() {
+			fmt.Fprintln(os.Stderr, "Error: 'bd debug explain' is not yet supported in embedded mode")
+			os.Exit(1)
+		}
+		if store == nil {
+			FatalErrorRespectJSON("no database connection available (%s)", diagHint())
+		}
+		accessor, ok := storage.UnwrapStore(store).(storage.RawDBAccessor)
+		if !ok {
+			FatalErrorRespectJSON("storage backend does not support raw DB access")
+		}
+		db := accessor.UnderlyingDB()
+		if db == nil {
+			FatalErrorRespectJSON("underlying database not available")
+		}
+
+		plans := make(map[string][]string, len(queries))
+		for _, query := range queries {
+			rows, err := db.QueryContext(rootCtx, "EXPLAIN "+query)
+			if err != nil {
+				FatalErrorRespectJSON("explain %s: %v", args[0], err)
+			}
+			var plan []string
+			for rows.Next() {
+				var line string
+				if err := rows.Scan(&line); err != nil {
+					_ = rows.Close()
+					FatalErrorRespectJSON("explain %s: scan: %v", args[0], err)
+				}
+				plan = append(plan, line)
+			}
+			_ = rows.Close()
+			if err := rows.Err(); err != nil {
+				FatalErrorRespectJSON("explain %s: %v", args[0], err)
+			}
+			plans[query] = plan
+		}
+
+		if jsonOutput {
+			outputJSON(plans)
+			return
+		}
+		for i, query := range queries {
+			if i > 0 {
+				fmt.Println()
+			}
+			fmt.Printf("-- %s\n", query)
+			for _, line := range plans[query] {
+				fmt.Println(line)
+			}
+		}
+	},
+}
+
+func init() {
+	debugCmd.AddCommand(debugExplainCmd)
+	rootCmd.AddCommand(debugCmd)
+}
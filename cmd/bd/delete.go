@@ -23,9 +23,11 @@ var deleteCmd = &cobra.Command{
 This command will:
 1. Remove all dependency links (any type, both directions) involving the issues
 2. Update text references to "[deleted:ID]" in directly connected issues
-3. Permanently delete the issues from the database
+3. Delete the issues, keeping a copy in the trash
 
-This is a destructive operation that cannot be undone. Use with caution.
+Deleted issues disappear from list, search, and every other view. Bring one
+back with 'bd restore <id>'; see 'bd trash' to list or permanently discard
+them. Text references rewritten to "[deleted:ID]" are not restored.
 
 BATCH DELETION:
 Delete multiple issues at once:
@@ -161,10 +163,18 @@ Force: Delete and orphan dependents
 					fmt.Printf("  (none have text references)\n")
 				}
 			}
-			fmt.Printf("\n%s\n", ui.RenderWarn("This operation cannot be undone!"))
+			fmt.Printf("\n%s\n", ui.RenderWarn("Deleted issues go to the trash (see 'bd trash')."))
 			fmt.Printf("To proceed, run: %s\n\n", ui.RenderWarn("bd delete "+issueID+" --force"))
 			return
 		}
+		// Keep a copy for 'bd restore' before anything changes
+		trashed, err := snapshotForTrash(ctx, activeStore, []string{issueID}, actor)
+		if err != nil {
+			FatalError("%v", err)
+		}
+		if err := putInTrash(ctx, activeStore, trashed); err != nil {
+			FatalError("%v", err)
+		}
 		// Actually delete — all writes in a single transaction
 		updatedIssueCount := 0
 		totalDepsRemoved := 0
@@ -209,9 +219,11 @@ Force: Delete and orphan dependents
 			if err := tx.DeleteIssue(ctx, issueID); err != nil {
 				return fmt.Errorf("delete %s: %w", issueID, err)
 			}
-			return nil
+			// 5. Record a tombstone so a JSONL import does not bring it back
+			return recordTombstones(ctx, tx, []string{issueID}, actor)
 		})
 		if deleteErr != nil {
+			dropUndeletedFromTrash(ctx, activeStore, trashed, nil)
 			FatalError("deleting issue: %v", deleteErr)
 		}
 
@@ -297,7 +309,7 @@ func deleteBatch(_ *cobra.Command, issueIDs []string, force bool, dryRun bool, c
 		if dryRun {
 			fmt.Printf("\n(Dry-run mode - no changes made)\n")
 		} else {
-			fmt.Printf("\n%s\n", ui.RenderWarn("This operation cannot be undone!"))
+			fmt.Printf("\n%s\n", ui.RenderWarn("Deleted issues go to the trash (see 'bd trash')."))
 			if cascade {
 				fmt.Printf("To proceed with cascade deletion, run: %s\n",
 					ui.RenderWarn("bd delete "+strings.Join(issueIDs, " ")+" --cascade --force"))
@@ -334,11 +346,32 @@ func deleteBatch(_ *cobra.Command, issueIDs []string, force bool, dryRun bool, c
 			}
 		}
 	}
-	// Actually delete
-	result, err := batchStore.DeleteIssues(ctx, issueIDs, cascade, force, false)
+	// Everything a cascade may delete, for the trash.
+	deleteCandidates := issueIDs
+	if cascade {
+		deleteCandidates = cascadeDeleteCandidates(ctx, batchStore, issueIDs)
+	}
+	trashed, err := snapshotForTrash(ctx, batchStore, deleteCandidates, actor)
 	if err != nil {
 		FatalError("%v", err)
 	}
+	if err := putInTrash(ctx, batchStore, trashed); err != nil {
+		FatalError("%v", err)
+	}
+	// Actually delete, with the tombstones in the same transaction
+	var result *types.DeleteIssuesResult
+	err = transact(ctx, batchStore, fmt.Sprintf("bd: delete %d issue(s)", len(issueIDs)), func(tx storage.Transaction) error {
+		var err error
+		if result, err = tx.DeleteIssues(ctx, issueIDs, cascade, force); err != nil {
+			return err
+		}
+		return recordTombstones(ctx, tx, result.DeletedIDs, actor)
+	})
+	if err != nil {
+		dropUndeletedFromTrash(ctx, batchStore, trashed, nil)
+		FatalError("%v", err)
+	}
+	dropUndeletedFromTrash(ctx, batchStore, trashed, result.DeletedIDs)
 
 	// Update text references in connected issues (using pre-collected issues)
 	updatedCount := updateTextReferencesInIssues(ctx, issueIDs, connectedIssues)
@@ -369,6 +402,29 @@ func deleteBatch(_ *cobra.Command, issueIDs []string, force bool, dryRun bool, c
 	}
 }
 
+// cascadeDeleteCandidates returns ids and, transitively, every issue that
+// depends on them: what 'bd delete --cascade' removes.
+func cascadeDeleteCandidates(ctx context.Context, s storage.DoltStorage, ids []string) []string {
+	seen := make(map[string]bool, len(ids))
+	out := append([]string(nil), ids...)
+	for _, id := range ids {
+		seen[id] = true
+	}
+	for i := 0; i < len(out); i++ {
+		dependents, err := s.GetDependents(ctx, out[i])
+		if err != nil {
+			continue
+		}
+		for _, dep := range dependents {
+			if !seen[dep.ID] {
+				seen[dep.ID] = true
+				out = append(out, dep.ID)
+			}
+		}
+	}
+	return out
+}
+
 // deleteBatchFallback handles batch deletion for non-SQLite storage (e.g., MemoryStorage in --no-db mode)
 // It iterates through issues one by one, deleting each.
 func deleteBatchFallback(issueIDs []string, force bool, dryRun bool, cascade bool, jsonOutput bool) {
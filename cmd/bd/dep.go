@@ -200,6 +200,7 @@ depends on (is blocked by) the specified issue."
 The depends-on-id can be:
   - A local issue ID (e.g., bd-xyz)
   - An external reference: external:<project>:<capability>
+  - An issue in another registered repository: <workspace>:<issue-id>
 
 For bulk wiring, pass newline-delimited JSON with --file. Each line must be an
 object with "from" and "to" fields, and may include "type". The aliases
@@ -209,11 +210,17 @@ External references are stored as-is and resolved at query time using
 the external_projects config. They block the issue until the capability
 is "shipped" in the target project.
 
+Cross-repository references name a repository from the workspace registry
+(see 'bd workspace'). The target must exist when the dependency is added;
+'bd ready' and 'bd blocked' then read its status from that repository, so
+the issue stays blocked until the target is closed there.
+
 Examples:
   bd dep add bd-42 bd-41                              # Positional args
   bd dep add bd-42 --blocked-by bd-41                 # Flag syntax (same effect)
   bd dep add bd-42 --depends-on bd-41                 # Alias (same effect)
   bd dep add gt-xyz external:beads:mol-run-assignee   # Cross-project dependency
+  bd dep add bd-12 api:xy-7                           # Blocked on an issue in the api repo
   bd dep add bd-42 bd-41 --no-cycle-check             # Skip cycle check (bulk wiring)
   bd dep add --file deps.jsonl                        # Bulk JSONL: {"from":"bd-42","to":"bd-41"}`,
 	Args: func(cmd *cobra.Command, args []string) error {
@@ -292,6 +299,20 @@ Examples:
 			if err := validateExternalRef(toID); err != nil {
 				FatalErrorRespectJSON("%v", err)
 			}
+		} else if _, _, ok := parseCrossRepoRef(dependsOnArg); ok {
+			// Cross-repository reference: the target lives in a registered
+			// workspace. Check it exists and store it as <workspace>:<full-id>.
+			resolver, err := newCrossRepoResolver()
+			if err != nil {
+				FatalErrorRespectJSON("%v", err)
+			}
+			target, err := resolver.lookup(ctx, dependsOnArg)
+			resolver.Close()
+			if err != nil {
+				FatalErrorRespectJSON("%v", err)
+			}
+			repo, _, _ := parseCrossRepoRef(dependsOnArg)
+			toID = repo + ":" + target.ID
 		} else {
 			var toCleanup func()
 			toID, _, toCleanup, err = resolveIDWithRouting(ctx, store, dependsOnArg)
@@ -20,6 +20,8 @@ bd.sock.startlock
 sync-state.json
 last-touched
 .exclusive-lock
+lock
+conflicts.jsonl
 
 # Daemon runtime (lock, log, pid)
 daemon.*
@@ -70,6 +72,9 @@ dolt-pprof/
 # Backup data (auto-exported JSONL, local-only)
 backup/
 
+# Snapshots taken by 'bd backup snapshot' (local-only)
+backups/
+
 # Per-project environment file (Dolt connection config, GH#2520)
 .env
 
@@ -121,6 +126,7 @@ var requiredPatterns = []string{
 	"daemon.*",
 	"*.lock",
 	"*.corrupt.backup/",
+	"backups/",
 	".beads-credential-key",
 	"proxied_server_client_info.json",
 }
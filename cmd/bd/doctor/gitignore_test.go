@@ -5,6 +5,7 @@ import (
 	"os/exec"
 	"path/filepath"
 	"runtime"
+	"slices"
 	"strings"
 	"testing"
 )
@@ -1705,6 +1706,17 @@ func TestRequiredPatterns_ContainsDolt(t *testing.T) {
 	}
 }
 
+// TestRequiredPatterns_ContainsBackups verifies that bd doctor validates
+// the presence of the backups/ pattern, where snapshots are written.
+func TestRequiredPatterns_ContainsBackups(t *testing.T) {
+	if !slices.Contains(requiredPatterns, "backups/") {
+		t.Error("requiredPatterns should include 'backups/'")
+	}
+	if !strings.Contains(GitignoreTemplate, "\nbackups/\n") {
+		t.Error("GitignoreTemplate should include 'backups/'")
+	}
+}
+
 func TestCheckProjectGitignore_NoFile(t *testing.T) {
 	tmpDir := t.TempDir()
 	oldDir, err := os.Getwd()
@@ -24,6 +24,7 @@ var managedHookNames = []string{
 	"pre-push",
 	"post-checkout",
 	"prepare-commit-msg",
+	"post-commit",
 }
 
 // HookMigrationHookPlan describes migration state for a single hook file.
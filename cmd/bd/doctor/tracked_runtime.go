@@ -21,11 +21,13 @@ var trackedRuntimePatterns = []string{
 	// Lock files
 	"*.lock",
 	"*.pid.lock",
+	"lock",
 
 	// Daemon / server runtime
 	"daemon.pid",
 	"daemon.log",
 	"daemon.lock",
+	"daemon.json",
 	"dolt-server.pid",
 	"dolt-server.log",
 	"dolt-server.lock",
@@ -352,7 +352,7 @@ func formatDuplicateGroupsJSON(groups [][]*types.Issue, refCounts map[string]int
 // performMerge executes the merge operation:
 // 1. Re-parents children of source issues to the target (prevents orphaning)
 // 2. Closes all source issues with a reason indicating they are duplicates
-// 3. Links each source to the target with a "related" dependency
+// 3. Links each source to the target with a "duplicates" dependency
 // Returns a map with the merge result for JSON output
 func performMerge(targetID string, sourceIDs []string) map[string]interface{} {
 	ctx := rootCtx
@@ -411,7 +411,7 @@ func performMerge(targetID string, sourceIDs []string) map[string]interface{} {
 		dep := &types.Dependency{
 			IssueID:     sourceID,
 			DependsOnID: targetID,
-			Type:        types.DependencyType("related"),
+			Type:        types.DepDuplicates,
 		}
 		if err := store.AddDependency(ctx, dep, getActor()); err != nil {
 			errors = append(errors, fmt.Sprintf("failed to link %s to %s: %v", sourceID, targetID, err))
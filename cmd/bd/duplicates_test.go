@@ -434,21 +434,21 @@ func TestPerformMerge(t *testing.T) {
 		t.Errorf("Target issue should still be open, got status %s", targetIssue.Status)
 	}
 
-	// Verify dependencies were created (GetDependencies returns issues this depends on)
+	// Verify each source is marked as a duplicate of the target
 	for _, sourceID := range []string{source1.ID, source2.ID} {
-		deps, err := testStore.GetDependencies(ctx, sourceID)
+		deps, err := testStore.GetDependenciesWithMetadata(ctx, sourceID)
 		if err != nil {
-			t.Fatalf("GetDependencies(%s) failed: %v", sourceID, err)
+			t.Fatalf("GetDependenciesWithMetadata(%s) failed: %v", sourceID, err)
 		}
 		found := false
 		for _, dep := range deps {
-			if dep.ID == target.ID {
+			if dep.ID == target.ID && dep.DependencyType == types.DepDuplicates {
 				found = true
 				break
 			}
 		}
 		if !found {
-			t.Errorf("Expected dependency from %s to %s", sourceID, target.ID)
+			t.Errorf("Expected duplicates dependency from %s to %s", sourceID, target.ID)
 		}
 	}
 }
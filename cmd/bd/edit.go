@@ -147,6 +147,8 @@ Examples:
 			fieldToEdit: newValue,
 		}
 
+		// The write lock is only taken now, not while the editor is open.
+		lockCommandWrites()
 		err = issueStore.UpdateIssue(ctx, id, updates, actor)
 		if err != nil {
 			// Connection may have gone stale while the editor was open.
@@ -1,6 +1,7 @@
 package main
 
 import (
+	"context"
 	"encoding/json"
 	"fmt"
 	"io"
@@ -11,18 +12,29 @@ import (
 
 	"github.com/spf13/cobra"
 	"github.com/steveyegge/beads/internal/atomicfile"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/debug"
 	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
 )
 
 var exportCmd = &cobra.Command{
 	Use:   "export",
-	Short: "Export issues to JSONL format",
+	Short: "Export issues to JSONL, CSV, markdown, or org",
 	Long: `Export all issues to JSONL (newline-delimited JSON) format.
 
 Each line is a complete JSON object representing one issue, including its
 labels, dependencies, and comments.
 
+The JSONL is canonical, so exporting unchanged data produces a byte-identical
+file and git diffs show only real changes. Fields appear in a fixed order.
+Timestamps are UTC with whole-second precision. Labels, dependencies, and
+comments are sorted, and metadata objects have sorted keys.
+
+Every line carries "_format", the version of the record format. 'bd import'
+translates older formats and reads newer ones with a warning, so
+collaborators on different bd releases can share the file.
+
 This command is for issue export, migration, and interoperability. It exports
 records from the issues table; it is not a full database backup and does not
 capture Dolt branches, commit history, working-set state, or non-issue tables.
@@ -36,12 +48,71 @@ Memories (from 'bd remember') are excluded by default because they may
 contain sensitive agent context. Use --include-memories or --all to
 include them.
 
+Use --format csv for a spreadsheet-friendly export that can be edited and
+read back with 'bd import --format csv'. The CSV has a header row and one
+row per issue with these columns:
+
+  id, title, status, priority, issue_type, assignee, owner, labels,
+  description, design, acceptance_criteria, notes, close_reason,
+  external_ref, estimated_minutes, due_at, defer_until, created_at,
+  updated_at, started_at, closed_at, metadata
+
+Cells are quoted per RFC 4180, so commas, quotes, and newlines survive.
+Labels are comma-separated within their cell, timestamps are RFC3339 in
+UTC, and metadata is a JSON object. Text cells that a spreadsheet would
+evaluate as a formula (starting with =, +, -, or @) or that start with a
+single quote get a leading ' added; import strips it again. Dependencies,
+comments, and memories are not included in CSV.
+
+Use --format markdown for a readable report to publish to a wiki or a
+repo's docs: each issue gets a heading, a metadata table, its description,
+design, acceptance criteria and notes, dependencies, and comments. By
+default a single document with a table of contents is written; with
+--dir DIR, each issue is written to DIR/<id>.md alongside an index.md.
+
+To export a subset, use the same filter flags as 'bd list': --status,
+--assignee, --type, --label, --label-any, --exclude-label, --priority, --id,
+and --created-after/--created-before/--updated-after/--updated-before. All
+statuses are included unless --status is given. --parent ID exports that
+issue and its descendants. Dependencies on issues outside the subset are
+kept in the records; 'bd import' reports them as skipped. Memories are left
+out of a filtered export unless --include-memories is given.
+
+Use --format org for an org-mode outline to review in Emacs and
+org-agenda. Child issues are nested under their parent. Statuses map to
+TODO keywords (open TODO, in_progress STARTED, blocked WAITING, deferred
+HOLD, closed DONE) and priorities 0-4 to cookies [#A]-[#E]; the file
+declares both, so no Emacs configuration is needed. closed_at, due_at, and
+defer_until become CLOSED, DEADLINE, and SCHEDULED timestamps in local
+time, labels become tags, and each heading's properties drawer holds the
+beads ID as :ID:, so [[id:bd-42]] links resolve to it.
+
+Use --since to export only what changed, e.g. to append to an event log or
+ship to another system. --since takes a time (updated_at after it, as in
+--updated-after), a Dolt commit or branch (issues added or modified since
+that commit), or "last" (since the previous export of everything from this
+clone, full or --since). With a commit or "last", issues deleted since then are written as
+{"_type":"deletion","id":...} records after the issues.
+
+Deleting an issue records a tombstone (its id, deleted_at, and the actor),
+and an unfiltered JSONL export writes each one as a deletion record after
+the issues. 'bd import' deletes the local copy of such an issue and will
+not re-create it from a JSONL file that still has it, unless that copy was
+updated after the deletion.
+
 EXAMPLES:
   bd export                              # Export issues to stdout
   bd export -o issues.jsonl              # Export issues to file
+  bd export -o - | other-db import       # "-" is stdout; messages go to stderr
   bd export --include-memories           # Export issues + memories
   bd export --all -o full.jsonl          # Include infra + templates + gates + memories
-  bd export --scrub -o clean.jsonl       # Exclude test/pollution records`,
+  bd export --scrub -o clean.jsonl       # Exclude test/pollution records
+  bd export --format csv -o issues.csv   # Spreadsheet-friendly CSV
+  bd export --format markdown --dir docs/issues  # One markdown file per issue
+  bd export --format org -o issues.org   # Outline for org-agenda
+  bd export --status open --label infra  # Only open infra issues
+  bd export --parent bd-42 -o epic.jsonl # One epic, for a handoff
+  bd export --since last >> events.jsonl # Changes since the previous export`,
 	GroupID: "sync",
 	RunE:    runExport,
 }
@@ -53,10 +124,14 @@ var (
 	exportScrub           bool
 	exportNoMemories      bool
 	exportIncludeMemories bool
+	exportFormat          string
+	exportDir             string
 )
 
 func init() {
-	exportCmd.Flags().StringVarP(&exportOutput, "output", "o", "", "Output file path (default: stdout)")
+	exportCmd.Flags().StringVarP(&exportOutput, "output", "o", "", "Output file path, or - for stdout (default: stdout)")
+	exportCmd.Flags().StringVar(&exportFormat, "format", "jsonl", "Output format: jsonl, csv, markdown, or org")
+	exportCmd.Flags().StringVar(&exportDir, "dir", "", "With --format markdown, write one file per issue into this directory")
 	exportCmd.Flags().BoolVar(&exportAll, "all", false, "Include all records (infra, templates, gates, memories)")
 	exportCmd.Flags().BoolVar(&exportIncludeInfra, "include-infra", false, "Include infrastructure beads (agents, rigs, roles, messages)")
 	exportCmd.Flags().BoolVar(&exportScrub, "scrub", false, "Exclude test/pollution records")
@@ -69,12 +144,49 @@ func init() {
 func runExport(cmd *cobra.Command, args []string) error {
 	ctx := rootCtx
 
+	// The local --format flag shadows the hidden persistent --format on
+	// rootCmd; "json" has always meant the default JSONL output here.
+	exportFormat = strings.ToLower(exportFormat)
+	if exportFormat == "json" {
+		exportFormat = "jsonl"
+	}
+	if exportFormat == "md" {
+		exportFormat = "markdown"
+	}
+	switch exportFormat {
+	case "jsonl", "csv", "markdown", "org":
+	default:
+		return fmt.Errorf("unsupported --format %q (use jsonl, csv, markdown, or org)", exportFormat)
+	}
+	if exportFormat != "jsonl" && exportIncludeMemories {
+		return fmt.Errorf("--include-memories is not supported with --format %s", exportFormat)
+	}
+	if exportDir != "" {
+		if exportFormat != "markdown" {
+			return fmt.Errorf("--dir requires --format markdown")
+		}
+		if exportOutput != "" {
+			return fmt.Errorf("use either --dir or --output, not both")
+		}
+	}
+
+	// "-o -" means stdout, like no -o at all.
+	if exportOutput == "-" {
+		exportOutput = ""
+	}
+
 	// Determine output destination. File output uses atomic writes
 	// (temp file + rename) so concurrent exports and crashes never
 	// leave a truncated or interleaved JSONL file.
 	var w io.Writer
 	var aw *atomicfile.Writer
 	if exportOutput != "" {
+		// export opens the store read-only, so it takes the write lock
+		// itself when it writes a file.
+		if beadsDir := beads.FindBeadsDir(); beadsDir != "" && commandWriteLock == nil {
+			acquireCommandWriteLock(beadsDir)
+			defer releaseCommandWriteLock()
+		}
 		var err error
 		aw, err = atomicfile.Create(exportOutput, 0o644)
 		if err != nil {
@@ -125,82 +237,128 @@ func runExport(cmd *cobra.Command, args []string) error {
 		filter.Ephemeral = &persistentOnly
 	}
 
-	issues, err := store.SearchIssues(ctx, "", filter)
+	filtered, err := applyExportFilters(ctx, &filter)
+	if err != nil {
+		return err
+	}
+	var delta *exportDelta
+	if exportSince != "" {
+		if delta, err = applyExportSince(ctx, &filter); err != nil {
+			return err
+		}
+	}
+
+	// JSONL is written a page at a time, following the list cursor, so a
+	// large database is never held in memory at once. The other formats
+	// (and --parent) need every issue before they can write anything.
+	streaming := exportFormat == "jsonl" && exportParent == ""
+	if streaming {
+		filter.Limit = exportPageSize
+	}
+
+	var page []*types.Issue
+	switch {
+	case delta != nil && delta.none:
+	case exportParent != "":
+		page, err = exportParentTree(ctx, filter)
+	default:
+		page, err = store.SearchIssues(ctx, "", filter)
+	}
 	if err != nil {
 		return fmt.Errorf("failed to search issues: %w", err)
 	}
 
 	// Scrub test/pollution records if requested
+	issues := page
 	if exportScrub {
 		issues = filterOutPollution(issues)
 	}
 
-	if len(issues) == 0 && exportNoMemories {
+	if len(issues) == 0 && (!streaming || len(page) < exportPageSize) && exportNoMemories {
 		if exportOutput != "" {
 			fmt.Fprintln(os.Stderr, "No issues to export.")
 		}
 		return nil
 	}
 
-	// Bulk-load relational data
-	issueIDs := make([]string, len(issues))
-	for i, issue := range issues {
-		issueIDs[i] = issue.ID
+	if exportFormat != "jsonl" {
+		loadExportRelations(ctx, issues)
 	}
-
-	labelsMap, _ := store.GetLabelsForIssues(ctx, issueIDs)
-	allDeps, _ := store.GetDependencyRecordsForIssues(ctx, issueIDs)
-	commentsMap, _ := store.GetCommentsForIssues(ctx, issueIDs)
-	commentCounts, _ := store.GetCommentCounts(ctx, issueIDs)
-	depCounts, _ := store.GetDependencyCounts(ctx, issueIDs)
-
-	// Populate relational data on each issue
-	for _, issue := range issues {
-		issue.Labels = labelsMap[issue.ID]
-		issue.Dependencies = allDeps[issue.ID]
-		issue.Comments = commentsMap[issue.ID]
+	if exportDir != "" {
+		count, err := writeMarkdownDir(exportDir, issues)
+		if err != nil {
+			return err
+		}
+		fmt.Fprintf(os.Stderr, "Exported %d issues to %s\n", count, exportDir)
+		return nil
+	}
+	if exportFormat != "jsonl" {
+		var count int
+		switch exportFormat {
+		case "csv":
+			count, err = writeCSVExport(w, issues)
+		case "org":
+			count, err = writeOrgExport(w, issues)
+		default:
+			count, err = writeMarkdownReport(w, issues)
+		}
+		if err != nil {
+			return err
+		}
+		if aw != nil {
+			if err := aw.Close(); err != nil {
+				return fmt.Errorf("failed to finalize export file: %w", err)
+			}
+			fmt.Fprintf(os.Stderr, "Exported %d issues to %s\n", count, exportOutput)
+		}
+		return nil
 	}
 
 	// Write JSONL: one JSON object per line
 	count := 0
-	for _, issue := range issues {
-		counts := depCounts[issue.ID]
-		if counts == nil {
-			counts = &types.DependencyCounts{}
+	for {
+		n, err := writeJSONLIssues(w, issues, loadExportRelations(ctx, issues))
+		count += n
+		if err != nil {
+			return err
 		}
-
-		// Sanitize zero-value timestamps that can't be marshaled to JSON.
-		// NULL datetime columns scanned as time.Time{} (year 0001) cause
-		// MarshalJSON to fail with "year outside of range [0,9999]". (GH#2488)
-		sanitizeZeroTime(issue)
-
-		record := &exportIssueRecord{
-			RecordType: "issue",
-			IssueWithCounts: &types.IssueWithCounts{
-				Issue:           issue,
-				DependencyCount: counts.DependencyCount,
-				DependentCount:  counts.DependentCount,
-				CommentCount:    commentCounts[issue.ID],
-			},
+		if !streaming || len(page) < exportPageSize {
+			break
+		}
+		filter.After = types.IssueCursorFor(page[len(page)-1])
+		page, err = store.SearchIssues(ctx, "", filter)
+		if err != nil {
+			return fmt.Errorf("failed to search issues: %w", err)
 		}
+		issues = page
+		if exportScrub {
+			issues = filterOutPollution(issues)
+		}
+	}
 
-		data, err := json.Marshal(record)
+	// Deletions: those since the commit for --since, else every recorded
+	// tombstone, so an import elsewhere deletes the issues instead of
+	// keeping its own copies. A filtered subset says nothing about them.
+	if delta != nil || !filtered {
+		recorded, err := loadTombstones(ctx, store)
 		if err != nil {
-			return fmt.Errorf("failed to marshal issue %s: %w", issue.ID, err)
+			return err
 		}
-		if _, err := w.Write(data); err != nil {
-			return fmt.Errorf("failed to write: %w", err)
+		deletions := sortedTombstones(recorded)
+		if delta != nil {
+			deletions = tombstonesFor(delta.deleted, recorded)
 		}
-		if _, err := w.Write([]byte{'\n'}); err != nil {
-			return fmt.Errorf("failed to write newline: %w", err)
+		if err := writeJSONLDeletions(w, deletions); err != nil {
+			return err
 		}
-		count++
 	}
 
 	// Export memories only when explicitly requested (GH#3650).
 	// Memories may contain sensitive agent context and are excluded by default.
+	// A filtered export is a subset meant for sharing, so --all alone does not
+	// pull memories into it.
 	memoryCount := 0
-	if (exportIncludeMemories || exportAll) && !exportNoMemories {
+	if (exportIncludeMemories || (exportAll && !filtered && delta == nil)) && !exportNoMemories {
 		allConfig, err := store.GetAllConfig(ctx)
 		if err != nil {
 			return fmt.Errorf("failed to read config for memories: %w", err)
@@ -217,10 +375,11 @@ func runExport(cmd *cobra.Command, args []string) error {
 		for _, k := range memKeys {
 			v := allConfig[k]
 			userKey := strings.TrimPrefix(k, fullPrefix)
-			record := map[string]string{
-				"_type": "memory",
-				"key":   userKey,
-				"value": v,
+			record := map[string]interface{}{
+				"_type":   "memory",
+				"_format": jsonlFormatVersion,
+				"key":     userKey,
+				"value":   v,
 			}
 			data, err := json.Marshal(record)
 			if err != nil {
@@ -243,6 +402,18 @@ func runExport(cmd *cobra.Command, args []string) error {
 		}
 	}
 
+	if aw != nil {
+		recordJSONLFileHash(ctx, store, exportOutput)
+	}
+
+	// Mark where the next 'bd export --since last' picks up. Full and
+	// --since exports count; a filtered subset would leave gaps.
+	if !filtered {
+		if err := recordExportCommit(ctx); err != nil {
+			debug.Logf("export: failed to record last export commit: %v\n", err)
+		}
+	}
+
 	// Print summary to stderr (not stdout, to avoid mixing with JSONL)
 	if exportOutput != "" {
 		if memoryCount > 0 {
@@ -255,11 +426,100 @@ func runExport(cmd *cobra.Command, args []string) error {
 	return nil
 }
 
+// exportPageSize is how many issues a JSONL export loads per query.
+const exportPageSize = 1000
+
+// exportRelations holds the per-issue counts loaded alongside each batch
+// of exported issues.
+type exportRelations struct {
+	depCounts     map[string]*types.DependencyCounts
+	commentCounts map[string]int
+}
+
+// loadExportRelations bulk-loads labels, dependencies, and comments onto
+// issues and returns their dependency and comment counts.
+func loadExportRelations(ctx context.Context, issues []*types.Issue) exportRelations {
+	return loadExportRelationsFrom(ctx, store, issues)
+}
+
+// loadExportRelationsFrom is loadExportRelations against s rather than the
+// global store.
+func loadExportRelationsFrom(ctx context.Context, s storage.DoltStorage, issues []*types.Issue) exportRelations {
+	if len(issues) == 0 {
+		return exportRelations{}
+	}
+	issueIDs := make([]string, len(issues))
+	for i, issue := range issues {
+		issueIDs[i] = issue.ID
+	}
+
+	labelsMap, _ := s.GetLabelsForIssues(ctx, issueIDs)
+	allDeps, _ := s.GetDependencyRecordsForIssues(ctx, issueIDs)
+	commentsMap, _ := s.GetCommentsForIssues(ctx, issueIDs)
+	codeRefsMap, _ := s.GetCodeRefsForIssues(ctx, issueIDs)
+	workLogMap, _ := s.GetWorkLogForIssues(ctx, issueIDs)
+	todosMap, _ := s.GetTodosForIssues(ctx, issueIDs)
+	assigneesMap, _ := s.GetAssigneesForIssues(ctx, issueIDs)
+	commentCounts, _ := s.GetCommentCounts(ctx, issueIDs)
+	depCounts, _ := s.GetDependencyCounts(ctx, issueIDs)
+
+	for _, issue := range issues {
+		issue.Labels = labelsMap[issue.ID]
+		issue.Dependencies = allDeps[issue.ID]
+		issue.Comments = commentsMap[issue.ID]
+		issue.CodeRefs = codeRefsMap[issue.ID]
+		issue.WorkLog = workLogMap[issue.ID]
+		issue.Todos = todosMap[issue.ID]
+		issue.Assignees = assigneesMap[issue.ID]
+	}
+	return exportRelations{depCounts: depCounts, commentCounts: commentCounts}
+}
+
+// writeJSONLIssues writes one export record per issue, in canonical form
+// (see canonicalizeExportIssue), and returns how many were written.
+func writeJSONLIssues(w io.Writer, issues []*types.Issue, rel exportRelations) (int, error) {
+	count := 0
+	for _, issue := range issues {
+		counts := rel.depCounts[issue.ID]
+		if counts == nil {
+			counts = &types.DependencyCounts{}
+		}
+
+		canonicalizeExportIssue(issue)
+
+		record := &exportIssueRecord{
+			RecordType:    "issue",
+			FormatVersion: jsonlFormatVersion,
+			IssueWithCounts: &types.IssueWithCounts{
+				Issue:           issue,
+				DependencyCount: counts.DependencyCount,
+				DependentCount:  counts.DependentCount,
+				CommentCount:    rel.commentCounts[issue.ID],
+			},
+		}
+
+		data, err := json.Marshal(record)
+		if err != nil {
+			return count, fmt.Errorf("failed to marshal issue %s: %w", issue.ID, err)
+		}
+		if _, err := w.Write(data); err != nil {
+			return count, fmt.Errorf("failed to write: %w", err)
+		}
+		if _, err := w.Write([]byte{'\n'}); err != nil {
+			return count, fmt.Errorf("failed to write newline: %w", err)
+		}
+		count++
+	}
+	return count, nil
+}
+
 // exportIssueRecord wraps IssueWithCounts with a _type discriminator so that
 // every line in the JSONL export is self-describing. Memory lines already
 // carry "_type":"memory"; this gives issue lines "_type":"issue". (GH#3271)
+// FormatVersion is the record format version (see jsonlFormatVersion).
 type exportIssueRecord struct {
-	RecordType string `json:"_type"`
+	RecordType    string `json:"_type"`
+	FormatVersion int    `json:"_format"`
 	*types.IssueWithCounts
 }
 
@@ -2,9 +2,12 @@ package main
 
 import (
 	"bufio"
+	"bytes"
 	"context"
+	"crypto/sha256"
 	"encoding/json"
 	"fmt"
+	"io"
 	"os"
 	"os/exec"
 	"path/filepath"
@@ -33,8 +36,13 @@ const exportAutoStateFile = "export-state.json"
 const gitAddTimeout = 5 * time.Second
 
 // maybeAutoExport writes a git-tracked JSONL file if enabled and due.
-// Called from PersistentPostRun after auto-backup.
-func maybeAutoExport(ctx context.Context, serverMode, allowEmptyOverwrite bool) error {
+// Called from PersistentPostRun after auto-backup. command names the write
+// that prompted it, for the git commit sync.auto_commit makes.
+//
+// export.auto exports at most once per export.interval; sync.auto_export
+// exports after every write, and with sync.auto_commit also commits the
+// file to git.
+func maybeAutoExport(ctx context.Context, command string, serverMode, allowEmptyOverwrite bool) error {
 	if serverMode {
 		debug.Logf("auto-export: skipping — server mode\n")
 		return nil
@@ -46,7 +54,8 @@ func maybeAutoExport(ctx context.Context, serverMode, allowEmptyOverwrite bool)
 		return nil
 	}
 
-	if !config.GetBool("export.auto") {
+	syncAutoExport := config.GetBool("sync.auto_export")
+	if !config.GetBool("export.auto") && !syncAutoExport {
 		return nil
 	}
 	if store == nil {
@@ -63,15 +72,7 @@ func maybeAutoExport(ctx context.Context, serverMode, allowEmptyOverwrite bool)
 
 	// Resolve the export path before throttle/check detection so all decisions
 	// refer to the path that would actually be written.
-	exportPath := config.GetString("export.path")
-	if exportPath == "" {
-		if globalFlag {
-			exportPath = "global-issues.jsonl"
-		} else {
-			exportPath = "issues.jsonl"
-		}
-	}
-	fullPath := filepath.Join(beadsDir, exportPath)
+	fullPath := autoExportPath(beadsDir)
 
 	// Load state + interval.
 	state := loadExportAutoState(beadsDir)
@@ -82,17 +83,20 @@ func maybeAutoExport(ctx context.Context, serverMode, allowEmptyOverwrite bool)
 
 	// Change detection via Dolt commit hash. This is cheap, so do it before
 	// throttle: when there are no changes, there is nothing to throttle.
+	// sync.auto_export exports regardless, since writes may sit uncommitted
+	// in the working set (dolt.auto-commit off); an unchanged export is
+	// still not rewritten.
 	currentCommit, err := store.GetCurrentCommit(ctx)
 	if err != nil {
 		fmt.Fprintf(os.Stderr, "Warning: auto-export skipped: failed to get current commit: %v\n", err)
 		return nil
 	}
-	if currentCommit == state.LastDoltCommit && state.LastDoltCommit != "" {
+	if !syncAutoExport && currentCommit == state.LastDoltCommit && state.LastDoltCommit != "" {
 		debug.Logf("auto-export: no changes since last export\n")
 		return nil
 	}
 
-	if !shouldExport(state, interval) {
+	if !syncAutoExport && !shouldExport(state, interval) {
 		debug.Logf("auto-export: throttled (last export %s ago, interval %s)\n",
 			time.Since(state.Timestamp).Round(time.Second), interval)
 		return nil
@@ -117,9 +121,17 @@ func maybeAutoExport(ctx context.Context, serverMode, allowEmptyOverwrite bool)
 		}
 	}
 
+	autoCommit := config.GetBool("sync.auto_commit") && !config.GetBool("no-git-ops") && isGitRepo()
+	var before map[string]string
+	if autoCommit {
+		if before, err = jsonlIssueLines(fullPath); err != nil {
+			debug.Logf("auto-export: reading previous export: %v\n", err)
+		}
+	}
+
 	// Run the export — memories are excluded from auto-export because they
 	// contain private agent context that must not reach git history (GH#3650).
-	issueCount, memoryCount, err := exportToFile(ctx, fullPath, false)
+	issueCount, memoryCount, unchanged, err := exportToFile(ctx, fullPath, false)
 	if err != nil {
 		fmt.Fprintf(os.Stderr, "Warning: auto-export failed: %v\n", err)
 		return nil
@@ -127,6 +139,7 @@ func maybeAutoExport(ctx context.Context, serverMode, allowEmptyOverwrite bool)
 
 	debug.Logf("auto-export: wrote %d issues and %d memories to %s\n",
 		issueCount, memoryCount, fullPath)
+	recordJSONLFileHash(ctx, store, fullPath)
 
 	// Don't prime the throttle on an empty export (e.g. immediately after
 	// `bd init`). Saving state here would block the first real `bd create`
@@ -143,15 +156,37 @@ func maybeAutoExport(ctx context.Context, serverMode, allowEmptyOverwrite bool)
 		})
 		return nil
 	}
+	// The Dolt commit moves for changes that never reach the JSONL (wisps,
+	// config, local metadata). Leave the file and the git index alone then.
+	if unchanged {
+		debug.Logf("auto-export: %s content unchanged; not rewriting\n", fullPath)
+		saveExportAutoState(beadsDir, &exportAutoState{
+			LastDoltCommit: currentCommit,
+			Timestamp:      time.Now(),
+			Issues:         issueCount,
+			Memories:       memoryCount,
+		})
+		return nil
+	}
 	warnJSONLWithoutDoltRemote("auto-export")
 
 	// Optional git add — skip when no-git-ops is set (GH#3314), when not in a
 	// git repo (standalone BEADS_DIR flow), or when export.git-add is false.
-	if config.GetBool("export.git-add") && !config.GetBool("no-git-ops") && isGitRepo() {
+	if (config.GetBool("export.git-add") || autoCommit) && !config.GetBool("no-git-ops") && isGitRepo() {
 		if err := gitAddFile(fullPath); err != nil {
 			return fmt.Errorf("auto-export: git add failed: %w", err)
 		}
 	}
+	if autoCommit {
+		var ids []string
+		if after, err := jsonlIssueLines(fullPath); err == nil && before != nil {
+			ids = changedJSONLIssues(before, after)
+		}
+		msg := formatDoltAutoCommitMessage(command, getActor(), ids)
+		if err := gitCommitFile(fullPath, msg); err != nil {
+			return fmt.Errorf("auto-export: git commit failed: %w", err)
+		}
+	}
 
 	// Save state
 	newState := exportAutoState{
@@ -164,6 +199,19 @@ func maybeAutoExport(ctx context.Context, serverMode, allowEmptyOverwrite bool)
 	return nil
 }
 
+// autoExportPath is the JSONL file auto-export writes in beadsDir.
+func autoExportPath(beadsDir string) string {
+	exportPath := config.GetString("export.path")
+	if exportPath == "" {
+		if globalFlag {
+			exportPath = "global-issues.jsonl"
+		} else {
+			exportPath = "issues.jsonl"
+		}
+	}
+	return filepath.Join(beadsDir, exportPath)
+}
+
 // shouldExport reports whether the throttle window has elapsed, or whether
 // this is the first auto-export attempt. It returns false only when a recent
 // export exists and the configured interval has not elapsed.
@@ -340,68 +388,53 @@ func buildAutoExportFilter(ctx context.Context) (types.IssueFilter, map[string]b
 
 // exportToFile atomically exports issues + memories to the given file path.
 // Writes to a temp file first, then renames into place so readers never see
-// a partial or truncated export. Used by both `bd export -o` and auto-export.
-func exportToFile(ctx context.Context, path string, includeMemories bool) (issueCount, memoryCount int, err error) {
+// a partial or truncated export. When the rendered content is byte-identical
+// to the existing file, the file is left untouched (no rename, no mtime
+// change) and unchanged is true. Used by both `bd export -o` and auto-export.
+func exportToFile(ctx context.Context, path string, includeMemories bool) (issueCount, memoryCount int, unchanged bool, err error) {
 	w, err := atomicfile.Create(path, 0o644)
 	if err != nil {
-		return 0, 0, fmt.Errorf("failed to create export file: %w", err)
+		return 0, 0, false, fmt.Errorf("failed to create export file: %w", err)
 	}
 	defer func() {
 		if err != nil {
 			_ = w.Abort()
 		}
 	}()
+	hash := sha256.New()
+	out := io.MultiWriter(w, hash)
 
 	filter, infraTypeSet := buildAutoExportFilter(ctx)
-	issues, err := store.SearchIssues(ctx, "", filter)
-	if err != nil {
-		return 0, 0, fmt.Errorf("failed to search issues: %w", err)
-	}
-
 	if err := guardAutoExportOverwrite(path, infraTypeSet, includeMemories); err != nil {
-		return 0, 0, err
+		return 0, 0, false, err
 	}
 
-	// Bulk-load relational data
-	if len(issues) > 0 {
-		issueIDs := make([]string, len(issues))
-		for i, issue := range issues {
-			issueIDs[i] = issue.ID
+	// Issues are written a page at a time, following the list cursor as
+	// 'bd export' does, so a large database is never held in memory at once.
+	filter.Limit = exportPageSize
+	for {
+		var page []*types.Issue
+		if page, err = store.SearchIssues(ctx, "", filter); err != nil {
+			return issueCount, 0, false, fmt.Errorf("failed to search issues: %w", err)
 		}
-		labelsMap, _ := store.GetLabelsForIssues(ctx, issueIDs)
-		allDeps, _ := store.GetDependencyRecordsForIssues(ctx, issueIDs)
-		commentsMap, _ := store.GetCommentsForIssues(ctx, issueIDs)
-		commentCounts, _ := store.GetCommentCounts(ctx, issueIDs)
-		depCounts, _ := store.GetDependencyCounts(ctx, issueIDs)
-
-		for _, issue := range issues {
-			issue.Labels = labelsMap[issue.ID]
-			issue.Dependencies = allDeps[issue.ID]
-			issue.Comments = commentsMap[issue.ID]
+		var n int
+		n, err = writeJSONLIssues(out, page, loadExportRelations(ctx, page))
+		issueCount += n
+		if err != nil {
+			return issueCount, 0, false, err
 		}
-
-		// Write issues
-		enc := json.NewEncoder(w)
-		for _, issue := range issues {
-			counts := depCounts[issue.ID]
-			if counts == nil {
-				counts = &types.DependencyCounts{}
-			}
-			sanitizeZeroTime(issue)
-			record := &exportIssueRecord{
-				RecordType: "issue",
-				IssueWithCounts: &types.IssueWithCounts{
-					Issue:           issue,
-					DependencyCount: counts.DependencyCount,
-					DependentCount:  counts.DependentCount,
-					CommentCount:    commentCounts[issue.ID],
-				},
-			}
-			if err := enc.Encode(record); err != nil {
-				return 0, 0, fmt.Errorf("failed to write issue %s: %w", issue.ID, err)
-			}
-			issueCount++
+		if len(page) < exportPageSize {
+			break
 		}
+		filter.After = types.IssueCursorFor(page[len(page)-1])
+	}
+
+	recorded, err := loadTombstones(ctx, store)
+	if err != nil {
+		return issueCount, 0, false, err
+	}
+	if err := writeJSONLDeletions(out, sortedTombstones(recorded)); err != nil {
+		return issueCount, 0, false, err
 	}
 
 	// Write memories
@@ -420,31 +453,50 @@ func exportToFile(ctx context.Context, path string, includeMemories bool) (issue
 			for _, k := range memKeys {
 				v := allConfig[k]
 				userKey := strings.TrimPrefix(k, fullPrefix)
-				record := map[string]string{
-					"_type": "memory",
-					"key":   userKey,
-					"value": v,
+				record := map[string]interface{}{
+					"_type":   "memory",
+					"_format": jsonlFormatVersion,
+					"key":     userKey,
+					"value":   v,
 				}
 				data, err := json.Marshal(record)
 				if err != nil {
-					return issueCount, memoryCount, fmt.Errorf("failed to marshal memory %s: %w", userKey, err)
+					return issueCount, memoryCount, false, fmt.Errorf("failed to marshal memory %s: %w", userKey, err)
 				}
-				if _, err := w.Write(data); err != nil {
-					return issueCount, memoryCount, fmt.Errorf("failed to write memory: %w", err)
+				if _, err := out.Write(data); err != nil {
+					return issueCount, memoryCount, false, fmt.Errorf("failed to write memory: %w", err)
 				}
-				if _, err := w.Write([]byte{'\n'}); err != nil {
-					return issueCount, memoryCount, fmt.Errorf("failed to write newline: %w", err)
+				if _, err := out.Write([]byte{'\n'}); err != nil {
+					return issueCount, memoryCount, false, fmt.Errorf("failed to write newline: %w", err)
 				}
 				memoryCount++
 			}
 		}
 	}
 
+	if fileHasSHA256(path, hash.Sum(nil)) {
+		_ = w.Abort()
+		return issueCount, memoryCount, true, nil
+	}
 	if err := w.Close(); err != nil {
-		return issueCount, memoryCount, fmt.Errorf("failed to finalize export: %w", err)
+		return issueCount, memoryCount, false, fmt.Errorf("failed to finalize export: %w", err)
 	}
 
-	return issueCount, memoryCount, nil
+	return issueCount, memoryCount, false, nil
+}
+
+// fileHasSHA256 reports whether the file at path exists and hashes to sum.
+func fileHasSHA256(path string, sum []byte) bool {
+	f, err := os.Open(path) //nolint:gosec // path is the configured export target
+	if err != nil {
+		return false
+	}
+	defer f.Close()
+	h := sha256.New()
+	if _, err := io.Copy(h, f); err != nil {
+		return false
+	}
+	return bytes.Equal(h.Sum(nil), sum)
 }
 
 func guardAutoExportOverwrite(path string, infraTypes map[string]bool, includeMemories bool) error {
@@ -507,6 +559,8 @@ func classifyExistingAutoExportRecord(line []byte, infraTypes map[string]bool, i
 			stats.Memories++
 		}
 		return nil
+	case "deletion":
+		return nil
 	case "", "issue":
 		if record.ID == "" {
 			stats.FilteredRecords++
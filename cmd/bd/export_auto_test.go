@@ -2,10 +2,12 @@ package main
 
 import (
 	"context"
+	"crypto/sha256"
 	"encoding/json"
 	"os"
 	"os/exec"
 	"path/filepath"
+	"sort"
 	"strings"
 	"testing"
 	"time"
@@ -204,7 +206,7 @@ func TestMaybeAutoExportSkipsServerModeBeforeStoreAccess(t *testing.T) {
 	}
 	t.Chdir(dir)
 
-	if err := maybeAutoExport(context.Background(), true, false); err != nil {
+	if err := maybeAutoExport(context.Background(), "create", true, false); err != nil {
 		t.Fatalf("maybeAutoExport(serverMode=true): %v", err)
 	}
 
@@ -928,6 +930,28 @@ func TestCountIssueRecordsInJSONL(t *testing.T) {
 	}
 }
 
+func TestFileHasSHA256(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "issues.jsonl")
+	content := []byte(`{"_type":"issue","id":"bd-1"}` + "\n")
+	sum := sha256.Sum256(content)
+
+	if fileHasSHA256(path, sum[:]) {
+		t.Fatal("missing file must not match")
+	}
+	if err := os.WriteFile(path, content, 0o644); err != nil {
+		t.Fatal(err)
+	}
+	if !fileHasSHA256(path, sum[:]) {
+		t.Fatal("identical content should match")
+	}
+	if err := os.WriteFile(path, append(content, '\n'), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	if fileHasSHA256(path, sum[:]) {
+		t.Fatal("changed content must not match")
+	}
+}
+
 func TestAutoExportSkipsEmptyExportOverPopulatedJSONL(t *testing.T) {
 	bd := buildBDForInitTests(t)
 	dir := t.TempDir()
@@ -1032,3 +1056,42 @@ func autoExportDataLossTestEnv(home string) []string {
 	}
 	return append(env, "HOME="+home, "BEADS_DOLT_AUTO_START=0", "BEADS_NO_DAEMON=1")
 }
+
+func TestChangedJSONLIssues(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "issues.jsonl")
+	before, err := jsonlIssueLines(path)
+	if err != nil || len(before) != 0 {
+		t.Fatalf("missing file: got %v, %v; want no issues", before, err)
+	}
+
+	if err := os.WriteFile(path, []byte(`{"id":"bd-1","title":"a"}
+{"id":"bd-2","title":"b"}
+{"_type":"memory","id":"m-1"}
+`), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	before, err = jsonlIssueLines(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(before) != 2 {
+		t.Fatalf("got %d issues, want 2 (memories are not issues)", len(before))
+	}
+
+	after := map[string]string{
+		"bd-1": before["bd-1"],
+		"bd-2": `{"id":"bd-2","title":"changed"}`,
+		"bd-3": `{"id":"bd-3","title":"new"}`,
+	}
+	before["bd-4"] = `{"id":"bd-4","title":"gone"}`
+
+	got := changedJSONLIssues(before, after)
+	sort.Strings(got)
+	want := []string{"bd-2", "bd-3", "bd-4"}
+	if strings.Join(got, ",") != strings.Join(want, ",") {
+		t.Errorf("changedJSONLIssues = %v, want %v", got, want)
+	}
+	if got := changedJSONLIssues(after, after); len(got) != 0 {
+		t.Errorf("no change: got %v", got)
+	}
+}
@@ -0,0 +1,102 @@
+package main
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/config"
+)
+
+const gitCommitTimeout = 30 * time.Second
+
+// jsonlIssueLines maps each issue ID in the JSONL file at path to its
+// line. A missing file has no issues.
+func jsonlIssueLines(path string) (map[string]string, error) {
+	f, err := os.Open(path) //nolint:gosec
+	if err != nil {
+		if os.IsNotExist(err) {
+			return map[string]string{}, nil
+		}
+		return nil, err
+	}
+	defer f.Close()
+
+	scanner := bufio.NewScanner(f)
+	scanner.Buffer(make([]byte, 0, 1024*1024), 64*1024*1024)
+	lines := make(map[string]string)
+	for scanner.Scan() {
+		var rec struct {
+			Type string `json:"_type"`
+			ID   string `json:"id"`
+		}
+		if json.Unmarshal(scanner.Bytes(), &rec) != nil || rec.ID == "" || (rec.Type != "" && rec.Type != "issue") {
+			continue
+		}
+		lines[rec.ID] = scanner.Text()
+	}
+	return lines, scanner.Err()
+}
+
+// changedJSONLIssues lists the issues added, changed, or removed between
+// two exports.
+func changedJSONLIssues(before, after map[string]string) []string {
+	var ids []string
+	for id, line := range after {
+		if before[id] != line {
+			ids = append(ids, id)
+		}
+	}
+	for id := range before {
+		if _, ok := after[id]; !ok {
+			ids = append(ids, id)
+		}
+	}
+	return ids
+}
+
+// gitCommitFile commits path, and nothing else that is staged, with msg.
+// git.author and git.no-gpg-sign apply. Like gitAddFile, it scrubs the
+// environment of a calling git hook.
+func gitCommitFile(path, msg string) error {
+	if wt := hookWorkTreeRoot(); wt != "" {
+		// Committing from inside a hook would nest in the hook's own
+		// commit; the hook stages the export instead.
+		return nil
+	}
+	args := []string{"commit", "--no-verify", "-m", msg}
+	if author := config.GetString("git.author"); author != "" {
+		args = append(args, "--author", author)
+	}
+	if config.GetBool("git.no-gpg-sign") {
+		args = append(args, "--no-gpg-sign")
+	}
+	args = append(args, "--only", "--", filepath.Base(path))
+
+	ctx, cancel := context.WithTimeout(context.Background(), gitCommitTimeout)
+	defer cancel()
+	cmd := exec.CommandContext(ctx, "git", args...) //nolint:gosec // G204: fixed git subcommand; args are config values and a path
+	cmd.Dir = filepath.Dir(path)
+	cmd.Env = scrubGitHookEnv(os.Environ())
+	out, err := cmd.CombinedOutput()
+	if ctx.Err() == context.DeadlineExceeded {
+		return fmt.Errorf("git commit timed out after %s", gitCommitTimeout)
+	}
+	if err != nil {
+		trimmed := strings.TrimSpace(string(out))
+		if strings.Contains(trimmed, "nothing to commit") || strings.Contains(trimmed, "no changes added to commit") {
+			return nil
+		}
+		if trimmed != "" {
+			return fmt.Errorf("%w: %s", err, trimmed)
+		}
+		return err
+	}
+	return nil
+}
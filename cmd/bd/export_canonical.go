@@ -0,0 +1,109 @@
+package main
+
+import (
+	"bytes"
+	"cmp"
+	"encoding/json"
+	"slices"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// canonicalizeExportIssue puts issue into the canonical form written to
+// JSONL, so exporting unchanged data always yields the same bytes no matter
+// which connection, time zone, or query plan produced the rows:
+//
+//   - timestamps are UTC at whole-second precision (what DATETIME stores)
+//   - labels are sorted, dependencies are sorted by target then type,
+//     comments by creation time then id, code references by path then
+//     line range, work log entries by time logged then id, and checklist
+//     items by number
+//   - issue and dependency metadata are re-encoded with sorted keys and no
+//     insignificant whitespace
+//
+// Field order is the fixed order of the record structs.
+func canonicalizeExportIssue(issue *types.Issue) {
+	// Sanitize zero-value timestamps that can't be marshaled to JSON.
+	// NULL datetime columns scanned as time.Time{} (year 0001) cause
+	// MarshalJSON to fail with "year outside of range [0,9999]". (GH#2488)
+	sanitizeZeroTime(issue)
+
+	issue.CreatedAt = canonicalTime(issue.CreatedAt)
+	issue.UpdatedAt = canonicalTime(issue.UpdatedAt)
+	for _, t := range []**time.Time{&issue.StartedAt, &issue.ClosedAt, &issue.DueAt, &issue.DeferUntil, &issue.CompactedAt} {
+		if *t != nil {
+			c := canonicalTime(**t)
+			*t = &c
+		}
+	}
+
+	slices.Sort(issue.Labels)
+	slices.Sort(issue.Assignees)
+	for _, dep := range issue.Dependencies {
+		dep.CreatedAt = canonicalTime(dep.CreatedAt)
+		dep.Metadata = string(canonicalJSON(json.RawMessage(dep.Metadata)))
+	}
+	slices.SortStableFunc(issue.Dependencies, func(a, b *types.Dependency) int {
+		if c := cmp.Compare(a.DependsOnID, b.DependsOnID); c != 0 {
+			return c
+		}
+		return cmp.Compare(a.Type, b.Type)
+	})
+	for _, c := range issue.Comments {
+		c.CreatedAt = canonicalTime(c.CreatedAt)
+	}
+	slices.SortStableFunc(issue.Comments, func(a, b *types.Comment) int {
+		if c := a.CreatedAt.Compare(b.CreatedAt); c != 0 {
+			return c
+		}
+		return cmp.Compare(a.ID, b.ID)
+	})
+	for _, r := range issue.CodeRefs {
+		r.CreatedAt = canonicalTime(r.CreatedAt)
+	}
+	slices.SortStableFunc(issue.CodeRefs, func(a, b *types.CodeRef) int {
+		return cmp.Or(cmp.Compare(a.Path, b.Path), cmp.Compare(a.LineStart, b.LineStart), cmp.Compare(a.LineEnd, b.LineEnd))
+	})
+	for _, w := range issue.WorkLog {
+		w.LoggedAt = canonicalTime(w.LoggedAt)
+	}
+	slices.SortStableFunc(issue.WorkLog, func(a, b *types.WorkLog) int {
+		return cmp.Or(a.LoggedAt.Compare(b.LoggedAt), cmp.Compare(a.ID, b.ID))
+	})
+	for _, todo := range issue.Todos {
+		todo.CreatedAt = canonicalTime(todo.CreatedAt)
+		if todo.DoneAt != nil {
+			c := canonicalTime(*todo.DoneAt)
+			todo.DoneAt = &c
+		}
+	}
+	slices.SortStableFunc(issue.Todos, func(a, b *types.TodoItem) int {
+		return cmp.Compare(a.Seq, b.Seq)
+	})
+
+	issue.Metadata = canonicalJSON(issue.Metadata)
+}
+
+func canonicalTime(t time.Time) time.Time {
+	return t.UTC().Truncate(time.Second)
+}
+
+// canonicalJSON re-encodes raw with object keys sorted, keeping numbers
+// exactly as written. Input that does not parse is returned unchanged.
+func canonicalJSON(raw json.RawMessage) json.RawMessage {
+	if len(raw) == 0 {
+		return raw
+	}
+	dec := json.NewDecoder(bytes.NewReader(raw))
+	dec.UseNumber()
+	var v interface{}
+	if err := dec.Decode(&v); err != nil {
+		return raw
+	}
+	out, err := json.Marshal(v)
+	if err != nil {
+		return raw
+	}
+	return out
+}
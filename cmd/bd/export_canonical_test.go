@@ -0,0 +1,59 @@
+package main
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestCanonicalizeExportIssue(t *testing.T) {
+	zone := time.FixedZone("UTC+2", 2*60*60)
+	closed := time.Date(2026, 3, 1, 12, 0, 0, 500_000_000, zone)
+	issue := &types.Issue{
+		ID:        "bd-1",
+		CreatedAt: time.Date(2026, 3, 1, 10, 0, 0, 123_000_000, zone),
+		ClosedAt:  &closed,
+		Labels:    []string{"web", "auth"},
+		Dependencies: []*types.Dependency{
+			{IssueID: "bd-1", DependsOnID: "bd-9", Type: types.DepBlocks},
+			{IssueID: "bd-1", DependsOnID: "bd-2", Type: types.DepRelated},
+			{IssueID: "bd-1", DependsOnID: "bd-2", Type: types.DepBlocks},
+		},
+		Comments: []*types.Comment{
+			{ID: "c2", CreatedAt: time.Date(2026, 3, 2, 0, 0, 0, 0, time.UTC)},
+			{ID: "c1", CreatedAt: time.Date(2026, 3, 1, 0, 0, 0, 0, time.UTC)},
+		},
+		Metadata: json.RawMessage(`{ "b": 1.50, "a": {"y": true, "x": null} }`),
+	}
+
+	canonicalizeExportIssue(issue)
+
+	if got := issue.CreatedAt.Format(time.RFC3339Nano); got != "2026-03-01T08:00:00Z" {
+		t.Errorf("created_at = %s, want UTC whole seconds", got)
+	}
+	if got := issue.ClosedAt.Format(time.RFC3339Nano); got != "2026-03-01T10:00:00Z" {
+		t.Errorf("closed_at = %s", got)
+	}
+	if issue.UpdatedAt.IsZero() {
+		t.Error("zero updated_at should be sanitized to the epoch")
+	}
+	if issue.Labels[0] != "auth" {
+		t.Errorf("labels = %v, want sorted", issue.Labels)
+	}
+	var deps []string
+	for _, d := range issue.Dependencies {
+		deps = append(deps, d.DependsOnID+"/"+string(d.Type))
+	}
+	if want := "bd-2/blocks bd-2/related bd-9/blocks"; strings.Join(deps, " ") != want {
+		t.Errorf("dependencies = %s, want %s", strings.Join(deps, " "), want)
+	}
+	if issue.Comments[0].ID != "c1" {
+		t.Errorf("comments not sorted by time: first = %s", issue.Comments[0].ID)
+	}
+	if got := string(issue.Metadata); got != `{"a":{"x":null,"y":true},"b":1.50}` {
+		t.Errorf("metadata = %s", got)
+	}
+}
@@ -0,0 +1,321 @@
+package main
+
+import (
+	"encoding/csv"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// csvColumns is the documented column set for 'bd export --format csv' and
+// 'bd import --format csv'. The order is the export order; import matches
+// columns by header name, so a spreadsheet may reorder them.
+var csvColumns = []string{
+	"id",
+	"title",
+	"status",
+	"priority",
+	"issue_type",
+	"assignee",
+	"owner",
+	"labels",
+	"description",
+	"design",
+	"acceptance_criteria",
+	"notes",
+	"close_reason",
+	"external_ref",
+	"estimated_minutes",
+	"due_at",
+	"defer_until",
+	"created_at",
+	"updated_at",
+	"started_at",
+	"closed_at",
+	"metadata",
+}
+
+// csvTextColumns are free-text columns that get formula escaping.
+var csvTextColumns = map[string]bool{
+	"title":               true,
+	"assignee":            true,
+	"owner":               true,
+	"labels":              true,
+	"description":         true,
+	"design":              true,
+	"acceptance_criteria": true,
+	"notes":               true,
+	"close_reason":        true,
+	"external_ref":        true,
+}
+
+// escapeCSVFormula prefixes a single quote to text that a spreadsheet would
+// otherwise evaluate as a formula (leading =, +, -, @) or that already
+// starts with a quote, so unescapeCSVFormula can restore it exactly.
+func escapeCSVFormula(s string) string {
+	if s == "" {
+		return s
+	}
+	switch s[0] {
+	case '=', '+', '-', '@', '\'':
+		return "'" + s
+	}
+	return s
+}
+
+// unescapeCSVFormula reverses escapeCSVFormula.
+func unescapeCSVFormula(s string) string {
+	return strings.TrimPrefix(s, "'")
+}
+
+func formatCSVTime(t *time.Time) string {
+	if t == nil || t.IsZero() {
+		return ""
+	}
+	return t.UTC().Format(time.RFC3339)
+}
+
+// csvRecord renders issue as a row in csvColumns order.
+func csvRecord(issue *types.Issue) []string {
+	values := map[string]string{
+		"id":                  issue.ID,
+		"title":               issue.Title,
+		"status":              string(issue.Status),
+		"priority":            strconv.Itoa(issue.Priority),
+		"issue_type":          string(issue.IssueType),
+		"assignee":            issue.Assignee,
+		"owner":               issue.Owner,
+		"labels":              strings.Join(issue.Labels, ","),
+		"description":         issue.Description,
+		"design":              issue.Design,
+		"acceptance_criteria": issue.AcceptanceCriteria,
+		"notes":               issue.Notes,
+		"close_reason":        issue.CloseReason,
+		"due_at":              formatCSVTime(issue.DueAt),
+		"defer_until":         formatCSVTime(issue.DeferUntil),
+		"created_at":          formatCSVTime(&issue.CreatedAt),
+		"updated_at":          formatCSVTime(&issue.UpdatedAt),
+		"started_at":          formatCSVTime(issue.StartedAt),
+		"closed_at":           formatCSVTime(issue.ClosedAt),
+	}
+	if issue.ExternalRef != nil {
+		values["external_ref"] = *issue.ExternalRef
+	}
+	if issue.EstimatedMinutes != nil {
+		values["estimated_minutes"] = strconv.Itoa(*issue.EstimatedMinutes)
+	}
+	if len(issue.Metadata) > 0 && string(issue.Metadata) != "{}" {
+		values["metadata"] = string(issue.Metadata)
+	}
+
+	record := make([]string, len(csvColumns))
+	for i, col := range csvColumns {
+		v := values[col]
+		if csvTextColumns[col] {
+			v = escapeCSVFormula(v)
+		}
+		record[i] = v
+	}
+	return record
+}
+
+// writeCSVExport writes issues as CSV with a header row of csvColumns.
+// Labels must already be populated on each issue.
+func writeCSVExport(w io.Writer, issues []*types.Issue) (int, error) {
+	cw := csv.NewWriter(w)
+	if err := cw.Write(csvColumns); err != nil {
+		return 0, fmt.Errorf("failed to write CSV header: %w", err)
+	}
+	count := 0
+	for _, issue := range issues {
+		if err := cw.Write(csvRecord(issue)); err != nil {
+			return count, fmt.Errorf("failed to write issue %s: %w", issue.ID, err)
+		}
+		count++
+	}
+	cw.Flush()
+	if err := cw.Error(); err != nil {
+		return count, fmt.Errorf("failed to write CSV: %w", err)
+	}
+	return count, nil
+}
+
+// parseCSVTime parses an RFC3339 timestamp or a bare YYYY-MM-DD date, the
+// two forms spreadsheets are most likely to leave behind.
+func parseCSVTime(s string) (*time.Time, error) {
+	if s == "" {
+		return nil, nil
+	}
+	if t, err := time.Parse(time.RFC3339, s); err == nil {
+		t = t.UTC()
+		return &t, nil
+	}
+	t, err := time.Parse("2006-01-02", s)
+	if err != nil {
+		return nil, fmt.Errorf("expected RFC3339 timestamp or YYYY-MM-DD, got %q", s)
+	}
+	return &t, nil
+}
+
+// csvIssue builds an issue from one CSV row. col maps header names to
+// field indexes; columns absent from the header are left at their zero value,
+// except priority, which defaults to 2 like 'bd create'.
+func csvIssue(col map[string]int, record []string) (*types.Issue, error) {
+	get := func(name string) string {
+		i, ok := col[name]
+		if !ok || i >= len(record) {
+			return ""
+		}
+		// Text is kept verbatim so descriptions round-trip byte for byte.
+		if csvTextColumns[name] {
+			return unescapeCSVFormula(record[i])
+		}
+		return strings.TrimSpace(record[i])
+	}
+
+	issue := &types.Issue{
+		ID:                 get("id"),
+		Title:              get("title"),
+		Status:             types.Status(get("status")),
+		IssueType:          types.IssueType(get("issue_type")),
+		Assignee:           get("assignee"),
+		Owner:              get("owner"),
+		Description:        get("description"),
+		Design:             get("design"),
+		AcceptanceCriteria: get("acceptance_criteria"),
+		Notes:              get("notes"),
+		CloseReason:        get("close_reason"),
+	}
+
+	if v := get("priority"); v != "" {
+		p, err := strconv.Atoi(strings.TrimPrefix(strings.ToUpper(v), "P"))
+		if err != nil {
+			return nil, fmt.Errorf("priority: expected 0-4, got %q", v)
+		}
+		issue.Priority = p
+	} else {
+		issue.Priority = 2
+	}
+	if v := get("labels"); v != "" {
+		for _, label := range strings.Split(v, ",") {
+			if label = strings.TrimSpace(label); label != "" {
+				issue.Labels = append(issue.Labels, label)
+			}
+		}
+	}
+	if v := get("external_ref"); v != "" {
+		issue.ExternalRef = &v
+	}
+	if v := get("estimated_minutes"); v != "" {
+		minutes, err := strconv.Atoi(v)
+		if err != nil {
+			return nil, fmt.Errorf("estimated_minutes: expected an integer, got %q", v)
+		}
+		issue.EstimatedMinutes = &minutes
+	}
+	if v := get("metadata"); v != "" {
+		if !json.Valid([]byte(v)) {
+			return nil, fmt.Errorf("metadata: not valid JSON")
+		}
+		issue.Metadata = json.RawMessage(v)
+	}
+
+	for _, f := range []struct {
+		name string
+		dst  **time.Time
+	}{
+		{"due_at", &issue.DueAt},
+		{"defer_until", &issue.DeferUntil},
+		{"started_at", &issue.StartedAt},
+		{"closed_at", &issue.ClosedAt},
+	} {
+		t, err := parseCSVTime(get(f.name))
+		if err != nil {
+			return nil, fmt.Errorf("%s: %w", f.name, err)
+		}
+		*f.dst = t
+	}
+	// created_at and updated_at are not pointers: blank means "let the
+	// importer fill it in".
+	for _, f := range []struct {
+		name string
+		dst  *time.Time
+	}{
+		{"created_at", &issue.CreatedAt},
+		{"updated_at", &issue.UpdatedAt},
+	} {
+		t, err := parseCSVTime(get(f.name))
+		if err != nil {
+			return nil, fmt.Errorf("%s: %w", f.name, err)
+		}
+		if t != nil {
+			*f.dst = *t
+		}
+	}
+	return issue, nil
+}
+
+// parseCSVImport reads a CSV file with a header row into c. Rows are
+// numbered by the line they start on, so multi-line cells don't throw off
+// the line numbers reported for later rows.
+func parseCSVImport(r io.Reader, c *importCollector) error {
+	cr := csv.NewReader(r)
+	header, err := cr.Read()
+	if err == io.EOF {
+		return nil
+	}
+	if err != nil {
+		return fmt.Errorf("failed to read CSV header: %w", err)
+	}
+
+	known := make(map[string]bool, len(csvColumns))
+	for _, name := range csvColumns {
+		known[name] = true
+	}
+	col := make(map[string]int, len(header))
+	for i, name := range header {
+		name = strings.ToLower(strings.TrimSpace(strings.TrimPrefix(name, "\ufeff")))
+		if !known[name] {
+			return fmt.Errorf("unknown CSV column %q (expected columns: %s)", name, strings.Join(csvColumns, ", "))
+		}
+		if _, dup := col[name]; dup {
+			return fmt.Errorf("duplicate CSV column %q", name)
+		}
+		col[name] = i
+	}
+	if _, ok := col["title"]; !ok {
+		return fmt.Errorf("CSV header has no title column")
+	}
+
+	for {
+		record, err := cr.Read()
+		if err == io.EOF {
+			return nil
+		}
+		var parseErr *csv.ParseError
+		if errors.As(err, &parseErr) && errors.Is(err, csv.ErrFieldCount) {
+			c.fail(parseErr.StartLine, "expected %d fields, got %d", len(header), len(record))
+			continue
+		}
+		if err != nil {
+			return fmt.Errorf("failed to read CSV: %w", err)
+		}
+		line, _ := cr.FieldPos(0)
+
+		if strings.TrimSpace(strings.Join(record, "")) == "" {
+			continue
+		}
+		issue, err := csvIssue(col, record)
+		if err != nil {
+			c.fail(line, "%v", err)
+			continue
+		}
+		c.addIssue(line, issue)
+	}
+}
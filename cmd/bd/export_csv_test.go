@@ -0,0 +1,115 @@
+package main
+
+import (
+	"bytes"
+	"encoding/json"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestCSVExportImportRoundTrip(t *testing.T) {
+	created := time.Date(2026, 3, 1, 9, 30, 0, 0, time.UTC)
+	closed := created.Add(48 * time.Hour)
+	ref := "gh-42"
+	estimate := 90
+	original := &types.Issue{
+		ID:          "bd-1",
+		Title:       `=SUM(A1) "quoted", with comma`,
+		Description: "- first line\nsecond line\n",
+		Status:      types.StatusClosed,
+		Priority:    0,
+		IssueType:   types.TypeBug,
+		Assignee:    "alice",
+		Labels:      []string{"backend", "urgent"},
+		CloseReason: "'fixed'",
+		ExternalRef: &ref,
+
+		EstimatedMinutes: &estimate,
+		CreatedAt:        created,
+		UpdatedAt:        closed,
+		ClosedAt:         &closed,
+		Metadata:         json.RawMessage(`{"team":"core"}`),
+	}
+
+	var buf bytes.Buffer
+	if _, err := writeCSVExport(&buf, []*types.Issue{original}); err != nil {
+		t.Fatalf("writeCSVExport: %v", err)
+	}
+	if !strings.Contains(buf.String(), `'=SUM(A1)`) {
+		t.Errorf("formula-like title was not escaped:\n%s", buf.String())
+	}
+
+	c := &importCollector{}
+	if err := parseCSVImport(&buf, c); err != nil {
+		t.Fatalf("parseCSVImport: %v", err)
+	}
+	if len(c.lineErrors) != 0 {
+		t.Fatalf("unexpected line errors: %+v", c.lineErrors)
+	}
+	if len(c.issues) != 1 {
+		t.Fatalf("got %d issues, want 1", len(c.issues))
+	}
+	got := c.issues[0]
+
+	if got.ID != original.ID || got.Title != original.Title || got.Description != original.Description {
+		t.Errorf("text fields changed: id=%q title=%q description=%q", got.ID, got.Title, got.Description)
+	}
+	if got.Status != original.Status || got.Priority != 0 || got.IssueType != original.IssueType {
+		t.Errorf("workflow fields changed: status=%q priority=%d type=%q", got.Status, got.Priority, got.IssueType)
+	}
+	if got.CloseReason != original.CloseReason {
+		t.Errorf("close_reason = %q, want %q", got.CloseReason, original.CloseReason)
+	}
+	if strings.Join(got.Labels, ",") != "backend,urgent" {
+		t.Errorf("labels = %v", got.Labels)
+	}
+	if got.ExternalRef == nil || *got.ExternalRef != ref {
+		t.Errorf("external_ref = %v", got.ExternalRef)
+	}
+	if got.EstimatedMinutes == nil || *got.EstimatedMinutes != estimate {
+		t.Errorf("estimated_minutes = %v", got.EstimatedMinutes)
+	}
+	if !got.CreatedAt.Equal(created) || !got.UpdatedAt.Equal(closed) {
+		t.Errorf("timestamps changed: created=%v updated=%v", got.CreatedAt, got.UpdatedAt)
+	}
+	if got.ClosedAt == nil || !got.ClosedAt.Equal(closed) {
+		t.Errorf("closed_at = %v", got.ClosedAt)
+	}
+	if string(got.Metadata) != `{"team":"core"}` {
+		t.Errorf("metadata = %s", got.Metadata)
+	}
+}
+
+func TestParseCSVImportReportsBadRows(t *testing.T) {
+	input := "title,priority,due_at\n" +
+		"Good row,P1,2026-07-01\n" +
+		"Bad priority,high,\n" +
+		",2,\n" +
+		"Too,many,fields,here\n"
+
+	c := &importCollector{}
+	if err := parseCSVImport(strings.NewReader(input), c); err != nil {
+		t.Fatalf("parseCSVImport: %v", err)
+	}
+	if len(c.issues) != 1 || c.issues[0].Priority != 1 || c.issues[0].DueAt == nil {
+		t.Fatalf("expected the good row with priority 1 and a due date, got %+v", c.issues)
+	}
+	var lines []int
+	for _, le := range c.lineErrors {
+		lines = append(lines, le.Line)
+	}
+	if len(lines) != 3 || lines[0] != 3 || lines[1] != 4 || lines[2] != 5 {
+		t.Errorf("line errors = %+v, want lines 3, 4, 5", c.lineErrors)
+	}
+}
+
+func TestParseCSVImportRejectsUnknownColumn(t *testing.T) {
+	c := &importCollector{}
+	err := parseCSVImport(strings.NewReader("title,severity\nx,high\n"), c)
+	if err == nil || !strings.Contains(err.Error(), "severity") {
+		t.Fatalf("expected unknown column error, got %v", err)
+	}
+}
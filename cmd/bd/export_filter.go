@@ -0,0 +1,139 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/utils"
+	"github.com/steveyegge/beads/internal/validation"
+)
+
+// Filter flags for exporting a subset of issues. They mirror the bd list
+// flags of the same names.
+var (
+	exportStatus        string
+	exportAssignee      string
+	exportType          string
+	exportLabels        []string
+	exportLabelsAny     []string
+	exportExcludeLabels []string
+	exportPriority      string
+	exportIDs           string
+	exportParent        string
+	exportCreatedAfter  string
+	exportCreatedBefore string
+	exportUpdatedAfter  string
+	exportUpdatedBefore string
+)
+
+func init() {
+	exportCmd.Flags().StringVarP(&exportStatus, "status", "s", "", "Only export issues with this status (comma-separated for multiple)")
+	exportCmd.Flags().StringVarP(&exportAssignee, "assignee", "a", "", "Only export issues assigned to this user")
+	exportCmd.Flags().StringVarP(&exportType, "type", "t", "", "Only export issues of this type")
+	exportCmd.Flags().StringSliceVarP(&exportLabels, "label", "l", nil, "Only export issues with ALL of these labels")
+	exportCmd.Flags().StringSliceVar(&exportLabelsAny, "label-any", nil, "Only export issues with AT LEAST ONE of these labels")
+	exportCmd.Flags().StringSliceVar(&exportExcludeLabels, "exclude-label", nil, "Skip issues that have ANY of these labels")
+	exportCmd.Flags().StringVarP(&exportPriority, "priority", "p", "", "Only export issues with this priority (0-4 or P0-P4)")
+	exportCmd.Flags().StringVar(&exportIDs, "id", "", "Only export these issue IDs (comma-separated)")
+	exportCmd.Flags().StringVar(&exportParent, "parent", "", "Only export this issue and its descendants")
+	exportCmd.Flags().StringVar(&exportCreatedAfter, "created-after", "", "Only export issues created after this date")
+	exportCmd.Flags().StringVar(&exportCreatedBefore, "created-before", "", "Only export issues created before this date")
+	exportCmd.Flags().StringVar(&exportUpdatedAfter, "updated-after", "", "Only export issues updated after this date")
+	exportCmd.Flags().StringVar(&exportUpdatedBefore, "updated-before", "", "Only export issues updated before this date")
+}
+
+// applyExportFilters narrows filter by the export filter flags and reports
+// whether any were set (including --parent).
+func applyExportFilters(ctx context.Context, filter *types.IssueFilter) (bool, error) {
+	filtered := false
+
+	if exportStatus != "" && exportStatus != "all" {
+		customStatuses, _ := store.GetCustomStatuses(ctx)
+		for _, part := range strings.Split(exportStatus, ",") {
+			s := types.Status(strings.TrimSpace(part))
+			if !s.IsValidWithCustom(customStatuses) {
+				return false, fmt.Errorf("invalid --status %q", part)
+			}
+			filter.Statuses = append(filter.Statuses, s)
+		}
+		filtered = true
+	}
+	if exportAssignee != "" {
+		filter.Assignee = &exportAssignee
+		filtered = true
+	}
+	if exportType != "" {
+		t := types.IssueType(exportType)
+		filter.IssueType = &t
+		filtered = true
+	}
+	if labels := utils.NormalizeLabels(exportLabels); len(labels) > 0 {
+		filter.Labels = labels
+		filtered = true
+	}
+	if labels := utils.NormalizeLabels(exportLabelsAny); len(labels) > 0 {
+		filter.LabelsAny = labels
+		filtered = true
+	}
+	if labels := utils.NormalizeLabels(exportExcludeLabels); len(labels) > 0 {
+		filter.ExcludeLabels = labels
+		filtered = true
+	}
+	if exportPriority != "" {
+		priority, err := validation.ValidatePriority(exportPriority)
+		if err != nil {
+			return false, err
+		}
+		filter.Priority = &priority
+		filtered = true
+	}
+	if exportIDs != "" {
+		filter.IDs = utils.NormalizeLabels(strings.Split(exportIDs, ","))
+		filtered = true
+	}
+
+	for _, f := range []struct {
+		flag  string
+		value string
+		dst   **time.Time
+	}{
+		{"--created-after", exportCreatedAfter, &filter.CreatedAfter},
+		{"--created-before", exportCreatedBefore, &filter.CreatedBefore},
+		{"--updated-after", exportUpdatedAfter, &filter.UpdatedAfter},
+		{"--updated-before", exportUpdatedBefore, &filter.UpdatedBefore},
+	} {
+		if f.value == "" {
+			continue
+		}
+		t, err := parseDateFilterFlag(f.value)
+		if err != nil {
+			return false, fmt.Errorf("parsing %s: %w", f.flag, err)
+		}
+		*f.dst = &t
+		filtered = true
+	}
+	return filtered || exportParent != "", nil
+}
+
+// exportParentTree returns the --parent issue and its descendants matching
+// filter, in the same order a plain export uses.
+func exportParentTree(ctx context.Context, filter types.IssueFilter) ([]*types.Issue, error) {
+	parent, err := store.GetIssue(ctx, exportParent)
+	if err != nil || parent == nil {
+		return nil, fmt.Errorf("parent issue '%s' not found", exportParent)
+	}
+	found := map[string]*types.Issue{parent.ID: parent}
+	if err := findAllDescendants(ctx, store, "", parent.ID, filter, found); err != nil {
+		return nil, fmt.Errorf("error finding descendants: %w", err)
+	}
+	issues := make([]*types.Issue, 0, len(found))
+	for _, issue := range found {
+		issues = append(issues, issue)
+	}
+	slices.SortFunc(issues, types.CompareListOrder)
+	return issues, nil
+}
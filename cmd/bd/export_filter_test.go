@@ -0,0 +1,46 @@
+package main
+
+import (
+	"context"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestApplyExportFilters(t *testing.T) {
+	defer func() {
+		exportLabels, exportPriority, exportIDs, exportUpdatedAfter = nil, "", "", ""
+	}()
+
+	var filter types.IssueFilter
+	filtered, err := applyExportFilters(context.Background(), &filter)
+	if err != nil || filtered {
+		t.Fatalf("no flags: filtered = %v, err = %v", filtered, err)
+	}
+
+	exportLabels = []string{" infra "}
+	exportPriority = "P1"
+	exportIDs = "bd-1, bd-2"
+	exportUpdatedAfter = "2026-01-01"
+	filtered, err = applyExportFilters(context.Background(), &filter)
+	if err != nil || !filtered {
+		t.Fatalf("filtered = %v, err = %v", filtered, err)
+	}
+	if len(filter.Labels) != 1 || filter.Labels[0] != "infra" {
+		t.Errorf("Labels = %v", filter.Labels)
+	}
+	if filter.Priority == nil || *filter.Priority != 1 {
+		t.Errorf("Priority = %v", filter.Priority)
+	}
+	if len(filter.IDs) != 2 || filter.IDs[1] != "bd-2" {
+		t.Errorf("IDs = %v", filter.IDs)
+	}
+	if filter.UpdatedAfter == nil {
+		t.Error("UpdatedAfter not set")
+	}
+
+	exportPriority = "P9"
+	if _, err := applyExportFilters(context.Background(), &types.IssueFilter{}); err == nil {
+		t.Error("expected an error for an invalid priority")
+	}
+}
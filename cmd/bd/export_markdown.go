@@ -0,0 +1,181 @@
+package main
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/atomicfile"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// markdownTableCell makes s safe inside a single markdown table cell.
+func markdownTableCell(s string) string {
+	s = strings.ReplaceAll(s, "|", `\|`)
+	s = strings.ReplaceAll(s, "\r\n", " ")
+	return strings.ReplaceAll(s, "\n", " ")
+}
+
+// markdownIssueFileName returns the file name used for issue in a
+// 'bd export --format markdown --dir' export.
+func markdownIssueFileName(id string) string {
+	return strings.NewReplacer("/", "_", `\`, "_").Replace(id) + ".md"
+}
+
+// formatMarkdownIssue renders one issue as markdown. level is the heading
+// level of the title (1 for a standalone file, 2 inside a report); sections
+// are nested one level below it.
+func formatMarkdownIssue(issue *types.Issue, level int) string {
+	var b strings.Builder
+	title := strings.Repeat("#", level)
+	section := strings.Repeat("#", level+1)
+
+	fmt.Fprintf(&b, "%s %s: %s\n\n", title, issue.ID, issue.Title)
+
+	rows := [][2]string{
+		{"Status", string(issue.Status)},
+		{"Priority", fmt.Sprintf("P%d", issue.Priority)},
+		{"Type", string(issue.IssueType)},
+	}
+	if issue.Assignee != "" {
+		rows = append(rows, [2]string{"Assignee", issue.Assignee})
+	}
+	if issue.Owner != "" {
+		rows = append(rows, [2]string{"Owner", issue.Owner})
+	}
+	if len(issue.Labels) > 0 {
+		rows = append(rows, [2]string{"Labels", strings.Join(issue.Labels, ", ")})
+	}
+	if issue.ExternalRef != nil && *issue.ExternalRef != "" {
+		rows = append(rows, [2]string{"External ref", *issue.ExternalRef})
+	}
+	if issue.DueAt != nil {
+		rows = append(rows, [2]string{"Due", issue.DueAt.UTC().Format("2006-01-02")})
+	}
+	if !issue.CreatedAt.IsZero() {
+		rows = append(rows, [2]string{"Created", issue.CreatedAt.UTC().Format("2006-01-02")})
+	}
+	if !issue.UpdatedAt.IsZero() {
+		rows = append(rows, [2]string{"Updated", issue.UpdatedAt.UTC().Format("2006-01-02")})
+	}
+	if issue.ClosedAt != nil {
+		closed := issue.ClosedAt.UTC().Format("2006-01-02")
+		if issue.CloseReason != "" {
+			closed += " (" + issue.CloseReason + ")"
+		}
+		rows = append(rows, [2]string{"Closed", closed})
+	}
+
+	b.WriteString("| Field | Value |\n|---|---|\n")
+	for _, row := range rows {
+		fmt.Fprintf(&b, "| %s | %s |\n", row[0], markdownTableCell(row[1]))
+	}
+
+	for _, s := range []struct{ heading, body string }{
+		{"Description", issue.Description},
+		{"Design", issue.Design},
+		{"Acceptance Criteria", issue.AcceptanceCriteria},
+		{"Notes", issue.Notes},
+	} {
+		if strings.TrimSpace(s.body) == "" {
+			continue
+		}
+		fmt.Fprintf(&b, "\n%s %s\n\n%s\n", section, s.heading, strings.TrimRight(s.body, "\n"))
+	}
+
+	if len(issue.Dependencies) > 0 {
+		fmt.Fprintf(&b, "\n%s Dependencies\n\n", section)
+		for _, dep := range issue.Dependencies {
+			fmt.Fprintf(&b, "- %s %s\n", dep.Type, dep.DependsOnID)
+		}
+	}
+
+	if len(issue.Comments) > 0 {
+		fmt.Fprintf(&b, "\n%s Comments\n", section)
+		for _, c := range issue.Comments {
+			fmt.Fprintf(&b, "\n**%s** — %s\n\n%s\n", c.Author, c.CreatedAt.UTC().Format("2006-01-02 15:04"), strings.TrimRight(c.Text, "\n"))
+		}
+	}
+	return b.String()
+}
+
+// writeMarkdownReport writes all issues as a single markdown document with
+// a linked table of contents.
+func writeMarkdownReport(w io.Writer, issues []*types.Issue) (int, error) {
+	var b strings.Builder
+	b.WriteString("# Issues\n\n")
+	for _, issue := range issues {
+		fmt.Fprintf(&b, "- [%s: %s](#%s) — %s, P%d\n", issue.ID, markdownLinkText(issue.Title), markdownAnchor(issue.ID+": "+issue.Title), issue.Status, issue.Priority)
+	}
+	for _, issue := range issues {
+		b.WriteString("\n")
+		b.WriteString(formatMarkdownIssue(issue, 2))
+	}
+	if _, err := io.WriteString(w, b.String()); err != nil {
+		return 0, fmt.Errorf("failed to write markdown: %w", err)
+	}
+	return len(issues), nil
+}
+
+// writeMarkdownDir writes one <id>.md file per issue into dir, plus an
+// index.md linking to each of them.
+func writeMarkdownDir(dir string, issues []*types.Issue) (int, error) {
+	if err := os.MkdirAll(dir, 0o755); err != nil {
+		return 0, fmt.Errorf("failed to create %s: %w", dir, err)
+	}
+
+	var index strings.Builder
+	index.WriteString("# Issues\n\n")
+	count := 0
+	for _, issue := range issues {
+		name := markdownIssueFileName(issue.ID)
+		if err := writeMarkdownFile(filepath.Join(dir, name), formatMarkdownIssue(issue, 1)); err != nil {
+			return count, err
+		}
+		fmt.Fprintf(&index, "- [%s: %s](%s) — %s, P%d\n", issue.ID, markdownLinkText(issue.Title), name, issue.Status, issue.Priority)
+		count++
+	}
+	if err := writeMarkdownFile(filepath.Join(dir, "index.md"), index.String()); err != nil {
+		return count, err
+	}
+	return count, nil
+}
+
+func writeMarkdownFile(path, content string) error {
+	aw, err := atomicfile.Create(path, 0o644)
+	if err != nil {
+		return fmt.Errorf("failed to create %s: %w", path, err)
+	}
+	if _, err := io.WriteString(aw, content); err != nil {
+		_ = aw.Abort()
+		return fmt.Errorf("failed to write %s: %w", path, err)
+	}
+	if err := aw.Close(); err != nil {
+		return fmt.Errorf("failed to finalize %s: %w", path, err)
+	}
+	return nil
+}
+
+// markdownLinkText escapes the characters that would end a link label early.
+func markdownLinkText(s string) string {
+	return strings.NewReplacer("[", `\[`, "]", `\]`).Replace(s)
+}
+
+// markdownAnchor approximates the heading anchors generated by GitHub:
+// lowercase, spaces to hyphens, and punctuation other than - and _ dropped.
+func markdownAnchor(heading string) string {
+	var b strings.Builder
+	for _, r := range strings.ToLower(heading) {
+		switch {
+		case r == ' ':
+			b.WriteRune('-')
+		case r == '-' || r == '_':
+			b.WriteRune(r)
+		case r >= 'a' && r <= 'z', r >= '0' && r <= '9', r > 127:
+			b.WriteRune(r)
+		}
+	}
+	return b.String()
+}
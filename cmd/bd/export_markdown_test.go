@@ -0,0 +1,89 @@
+package main
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func markdownTestIssue() *types.Issue {
+	return &types.Issue{
+		ID:                 "bd-7",
+		Title:              "Fix [login] | retry",
+		Status:             types.StatusOpen,
+		Priority:           1,
+		IssueType:          types.TypeBug,
+		Assignee:           "alice",
+		Labels:             []string{"auth", "web"},
+		Description:        "Users get logged out.\n",
+		AcceptanceCriteria: "- retries succeed",
+		CreatedAt:          time.Date(2026, 4, 2, 10, 0, 0, 0, time.UTC),
+		Dependencies:       []*types.Dependency{{IssueID: "bd-7", DependsOnID: "bd-3", Type: types.DepBlocks}},
+		Comments:           []*types.Comment{{Author: "bob", Text: "Seen on staging too.", CreatedAt: time.Date(2026, 4, 3, 8, 15, 0, 0, time.UTC)}},
+	}
+}
+
+func TestFormatMarkdownIssue(t *testing.T) {
+	out := formatMarkdownIssue(markdownTestIssue(), 1)
+
+	for _, want := range []string{
+		"# bd-7: Fix [login] | retry\n",
+		"| Priority | P1 |\n",
+		"| Labels | auth, web |\n",
+		"## Description\n\nUsers get logged out.\n",
+		"## Acceptance Criteria\n\n- retries succeed\n",
+		"- blocks bd-3\n",
+		"**bob** — 2026-04-03 08:15\n\nSeen on staging too.\n",
+	} {
+		if !strings.Contains(out, want) {
+			t.Errorf("missing %q in:\n%s", want, out)
+		}
+	}
+	if strings.Contains(out, "## Design") {
+		t.Errorf("empty design section should be omitted:\n%s", out)
+	}
+}
+
+func TestWriteMarkdownReportLinksToHeadings(t *testing.T) {
+	var buf bytes.Buffer
+	if _, err := writeMarkdownReport(&buf, []*types.Issue{markdownTestIssue()}); err != nil {
+		t.Fatalf("writeMarkdownReport: %v", err)
+	}
+	out := buf.String()
+	if !strings.Contains(out, `- [bd-7: Fix \[login\] | retry](#bd-7-fix-login--retry)`) {
+		t.Errorf("table of contents entry not found:\n%s", out)
+	}
+	if !strings.Contains(out, "\n## bd-7: Fix [login] | retry\n") {
+		t.Errorf("issue heading should be level 2 in a report:\n%s", out)
+	}
+}
+
+func TestWriteMarkdownDir(t *testing.T) {
+	dir := filepath.Join(t.TempDir(), "issues")
+	count, err := writeMarkdownDir(dir, []*types.Issue{markdownTestIssue()})
+	if err != nil {
+		t.Fatalf("writeMarkdownDir: %v", err)
+	}
+	if count != 1 {
+		t.Fatalf("count = %d, want 1", count)
+	}
+	issue, err := os.ReadFile(filepath.Join(dir, "bd-7.md"))
+	if err != nil {
+		t.Fatalf("read issue file: %v", err)
+	}
+	if !strings.HasPrefix(string(issue), "# bd-7: ") {
+		t.Errorf("issue file should start with its title:\n%s", issue)
+	}
+	index, err := os.ReadFile(filepath.Join(dir, "index.md"))
+	if err != nil {
+		t.Fatalf("read index: %v", err)
+	}
+	if !strings.Contains(string(index), "](bd-7.md)") {
+		t.Errorf("index should link to bd-7.md:\n%s", index)
+	}
+}
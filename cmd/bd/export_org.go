@@ -0,0 +1,237 @@
+package main
+
+import (
+	"fmt"
+	"io"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// orgHeader declares the TODO keywords and priority range used by
+// 'bd export --format org', so org-mode parses the file without any
+// configuration. Beads priorities 0-4 map to cookies [#A]-[#E].
+const orgHeader = `#+TITLE: Issues
+#+TODO: TODO STARTED WAITING HOLD | DONE
+#+PRIORITIES: A E C
+`
+
+// orgKeyword maps a beads status to one of the TODO keywords in orgHeader.
+// Custom statuses fall back to TODO so they still show up in the agenda.
+func orgKeyword(status types.Status) string {
+	switch status {
+	case types.StatusInProgress, types.StatusHooked:
+		return "STARTED"
+	case types.StatusBlocked:
+		return "WAITING"
+	case types.StatusDeferred:
+		return "HOLD"
+	case types.StatusClosed:
+		return "DONE"
+	default:
+		return "TODO"
+	}
+}
+
+// orgPriority returns the priority cookie for a beads priority.
+func orgPriority(priority int) string {
+	if priority < 0 {
+		priority = 0
+	}
+	if priority > 4 {
+		priority = 4
+	}
+	return fmt.Sprintf("[#%c]", 'A'+priority)
+}
+
+// orgTag turns a label into a valid org tag, which may only contain
+// letters, digits, and _@#%.
+func orgTag(label string) string {
+	return strings.Map(func(r rune) rune {
+		switch {
+		case r >= 'a' && r <= 'z', r >= 'A' && r <= 'Z', r >= '0' && r <= '9', r > 127:
+			return r
+		case r == '_' || r == '@' || r == '#' || r == '%':
+			return r
+		}
+		return '_'
+	}, label)
+}
+
+// orgActiveDate and orgInactiveTime format timestamps the way org writes
+// them, in local time since that is what the agenda compares against.
+func orgActiveDate(t time.Time) string {
+	return t.Local().Format("<2006-01-02 Mon>")
+}
+
+func orgInactiveTime(t time.Time) string {
+	return t.Local().Format("[2006-01-02 Mon 15:04]")
+}
+
+// orgText writes free text as an org body, keeping lines that begin with
+// "*" from being read as headings.
+func orgText(b *strings.Builder, text string) {
+	for _, line := range strings.Split(strings.TrimRight(text, "\n"), "\n") {
+		if strings.HasPrefix(line, "*") {
+			line = " " + line
+		}
+		b.WriteString(line)
+		b.WriteString("\n")
+	}
+}
+
+// formatOrgIssue renders one issue as an org heading at the given level,
+// without its children.
+func formatOrgIssue(issue *types.Issue, level int) string {
+	var b strings.Builder
+	stars := strings.Repeat("*", level)
+
+	fmt.Fprintf(&b, "%s %s %s %s", stars, orgKeyword(issue.Status), orgPriority(issue.Priority), issue.Title)
+	if len(issue.Labels) > 0 {
+		tags := make([]string, len(issue.Labels))
+		for i, label := range issue.Labels {
+			tags[i] = orgTag(label)
+		}
+		fmt.Fprintf(&b, " :%s:", strings.Join(tags, ":"))
+	}
+	b.WriteString("\n")
+
+	var planning []string
+	if issue.ClosedAt != nil {
+		planning = append(planning, "CLOSED: "+orgInactiveTime(*issue.ClosedAt))
+	}
+	if issue.DueAt != nil {
+		planning = append(planning, "DEADLINE: "+orgActiveDate(*issue.DueAt))
+	}
+	if issue.DeferUntil != nil {
+		planning = append(planning, "SCHEDULED: "+orgActiveDate(*issue.DeferUntil))
+	}
+	if len(planning) > 0 {
+		b.WriteString(strings.Join(planning, " "))
+		b.WriteString("\n")
+	}
+
+	props := [][2]string{
+		{"ID", issue.ID},
+		{"STATUS", string(issue.Status)},
+		{"TYPE", string(issue.IssueType)},
+	}
+	if issue.Assignee != "" {
+		props = append(props, [2]string{"ASSIGNEE", issue.Assignee})
+	}
+	if issue.Owner != "" {
+		props = append(props, [2]string{"OWNER", issue.Owner})
+	}
+	if issue.ExternalRef != nil && *issue.ExternalRef != "" {
+		props = append(props, [2]string{"EXTERNAL_REF", *issue.ExternalRef})
+	}
+	if !issue.CreatedAt.IsZero() {
+		props = append(props, [2]string{"CREATED", orgInactiveTime(issue.CreatedAt)})
+	}
+	if issue.CloseReason != "" {
+		props = append(props, [2]string{"CLOSE_REASON", strings.ReplaceAll(issue.CloseReason, "\n", " ")})
+	}
+	b.WriteString(":PROPERTIES:\n")
+	for _, p := range props {
+		fmt.Fprintf(&b, ":%s: %s\n", p[0], p[1])
+	}
+	b.WriteString(":END:\n")
+
+	if strings.TrimSpace(issue.Description) != "" {
+		orgText(&b, issue.Description)
+	}
+
+	section := strings.Repeat("*", level+1)
+	for _, s := range []struct{ heading, body string }{
+		{"Design", issue.Design},
+		{"Acceptance Criteria", issue.AcceptanceCriteria},
+		{"Notes", issue.Notes},
+	} {
+		if strings.TrimSpace(s.body) == "" {
+			continue
+		}
+		fmt.Fprintf(&b, "%s %s\n", section, s.heading)
+		orgText(&b, s.body)
+	}
+
+	var deps []*types.Dependency
+	for _, dep := range issue.Dependencies {
+		if dep.Type != types.DepParentChild {
+			deps = append(deps, dep)
+		}
+	}
+	if len(deps) > 0 {
+		fmt.Fprintf(&b, "%s Dependencies\n", section)
+		for _, dep := range deps {
+			fmt.Fprintf(&b, "- %s [[id:%s][%s]]\n", dep.Type, dep.DependsOnID, dep.DependsOnID)
+		}
+	}
+
+	if len(issue.Comments) > 0 {
+		fmt.Fprintf(&b, "%s Comments\n", section)
+		for _, c := range issue.Comments {
+			fmt.Fprintf(&b, "- %s %s ::\n", c.Author, orgInactiveTime(c.CreatedAt))
+			for _, line := range strings.Split(strings.TrimRight(c.Text, "\n"), "\n") {
+				fmt.Fprintf(&b, "  %s\n", line)
+			}
+		}
+	}
+	return b.String()
+}
+
+// writeOrgExport writes issues as an org-mode outline. Children (via
+// parent-child dependencies) are nested under their parent when the parent
+// is part of the export; everything else is a top-level heading.
+func writeOrgExport(w io.Writer, issues []*types.Issue) (int, error) {
+	byID := make(map[string]*types.Issue, len(issues))
+	for _, issue := range issues {
+		byID[issue.ID] = issue
+	}
+	children := make(map[string][]*types.Issue)
+	hasParent := make(map[string]bool)
+	for _, issue := range issues {
+		for _, dep := range issue.Dependencies {
+			if dep.Type != types.DepParentChild || byID[dep.DependsOnID] == nil || hasParent[issue.ID] {
+				continue
+			}
+			children[dep.DependsOnID] = append(children[dep.DependsOnID], issue)
+			hasParent[issue.ID] = true
+		}
+	}
+	for _, kids := range children {
+		sort.SliceStable(kids, func(i, j int) bool { return kids[i].ID < kids[j].ID })
+	}
+
+	var b strings.Builder
+	b.WriteString(orgHeader)
+	written := make(map[string]bool, len(issues))
+	var emit func(issue *types.Issue, level int)
+	emit = func(issue *types.Issue, level int) {
+		if written[issue.ID] {
+			return
+		}
+		written[issue.ID] = true
+		b.WriteString("\n")
+		b.WriteString(formatOrgIssue(issue, level))
+		for _, child := range children[issue.ID] {
+			emit(child, level+1)
+		}
+	}
+	for _, issue := range issues {
+		if !hasParent[issue.ID] {
+			emit(issue, 1)
+		}
+	}
+	// Issues whose parent chain loops back on itself have no root; emit
+	// them at the top level rather than dropping them.
+	for _, issue := range issues {
+		emit(issue, 1)
+	}
+
+	if _, err := io.WriteString(w, b.String()); err != nil {
+		return 0, fmt.Errorf("failed to write org: %w", err)
+	}
+	return len(issues), nil
+}
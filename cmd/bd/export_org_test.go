@@ -0,0 +1,60 @@
+package main
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestWriteOrgExport(t *testing.T) {
+	closed := time.Date(2026, 4, 5, 16, 30, 0, 0, time.Local)
+	due := time.Date(2026, 4, 10, 0, 0, 0, 0, time.Local)
+	issues := []*types.Issue{
+		{
+			ID:        "bd-1",
+			Title:     "Auth epic",
+			Status:    types.StatusInProgress,
+			Priority:  1,
+			IssueType: types.TypeEpic,
+			Labels:    []string{"auth", "q2-goals"},
+			DueAt:     &due,
+		},
+		{
+			ID:           "bd-2",
+			Title:        "Fix login",
+			Status:       types.StatusClosed,
+			Priority:     0,
+			IssueType:    types.TypeBug,
+			ClosedAt:     &closed,
+			Description:  "* not a heading",
+			Dependencies: []*types.Dependency{{IssueID: "bd-2", DependsOnID: "bd-1", Type: types.DepParentChild}, {IssueID: "bd-2", DependsOnID: "bd-9", Type: types.DepBlocks}},
+		},
+	}
+
+	var buf bytes.Buffer
+	count, err := writeOrgExport(&buf, issues)
+	if err != nil {
+		t.Fatalf("writeOrgExport: %v", err)
+	}
+	if count != 2 {
+		t.Fatalf("count = %d, want 2", count)
+	}
+	out := buf.String()
+	for _, want := range []string{
+		"#+TODO: TODO STARTED WAITING HOLD | DONE\n",
+		"\n* STARTED [#B] Auth epic :auth:q2_goals:\nDEADLINE: " + due.Format("<2006-01-02 Mon>") + "\n:PROPERTIES:\n:ID: bd-1\n",
+		"\n** DONE [#A] Fix login\nCLOSED: " + closed.Format("[2006-01-02 Mon 15:04]") + "\n",
+		"\n * not a heading\n",
+		"*** Dependencies\n- blocks [[id:bd-9][bd-9]]\n",
+	} {
+		if !strings.Contains(out, want) {
+			t.Errorf("missing %q in:\n%s", want, out)
+		}
+	}
+	if strings.Contains(out, "parent-child") {
+		t.Errorf("parent-child links should be expressed by nesting:\n%s", out)
+	}
+}
@@ -0,0 +1,80 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"slices"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// exportSinceKey is the local metadata key holding the Dolt commit of the
+// last unfiltered JSONL export, which 'bd export --since last' diffs from.
+const exportSinceKey = "export.last_commit"
+
+var exportSince string
+
+func init() {
+	exportCmd.Flags().StringVar(&exportSince, "since", "", "Only export issues changed since a time, a Dolt commit or branch, or 'last' (the previous export)")
+}
+
+// exportDelta is what --since narrowed the export to.
+type exportDelta struct {
+	// none is set when nothing changed, so the export writes no issues.
+	none bool
+	// deleted lists issues removed since the commit; time-based --since
+	// cannot see deletions.
+	deleted []string
+}
+
+// applyExportSince narrows filter to the issues changed since exportSince.
+// A time compares against updated_at. Anything else is a Dolt ref ('last'
+// being the commit recorded by the previous export), and the changed
+// issues are those the commit history shows added or modified since then.
+func applyExportSince(ctx context.Context, filter *types.IssueFilter) (*exportDelta, error) {
+	delta := &exportDelta{}
+	ref := exportSince
+	if ref == "last" {
+		last, err := store.GetLocalMetadata(ctx, exportSinceKey)
+		if err != nil {
+			return nil, fmt.Errorf("reading last export commit: %w", err)
+		}
+		if last == "" {
+			return nil, fmt.Errorf("--since last: no previous export recorded in this clone; pass a time or commit instead")
+		}
+		ref = last
+	} else if t, err := parseDateFilterFlag(ref); err == nil {
+		filter.UpdatedAfter = &t
+		return delta, nil
+	}
+
+	entries, err := store.Diff(ctx, ref, "WORKING")
+	if err != nil {
+		return nil, fmt.Errorf("--since %s: %w", exportSince, err)
+	}
+	var changed []string
+	for _, e := range entries {
+		if e.DiffType == "removed" {
+			delta.deleted = append(delta.deleted, e.IssueID)
+		} else {
+			changed = append(changed, e.IssueID)
+		}
+	}
+	slices.Sort(delta.deleted)
+	if len(filter.IDs) > 0 {
+		changed = slices.DeleteFunc(changed, func(id string) bool { return !slices.Contains(filter.IDs, id) })
+	}
+	filter.IDs = changed
+	delta.none = len(changed) == 0
+	return delta, nil
+}
+
+// recordExportCommit remembers the current commit as the point the next
+// 'bd export --since last' starts from.
+func recordExportCommit(ctx context.Context) error {
+	head, err := store.GetCurrentCommit(ctx)
+	if err != nil {
+		return err
+	}
+	return store.SetLocalMetadata(ctx, exportSinceKey, head)
+}
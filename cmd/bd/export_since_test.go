@@ -0,0 +1,127 @@
+package main
+
+import (
+	"bytes"
+	"context"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestJSONLDeletionRecordsAreSkippedOnImport(t *testing.T) {
+	var buf bytes.Buffer
+	if err := writeJSONLDeletions(&buf, tombstonesFor([]string{"bd-1", "bd-2"}, nil)); err != nil {
+		t.Fatalf("writeJSONLDeletions: %v", err)
+	}
+	want := `{"_format":1,"_type":"deletion","id":"bd-1"}` + "\n" + `{"_format":1,"_type":"deletion","id":"bd-2"}` + "\n"
+	if buf.String() != want {
+		t.Errorf("deletion records = %q, want %q", buf.String(), want)
+	}
+
+	c := &importCollector{}
+	if err := parseJSONLImport(&buf, c); err != nil {
+		t.Fatalf("parseJSONLImport: %v", err)
+	}
+	if len(c.issues) != 0 || len(c.lineErrors) != 0 || len(c.tombstones) != 0 {
+		t.Errorf("deletion records without deleted_at imported as issues=%d tombstones=%d errors=%+v", len(c.issues), len(c.tombstones), c.lineErrors)
+	}
+}
+
+func TestJSONLTombstonesRoundTrip(t *testing.T) {
+	deleted := time.Date(2026, 3, 4, 5, 6, 7, 0, time.UTC)
+	var buf bytes.Buffer
+	if err := writeJSONLDeletions(&buf, []tombstone{{ID: "bd-1", DeletedAt: deleted, Actor: "alice"}}); err != nil {
+		t.Fatalf("writeJSONLDeletions: %v", err)
+	}
+	buf.WriteString(`{"id":"bd-1","title":"stale copy","updated_at":"2026-03-01T00:00:00Z"}` + "\n")
+
+	c := &importCollector{}
+	if err := parseJSONLImport(&buf, c); err != nil {
+		t.Fatalf("parseJSONLImport: %v", err)
+	}
+	if len(c.tombstones) != 1 {
+		t.Fatalf("tombstones = %+v, want one", c.tombstones)
+	}
+	got := c.tombstones[0]
+	if got.ID != "bd-1" || !got.DeletedAt.Equal(deleted) || got.Actor != "alice" {
+		t.Errorf("tombstone = %+v", got)
+	}
+	if len(c.issues) != 1 || !got.buries(c.issues[0]) {
+		t.Errorf("tombstone should bury the stale copy: issues = %+v", c.issues)
+	}
+	if got.buries(&types.Issue{ID: "bd-1", UpdatedAt: deleted.Add(time.Minute)}) {
+		t.Error("tombstone buries an issue updated after the deletion")
+	}
+}
+
+func TestTombstonesInConfig(t *testing.T) {
+	ctx := context.Background()
+	st := &configMapStore{config: map[string]string{"issue_prefix": "bd"}}
+	if err := recordTombstones(ctx, st, []string{"bd-2", "bd-1"}, "bob"); err != nil {
+		t.Fatal(err)
+	}
+	got := tombstonesIn(st.config)
+	if len(got) != 2 || got[0].ID != "bd-1" || got[1].ID != "bd-2" || got[0].Actor != "bob" || got[0].DeletedAt.IsZero() {
+		t.Errorf("tombstonesIn = %+v", got)
+	}
+}
+
+func TestPruneTombstones(t *testing.T) {
+	ctx := context.Background()
+	now := time.Now().UTC()
+	st := &configMapStore{config: map[string]string{"issue_prefix": "bd"}}
+	for id, age := range map[string]int{"bd-1": 400, "bd-2": 10, "bd-3": 200} {
+		if err := setTombstone(ctx, st, tombstone{ID: id, DeletedAt: now.AddDate(0, 0, -age)}); err != nil {
+			t.Fatal(err)
+		}
+	}
+	cutoff := now.AddDate(0, 0, -180)
+
+	got, err := pruneTombstones(ctx, st, cutoff, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(got) != 2 || got[0] != "bd-1" || got[1] != "bd-3" {
+		t.Errorf("dry run = %v, want [bd-1 bd-3]", got)
+	}
+	if len(tombstonesIn(st.config)) != 3 {
+		t.Errorf("dry run pruned tombstones")
+	}
+
+	if _, err := pruneTombstones(ctx, st, cutoff, false); err != nil {
+		t.Fatal(err)
+	}
+	left := tombstonesIn(st.config)
+	if len(left) != 1 || left[0].ID != "bd-2" {
+		t.Errorf("tombstones left = %+v, want bd-2", left)
+	}
+	if st.config["issue_prefix"] != "bd" {
+		t.Errorf("pruning touched other config: %v", st.config)
+	}
+}
+
+// configMapStore keeps config in a map; any other store method panics.
+type configMapStore struct {
+	storage.DoltStorage
+	config map[string]string
+}
+
+func (s *configMapStore) SetConfig(_ context.Context, key, value string) error {
+	s.config[key] = value
+	return nil
+}
+
+func (s *configMapStore) GetAllConfig(_ context.Context) (map[string]string, error) {
+	out := make(map[string]string, len(s.config))
+	for k, v := range s.config {
+		out[k] = v
+	}
+	return out, nil
+}
+
+func (s *configMapStore) DeleteConfig(_ context.Context, key string) error {
+	delete(s.config, key)
+	return nil
+}
@@ -10,11 +10,12 @@ import (
 )
 
 var (
-	gcDryRun    bool
-	gcForce     bool
-	gcOlderThan int
-	gcSkipDecay bool
-	gcSkipDolt  bool
+	gcDryRun        bool
+	gcForce         bool
+	gcOlderThan     int
+	gcTombstoneDays int
+	gcSkipDecay     bool
+	gcSkipDolt      bool
 )
 
 var gcCmd = &cobra.Command{
@@ -24,7 +25,9 @@ var gcCmd = &cobra.Command{
 	Long: `Full lifecycle garbage collection for standalone Beads databases.
 
 Runs three phases in sequence:
-  1. DECAY   — Delete closed issues older than N days (default 90)
+  1. DECAY   — Delete closed issues older than N days (default 90), and
+               forget deletion records older than --tombstones-older-than
+               days (default 365)
   2. COMPACT — Squash old Dolt commits into fewer commits (bd compact)
   3. GC      — Run Dolt garbage collection to reclaim disk space
 
@@ -48,6 +51,9 @@ Examples:
 		if gcOlderThan < 0 {
 			FatalError("--older-than must be non-negative")
 		}
+		if gcTombstoneDays < 0 {
+			FatalError("--tombstones-older-than must be non-negative")
+		}
 
 		// Phase tracking for summary
 		type phaseResult struct {
@@ -102,14 +108,18 @@ Examples:
 							"Use --force to confirm or --dry-run to preview.")
 					}
 
-					deleted := 0
+					var deletedIDs []string
 					for _, issue := range closedIssues {
 						if err := store.DeleteIssue(ctx, issue.ID); err != nil {
 							WarnError("failed to delete %s: %v", issue.ID, err)
 						} else {
-							deleted++
+							deletedIDs = append(deletedIDs, issue.ID)
 						}
 					}
+					if err := recordTombstones(ctx, store, deletedIDs, getActor()); err != nil {
+						WarnError("could not record deletions for sync: %v", err)
+					}
+					deleted := len(deletedIDs)
 					commandDidWrite.Store(true)
 					detail := fmt.Sprintf("  Deleted %d issue(s)", deleted)
 					if !jsonOutput {
@@ -122,6 +132,25 @@ Examples:
 					}
 				}
 			}
+
+			// Deletion records only need to outlive the JSONL copies that
+			// still carry the issues they bury.
+			tombstoneCutoff := time.Now().UTC().AddDate(0, 0, -gcTombstoneDays)
+			pruned, err := pruneTombstones(ctx, store, tombstoneCutoff, gcDryRun)
+			if err != nil {
+				WarnError("could not prune deletion records: %v", err)
+			} else if len(pruned) > 0 {
+				if gcDryRun {
+					if !jsonOutput {
+						fmt.Printf("  Would forget %d deletion record(s) older than %d days\n", len(pruned), gcTombstoneDays)
+					}
+				} else {
+					commandDidWrite.Store(true)
+					if !jsonOutput {
+						fmt.Printf("  Forgot %d deletion record(s) older than %d days\n", len(pruned), gcTombstoneDays)
+					}
+				}
+			}
 			if !jsonOutput {
 				fmt.Println()
 			}
@@ -237,6 +266,7 @@ func init() {
 	gcCmd.Flags().BoolVar(&gcDryRun, "dry-run", false, "Preview without making changes")
 	gcCmd.Flags().BoolVarP(&gcForce, "force", "f", false, "Skip confirmation prompts")
 	gcCmd.Flags().IntVar(&gcOlderThan, "older-than", 90, "Delete closed issues older than N days")
+	gcCmd.Flags().IntVar(&gcTombstoneDays, "tombstones-older-than", 365, "Forget deletion records older than N days")
 	gcCmd.Flags().BoolVar(&gcSkipDecay, "skip-decay", false, "Skip issue deletion phase")
 	gcCmd.Flags().BoolVar(&gcSkipDolt, "skip-dolt", false, "Skip Dolt garbage collection phase")
 
@@ -244,6 +244,8 @@ func githubConfigToEnvVar(key string) string {
 		return "GITHUB_REPOSITORY"
 	case "github.url":
 		return "GITHUB_API_URL"
+	case "github.project":
+		return "GITHUB_PROJECT"
 	default:
 		return ""
 	}
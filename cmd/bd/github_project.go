@@ -0,0 +1,367 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/github"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// githubProjectItemsKey is the config key holding, per board, which item
+// each exported issue became, so a re-export updates items instead of
+// adding duplicates: {"<project node id>": {"bd-1": "<item id>"}}.
+const githubProjectItemsKey = "github.project_items"
+
+// githubProjectCmd exports issues to a GitHub Projects (v2) board.
+var githubProjectCmd = &cobra.Command{
+	Use:   "project [number]",
+	Short: "Export issues to a GitHub Projects board",
+	Long: `Export issues to a GitHub Projects (v2) board, setting each item's Status
+and Priority fields from the issue.
+
+Issues linked to an issue in the configured repository (external_ref from
+'bd github sync') are added to the board as that GitHub issue. Other issues
+are added as draft issues carrying the title and description. Re-running the
+export updates the items it added earlier rather than adding them again.
+
+Open issues are exported; closed issues are exported only if they are
+already on the board (so they move to Done) or with --include-closed.
+
+Status options are matched by the beads status name, then by the column
+of GitHub's default template it belongs in (Todo, In Progress, Done).
+Priority options are matched by name (P0, P1, ...). Boards with other
+option names can map them explicitly:
+
+  github.project_status_map   - e.g. "blocked=Blocked,deferred=Backlog"
+  github.project_priority_map - e.g. "0=Urgent,1=High,2=Medium"
+
+Configuration:
+  github.project / GITHUB_PROJECT - Board number (from the board's URL)
+  github.project_owner            - User or organization owning the board (default: github.owner)
+  github.project_status_field     - Status field name (default: Status)
+  github.project_priority_field   - Priority field name (default: Priority)
+
+The token needs the 'project' scope.`,
+	Example: `  bd github project 3
+  bd github project --label frontend --dry-run
+  bd github project 3 --owner my-org --include-closed`,
+	Args: cobra.MaximumNArgs(1),
+	RunE: runGitHubProject,
+}
+
+var (
+	githubProjectDryRun        bool
+	githubProjectOwner         string
+	githubProjectLabels        []string
+	githubProjectIncludeClosed bool
+)
+
+func init() {
+	githubProjectCmd.Flags().BoolVar(&githubProjectDryRun, "dry-run", false, "Show what would be exported without changing the board")
+	githubProjectCmd.Flags().StringVar(&githubProjectOwner, "owner", "", "User or organization owning the board (default: github.project_owner, then github.owner)")
+	githubProjectCmd.Flags().StringSliceVarP(&githubProjectLabels, "label", "l", nil, "Only export issues with all of these labels")
+	githubProjectCmd.Flags().BoolVar(&githubProjectIncludeClosed, "include-closed", false, "Also export closed issues that are not on the board yet")
+	githubCmd.AddCommand(githubProjectCmd)
+}
+
+// githubProjectResult is the JSON output of 'bd github project'.
+type githubProjectResult struct {
+	Project string                    `json:"project"`
+	URL     string                    `json:"url"`
+	DryRun  bool                      `json:"dry_run,omitempty"`
+	Added   int                       `json:"added"`
+	Updated int                       `json:"updated"`
+	Items   []githubProjectResultItem `json:"items"`
+}
+
+type githubProjectResultItem struct {
+	ID       string `json:"id"`
+	Action   string `json:"action"` // "add" or "update"
+	Draft    bool   `json:"draft,omitempty"`
+	Status   string `json:"status,omitempty"`
+	Priority string `json:"priority,omitempty"`
+}
+
+func runGitHubProject(cmd *cobra.Command, args []string) error {
+	ctx := context.Background()
+	cfg := getGitHubConfig()
+	if cfg.Token == "" {
+		return fmt.Errorf("github.token is not configured. Set via 'bd config set github.token <token>' or GITHUB_TOKEN environment variable")
+	}
+	numberStr := getGitHubConfigValue(ctx, "github.project")
+	if len(args) > 0 {
+		numberStr = args[0]
+	}
+	if numberStr == "" {
+		return fmt.Errorf("no project number: pass one or set github.project")
+	}
+	number, err := strconv.Atoi(strings.TrimPrefix(numberStr, "#"))
+	if err != nil || number <= 0 {
+		return fmt.Errorf("invalid project number %q", numberStr)
+	}
+	owner := githubProjectOwner
+	if owner == "" {
+		owner = getGitHubConfigValue(ctx, "github.project_owner")
+	}
+	if owner == "" {
+		owner = cfg.Owner
+	}
+	if owner == "" {
+		return fmt.Errorf("no project owner: pass --owner or set github.project_owner or github.owner")
+	}
+	if !githubProjectDryRun {
+		CheckReadonly("github project")
+	}
+	if err := ensureStoreActive(); err != nil {
+		return fmt.Errorf("database not available: %w", err)
+	}
+
+	statusMap, err := parseGitHubProjectMap(getGitHubConfigValue(ctx, "github.project_status_map"))
+	if err != nil {
+		return fmt.Errorf("github.project_status_map: %w", err)
+	}
+	priorityMap, err := parseGitHubProjectMap(getGitHubConfigValue(ctx, "github.project_priority_map"))
+	if err != nil {
+		return fmt.Errorf("github.project_priority_map: %w", err)
+	}
+	statusFieldName := getGitHubConfigValue(ctx, "github.project_status_field")
+	if statusFieldName == "" {
+		statusFieldName = "Status"
+	}
+	priorityFieldName := getGitHubConfigValue(ctx, "github.project_priority_field")
+	if priorityFieldName == "" {
+		priorityFieldName = "Priority"
+	}
+
+	client := getGitHubClient(cfg)
+	project, err := client.GetProjectV2(ctx, owner, number)
+	if err != nil {
+		return err
+	}
+	statusField, priorityField := project.Field(statusFieldName), project.Field(priorityFieldName)
+	if statusField == nil {
+		fmt.Fprintf(os.Stderr, "Warning: board %q has no single-select %q field; statuses will not be set\n", project.Title, statusFieldName)
+	}
+	if priorityField == nil {
+		fmt.Fprintf(os.Stderr, "Warning: board %q has no single-select %q field; priorities will not be set\n", project.Title, priorityFieldName)
+	}
+
+	boardItems, err := client.ListProjectV2Items(ctx, project.ID)
+	if err != nil {
+		return err
+	}
+	onBoard := make(map[string]github.ProjectV2Item, len(boardItems))
+	for _, item := range boardItems {
+		onBoard[item.ID] = item
+	}
+	allMapped, err := loadGitHubProjectItems(ctx)
+	if err != nil {
+		return err
+	}
+	mapped := allMapped[project.ID]
+	if mapped == nil {
+		mapped = map[string]string{}
+		allMapped[project.ID] = mapped
+	}
+
+	notTemplate, notEphemeral := false, false
+	issues, err := store.SearchIssues(ctx, "", types.IssueFilter{
+		Labels:     githubProjectLabels,
+		IsTemplate: &notTemplate,
+		Ephemeral:  &notEphemeral,
+	})
+	if err != nil {
+		return fmt.Errorf("failed to search issues: %w", err)
+	}
+
+	out := cmd.OutOrStdout()
+	result := &githubProjectResult{Project: project.Title, URL: project.URL, DryRun: githubProjectDryRun, Items: []githubProjectResultItem{}}
+	warnedStatus, warnedPriority := map[types.Status]bool{}, map[int]bool{}
+	for _, issue := range issues {
+		itemID := mapped[issue.ID]
+		if _, ok := onBoard[itemID]; !ok {
+			itemID = ""
+		}
+		if issue.Status == types.StatusClosed && itemID == "" && !githubProjectIncludeClosed {
+			continue
+		}
+
+		entry := githubProjectResultItem{ID: issue.ID, Action: "update"}
+		if itemID == "" {
+			entry.Action = "add"
+		}
+		issueNumber, linked := githubIssueNumberFor(issue, cfg)
+		entry.Draft = !linked
+
+		var statusOpt, priorityOpt github.ProjectV2Option
+		if statusField != nil {
+			names := append([]string{statusMap[string(issue.Status)]}, github.ProjectStatusOptionNames(issue.Status)...)
+			opt, ok := statusField.Option(names...)
+			if ok {
+				statusOpt, entry.Status = opt, opt.Name
+			} else if !warnedStatus[issue.Status] {
+				fmt.Fprintf(os.Stderr, "Warning: no %q option for status %s; set github.project_status_map\n", statusField.Name, issue.Status)
+				warnedStatus[issue.Status] = true
+			}
+		}
+		if priorityField != nil {
+			names := append([]string{priorityMap[strconv.Itoa(issue.Priority)]}, github.ProjectPriorityOptionNames(issue.Priority)...)
+			opt, ok := priorityField.Option(names...)
+			if ok {
+				priorityOpt, entry.Priority = opt, opt.Name
+			} else if !warnedPriority[issue.Priority] {
+				fmt.Fprintf(os.Stderr, "Warning: no %q option for priority P%d; set github.project_priority_map\n", priorityField.Name, issue.Priority)
+				warnedPriority[issue.Priority] = true
+			}
+		}
+
+		if !githubProjectDryRun {
+			body := githubProjectDraftBody(issue)
+			switch {
+			case itemID == "" && linked:
+				nodeID, err := client.IssueNodeID(ctx, issueNumber)
+				if err != nil {
+					return fmt.Errorf("%s: %w", issue.ID, err)
+				}
+				if itemID, err = client.AddProjectV2Item(ctx, project.ID, nodeID); err != nil {
+					return fmt.Errorf("%s: %w", issue.ID, err)
+				}
+			case itemID == "":
+				if itemID, err = client.AddProjectV2DraftIssue(ctx, project.ID, issue.Title, body); err != nil {
+					return fmt.Errorf("%s: %w", issue.ID, err)
+				}
+			case onBoard[itemID].ContentType == "DraftIssue":
+				if err := client.UpdateProjectV2DraftIssue(ctx, onBoard[itemID].ContentID, issue.Title, body); err != nil {
+					return fmt.Errorf("%s: %w", issue.ID, err)
+				}
+			}
+			mapped[issue.ID] = itemID
+			// Save after every new item, so an interrupted export does not
+			// add it again next time.
+			if entry.Action == "add" {
+				if err := saveGitHubProjectItems(ctx, allMapped); err != nil {
+					return err
+				}
+			}
+			if statusOpt.ID != "" {
+				if err := client.SetProjectV2SingleSelect(ctx, project.ID, itemID, statusField.ID, statusOpt.ID); err != nil {
+					return fmt.Errorf("%s: %w", issue.ID, err)
+				}
+			}
+			if priorityOpt.ID != "" {
+				if err := client.SetProjectV2SingleSelect(ctx, project.ID, itemID, priorityField.ID, priorityOpt.ID); err != nil {
+					return fmt.Errorf("%s: %w", issue.ID, err)
+				}
+			}
+		}
+
+		if entry.Action == "add" {
+			result.Added++
+		} else {
+			result.Updated++
+		}
+		result.Items = append(result.Items, entry)
+	}
+
+	if jsonOutput {
+		outputJSON(result)
+		return nil
+	}
+	if githubProjectDryRun {
+		_, _ = fmt.Fprintln(out, "Dry run mode - no changes will be made")
+		_, _ = fmt.Fprintln(out)
+		for _, item := range result.Items {
+			kind := "issue"
+			if item.Draft {
+				kind = "draft"
+			}
+			mark := "~"
+			if item.Action == "add" {
+				mark = "+"
+			}
+			_, _ = fmt.Fprintf(out, "  %s %s (%s) %s / %s\n", mark, item.ID, kind, item.Status, item.Priority)
+		}
+		_, _ = fmt.Fprintln(out)
+	}
+	verb := "Exported"
+	if githubProjectDryRun {
+		verb = "Would export"
+	}
+	_, _ = fmt.Fprintf(out, "✓ %s %d issues to %q (%d added, %d updated)\n", verb, len(result.Items), project.Title, result.Added, result.Updated)
+	if project.URL != "" {
+		_, _ = fmt.Fprintf(out, "  %s\n", project.URL)
+	}
+	return nil
+}
+
+// githubIssueNumberFor returns the number of the GitHub issue in the
+// configured repository that issue is linked to, if any.
+func githubIssueNumberFor(issue *types.Issue, cfg GitHubConfig) (int, bool) {
+	if issue.ExternalRef == nil || cfg.Owner == "" || cfg.Repo == "" {
+		return 0, false
+	}
+	ref := *issue.ExternalRef
+	gt := &github.Tracker{}
+	if !gt.IsExternalRef(ref) {
+		return 0, false
+	}
+	if strings.Contains(ref, "/") && !strings.Contains(strings.ToLower(ref), strings.ToLower("/"+cfg.Owner+"/"+cfg.Repo+"/")) {
+		return 0, false
+	}
+	number, err := strconv.Atoi(gt.ExtractIdentifier(ref))
+	return number, err == nil
+}
+
+// githubProjectDraftBody is the body of the draft issue exported for issue.
+func githubProjectDraftBody(issue *types.Issue) string {
+	footer := "_Exported from beads issue " + issue.ID + "_"
+	if issue.Description == "" {
+		return footer
+	}
+	return issue.Description + "\n\n" + footer
+}
+
+// parseGitHubProjectMap parses "key=Option,key=Option" mapping config.
+func parseGitHubProjectMap(s string) (map[string]string, error) {
+	m := map[string]string{}
+	for _, pair := range strings.Split(s, ",") {
+		if strings.TrimSpace(pair) == "" {
+			continue
+		}
+		k, v, ok := strings.Cut(pair, "=")
+		if !ok || strings.TrimSpace(k) == "" || strings.TrimSpace(v) == "" {
+			return nil, fmt.Errorf("invalid entry %q (want key=Option)", strings.TrimSpace(pair))
+		}
+		m[strings.ToLower(strings.TrimSpace(k))] = strings.TrimSpace(v)
+	}
+	return m, nil
+}
+
+func loadGitHubProjectItems(ctx context.Context) (map[string]map[string]string, error) {
+	items := map[string]map[string]string{}
+	raw, err := store.GetConfig(ctx, githubProjectItemsKey)
+	if err != nil || raw == "" {
+		return items, nil
+	}
+	if err := json.Unmarshal([]byte(raw), &items); err != nil {
+		return nil, fmt.Errorf("invalid %s config: %w", githubProjectItemsKey, err)
+	}
+	return items, nil
+}
+
+func saveGitHubProjectItems(ctx context.Context, items map[string]map[string]string) error {
+	data, err := json.Marshal(items)
+	if err != nil {
+		return err
+	}
+	if err := store.SetConfig(ctx, githubProjectItemsKey, string(data)); err != nil {
+		return fmt.Errorf("failed to record project items: %w", err)
+	}
+	return nil
+}
@@ -0,0 +1,45 @@
+package main
+
+import (
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseGitHubProjectMap(t *testing.T) {
+	t.Parallel()
+	m, err := parseGitHubProjectMap(" Blocked=Waiting , deferred=Backlog,")
+	if err != nil {
+		t.Fatalf("parseGitHubProjectMap: %v", err)
+	}
+	if m["blocked"] != "Waiting" || m["deferred"] != "Backlog" || len(m) != 2 {
+		t.Errorf("map = %v", m)
+	}
+	if _, err := parseGitHubProjectMap("blocked"); err == nil {
+		t.Error("expected an error for an entry without '='")
+	}
+}
+
+func TestGitHubIssueNumberFor(t *testing.T) {
+	t.Parallel()
+	cfg := GitHubConfig{Owner: "acme", Repo: "app"}
+	ref := func(s string) *types.Issue { return &types.Issue{ID: "bd-1", ExternalRef: &s} }
+
+	tests := []struct {
+		issue  *types.Issue
+		number int
+		linked bool
+	}{
+		{ref("https://github.com/acme/app/issues/42"), 42, true},
+		{ref("github:7"), 7, true},
+		{ref("https://github.com/other/app/issues/42"), 0, false},
+		{ref("gitlab:12"), 0, false},
+		{&types.Issue{ID: "bd-2"}, 0, false},
+	}
+	for _, tt := range tests {
+		number, linked := githubIssueNumberFor(tt.issue, cfg)
+		if number != tt.number || linked != tt.linked {
+			t.Errorf("githubIssueNumberFor(%v) = %d, %v; want %d, %v", tt.issue.ExternalRef, number, linked, tt.number, tt.linked)
+		}
+	}
+}
@@ -36,6 +36,9 @@ var (
 	graphAll     bool
 	graphDOT     bool
 	graphHTML    bool
+	graphMermaid bool
+	graphFormat  string
+	graphRoot    string
 )
 
 var graphCmd = &cobra.Command{
@@ -56,6 +59,13 @@ Display formats:
   --dot            Graphviz DOT format (pipe to dot -Tsvg > graph.svg)
   --html           Self-contained interactive HTML with D3.js visualization
 
+--format dot|mermaid|html|box|compact selects the same formats by name.
+Mermaid output is a flowchart to paste into a mermaid code block in
+GitHub, GitLab, or wiki docs; with --all every component goes into one
+diagram. In DOT and Mermaid, nodes are colored by status, blocking edges are
+solid, and parent-child edges are dashed. --root ID is the same as giving
+the issue ID as an argument.
+
 The graph shows execution order:
 - Layer 0 / leftmost = no dependencies (can start immediately)
 - Higher layers depend on lower layers
@@ -68,12 +78,39 @@ Examples:
   bd graph --box issue-id        # ASCII boxes with layer grouping
   bd graph --dot issue-id | dot -Tsvg > graph.svg  # SVG via Graphviz
   bd graph --dot issue-id | dot -Tpng > graph.png  # PNG via Graphviz
+  bd graph --format mermaid --root issue-id  # Mermaid flowchart for docs
   bd graph --html issue-id > graph.html  # Interactive browser view
   bd graph --all --html > all.html       # All issues, interactive`,
 	Args: cobra.RangeArgs(0, 1),
 	Run: func(cmd *cobra.Command, args []string) {
 		ctx := rootCtx
 
+		// The local --format flag shadows the hidden persistent --format on
+		// rootCmd; "json" keeps meaning structured output.
+		switch strings.ToLower(graphFormat) {
+		case "":
+		case "json":
+			jsonOutput = true
+		case "dot":
+			graphDOT = true
+		case "mermaid":
+			graphMermaid = true
+		case "html":
+			graphHTML = true
+		case "box":
+			graphBox = true
+		case "compact":
+			graphCompact = true
+		default:
+			FatalError("unsupported --format %q (use dot, mermaid, html, box, or compact)", graphFormat)
+		}
+		if graphRoot != "" {
+			if len(args) > 0 {
+				FatalError("use either --root or an issue ID argument, not both")
+			}
+			args = []string{graphRoot}
+		}
+
 		// Validate args
 		if graphAll && len(args) > 0 {
 			FatalError("cannot specify issue ID with --all flag")
@@ -103,11 +140,16 @@ Examples:
 				return
 			}
 
-			// HTML: merge all components into one graph for a single document
-			if graphHTML {
+			// HTML and Mermaid: merge all components into one graph for a
+			// single document
+			if graphHTML || graphMermaid {
 				merged := mergeSubgraphsForHTML(subgraphs)
 				layout := computeLayout(merged)
-				renderGraphHTML(layout, merged)
+				if graphMermaid {
+					renderGraphMermaid(layout, merged)
+				} else {
+					renderGraphHTML(layout, merged)
+				}
 				return
 			}
 
@@ -157,6 +199,8 @@ Examples:
 		// Render graph in selected format
 		if graphDOT {
 			renderGraphDOT(layout, subgraph)
+		} else if graphMermaid {
+			renderGraphMermaid(layout, subgraph)
 		} else if graphHTML {
 			renderGraphHTML(layout, subgraph)
 		} else if graphCompact {
@@ -246,6 +290,8 @@ func init() {
 	graphCmd.Flags().BoolVar(&graphBox, "box", false, "ASCII boxes showing layers")
 	graphCmd.Flags().BoolVar(&graphDOT, "dot", false, "Output Graphviz DOT format (pipe to: dot -Tsvg > graph.svg)")
 	graphCmd.Flags().BoolVar(&graphHTML, "html", false, "Output self-contained interactive HTML (redirect to file)")
+	graphCmd.Flags().StringVar(&graphFormat, "format", "", "Output format: dot, mermaid, html, box, or compact")
+	graphCmd.Flags().StringVar(&graphRoot, "root", "", "Issue to graph (same as the issue-id argument)")
 	graphCmd.ValidArgsFunction = issueIDCompletion
 	rootCmd.AddCommand(graphCmd)
 	graphCmd.AddCommand(graphCheckCmd)
@@ -311,13 +357,13 @@ func loadGraphSubgraph(ctx context.Context, s storage.DoltStorage, issueID strin
 		}
 	}
 
-	// Load all dependencies within the subgraph
+	// Load all dependencies within the subgraph in one batched query
+	subgraphDeps, err := s.GetDependencyRecordsForIssues(ctx, collectIssueIDs(subgraph.Issues))
+	if err != nil {
+		return nil, fmt.Errorf("failed to load dependencies: %w", err)
+	}
 	for _, issue := range subgraph.Issues {
-		deps, err := s.GetDependencyRecords(ctx, issue.ID)
-		if err != nil {
-			continue
-		}
-		for _, dep := range deps {
+		for _, dep := range subgraphDeps[issue.ID] {
 			// Resolve external deps via routing (bd-k0pfm)
 			if strings.HasPrefix(dep.DependsOnID, "external:") {
 				parts := strings.SplitN(dep.DependsOnID, ":", 3)
@@ -385,12 +431,12 @@ func loadAllGraphSubgraphs(ctx context.Context, s storage.DoltStorage) ([]*Templ
 
 	// Load all dependencies between these issues
 	allDeps := make([]*types.Dependency, 0)
+	depsByIssue, err := s.GetDependencyRecordsForIssues(ctx, collectIssueIDs(allIssues))
+	if err != nil {
+		return nil, fmt.Errorf("failed to load dependencies: %w", err)
+	}
 	for _, issue := range allIssues {
-		deps, err := s.GetDependencyRecords(ctx, issue.ID)
-		if err != nil {
-			continue
-		}
-		for _, dep := range deps {
+		for _, dep := range depsByIssue[issue.ID] {
 			// Resolve external deps via routing (bd-k0pfm)
 			if strings.HasPrefix(dep.DependsOnID, "external:") {
 				parts := strings.SplitN(dep.DependsOnID, ":", 3)
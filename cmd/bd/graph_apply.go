@@ -544,6 +544,11 @@ func executeGraphApply(ctx context.Context, plan *GraphApplyPlan, opts GraphAppl
 					issue.Assignee = node.Assignee
 				}
 			}
+			ruleIssue := *issue
+			ruleIssue.Assignee = node.Assignee
+			if err := checkValidationRules(&ruleIssue); err != nil {
+				return fmt.Errorf("node %q: %w", node.Key, err)
+			}
 
 			issues = append(issues, issue)
 		}
@@ -108,6 +108,68 @@ func dotEscapeID(id string) string {
 	return r.Replace(id)
 }
 
+// renderGraphMermaid renders the graph as a Mermaid flowchart, which GitHub,
+// GitLab, and most wikis render inside a ```mermaid code block.
+// Blocking edges are solid and parent-child edges dotted; nodes are
+// colored by status like the DOT output.
+func renderGraphMermaid(layout *GraphLayout, subgraph *TemplateSubgraph) {
+	fmt.Println("flowchart LR")
+	fmt.Println("  classDef open fill:#e8f4fd,color:#1a1a1a,stroke:#666666")
+	fmt.Println("  classDef in_progress fill:#fff3cd,color:#664d03,stroke:#666666")
+	fmt.Println("  classDef blocked fill:#f8d7da,color:#842029,stroke:#666666")
+	fmt.Println("  classDef closed fill:#d4edda,color:#888888,stroke:#666666")
+	fmt.Println("  classDef other fill:#e2e3e5,color:#41464b,stroke:#666666")
+
+	// Mermaid node IDs cannot contain every character an issue ID can,
+	// so nodes are numbered in layer order and labeled with the real ID.
+	nodeIDs := make(map[string]string, len(layout.Nodes))
+	for _, layer := range layout.Layers {
+		for _, id := range layer {
+			node := layout.Nodes[id]
+			if node == nil {
+				continue
+			}
+			nodeIDs[id] = fmt.Sprintf("n%d", len(nodeIDs))
+			label := fmt.Sprintf("%s %s<br/>P%d | %s", statusPlainIcon(node.Issue.Status), id,
+				node.Issue.Priority, truncateTitle(node.Issue.Title, 40))
+			fmt.Printf("  %s[\"%s\"]:::%s\n", nodeIDs[id], mermaidEscape(label), mermaidStatusClass(node.Issue.Status))
+		}
+	}
+
+	for _, dep := range subgraph.Dependencies {
+		if dep.Type != types.DepBlocks && dep.Type != types.DepParentChild {
+			continue
+		}
+		from, to := nodeIDs[dep.DependsOnID], nodeIDs[dep.IssueID]
+		if from == "" || to == "" {
+			continue
+		}
+		arrow := "-->"
+		if dep.Type == types.DepParentChild {
+			arrow = "-.->"
+		}
+		// Blocker points to blocked, as in the DOT output.
+		fmt.Printf("  %s %s %s\n", from, arrow, to)
+	}
+}
+
+// mermaidStatusClass returns the classDef name used for a status.
+func mermaidStatusClass(status types.Status) string {
+	switch status {
+	case types.StatusOpen, types.StatusInProgress, types.StatusBlocked, types.StatusClosed:
+		return string(status)
+	default:
+		return "other"
+	}
+}
+
+// mermaidEscape escapes text for a quoted Mermaid label. The <br/> line
+// breaks the label uses are kept.
+func mermaidEscape(s string) string {
+	s = strings.NewReplacer(`"`, "#quot;", "<", "#lt;", ">", "#gt;").Replace(s)
+	return strings.ReplaceAll(s, "#lt;br/#gt;", "<br/>")
+}
+
 // statusPlainIcon returns a plain text status icon (no ANSI colors) for export formats
 func statusPlainIcon(status types.Status) string {
 	switch status {
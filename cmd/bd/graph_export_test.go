@@ -121,6 +121,35 @@ func TestRenderGraphDOT_Empty(t *testing.T) {
 	}
 }
 
+func TestRenderGraphMermaid(t *testing.T) {
+	// Not parallel: captureGraphOutput redirects global os.Stdout
+	subgraph, layout := makeTestSubgraph()
+	subgraph.IssueMap["test-c"].Title = `Fix "quoted" <tag>`
+
+	output := captureGraphOutput(func() {
+		renderGraphMermaid(layout, subgraph)
+	})
+
+	if !strings.HasPrefix(output, "flowchart LR\n") {
+		t.Errorf("Mermaid output should start with 'flowchart LR', got: %s", output)
+	}
+	for _, want := range []string{
+		`["○ test-a<br/>P0 | Root issue"]:::open`,
+		`["● test-c<br/>P2 | Fix #quot;quoted#quot; #lt;tag#gt;"]:::blocked`,
+		`:::closed`,
+	} {
+		if !strings.Contains(output, want) {
+			t.Errorf("Mermaid output missing %q:\n%s", want, output)
+		}
+	}
+	if n := strings.Count(output, " --> "); n != 2 {
+		t.Errorf("got %d blocking edges, want 2:\n%s", n, output)
+	}
+	if n := strings.Count(output, " -.-> "); n != 1 {
+		t.Errorf("got %d parent-child edges, want 1:\n%s", n, output)
+	}
+}
+
 func TestDotNodeAttrs(t *testing.T) {
 	t.Parallel()
 	tests := []struct {
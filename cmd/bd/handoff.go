@@ -0,0 +1,356 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+	"unicode/utf8"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+// defaultHandoffTokenBudget keeps a bundle small enough to paste into a
+// fresh context window next to the instructions that go with it.
+const defaultHandoffTokenBudget = 4000
+
+// handoffMaxEvents caps the history a bundle starts from, before the token
+// budget is applied.
+const handoffMaxEvents = 50
+
+// handoffMinText is the shortest a long text field is cut down to when
+// fitting a bundle to its budget.
+const handoffMinText = 200
+
+const handoffTruncatedMarker = "\n\n[truncated]"
+
+// handoffLink is a related issue in a handoff bundle.
+type handoffLink struct {
+	ID     string               `json:"id"`
+	Title  string               `json:"title"`
+	Status types.Status         `json:"status"`
+	Type   types.DependencyType `json:"type"`
+}
+
+// handoffEvent is one line of an issue's recent activity.
+type handoffEvent struct {
+	Actor     string    `json:"actor"`
+	ActorKind string    `json:"actor_kind,omitempty"`
+	Summary   string    `json:"summary"`
+	CreatedAt time.Time `json:"created_at"`
+}
+
+// handoffBundle is what a new agent needs to pick up an issue. The issue
+// carries its labels, comments and code references.
+type handoffBundle struct {
+	Issue           *types.Issue   `json:"issue"`
+	Dependencies    []handoffLink  `json:"dependencies"`
+	Dependents      []handoffLink  `json:"dependents"`
+	Events          []handoffEvent `json:"events"`
+	Commits         []issueCommit  `json:"commits"`
+	Omitted         []string       `json:"omitted,omitempty"`
+	EstimatedTokens int            `json:"estimated_tokens"`
+}
+
+// buildHandoffBundle gathers the full, untrimmed bundle for issue id.
+func buildHandoffBundle(ctx context.Context, s storage.DoltStorage, id string) (*handoffBundle, error) {
+	issue, err := s.GetIssue(ctx, id)
+	if err != nil {
+		return nil, err
+	}
+	if issue.Labels, err = s.GetLabels(ctx, id); err != nil {
+		return nil, fmt.Errorf("labels: %w", err)
+	}
+	if issue.Comments, err = s.GetIssueComments(ctx, id); err != nil {
+		return nil, fmt.Errorf("comments: %w", err)
+	}
+	refs, err := s.GetCodeRefsForIssues(ctx, []string{id})
+	if err != nil {
+		return nil, fmt.Errorf("code references: %w", err)
+	}
+	issue.CodeRefs = refs[id]
+
+	b := &handoffBundle{
+		Issue:        issue,
+		Dependencies: []handoffLink{},
+		Dependents:   []handoffLink{},
+		Events:       []handoffEvent{},
+		Commits:      issueCommits(issue),
+	}
+	if b.Commits == nil {
+		b.Commits = []issueCommit{}
+	}
+	deps, err := s.GetDependenciesWithMetadata(ctx, id)
+	if err != nil {
+		return nil, fmt.Errorf("dependencies: %w", err)
+	}
+	for _, d := range deps {
+		b.Dependencies = append(b.Dependencies, handoffLink{ID: d.ID, Title: d.Title, Status: d.Status, Type: d.DependencyType})
+	}
+	dependents, err := s.GetDependentsWithMetadata(ctx, id)
+	if err != nil {
+		return nil, fmt.Errorf("dependents: %w", err)
+	}
+	for _, d := range dependents {
+		b.Dependents = append(b.Dependents, handoffLink{ID: d.ID, Title: d.Title, Status: d.Status, Type: d.DependencyType})
+	}
+
+	events, err := s.GetEvents(ctx, id, handoffMaxEvents)
+	if err != nil {
+		return nil, fmt.Errorf("events: %w", err)
+	}
+	annotateActorKinds(ctx, events)
+	// Events come newest first; the bundle reads oldest first. Comments are
+	// already in the bundle in full.
+	for i := len(events) - 1; i >= 0; i-- {
+		if e := events[i]; e.EventType != types.EventCommented {
+			b.Events = append(b.Events, handoffEvent{Actor: e.Actor, ActorKind: e.ActorKind, Summary: describeInboxEvent(e), CreatedAt: e.CreatedAt})
+		}
+	}
+	return b, nil
+}
+
+// handoffTokens estimates the tokens in s (1 token ~ 4 chars).
+func handoffTokens(s string) int {
+	return len(s) / 4
+}
+
+// renderHandoffMarkdown renders the bundle as a standalone markdown
+// document.
+func renderHandoffMarkdown(b *handoffBundle) string {
+	var sb strings.Builder
+	sb.WriteString(formatMarkdownIssue(b.Issue, 1))
+
+	for _, s := range []struct {
+		heading string
+		links   []handoffLink
+	}{
+		{"Depends On", b.Dependencies},
+		{"Dependents", b.Dependents},
+	} {
+		if len(s.links) == 0 {
+			continue
+		}
+		fmt.Fprintf(&sb, "\n## %s\n\n", s.heading)
+		for _, l := range s.links {
+			fmt.Fprintf(&sb, "- %s %s (%s): %s\n", l.Type, l.ID, l.Status, l.Title)
+		}
+	}
+
+	if len(b.Issue.CodeRefs) > 0 {
+		sb.WriteString("\n## Code References\n\n")
+		for _, ref := range b.Issue.CodeRefs {
+			fmt.Fprintf(&sb, "- `%s`", ref.String())
+			if ref.Note != "" {
+				fmt.Fprintf(&sb, " — %s", ref.Note)
+			}
+			sb.WriteString("\n")
+		}
+	}
+
+	if len(b.Commits) > 0 {
+		sb.WriteString("\n## Commits\n\n")
+		for _, c := range b.Commits {
+			fmt.Fprintf(&sb, "- %s", shortSHA(c.SHA))
+			if c.Trailer != "" {
+				fmt.Fprintf(&sb, " (%s)", c.Trailer)
+			}
+			if c.Subject != "" {
+				fmt.Fprintf(&sb, " %s", c.Subject)
+			}
+			sb.WriteString("\n")
+		}
+	}
+
+	if len(b.Events) > 0 {
+		sb.WriteString("\n## Recent Activity\n\n")
+		for _, e := range b.Events {
+			who := e.Actor
+			if e.ActorKind != "" {
+				who += " [" + e.ActorKind + "]"
+			}
+			fmt.Fprintf(&sb, "- %s %s: %s\n", e.CreatedAt.UTC().Format("2006-01-02 15:04"), who, e.Summary)
+		}
+	}
+
+	if len(b.Omitted) > 0 {
+		fmt.Fprintf(&sb, "\n---\n\n_Trimmed to fit the token budget: %s. Run `bd show %s` for the rest._\n",
+			strings.Join(b.Omitted, ", "), b.Issue.ID)
+	}
+	return sb.String()
+}
+
+// fitHandoffBudget trims b until its markdown fits in budget tokens,
+// cutting the least essential context first: old activity, old comments,
+// dependents, code references and commits, then the long text fields.
+// The header and the issues it depends on are always kept. A budget of 0
+// means no limit.
+func fitHandoffBudget(b *handoffBundle, budget int) {
+	over := func() bool { return handoffTokens(renderHandoffMarkdown(b)) > budget }
+	if budget <= 0 || !over() {
+		return
+	}
+	issue := b.Issue
+
+	cuts := []struct {
+		what string
+		n    int
+		cut  func() bool
+	}{
+		{what: "older events", cut: func() bool {
+			if len(b.Events) == 0 {
+				return false
+			}
+			b.Events = b.Events[1:]
+			return true
+		}},
+		{what: "older comments", cut: func() bool {
+			if len(issue.Comments) == 0 {
+				return false
+			}
+			issue.Comments = issue.Comments[1:]
+			return true
+		}},
+		{what: "dependents", cut: func() bool {
+			if len(b.Dependents) == 0 {
+				return false
+			}
+			b.Dependents = b.Dependents[:len(b.Dependents)-1]
+			return true
+		}},
+		{what: "code references", cut: func() bool {
+			if len(issue.CodeRefs) == 0 {
+				return false
+			}
+			issue.CodeRefs = issue.CodeRefs[:len(issue.CodeRefs)-1]
+			return true
+		}},
+		{what: "older commits", cut: func() bool {
+			if len(b.Commits) == 0 {
+				return false
+			}
+			b.Commits = b.Commits[1:]
+			return true
+		}},
+	}
+	// The note saying what was left out counts against the budget too, so
+	// it is kept current as the bundle shrinks.
+	var truncated []string
+	updateNote := func() {
+		b.Omitted = nil
+		for _, c := range cuts {
+			if c.n > 0 {
+				b.Omitted = append(b.Omitted, fmt.Sprintf("%d %s", c.n, c.what))
+			}
+		}
+		b.Omitted = append(b.Omitted, truncated...)
+	}
+
+	for i := range cuts {
+		for over() && cuts[i].cut() {
+			cuts[i].n++
+			updateNote()
+		}
+	}
+
+	for _, f := range []struct {
+		name string
+		text *string
+	}{
+		{"notes", &issue.Notes},
+		{"design", &issue.Design},
+		{"description", &issue.Description},
+		{"acceptance criteria", &issue.AcceptanceCriteria},
+	} {
+		if !over() {
+			break
+		}
+		truncated = append(truncated, f.name+" truncated")
+		updateNote()
+		excess := (handoffTokens(renderHandoffMarkdown(b)) - budget) * 4
+		cut, ok := truncateHandoffText(*f.text, len(*f.text)-excess)
+		if !ok {
+			truncated = truncated[:len(truncated)-1]
+			updateNote()
+			continue
+		}
+		*f.text = cut
+	}
+}
+
+// truncateHandoffText cuts s to about n bytes, but never below
+// handoffMinText, marking the cut. It reports false if s was left alone.
+func truncateHandoffText(s string, n int) (string, bool) {
+	n -= len(handoffTruncatedMarker)
+	if n < handoffMinText {
+		n = handoffMinText
+	}
+	if len(s) <= n {
+		return s, false
+	}
+	for n > 0 && !utf8.RuneStart(s[n]) {
+		n--
+	}
+	return strings.TrimRight(s[:n], " \t\n") + handoffTruncatedMarker, true
+}
+
+var handoffCmd = &cobra.Command{
+	Use:     "handoff <issue-id>",
+	GroupID: "views",
+	Short:   "Bundle an issue's context for handing it to a fresh agent",
+	Long: `Print a self-contained markdown bundle for an issue, sized for pasting into
+a fresh agent context window: its description, design, acceptance criteria
+and notes, the issues it depends on and that depend on it, comments, code
+references, linked commits, and recent activity.
+
+The bundle is trimmed to --token-budget (estimated at 4 characters per
+token) by dropping the oldest activity and comments first, then dependents,
+code references and commits, and finally shortening the long text fields.
+A note at the end lists what was left out. Use --token-budget 0 for
+everything.
+
+With --json, the bundle is printed as structured data instead.
+
+Examples:
+  bd handoff bd-42
+  bd handoff bd-42 --token-budget 1500 | pbcopy
+  bd handoff bd-42 --token-budget 0 --json`,
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		budget, _ := cmd.Flags().GetInt("token-budget")
+		if budget < 0 {
+			FatalErrorRespectJSON("--token-budget must be 0 (no limit) or a positive number of tokens")
+		}
+		id, err := utils.ResolvePartialID(ctx, store, args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		bundle, err := buildHandoffBundle(ctx, store, id)
+		if err != nil {
+			if errors.Is(err, storage.ErrNotFound) {
+				FatalErrorRespectJSON("issue %s not found", id)
+			}
+			FatalErrorRespectJSON("building handoff for %s: %v", id, err)
+		}
+
+		fitHandoffBudget(bundle, budget)
+		markdown := renderHandoffMarkdown(bundle)
+		bundle.EstimatedTokens = handoffTokens(markdown)
+
+		if jsonOutput {
+			outputJSON(bundle)
+			return
+		}
+		fmt.Print(markdown)
+	},
+}
+
+func init() {
+	handoffCmd.Flags().Int("token-budget", defaultHandoffTokenBudget, "Approximate maximum size of the bundle in tokens (0 = no limit)")
+	rootCmd.AddCommand(handoffCmd)
+}
@@ -0,0 +1,123 @@
+package main
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func testHandoffBundle() *handoffBundle {
+	issue := &types.Issue{
+		ID:                 "bd-1",
+		Title:              "Fix login",
+		Status:             types.StatusInProgress,
+		Priority:           1,
+		IssueType:          types.TypeBug,
+		Description:        strings.Repeat("The login form loses its state. ", 100),
+		AcceptanceCriteria: "Logging in twice works.",
+		Notes:              strings.Repeat("Tried clearing the cache. ", 100),
+		CodeRefs:           []*types.CodeRef{{Path: "web/login.go", LineStart: 10, LineEnd: 20, Note: "form handler"}},
+	}
+	at := time.Date(2026, 1, 2, 15, 4, 0, 0, time.UTC)
+	b := &handoffBundle{
+		Issue:        issue,
+		Dependencies: []handoffLink{{ID: "bd-2", Title: "Session store", Status: types.StatusClosed, Type: types.DepBlocks}},
+		Dependents:   []handoffLink{{ID: "bd-3", Title: "Release", Status: types.StatusOpen, Type: types.DepBlocks}},
+		Commits:      []issueCommit{{SHA: "0123456789abcdef", Subject: "Keep form state", Trailer: "Refs"}},
+	}
+	for i := 0; i < 30; i++ {
+		issue.Comments = append(issue.Comments, &types.Comment{Author: "alice", Text: fmt.Sprintf("comment %d", i), CreatedAt: at})
+		b.Events = append(b.Events, handoffEvent{Actor: "alice", Summary: fmt.Sprintf("event %d", i), CreatedAt: at})
+	}
+	return b
+}
+
+func TestRenderHandoffMarkdown(t *testing.T) {
+	b := testHandoffBundle()
+	b.Events[0].ActorKind = actorKindAgent
+	md := renderHandoffMarkdown(b)
+	for _, want := range []string{
+		"# bd-1: Fix login",
+		"## Acceptance Criteria\n\nLogging in twice works.",
+		"## Depends On\n\n- blocks bd-2 (closed): Session store",
+		"## Dependents\n\n- blocks bd-3 (open): Release",
+		"- `web/login.go:10-20` — form handler",
+		"- 0123456789ab (Refs) Keep form state",
+		"- 2026-01-02 15:04 alice [agent]: event 0",
+		"- 2026-01-02 15:04 alice: event 29",
+	} {
+		if !strings.Contains(md, want) {
+			t.Errorf("markdown missing %q:\n%s", want, md)
+		}
+	}
+	if strings.Contains(md, "Trimmed") {
+		t.Errorf("untrimmed bundle should not carry a trim note")
+	}
+}
+
+func TestFitHandoffBudget(t *testing.T) {
+	t.Run("no_limit", func(t *testing.T) {
+		b := testHandoffBundle()
+		fitHandoffBudget(b, 0)
+		if len(b.Events) != 30 || len(b.Issue.Comments) != 30 || b.Omitted != nil {
+			t.Errorf("budget 0 trimmed the bundle: %d events, %d comments, omitted %v", len(b.Events), len(b.Issue.Comments), b.Omitted)
+		}
+	})
+
+	t.Run("drops_history_first", func(t *testing.T) {
+		b := testHandoffBundle()
+		full := handoffTokens(renderHandoffMarkdown(b))
+		fitHandoffBudget(b, full-20)
+		if len(b.Events) == 30 || len(b.Events) == 0 {
+			t.Errorf("expected some older events dropped, have %d", len(b.Events))
+		}
+		if b.Events[len(b.Events)-1].Summary != "event 29" {
+			t.Errorf("newest event was dropped: %+v", b.Events)
+		}
+		if len(b.Issue.Comments) != 30 || len(b.Dependents) != 1 {
+			t.Errorf("comments or dependents cut before events were exhausted")
+		}
+		if len(b.Omitted) != 1 || !strings.HasSuffix(b.Omitted[0], "older events") {
+			t.Errorf("omitted = %v", b.Omitted)
+		}
+	})
+
+	t.Run("truncates_text_last", func(t *testing.T) {
+		b := testHandoffBundle()
+		fitHandoffBudget(b, 500)
+		md := renderHandoffMarkdown(b)
+		if got := handoffTokens(md); got > 550 {
+			t.Errorf("bundle is %d tokens, want about 500", got)
+		}
+		if len(b.Events) != 0 || len(b.Issue.Comments) != 0 || len(b.Dependents) != 0 {
+			t.Errorf("history should be dropped before text is truncated")
+		}
+		if len(b.Dependencies) != 1 || !strings.Contains(md, "Fix login") {
+			t.Errorf("header and dependencies must always be kept")
+		}
+		if !strings.Contains(b.Issue.Notes, "[truncated]") {
+			t.Errorf("notes should be truncated first")
+		}
+		if !strings.Contains(md, "notes truncated") {
+			t.Errorf("trim note missing:\n%s", md)
+		}
+	})
+}
+
+func TestTruncateHandoffText(t *testing.T) {
+	short := strings.Repeat("a", handoffMinText)
+	if got, ok := truncateHandoffText(short, 10); ok || got != short {
+		t.Errorf("text at the minimum length should be left alone")
+	}
+	long := strings.Repeat("é", 500)
+	got, ok := truncateHandoffText(long, 300)
+	if !ok || !strings.HasSuffix(got, handoffTruncatedMarker) || len(got) > 300 {
+		t.Fatalf("truncateHandoffText = %q (%d bytes), %v", got, len(got), ok)
+	}
+	if body := strings.TrimSuffix(got, handoffTruncatedMarker); !strings.HasSuffix(body, "é") {
+		t.Errorf("cut split a rune: %q", body[len(body)-4:])
+	}
+}
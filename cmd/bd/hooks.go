@@ -19,7 +19,7 @@ import (
 
 // managedHookNames lists the git hooks managed by beads.
 // Hook content is generated dynamically by generateHookSection().
-var managedHookNames = []string{"pre-commit", "post-merge", "pre-push", "post-checkout", "prepare-commit-msg"}
+var managedHookNames = []string{"pre-commit", "post-merge", "pre-push", "post-checkout", "prepare-commit-msg", "post-commit"}
 
 const hookVersionPrefix = "# bd-hooks-version: "
 const shimVersionPrefix = "# bd-shim "
@@ -473,7 +473,7 @@ type HookStatus struct {
 
 // CheckGitHooks checks the status of bd git hooks in .git/hooks/
 func CheckGitHooks() []HookStatus {
-	hooks := []string{"pre-commit", "post-merge", "pre-push", "post-checkout", "prepare-commit-msg"}
+	hooks := []string{"pre-commit", "post-merge", "pre-push", "post-checkout", "prepare-commit-msg", "post-commit"}
 	statuses := make([]HookStatus, 0, len(hooks))
 
 	// Get hooks directory from common git dir (hooks are shared across worktrees)
@@ -615,11 +615,15 @@ var hooksCmd = &cobra.Command{
 	Long: `Install, uninstall, or list git hooks for beads integration.
 
 The hooks provide:
-- pre-commit: Run chained hooks before commit
-- post-merge: Run chained hooks after pull/merge
+- pre-commit: Export issues to JSONL and stage it with the commit
+- post-merge: Import the merged JSONL after pull/merge
 - pre-push: Run chained hooks before push
-- post-checkout: Run chained hooks after branch checkout
-- prepare-commit-msg: Add agent identity trailers for forensics`,
+- post-checkout: Import the JSONL after a branch checkout
+- prepare-commit-msg: Add agent identity trailers for forensics
+- post-commit: Link the commit to issues named in Fixes:/Refs: trailers
+
+The export runs when export.auto or sync.auto_export is set, the import
+when import.auto is set and no Dolt remote is configured.`,
 }
 
 var hooksInstallCmd = &cobra.Command{
@@ -636,11 +640,12 @@ Hooks use section markers to coexist with existing hooks — any user content
 outside the markers is preserved across installs and upgrades.
 
 Installed hooks:
-  - pre-commit: Run chained hooks before commit
-  - post-merge: Run chained hooks after pull/merge
+  - pre-commit: Export issues to JSONL and stage it with the commit
+  - post-merge: Import the merged JSONL after pull/merge
   - pre-push: Run chained hooks before push
-  - post-checkout: Run chained hooks after branch checkout
-  - prepare-commit-msg: Add agent identity trailers (for orchestrator agents)`,
+  - post-checkout: Import the JSONL after a branch checkout
+  - prepare-commit-msg: Add agent identity trailers (for orchestrator agents)
+  - post-commit: Link the commit to issues named in Fixes:/Refs: trailers`,
 	Run: func(cmd *cobra.Command, args []string) {
 		force, _ := cmd.Flags().GetBool("force")
 		shared, _ := cmd.Flags().GetBool("shared")
@@ -686,7 +691,12 @@ Installed hooks:
 var hooksUninstallCmd = &cobra.Command{
 	Use:   "uninstall",
 	Short: "Uninstall bd git hooks",
-	Long:  `Remove bd git hooks from .git/hooks/ directory.`,
+	Long: `Remove bd git hooks from the hooks directory git uses (.git/hooks/, or
+core.hooksPath when set).
+
+Only the beads section is removed from a hook that has other content; a
+hook left with nothing else is deleted. core.hooksPath is unset if it
+points at .beads/hooks or .beads-hooks.`,
 	Run: func(cmd *cobra.Command, args []string) {
 		if err := uninstallHooks(); err != nil {
 			FatalErrorRespectJSON("uninstalling hooks: %v", err)
@@ -1177,9 +1187,7 @@ func uninstallHooks() error {
 	if err != nil {
 		return err
 	}
-	hookNames := []string{"pre-commit", "post-merge", "pre-push", "post-checkout", "prepare-commit-msg"}
-
-	for _, hookName := range hookNames {
+	for _, hookName := range managedHookNames {
 		hookPath := filepath.Join(hooksDir, hookName)
 
 		// #nosec G304 -- hook path constrained to .git/hooks directory
@@ -1337,12 +1345,13 @@ func runPreCommitHook() int {
 }
 
 // exportJSONLForCommit exports Dolt issue state to the git-tracked JSONL file
-// when export.auto is enabled. Called from the pre-commit hook so that the
-// exported file can be staged and included in the pending commit.
+// when export.auto or sync.auto_export is enabled. Called from the pre-commit
+// hook so that the exported file can be staged and included in the pending
+// commit.
 //
 // Errors are logged as warnings but never block the commit.
 func exportJSONLForCommit() {
-	if !config.GetBool("export.auto") {
+	if !config.GetBool("export.auto") && !config.GetBool("sync.auto_export") {
 		return
 	}
 
@@ -1385,12 +1394,13 @@ func exportJSONLForCommit() {
 		return
 	}
 
-	// Stage the exported file if configured. Skip when no-git-ops is set
-	// (GH#3314). gitAddFile scrubs the inherited git hook env vars so git
+	// Stage the exported file if configured; sync.auto_commit implies it,
+	// since its own commit is skipped inside a hook. Skip when no-git-ops is
+	// set (GH#3314). gitAddFile scrubs the inherited git hook env vars so git
 	// rediscovers the repo from cwd, and silently skips when fullPath is
 	// outside the hook's worktree (the .beads/redirect case where fullPath
 	// points into the main repo, not this worktree). See GH#3311.
-	if config.GetBool("export.git-add") && !config.GetBool("no-git-ops") {
+	if (config.GetBool("export.git-add") || config.GetBool("sync.auto_commit")) && !config.GetBool("no-git-ops") {
 		if err := gitAddFile(fullPath); err != nil {
 			debug.Logf("pre-commit: git add failed: %v\n", err)
 		}
@@ -1554,6 +1564,20 @@ func runPostCheckoutHook(args []string) int {
 	return 0
 }
 
+// runPostCommitHook runs chained hooks after a commit, then links the new
+// commit to the issues named in its Fixes: and Refs: trailers.
+// Returns 0 on success (or if not applicable).
+//
+//nolint:unparam // Always returns 0 by design - the commit is already made
+func runPostCommitHook(args []string) int {
+	// Run chained hook first (if exists)
+	if exitCode := runChainedHook("post-commit", args); exitCode != 0 {
+		return exitCode
+	}
+	linkCommitTrailers()
+	return 0
+}
+
 // runPrepareCommitMsgHook adds agent identity trailers to commit messages.
 // args: [commit-msg-file, source, sha1]
 // Returns 0 on success (or if not applicable), non-zero on error.
@@ -1642,6 +1666,7 @@ Supported hooks:
   - pre-push: Run chained hooks before push
   - post-checkout: Run chained hooks after branch checkout
   - prepare-commit-msg: Add agent identity trailers for forensics
+  - post-commit: Link the commit to issues named in Fixes:/Refs: trailers
 
 The thin shim pattern ensures hook logic is always in sync with the
 installed bd version - upgrading bd automatically updates hook behavior.`,
@@ -1670,6 +1695,8 @@ installed bd version - upgrading bd automatically updates hook behavior.`,
 			exitCode = runPostCheckoutHook(hookArgs)
 		case "prepare-commit-msg":
 			exitCode = runPrepareCommitMsgHook(hookArgs)
+		case "post-commit":
+			exitCode = runPostCommitHook(hookArgs)
 		default:
 			FatalError("unknown hook: %s", hookName)
 		}
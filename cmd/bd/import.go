@@ -8,12 +8,17 @@ import (
 	"io"
 	"os"
 	"path/filepath"
+	"sort"
 	"strings"
+	"time"
 
 	"github.com/spf13/cobra"
 	"github.com/steveyegge/beads/internal/beads"
 	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/tracker"
 	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/validation"
+	"golang.org/x/term"
 )
 
 var importCmd = &cobra.Command{
@@ -26,6 +31,10 @@ If no file is specified, imports from the configured import.path under .beads/
 'bd export': new issues are created and existing issues are updated (upsert
 semantics).
 
+Progress, warnings, and errors go to stderr, so only --json output reaches
+stdout and the command can sit anywhere in a pipeline. Pipes such as
+/dev/stdin are read like any other file.
+
 Memory records (lines with "_type":"memory") are automatically detected and
 imported as persistent memories (equivalent to 'bd remember'). This makes
 'bd export | bd import' a full round-trip for both issues and memories.
@@ -54,8 +63,132 @@ Common fields:
   metadata               Arbitrary JSON object preserved verbatim.
 
 Timestamps (created_at, updated_at, started_at, closed_at) are preserved
-when present in the JSONL and otherwise filled in by the importer. The
-legacy "wisp" boolean is accepted as an alias for "ephemeral".
+when present in the JSONL and otherwise filled in by the importer.
+
+Each line written by 'bd export' carries "_format", the version of the
+record format. Lines without it, or from an older format, are translated
+(e.g. the legacy "wisp" boolean becomes "ephemeral"), so files written by
+older bd releases import cleanly. Lines from a newer format are imported
+with a warning; fields this version does not know are ignored.
+
+Lines that fail to parse or have no title do not abort the import: every
+valid line is imported, the bad lines are listed by line number at the end,
+and the command exits non-zero.
+
+Issues are checked against the validation rules in config.yaml
+(validation.strictness, title-max-length, priorities, required.<type>);
+under strict, a line that breaks one is reported like a parse error. Legacy
+data - lines without "_format" and the jira, gitlab, and taskwarrior
+formats - predates those rules and is imported under the relaxed profile.
+
+Before importing into a database that already has issues, a snapshot is
+taken in .beads/backups/ so the import can be undone with 'bd backup restore
+<timestamp> --force' (see 'bd backup snapshot').
+
+Use --format csv to import a file written by 'bd export --format csv'
+(see 'bd export --help' for the columns). Columns are matched by header
+name and may be reordered; every column except title is optional, and an
+unknown column is an error. Rows with an id update that issue: import
+replaces the whole row, so leaving a column out clears it on existing
+issues. Rows without an id create new issues. Labels are added, never
+removed. Priority defaults to 2 when blank, and dates may be RFC3339 or
+YYYY-MM-DD.
+
+Use --format jira to import a Jira CSV export (Filters > Export > CSV
+(all fields)). Issue types, statuses, and priorities are mapped with the
+same defaults and jira.type_map.*, jira.status_map.*, and
+jira.priority_map.* config as 'bd jira sync'. Each issue's external_ref is
+set to its Jira browse URL (the bare key if jira.url is not configured), so
+a later 'bd jira sync' matches it, and re-importing updates the issues in
+place. Blocks, duplicate, and other issue links, and parent links, become
+dependencies once both ends are in the database. To pull straight from the
+Jira API instead, use 'bd jira sync --pull --project KEY'.
+
+Use --format gitlab to import issues saved from the GitLab issues API, e.g.
+'glab api "projects/:id/issues?state=all" --paginate > gl.json'.
+Labels, priority::/status::/type:: scoped labels, weight, due date, and
+closed state are mapped as in 'bd gitlab sync'. Each issue's external_ref is
+its GitLab URL, which carries the issue IID, so a later sync matches it and
+re-importing updates the issues in place. Each milestone becomes an epic
+(external_ref set to the milestone URL) with its issues as children. Errors
+are reported by the issue's position in the file. To pull straight from the
+GitLab API instead, configure gitlab.url, gitlab.token, and
+gitlab.project_id and run 'bd gitlab sync --pull-only'.
+
+Use --format taskwarrior to import the JSON written by 'task export'. The
+task's project and tags become labels, and annotations become comments.
+Priority H, M, and L map to 1, 2, and 3 (2 when unset). Completed tasks
+are closed, started tasks are in_progress, and waiting tasks are deferred
+until their wait date. due becomes due_at. Each depends entry becomes a
+blocking dependency once both tasks are in the database. Deleted tasks and
+recurrence templates are skipped. Each issue's external_ref is
+"taskwarrior:<uuid>", so re-importing updates the issues in place. Errors
+are reported by the task's position in the export.
+
+Use --format md-dir DIR to import a directory of markdown files, one issue
+per file, each starting with a YAML front matter block:
+
+  ---
+  id: bd-42              # optional; without it a new issue is created
+  title: Fix login       # optional; else the first "# " heading, else the file name
+  status: open
+  priority: 1            # 0-4 or P0-P4
+  type: bug
+  assignee: alice
+  labels: [auth, ui]     # or "auth, ui"
+  parent: bd-40          # parent-child dependency
+  depends_on: [bd-41]    # blocking dependencies
+  due: 2026-06-01        # also defer_until
+  ---
+  The body becomes the description.
+
+Subdirectories are included, except hidden ones. Files without front matter
+(a README, an index) are skipped, as are unknown keys. A file with an id
+updates that issue, changing only the fields its front matter sets plus the
+title and description. A file without an id gets external_ref "md:<path>"
+relative to DIR, so importing the directory again updates the issues it
+created. Errors are reported by the file's position in path order.
+
+Large inputs: by default every issue is read before any is written, in
+one transaction. With --batch-size N, issues are written N at a time as
+they are read, so memory stays bounded however large the file is.
+Dependencies are added after the last batch, so they may point at issues
+from any batch. A failure stops the import, but batches already written
+stay in the working set.
+
+Use --repair for exports from older databases that violate current
+invariants. It backfills closed_at on closed issues (from updated_at),
+clears closed_at on open ones, clamps priorities into 0-4, defaults a
+missing title, and drops negative estimates, printing each repair.
+
+Deletion records ("_type":"deletion", written by 'bd export' for deleted
+issues) carry the issue's id, deleted_at, and actor. The import records
+the deletion, deletes the local copy unless it was updated after
+deleted_at, and skips issue lines for deleted issues, so a JSONL file that
+predates a deletion does not bring the issue back.
+
+When an incoming issue and the local copy have the same ID but different
+content, --strategy decides which one is kept:
+  newest       keep the copy with the later updated_at (default)
+  local        always keep the local copy
+  remote       always take the incoming copy
+  interactive  show the differing fields and ask for each conflict
+The copy that is not kept is appended to .beads/conflicts.jsonl (with the
+strategy, which side was kept, and the source) instead of being dropped, so
+it can be recovered by hand. The file is local and ignored by git.
+
+With --rename-on-import, such an ID collision keeps both: the incoming
+issue is created under a new ID, and the dependencies the import brings in
+that point at the old ID are rewritten to the new one. The old and new IDs
+are listed at the end ("renumbered" with --json). Importing the same file
+again reuses the IDs given the first time.
+
+Use --dry-run to preview an import without writing anything. It lists the
+issues that would be created, the existing issues that would change with
+each changed field's old and new value, the issues that would be skipped
+because the local copy is newer (conflicts), and any lines that fail
+validation. With --json the preview is in the "preview" object, so a script
+or agent can decide whether to go ahead.
 
 EXAMPLES:
   bd import                        # Import from configured import.path
@@ -63,23 +196,43 @@ EXAMPLES:
   bd import -i backup.jsonl        # Legacy alias for a specific file
   bd import -                      # Read JSONL from stdin
   cat issues.jsonl | bd import -   # Pipe JSONL from another tool
-  bd import --dry-run              # Show what would be imported
+  git show HEAD:.beads/issues.jsonl | bd import -i /dev/stdin  # Restore from git
+  bd import --dry-run              # Preview new, changed, and conflicting issues
   bd import --dedup                # Skip issues with duplicate titles
+  bd import --strategy local       # Keep local edits when both sides changed
+  bd import --rename-on-import other.jsonl # Import colliding IDs as new issues
+  bd import --repair old.jsonl     # Fix legacy invariant violations while importing
+  bd import --format csv edits.csv # Import a spreadsheet round-trip
+  bd import --format md-dir backlog/  # One issue per markdown file
+  bd import --format jira jira.csv # Import a Jira CSV export
+  bd import --format gitlab gl.json # Import GitLab API issue JSON
+  task export | bd import --format taskwarrior -  # Migrate from TaskWarrior
+  bd import --batch-size 1000 big.jsonl # Bounded memory for large files
   bd import --json                 # Structured output with created and skipped IDs`,
 	GroupID: "sync",
 	RunE:    runImport,
 }
 
 var (
-	importDryRun bool
-	importDedup  bool
-	importInput  string
+	importDryRun    bool
+	importDedup     bool
+	importRepair    bool
+	importInput     string
+	importFormat    string
+	importBatchSize int
+	importStrategy  string
+	importRename    bool
 )
 
 func init() {
-	importCmd.Flags().StringVarP(&importInput, "input", "i", "", "Read JSONL from a specific file")
+	importCmd.Flags().StringVarP(&importInput, "input", "i", "", "Read JSONL from a specific file, or - for stdin")
 	importCmd.Flags().BoolVar(&importDryRun, "dry-run", false, "Show what would be imported without importing")
 	importCmd.Flags().BoolVar(&importDedup, "dedup", false, "Skip lines whose title matches an existing open issue")
+	importCmd.Flags().StringVar(&importFormat, "format", "jsonl", "Input format: jsonl, csv, jira, gitlab, taskwarrior, or md-dir")
+	importCmd.Flags().BoolVar(&importRepair, "repair", false, "Repair known legacy invariant violations (missing closed_at, out-of-range priority, ...) instead of rejecting them")
+	importCmd.Flags().StringVar(&importStrategy, "strategy", importStrategyNewest, "Conflict resolution when an issue differs locally: newest, local, remote, or interactive")
+	importCmd.Flags().BoolVar(&importRename, "rename-on-import", false, "Give an incoming issue a new ID when a local issue with different content has its ID")
+	importCmd.Flags().IntVar(&importBatchSize, "batch-size", 0, "Import issues in transactions of this many as they are read (0 = all at once)")
 	rootCmd.AddCommand(importCmd)
 }
 
@@ -88,9 +241,50 @@ func runImport(cmd *cobra.Command, args []string) error {
 	if importInput != "" && len(args) > 0 {
 		return fmt.Errorf("use either --input or a positional file, not both")
 	}
+	// The local --format flag shadows the hidden persistent --format on
+	// rootCmd, so route "json" to structured output like 'bd list' does.
+	if strings.EqualFold(importFormat, "json") {
+		jsonOutput = true
+		importFormat = "jsonl"
+	}
+	importFormat = strings.ToLower(importFormat)
+	switch importFormat {
+	case "jsonl", "csv", "jira", "gitlab", "taskwarrior", "md-dir":
+	default:
+		return fmt.Errorf("unsupported --format %q (use jsonl, csv, jira, gitlab, taskwarrior, or md-dir)", importFormat)
+	}
 
 	fromStdin := importInput == "-" || (len(args) > 0 && args[0] == "-")
 
+	importStrategy = strings.ToLower(importStrategy)
+	if !validImportStrategy(importStrategy) {
+		return fmt.Errorf("unsupported --strategy %q (use newest, local, remote, or interactive)", importStrategy)
+	}
+	if importStrategy == importStrategyInteractive && !importDryRun {
+		if fromStdin {
+			return fmt.Errorf("--strategy interactive reads answers from stdin, so the import cannot; pass a file")
+		}
+		if !term.IsTerminal(int(os.Stdin.Fd())) {
+			return fmt.Errorf("--strategy interactive needs a terminal")
+		}
+	}
+
+	if importFormat == "md-dir" {
+		dir := importInput
+		if len(args) > 0 {
+			dir = args[0]
+		}
+		if dir == "" || fromStdin {
+			return fmt.Errorf("--format md-dir needs the directory to import")
+		}
+		if info, err := os.Stat(dir); err != nil {
+			return fmt.Errorf("cannot read %s: %w", dir, err)
+		} else if !info.IsDir() {
+			return fmt.Errorf("--format md-dir: %s is not a directory", dir)
+		}
+		return runImportFromReader(ctx, nil, dir)
+	}
+
 	if fromStdin {
 		return runImportFromReader(ctx, os.Stdin, "stdin")
 	}
@@ -117,7 +311,9 @@ func runImport(cmd *cobra.Command, args []string) error {
 	if err != nil {
 		return fmt.Errorf("cannot read %s: %w", jsonlPath, err)
 	}
-	if info.Size() == 0 {
+	// Only a regular file can be known empty up front; /dev/stdin, named
+	// pipes, and process substitutions report size 0 but still have data.
+	if info.Mode().IsRegular() && info.Size() == 0 {
 		if jsonOutput {
 			outputJSON(importResultJSON{Source: jsonlPath})
 			return nil
@@ -132,7 +328,13 @@ func runImport(cmd *cobra.Command, args []string) error {
 	}
 	defer f.Close()
 
-	return runImportFromReader(ctx, f, jsonlPath)
+	if err := runImportFromReader(ctx, f, jsonlPath); err != nil {
+		return err
+	}
+	if !importDryRun {
+		recordJSONLFileHash(ctx, store, jsonlPath)
+	}
+	return nil
 }
 
 type importResultJSON struct {
@@ -143,8 +345,37 @@ type importResultJSON struct {
 	Memories            int      `json:"memories,omitempty"`
 	IDs                 []string `json:"ids,omitempty"`
 	StaleSkippedIDs     []string `json:"stale_skipped_ids,omitempty"`
+	TombstonedIDs       []string `json:"tombstoned_ids,omitempty"`
+	DeletedIDs          []string `json:"deleted_ids,omitempty"`
+	ConflictIDs         []string `json:"conflict_ids,omitempty"`
+	ConflictsFile       string   `json:"conflicts_file,omitempty"`
+	RegisteredTypes     []string `json:"registered_types,omitempty"`
 	SkippedDependencies []string `json:"skipped_dependencies,omitempty"`
 	DryRun              bool     `json:"dry_run,omitempty"`
+
+	// Preview is set by --dry-run.
+	Preview *importPreview `json:"preview,omitempty"`
+
+	// Renumbered maps incoming IDs already taken by a different local
+	// issue (under a sequential issue_id_mode, or with --rename-on-import)
+	// to the IDs they got.
+	Renumbered map[string]string `json:"renumbered,omitempty"`
+
+	Repaired []importRepairNote `json:"repaired,omitempty"`
+	Warnings []string           `json:"warnings,omitempty"`
+	Errors   []importLineError  `json:"errors,omitempty"`
+}
+
+// importRepairNote lists the --repair fixes applied to one issue.
+type importRepairNote struct {
+	ID      string   `json:"id"`
+	Repairs []string `json:"repairs"`
+}
+
+// importLineError records an input line that could not be imported.
+type importLineError struct {
+	Line  int    `json:"line"`
+	Error string `json:"error"`
 }
 
 func runImportFromReader(ctx context.Context, r io.Reader, source string) error {
@@ -152,83 +383,167 @@ func runImportFromReader(ctx context.Context, r io.Reader, source string) error
 		return fmt.Errorf("no database — run 'bd init' or 'bd bootstrap' first")
 	}
 
-	scanner := bufio.NewScanner(r)
-	scanner.Buffer(make([]byte, 0, 1024*1024), 64*1024*1024)
-
-	var issues []*types.Issue
-	var memories []memoryRecord
+	result := importResultJSON{
+		Source: source,
+		DryRun: importDryRun,
+	}
+	if importDryRun {
+		result.Preview = newImportPreview()
+		result.Preview.strategy = importStrategy
+		result.Preview.rename = importRename
+	}
 
-	for scanner.Scan() {
-		line := scanner.Text()
-		if line == "" {
-			continue
+	// With --batch-size, issues are imported as they are parsed, one
+	// transaction per batch, instead of being held until the input ends.
+	// Dependencies wait until every batch is in, since they may point at
+	// issues from a later batch.
+	var deferredDeps []*types.Dependency
+	importBatch := func(issues []*types.Issue) error {
+		// Skip issues deleted here (or by an import of their tombstone).
+		issues, buried, err := withoutTombstoned(ctx, store, issues)
+		if err != nil {
+			return err
 		}
-
-		var peek map[string]json.RawMessage
-		if err := json.Unmarshal([]byte(line), &peek); err != nil {
-			return fmt.Errorf("failed to parse JSONL line: %w", err)
+		result.Skipped += len(buried)
+		result.TombstonedIDs = append(result.TombstonedIDs, buried...)
+
+		// Dedup: skip issues whose title matches an existing open issue
+		if importDedup && len(issues) > 0 {
+			var hits int
+			issues, hits = filterDuplicatesByTitle(ctx, store, issues)
+			result.DedupHits += hits
+			if importDryRun {
+				result.Skipped += hits
+			}
 		}
-
-		if rawType, ok := peek["_type"]; ok {
-			var typeStr string
-			if err := json.Unmarshal(rawType, &typeStr); err == nil && typeStr == "memory" {
-				var mem memoryRecord
-				if err := json.Unmarshal([]byte(line), &mem); err != nil {
-					return fmt.Errorf("failed to parse memory record: %w", err)
-				}
-				if mem.Key != "" && mem.Value != "" {
-					memories = append(memories, mem)
-				}
-				continue
+		if importDryRun || len(issues) == 0 {
+			result.Created += len(issues)
+			if importDryRun && len(issues) > 0 {
+				return result.Preview.add(ctx, store, issues)
 			}
+			return nil
 		}
 
-		var issue types.Issue
-		if err := json.Unmarshal([]byte(line), &issue); err != nil {
-			return fmt.Errorf("failed to parse issue from JSONL: %w", err)
+		var deps [][]*types.Dependency
+		if importBatchSize > 0 {
+			deps = make([][]*types.Dependency, len(issues))
+			for i, issue := range issues {
+				deps[i], issue.Dependencies = issue.Dependencies, nil
+			}
 		}
-		if issue.Status == "tombstone" {
-			continue
+		opts := ImportOptions{
+			SkipPrefixValidation: true,
+			RenameOnImport:       importRename,
+			Strategy:             importStrategy,
+			ChooseConflict:       promptImportConflict,
+			RecordConflicts: func(conflicts []importConflict) error {
+				return recordImportConflicts(&result, conflicts)
+			},
 		}
-		if _, hasWisp := peek["wisp"]; hasWisp && !issue.Ephemeral {
-			var wisp bool
-			if err := json.Unmarshal(peek["wisp"], &wisp); err == nil && wisp {
-				issue.Ephemeral = true
+		importResult, err := importIssuesCore(ctx, "", store, issues, opts)
+		if err != nil {
+			return fmt.Errorf("import failed: %w", err)
+		}
+		result.Created += importResult.Created
+		result.Skipped += importResult.Skipped
+		result.SkippedDependencies = append(result.SkippedDependencies, importResult.SkippedDependencies...)
+		result.IDs = append(result.IDs, importResult.ImportedIDs...)
+		result.StaleSkippedIDs = append(result.StaleSkippedIDs, importResult.StaleSkippedIDs...)
+		result.RegisteredTypes = append(result.RegisteredTypes, importResult.RegisteredTypes...)
+		for oldID, newID := range importResult.IDMapping {
+			if result.Renumbered == nil {
+				result.Renumbered = make(map[string]string)
 			}
+			result.Renumbered[oldID] = newID
 		}
-		issue.SetDefaults()
-		issues = append(issues, &issue)
-	}
-	if err := scanner.Err(); err != nil {
-		return fmt.Errorf("failed to scan JSONL: %w", err)
+
+		stale := make(map[string]bool, len(importResult.StaleSkippedIDs))
+		for _, id := range importResult.StaleSkippedIDs {
+			stale[id] = true
+		}
+		for i, issue := range issues {
+			if deps == nil || stale[issue.ID] {
+				continue
+			}
+			for _, dep := range deps[i] {
+				if dep.IssueID == "" {
+					dep.IssueID = issue.ID
+				}
+				deferredDeps = append(deferredDeps, dep)
+			}
+		}
+		return nil
 	}
 
-	// Dedup: skip issues whose title matches an existing open issue
-	dedupHits := 0
-	if importDedup && len(issues) > 0 {
-		issues, dedupHits = filterDuplicatesByTitle(ctx, store, issues)
+	if !importDryRun {
+		snapshotBeforeWrite(ctx, "bd import "+filepath.Base(source))
 	}
 
-	result := importResultJSON{
-		Source:    source,
-		DedupHits: dedupHits,
-		DryRun:    importDryRun,
+	// Data from other trackers is legacy data: it predates the project's
+	// validation rules, so it is imported under the relaxed profile.
+	c := &importCollector{rules: validation.RulesFromConfig()}
+	switch importFormat {
+	case "jira", "gitlab", "taskwarrior":
+		c.rules = c.rules.Relaxed()
+	}
+	if importBatchSize > 0 {
+		c.batchSize = importBatchSize
+		c.flush = importBatch
+	}
+	var err error
+	switch importFormat {
+	case "csv":
+		err = parseCSVImport(r, c)
+	case "jira":
+		err = parseJiraImport(ctx, r, c)
+	case "gitlab":
+		err = parseGitLabImport(ctx, r, c)
+	case "taskwarrior":
+		err = parseTaskWarriorImport(ctx, r, c)
+	case "md-dir":
+		err = parseMarkdownDirImport(ctx, source, c)
+	default:
+		err = parseJSONLImport(r, c)
+	}
+	if err == nil {
+		err = c.flushErr
 	}
+	if err == nil {
+		err = importBatch(c.issues)
+	}
+	if err != nil {
+		return err
+	}
+	if importDryRun && len(c.tombstones) > 0 {
+		if result.DeletedIDs, err = localIssuesBuried(ctx, store, c.tombstones); err != nil {
+			return err
+		}
+	}
+	memories, lineErrors, repaired := c.memories, c.lineErrors, c.repaired
+	result.Repaired = repaired
+	result.Warnings = c.warnings
+	result.Errors = lineErrors
+	dedupHits := result.DedupHits
 
 	if importDryRun {
-		result.Created = len(issues)
 		result.Memories = len(memories)
-		result.Skipped = dedupHits
 		if jsonOutput {
 			outputJSON(result)
-			return nil
+			return importLineErrorsSummary(lineErrors)
 		}
-		fmt.Fprintf(os.Stderr, "Would import %d issues and %d memories from %s", len(issues), len(memories), source)
+		fmt.Fprintf(os.Stderr, "Would import %d issues and %d memories from %s", result.Created, len(memories), source)
 		if dedupHits > 0 {
 			fmt.Fprintf(os.Stderr, " (%d duplicates skipped)", dedupHits)
 		}
 		fmt.Fprintln(os.Stderr)
-		return nil
+		if len(result.DeletedIDs) > 0 {
+			fmt.Fprintf(os.Stderr, "Would delete %d issues: %s\n", len(result.DeletedIDs), strings.Join(result.DeletedIDs, ", "))
+		}
+		printImportPreview(os.Stderr, result.Preview)
+		printImportRepairs(repaired, true)
+		printImportWarnings(c.warnings)
+		printImportLineErrors(lineErrors)
+		return importLineErrorsSummary(lineErrors)
 	}
 
 	// Import memories
@@ -240,25 +555,28 @@ func runImportFromReader(ctx context.Context, r io.Reader, source string) error
 		result.Memories++
 	}
 
-	// Import issues
-	if len(issues) > 0 {
-		opts := ImportOptions{SkipPrefixValidation: true}
-		importResult, err := importIssuesCore(ctx, "", store, issues, opts)
-		if err != nil {
-			return fmt.Errorf("import failed: %w", err)
+	remapImportDependencies(deferredDeps, result.Renumbered)
+	for _, dep := range deferredDeps {
+		if err := store.AddDependency(ctx, dep, actor); err != nil {
+			result.SkippedDependencies = append(result.SkippedDependencies, fmt.Sprintf("%s -> %s: %v", dep.IssueID, dep.DependsOnID, err))
 		}
-		result.Created = importResult.Created
-		result.Skipped += importResult.Skipped
-		result.SkippedDependencies = append(result.SkippedDependencies, importResult.SkippedDependencies...)
-		result.IDs = append(result.IDs, importResult.ImportedIDs...)
-		result.StaleSkippedIDs = append(result.StaleSkippedIDs, importResult.StaleSkippedIDs...)
+	}
+	if len(c.deps) > 0 {
+		result.SkippedDependencies = append(result.SkippedDependencies, linkExternalDependencies(ctx, c.deps)...)
+	}
+
+	if result.DeletedIDs, err = applyTombstones(ctx, store, c.tombstones); err != nil {
+		return err
 	}
 
-	if result.Created > 0 || result.Memories > 0 {
+	if result.Created > 0 || result.Memories > 0 || len(c.tombstones) > 0 {
 		commitMsg := fmt.Sprintf("bd import: %d issues", result.Created)
 		if result.Memories > 0 {
 			commitMsg += fmt.Sprintf(", %d memories", result.Memories)
 		}
+		if len(result.DeletedIDs) > 0 {
+			commitMsg += fmt.Sprintf(", %d deletions", len(result.DeletedIDs))
+		}
 		commitMsg += fmt.Sprintf(" from %s", filepath.Base(source))
 		if err := store.Commit(ctx, commitMsg); err != nil {
 			return fmt.Errorf("commit: %w", err)
@@ -267,7 +585,7 @@ func runImportFromReader(ctx context.Context, r io.Reader, source string) error
 
 	if jsonOutput {
 		outputJSON(result)
-		return nil
+		return importLineErrorsSummary(lineErrors)
 	}
 
 	fmt.Fprintf(os.Stderr, "Imported %d issues", result.Created)
@@ -278,16 +596,245 @@ func runImportFromReader(ctx context.Context, r io.Reader, source string) error
 	if dedupHits > 0 {
 		fmt.Fprintf(os.Stderr, " (%d duplicates skipped)", dedupHits)
 	}
-	if staleSkipped := result.Skipped - dedupHits; staleSkipped > 0 {
+	if len(result.TombstonedIDs) > 0 {
+		fmt.Fprintf(os.Stderr, " (%d deleted issues skipped)", len(result.TombstonedIDs))
+	}
+	if staleSkipped := result.Skipped - dedupHits - len(result.TombstonedIDs); staleSkipped > 0 {
 		fmt.Fprintf(os.Stderr, " (%d stale skipped)", staleSkipped)
 	}
 	fmt.Fprintln(os.Stderr)
+	if len(result.DeletedIDs) > 0 {
+		fmt.Fprintf(os.Stderr, "Deleted %d issues with deletion records: %s\n", len(result.DeletedIDs), strings.Join(result.DeletedIDs, ", "))
+	}
+	if len(result.ConflictIDs) > 0 {
+		fmt.Fprintf(os.Stderr, "Resolved %d conflicts (--strategy %s); the versions not kept are in %s\n",
+			len(result.ConflictIDs), importStrategy, result.ConflictsFile)
+		if !importRename {
+			fmt.Fprintf(os.Stderr, "  Use --rename-on-import to import issues whose IDs collide as new issues\n")
+		}
+	}
+	if len(result.Renumbered) > 0 {
+		renumbered := make([]string, 0, len(result.Renumbered))
+		for oldID, newID := range result.Renumbered {
+			renumbered = append(renumbered, oldID+" → "+newID)
+		}
+		sort.Strings(renumbered)
+		fmt.Fprintf(os.Stderr, "Renumbered %d issues whose IDs were taken by different local issues: %s\n",
+			len(renumbered), strings.Join(renumbered, ", "))
+	}
+	if len(result.RegisteredTypes) > 0 {
+		fmt.Fprintf(os.Stderr, "Registered custom issue types: %s\n", strings.Join(result.RegisteredTypes, ", "))
+	}
 	for _, skipped := range result.SkippedDependencies {
 		fmt.Fprintf(os.Stderr, "Skipped dependency: %s\n", skipped)
 	}
+	printImportRepairs(repaired, false)
+	printImportWarnings(c.warnings)
+	printImportLineErrors(lineErrors)
+	return importLineErrorsSummary(lineErrors)
+}
+
+// recordImportConflicts appends the losing side of each conflict to
+// .beads/conflicts.jsonl and notes the conflicts in result.
+func recordImportConflicts(result *importResultJSON, conflicts []importConflict) error {
+	if len(conflicts) == 0 {
+		return nil
+	}
+	beadsDir := beads.FindBeadsDir()
+	if beadsDir == "" {
+		return fmt.Errorf("cannot save %d import conflicts: no .beads directory", len(conflicts))
+	}
+	now := time.Now()
+	records := make([]importConflictRecord, 0, len(conflicts))
+	for _, c := range conflicts {
+		records = append(records, newImportConflictRecord(c, importStrategy, result.Source, now))
+		result.ConflictIDs = append(result.ConflictIDs, c.ID)
+	}
+	path, err := appendImportConflicts(beadsDir, records)
+	if err != nil {
+		return err
+	}
+	result.ConflictsFile = path
+	return nil
+}
+
+// importCollector accumulates the records parsed from an import source,
+// along with per-line errors and --repair notes, so every input format
+// applies the same validation.
+type importCollector struct {
+	issues     []*types.Issue
+	memories   []memoryRecord
+	tombstones []tombstone
+	lineErrors []importLineError
+	repaired   []importRepairNote
+	warnings   []string
+
+	// rules are the configured validation rules checked on each issue;
+	// parseJSONLImport relaxes them for records in an older format.
+	rules validation.Rules
+
+	// deps are links between issues identified by external_ref, created
+	// once the issues themselves have been imported.
+	deps []tracker.DependencyInfo
+
+	// When batchSize is positive, addIssue hands every batchSize issues to
+	// flush instead of keeping them. The first flush error stops further
+	// flushes and is kept in flushErr.
+	batchSize int
+	flush     func([]*types.Issue) error
+	flushErr  error
+}
+
+func (c *importCollector) fail(line int, format string, args ...interface{}) {
+	c.lineErrors = append(c.lineErrors, importLineError{Line: line, Error: fmt.Sprintf(format, args...)})
+}
+
+// addIssue validates a decoded issue and queues it for import.
+func (c *importCollector) addIssue(line int, issue *types.Issue) {
+	c.addIssueWithRules(line, issue, c.rules)
+}
+
+// addIssueWithRules is addIssue checking the issue against rules instead of
+// c.rules.
+func (c *importCollector) addIssueWithRules(line int, issue *types.Issue, rules validation.Rules) {
+	if issue.Status == "tombstone" {
+		return
+	}
+	if importRepair {
+		if repairs := repairImportIssue(issue); len(repairs) > 0 {
+			c.repaired = append(c.repaired, importRepairNote{ID: issue.ID, Repairs: repairs})
+		}
+	}
+	if strings.TrimSpace(issue.Title) == "" {
+		if issue.ID != "" {
+			c.fail(line, "issue %s: title is required", issue.ID)
+		} else {
+			c.fail(line, "title is required")
+		}
+		return
+	}
+	issue.SetDefaults()
+	if err := rules.Check(issue); err != nil {
+		if rules.Strictness != validation.StrictnessWarn {
+			c.fail(line, "issue %s: %v", issue.ID, err)
+			return
+		}
+		c.warnings = append(c.warnings, fmt.Sprintf("issue %s: %v", issue.ID, err))
+	}
+	c.issues = append(c.issues, issue)
+	if c.batchSize > 0 && len(c.issues) >= c.batchSize && c.flushErr == nil {
+		c.flushErr = c.flush(c.issues)
+		c.issues = nil
+	}
+}
+
+// parseJSONLImport reads newline-delimited issue and memory records into c.
+func parseJSONLImport(r io.Reader, c *importCollector) error {
+	scanner := bufio.NewScanner(r)
+	scanner.Buffer(make([]byte, 0, 1024*1024), 64*1024*1024)
+
+	lineNo := 0
+	fileVersion := 0
+	warnedNewer := false
+	for scanner.Scan() {
+		lineNo++
+		line := scanner.Text()
+		if strings.TrimSpace(line) == "" {
+			continue
+		}
+
+		var peek map[string]json.RawMessage
+		if err := json.Unmarshal([]byte(line), &peek); err != nil {
+			c.fail(lineNo, "invalid JSON: %v", err)
+			continue
+		}
+		// Skip the optional beads-jsonl header record (see parseJSONLFile).
+		if _, isHeader := peek["_schema"]; isHeader {
+			fileVersion = jsonlHeaderFormat(peek)
+			continue
+		}
+		format := jsonlFormatOf(peek, fileVersion)
+		if format > jsonlFormatVersion && !warnedNewer {
+			c.warnings = append(c.warnings, jsonlNewerFormatWarning(format))
+			warnedNewer = true
+		}
+
+		if rawType, ok := peek["_type"]; ok {
+			var typeStr string
+			_ = json.Unmarshal(rawType, &typeStr)
+			if typeStr == "deletion" {
+				// Written by 'bd export' for deleted issues. Records
+				// without deleted_at predate tombstones and are skipped.
+				if t, ok := parseJSONLDeletion([]byte(line)); ok {
+					c.tombstones = append(c.tombstones, t)
+				}
+				continue
+			}
+			if typeStr == "memory" {
+				var mem memoryRecord
+				if err := json.Unmarshal([]byte(line), &mem); err != nil {
+					c.fail(lineNo, "invalid memory record: %v", err)
+					continue
+				}
+				if mem.Key != "" && mem.Value != "" {
+					c.memories = append(c.memories, mem)
+				}
+				continue
+			}
+		}
+
+		var issue types.Issue
+		if err := json.Unmarshal([]byte(line), &issue); err != nil {
+			c.fail(lineNo, "invalid issue record: %v", err)
+			continue
+		}
+		upgradeJSONLIssue(&issue, peek, format)
+		rules := c.rules
+		if format < jsonlFormatVersion {
+			rules = rules.Relaxed()
+		}
+		c.addIssueWithRules(lineNo, &issue, rules)
+		if c.flushErr != nil {
+			return c.flushErr
+		}
+	}
+	if err := scanner.Err(); err != nil {
+		return fmt.Errorf("failed to scan JSONL: %w", err)
+	}
 	return nil
 }
 
+func printImportRepairs(repaired []importRepairNote, dryRun bool) {
+	verb := "Repaired"
+	if dryRun {
+		verb = "Would repair"
+	}
+	for _, note := range repaired {
+		fmt.Fprintf(os.Stderr, "%s %s: %s\n", verb, note.ID, strings.Join(note.Repairs, "; "))
+	}
+}
+
+func printImportWarnings(warnings []string) {
+	for _, w := range warnings {
+		fmt.Fprintf(os.Stderr, "Warning: %s\n", w)
+	}
+}
+
+func printImportLineErrors(lineErrors []importLineError) {
+	for _, le := range lineErrors {
+		fmt.Fprintf(os.Stderr, "Line %d: %s\n", le.Line, le.Error)
+	}
+}
+
+// importLineErrorsSummary turns per-line failures into the command's exit
+// error once everything importable has been imported.
+func importLineErrorsSummary(lineErrors []importLineError) error {
+	if len(lineErrors) == 0 {
+		return nil
+	}
+	return fmt.Errorf("%d line(s) could not be imported", len(lineErrors))
+}
+
 // filterDuplicatesByTitle removes issues whose title matches an existing open issue.
 func filterDuplicatesByTitle(ctx context.Context, st storage.DoltStorage, issues []*types.Issue) ([]*types.Issue, int) {
 	existing, err := st.SearchIssues(ctx, "", types.IssueFilter{})
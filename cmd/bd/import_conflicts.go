@@ -0,0 +1,128 @@
+package main
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// Import conflict strategies, chosen with 'bd import --strategy'. A conflict
+// is an incoming issue whose ID exists locally with different content (see
+// types.Issue.ComputeContentHash).
+const (
+	importStrategyNewest      = "newest"      // keep whichever copy has the later updated_at
+	importStrategyLocal       = "local"       // always keep the local copy
+	importStrategyRemote      = "remote"      // always take the incoming copy
+	importStrategyInteractive = "interactive" // ask for each conflict
+)
+
+// importConflictsFileName is the sidecar in .beads/ that keeps the losing
+// version of every import conflict, one JSON record per line.
+const importConflictsFileName = "conflicts.jsonl"
+
+func validImportStrategy(strategy string) bool {
+	switch strategy {
+	case importStrategyNewest, importStrategyLocal, importStrategyRemote, importStrategyInteractive:
+		return true
+	}
+	return false
+}
+
+// importConflict is one conflict an import resolved. Loser is the copy that
+// was not kept: the local issue when KeptLocal is false, else the incoming one.
+type importConflict struct {
+	ID        string
+	KeptLocal bool
+	Loser     *types.Issue
+}
+
+// importIncomingIsOlder reports whether incoming was last updated before
+// local. Incoming issues without updated_at are never older.
+func importIncomingIsOlder(local, incoming *types.Issue) bool {
+	return !incoming.UpdatedAt.IsZero() && incoming.UpdatedAt.UTC().Before(local.UpdatedAt.UTC())
+}
+
+// importKeepsLocal decides a conflict for the non-interactive strategies.
+func importKeepsLocal(strategy string, local, incoming *types.Issue) bool {
+	switch strategy {
+	case importStrategyLocal:
+		return true
+	case importStrategyRemote:
+		return false
+	default:
+		return importIncomingIsOlder(local, incoming)
+	}
+}
+
+// importConflictRecord is one line of .beads/conflicts.jsonl.
+type importConflictRecord struct {
+	ID         string       `json:"id"`
+	Strategy   string       `json:"strategy"`
+	Kept       string       `json:"kept"` // "local" or "incoming"
+	Source     string       `json:"source,omitempty"`
+	RecordedAt time.Time    `json:"recorded_at"`
+	Issue      *types.Issue `json:"issue"` // the version that was not kept
+}
+
+func newImportConflictRecord(c importConflict, strategy, source string, now time.Time) importConflictRecord {
+	kept := "incoming"
+	if c.KeptLocal {
+		kept = "local"
+	}
+	return importConflictRecord{ID: c.ID, Strategy: strategy, Kept: kept, Source: source, RecordedAt: now.UTC(), Issue: c.Loser}
+}
+
+// appendImportConflicts adds records to the conflicts sidecar in beadsDir and
+// returns its path.
+func appendImportConflicts(beadsDir string, records []importConflictRecord) (string, error) {
+	path := filepath.Join(beadsDir, importConflictsFileName)
+	f, err := os.OpenFile(path, os.O_CREATE|os.O_APPEND|os.O_WRONLY, 0o600) // #nosec G304 -- path is inside the beads directory
+	if err != nil {
+		return path, fmt.Errorf("opening %s: %w", importConflictsFileName, err)
+	}
+	w := bufio.NewWriter(f)
+	enc := json.NewEncoder(w)
+	for _, rec := range records {
+		if err := enc.Encode(rec); err != nil {
+			_ = f.Close()
+			return path, fmt.Errorf("writing %s: %w", importConflictsFileName, err)
+		}
+	}
+	if err := w.Flush(); err != nil {
+		_ = f.Close()
+		return path, fmt.Errorf("writing %s: %w", importConflictsFileName, err)
+	}
+	return path, f.Close()
+}
+
+// promptImportConflict asks on the terminal which copy of a conflicting
+// issue to keep. Used by --strategy interactive.
+func promptImportConflict(local, incoming *types.Issue) bool {
+	fmt.Fprintf(os.Stderr, "\nConflict on %s (local updated %s, incoming updated %s):\n", local.ID,
+		local.UpdatedAt.UTC().Format(time.RFC3339), incoming.UpdatedAt.UTC().Format(time.RFC3339))
+	for _, c := range diffImportIssue(local, incoming) {
+		fmt.Fprintf(os.Stderr, "    %s: %s → %s\n", c.Field, previewValue(c.Old), previewValue(c.New))
+	}
+	reader := bufio.NewReader(os.Stdin)
+	for {
+		fmt.Fprintf(os.Stderr, "Keep [l]ocal or [i]ncoming? ")
+		line, err := reader.ReadString('\n')
+		switch strings.TrimSpace(strings.ToLower(line)) {
+		case "l", "local":
+			return true
+		case "i", "incoming", "r", "remote":
+			return false
+		}
+		if err != nil {
+			// No answer (EOF): keep what is already in the database.
+			fmt.Fprintln(os.Stderr)
+			return true
+		}
+	}
+}
@@ -111,6 +111,34 @@ func TestEmbeddedImport(t *testing.T) {
 		}
 	})
 
+	t.Run("bad_lines_reported_not_fatal", func(t *testing.T) {
+		dir, _, _ := bdInit(t, bd, "--prefix", "imbad")
+
+		jsonlPath := filepath.Join(t.TempDir(), "import.jsonl")
+		content := strings.Join([]string{
+			`{"id":"imbad-aaa","title":"Good A","status":"open","issue_type":"task"}`,
+			`{"id":"imbad-broken",`,
+			`{"id":"imbad-notitle","status":"open"}`,
+			`{"id":"imbad-bbb","title":"Good B","status":"open","issue_type":"task"}`,
+		}, "\n") + "\n"
+		if err := os.WriteFile(jsonlPath, []byte(content), 0644); err != nil {
+			t.Fatalf("write JSONL: %v", err)
+		}
+
+		cmd := exec.Command(bd, "import", jsonlPath)
+		cmd.Dir = dir
+		cmd.Env = bdEnv(dir)
+		out, err := cmd.CombinedOutput()
+		if err == nil {
+			t.Fatalf("expected non-zero exit for bad lines, got success:\n%s", out)
+		}
+		for _, want := range []string{"Imported 2 issues", "Line 2: invalid JSON", "Line 3: issue imbad-notitle: title is required"} {
+			if !strings.Contains(string(out), want) {
+				t.Errorf("expected %q in output:\n%s", want, out)
+			}
+		}
+	})
+
 	t.Run("dry_run", func(t *testing.T) {
 		dir, _, _ := bdInit(t, bd, "--prefix", "imdry")
 
@@ -179,6 +207,27 @@ func TestEmbeddedImport(t *testing.T) {
 		}
 	})
 
+	t.Run("from_dev_stdin_pipe", func(t *testing.T) {
+		dir, _, _ := bdInit(t, bd, "--prefix", "impipe")
+
+		// A pipe stats as size 0; it must still be read, and every
+		// message must go to stderr so stdout stays clean.
+		cmd := exec.Command(bd, "import", "-i", "/dev/stdin")
+		cmd.Dir = dir
+		cmd.Env = bdEnv(dir)
+		cmd.Stdin = strings.NewReader(`{"id":"impipe-aaa","title":"Piped","status":"open","issue_type":"task"}` + "\n")
+		stdout, stderr, err := runCommandBuffers(t, cmd)
+		if err != nil {
+			t.Fatalf("bd import -i /dev/stdin failed: %v\nstderr:\n%s", err, stderr.String())
+		}
+		if !strings.Contains(stderr.String(), "Imported 1 issue") {
+			t.Errorf("expected 'Imported 1 issue' on stderr, got: %s", stderr.String())
+		}
+		if stdout.Len() != 0 {
+			t.Errorf("expected nothing on stdout, got: %s", stdout.String())
+		}
+	})
+
 	t.Run("upsert_existing", func(t *testing.T) {
 		dir, _, _ := bdInit(t, bd, "--prefix", "imups")
 
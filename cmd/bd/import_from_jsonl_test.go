@@ -692,3 +692,49 @@ func TestImportFromLocalJSONL_LegacyFormats(t *testing.T) {
 		}
 	})
 }
+
+func TestImportIssuesCore_RenameOnImport(t *testing.T) {
+	skipIfNoDolt(t)
+
+	tmpDir := t.TempDir()
+	store := newTestStore(t, filepath.Join(tmpDir, "dolt"))
+	ctx := context.Background()
+
+	base := time.Date(2025, 1, 1, 0, 0, 0, 0, time.UTC)
+	local := &types.Issue{ID: "test-abc123", Title: "Local issue", Status: types.StatusOpen, Priority: 2,
+		IssueType: types.TypeTask, CreatedAt: base, UpdatedAt: base}
+	if err := store.CreateIssue(ctx, local, "tester"); err != nil {
+		t.Fatalf("CreateIssue: %v", err)
+	}
+
+	// Another clone has a different test-abc123 and an issue that depends on it.
+	incoming := []*types.Issue{
+		{ID: "test-abc123", Title: "Remote issue", Status: types.StatusOpen, Priority: 1,
+			IssueType: types.TypeBug, CreatedAt: base, UpdatedAt: base.Add(time.Hour)},
+		{ID: "test-def456", Title: "Remote dependent", Status: types.StatusOpen, Priority: 2,
+			IssueType: types.TypeTask, CreatedAt: base, UpdatedAt: base,
+			Dependencies: []*types.Dependency{{IssueID: "test-def456", DependsOnID: "test-abc123", Type: types.DepBlocks}}},
+	}
+	result, err := importIssuesCore(ctx, "", store, incoming, ImportOptions{SkipPrefixValidation: true, RenameOnImport: true})
+	if err != nil {
+		t.Fatalf("importIssuesCore: %v", err)
+	}
+
+	newID := result.IDMapping["test-abc123"]
+	if newID == "" || newID == "test-abc123" {
+		t.Fatalf("IDMapping = %v, want test-abc123 renamed", result.IDMapping)
+	}
+	if got, err := store.GetIssue(ctx, "test-abc123"); err != nil || got.Title != "Local issue" {
+		t.Errorf("local test-abc123 = %+v, %v; want it untouched", got, err)
+	}
+	if got, err := store.GetIssue(ctx, newID); err != nil || got.Title != "Remote issue" {
+		t.Errorf("%s = %+v, %v; want the renamed incoming issue", newID, got, err)
+	}
+	records, err := store.GetDependencyRecords(ctx, "test-def456")
+	if err != nil {
+		t.Fatalf("GetDependencyRecords: %v", err)
+	}
+	if len(records) != 1 || records[0].DependsOnID != newID {
+		t.Errorf("test-def456 dependencies = %+v, want one on %s", records, newID)
+	}
+}
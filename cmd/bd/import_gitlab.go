@@ -0,0 +1,104 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+
+	"github.com/steveyegge/beads/internal/gitlab"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/tracker"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// parseGitLabImport reads issues saved from the GitLab issues API into c.
+// Each issue's external_ref is its web URL (which carries the IID), the same
+// ref 'bd gitlab sync' uses. Milestones become epics with the milestone URL
+// as external_ref, and their issues become children of the epic, mirroring
+// how push turns epics into milestones. Issues are reported by their
+// position in the input, since JSON has no meaningful line numbers.
+func parseGitLabImport(ctx context.Context, r io.Reader, c *importCollector) error {
+	glIssues, err := gitlab.ReadIssuesJSON(r)
+	if err != nil {
+		return err
+	}
+	config := gitlab.DefaultMappingConfig()
+
+	lookup := func(ref string) (*types.Issue, error) {
+		existing, err := store.GetIssueByExternalRef(ctx, ref)
+		if err != nil && !errors.Is(err, storage.ErrNotFound) {
+			return nil, fmt.Errorf("failed to look up %s: %w", ref, err)
+		}
+		return existing, nil
+	}
+
+	milestones := make(map[string]bool)
+	for i := range glIssues {
+		gl := &glIssues[i]
+		pos := i + 1
+		if gl.IID == 0 {
+			c.fail(pos, "issue has no iid; expected GitLab issues API JSON")
+			continue
+		}
+		ref := gitlab.IssueExternalRef(gl)
+		issue := gitlab.GitLabIssueToBeads(gl, config).Issue
+		issue.ExternalRef = &ref
+
+		existing, err := lookup(ref)
+		if err != nil {
+			return err
+		}
+		if existing != nil {
+			issue = mergeGitLabIssue(existing, issue)
+		}
+		c.addIssue(pos, issue)
+
+		ms := gl.Milestone
+		if ms == nil || ms.WebURL == "" {
+			continue
+		}
+		if !milestones[ms.WebURL] {
+			milestones[ms.WebURL] = true
+			epic := gitlab.MilestoneToEpic(ms)
+			existing, err := lookup(ms.WebURL)
+			if err != nil {
+				return err
+			}
+			if existing != nil {
+				epic = mergeGitLabIssue(existing, epic)
+			}
+			c.addIssue(pos, epic)
+		}
+		c.deps = append(c.deps, tracker.DependencyInfo{
+			FromExternalID: ref,
+			ToExternalID:   ms.WebURL,
+			Type:           string(types.DepParentChild),
+			Source:         tracker.DependencySourceParent,
+		})
+	}
+	return nil
+}
+
+// mergeGitLabIssue overlays the fields a GitLab issue or milestone provides
+// onto a copy of the local issue linked to it.
+func mergeGitLabIssue(local, incoming *types.Issue) *types.Issue {
+	merged := *local
+	merged.Title = incoming.Title
+	merged.Description = incoming.Description
+	merged.Status = incoming.Status
+	merged.ClosedAt = incoming.ClosedAt
+	merged.DueAt = incoming.DueAt
+	merged.SourceSystem = incoming.SourceSystem
+	if incoming.IssueType != types.TypeEpic {
+		merged.IssueType = incoming.IssueType
+		merged.Priority = incoming.Priority
+		merged.Assignee = incoming.Assignee
+		merged.Labels = incoming.Labels
+		merged.EstimatedMinutes = incoming.EstimatedMinutes
+	}
+	if !incoming.UpdatedAt.IsZero() {
+		merged.UpdatedAt = incoming.UpdatedAt
+	}
+	return &merged
+}
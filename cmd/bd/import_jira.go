@@ -0,0 +1,130 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+
+	"github.com/steveyegge/beads/internal/jira"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/tracker"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// parseJiraImport reads a Jira CSV export into c. Each issue's external_ref
+// is its Jira browse URL (or bare key when jira.url is not configured), the
+// same ref 'bd jira sync' uses, so later syncs match the imported issues.
+// Issues already linked to Jira are updated in place, keeping the local
+// fields a Jira export doesn't carry.
+func parseJiraImport(ctx context.Context, r io.Reader, c *importCollector) error {
+	allConfig, err := store.GetAllConfig(ctx)
+	if err != nil {
+		return fmt.Errorf("failed to read config: %w", err)
+	}
+	mapper, err := jira.NewFieldMapper(allConfig)
+	if err != nil {
+		return fmt.Errorf("invalid Jira mapping config: %w", err)
+	}
+	jiraURL := allConfig["jira.url"]
+	if jiraURL == "" {
+		jiraURL = os.Getenv("JIRA_URL")
+	}
+
+	rows, deps, err := jira.ReadCSVExport(r, mapper)
+	if err != nil {
+		return err
+	}
+	for _, row := range rows {
+		if row.Err != nil {
+			if row.Key != "" {
+				c.fail(row.Line, "%s: %v", row.Key, row.Err)
+			} else {
+				c.fail(row.Line, "%v", row.Err)
+			}
+			continue
+		}
+		ref := jira.BrowseURL(jiraURL, row.Key)
+		issue := row.Issue
+		issue.ExternalRef = &ref
+
+		existing, err := store.GetIssueByExternalRef(ctx, ref)
+		if err != nil && !errors.Is(err, storage.ErrNotFound) {
+			return fmt.Errorf("failed to look up %s: %w", ref, err)
+		}
+		if existing != nil {
+			issue = mergeJiraIssue(existing, issue)
+		}
+		c.addIssue(row.Line, issue)
+	}
+
+	for _, dep := range deps {
+		dep.FromExternalID = jira.BrowseURL(jiraURL, dep.FromExternalID)
+		dep.ToExternalID = jira.BrowseURL(jiraURL, dep.ToExternalID)
+		c.deps = append(c.deps, dep)
+	}
+	return nil
+}
+
+// mergeJiraIssue overlays the fields a Jira export provides onto a copy of
+// the local issue linked to it.
+func mergeJiraIssue(local, incoming *types.Issue) *types.Issue {
+	merged := *local
+	merged.Title = incoming.Title
+	merged.Description = incoming.Description
+	merged.Status = incoming.Status
+	merged.Priority = incoming.Priority
+	merged.IssueType = incoming.IssueType
+	merged.Owner = incoming.Owner
+	merged.Labels = incoming.Labels
+	merged.DueAt = incoming.DueAt
+	merged.ClosedAt = incoming.ClosedAt
+	merged.CloseReason = incoming.CloseReason
+	if !incoming.UpdatedAt.IsZero() {
+		merged.UpdatedAt = incoming.UpdatedAt
+	}
+	return &merged
+}
+
+// linkExternalDependencies creates dependencies whose ends are identified by
+// external_ref, once the issues they connect have been imported. Links to
+// issues that are not in the database are skipped and reported, in the same
+// "from -> to: reason" form as other skipped import dependencies.
+func linkExternalDependencies(ctx context.Context, deps []tracker.DependencyInfo) []string {
+	resolved := make(map[string]string)
+	resolve := func(ref string) (string, error) {
+		if id, ok := resolved[ref]; ok {
+			return id, nil
+		}
+		issue, err := store.GetIssueByExternalRef(ctx, ref)
+		if err != nil {
+			return "", err
+		}
+		resolved[ref] = issue.ID
+		return issue.ID, nil
+	}
+
+	var skipped []string
+	for _, dep := range deps {
+		fromID, err := resolve(dep.FromExternalID)
+		if err != nil {
+			skipped = append(skipped, fmt.Sprintf("%s -> %s: %v", dep.FromExternalID, dep.ToExternalID, err))
+			continue
+		}
+		toID, err := resolve(dep.ToExternalID)
+		if err != nil {
+			skipped = append(skipped, fmt.Sprintf("%s -> %s: %v", dep.FromExternalID, dep.ToExternalID, err))
+			continue
+		}
+		d := &types.Dependency{
+			IssueID:     fromID,
+			DependsOnID: toID,
+			Type:        types.DependencyType(dep.Type),
+		}
+		if err := store.AddDependency(ctx, d, actor); err != nil {
+			skipped = append(skipped, fmt.Sprintf("%s -> %s: %v", fromID, toID, err))
+		}
+	}
+	return skipped
+}
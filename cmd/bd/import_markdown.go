@@ -0,0 +1,249 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"gopkg.in/yaml.v3"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/validation"
+)
+
+// markdownFrontmatter is the YAML block at the top of an issue file read by
+// 'bd import --format md-dir'. Keys it does not list are ignored, so the
+// same files can carry front matter for a static site generator.
+type markdownFrontmatter struct {
+	ID          string         `yaml:"id"`
+	Title       string         `yaml:"title"`
+	Status      string         `yaml:"status"`
+	Priority    string         `yaml:"priority"`
+	Type        string         `yaml:"type"`
+	Assignee    string         `yaml:"assignee"`
+	Labels      yamlStringList `yaml:"labels"`
+	Parent      string         `yaml:"parent"`
+	DependsOn   yamlStringList `yaml:"depends_on"`
+	Due         string         `yaml:"due"`
+	DeferUntil  string         `yaml:"defer_until"`
+	ExternalRef string         `yaml:"external_ref"`
+}
+
+// yamlStringList accepts either a YAML sequence or one comma-separated
+// string ("labels: auth, ui").
+type yamlStringList []string
+
+func (l *yamlStringList) UnmarshalYAML(node *yaml.Node) error {
+	if node.Kind == yaml.SequenceNode {
+		var list []string
+		if err := node.Decode(&list); err != nil {
+			return err
+		}
+		*l = list
+		return nil
+	}
+	var joined string
+	if err := node.Decode(&joined); err != nil {
+		return err
+	}
+	*l = parseStringList(joined)
+	return nil
+}
+
+// splitMarkdownFrontmatter splits a file into its YAML front matter and
+// body. ok is false when the file does not start with a "---" block.
+func splitMarkdownFrontmatter(data []byte) (front []byte, body string, ok bool) {
+	text := strings.ReplaceAll(string(data), "\r\n", "\n")
+	if !strings.HasPrefix(text, "---\n") {
+		return nil, text, false
+	}
+	lines := strings.SplitAfter(text[len("---\n"):], "\n")
+	for i, line := range lines {
+		if l := strings.TrimSuffix(line, "\n"); l == "---" || l == "..." {
+			return []byte(strings.Join(lines[:i], "")), strings.Join(lines[i+1:], ""), true
+		}
+	}
+	return nil, text, false
+}
+
+// markdownFileToIssue converts one issue file. The title comes from the
+// front matter, else the first "# " heading (which is then left out of the
+// description), else the file name.
+func markdownFileToIssue(name string, data []byte) (*types.Issue, *markdownFrontmatter, error) {
+	front, body, ok := splitMarkdownFrontmatter(data)
+	if !ok {
+		return nil, nil, nil
+	}
+	var fm markdownFrontmatter
+	if err := yaml.Unmarshal(front, &fm); err != nil {
+		return nil, nil, fmt.Errorf("front matter: %w", err)
+	}
+
+	body = strings.TrimSpace(body)
+	title := strings.TrimSpace(fm.Title)
+	if title == "" {
+		if first, rest, _ := strings.Cut(body, "\n"); strings.HasPrefix(first, "# ") {
+			title = strings.TrimSpace(first[2:])
+			body = strings.TrimSpace(rest)
+		}
+	}
+	if title == "" {
+		title = strings.TrimSuffix(filepath.Base(name), filepath.Ext(name))
+	}
+
+	issue := &types.Issue{
+		ID:          strings.TrimSpace(fm.ID),
+		Title:       title,
+		Description: body,
+		Status:      types.Status(fm.Status),
+		IssueType:   types.IssueType(fm.Type),
+		Assignee:    fm.Assignee,
+		Labels:      fm.Labels,
+		Priority:    2,
+	}
+	if fm.Priority != "" {
+		p := validation.ParsePriority(fm.Priority)
+		if p < 0 {
+			return nil, nil, fmt.Errorf("invalid priority %q", fm.Priority)
+		}
+		issue.Priority = p
+	}
+	if fm.ExternalRef != "" {
+		issue.ExternalRef = &fm.ExternalRef
+	}
+	for _, f := range []struct {
+		key   string
+		value string
+		dst   **time.Time
+	}{
+		{"due", fm.Due, &issue.DueAt},
+		{"defer_until", fm.DeferUntil, &issue.DeferUntil},
+	} {
+		if f.value == "" {
+			continue
+		}
+		t, err := parseTimeFlag(f.value)
+		if err != nil {
+			return nil, nil, fmt.Errorf("invalid %s %q: %w", f.key, f.value, err)
+		}
+		*f.dst = &t
+	}
+	if fm.Parent != "" {
+		issue.Dependencies = append(issue.Dependencies, &types.Dependency{IssueID: issue.ID, DependsOnID: fm.Parent, Type: types.DepParentChild})
+	}
+	for _, id := range fm.DependsOn {
+		issue.Dependencies = append(issue.Dependencies, &types.Dependency{IssueID: issue.ID, DependsOnID: id, Type: types.DepBlocks})
+	}
+	return issue, &fm, nil
+}
+
+// parseMarkdownDirImport reads every .md file under dir that starts with
+// YAML front matter into c; other files (a README, an index) are skipped.
+// A file with an id updates that issue. A file without one is tied to its
+// issue by external_ref "md:<path>", so re-importing the directory updates
+// the issues it created instead of duplicating them. Errors are reported by
+// the file's position in path order.
+func parseMarkdownDirImport(ctx context.Context, dir string, c *importCollector) error {
+	var paths []string
+	err := filepath.WalkDir(dir, func(path string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+		if d.IsDir() && path != dir && strings.HasPrefix(d.Name(), ".") {
+			return filepath.SkipDir
+		}
+		if !d.IsDir() && strings.EqualFold(filepath.Ext(path), ".md") {
+			paths = append(paths, path)
+		}
+		return nil
+	})
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", dir, err)
+	}
+
+	for i, path := range paths {
+		pos := i + 1
+		rel, _ := filepath.Rel(dir, path)
+		rel = filepath.ToSlash(rel)
+		data, err := os.ReadFile(path) //nolint:gosec // G304: file under the directory given on the command line
+		if err != nil {
+			return fmt.Errorf("failed to read %s: %w", path, err)
+		}
+		issue, fm, err := markdownFileToIssue(rel, data)
+		if err != nil {
+			c.fail(pos, "%s: %v", rel, err)
+			continue
+		}
+		if issue == nil {
+			continue
+		}
+
+		var existing *types.Issue
+		if issue.ID != "" {
+			existing, err = store.GetIssue(ctx, issue.ID)
+		} else {
+			if issue.ExternalRef == nil {
+				ref := "md:" + rel
+				issue.ExternalRef = &ref
+			}
+			existing, err = store.GetIssueByExternalRef(ctx, *issue.ExternalRef)
+		}
+		if err != nil && !errors.Is(err, storage.ErrNotFound) {
+			return fmt.Errorf("failed to look up %s: %w", rel, err)
+		}
+		if existing != nil {
+			issue = mergeMarkdownIssue(existing, issue, fm)
+		}
+		if issue.Status == types.StatusClosed && issue.ClosedAt == nil {
+			now := time.Now().UTC()
+			issue.ClosedAt = &now
+		}
+		c.addIssue(pos, issue)
+	}
+	return nil
+}
+
+// mergeMarkdownIssue overlays what an issue file says onto a copy of the
+// local issue: the title and description always, other fields only when
+// the front matter sets them. Labels and dependencies are added by the
+// import itself.
+func mergeMarkdownIssue(local, incoming *types.Issue, fm *markdownFrontmatter) *types.Issue {
+	merged := *local
+	merged.Title = incoming.Title
+	merged.Description = incoming.Description
+	merged.Labels = incoming.Labels
+	merged.Dependencies = incoming.Dependencies
+	for _, dep := range merged.Dependencies {
+		dep.IssueID = local.ID
+	}
+	if fm.Status != "" && incoming.Status != local.Status {
+		merged.Status = incoming.Status
+		merged.ClosedAt = nil
+	}
+	if fm.Priority != "" {
+		merged.Priority = incoming.Priority
+	}
+	if fm.Type != "" {
+		merged.IssueType = incoming.IssueType
+	}
+	if fm.Assignee != "" {
+		merged.Assignee = incoming.Assignee
+	}
+	if fm.Due != "" {
+		merged.DueAt = incoming.DueAt
+	}
+	if fm.DeferUntil != "" {
+		merged.DeferUntil = incoming.DeferUntil
+	}
+	if fm.ExternalRef != "" {
+		merged.ExternalRef = incoming.ExternalRef
+	}
+	return &merged
+}
+
@@ -0,0 +1,43 @@
+package main
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestMarkdownFileToIssue(t *testing.T) {
+	data := "---\r\nid: bd-42\r\nstatus: in_progress\r\npriority: P1\r\ntype: bug\r\nlabels: auth, ui\r\nparent: bd-40\r\ndepends_on: [bd-41]\r\nlayout: post\r\n---\r\n# Fix login\r\n\r\nUsers are logged out.\r\n"
+	issue, fm, err := markdownFileToIssue("auth/fix-login.md", []byte(data))
+	if err != nil {
+		t.Fatalf("markdownFileToIssue: %v", err)
+	}
+	if issue.ID != "bd-42" || issue.Title != "Fix login" || issue.Description != "Users are logged out." {
+		t.Errorf("id/title/description = %q/%q/%q", issue.ID, issue.Title, issue.Description)
+	}
+	if issue.Status != types.StatusInProgress || issue.Priority != 1 || issue.IssueType != types.TypeBug {
+		t.Errorf("status/priority/type = %s/%d/%s", issue.Status, issue.Priority, issue.IssueType)
+	}
+	if strings.Join(issue.Labels, ",") != "auth,ui" {
+		t.Errorf("labels = %v", issue.Labels)
+	}
+	if len(issue.Dependencies) != 2 || issue.Dependencies[0].Type != types.DepParentChild || issue.Dependencies[1].DependsOnID != "bd-41" {
+		t.Errorf("dependencies = %+v", issue.Dependencies)
+	}
+	if fm.Priority != "P1" {
+		t.Errorf("front matter priority = %q", fm.Priority)
+	}
+
+	issue, _, err = markdownFileToIssue("notes/todo-later.md", []byte("---\ntitle: \"\"\n---\nJust a body.\n"))
+	if err != nil || issue.Title != "todo-later" || issue.Priority != 2 {
+		t.Errorf("fallback title/priority = %+v, %v", issue, err)
+	}
+
+	if issue, _, err := markdownFileToIssue("README.md", []byte("# Backlog\n")); issue != nil || err != nil {
+		t.Errorf("file without front matter = %+v, %v; want skipped", issue, err)
+	}
+	if _, _, err := markdownFileToIssue("bad.md", []byte("---\npriority: urgent\n---\n")); err == nil {
+		t.Error("expected an error for an invalid priority")
+	}
+}
@@ -0,0 +1,228 @@
+package main
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"slices"
+	"time"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// importPreview is what 'bd import --dry-run' reports: the issues an import
+// would create, the existing issues it would change (field by field), and
+// the ones it would skip because the local copy is newer.
+type importPreview struct {
+	New       []importPreviewIssue    `json:"new"`
+	Updates   []importPreviewUpdate   `json:"updates"`
+	Unchanged []string                `json:"unchanged"`
+	Conflicts []importPreviewConflict `json:"conflicts"`
+
+	// strategy is the --strategy the import would resolve conflicts with.
+	strategy string
+	// rename is --rename-on-import: a differing issue gets a new ID.
+	rename bool
+}
+
+type importPreviewIssue struct {
+	ID    string `json:"id,omitempty"`
+	Title string `json:"title"`
+}
+
+type importPreviewUpdate struct {
+	ID      string              `json:"id"`
+	Changes []importFieldChange `json:"changes"`
+}
+
+// importFieldChange is one field an import would change. Old and New are
+// the field's JSON values as 'bd export' writes them; a missing side means
+// the field is unset.
+type importFieldChange struct {
+	Field string          `json:"field"`
+	Old   json.RawMessage `json:"old,omitempty"`
+	New   json.RawMessage `json:"new,omitempty"`
+}
+
+type importPreviewConflict struct {
+	ID                string    `json:"id"`
+	Reason            string    `json:"reason"`
+	LocalUpdatedAt    time.Time `json:"local_updated_at"`
+	IncomingUpdatedAt time.Time `json:"incoming_updated_at"`
+}
+
+func newImportPreview() *importPreview {
+	return &importPreview{
+		New:       []importPreviewIssue{},
+		Updates:   []importPreviewUpdate{},
+		Unchanged: []string{},
+		Conflicts: []importPreviewConflict{},
+	}
+}
+
+// add classifies issues against the database, using the same rules as the
+// import itself: an issue whose id exists is updated unless the local copy
+// wins under the strategy (see resolveImportConflicts), and labels,
+// dependencies, and comments are only ever added.
+func (p *importPreview) add(ctx context.Context, s storage.DoltStorage, issues []*types.Issue) error {
+	var ids []string
+	for _, issue := range issues {
+		if issue.ID != "" {
+			ids = append(ids, issue.ID)
+		}
+	}
+	local := make(map[string]*types.Issue, len(ids))
+	if len(ids) > 0 {
+		existing, err := s.GetIssuesByIDs(ctx, ids)
+		if err != nil {
+			return fmt.Errorf("check existing issues before import: %w", err)
+		}
+		for _, issue := range existing {
+			local[issue.ID] = issue
+		}
+		labels, err := s.GetLabelsForIssues(ctx, ids)
+		if err != nil {
+			return fmt.Errorf("check existing labels before import: %w", err)
+		}
+		for id, issue := range local {
+			issue.Labels = labels[id]
+		}
+	}
+
+	for _, issue := range issues {
+		existing := local[issue.ID]
+		switch {
+		case existing == nil:
+			p.New = append(p.New, importPreviewIssue{ID: issue.ID, Title: issue.Title})
+		case p.conflictReason(existing, issue) != "":
+			p.Conflicts = append(p.Conflicts, importPreviewConflict{
+				ID:                issue.ID,
+				Reason:            p.conflictReason(existing, issue),
+				LocalUpdatedAt:    existing.UpdatedAt.UTC(),
+				IncomingUpdatedAt: issue.UpdatedAt.UTC(),
+			})
+		default:
+			if changes := diffImportIssue(existing, issue); len(changes) > 0 {
+				p.Updates = append(p.Updates, importPreviewUpdate{ID: issue.ID, Changes: changes})
+			} else {
+				p.Unchanged = append(p.Unchanged, issue.ID)
+			}
+		}
+	}
+	return nil
+}
+
+// conflictReason explains why the import would not simply apply incoming
+// over local, or returns "" if it would.
+func (p *importPreview) conflictReason(local, incoming *types.Issue) string {
+	if local.ComputeContentHash() == incoming.ComputeContentHash() {
+		if importIncomingIsOlder(local, incoming) {
+			return "local copy was updated more recently; the import would skip it"
+		}
+		return ""
+	}
+	switch {
+	case p.rename:
+		return "local issue with this ID differs; --rename-on-import would import it under a new ID"
+	case p.strategy == importStrategyInteractive:
+		return "local copy differs; the import would ask which to keep"
+	case p.strategy == importStrategyLocal:
+		return "local copy differs; --strategy local would keep it"
+	case importKeepsLocal(p.strategy, local, incoming):
+		return "local copy was updated more recently; the import would skip it"
+	}
+	return ""
+}
+
+// diffImportIssue lists the fields importing incoming over local would
+// change. Timestamps are compared at the precision the database stores, and
+// updated_at is left out since every update moves it.
+func diffImportIssue(local, incoming *types.Issue) []importFieldChange {
+	l, in := *local, *incoming
+	for _, issue := range []*types.Issue{&l, &in} {
+		issue.Labels, issue.Dependencies, issue.Comments = nil, nil, nil
+		canonicalizeExportIssue(issue)
+	}
+	lf, inf := issueJSONFields(&l), issueJSONFields(&in)
+
+	var fields []string
+	for f := range lf {
+		fields = append(fields, f)
+	}
+	for f := range inf {
+		if _, ok := lf[f]; !ok {
+			fields = append(fields, f)
+		}
+	}
+	slices.Sort(fields)
+
+	var changes []importFieldChange
+	for _, f := range fields {
+		if f == "id" || f == "updated_at" || (f == "created_at" && incoming.CreatedAt.IsZero()) {
+			continue
+		}
+		if !bytes.Equal(lf[f], inf[f]) {
+			changes = append(changes, importFieldChange{Field: f, Old: lf[f], New: inf[f]})
+		}
+	}
+
+	merged := slices.Clone(local.Labels)
+	for _, label := range incoming.Labels {
+		if !slices.Contains(merged, label) {
+			merged = append(merged, label)
+		}
+	}
+	if len(merged) > len(local.Labels) {
+		slices.Sort(merged)
+		old := slices.Sorted(slices.Values(local.Labels))
+		changes = append(changes, importFieldChange{Field: "labels", Old: mustMarshalJSON(old), New: mustMarshalJSON(merged)})
+	}
+	return changes
+}
+
+// issueJSONFields returns issue's fields as 'bd export' would write them.
+func issueJSONFields(issue *types.Issue) map[string]json.RawMessage {
+	fields := map[string]json.RawMessage{}
+	if data, err := json.Marshal(issue); err == nil {
+		_ = json.Unmarshal(data, &fields)
+	}
+	return fields
+}
+
+func mustMarshalJSON(v interface{}) json.RawMessage {
+	data, _ := json.Marshal(v)
+	return data
+}
+
+// printImportPreview writes the human form of p.
+func printImportPreview(w io.Writer, p *importPreview) {
+	fmt.Fprintf(w, "  %d new, %d updated, %d unchanged, %d conflicts\n",
+		len(p.New), len(p.Updates), len(p.Unchanged), len(p.Conflicts))
+	for _, issue := range p.New {
+		id := issue.ID
+		if id == "" {
+			id = "(new id)"
+		}
+		fmt.Fprintf(w, "  + %s  %s\n", id, issue.Title)
+	}
+	for _, u := range p.Updates {
+		fmt.Fprintf(w, "  ~ %s\n", u.ID)
+		for _, c := range u.Changes {
+			fmt.Fprintf(w, "      %s: %s → %s\n", c.Field, previewValue(c.Old), previewValue(c.New))
+		}
+	}
+	for _, c := range p.Conflicts {
+		fmt.Fprintf(w, "  ! %s  %s (local %s, incoming %s)\n", c.ID, c.Reason,
+			c.LocalUpdatedAt.Format(time.RFC3339), c.IncomingUpdatedAt.Format(time.RFC3339))
+	}
+}
+
+func previewValue(v json.RawMessage) string {
+	if len(v) == 0 {
+		return "(unset)"
+	}
+	return truncate(string(v), 60)
+}
@@ -0,0 +1,55 @@
+package main
+
+import (
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestDiffImportIssue(t *testing.T) {
+	created := time.Date(2026, 1, 1, 9, 0, 0, 0, time.UTC)
+	local := &types.Issue{
+		ID:          "bd-1",
+		Title:       "Fix login",
+		Description: "Old notes",
+		Status:      types.StatusOpen,
+		Priority:    2,
+		IssueType:   types.TypeBug,
+		CreatedAt:   created.In(time.FixedZone("PST", -8*3600)),
+		UpdatedAt:   created,
+		Labels:      []string{"auth"},
+	}
+	incoming := &types.Issue{
+		ID:        "bd-1",
+		Title:     "Fix login",
+		Status:    types.StatusClosed,
+		Priority:  2,
+		IssueType: types.TypeBug,
+		CreatedAt: created.Add(400 * time.Millisecond),
+		UpdatedAt: created.Add(time.Hour),
+		Labels:    []string{"auth", "p1"},
+	}
+
+	changes := diffImportIssue(local, incoming)
+	got := map[string]importFieldChange{}
+	for _, c := range changes {
+		got[c.Field] = c
+	}
+	if len(changes) != 3 {
+		t.Fatalf("changes = %+v, want description, labels, status", changes)
+	}
+	if c := got["status"]; string(c.Old) != `"open"` || string(c.New) != `"closed"` {
+		t.Errorf("status change = %s -> %s", c.Old, c.New)
+	}
+	if c := got["description"]; string(c.Old) != `"Old notes"` || c.New != nil {
+		t.Errorf("description change = %s -> %s", c.Old, c.New)
+	}
+	if c := got["labels"]; string(c.New) != `["auth","p1"]` {
+		t.Errorf("labels change = %s -> %s", c.Old, c.New)
+	}
+
+	if changes := diffImportIssue(local, local); len(changes) != 0 {
+		t.Errorf("diff of an issue with itself = %+v", changes)
+	}
+}
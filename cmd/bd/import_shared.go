@@ -6,9 +6,11 @@ import (
 	"encoding/json"
 	"fmt"
 	"os"
+	"slices"
 	"strings"
 	"time"
 
+	"github.com/steveyegge/beads/internal/idgen"
 	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/utils"
@@ -30,16 +32,26 @@ type ImportOptions struct {
 	// auto-import upgrade-recovery fallback (GH#3955); explicit `bd import`
 	// leaves this false and keeps UPSERT semantics.
 	ConflictSkip bool
+	// Strategy decides which copy wins when an incoming issue and the local
+	// one have different content: importStrategyNewest (the default when
+	// empty), Local, Remote, or Interactive, which calls ChooseConflict.
+	Strategy       string
+	ChooseConflict func(local, incoming *types.Issue) (keepLocal bool)
+	// RecordConflicts, if set, is given the resolved conflicts before
+	// anything is written; an error aborts the import.
+	RecordConflicts func([]importConflict) error
 }
 
 // ImportResult describes what an import operation did.
 type ImportResult struct {
-	Created             int
-	Updated             int
-	Unchanged           int
-	Skipped             int
-	Deleted             int
-	Collisions          int
+	Created    int
+	Updated    int
+	Unchanged  int
+	Skipped    int
+	Deleted    int
+	Collisions int
+	// IDMapping maps the IDs of incoming issues renumbered because a
+	// different local issue had them to their new IDs.
 	IDMapping           map[string]string
 	CollisionIDs        []string
 	PrefixMismatch      bool
@@ -48,6 +60,10 @@ type ImportResult struct {
 	ImportedIDs         []string
 	StaleSkippedIDs     []string
 	SkippedDependencies []string
+	Conflicts           []importConflict
+	// RegisteredTypes are custom issue types the import added to
+	// types.custom because incoming issues use them.
+	RegisteredTypes []string
 }
 
 // importIssuesCore imports issues into the Dolt store.
@@ -57,13 +73,43 @@ func importIssuesCore(ctx context.Context, _ string, store storage.DoltStorage,
 		return &ImportResult{Skipped: len(issues)}, nil
 	}
 
-	filtered, staleSkippedIDs, err := filterStaleImportIssues(ctx, store, issues)
+	reused, renumber, err := planImportRenumbering(ctx, store, issues, opts)
 	if err != nil {
 		return nil, err
 	}
-	issues = filtered
+
+	// Issues to renumber are new issues, not versions of the local issues
+	// with their IDs, so they skip conflict resolution.
+	toResolve, fresh := issues, []*types.Issue(nil)
+	if len(renumber) > 0 {
+		toResolve = nil
+		for _, issue := range issues {
+			if issue != nil {
+				if _, ok := renumber[issue.ID]; ok {
+					fresh = append(fresh, issue)
+					continue
+				}
+			}
+			toResolve = append(toResolve, issue)
+		}
+	}
+	filtered, staleSkippedIDs, conflicts, err := resolveImportConflicts(ctx, store, toResolve, opts)
+	if err != nil {
+		return nil, err
+	}
+	if len(conflicts) > 0 && opts.RecordConflicts != nil {
+		if err := opts.RecordConflicts(conflicts); err != nil {
+			return nil, err
+		}
+	}
+	issues = append(filtered, fresh...)
 	if len(issues) == 0 {
-		return &ImportResult{Skipped: len(staleSkippedIDs), StaleSkippedIDs: staleSkippedIDs}, nil
+		return &ImportResult{Skipped: len(staleSkippedIDs), StaleSkippedIDs: staleSkippedIDs, Conflicts: conflicts, IDMapping: reused}, nil
+	}
+
+	registeredTypes, err := ensureCustomTypes(ctx, store, issues)
+	if err != nil {
+		return nil, err
 	}
 
 	var skippedDependencies []string
@@ -73,6 +119,7 @@ func importIssuesCore(ctx context.Context, _ string, store storage.DoltStorage,
 		SkipPrefixValidation:           opts.SkipPrefixValidation,
 		ConflictSkip:                   opts.ConflictSkip,
 		SkipDependencyValidationErrors: true,
+		RenumberIDs:                    renumber,
 		OnSkippedDependency: func(issueID, dependsOnID, reason string) {
 			skipped := fmt.Sprintf("%s -> %s: %s", issueID, dependsOnID, reason)
 			if _, ok := skippedDependencySet[skipped]; ok {
@@ -86,6 +133,17 @@ func importIssuesCore(ctx context.Context, _ string, store storage.DoltStorage,
 		return nil, err
 	}
 
+	for _, issue := range fresh {
+		issue.PrefixOverride = ""
+	}
+	idMapping := reused
+	for oldID, newID := range renumber {
+		if idMapping == nil {
+			idMapping = make(map[string]string)
+		}
+		idMapping[oldID] = newID
+	}
+
 	importedIDs := make([]string, 0, len(issues))
 	for _, issue := range issues {
 		importedIDs = append(importedIDs, issue.ID)
@@ -93,13 +151,178 @@ func importIssuesCore(ctx context.Context, _ string, store storage.DoltStorage,
 	return &ImportResult{
 		Created:             len(issues),
 		Skipped:             len(staleSkippedIDs),
+		Collisions:          len(idMapping),
+		IDMapping:           idMapping,
 		ImportedIDs:         importedIDs,
 		StaleSkippedIDs:     staleSkippedIDs,
 		SkippedDependencies: skippedDependencies,
+		Conflicts:           conflicts,
+		RegisteredTypes:     registeredTypes,
 	}, nil
 }
 
+// planImportRenumbering finds the incoming issues whose ID a different
+// local issue already has. Only the sequential schemes (issue_id_mode
+// counter and actor) can mint the same ID for two issues, so only they are
+// checked; an issue created at another time than the local one is a
+// different issue, not a newer version. With opts.RenameOnImport, any
+// incoming issue whose content differs from the local issue with its ID
+// collides, under every scheme. An issue an earlier import already
+// renumbered takes the ID it got then, returned in reused with the batch's
+// dependencies pointed at it. The rest are returned in renumber, keyed by
+// ID, for the import's batch create to give new IDs in its transaction.
+func planImportRenumbering(ctx context.Context, store storage.DoltStorage, issues []*types.Issue, opts ImportOptions) (reused, renumber map[string]string, err error) {
+	if opts.ConflictSkip {
+		return nil, nil, nil
+	}
+	mode, err := store.GetConfig(ctx, "issue_id_mode")
+	if err != nil {
+		return nil, nil, fmt.Errorf("reading issue_id_mode: %w", err)
+	}
+	scheme, err := idgen.ParseScheme(mode)
+	sequential := err == nil && scheme.Sequential()
+	if !sequential && !opts.RenameOnImport {
+		return nil, nil, nil
+	}
+
+	ids := make([]string, 0, len(issues))
+	for _, issue := range issues {
+		if issue != nil && issue.ID != "" {
+			ids = append(ids, issue.ID)
+		}
+	}
+	if len(ids) == 0 {
+		return nil, nil, nil
+	}
+	localIssues, err := store.GetIssuesByIDs(ctx, ids)
+	if err != nil {
+		return nil, nil, fmt.Errorf("check existing issues before import: %w", err)
+	}
+	local := make(map[string]*types.Issue, len(localIssues))
+	for _, issue := range localIssues {
+		if issue != nil {
+			local[issue.ID] = issue
+		}
+	}
+
+	prefix, err := store.GetConfig(ctx, "issue_prefix")
+	if err != nil {
+		return nil, nil, fmt.Errorf("reading issue_prefix: %w", err)
+	}
+	prefixOf := issuePrefixFunc(scheme)
+	for _, issue := range issues {
+		if issue == nil {
+			continue
+		}
+		existing := local[issue.ID]
+		differs := opts.RenameOnImport && existing != nil && existing.ComputeContentHash() != issue.ComputeContentHash()
+		if !differs && !(sequential && importIDCollides(existing, issue)) {
+			continue
+		}
+		prev, err := findRenumberedImport(ctx, store, issue)
+		if err != nil {
+			return nil, nil, err
+		}
+		if prev != "" {
+			if reused == nil {
+				reused = make(map[string]string)
+			}
+			reused[issue.ID] = prev
+			issue.ID = prev
+			continue
+		}
+		if renumber == nil {
+			renumber = make(map[string]string)
+		}
+		renumber[issue.ID] = ""
+		// Keep the issue's prefix when it is not the configured one.
+		if prefix == "" || !strings.HasPrefix(issue.ID, prefix+"-") {
+			issue.PrefixOverride = prefixOf(issue.ID)
+		}
+	}
+	if len(reused) > 0 {
+		for _, issue := range issues {
+			if issue != nil {
+				remapImportDependencies(issue.Dependencies, reused)
+			}
+		}
+	}
+	return reused, renumber, nil
+}
+
+// issuePrefixFunc returns the function that extracts the prefix from IDs
+// minted under scheme. Actor-scheme IDs carry the actor's namespace between
+// the prefix and the number.
+func issuePrefixFunc(scheme idgen.Scheme) func(string) string {
+	if scheme == idgen.SchemeActor {
+		return utils.ExtractIssuePrefixActor
+	}
+	return utils.ExtractIssuePrefix
+}
+
+// storeIssuePrefixFunc is issuePrefixFunc for the store's issue_id_mode.
+func storeIssuePrefixFunc(ctx context.Context, store storage.DoltStorage) (func(string) string, error) {
+	mode, err := store.GetConfig(ctx, "issue_id_mode")
+	if err != nil {
+		return nil, fmt.Errorf("reading issue_id_mode: %w", err)
+	}
+	scheme, _ := idgen.ParseScheme(mode)
+	return issuePrefixFunc(scheme), nil
+}
+
+// remapImportDependencies points deps at the new IDs of renumbered issues.
+func remapImportDependencies(deps []*types.Dependency, mapping map[string]string) {
+	for _, dep := range deps {
+		if dep == nil {
+			continue
+		}
+		if id, ok := mapping[dep.IssueID]; ok {
+			dep.IssueID = id
+		}
+		if id, ok := mapping[dep.DependsOnID]; ok {
+			dep.DependsOnID = id
+		}
+	}
+}
+
+// importIDCollides reports whether incoming, which has the ID of local, is
+// a different issue: one created at another time.
+func importIDCollides(local, incoming *types.Issue) bool {
+	if local == nil || local.CreatedAt.IsZero() || incoming.CreatedAt.IsZero() {
+		return false
+	}
+	return !local.CreatedAt.Truncate(time.Second).Equal(incoming.CreatedAt.Truncate(time.Second))
+}
+
+// findRenumberedImport returns the ID of a local issue with the title and
+// creation time of incoming, which an earlier import renumbered, or "".
+func findRenumberedImport(ctx context.Context, store storage.DoltStorage, incoming *types.Issue) (string, error) {
+	created := incoming.CreatedAt.UTC().Truncate(time.Second)
+	after, before := created.Add(-time.Second), created.Add(time.Second)
+	candidates, err := store.SearchIssues(ctx, "", types.IssueFilter{CreatedAfter: &after, CreatedBefore: &before})
+	if err != nil {
+		return "", fmt.Errorf("looking for earlier renumbering of %s: %w", incoming.ID, err)
+	}
+	for _, c := range candidates {
+		if c.ID != incoming.ID && c.Title == incoming.Title && c.CreatedAt.Truncate(time.Second).Equal(created) {
+			return c.ID, nil
+		}
+	}
+	return "", nil
+}
+
+// filterStaleImportIssues drops incoming issues that are older than their
+// local copy, the default newest-wins resolution.
 func filterStaleImportIssues(ctx context.Context, store storage.DoltStorage, issues []*types.Issue) ([]*types.Issue, []string, error) {
+	filtered, skippedIDs, _, err := resolveImportConflicts(ctx, store, issues, ImportOptions{})
+	return filtered, skippedIDs, err
+}
+
+// resolveImportConflicts drops the incoming issues the local copy wins over
+// under opts.Strategy, returning the ones to import, the IDs it dropped, and
+// every conflict (differing content) it resolved either way. An incoming
+// issue with the same content as the local copy is dropped only if older.
+func resolveImportConflicts(ctx context.Context, store storage.DoltStorage, issues []*types.Issue, opts ImportOptions) ([]*types.Issue, []string, []importConflict, error) {
 	ids := make([]string, 0, len(issues))
 	seen := make(map[string]struct{}, len(issues))
 	for _, issue := range issues {
@@ -113,37 +336,61 @@ func filterStaleImportIssues(ctx context.Context, store storage.DoltStorage, iss
 		ids = append(ids, issue.ID)
 	}
 	if len(ids) == 0 {
-		return issues, nil, nil
+		return issues, nil, nil, nil
 	}
 
 	localIssues, err := store.GetIssuesByIDs(ctx, ids)
 	if err != nil {
-		return nil, nil, fmt.Errorf("check existing issues before import: %w", err)
+		return nil, nil, nil, fmt.Errorf("check existing issues before import: %w", err)
 	}
-	localUpdatedAt := make(map[string]time.Time, len(localIssues))
+	local := make(map[string]*types.Issue, len(localIssues))
 	for _, issue := range localIssues {
-		if issue != nil && issue.ID != "" && !issue.UpdatedAt.IsZero() {
-			localUpdatedAt[issue.ID] = issue.UpdatedAt
+		if issue != nil && issue.ID != "" {
+			local[issue.ID] = issue
 		}
 	}
-	if len(localUpdatedAt) == 0 {
-		return issues, nil, nil
+	if len(local) == 0 {
+		return issues, nil, nil, nil
 	}
 
 	filtered := make([]*types.Issue, 0, len(issues))
 	skippedIDs := make([]string, 0)
+	var conflicts []importConflict
 	for _, issue := range issues {
-		if issue == nil || issue.ID == "" || issue.UpdatedAt.IsZero() {
+		if issue == nil || local[issue.ID] == nil {
 			filtered = append(filtered, issue)
 			continue
 		}
-		if local, ok := localUpdatedAt[issue.ID]; ok && issue.UpdatedAt.UTC().Before(local.UTC()) {
+		existing := local[issue.ID]
+		var keepLocal bool
+		switch {
+		case existing.ComputeContentHash() == issue.ComputeContentHash():
+			keepLocal = importIncomingIsOlder(existing, issue)
+		case opts.Strategy == importStrategyInteractive && opts.ChooseConflict != nil:
+			keepLocal = opts.ChooseConflict(existing, issue)
+			conflicts = append(conflicts, newImportConflict(existing, issue, keepLocal))
+		default:
+			keepLocal = importKeepsLocal(opts.Strategy, existing, issue)
+			conflicts = append(conflicts, newImportConflict(existing, issue, keepLocal))
+		}
+		if keepLocal {
 			skippedIDs = append(skippedIDs, issue.ID)
 			continue
 		}
 		filtered = append(filtered, issue)
 	}
-	return filtered, skippedIDs, nil
+	if opts.ConflictSkip {
+		// Existing rows are never written, so nothing was lost.
+		conflicts = nil
+	}
+	return filtered, skippedIDs, conflicts, nil
+}
+
+func newImportConflict(local, incoming *types.Issue, keepLocal bool) importConflict {
+	if keepLocal {
+		return importConflict{ID: incoming.ID, KeptLocal: true, Loser: incoming}
+	}
+	return importConflict{ID: incoming.ID, Loser: local}
 }
 
 // importLocalResult holds counts from a local JSONL import.
@@ -171,7 +418,9 @@ func importFromLocalJSONL(ctx context.Context, store storage.DoltStorage, localP
 }
 
 // parseJSONLFile reads a JSONL file and returns parsed issues and config
-// entries (memories). Pure function — no store I/O.
+// entries (memories, and tombstones from deletion records). Issues that a
+// deletion record in the same file buries are left out. Pure function —
+// no store I/O.
 func parseJSONLFile(path string) ([]*types.Issue, map[string]string, error) {
 	//nolint:gosec // G304: path from user-provided CLI argument
 	data, err := os.ReadFile(path)
@@ -184,6 +433,8 @@ func parseJSONLFile(path string) ([]*types.Issue, map[string]string, error) {
 	scanner.Buffer(make([]byte, 0, 1024*1024), 64*1024*1024)
 	var issues []*types.Issue
 	configEntries := make(map[string]string)
+	fileVersion := 0
+	warnedNewer := false
 
 	for scanner.Scan() {
 		line := scanner.Text()
@@ -208,13 +459,29 @@ func parseJSONLFile(path string) ([]*types.Issue, map[string]string, error) {
 		// issue : title is required". Identified by the _schema
 		// sentinel, which real issue/memory records never carry.
 		if _, isHeader := peek["_schema"]; isHeader {
+			fileVersion = jsonlHeaderFormat(peek)
 			continue
 		}
+		format := jsonlFormatOf(peek, fileVersion)
+		if format > jsonlFormatVersion && !warnedNewer {
+			fmt.Fprintf(os.Stderr, "Warning: %s: %s\n", path, jsonlNewerFormatWarning(format))
+			warnedNewer = true
+		}
 
 		// Check if this is a memory record
 		if rawType, ok := peek["_type"]; ok {
 			var typeStr string
-			if err := json.Unmarshal(rawType, &typeStr); err == nil && typeStr == "memory" {
+			_ = json.Unmarshal(rawType, &typeStr)
+			if typeStr == "deletion" {
+				// Written by 'bd export' for deleted issues; kept as a
+				// tombstone config entry (see tombstones.go).
+				if t, ok := parseJSONLDeletion([]byte(line)); ok {
+					data, _ := json.Marshal(tombstone{DeletedAt: t.DeletedAt.UTC(), Actor: t.Actor})
+					configEntries[tombstoneConfigPrefix+t.ID] = string(data)
+				}
+				continue
+			}
+			if typeStr == "memory" {
 				var mem memoryRecord
 				if err := json.Unmarshal([]byte(line), &mem); err != nil {
 					return nil, nil, fmt.Errorf("failed to parse memory record: %w", err)
@@ -238,14 +505,7 @@ func parseJSONLFile(path string) ([]*types.Issue, map[string]string, error) {
 			continue
 		}
 
-		// v0.35–v0.37 exported "wisp" (bool), renamed to "ephemeral" in v0.38+.
-		// map old field name so the flag is preserved on import.
-		if _, hasWisp := peek["wisp"]; hasWisp && !issue.Ephemeral {
-			var wisp bool
-			if err := json.Unmarshal(peek["wisp"], &wisp); err == nil && wisp {
-				issue.Ephemeral = true
-			}
-		}
+		upgradeJSONLIssue(&issue, peek, format)
 
 		issue.SetDefaults()
 		issues = append(issues, &issue)
@@ -254,6 +514,16 @@ func parseJSONLFile(path string) ([]*types.Issue, map[string]string, error) {
 		return nil, nil, fmt.Errorf("failed to scan JSONL: %w", err)
 	}
 
+	if deletions := tombstonesIn(configEntries); len(deletions) > 0 {
+		byID := make(map[string]tombstone, len(deletions))
+		for _, t := range deletions {
+			byID[t.ID] = t
+		}
+		issues = slices.DeleteFunc(issues, func(issue *types.Issue) bool {
+			t, ok := byID[issue.ID]
+			return ok && t.buries(issue)
+		})
+	}
 	return issues, configEntries, nil
 }
 
@@ -288,18 +558,41 @@ func importFromLocalJSONLWithOpts(ctx context.Context, store storage.DoltStorage
 
 	// Import memories
 	for key, value := range configEntries {
+		if strings.HasPrefix(key, tombstoneConfigPrefix) {
+			continue
+		}
 		if err := store.SetConfig(ctx, key, value); err != nil {
 			return nil, fmt.Errorf("failed to import config %q: %w", key, err)
 		}
 		result.Memories++
 	}
 
+	// Deletions: record the file's tombstones, deleting the local issues
+	// they bury unless only inserting, and skip issues deleted here.
+	if conflictSkip {
+		for _, t := range tombstonesIn(configEntries) {
+			if err := setTombstone(ctx, store, t); err != nil {
+				return nil, fmt.Errorf("failed to import deletion of %s: %w", t.ID, err)
+			}
+		}
+	} else if _, err := applyTombstones(ctx, store, tombstonesIn(configEntries)); err != nil {
+		return nil, err
+	}
+	issues, _, err = withoutTombstoned(ctx, store, issues)
+	if err != nil {
+		return nil, err
+	}
+
 	// Import issues
 	if len(issues) > 0 {
 		// Auto-detect prefix from first issue if not already configured
 		configuredPrefix, err := store.GetConfig(ctx, "issue_prefix")
 		if err == nil && strings.TrimSpace(configuredPrefix) == "" {
-			firstPrefix := utils.ExtractIssuePrefix(issues[0].ID)
+			prefixOf, err := storeIssuePrefixFunc(ctx, store)
+			if err != nil {
+				return nil, err
+			}
+			firstPrefix := prefixOf(issues[0].ID)
 			if firstPrefix != "" {
 				if err := store.SetConfig(ctx, "issue_prefix", firstPrefix); err != nil {
 					return nil, fmt.Errorf("failed to set issue_prefix from imported issues: %w", err)
@@ -320,3 +613,49 @@ func importFromLocalJSONLWithOpts(ctx context.Context, store storage.DoltStorage
 
 	return result, nil
 }
+
+// repairImportIssue fixes known invariant violations that older databases
+// exported (GH-reported: closed issues without closed_at). It mutates issue
+// in place and returns a human-readable note per repair; nil means the record
+// was already valid. Used by `bd import --repair`.
+func repairImportIssue(issue *types.Issue) []string {
+	var repairs []string
+
+	if issue.Status == types.StatusClosed && issue.ClosedAt == nil {
+		closedAt := issue.UpdatedAt
+		source := "updated_at"
+		if closedAt.IsZero() {
+			closedAt = issue.CreatedAt
+			source = "created_at"
+		}
+		if closedAt.IsZero() {
+			closedAt = time.Now().UTC()
+			source = "import time"
+		}
+		issue.ClosedAt = &closedAt
+		repairs = append(repairs, "backfilled closed_at from "+source)
+	}
+	if issue.Status != types.StatusClosed && issue.ClosedAt != nil {
+		issue.ClosedAt = nil
+		repairs = append(repairs, fmt.Sprintf("cleared closed_at on %s issue", issue.Status))
+	}
+
+	switch {
+	case issue.Priority < 0:
+		repairs = append(repairs, fmt.Sprintf("clamped priority %d to 0", issue.Priority))
+		issue.Priority = 0
+	case issue.Priority > 4:
+		repairs = append(repairs, fmt.Sprintf("clamped priority %d to 4", issue.Priority))
+		issue.Priority = 4
+	}
+
+	if strings.TrimSpace(issue.Title) == "" && issue.ID != "" {
+		issue.Title = "(untitled " + issue.ID + ")"
+		repairs = append(repairs, "defaulted missing title")
+	}
+	if issue.EstimatedMinutes != nil && *issue.EstimatedMinutes < 0 {
+		issue.EstimatedMinutes = nil
+		repairs = append(repairs, "dropped negative estimated_minutes")
+	}
+	return repairs
+}
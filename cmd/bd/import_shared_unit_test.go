@@ -2,22 +2,34 @@ package main
 
 import (
 	"context"
+	"errors"
+	"strings"
 	"testing"
 	"time"
 
 	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/validation"
 )
 
 type fakeImportIssueLookupStore struct {
 	storage.DoltStorage
 	issues []*types.Issue
+	config map[string]string
 }
 
 func (f *fakeImportIssueLookupStore) GetIssuesByIDs(_ context.Context, _ []string) ([]*types.Issue, error) {
 	return f.issues, nil
 }
 
+func (f *fakeImportIssueLookupStore) SearchIssues(_ context.Context, _ string, _ types.IssueFilter) ([]*types.Issue, error) {
+	return f.issues, nil
+}
+
+func (f *fakeImportIssueLookupStore) GetConfig(_ context.Context, key string) (string, error) {
+	return f.config[key], nil
+}
+
 func TestFilterStaleImportIssuesSkipsOlderIncomingRecords(t *testing.T) {
 	base := time.Date(2026, 5, 27, 12, 0, 0, 0, time.UTC)
 	incoming := []*types.Issue{
@@ -79,3 +91,304 @@ func TestImportIssuesCoreReportsStaleSkippedIDs(t *testing.T) {
 		t.Fatalf("StaleSkippedIDs = %#v, want [bd-stale]", result.StaleSkippedIDs)
 	}
 }
+
+func TestResolveImportConflictsStrategies(t *testing.T) {
+	base := time.Date(2026, 5, 27, 12, 0, 0, 0, time.UTC)
+	store := &fakeImportIssueLookupStore{issues: []*types.Issue{
+		{ID: "bd-edited", Title: "local edit", UpdatedAt: base.Add(time.Hour)},
+		{ID: "bd-same", Title: "same", UpdatedAt: base.Add(time.Hour)},
+	}}
+	incoming := func() []*types.Issue {
+		return []*types.Issue{
+			{ID: "bd-edited", Title: "remote edit", UpdatedAt: base},
+			{ID: "bd-same", Title: "same", UpdatedAt: base.Add(2 * time.Hour)},
+		}
+	}
+
+	tests := []struct {
+		strategy      string
+		choose        func(local, incoming *types.Issue) bool
+		wantKeptLocal bool
+		wantLoser     string
+	}{
+		{importStrategyNewest, nil, true, "remote edit"},
+		{importStrategyLocal, nil, true, "remote edit"},
+		{importStrategyRemote, nil, false, "local edit"},
+		{importStrategyInteractive, func(_, _ *types.Issue) bool { return false }, false, "local edit"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.strategy, func(t *testing.T) {
+			opts := ImportOptions{Strategy: tt.strategy, ChooseConflict: tt.choose}
+			filtered, skippedIDs, conflicts, err := resolveImportConflicts(context.Background(), store, incoming(), opts)
+			if err != nil {
+				t.Fatalf("resolveImportConflicts: %v", err)
+			}
+			if len(conflicts) != 1 || conflicts[0].ID != "bd-edited" {
+				t.Fatalf("conflicts = %+v, want only bd-edited (bd-same has identical content)", conflicts)
+			}
+			c := conflicts[0]
+			if c.KeptLocal != tt.wantKeptLocal || c.Loser.Title != tt.wantLoser {
+				t.Errorf("conflict kept local = %v, loser %q; want %v, %q", c.KeptLocal, c.Loser.Title, tt.wantKeptLocal, tt.wantLoser)
+			}
+			if skipped := len(skippedIDs) == 1 && skippedIDs[0] == "bd-edited"; skipped != tt.wantKeptLocal {
+				t.Errorf("skippedIDs = %v", skippedIDs)
+			}
+			if len(filtered)+len(skippedIDs) != 2 {
+				t.Errorf("filtered = %d issues, skipped = %v; want 2 in total", len(filtered), skippedIDs)
+			}
+		})
+	}
+}
+
+func TestImportIssuesCoreRecordsConflictsBeforeWriting(t *testing.T) {
+	base := time.Date(2026, 5, 27, 12, 0, 0, 0, time.UTC)
+	store := &fakeImportIssueLookupStore{issues: []*types.Issue{
+		{ID: "bd-1", Title: "local", UpdatedAt: base.Add(time.Hour)},
+	}}
+	recordErr := errors.New("disk full")
+
+	// The fake store panics on writes, so reaching CreateIssuesWithFullOptions
+	// would fail the test: a sidecar error has to stop the import first.
+	_, err := importIssuesCore(context.Background(), "", store, []*types.Issue{
+		{ID: "bd-1", Title: "incoming", UpdatedAt: base},
+	}, ImportOptions{
+		Strategy:        importStrategyRemote,
+		RecordConflicts: func([]importConflict) error { return recordErr },
+	})
+	if !errors.Is(err, recordErr) {
+		t.Fatalf("importIssuesCore err = %v, want %v", err, recordErr)
+	}
+}
+
+func TestRenumberImportCollisionsReusesEarlierRenumbering(t *testing.T) {
+	base := time.Date(2026, 5, 27, 12, 0, 0, 0, time.UTC)
+	store := &fakeImportIssueLookupStore{
+		issues: []*types.Issue{
+			{ID: "bd-5", Title: "local five", CreatedAt: base},
+			{ID: "bd-9", Title: "remote five", CreatedAt: base.Add(time.Hour)},
+		},
+		config: map[string]string{"issue_id_mode": "counter", "issue_prefix": "bd"},
+	}
+	incoming := []*types.Issue{
+		{ID: "bd-5", Title: "remote five", CreatedAt: base.Add(time.Hour)},
+		{ID: "bd-6", Title: "remote six", CreatedAt: base.Add(time.Hour),
+			Dependencies: []*types.Dependency{{IssueID: "bd-6", DependsOnID: "bd-5", Type: types.DepBlocks}}},
+	}
+
+	// An earlier import renumbered the incoming bd-5 to bd-9, so nothing is
+	// left to renumber.
+	reused, renumber, err := planImportRenumbering(context.Background(), store, incoming, ImportOptions{})
+	if err != nil {
+		t.Fatalf("planImportRenumbering: %v", err)
+	}
+	if len(reused) != 1 || reused["bd-5"] != "bd-9" || renumber != nil {
+		t.Fatalf("reused = %v, renumber = %v; want bd-5 -> bd-9 only", reused, renumber)
+	}
+	if incoming[0].ID != "bd-9" {
+		t.Errorf("incoming bd-5 has ID %s, want bd-9", incoming[0].ID)
+	}
+	if dep := incoming[1].Dependencies[0]; dep.IssueID != "bd-6" || dep.DependsOnID != "bd-9" {
+		t.Errorf("dependency = %s -> %s, want bd-6 -> bd-9", dep.IssueID, dep.DependsOnID)
+	}
+
+	// Under hash IDs the same ID means the same issue.
+	store.config["issue_id_mode"] = "hash"
+	incoming[0].ID = "bd-5"
+	if reused, renumber, err := planImportRenumbering(context.Background(), store, incoming, ImportOptions{}); err != nil || reused != nil || renumber != nil {
+		t.Errorf("hash mode: reused = %v, renumber = %v, err = %v; want none", reused, renumber, err)
+	}
+}
+
+func TestPlanImportRenumberingActorScheme(t *testing.T) {
+	base := time.Date(2026, 5, 27, 12, 0, 0, 0, time.UTC)
+	store := &fakeImportIssueLookupStore{
+		issues: []*types.Issue{
+			{ID: "bd-alice-1", Title: "local", CreatedAt: base},
+			{ID: "up-alice-1", Title: "local upstream", CreatedAt: base},
+		},
+		config: map[string]string{"issue_id_mode": "actor", "issue_prefix": "bd"},
+	}
+	incoming := []*types.Issue{
+		{ID: "bd-alice-1", Title: "remote", CreatedAt: base.Add(time.Hour)},
+		{ID: "up-alice-1", Title: "remote upstream", CreatedAt: base.Add(time.Hour)},
+	}
+
+	reused, renumber, err := planImportRenumbering(context.Background(), store, incoming, ImportOptions{})
+	if err != nil {
+		t.Fatalf("planImportRenumbering: %v", err)
+	}
+	if reused != nil || len(renumber) != 2 {
+		t.Fatalf("reused = %v, renumber = %v; want both incoming issues to renumber", reused, renumber)
+	}
+	// The batch create mints the new IDs; an issue keeps a prefix other
+	// than the configured one, without the actor namespace.
+	if incoming[0].ID != "bd-alice-1" || incoming[0].PrefixOverride != "" {
+		t.Errorf("incoming[0] = %s, override %q; want bd-alice-1 and no override", incoming[0].ID, incoming[0].PrefixOverride)
+	}
+	if incoming[1].PrefixOverride != "up" {
+		t.Errorf("incoming[1] override = %q, want up", incoming[1].PrefixOverride)
+	}
+}
+
+func TestPlanImportRenumberingRenameOnImport(t *testing.T) {
+	base := time.Date(2026, 5, 27, 12, 0, 0, 0, time.UTC)
+	store := &fakeImportIssueLookupStore{
+		issues: []*types.Issue{
+			{ID: "bd-a1", Title: "local", CreatedAt: base},
+			{ID: "bd-b2", Title: "same", CreatedAt: base},
+		},
+		config: map[string]string{"issue_id_mode": "hash", "issue_prefix": "bd"},
+	}
+	incoming := []*types.Issue{
+		{ID: "bd-a1", Title: "remote", CreatedAt: base},
+		{ID: "bd-b2", Title: "same", CreatedAt: base},
+	}
+
+	// Under hash IDs only --rename-on-import treats a differing issue as a
+	// collision; an identical one is the same issue.
+	reused, renumber, err := planImportRenumbering(context.Background(), store, incoming, ImportOptions{RenameOnImport: true})
+	if err != nil {
+		t.Fatalf("planImportRenumbering: %v", err)
+	}
+	if reused != nil || len(renumber) != 1 {
+		t.Fatalf("reused = %v, renumber = %v; want bd-a1 to renumber", reused, renumber)
+	}
+	if _, ok := renumber["bd-a1"]; !ok {
+		t.Errorf("renumber = %v, want bd-a1", renumber)
+	}
+}
+
+func TestRepairImportIssue(t *testing.T) {
+	updated := time.Date(2024, 2, 3, 4, 5, 6, 0, time.UTC)
+	estimate := -5
+	issue := &types.Issue{
+		ID:               "bd-legacy",
+		Status:           types.StatusClosed,
+		IssueType:        types.TypeTask,
+		Priority:         9,
+		UpdatedAt:        updated,
+		EstimatedMinutes: &estimate,
+	}
+
+	repairs := repairImportIssue(issue)
+	if len(repairs) != 4 {
+		t.Fatalf("repairs = %#v, want 4 entries", repairs)
+	}
+	if issue.ClosedAt == nil || !issue.ClosedAt.Equal(updated) {
+		t.Errorf("closed_at = %v, want %v", issue.ClosedAt, updated)
+	}
+	if issue.Priority != 4 {
+		t.Errorf("priority = %d, want 4", issue.Priority)
+	}
+	if issue.Title == "" {
+		t.Error("title should have been defaulted")
+	}
+	if issue.EstimatedMinutes != nil {
+		t.Errorf("estimated_minutes = %d, want nil", *issue.EstimatedMinutes)
+	}
+	if err := issue.Validate(); err != nil {
+		t.Errorf("repaired issue should validate: %v", err)
+	}
+
+	reopened := &types.Issue{ID: "bd-open", Title: "ok", Status: types.StatusOpen, Priority: 1, ClosedAt: &updated}
+	if repairs := repairImportIssue(reopened); len(repairs) != 1 || reopened.ClosedAt != nil {
+		t.Errorf("open issue: repairs=%#v closed_at=%v", repairs, reopened.ClosedAt)
+	}
+
+	valid := &types.Issue{ID: "bd-ok", Title: "ok", Status: types.StatusOpen, Priority: 2}
+	if repairs := repairImportIssue(valid); repairs != nil {
+		t.Errorf("valid issue should need no repairs, got %#v", repairs)
+	}
+}
+
+func TestParseJSONLImportFlushesBatches(t *testing.T) {
+	var batches []int
+	c := &importCollector{
+		batchSize: 2,
+		flush: func(issues []*types.Issue) error {
+			batches = append(batches, len(issues))
+			return nil
+		},
+	}
+	input := strings.Repeat(`{"title":"t"}`+"\n", 5)
+	if err := parseJSONLImport(strings.NewReader(input), c); err != nil {
+		t.Fatalf("parseJSONLImport: %v", err)
+	}
+	if len(batches) != 2 || batches[0] != 2 || batches[1] != 2 {
+		t.Errorf("flushed batches = %v, want [2 2]", batches)
+	}
+	if len(c.issues) != 1 {
+		t.Errorf("%d issues left for the final batch, want 1", len(c.issues))
+	}
+
+	failing := &importCollector{
+		batchSize: 1,
+		flush:     func([]*types.Issue) error { return errors.New("boom") },
+	}
+	if err := parseJSONLImport(strings.NewReader(input), failing); err == nil || err.Error() != "boom" {
+		t.Errorf("parseJSONLImport error = %v, want boom", err)
+	}
+}
+
+func TestParseJSONLImportFormatVersions(t *testing.T) {
+	input := strings.Join([]string{
+		`{"title":"legacy","wisp":true}`,
+		`{"_type":"issue","_format":1,"title":"current","wisp":true}`,
+		`{"_type":"issue","_format":99,"title":"future","new_field":{"x":1}}`,
+		`{"_type":"issue","_format":99,"title":"future too"}`,
+	}, "\n")
+	c := &importCollector{}
+	if err := parseJSONLImport(strings.NewReader(input), c); err != nil {
+		t.Fatalf("parseJSONLImport: %v", err)
+	}
+	if len(c.lineErrors) != 0 {
+		t.Fatalf("line errors: %+v", c.lineErrors)
+	}
+	if len(c.issues) != 4 {
+		t.Fatalf("got %d issues, want 4", len(c.issues))
+	}
+	if !c.issues[0].Ephemeral {
+		t.Error("legacy wisp record was not translated to ephemeral")
+	}
+	if c.issues[1].Ephemeral {
+		t.Error("wisp should only be translated for format 0 records")
+	}
+	if len(c.warnings) != 1 || !strings.Contains(c.warnings[0], "format 99") {
+		t.Errorf("warnings = %q, want one newer-format warning", c.warnings)
+	}
+
+	headed := &importCollector{}
+	input = `{"_schema":"beads-jsonl/1"}` + "\n" + `{"title":"t","wisp":true}`
+	if err := parseJSONLImport(strings.NewReader(input), headed); err != nil {
+		t.Fatalf("parseJSONLImport: %v", err)
+	}
+	if len(headed.issues) != 1 || headed.issues[0].Ephemeral {
+		t.Error("header version should apply to records without _format")
+	}
+}
+
+func TestParseJSONLImportRelaxesRulesForLegacyRecords(t *testing.T) {
+	input := strings.Join([]string{
+		`{"title":"legacy record with a long title","priority":4}`,
+		`{"_format":1,"title":"current record with a long title","priority":4}`,
+		`{"_format":1,"title":"short","priority":1}`,
+	}, "\n")
+	c := &importCollector{rules: validation.Rules{TitleMaxLength: 10, Priorities: []int{0, 1, 2}}}
+	if err := parseJSONLImport(strings.NewReader(input), c); err != nil {
+		t.Fatalf("parseJSONLImport: %v", err)
+	}
+	if len(c.issues) != 2 || c.issues[0].Title != "legacy record with a long title" {
+		t.Errorf("imported %d issues, want the legacy and short records", len(c.issues))
+	}
+	if len(c.lineErrors) != 1 || c.lineErrors[0].Line != 2 || !strings.Contains(c.lineErrors[0].Error, "priority P4 is not allowed") {
+		t.Errorf("line errors = %+v, want line 2 rejected", c.lineErrors)
+	}
+
+	warn := &importCollector{rules: validation.Rules{Strictness: validation.StrictnessWarn, TitleMaxLength: 10}}
+	if err := parseJSONLImport(strings.NewReader(input), warn); err != nil {
+		t.Fatalf("parseJSONLImport: %v", err)
+	}
+	if len(warn.issues) != 3 || len(warn.lineErrors) != 0 || len(warn.warnings) != 1 {
+		t.Errorf("warn: %d issues, line errors %+v, warnings %q; want 3 issues and one warning", len(warn.issues), warn.lineErrors, warn.warnings)
+	}
+}
@@ -0,0 +1,266 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/tracker"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// taskwarriorAuthor is the comment author recorded for imported
+// annotations. TaskWarrior does not track who wrote an annotation, and a
+// fixed author lets re-imports recognize comments that already exist.
+const taskwarriorAuthor = "taskwarrior"
+
+// taskwarriorTask is one task as written by 'task export'.
+type taskwarriorTask struct {
+	UUID        string          `json:"uuid"`
+	Description string          `json:"description"`
+	Status      string          `json:"status"`
+	Project     string          `json:"project"`
+	Tags        []string        `json:"tags"`
+	Priority    string          `json:"priority"`
+	Entry       string          `json:"entry"`
+	Modified    string          `json:"modified"`
+	Start       string          `json:"start"`
+	End         string          `json:"end"`
+	Due         string          `json:"due"`
+	Wait        string          `json:"wait"`
+	Depends     json.RawMessage `json:"depends"`
+	Annotations []struct {
+		Entry       string `json:"entry"`
+		Description string `json:"description"`
+	} `json:"annotations"`
+}
+
+// taskwarriorExternalRef is the external_ref of an imported task, used to
+// update it on re-import and to resolve depends entries.
+func taskwarriorExternalRef(uuid string) string {
+	return "taskwarrior:" + uuid
+}
+
+// parseTaskWarriorTime parses TaskWarrior's compact UTC timestamps
+// ("20240115T103000Z"), accepting RFC3339 as well.
+func parseTaskWarriorTime(s string) (time.Time, error) {
+	if t, err := time.Parse("20060102T150405Z", s); err == nil {
+		return t, nil
+	}
+	if t, err := time.Parse(time.RFC3339, s); err == nil {
+		return t.UTC(), nil
+	}
+	return time.Time{}, fmt.Errorf("unrecognized timestamp %q", s)
+}
+
+// taskwarriorDepends returns the UUIDs a task depends on. TaskWarrior 2.6+
+// writes an array; older versions write one comma-separated string.
+func taskwarriorDepends(raw json.RawMessage) ([]string, error) {
+	if len(raw) == 0 || string(raw) == "null" {
+		return nil, nil
+	}
+	var list []string
+	if err := json.Unmarshal(raw, &list); err == nil {
+		return list, nil
+	}
+	var joined string
+	if err := json.Unmarshal(raw, &joined); err != nil {
+		return nil, fmt.Errorf("invalid depends: %s", raw)
+	}
+	for _, uuid := range strings.Split(joined, ",") {
+		if uuid = strings.TrimSpace(uuid); uuid != "" {
+			list = append(list, uuid)
+		}
+	}
+	return list, nil
+}
+
+// readTaskWarriorExport decodes 'task export' output: a JSON array, or the
+// stream of task objects older versions print.
+func readTaskWarriorExport(r io.Reader) ([]taskwarriorTask, error) {
+	dec := json.NewDecoder(r)
+	var tasks []taskwarriorTask
+	for {
+		var raw json.RawMessage
+		if err := dec.Decode(&raw); err == io.EOF {
+			break
+		} else if err != nil {
+			return nil, fmt.Errorf("parse TaskWarrior export: %w", err)
+		}
+		if len(raw) > 0 && raw[0] == '[' {
+			var batch []taskwarriorTask
+			if err := json.Unmarshal(raw, &batch); err != nil {
+				return nil, fmt.Errorf("parse TaskWarrior export: %w", err)
+			}
+			tasks = append(tasks, batch...)
+			continue
+		}
+		var task taskwarriorTask
+		if err := json.Unmarshal(raw, &task); err != nil {
+			return nil, fmt.Errorf("parse TaskWarrior export: %w", err)
+		}
+		tasks = append(tasks, task)
+	}
+	return tasks, nil
+}
+
+// taskwarriorToIssue converts a task to a beads issue. The project and
+// tags become labels and annotations become comments.
+func taskwarriorToIssue(task *taskwarriorTask) (*types.Issue, error) {
+	ref := taskwarriorExternalRef(task.UUID)
+	issue := &types.Issue{
+		Title:        task.Description,
+		ExternalRef:  &ref,
+		SourceSystem: "taskwarrior",
+		IssueType:    types.TypeTask,
+		Status:       types.StatusOpen,
+	}
+	switch strings.ToUpper(task.Priority) {
+	case "H":
+		issue.Priority = 1
+	case "L":
+		issue.Priority = 3
+	default:
+		issue.Priority = 2
+	}
+	if task.Project != "" {
+		issue.Labels = append(issue.Labels, task.Project)
+	}
+	issue.Labels = append(issue.Labels, task.Tags...)
+
+	times := map[string]time.Time{}
+	for name, value := range map[string]string{
+		"entry":    task.Entry,
+		"modified": task.Modified,
+		"start":    task.Start,
+		"end":      task.End,
+		"due":      task.Due,
+		"wait":     task.Wait,
+	} {
+		if value == "" {
+			continue
+		}
+		t, err := parseTaskWarriorTime(value)
+		if err != nil {
+			return nil, fmt.Errorf("%s: %w", name, err)
+		}
+		times[name] = t
+	}
+	issue.CreatedAt = times["entry"]
+	issue.UpdatedAt = times["modified"]
+	if due, ok := times["due"]; ok {
+		issue.DueAt = &due
+	}
+
+	switch task.Status {
+	case "completed":
+		issue.Status = types.StatusClosed
+		closed, ok := times["end"]
+		if !ok {
+			closed = issue.UpdatedAt
+		}
+		if !closed.IsZero() {
+			issue.ClosedAt = &closed
+		}
+	case "waiting":
+		issue.Status = types.StatusDeferred
+	default:
+		if _, ok := times["start"]; ok {
+			issue.Status = types.StatusInProgress
+		}
+	}
+	if wait, ok := times["wait"]; ok && issue.Status != types.StatusClosed {
+		issue.DeferUntil = &wait
+		if wait.After(time.Now()) {
+			issue.Status = types.StatusDeferred
+		}
+	}
+
+	for _, a := range task.Annotations {
+		comment := &types.Comment{Author: taskwarriorAuthor, Text: a.Description}
+		if a.Entry != "" {
+			t, err := parseTaskWarriorTime(a.Entry)
+			if err != nil {
+				return nil, fmt.Errorf("annotation: %w", err)
+			}
+			comment.CreatedAt = t
+		}
+		issue.Comments = append(issue.Comments, comment)
+	}
+	return issue, nil
+}
+
+// parseTaskWarriorImport reads 'task export' JSON into c. Deleted tasks and
+// recurrence templates are skipped. Each issue's external_ref is
+// "taskwarrior:<uuid>", so re-importing updates issues in place, and depends
+// entries become blocking dependencies once both tasks are imported. Tasks
+// are reported by their position in the export.
+func parseTaskWarriorImport(ctx context.Context, r io.Reader, c *importCollector) error {
+	tasks, err := readTaskWarriorExport(r)
+	if err != nil {
+		return err
+	}
+	for i := range tasks {
+		task := &tasks[i]
+		pos := i + 1
+		if task.Status == "deleted" || task.Status == "recurring" {
+			continue
+		}
+		if task.UUID == "" {
+			c.fail(pos, "task has no uuid; expected 'task export' JSON")
+			continue
+		}
+		issue, err := taskwarriorToIssue(task)
+		if err != nil {
+			c.fail(pos, "task %s: %v", task.UUID, err)
+			continue
+		}
+		depends, err := taskwarriorDepends(task.Depends)
+		if err != nil {
+			c.fail(pos, "task %s: %v", task.UUID, err)
+			continue
+		}
+
+		existing, err := store.GetIssueByExternalRef(ctx, *issue.ExternalRef)
+		if err != nil && !errors.Is(err, storage.ErrNotFound) {
+			return fmt.Errorf("failed to look up %s: %w", *issue.ExternalRef, err)
+		}
+		if existing != nil {
+			issue = mergeTaskWarriorIssue(existing, issue)
+		}
+		c.addIssue(pos, issue)
+
+		for _, uuid := range depends {
+			c.deps = append(c.deps, tracker.DependencyInfo{
+				FromExternalID: *issue.ExternalRef,
+				ToExternalID:   taskwarriorExternalRef(uuid),
+				Type:           string(types.DepBlocks),
+				Source:         tracker.DependencySourceRelation,
+			})
+		}
+	}
+	return nil
+}
+
+// mergeTaskWarriorIssue overlays the fields a task provides onto a copy of
+// the local issue linked to it.
+func mergeTaskWarriorIssue(local, incoming *types.Issue) *types.Issue {
+	merged := *local
+	merged.Title = incoming.Title
+	merged.Status = incoming.Status
+	merged.Priority = incoming.Priority
+	merged.Labels = incoming.Labels
+	merged.DueAt = incoming.DueAt
+	merged.DeferUntil = incoming.DeferUntil
+	merged.ClosedAt = incoming.ClosedAt
+	merged.Comments = incoming.Comments
+	if !incoming.UpdatedAt.IsZero() {
+		merged.UpdatedAt = incoming.UpdatedAt
+	}
+	return &merged
+}
@@ -0,0 +1,65 @@
+package main
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+const sampleTaskWarriorExport = `[
+{"id":1,"uuid":"a1","description":"Paint fence","status":"pending","project":"Home.Garden","tags":["weekend"],"priority":"H","entry":"20240115T103000Z","modified":"20240116T090000Z","start":"20240116T090000Z","due":"20240120T000000Z","depends":["b2"],"annotations":[{"entry":"20240116T091500Z","description":"Buy white paint"}]},
+{"id":0,"uuid":"b2","description":"Buy brushes","status":"completed","entry":"20240110T080000Z","end":"20240112T170000Z"},
+{"id":0,"uuid":"c3","description":"Old idea","status":"deleted"}
+]`
+
+func TestReadTaskWarriorExport(t *testing.T) {
+	tasks, err := readTaskWarriorExport(strings.NewReader(sampleTaskWarriorExport))
+	if err != nil {
+		t.Fatalf("readTaskWarriorExport: %v", err)
+	}
+	if len(tasks) != 3 {
+		t.Fatalf("got %d tasks, want 3", len(tasks))
+	}
+
+	paint, err := taskwarriorToIssue(&tasks[0])
+	if err != nil {
+		t.Fatalf("taskwarriorToIssue: %v", err)
+	}
+	if paint.Status != types.StatusInProgress || paint.Priority != 1 {
+		t.Errorf("status/priority = %s/%d, want in_progress/1", paint.Status, paint.Priority)
+	}
+	if strings.Join(paint.Labels, ",") != "Home.Garden,weekend" {
+		t.Errorf("labels = %v", paint.Labels)
+	}
+	if paint.ExternalRef == nil || *paint.ExternalRef != "taskwarrior:a1" {
+		t.Errorf("external_ref = %v", paint.ExternalRef)
+	}
+	if paint.DueAt == nil || !paint.DueAt.Equal(time.Date(2024, 1, 20, 0, 0, 0, 0, time.UTC)) {
+		t.Errorf("due_at = %v", paint.DueAt)
+	}
+	if len(paint.Comments) != 1 || paint.Comments[0].Text != "Buy white paint" || paint.Comments[0].Author != taskwarriorAuthor {
+		t.Errorf("comments = %+v", paint.Comments)
+	}
+
+	brushes, err := taskwarriorToIssue(&tasks[1])
+	if err != nil {
+		t.Fatalf("taskwarriorToIssue: %v", err)
+	}
+	if brushes.Status != types.StatusClosed || brushes.ClosedAt == nil || !brushes.ClosedAt.Equal(time.Date(2024, 1, 12, 17, 0, 0, 0, time.UTC)) {
+		t.Errorf("status = %s, closed_at = %v", brushes.Status, brushes.ClosedAt)
+	}
+}
+
+func TestTaskWarriorDependsFormats(t *testing.T) {
+	for _, raw := range []string{`["a1","b2"]`, `"a1,b2"`} {
+		got, err := taskwarriorDepends([]byte(raw))
+		if err != nil {
+			t.Fatalf("taskwarriorDepends(%s): %v", raw, err)
+		}
+		if strings.Join(got, ",") != "a1,b2" {
+			t.Errorf("taskwarriorDepends(%s) = %v", raw, got)
+		}
+	}
+}
@@ -0,0 +1,171 @@
+package main
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/storage"
+)
+
+// jsonlFileHashKey is the local metadata key holding the hash of the JSONL
+// file as the database last wrote or read it. A different file on disk
+// was changed behind the database's back, e.g. by a git pull.
+const jsonlFileHashKey = "jsonl_file_hash"
+
+// jsonlFileStatKey is the local metadata key holding the size and
+// modification time of the JSONL file when its hash was stored. While they
+// match, the file is taken to be unchanged without hashing it again.
+const jsonlFileStatKey = "jsonl_file_stat"
+
+// jsonlFileHash returns the hex SHA-256 of the file at path, or "" if there
+// is no such file.
+func jsonlFileHash(path string) (string, error) {
+	f, err := os.Open(path) // #nosec G304 -- the configured JSONL path
+	if os.IsNotExist(err) {
+		return "", nil
+	}
+	if err != nil {
+		return "", err
+	}
+	defer f.Close()
+	h := sha256.New()
+	if _, err := io.Copy(h, f); err != nil {
+		return "", err
+	}
+	return hex.EncodeToString(h.Sum(nil)), nil
+}
+
+// jsonlFileStat returns the size and modification time of the file at path
+// as "size:mtime-nanoseconds", or "" if there is no such file.
+func jsonlFileStat(path string) (string, error) {
+	info, err := os.Stat(path)
+	if os.IsNotExist(err) {
+		return "", nil
+	}
+	if err != nil {
+		return "", err
+	}
+	return fmt.Sprintf("%d:%d", info.Size(), info.ModTime().UnixNano()), nil
+}
+
+// storeJSONLFileState records the hash and stat of the JSONL file.
+func storeJSONLFileState(ctx context.Context, st storage.DoltStorage, hash, stat string) {
+	if err := st.SetLocalMetadata(ctx, jsonlFileHashKey, hash); err != nil {
+		debug.Logf("jsonl hash: failed to store: %v\n", err)
+		return
+	}
+	if err := st.SetLocalMetadata(ctx, jsonlFileStatKey, stat); err != nil {
+		debug.Logf("jsonl hash: failed to store stat: %v\n", err)
+	}
+}
+
+// isTrackedJSONLPath reports whether path is the JSONL file that
+// auto-export writes or auto-import reads in the current workspace.
+func isTrackedJSONLPath(path string) bool {
+	beadsDir := beads.FindBeadsDir()
+	if beadsDir == "" || path == "" {
+		return false
+	}
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return false
+	}
+	for _, p := range []string{autoExportPath(beadsDir), configuredImportJSONLPath(beadsDir)} {
+		if pAbs, err := filepath.Abs(p); err == nil && pAbs == abs {
+			return true
+		}
+	}
+	return false
+}
+
+// recordJSONLFileHash notes that the database and the JSONL file at path
+// are in step, after an export to it or an import from it. Best effort:
+// without a stored hash, divergence goes unchecked.
+func recordJSONLFileHash(ctx context.Context, st storage.DoltStorage, path string) {
+	if st == nil || !isTrackedJSONLPath(path) {
+		return
+	}
+	stat, err := jsonlFileStat(path)
+	if err != nil {
+		debug.Logf("jsonl hash: %v\n", err)
+		return
+	}
+	hash, err := jsonlFileHash(path)
+	if err != nil {
+		debug.Logf("jsonl hash: %v\n", err)
+		return
+	}
+	storeJSONLFileState(ctx, st, hash, stat)
+}
+
+// shouldCheckJSONLDivergence reports whether cmd should compare the JSONL
+// file with the database before running. Commands that read or write the
+// file themselves, server mode, and workspaces syncing through a Dolt
+// remote (where the file is only an export) are skipped.
+func shouldCheckJSONLDivergence(cmd *cobra.Command, s storage.DoltStorage, globalFlag, serverMode bool) bool {
+	if cmd == nil || s == nil || globalFlag || serverMode {
+		return false
+	}
+	switch cmd.Name() {
+	case "import", "export", "sync", "init", "bootstrap", "merge-file":
+		return false
+	}
+	return resolveSyncRemote() == ""
+}
+
+// checkJSONLDivergence compares the JSONL file in beadsDir with the hash
+// stored when the database last wrote or read it. The file is only hashed
+// when its size or modification time differs from the stored ones. If the
+// file changed, it is re-imported when import.auto is set and the command
+// may write; otherwise a one-line warning says how to load it.
+func checkJSONLDivergence(ctx context.Context, s storage.DoltStorage, beadsDir string, readOnly bool) {
+	stored, err := s.GetLocalMetadata(ctx, jsonlFileHashKey)
+	if err != nil || stored == "" {
+		return // never recorded: nothing to compare against
+	}
+	path := configuredImportJSONLPath(beadsDir)
+	stat, err := jsonlFileStat(path)
+	if err != nil || stat == "" {
+		return
+	}
+	if storedStat, err := s.GetLocalMetadata(ctx, jsonlFileStatKey); err == nil && storedStat == stat {
+		return
+	}
+	current, err := jsonlFileHash(path)
+	if err != nil || current == "" {
+		return
+	}
+	if current == stored {
+		// Touched but not changed (a checkout, a rewrite of the same
+		// content): remember the new stat so the next command skips the hash.
+		if !readOnly {
+			storeJSONLFileState(ctx, s, current, stat)
+		}
+		return
+	}
+
+	if readOnly || !config.GetBool("import.auto") {
+		fmt.Fprintf(os.Stderr, "warning: %s changed since the database last read it (e.g. after a git pull); run 'bd import' to load it\n", path)
+		return
+	}
+	result, err := importFromLocalJSONLFull(ctx, s, path)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "warning: %s changed since the database last read it, and re-importing it failed: %v; run 'bd import' to retry\n", path, err)
+		return
+	}
+	if err := s.Commit(ctx, fmt.Sprintf("auto-import: %s changed on disk", filepath.Base(path))); err != nil && !isDoltNothingToCommit(err) {
+		fmt.Fprintf(os.Stderr, "warning: auto-import: dolt commit failed: %v\n", err)
+		return
+	}
+	storeJSONLFileState(ctx, s, current, stat)
+	fmt.Fprintf(os.Stderr, "auto-imported %d issues from %s, which changed on disk\n", result.Issues, path)
+}
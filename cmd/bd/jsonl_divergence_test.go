@@ -0,0 +1,75 @@
+package main
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/storage"
+)
+
+// localMetadataStore keeps local metadata in a map; any other store
+// method panics.
+type localMetadataStore struct {
+	storage.DoltStorage
+	local map[string]string
+}
+
+func (s *localMetadataStore) GetLocalMetadata(_ context.Context, key string) (string, error) {
+	return s.local[key], nil
+}
+
+func (s *localMetadataStore) SetLocalMetadata(_ context.Context, key, value string) error {
+	s.local[key] = value
+	return nil
+}
+
+func TestCheckJSONLDivergence(t *testing.T) {
+	dir := t.TempDir()
+	writeAutoImportFixtureJSONL(t, dir)
+	path := filepath.Join(dir, "issues.jsonl")
+	hash, err := jsonlFileHash(path)
+	if err != nil || hash == "" {
+		t.Fatalf("jsonlFileHash = %q, %v", hash, err)
+	}
+	if missing, err := jsonlFileHash(filepath.Join(dir, "missing.jsonl")); err != nil || missing != "" {
+		t.Fatalf("jsonlFileHash(missing) = %q, %v", missing, err)
+	}
+
+	// In step, and never recorded: nothing to do (an import would panic).
+	st := &localMetadataStore{local: map[string]string{jsonlFileHashKey: hash}}
+	checkJSONLDivergence(context.Background(), st, dir, false)
+	st.local = map[string]string{}
+	checkJSONLDivergence(context.Background(), st, dir, false)
+
+	// Changed on disk, but the command is read-only: warn, keep the old hash.
+	st.local[jsonlFileHashKey] = hash
+	if err := os.WriteFile(path, []byte(`{"id":"unit-2","title":"pulled"}`+"\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	checkJSONLDivergence(context.Background(), st, dir, true)
+	if st.local[jsonlFileHashKey] != hash {
+		t.Errorf("stored hash changed on a read-only check")
+	}
+
+	// Same size and mtime as recorded: the file is not hashed, so the stale
+	// hash goes unnoticed (an import would panic).
+	stat, err := jsonlFileStat(path)
+	if err != nil || stat == "" {
+		t.Fatalf("jsonlFileStat = %q, %v", stat, err)
+	}
+	st.local[jsonlFileStatKey] = stat
+	checkJSONLDivergence(context.Background(), st, dir, false)
+
+	// Touched but unchanged: the new stat is stored with the same hash.
+	current, err := jsonlFileHash(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	st.local = map[string]string{jsonlFileHashKey: current, jsonlFileStatKey: "0:0"}
+	checkJSONLDivergence(context.Background(), st, dir, false)
+	if st.local[jsonlFileStatKey] != stat || st.local[jsonlFileHashKey] != current {
+		t.Errorf("after an unchanged check, local = %v; want stat %q", st.local, stat)
+	}
+}
@@ -0,0 +1,69 @@
+package main
+
+import (
+	"encoding/json"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// jsonlFormatVersion is the record format bd export writes. Every issue and
+// memory line carries it as "_format", so a file whose lines were written by
+// different bd versions (collaborators on mixed releases, merged branches)
+// can still be read one line at a time. Bump it when a field is renamed or
+// changes meaning, and teach upgradeJSONLIssue to translate the old form.
+//
+//	0  lines without "_format", written before records were versioned.
+//	   v0.35–v0.37 wrote "wisp" for what is now "ephemeral".
+//	1  adds "_format".
+const jsonlFormatVersion = 1
+
+// jsonlFormatOf returns the format version of a JSONL record: its "_format"
+// field, else fileVersion (from a beads-jsonl header line, or 0).
+func jsonlFormatOf(peek map[string]json.RawMessage, fileVersion int) int {
+	raw, ok := peek["_format"]
+	if !ok {
+		return fileVersion
+	}
+	var v int
+	if err := json.Unmarshal(raw, &v); err != nil {
+		return fileVersion
+	}
+	return v
+}
+
+// jsonlHeaderFormat returns the version named by a header line's
+// "_schema":"beads-jsonl/N", or 0 when it names none.
+func jsonlHeaderFormat(peek map[string]json.RawMessage) int {
+	var schema string
+	if err := json.Unmarshal(peek["_schema"], &schema); err != nil {
+		return 0
+	}
+	v, err := strconv.Atoi(strings.TrimPrefix(schema, "beads-jsonl/"))
+	if err != nil {
+		return 0
+	}
+	return v
+}
+
+// upgradeJSONLIssue translates an issue decoded from a record of the given
+// format version into the current form. Fields the current version does not
+// know were already dropped by decoding.
+func upgradeJSONLIssue(issue *types.Issue, peek map[string]json.RawMessage, format int) {
+	if format < 1 {
+		if _, hasWisp := peek["wisp"]; hasWisp && !issue.Ephemeral {
+			var wisp bool
+			if err := json.Unmarshal(peek["wisp"], &wisp); err == nil && wisp {
+				issue.Ephemeral = true
+			}
+		}
+	}
+}
+
+// jsonlNewerFormatWarning explains that records were written by a newer bd.
+// They are still imported; only the fields this version knows survive.
+func jsonlNewerFormatWarning(format int) string {
+	return fmt.Sprintf("JSONL records use format %d but this bd understands format %d; unknown fields are ignored (upgrade bd to keep them)", format, jsonlFormatVersion)
+}
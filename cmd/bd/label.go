@@ -189,14 +189,13 @@ var labelListAllCmd = &cobra.Command{
 		if err != nil {
 			FatalErrorRespectJSON("%v", err)
 		}
-		// Collect unique labels with counts
+		// Collect unique labels with counts (one batched label query)
+		labelsByIssue, err := store.GetLabelsForIssues(ctx, collectIssueIDs(issues))
+		if err != nil {
+			FatalErrorRespectJSON("getting labels: %v", err)
+		}
 		labelCounts := make(map[string]int)
-		for _, issue := range issues {
-			// Direct mode - need to fetch labels
-			labels, err := store.GetLabels(ctx, issue.ID)
-			if err != nil {
-				FatalErrorRespectJSON("getting labels for %s: %v", issue.ID, err)
-			}
+		for _, labels := range labelsByIssue {
 			for _, label := range labels {
 				labelCounts[label]++
 			}
@@ -317,6 +316,117 @@ var labelPropagateCmd = &cobra.Command{
 	},
 }
 
+var labelRenameCmd = &cobra.Command{
+	Use:   "rename [old] [new]",
+	Short: "Rename a label on every issue that has it",
+	Long: `Rename a label on every issue that carries it, in a single commit.
+
+With --namespace, old and new are namespaces rather than labels: every label
+of the form old/<name> becomes new/<name>. Labels can be grouped into
+namespaces with a slash (area/storage, area/cli) and filtered together with
+a trailing wildcard, e.g. 'bd list --label "area/*"'.
+
+Examples:
+  bd label rename bug defect                  # Rename one label everywhere
+  bd label rename --namespace area component  # area/storage -> component/storage`,
+	Args: cobra.ExactArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("label rename")
+		ctx := rootCtx
+		namespace, _ := cmd.Flags().GetBool("namespace")
+
+		oldName := strings.TrimSuffix(strings.TrimSpace(args[0]), "/")
+		newName := strings.TrimSuffix(strings.TrimSpace(args[1]), "/")
+		if oldName == "" || newName == "" {
+			FatalErrorRespectJSON("label cannot be empty")
+		}
+		if oldName == newName {
+			FatalErrorRespectJSON("old and new names are the same: %s", oldName)
+		}
+		if strings.HasPrefix(oldName, "provides:") || strings.HasPrefix(newName, "provides:") {
+			FatalErrorRespectJSON("'provides:' labels are reserved for cross-project capabilities and cannot be renamed")
+		}
+
+		match := oldName
+		if namespace {
+			match = oldName + "/*"
+		}
+		issues, err := store.SearchIssues(ctx, "", types.IssueFilter{Labels: []string{match}})
+		if err != nil {
+			FatalErrorRespectJSON("searching issues labeled %s: %v", match, err)
+		}
+		labelsByIssue, err := store.GetLabelsForIssues(ctx, collectIssueIDs(issues))
+		if err != nil {
+			FatalErrorRespectJSON("getting labels: %v", err)
+		}
+		renames := planLabelRenames(issues, labelsByIssue, oldName, newName, namespace)
+
+		if len(renames) == 0 {
+			if jsonOutput {
+				outputJSON([]labelRename{})
+			} else {
+				fmt.Printf("No issues labeled %s\n", match)
+			}
+			return
+		}
+
+		kind := "label"
+		if namespace {
+			kind = "label namespace"
+		}
+		commitMsg := fmt.Sprintf("bd: rename %s '%s' to '%s' on %d issue(s)", kind, oldName, newName, len(issues))
+		err = transact(ctx, store, commitMsg, func(tx storage.Transaction) error {
+			for _, r := range renames {
+				if err := tx.AddLabel(ctx, r.IssueID, r.NewLabel, actor); err != nil {
+					return fmt.Errorf("add label '%s' on %s: %w", r.NewLabel, r.IssueID, err)
+				}
+				if err := tx.RemoveLabel(ctx, r.IssueID, r.OldLabel, actor); err != nil {
+					return fmt.Errorf("remove label '%s' on %s: %w", r.OldLabel, r.IssueID, err)
+				}
+			}
+			return nil
+		})
+		if err != nil {
+			FatalErrorRespectJSON("label rename: %v", err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(renames)
+			return
+		}
+		for _, r := range renames {
+			fmt.Printf("%s Renamed label '%s' to '%s' on %s\n", ui.RenderPass("✓"), r.OldLabel, r.NewLabel, r.IssueID)
+		}
+	},
+}
+
+// labelRename is one label change made by 'bd label rename'.
+type labelRename struct {
+	IssueID  string `json:"issue_id"`
+	OldLabel string `json:"old_label"`
+	NewLabel string `json:"new_label"`
+}
+
+// planLabelRenames lists the label changes needed to rename oldName to
+// newName on issues. With namespace set, every oldName/<rest> label maps to
+// newName/<rest>.
+func planLabelRenames(issues []*types.Issue, labelsByIssue map[string][]string, oldName, newName string, namespace bool) []labelRename {
+	var renames []labelRename
+	for _, issue := range issues {
+		for _, label := range labelsByIssue[issue.ID] {
+			if namespace {
+				if rest, ok := strings.CutPrefix(label, oldName+"/"); ok {
+					renames = append(renames, labelRename{IssueID: issue.ID, OldLabel: label, NewLabel: newName + "/" + rest})
+				}
+			} else if label == oldName {
+				renames = append(renames, labelRename{IssueID: issue.ID, OldLabel: label, NewLabel: newName})
+			}
+		}
+	}
+	return renames
+}
+
 func init() {
 	// Issue ID completions
 	labelAddCmd.ValidArgsFunction = issueIDCompletion
@@ -324,10 +434,13 @@ func init() {
 	labelListCmd.ValidArgsFunction = issueIDCompletion
 	labelPropagateCmd.ValidArgsFunction = issueIDCompletion
 
+	labelRenameCmd.Flags().Bool("namespace", false, "Treat old and new as namespaces and rename every old/* label")
+
 	labelCmd.AddCommand(labelAddCmd)
 	labelCmd.AddCommand(labelRemoveCmd)
 	labelCmd.AddCommand(labelListCmd)
 	labelCmd.AddCommand(labelListAllCmd)
 	labelCmd.AddCommand(labelPropagateCmd)
+	labelCmd.AddCommand(labelRenameCmd)
 	rootCmd.AddCommand(labelCmd)
 }
@@ -311,6 +311,34 @@ func TestEmbeddedLabel(t *testing.T) {
 		}
 	})
 
+	// ===== Label Namespaces =====
+
+	t.Run("label_namespace_wildcard_and_rename", func(t *testing.T) {
+		storageIssue := bdCreate(t, bd, dir, "Namespace storage", "--type", "task")
+		cliIssue := bdCreate(t, bd, dir, "Namespace cli", "--type", "task")
+		bdLabel(t, bd, dir, "add", storageIssue.ID, "area/storage")
+		bdLabel(t, bd, dir, "add", cliIssue.ID, "area/cli")
+		bdLabel(t, bd, dir, "add", cliIssue.ID, "areas")
+
+		issues := bdListJSON(t, bd, dir, "--label", "area/*")
+		if len(issues) != 2 {
+			t.Fatalf("--label area/* returned %d issues, want 2", len(issues))
+		}
+
+		bdLabel(t, bd, dir, "rename", "--namespace", "area", "component")
+		if labels := bdLabelListJSON(t, bd, dir, storageIssue.ID); len(labels) != 1 || labels[0] != "component/storage" {
+			t.Errorf("labels on %s = %v, want [component/storage]", storageIssue.ID, labels)
+		}
+		if labels := bdLabelListJSON(t, bd, dir, cliIssue.ID); strings.Join(labels, ",") != "areas,component/cli" {
+			t.Errorf("labels on %s = %v, want [areas component/cli]", cliIssue.ID, labels)
+		}
+
+		bdLabel(t, bd, dir, "rename", "areas", "zones")
+		if labels := bdLabelListJSON(t, bd, dir, cliIssue.ID); strings.Join(labels, ",") != "component/cli,zones" {
+			t.Errorf("labels on %s = %v, want [component/cli zones]", cliIssue.ID, labels)
+		}
+	})
+
 	// ===== Error Cases =====
 
 	t.Run("label_add_empty_label", func(t *testing.T) {
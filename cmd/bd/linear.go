@@ -86,7 +86,8 @@ Examples:
   bd linear sync --dry-run      # Preview sync without changes
   bd create "Fix login" --external-ref https://linear.app/team/issue/TEAM-123
                               # Link a local issue to an existing Linear issue
-  bd linear status              # Show sync status`,
+  bd linear status              # Show sync status
+  bd linear link                # Configure workflow state mapping for push`,
 }
 
 // linearSyncCmd handles synchronization with Linear.
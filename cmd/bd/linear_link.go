@@ -0,0 +1,167 @@
+package main
+
+import (
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/linear"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// linearLinkCmd configures the explicit workflow state mapping push needs.
+var linearLinkCmd = &cobra.Command{
+	Use:   "link",
+	Short: "Configure Linear workflow state mapping for push",
+	Long: `Fetch the workflow states of the configured Linear team(s) and write the
+linear.state_map.* entries that 'bd linear sync --push' requires.
+
+Push only trusts explicit state mappings, because a team can have several
+states of the same type (e.g. "Todo" and "Triage" are both unstarted).
+This command picks one state per beads status:
+
+  open         first unstarted state (or backlog if there is none)
+  in_progress  first started state
+  closed       first completed state
+  blocked      a state named "Blocked", if the team has one
+
+Pull is unaffected: every Linear state still maps through its state type.
+Existing linear.state_map.* entries are left alone unless --force is given,
+in which case they are replaced. Adjust individual entries afterwards with
+'bd config set linear.state_map.<state name> <status>'.
+
+Examples:
+  bd linear link              # Configure mapping for the configured team(s)
+  bd linear link --dry-run    # Show the mapping without saving it
+  bd linear link --force      # Replace an existing mapping`,
+	Run: runLinearLink,
+}
+
+func init() {
+	linearLinkCmd.Flags().Bool("dry-run", false, "Show the proposed mapping without saving it")
+	linearLinkCmd.Flags().Bool("force", false, "Replace existing linear.state_map.* entries")
+	linearLinkCmd.Flags().StringSlice("team", nil, "Team ID(s) to configure (overrides configured team_id/team_ids)")
+	linearCmd.AddCommand(linearLinkCmd)
+}
+
+func runLinearLink(cmd *cobra.Command, args []string) {
+	ctx := rootCtx
+	dryRun, _ := cmd.Flags().GetBool("dry-run")
+	force, _ := cmd.Flags().GetBool("force")
+	cliTeams, _ := cmd.Flags().GetStringSlice("team")
+
+	if !dryRun {
+		CheckReadonly("linear link")
+	}
+	if err := ensureStoreActive(); err != nil {
+		FatalError("database not available: %v", err)
+	}
+
+	teamIDs := getLinearTeamIDs(ctx, cliTeams)
+	if len(teamIDs) == 0 {
+		FatalError("no Linear team configured\nRun: bd config set linear.team_id \"TEAM_ID\" (see 'bd linear teams')")
+	}
+
+	allConfig, err := store.GetAllConfig(ctx)
+	if err != nil {
+		FatalError("reading config: %v", err)
+	}
+	const statePrefix = "linear.state_map."
+	var existing []string
+	for key := range allConfig {
+		if strings.HasPrefix(key, statePrefix) {
+			existing = append(existing, key)
+		}
+	}
+	sort.Strings(existing)
+	if len(existing) > 0 && !force && !dryRun {
+		FatalError("linear.state_map is already configured (%s)\nUse --force to replace it", strings.Join(existing, ", "))
+	}
+
+	// Teams share one name-keyed map, so a state name suggested for one team
+	// can also match a state of another team. Each team is re-checked
+	// against the merged map below, the same way push resolves states.
+	mapping := make(map[string]string)
+	teamStates := make(map[string][]linear.State, len(teamIDs))
+	for _, teamID := range teamIDs {
+		client, err := buildLinearClient(ctx, teamID)
+		if err != nil {
+			FatalError("%v", err)
+		}
+		states, err := client.GetTeamStates(ctx)
+		if err != nil {
+			FatalError("fetching workflow states for team %s: %v", teamID, err)
+		}
+		teamStates[teamID] = states
+		for name, status := range linear.SuggestStateMap(states) {
+			if prev, ok := mapping[name]; ok && prev != status {
+				FatalError("state %q maps to both %s and %s across teams; configure linear.state_map manually", name, prev, status)
+			}
+			mapping[name] = status
+		}
+	}
+	if len(mapping) == 0 {
+		FatalError("no workflow states found for team(s) %s", strings.Join(teamIDs, ", "))
+	}
+	check := linear.DefaultMappingConfig()
+	check.ExplicitStateMap = mapping
+	for _, teamID := range teamIDs {
+		cache := &linear.StateCache{States: teamStates[teamID]}
+		for _, status := range []types.Status{types.StatusOpen, types.StatusInProgress, types.StatusClosed} {
+			if _, err := linear.ResolveStateIDForBeadsStatus(cache, status, check); err != nil {
+				FatalError("team %s: %v\nConfigure linear.state_map manually with 'bd config set'", teamID, err)
+			}
+		}
+	}
+
+	names := make([]string, 0, len(mapping))
+	for name := range mapping {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	if !dryRun {
+		if force {
+			for _, key := range existing {
+				if err := store.DeleteConfig(ctx, key); err != nil {
+					FatalError("removing %s: %v", key, err)
+				}
+			}
+		}
+		for _, name := range names {
+			if err := store.SetConfig(ctx, statePrefix+name, mapping[name]); err != nil {
+				FatalError("setting %s%s: %v", statePrefix, name, err)
+			}
+		}
+		commandDidWrite.Store(true)
+	}
+
+	if jsonOutput {
+		var replaced []string
+		if force {
+			replaced = existing
+		}
+		outputJSON(map[string]interface{}{
+			"state_map": mapping,
+			"replaced":  replaced,
+			"dry_run":   dryRun,
+		})
+		return
+	}
+
+	fmt.Printf("%-24s  %s\n", "Linear state", "Beads status")
+	for _, name := range names {
+		fmt.Printf("%-24s  %s\n", name, mapping[name])
+	}
+	fmt.Println()
+	if dryRun {
+		fmt.Println("Dry run: no changes made")
+		if len(existing) > 0 && !force {
+			fmt.Fprintln(os.Stderr, "Note: linear.state_map is already configured; saving requires --force")
+		}
+		return
+	}
+	fmt.Printf("✓ Saved %d linear.state_map entries\n", len(names))
+}
@@ -9,18 +9,25 @@ import (
 )
 
 var linkCmd = &cobra.Command{
-	Use:     "link <id1> <id2>",
+	Use:     "link <id1> <id2|commit>",
 	GroupID: "issues",
-	Short:   "Link two issues with a dependency",
+	Short:   "Link two issues with a dependency, or an issue to a commit",
 	Long: `Link two issues with a dependency.
 
 Shorthand for 'bd dep add <id1> <id2>'. By default creates a "blocks"
 dependency (id2 blocks id1). Use --type to specify a different relationship.
 
+If the second argument is a commit hash of the current git repository
+rather than an issue, the commit is linked to the issue instead and listed
+under COMMITS in 'bd show'. The post-commit hook ('bd hooks install') does
+this for the issues named in "Fixes: bd-12" and "Refs: bd-34" trailers of
+each commit message; with git.close-on-fixes it also closes those it fixes.
+
 Examples:
   bd link bd-123 bd-456                    # bd-456 blocks bd-123
   bd link bd-123 bd-456 --type related     # bd-123 related to bd-456
-  bd link bd-123 bd-456 --type parent-child`,
+  bd link bd-123 bd-456 --type parent-child
+  bd link bd-123 4f2a9c1                   # Link commit 4f2a9c1 to bd-123`,
 	Args: cobra.ExactArgs(2),
 	Run: func(cmd *cobra.Command, args []string) {
 		CheckReadonly("link")
@@ -38,6 +45,12 @@ Examples:
 		}
 		defer fromCleanup()
 
+		if sha, subject, ok := linkCommitTarget(ctx, id2); ok {
+			trailer, _ := cmd.Flags().GetString("trailer")
+			runLinkCommit(ctx, fromStore, fromID, sha, subject, trailer)
+			return
+		}
+
 		toID, _, toCleanup, err := resolveIDWithRouting(ctx, store, id2)
 		if err != nil {
 			FatalErrorRespectJSON("%v", err)
@@ -92,6 +105,8 @@ Examples:
 
 func init() {
 	linkCmd.Flags().StringP("type", "t", "blocks", "Dependency type (blocks|tracks|related|parent-child|discovered-from)")
+	linkCmd.Flags().String("trailer", "", "Trailer that named the issue when linking a commit (used by the post-commit hook)")
+	_ = linkCmd.Flags().MarkHidden("trailer")
 	linkCmd.ValidArgsFunction = issueIDCompletion
 	rootCmd.AddCommand(linkCmd)
 }
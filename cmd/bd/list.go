@@ -13,6 +13,7 @@ import (
 
 	"github.com/spf13/cobra"
 	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/query"
 	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/ui"
@@ -55,6 +56,7 @@ func readyWorkFilterFromIssueFilter(filter types.IssueFilter) types.WorkFilter {
 		ExcludeTypes:   filter.ExcludeTypes,
 		MetadataFields: filter.MetadataFields,
 		HasMetadataKey: filter.HasMetadataKey,
+		CustomFields:   filter.CustomFields,
 	}
 	if filter.IssueType != nil {
 		wf.Type = string(*filter.IssueType)
@@ -125,6 +127,8 @@ func findAllDescendants(ctx context.Context, store storage.DoltStorage, dbPath s
 		filter := baseFilter
 		filter.ParentID = &parentID
 		filter.Limit = 0 // unlimited per level to avoid truncating the tree walk
+		filter.Offset = 0
+		filter.After = nil
 		var err error
 		children, err = s.SearchIssues(ctx, "", filter)
 		return err
@@ -155,7 +159,7 @@ type watchListDependencyStore interface {
 
 func loadWatchedIssues(ctx context.Context, store storage.DoltStorage, filter types.IssueFilter, ready bool, parentID string, sortBy string, reverse bool) ([]*types.Issue, error) {
 	if ready {
-		issues, err := store.GetReadyWork(ctx, readyWorkFilterFromIssueFilter(filter))
+		issues, err := getReadyWork(ctx, store, readyWorkFilterFromIssueFilter(filter))
 		if err != nil {
 			return nil, err
 		}
@@ -205,7 +209,7 @@ func watchIssues(ctx context.Context, store storage.DoltStorage, filter types.Is
 		issues = issues[:effectiveLimit]
 	}
 	displayWatchedIssueList(ctx, store, issues)
-	printTruncationHint(truncated, effectiveLimit)
+	printTruncationHint(truncated, effectiveLimit, "")
 	lastSnapshot := issueSnapshot(issues)
 
 	fmt.Fprintf(os.Stderr, "\nWatching for changes... (Press Ctrl+C to exit)\n")
@@ -238,7 +242,7 @@ func watchIssues(ctx context.Context, store storage.DoltStorage, filter types.Is
 			if snap != lastSnapshot {
 				lastSnapshot = snap
 				displayWatchedIssueList(ctx, store, issues)
-				printTruncationHint(truncated, effectiveLimit)
+				printTruncationHint(truncated, effectiveLimit, "")
 				fmt.Fprintf(os.Stderr, "\nWatching for changes... (Press Ctrl+C to exit)\n")
 			}
 		}
@@ -296,6 +300,8 @@ func sortIssues(issues []*types.Issue, sortBy string, reverse bool) {
 			result = cmp.Compare(a.IssueType, b.IssueType)
 		case "assignee":
 			result = cmp.Compare(a.Assignee, b.Assignee)
+		case "triage":
+			result = compareTriage(a, b)
 		default:
 			// Unknown sort field, no sorting
 			result = 0
@@ -350,6 +356,8 @@ func sortIssuesWithCounts(items []*types.IssueWithCounts, sortBy string, reverse
 			result = cmp.Compare(a.IssueType, b.IssueType)
 		case "assignee":
 			result = cmp.Compare(a.Assignee, b.Assignee)
+		case "triage":
+			result = compareTriage(a.Issue, b.Issue)
 		default:
 			result = 0
 		}
@@ -443,7 +451,7 @@ func formatSkipLabelsConflictError(conflicts []string) string {
 	return fmt.Sprintf(
 		"error: --skip-labels cannot be combined with --label,\n"+
 			"       --label-any, --label-pattern, --label-regex,\n"+
-			"       --exclude-label, or --no-labels (the filter).\n"+
+			"       --exclude-label, --not-label, or --no-labels (the filter).\n"+
 			"       (got: --skip-labels %s)\n"+
 			"reason: --skip-labels suppresses the labels JOIN that those\n"+
 			"        filters depend on.\n\n"+
@@ -466,6 +474,9 @@ var knownListFlags = map[string]string{
 	"overdue": "--overdue",
 }
 
+// listSortFields are the fields 'bd list --sort' accepts.
+var listSortFields = []string{"priority", "created", "updated", "closed", "status", "id", "title", "type", "assignee", "triage"}
+
 var listCmd = &cobra.Command{
 	Use:     "list",
 	GroupID: "issues",
@@ -511,11 +522,15 @@ var listCmd = &cobra.Command{
 		longFormat, _ := cmd.Flags().GetBool("long")
 		sortBy, _ := cmd.Flags().GetString("sort")
 		reverse, _ := cmd.Flags().GetBool("reverse")
+		afterToken, _ := cmd.Flags().GetString("after")
+		offset, _ := cmd.Flags().GetInt("offset")
 
 		// Pattern matching flags
 		titleContains, _ := cmd.Flags().GetString("title-contains")
 		descContains, _ := cmd.Flags().GetString("desc-contains")
 		notesContains, _ := cmd.Flags().GetString("notes-contains")
+		titleRegex, _ := cmd.Flags().GetString("title-regex")
+		descRegex, _ := cmd.Flags().GetString("description-regex")
 
 		// Date range flags
 		createdAfter, _ := cmd.Flags().GetString("created-after")
@@ -535,6 +550,9 @@ var listCmd = &cobra.Command{
 		skipLabels, _ := cmd.Flags().GetBool("skip-labels")
 		if skipLabels {
 			conflicts := skipLabelsConflicts(labels, labelsAny, labelPattern, labelRegex, excludeLabels, noLabels)
+			if cmd.Flags().Changed("not-label") {
+				conflicts = append(conflicts, "--not-label")
+			}
 			if len(conflicts) > 0 {
 				fmt.Fprint(os.Stderr, formatSkipLabelsConflictError(conflicts))
 				os.Exit(2)
@@ -598,6 +616,7 @@ var listCmd = &cobra.Command{
 		dueAfter, _ := cmd.Flags().GetString("due-after")
 		dueBefore, _ := cmd.Flags().GetString("due-before")
 		overdueFlag, _ := cmd.Flags().GetBool("overdue")
+		effectivePriorityFlag, _ := cmd.Flags().GetBool("effective-priority")
 
 		// Pretty and watch flags (GH#654)
 		prettyFormat, _ := cmd.Flags().GetBool("pretty")
@@ -606,8 +625,11 @@ var listCmd = &cobra.Command{
 		if flatFormat {
 			treeFormat = false
 		}
-		// --tree is alias for --pretty; JSON and explicit --format win
-		prettyFormat = (prettyFormat || treeFormat) && !jsonOutput && formatStr == ""
+		// --tree is alias for --pretty; JSON, explicit --format, --columns, --fields and --group-by win
+		columnsFlag, _ := cmd.Flags().GetStringSlice("columns")
+		fieldsFlag, _ := cmd.Flags().GetStringSlice("fields")
+		groupBy, _ := cmd.Flags().GetString("group-by")
+		prettyFormat = (prettyFormat || treeFormat) && !jsonOutput && formatStr == "" && len(columnsFlag) == 0 && len(fieldsFlag) == 0 && groupBy == ""
 		watchMode, _ := cmd.Flags().GetBool("watch")
 
 		// Pager control (bd-jdz3)
@@ -616,6 +638,17 @@ var listCmd = &cobra.Command{
 		// Ready filter (bd-ihu31)
 		readyFlag, _ := cmd.Flags().GetBool("ready")
 
+		// Query expression, combined with the flag filters
+		queryExpr, _ := cmd.Flags().GetString("query")
+		var queryNode query.Node
+		if queryExpr != "" {
+			var err error
+			queryNode, err = query.Parse(queryExpr)
+			if err != nil {
+				FatalErrorRespectJSON("parsing --query: %v", err)
+			}
+		}
+
 		// Watch mode implies pretty format
 		if watchMode {
 			prettyFormat = true
@@ -652,16 +685,55 @@ var listCmd = &cobra.Command{
 		}
 
 		// Validate --sort field (bd-ttno)
-		if sortBy != "" {
-			validSortFields := map[string]bool{
-				"priority": true, "created": true, "updated": true, "closed": true,
-				"status": true, "id": true, "title": true, "type": true, "assignee": true,
+		if sortBy != "" && !slices.Contains(listSortFields, sortBy) {
+			FatalError("invalid sort field %q (valid: %s)", sortBy, strings.Join(listSortFields, ", "))
+		}
+		columns, err := parseListColumns(columnsFlag)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		fields, err := parseListColumns(fieldsFlag)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if len(fields) > 0 && (formatStr != "" || len(columns) > 0 || groupBy != "" || watchMode || effectivePriorityFlag) {
+			FatalErrorRespectJSON("--fields cannot be combined with --format, --columns, --group-by, --watch or --effective-priority")
+		}
+		if groupBy != "" {
+			if !slices.Contains(listGroupByFields, groupBy) {
+				FatalErrorRespectJSON("invalid --group-by field %q (valid: %s)", groupBy, strings.Join(listGroupByFields, ", "))
+			}
+			if formatStr != "" || len(columns) > 0 || watchMode || effectivePriorityFlag {
+				FatalErrorRespectJSON("--group-by cannot be combined with --format, --columns, --watch or --effective-priority")
 			}
-			if !validSortFields[sortBy] {
-				FatalError("invalid sort field %q (valid: priority, created, updated, closed, status, id, title, type, assignee)", sortBy)
+			if groupBy == "label" && skipLabels {
+				FatalErrorRespectJSON("--group-by label needs labels; drop --skip-labels")
 			}
 		}
 
+		// Pagination follows the default ordering only; a cursor taken from a
+		// re-sorted or ready-work listing would skip or repeat rows.
+		var afterCursor *types.IssueCursor
+		if afterToken != "" || offset != 0 {
+			if offset < 0 {
+				FatalErrorRespectJSON("--offset must be non-negative")
+			}
+			if afterToken != "" && offset != 0 {
+				FatalErrorRespectJSON("--after and --offset cannot be combined")
+			}
+			if sortBy != "" || reverse || readyFlag || watchMode {
+				FatalErrorRespectJSON("--after/--offset cannot be used with --sort, --reverse, --ready or --watch")
+			}
+			if afterToken != "" {
+				c, err := types.ParseIssueCursor(afterToken)
+				if err != nil {
+					FatalErrorRespectJSON("%v", err)
+				}
+				afterCursor = c
+			}
+		}
+		defaultOrder := sortBy == "" && !reverse && !readyFlag
+
 		// When --sort is specified, don't pass Limit to SQL — the hardcoded
 		// ORDER BY would truncate before Go-side sorting (GH#1237).
 		// Instead, apply limit in Go after sortIssues().
@@ -677,7 +749,9 @@ var listCmd = &cobra.Command{
 		}
 
 		filter := types.IssueFilter{
-			Limit: sqlLimit,
+			Limit:  sqlLimit,
+			Offset: offset,
+			After:  afterCursor,
 		}
 
 		// --ready flag: show only open issues (excludes hooked/in_progress/blocked/deferred) (bd-ihu31)
@@ -725,7 +799,7 @@ var listCmd = &cobra.Command{
 
 		// Default to non-closed/non-pinned issues unless --all, --pinned, or explicit --status (GH#788, bd-uhcg)
 		// Also exclude custom statuses in done/frozen categories
-		if status == "" && !allFlag && !readyFlag && !pinnedFlag {
+		if status == "" && !allFlag && !readyFlag && !pinnedFlag && (queryNode == nil || !hasExplicitStatusFilter(queryNode)) {
 			excludeStatuses := []types.Status{types.StatusClosed, types.StatusPinned}
 			if store != nil {
 				if detailed, err := store.GetCustomStatusesDetailed(rootCtx); err == nil {
@@ -747,6 +821,13 @@ var listCmd = &cobra.Command{
 			}
 			filter.Priority = &priority
 		}
+		if severityStr, _ := cmd.Flags().GetString("severity"); severityStr != "" {
+			severities, err := parseSeverityList(severityStr)
+			if err != nil {
+				FatalError("%v", err)
+			}
+			filter.Severities = severities
+		}
 		if assignee != "" {
 			filter.Assignee = &assignee
 		}
@@ -808,50 +889,57 @@ var listCmd = &cobra.Command{
 		if notesContains != "" {
 			filter.NotesContains = notesContains
 		}
+		filter.TitleRegex = titleRegex
+		filter.DescriptionRegex = descRegex
 
 		// Date ranges
 		if createdAfter != "" {
-			t, err := parseTimeFlag(createdAfter)
+			t, err := parseDateFilterFlag(createdAfter)
 			if err != nil {
 				FatalError("parsing --created-after: %v", err)
 			}
 			filter.CreatedAfter = &t
 		}
 		if createdBefore != "" {
-			t, err := parseTimeFlag(createdBefore)
+			t, err := parseDateFilterFlag(createdBefore)
 			if err != nil {
 				FatalError("parsing --created-before: %v", err)
 			}
 			filter.CreatedBefore = &t
 		}
 		if updatedAfter != "" {
-			t, err := parseTimeFlag(updatedAfter)
+			t, err := parseDateFilterFlag(updatedAfter)
 			if err != nil {
 				FatalError("parsing --updated-after: %v", err)
 			}
 			filter.UpdatedAfter = &t
 		}
 		if updatedBefore != "" {
-			t, err := parseTimeFlag(updatedBefore)
+			t, err := parseDateFilterFlag(updatedBefore)
 			if err != nil {
 				FatalError("parsing --updated-before: %v", err)
 			}
 			filter.UpdatedBefore = &t
 		}
 		if closedAfter != "" {
-			t, err := parseTimeFlag(closedAfter)
+			t, err := parseDateFilterFlag(closedAfter)
 			if err != nil {
 				FatalError("parsing --closed-after: %v", err)
 			}
 			filter.ClosedAfter = &t
 		}
 		if closedBefore != "" {
-			t, err := parseTimeFlag(closedBefore)
+			t, err := parseDateFilterFlag(closedBefore)
 			if err != nil {
 				FatalError("parsing --closed-before: %v", err)
 			}
 			filter.ClosedBefore = &t
 		}
+		applyUpdatedWindowFlags(cmd, &filter)
+		applyExclusionFlags(rootCtx, cmd, &filter)
+		if readyFlag && (cmd.Flags().Changed("not-status") || len(filter.ExcludeAssignees) > 0 || len(filter.ExcludeIDs) > 0) {
+			FatalError("--not-status, --not-assignee and --exclude-ids cannot be combined with --ready")
+		}
 
 		// Empty/null checks
 		if emptyDesc {
@@ -1025,6 +1113,20 @@ var listCmd = &cobra.Command{
 				filter.MetadataFields[k] = v
 			}
 		}
+		fieldFlags, _ := cmd.Flags().GetStringArray("field")
+		if len(fieldFlags) > 0 {
+			filter.CustomFields = make(map[string]string, len(fieldFlags))
+			for _, f := range fieldFlags {
+				k, v, ok := strings.Cut(f, "=")
+				if !ok || k == "" {
+					FatalErrorRespectJSON("invalid --field: expected name=value, got %q", f)
+				}
+				if err := storage.ValidateMetadataKey(k); err != nil {
+					FatalErrorRespectJSON("invalid --field name: %v", err)
+				}
+				filter.CustomFields[k] = v
+			}
+		}
 		hasMetadataKey, _ := cmd.Flags().GetString("has-metadata-key")
 		if hasMetadataKey != "" {
 			if err := storage.ValidateMetadataKey(hasMetadataKey); err != nil {
@@ -1033,8 +1135,43 @@ var listCmd = &cobra.Command{
 			filter.HasMetadataKey = hasMetadataKey
 		}
 
+		allRepos, _ := cmd.Flags().GetBool("all-repos")
+
+		// Fold --query into the filter. Terms SQL can express narrow the
+		// filter; free text becomes the search string, and anything else
+		// (OR, most NOTs, several free-text terms) is matched after fetching.
+		var queryText string
+		var queryPredicate func(*types.Issue) bool
+		if queryNode != nil {
+			result, err := query.NewEvaluator(time.Now()).EvaluateWith(queryNode, filter)
+			if err != nil {
+				FatalErrorRespectJSON("evaluating --query: %v", err)
+			}
+			filter = result.Filter
+			queryText = result.Text
+			if result.RequiresPredicate {
+				queryPredicate = result.Predicate
+				// The limit applies after matching, not in SQL.
+				filter.Limit = 0
+			}
+			if (queryText != "" || queryPredicate != nil) && (readyFlag || watchMode || allRepos) {
+				FatalErrorRespectJSON("free-text terms, OR and NOT in --query are not supported with --ready, --watch or --all-repos")
+			}
+		}
+
 		ctx := rootCtx
 
+		if allRepos {
+			if watchMode {
+				FatalError("--watch cannot be combined with --all-repos")
+			}
+			if groupBy != "" {
+				FatalError("--group-by cannot be combined with --all-repos")
+			}
+			listAllRepos(ctx, filter, readyFlag, sortBy, reverse, effectiveLimit)
+			return
+		}
+
 		activeStore := store
 		// Contributor auto-routing: read from the same target repo as bd create.
 		routedStore, routed, err := openRoutedReadStore(ctx, activeStore)
@@ -1055,28 +1192,58 @@ var listCmd = &cobra.Command{
 			var iwc []*types.IssueWithCounts
 			var err error
 			if readyFlag {
-				iwc, err = activeStore.GetReadyWorkWithCounts(ctx, readyWorkFilterFromIssueFilter(filter))
+				iwc, err = getReadyWorkWithCounts(ctx, activeStore, readyWorkFilterFromIssueFilter(filter))
 			} else {
-				iwc, err = activeStore.SearchIssuesWithCounts(ctx, "", filter)
+				iwc, err = activeStore.SearchIssuesWithCounts(ctx, queryText, filter)
 			}
 			if err != nil {
 				FatalError("%v", err)
 			}
+			if queryPredicate != nil {
+				iwc = slices.DeleteFunc(iwc, func(item *types.IssueWithCounts) bool {
+					return item == nil || item.Issue == nil || !queryPredicate(item.Issue)
+				})
+			}
 			sortIssuesWithCounts(iwc, sortBy, reverse)
 			truncated := effectiveLimit > 0 && len(iwc) > effectiveLimit
 			if truncated {
 				iwc = iwc[:effectiveLimit]
 			}
+			nextCursor := ""
+			if defaultOrder && len(iwc) > 0 {
+				nextCursor = nextPageCursor(truncated, iwc[len(iwc)-1].Issue)
+			}
 			if iwc == nil {
 				iwc = []*types.IssueWithCounts{}
 			}
+			if effectivePriorityFlag {
+				setEffectivePriorities(iwc, priorityAgingInterval(), time.Now())
+			}
+			if len(fields) > 0 {
+				issues := make([]*types.Issue, len(iwc))
+				for i, item := range iwc {
+					issues[i] = item.Issue
+				}
+				outputJSON(listFieldsJSON(issues, fields))
+				printTruncationHint(truncated, effectiveLimit, nextCursor)
+				return
+			}
+			if groupBy != "" {
+				groups := groupIssues(iwc, groupBy)
+				if groupBy == "epic" {
+					setEpicGroupTitles(ctx, activeStore, groups)
+				}
+				outputJSON(issueGroupsJSON{GroupBy: groupBy, Groups: groups})
+				printTruncationHint(truncated, effectiveLimit, nextCursor)
+				return
+			}
 			if skipLabels {
 				outputJSON(newSkipLabelsListJSONResponse(iwc))
-				printTruncationHint(truncated, effectiveLimit)
+				printTruncationHint(truncated, effectiveLimit, nextCursor)
 				return
 			}
 			outputJSON(iwc)
-			printTruncationHint(truncated, effectiveLimit)
+			printTruncationHint(truncated, effectiveLimit, nextCursor)
 			return
 		}
 
@@ -1087,7 +1254,7 @@ var listCmd = &cobra.Command{
 			// excluding issues with open blocks dependencies.
 			wf := readyWorkFilterFromIssueFilter(filter)
 			var err error
-			issues, err = activeStore.GetReadyWork(ctx, wf)
+			issues, err = getReadyWork(ctx, activeStore, wf)
 			if err != nil {
 				FatalError("%v", err)
 			}
@@ -1098,10 +1265,13 @@ var listCmd = &cobra.Command{
 				filter.SkipWisps = true
 			}
 			var err error
-			issues, err = activeStore.SearchIssues(ctx, "", filter)
+			issues, err = activeStore.SearchIssues(ctx, queryText, filter)
 			if err != nil {
 				FatalError("%v", err)
 			}
+			if queryPredicate != nil {
+				issues = slices.DeleteFunc(issues, func(issue *types.Issue) bool { return !queryPredicate(issue) })
+			}
 		}
 
 		// Apply sorting
@@ -1113,6 +1283,18 @@ var listCmd = &cobra.Command{
 		if truncated {
 			issues = issues[:effectiveLimit]
 		}
+		nextCursor := ""
+		if defaultOrder && len(issues) > 0 {
+			nextCursor = nextPageCursor(truncated, issues[len(issues)-1])
+		}
+
+		if effectivePriorityFlag {
+			var buf strings.Builder
+			formatEffectivePriorityList(&buf, issues, priorityAgingInterval(), time.Now())
+			fmt.Print(buf.String())
+			printTruncationHint(truncated, effectiveLimit, nextCursor)
+			return
+		}
 
 		// Handle pretty format (GH#654)
 		// JSON output takes priority over pretty/tree format (bd-list-json-fix, bd-03r)
@@ -1142,17 +1324,34 @@ var listCmd = &cobra.Command{
 			// Best effort: display gracefully degrades with empty data
 			allDeps, _ := activeStore.GetAllDependencyRecords(ctx)
 			displayPrettyListWithDeps(issues, false, allDeps)
-			printTruncationHint(truncated, effectiveLimit)
+			printTruncationHint(truncated, effectiveLimit, nextCursor)
 			printSkipLabelsFooter(skipLabels)
 			return
 		}
 
 		// Handle format flag (non-json presets handled here; json handled earlier)
 		if formatStr != "" {
-			if err := outputFormattedList(ctx, activeStore, issues, formatStr); err != nil {
+			if isFieldTemplate(formatStr) {
+				err = outputFieldTemplate(os.Stdout, issues, formatStr)
+			} else {
+				err = outputFormattedList(ctx, activeStore, issues, formatStr)
+			}
+			if err != nil {
 				FatalError("%v", err)
 			}
-			printTruncationHint(truncated, effectiveLimit)
+			printTruncationHint(truncated, effectiveLimit, nextCursor)
+			return
+		}
+
+		if len(columns) > 0 {
+			outputColumns(os.Stdout, issues, columns)
+			printTruncationHint(truncated, effectiveLimit, nextCursor)
+			return
+		}
+
+		if len(fields) > 0 {
+			outputFields(os.Stdout, issues, fields)
+			printTruncationHint(truncated, effectiveLimit, nextCursor)
 			return
 		}
 
@@ -1176,13 +1375,35 @@ var listCmd = &cobra.Command{
 
 		// Build output in buffer for pager support (bd-jdz3)
 		var buf strings.Builder
-		if ui.IsAgentMode() {
+		if groupBy != "" {
+			items := make([]*types.IssueWithCounts, len(issues))
+			for i, issue := range issues {
+				items[i] = &types.IssueWithCounts{Issue: issue}
+				if parent := parentMap[issue.ID]; parent != "" {
+					items[i].Parent = &parent
+				}
+			}
+			groups := groupIssues(items, groupBy)
+			if groupBy == "epic" {
+				setEpicGroupTitles(ctx, activeStore, groups)
+			}
+			formatIssueGroups(&buf, groupBy, groups, func(issue *types.Issue) {
+				switch {
+				case ui.IsAgentMode():
+					formatAgentIssue(&buf, issue, blockedByMap[issue.ID], blocksMap[issue.ID], parentMap[issue.ID])
+				case longFormat:
+					formatIssueLong(&buf, issue, labelsMap[issue.ID], skipLabels)
+				default:
+					formatIssueCompact(&buf, issue, labelsMap[issue.ID], blockedByMap[issue.ID], blocksMap[issue.ID], parentMap[issue.ID])
+				}
+			})
+		} else if ui.IsAgentMode() {
 			// Agent mode: ultra-compact, no colors, no pager
 			for _, issue := range issues {
 				formatAgentIssue(&buf, issue, blockedByMap[issue.ID], blocksMap[issue.ID], parentMap[issue.ID])
 			}
 			fmt.Print(buf.String())
-			printTruncationHint(truncated, effectiveLimit)
+			printTruncationHint(truncated, effectiveLimit, nextCursor)
 			return
 		} else if longFormat {
 			// Long format: multi-line with details
@@ -1211,7 +1432,7 @@ var listCmd = &cobra.Command{
 			}
 		}
 
-		printTruncationHint(truncated, effectiveLimit)
+		printTruncationHint(truncated, effectiveLimit, nextCursor)
 
 		// Show tip after successful list (direct mode only)
 		maybeShowTip(store)
@@ -1223,9 +1444,10 @@ func init() {
 	listCmd.Flags().String("state", "", "Alias for --status")
 	_ = listCmd.Flags().MarkHidden("state")
 	registerPriorityFlag(listCmd, "")
+	listCmd.Flags().String("severity", "", "Filter bugs by severity (S0-S3, comma-separated)")
 	listCmd.Flags().StringP("assignee", "a", "", "Filter by assignee")
 	listCmd.Flags().StringP("type", "t", "", "Filter by type (bug, feature, task, epic, chore, decision, merge-request, molecule, gate, convoy). Aliases: mr→merge-request, feat→feature, mol→molecule, dec/adr→decision")
-	listCmd.Flags().StringSliceP("label", "l", []string{}, "Filter by labels (AND: must have ALL). Can combine with --label-any")
+	listCmd.Flags().StringSliceP("label", "l", []string{}, "Filter by labels (AND: must have ALL; 'area/*' matches a namespace). Can combine with --label-any")
 	listCmd.Flags().StringSlice("label-any", []string{}, "Filter by labels (OR: must have AT LEAST ONE). Can combine with --label")
 	listCmd.Flags().StringSlice("exclude-label", []string{}, "Exclude issues that have ANY of these labels")
 	listCmd.Flags().String("label-pattern", "", "Filter by label glob pattern (e.g., 'tech-*' matches tech-debt, tech-legacy)")
@@ -1234,24 +1456,33 @@ func init() {
 	listCmd.Flags().String("spec", "", "Filter by spec_id prefix")
 	listCmd.Flags().String("id", "", "Filter by specific issue IDs (comma-separated, e.g., bd-1,bd-5,bd-10)")
 	listCmd.Flags().IntP("limit", "n", 50, "Limit results (default 50, use 0 for unlimited)")
-	listCmd.Flags().String("format", "", "Output format: 'digraph' (for golang.org/x/tools/cmd/digraph), 'dot' (Graphviz), or Go template")
+	listCmd.Flags().String("format", "", "Output format: 'digraph' (for golang.org/x/tools/cmd/digraph), 'dot' (Graphviz), a per-issue template of --fields names (e.g. '{{id}}\\t{{title}}'), or Go template")
 	listCmd.Flags().Bool("all", false, "Show all issues including closed (overrides default filter)")
 	listCmd.Flags().Bool("long", false, "Show detailed multi-line output for each issue")
-	listCmd.Flags().String("sort", "", "Sort by field: priority, created, updated, closed, status, id, title, type, assignee")
+	listCmd.Flags().String("sort", "", "Sort by field: priority, created, updated, closed, status, id, title, type, assignee, triage (severity, then priority)")
+	listCmd.Flags().String("group-by", "", "Print issues in sections by field: status, assignee, label, epic, priority (JSON: nested groups)")
+	listCmd.Flags().StringSlice("fields", nil, "Print only these fields, tab-separated without a header (with --json: objects with just these keys); same names as --columns")
+	listCmd.Flags().StringSlice("columns", nil, "Print a table of these columns (comma-separated: id, title, status, priority, type, severity, assignee, owner, labels, created, updated, due)")
+	listCmd.Flags().String("after", "", "Resume listing after this cursor (printed as \"Next page\" when output is truncated)")
+	listCmd.Flags().Int("offset", 0, "Skip the first N matching issues (default ordering only; prefer --after for stable paging)")
 	listCmd.Flags().BoolP("reverse", "r", false, "Reverse sort order")
 
 	// Pattern matching
 	listCmd.Flags().String("title-contains", "", "Filter by title substring (case-insensitive)")
 	listCmd.Flags().String("desc-contains", "", "Filter by description substring (case-insensitive)")
 	listCmd.Flags().String("notes-contains", "", "Filter by notes substring (case-insensitive)")
+	listCmd.Flags().String("title-regex", "", "Filter by title regular expression (case-insensitive, e.g. 'v[0-9]+\\.[0-9]+')")
+	listCmd.Flags().String("description-regex", "", "Filter by description regular expression (case-insensitive, e.g. 'E[0-9]{4}')")
 
 	// Date ranges
-	listCmd.Flags().String("created-after", "", "Filter issues created after date (YYYY-MM-DD or RFC3339)")
-	listCmd.Flags().String("created-before", "", "Filter issues created before date (YYYY-MM-DD or RFC3339)")
-	listCmd.Flags().String("updated-after", "", "Filter issues updated after date (YYYY-MM-DD or RFC3339)")
-	listCmd.Flags().String("updated-before", "", "Filter issues updated before date (YYYY-MM-DD or RFC3339)")
-	listCmd.Flags().String("closed-after", "", "Filter issues closed after date (YYYY-MM-DD or RFC3339)")
-	listCmd.Flags().String("closed-before", "", "Filter issues closed before date (YYYY-MM-DD or RFC3339)")
+	listCmd.Flags().String("created-after", "", "Filter issues created after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	listCmd.Flags().String("created-before", "", "Filter issues created before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	listCmd.Flags().String("updated-after", "", "Filter issues updated after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	listCmd.Flags().String("updated-before", "", "Filter issues updated before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	listCmd.Flags().String("closed-after", "", "Filter issues closed after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	listCmd.Flags().String("closed-before", "", "Filter issues closed before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	registerUpdatedWindowFlags(listCmd)
+	registerExclusionFlags(listCmd)
 
 	// Empty/null checks
 	listCmd.Flags().Bool("empty-description", false, "Filter issues with empty or missing description")
@@ -1263,7 +1494,7 @@ func init() {
 		"Skip label hydration. The labels field in output will be empty regardless "+
 			"of actual labels. Use only when the caller does not depend on label data. "+
 			"Cannot combine with --label, --label-any, --label-pattern, --label-regex, "+
-			"--exclude-label, or --no-labels.")
+			"--exclude-label, --not-label, or --no-labels.")
 
 	// Priority ranges
 	listCmd.Flags().String("priority-min", "", "Filter by minimum priority (inclusive, 0-4 or P0-P4)")
@@ -1304,6 +1535,7 @@ func init() {
 	listCmd.Flags().String("due-after", "", "Filter issues due after date (supports relative: +6h, tomorrow)")
 	listCmd.Flags().String("due-before", "", "Filter issues due before date (supports relative: +6h, tomorrow)")
 	listCmd.Flags().Bool("overdue", false, "Show only issues with due_at in the past (not closed)")
+	listCmd.Flags().Bool("effective-priority", false, "Show each issue's priority after priority aging (priority.aging config)")
 
 	// Pretty and watch flags (GH#654)
 	listCmd.Flags().Bool("pretty", false, "Display issues in a tree format with status/priority symbols")
@@ -1313,6 +1545,7 @@ func init() {
 
 	// Metadata filtering (GH#1406)
 	listCmd.Flags().StringArray("metadata-field", nil, "Filter by metadata field (key=value, repeatable)")
+	listCmd.Flags().StringArray("field", nil, "Filter by custom field set with --field (name=value, repeatable)")
 	listCmd.Flags().String("has-metadata-key", "", "Filter issues that have this metadata key set")
 
 	// Pager control (bd-jdz3)
@@ -1320,6 +1553,8 @@ func init() {
 
 	// Ready filter: show only issues ready to be worked on (bd-ihu31)
 	listCmd.Flags().Bool("ready", false, "Show only ready issues (no active blockers, same semantics as bd ready)")
+	listCmd.Flags().String("query", "", "Filter with a query expression, e.g. 'status:open p<=1 label:infra -label:wontfix' (see 'bd query --help'); combines with the other filter flags")
+	listCmd.Flags().Bool("all-repos", false, "Aggregate across this and every registered repository, tagging each issue with its repo (see 'bd workspace')")
 
 	// Note: --json flag is defined as a persistent flag in main.go, not here
 	rootCmd.AddCommand(listCmd)
@@ -7,10 +7,12 @@ import (
 	"strings"
 	"time"
 
+	"github.com/spf13/cobra"
 	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/timeparsing"
 	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
 )
 
 // parseTimeFlag parses time strings using the layered time parsing architecture.
@@ -20,6 +22,101 @@ func parseTimeFlag(s string) (time.Time, error) {
 	return timeparsing.ParseRelativeTime(s, time.Now())
 }
 
+// parseDateFilterFlag parses the value of a created/updated/closed date
+// filter. Like parseTimeFlag, except a bare duration counts back from now:
+// --created-before 2w means created more than two weeks ago.
+func parseDateFilterFlag(s string) (time.Time, error) {
+	return timeparsing.ParsePastTime(s, time.Now())
+}
+
+// registerUpdatedWindowFlags adds --updated-within and --stale-for, duration
+// shorthands for --updated-after and --updated-before.
+func registerUpdatedWindowFlags(cmd *cobra.Command) {
+	cmd.Flags().String("updated-within", "", "Filter issues updated within this long (e.g., 24h, 7d, 2w, 1M)")
+	cmd.Flags().String("stale-for", "", "Filter issues not updated for at least this long (e.g., 7d, 2w, 1M)")
+}
+
+// applyUpdatedWindowFlags sets filter.UpdatedAfter from --updated-within and
+// filter.UpdatedBefore from --stale-for.
+func applyUpdatedWindowFlags(cmd *cobra.Command, filter *types.IssueFilter) {
+	for _, f := range []struct {
+		flag, conflict string
+		dst            **time.Time
+	}{
+		{"updated-within", "updated-after", &filter.UpdatedAfter},
+		{"stale-for", "updated-before", &filter.UpdatedBefore},
+	} {
+		value, _ := cmd.Flags().GetString(f.flag)
+		if value == "" {
+			continue
+		}
+		if cmd.Flags().Changed(f.conflict) {
+			FatalErrorRespectJSON("--%s cannot be combined with --%s", f.flag, f.conflict)
+		}
+		t, err := timeparsing.ParseDurationAgo(value, time.Now())
+		if err != nil {
+			FatalErrorRespectJSON("parsing --%s: %v", f.flag, err)
+		}
+		*f.dst = &t
+	}
+}
+
+// registerExclusionFlags adds --not-status, --not-label, --not-assignee and
+// --exclude-ids, which drop matching issues from the results.
+func registerExclusionFlags(cmd *cobra.Command) {
+	cmd.Flags().StringSlice("not-status", nil, "Exclude issues with any of these statuses (comma-separated or repeatable)")
+	cmd.Flags().StringSlice("not-label", nil, "Exclude issues that have any of these labels ('area/*' matches a namespace)")
+	cmd.Flags().StringSlice("not-assignee", nil, "Exclude issues assigned or co-assigned to any of these people")
+	cmd.Flags().StringSlice("exclude-ids", nil, "Exclude these issues (comma-separated or repeatable; partial IDs allowed)")
+}
+
+// applyExclusionFlags adds the exclusion flags to filter. They stack with
+// the default status exclusions and with any inclusive filters.
+func applyExclusionFlags(ctx context.Context, cmd *cobra.Command, filter *types.IssueFilter) {
+	notStatuses, _ := cmd.Flags().GetStringSlice("not-status")
+	if len(notStatuses) > 0 {
+		var customStatuses []string
+		if store != nil {
+			customStatuses, _ = store.GetCustomStatuses(ctx)
+		}
+		for _, s := range notStatuses {
+			status := types.Status(strings.TrimSpace(s))
+			if !status.IsValidWithCustom(customStatuses) {
+				FatalErrorRespectJSON("invalid status %q in --not-status", s)
+			}
+			filter.ExcludeStatus = append(filter.ExcludeStatus, status)
+		}
+	}
+
+	notLabels, _ := cmd.Flags().GetStringSlice("not-label")
+	if len(notLabels) > 0 {
+		filter.ExcludeLabels = utils.NormalizeLabels(append(filter.ExcludeLabels, notLabels...))
+	}
+
+	notAssignees, _ := cmd.Flags().GetStringSlice("not-assignee")
+	for _, assignee := range notAssignees {
+		if assignee = strings.TrimSpace(assignee); assignee != "" {
+			filter.ExcludeAssignees = append(filter.ExcludeAssignees, assignee)
+		}
+	}
+
+	excludeIDs, _ := cmd.Flags().GetStringSlice("exclude-ids")
+	for _, id := range excludeIDs {
+		id = strings.TrimSpace(id)
+		if id == "" {
+			continue
+		}
+		// An ID that matches nothing can't be in the results either, so only
+		// partial IDs that resolve are expanded.
+		if store != nil {
+			if resolved, err := utils.ResolvePartialID(ctx, store, id); err == nil {
+				id = resolved
+			}
+		}
+		filter.ExcludeIDs = append(filter.ExcludeIDs, id)
+	}
+}
+
 // pinIndicator returns a pushpin emoji prefix for pinned issues
 func pinIndicator(issue *types.Issue) string {
 	if issue.Pinned {
@@ -40,6 +137,16 @@ func renderStatusIcon(status types.Status) string {
 	return ui.RenderStatusIcon(string(status))
 }
 
+// checklistIndicator renders acceptance checklist progress (" ☑ 2/3") for
+// list lines, or "" when the issue has no checklist.
+func checklistIndicator(issue *types.Issue) string {
+	done, total := issue.ChecklistProgress()
+	if total == 0 {
+		return ""
+	}
+	return fmt.Sprintf(" ☑ %d/%d", done, total)
+}
+
 // formatPrettyIssue formats a single issue for pretty output
 // Uses semantic colors: status icon colored, priority P0/P1 colored, rest neutral
 func formatPrettyIssue(issue *types.Issue) string {
@@ -103,6 +210,12 @@ func formatIssueLong(buf *strings.Builder, issue *types.Issue, labels []string,
 	if issue.Assignee != "" {
 		buf.WriteString(fmt.Sprintf("  Assignee: %s\n", issue.Assignee))
 	}
+	if issue.Severity != "" {
+		buf.WriteString(fmt.Sprintf("  Severity: %s\n", issue.Severity))
+	}
+	if done, total := issue.ChecklistProgress(); total > 0 {
+		buf.WriteString(fmt.Sprintf("  Acceptance: %d/%d done\n", done, total))
+	}
 	if desc := strings.TrimSpace(issue.Description); desc != "" {
 		buf.WriteString("  Description:\n")
 		for _, line := range strings.Split(desc, "\n") {
@@ -227,7 +340,7 @@ func getClosedBlockerIDs(ctx context.Context, s storage.DoltStorage, allDeps map
 
 // formatIssueCompact formats a single issue in compact format to a buffer
 // Uses status icons for better scanability - consistent with bd graph
-// Format: [icon] [pin] ID [Priority] [Type] @assignee [labels] - Title (parent: X, blocked by: Y, blocks: Z)
+// Format: [icon] [pin] ID [Priority] [Type] @assignee [labels] - Title [☑ done/total] (parent: X, blocked by: Y, blocks: Z)
 func formatIssueCompact(buf *strings.Builder, issue *types.Issue, labels []string, blockedBy, blocks []string, parent string) {
 	labelsStr := ""
 	if len(labels) > 0 {
@@ -252,20 +365,20 @@ func formatIssueCompact(buf *strings.Builder, issue *types.Issue, labels []strin
 
 	if issue.Status == types.StatusClosed {
 		// Closed issues: entire line muted (fades visually)
-		line := fmt.Sprintf("%s %s%s [P%d] [%s]%s%s - %s%s",
+		line := fmt.Sprintf("%s %s%s [P%d] [%s]%s%s - %s%s%s",
 			statusIcon, pinIndicator(issue), issue.ID, issue.Priority,
-			issue.IssueType, assigneeStr, labelsStr, issue.Title, depInfo)
+			issue.IssueType, assigneeStr, labelsStr, issue.Title, checklistIndicator(issue), depInfo)
 		buf.WriteString(ui.RenderClosedLine(line))
 		buf.WriteString("\n")
 	} else {
 		// Active issues: status icon + semantic colors for priority/type
-		buf.WriteString(fmt.Sprintf("%s %s%s [%s] [%s]%s%s - %s%s\n",
+		buf.WriteString(fmt.Sprintf("%s %s%s [%s] [%s]%s%s%s - %s%s%s\n",
 			statusIcon,
 			pinIndicator(issue),
 			ui.RenderID(issue.ID),
 			ui.RenderPriority(issue.Priority),
 			ui.RenderType(string(issue.IssueType)),
-			assigneeStr, labelsStr, issue.Title, depInfo))
+			severityTag(issue), assigneeStr, labelsStr, issue.Title, ui.RenderMuted(checklistIndicator(issue)), depInfo))
 	}
 }
 
@@ -0,0 +1,178 @@
+package main
+
+import (
+	"cmp"
+	"context"
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+// listGroupByFields are the values accepted by 'bd list --group-by'.
+var listGroupByFields = []string{"status", "assignee", "label", "epic", "priority"}
+
+// Placeholder group keys for issues with no value in the grouped field.
+const (
+	groupUnassigned = "(unassigned)"
+	groupNoLabels   = "(no labels)"
+	groupNoEpic     = "(no epic)"
+)
+
+// issueGroupStatusOrder is the order status groups are printed in; any
+// custom statuses follow alphabetically.
+var issueGroupStatusOrder = []types.Status{
+	types.StatusOpen,
+	types.StatusInProgress,
+	types.StatusBlocked,
+	types.StatusDeferred,
+	types.StatusClosed,
+}
+
+// issueGroup is one section of 'bd list --group-by' output.
+type issueGroup struct {
+	Key    string                   `json:"key"`
+	Title  string                   `json:"title,omitempty"` // epic title when grouping by epic
+	Count  int                      `json:"count"`
+	Issues []*types.IssueWithCounts `json:"issues"`
+}
+
+// issueGroupsJSON is the JSON shape of 'bd list --group-by'.
+type issueGroupsJSON struct {
+	GroupBy string        `json:"group_by"`
+	Groups  []*issueGroup `json:"groups"`
+}
+
+// issueGroupKeys returns the groups an issue belongs to. Issues with several
+// labels appear under each of them.
+func issueGroupKeys(item *types.IssueWithCounts, field string) []string {
+	switch field {
+	case "status":
+		return []string{string(item.Status)}
+	case "priority":
+		return []string{fmt.Sprintf("P%d", item.Priority)}
+	case "assignee":
+		if item.Assignee == "" {
+			return []string{groupUnassigned}
+		}
+		return []string{item.Assignee}
+	case "label":
+		if len(item.Labels) == 0 {
+			return []string{groupNoLabels}
+		}
+		return item.Labels
+	case "epic":
+		if item.Parent == nil || *item.Parent == "" {
+			return []string{groupNoEpic}
+		}
+		return []string{*item.Parent}
+	}
+	return nil
+}
+
+// groupIssues splits items into groups by field, keeping the existing order
+// of items within each group, and orders the groups for display.
+func groupIssues(items []*types.IssueWithCounts, field string) []*issueGroup {
+	byKey := make(map[string]*issueGroup)
+	var groups []*issueGroup
+	for _, item := range items {
+		for _, key := range issueGroupKeys(item, field) {
+			g, ok := byKey[key]
+			if !ok {
+				g = &issueGroup{Key: key}
+				byKey[key] = g
+				groups = append(groups, g)
+			}
+			g.Issues = append(g.Issues, item)
+			g.Count++
+		}
+	}
+	slices.SortStableFunc(groups, func(a, b *issueGroup) int {
+		return compareGroupKeys(field, a.Key, b.Key)
+	})
+	return groups
+}
+
+// compareGroupKeys orders group keys: statuses by workflow, priorities
+// numerically, epics by ID, everything else alphabetically. Placeholder
+// groups such as "(unassigned)" always come last.
+func compareGroupKeys(field, a, b string) int {
+	aEmpty, bEmpty := strings.HasPrefix(a, "("), strings.HasPrefix(b, "(")
+	if aEmpty != bEmpty {
+		if aEmpty {
+			return 1
+		}
+		return -1
+	}
+	switch field {
+	case "status":
+		ai := slices.Index(issueGroupStatusOrder, types.Status(a))
+		bi := slices.Index(issueGroupStatusOrder, types.Status(b))
+		if ai < 0 {
+			ai = len(issueGroupStatusOrder)
+		}
+		if bi < 0 {
+			bi = len(issueGroupStatusOrder)
+		}
+		if ai != bi {
+			return cmp.Compare(ai, bi)
+		}
+	case "epic":
+		return utils.NaturalCompareIDs(a, b)
+	}
+	// Priorities are "P0".."P4", so string order is numeric order.
+	return cmp.Compare(strings.ToLower(a), strings.ToLower(b))
+}
+
+// setEpicGroupTitles fills in the epic title of each group when grouping by
+// epic. Best effort: groups keep just the ID if the lookup fails.
+func setEpicGroupTitles(ctx context.Context, st storage.DoltStorage, groups []*issueGroup) {
+	var ids []string
+	for _, g := range groups {
+		if g.Key != groupNoEpic {
+			ids = append(ids, g.Key)
+		}
+	}
+	if len(ids) == 0 {
+		return
+	}
+	epics, err := st.GetIssuesByIDs(ctx, ids)
+	if err != nil {
+		return
+	}
+	titles := make(map[string]string, len(epics))
+	for _, epic := range epics {
+		titles[epic.ID] = epic.Title
+	}
+	for _, g := range groups {
+		g.Title = titles[g.Key]
+	}
+}
+
+// formatIssueGroupHeader renders the heading printed above a group.
+func formatIssueGroupHeader(field string, g *issueGroup) string {
+	label := g.Key
+	if field == "epic" && g.Title != "" {
+		label = fmt.Sprintf("%s %s", g.Key, g.Title)
+	}
+	return fmt.Sprintf("%s %s", ui.RenderBold(label), ui.RenderMuted(fmt.Sprintf("(%d)", g.Count)))
+}
+
+// formatIssueGroups writes grouped list output: a heading for each group
+// followed by its issues, each written by formatIssue.
+func formatIssueGroups(buf *strings.Builder, field string, groups []*issueGroup, formatIssue func(*types.Issue)) {
+	for i, g := range groups {
+		if i > 0 {
+			buf.WriteString("\n")
+		}
+		buf.WriteString(formatIssueGroupHeader(field, g))
+		buf.WriteString("\n")
+		for _, item := range g.Issues {
+			formatIssue(item.Issue)
+		}
+	}
+}
@@ -0,0 +1,47 @@
+package main
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestGroupIssues(t *testing.T) {
+	epic := "bd-10"
+	items := []*types.IssueWithCounts{
+		{Issue: &types.Issue{ID: "bd-1", Status: types.StatusClosed, Priority: 2, Labels: []string{"ui", "auth"}}},
+		{Issue: &types.Issue{ID: "bd-2", Status: types.StatusInProgress, Priority: 0, Assignee: "zoe"}, Parent: &epic},
+		{Issue: &types.Issue{ID: "bd-3", Status: types.StatusOpen, Priority: 2, Assignee: "al"}},
+		{Issue: &types.Issue{ID: "bd-4", Status: types.StatusOpen, Priority: 1, Labels: []string{"auth"}}, Parent: &epic},
+	}
+
+	tests := []struct {
+		field string
+		want  string
+	}{
+		{"status", "open:bd-3,bd-4 in_progress:bd-2 closed:bd-1"},
+		{"priority", "P0:bd-2 P1:bd-4 P2:bd-1,bd-3"},
+		{"assignee", "al:bd-3 zoe:bd-2 (unassigned):bd-1,bd-4"},
+		{"label", "auth:bd-1,bd-4 ui:bd-1 (no labels):bd-2,bd-3"},
+		{"epic", "bd-10:bd-2,bd-4 (no epic):bd-1,bd-3"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.field, func(t *testing.T) {
+			var parts []string
+			for _, g := range groupIssues(items, tt.field) {
+				ids := make([]string, len(g.Issues))
+				for i, item := range g.Issues {
+					ids[i] = item.ID
+				}
+				if g.Count != len(ids) {
+					t.Errorf("group %s: Count = %d, want %d", g.Key, g.Count, len(ids))
+				}
+				parts = append(parts, g.Key+":"+strings.Join(ids, ","))
+			}
+			if got := strings.Join(parts, " "); got != tt.want {
+				t.Errorf("groupIssues(%s) = %q, want %q", tt.field, got, tt.want)
+			}
+		})
+	}
+}
@@ -4,7 +4,12 @@ import (
 	"bytes"
 	"context"
 	"fmt"
+	"io"
 	"os"
+	"regexp"
+	"slices"
+	"strings"
+	"text/tabwriter"
 	"text/template"
 
 	"github.com/steveyegge/beads/internal/storage"
@@ -15,14 +20,29 @@ import (
 // printTruncationHint emits a one-line notice to stderr when the list output
 // was truncated by --limit, so users and agents can't mistake a partial view
 // for a complete one (GH#3212, GH#788).
-func printTruncationHint(truncated bool, effectiveLimit int) {
+//
+// nextCursor, when non-empty, is the --after token for the following page.
+func printTruncationHint(truncated bool, effectiveLimit int, nextCursor string) {
 	if !truncated || effectiveLimit <= 0 {
 		return
 	}
 	msg := fmt.Sprintf("\nShowing %d issues; more results matched but were hidden by --limit. Use --limit 0 for all, or --limit N to raise the cap.\n", effectiveLimit)
+	if nextCursor != "" {
+		msg += fmt.Sprintf("Next page: --after %s\n", nextCursor)
+	}
 	fmt.Fprint(os.Stderr, ui.RenderWarn(msg))
 }
 
+// nextPageCursor returns the --after token that resumes listing after the
+// last displayed issue, or "" when the page was not truncated. Callers only
+// use it for listings in the default ordering.
+func nextPageCursor(truncated bool, last *types.Issue) string {
+	if !truncated || last == nil {
+		return ""
+	}
+	return types.IssueCursorFor(last).Encode()
+}
+
 // outputDotFormat outputs issues in Graphviz DOT format
 func outputDotFormat(ctx context.Context, store storage.DoltStorage, issues []*types.Issue) error {
 	fmt.Println("digraph dependencies {")
@@ -65,13 +85,14 @@ func outputDotFormat(ctx context.Context, store storage.DoltStorage, issues []*t
 	}
 	fmt.Println()
 
-	// Output edges with labels for dependency type
+	// Output edges with labels for dependency type. Dependency records are
+	// fetched in one batched query rather than once per issue.
+	depsByIssue, err := store.GetDependencyRecordsForIssues(ctx, collectIssueIDs(issues))
+	if err != nil {
+		return fmt.Errorf("loading dependencies: %w", err)
+	}
 	for _, issue := range issues {
-		deps, err := store.GetDependencyRecords(ctx, issue.ID)
-		if err != nil {
-			continue
-		}
-		for _, dep := range deps {
+		for _, dep := range depsByIssue[issue.ID] {
 			// Only output edges where both nodes are in the filtered list
 			if issueMap[dep.DependsOnID] != nil {
 				// Color code by dependency type
@@ -131,12 +152,12 @@ func outputFormattedList(ctx context.Context, store storage.DoltStorage, issues
 	}
 
 	// For each issue, output its dependencies using the template
+	depsByIssue, err := store.GetDependencyRecordsForIssues(ctx, collectIssueIDs(issues))
+	if err != nil {
+		return fmt.Errorf("loading dependencies: %w", err)
+	}
 	for _, issue := range issues {
-		deps, err := store.GetDependencyRecords(ctx, issue.ID)
-		if err != nil {
-			continue
-		}
-		for _, dep := range deps {
+		for _, dep := range depsByIssue[issue.ID] {
 			// Only output edges where both nodes are in the filtered list
 			if issueMap[dep.DependsOnID] {
 				// Template data includes both issue and dependency info
@@ -159,3 +180,128 @@ func outputFormattedList(ctx context.Context, store storage.DoltStorage, issues
 
 	return nil
 }
+
+// listColumns maps the column names 'bd list --columns' accepts to the
+// value each shows for an issue.
+var listColumns = map[string]func(*types.Issue) string{
+	"id":       func(i *types.Issue) string { return i.ID },
+	"title":    func(i *types.Issue) string { return i.Title },
+	"status":   func(i *types.Issue) string { return string(i.Status) },
+	"priority": func(i *types.Issue) string { return fmt.Sprintf("P%d", i.Priority) },
+	"type":     func(i *types.Issue) string { return string(i.IssueType) },
+	"severity": func(i *types.Issue) string { return string(i.Severity) },
+	"assignee": func(i *types.Issue) string { return i.Assignee },
+	"owner":    func(i *types.Issue) string { return i.Owner },
+	"labels":   func(i *types.Issue) string { return strings.Join(i.Labels, ",") },
+	"created":  func(i *types.Issue) string { return i.CreatedAt.Format("2006-01-02") },
+	"updated":  func(i *types.Issue) string { return i.UpdatedAt.Format("2006-01-02") },
+	"due": func(i *types.Issue) string {
+		if i.DueAt == nil {
+			return ""
+		}
+		return i.DueAt.Format("2006-01-02")
+	},
+}
+
+// parseListColumns normalizes a --columns value, rejecting unknown names.
+func parseListColumns(names []string) ([]string, error) {
+	var columns []string
+	for _, name := range names {
+		name = strings.ToLower(strings.TrimSpace(name))
+		if name == "" {
+			continue
+		}
+		if _, ok := listColumns[name]; !ok {
+			valid := make([]string, 0, len(listColumns))
+			for column := range listColumns {
+				valid = append(valid, column)
+			}
+			slices.Sort(valid)
+			return nil, fmt.Errorf("unknown column %q (valid: %s)", name, strings.Join(valid, ", "))
+		}
+		columns = append(columns, name)
+	}
+	return columns, nil
+}
+
+// outputColumns prints issues as an aligned table of the given columns.
+func outputColumns(w io.Writer, issues []*types.Issue, columns []string) {
+	tw := tabwriter.NewWriter(w, 0, 0, 2, ' ', 0)
+	header := make([]string, len(columns))
+	for i, column := range columns {
+		header[i] = strings.ToUpper(column)
+	}
+	fmt.Fprintln(tw, strings.Join(header, "\t"))
+	row := make([]string, len(columns))
+	for _, issue := range issues {
+		for i, column := range columns {
+			row[i] = listColumns[column](issue)
+		}
+		fmt.Fprintln(tw, strings.Join(row, "\t"))
+	}
+	_ = tw.Flush()
+}
+
+// outputFields prints the given fields of each issue as tab-separated
+// values, one issue per line and without a header, for scripts.
+func outputFields(w io.Writer, issues []*types.Issue, fields []string) {
+	row := make([]string, len(fields))
+	for _, issue := range issues {
+		for i, field := range fields {
+			row[i] = listColumns[field](issue)
+		}
+		fmt.Fprintln(w, strings.Join(row, "\t"))
+	}
+}
+
+// listFieldsJSON returns the given fields of each issue, keyed by field name,
+// for 'bd list --fields ... --json'.
+func listFieldsJSON(issues []*types.Issue, fields []string) []map[string]string {
+	out := make([]map[string]string, len(issues))
+	for i, issue := range issues {
+		out[i] = make(map[string]string, len(fields))
+		for _, field := range fields {
+			out[i][field] = listColumns[field](issue)
+		}
+	}
+	return out
+}
+
+// fieldPlaceholder matches a {{field}} placeholder in a --format template.
+var fieldPlaceholder = regexp.MustCompile(`\{\{\s*([a-z]+)\s*\}\}`)
+
+// isFieldTemplate reports whether a --format value is written with {{field}}
+// placeholders, such as '{{id}}\t{{title}}', rather than as a Go template.
+func isFieldTemplate(format string) bool {
+	n := strings.Count(format, "{{")
+	return n > 0 && len(fieldPlaceholder.FindAllStringIndex(format, -1)) == n
+}
+
+// outputFieldTemplate prints one line per issue with each {{field}}
+// placeholder replaced by that field's value. The escapes \t and \n are
+// expanded so templates can be passed in single quotes.
+func outputFieldTemplate(w io.Writer, issues []*types.Issue, format string) error {
+	for _, m := range fieldPlaceholder.FindAllStringSubmatch(format, -1) {
+		if _, err := parseListColumns([]string{m[1]}); err != nil {
+			return fmt.Errorf("invalid format template: %w", err)
+		}
+	}
+	format = strings.NewReplacer(`\t`, "\t", `\n`, "\n").Replace(format)
+	for _, issue := range issues {
+		line := fieldPlaceholder.ReplaceAllStringFunc(format, func(m string) string {
+			return listColumns[fieldPlaceholder.FindStringSubmatch(m)[1]](issue)
+		})
+		fmt.Fprintln(w, line)
+	}
+	return nil
+}
+
+// collectIssueIDs returns the IDs of issues, preserving order. Used to feed
+// the batched *ForIssues storage lookups.
+func collectIssueIDs(issues []*types.Issue) []string {
+	ids := make([]string, 0, len(issues))
+	for _, issue := range issues {
+		ids = append(ids, issue.ID)
+	}
+	return ids
+}
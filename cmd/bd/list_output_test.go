@@ -0,0 +1,52 @@
+package main
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestOutputFields(t *testing.T) {
+	issues := []*types.Issue{
+		{ID: "bd-1", Title: "Crash on login", Status: types.StatusOpen, Assignee: "al"},
+		{ID: "bd-2", Title: "Docs", Status: types.StatusClosed},
+	}
+	var buf bytes.Buffer
+	outputFields(&buf, issues, []string{"id", "status", "assignee", "title"})
+	if want := "bd-1\topen\tal\tCrash on login\nbd-2\tclosed\t\tDocs\n"; buf.String() != want {
+		t.Errorf("outputFields = %q, want %q", buf.String(), want)
+	}
+
+	got := listFieldsJSON(issues, []string{"id", "assignee"})
+	if len(got) != 2 || len(got[0]) != 2 || got[0]["id"] != "bd-1" || got[0]["assignee"] != "al" || got[1]["assignee"] != "" {
+		t.Errorf("listFieldsJSON = %v", got)
+	}
+}
+
+func TestOutputFieldTemplate(t *testing.T) {
+	for format, want := range map[string]bool{
+		`{{id}}\t{{title}}`:       true,
+		`{{ id }}: {{priority}}`:  true,
+		`{{.IssueID}} {{.Type}}`:  false,
+		`{{id}} {{.DependsOnID}}`: false,
+		"digraph":                 false,
+	} {
+		if got := isFieldTemplate(format); got != want {
+			t.Errorf("isFieldTemplate(%q) = %v, want %v", format, got, want)
+		}
+	}
+
+	issues := []*types.Issue{{ID: "bd-1", Title: "Crash on login", Priority: 1}}
+	var buf bytes.Buffer
+	if err := outputFieldTemplate(&buf, issues, `{{id}}\t{{ priority }}\t{{title}}`); err != nil {
+		t.Fatalf("outputFieldTemplate: %v", err)
+	}
+	if want := "bd-1\tP1\tCrash on login\n"; buf.String() != want {
+		t.Errorf("outputFieldTemplate = %q, want %q", buf.String(), want)
+	}
+	if err := outputFieldTemplate(&buf, issues, "{{id}} {{color}}"); err == nil || !strings.Contains(err.Error(), "color") {
+		t.Errorf("unknown field: err = %v", err)
+	}
+}
@@ -0,0 +1,286 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"math"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/timeparsing"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+var logTimeCmd = &cobra.Command{
+	Use:     "log-time <issue-id> <duration>",
+	GroupID: "issues",
+	Short:   "Record time spent on an issue",
+	Long: `Record time actually spent on an issue, for comparison with its estimate.
+
+Durations are a number of minutes (45) or Go-style durations (45m, 1h30m,
+1.5h). Time is logged by the current actor; use --at to backdate it.`,
+	Example: `  bd log-time bd-12 45m
+  bd log-time bd-12 1h30m --note "bisecting the flaky test"
+  bd log-time bd-12 2h --at yesterday`,
+	Args: cobra.ExactArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("log-time")
+		ctx := rootCtx
+		issueID, err := utils.ResolvePartialID(ctx, store, args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		minutes, err := parseWorkDuration(args[1])
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		entry := &types.WorkLog{
+			IssueID: issueID,
+			Actor:   getActor(),
+			Minutes: minutes,
+		}
+		entry.Note, _ = cmd.Flags().GetString("note")
+		if at, _ := cmd.Flags().GetString("at"); at != "" {
+			t, err := timeparsing.ParseRelativeTime(at, time.Now())
+			if err != nil {
+				FatalErrorRespectJSON("invalid --at: %v", err)
+			}
+			entry.LoggedAt = t.UTC()
+		}
+
+		if err := store.AddWorkLog(ctx, entry); err != nil {
+			FatalErrorRespectJSON("logging time: %v", err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(entry)
+			return
+		}
+		fmt.Printf("%s Logged %s on %s\n", ui.RenderPass("✓"), formatMinutes(minutes), issueID)
+		if summary, err := issueWorkSummary(ctx, store, issueID); err == nil {
+			fmt.Printf("  %s\n", summary)
+		}
+	},
+}
+
+var worklogCmd = &cobra.Command{
+	Use:     "worklog [issue-id...]",
+	GroupID: "views",
+	Short:   "Show time logged on issues",
+	Long: `Show time logged with 'bd log-time'.
+
+With issue IDs, lists each issue's entries with its total and estimate.
+Without, shows totals per issue and per actor across the database.`,
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		if len(args) > 0 {
+			showIssueWorkLogs(ctx, args)
+			return
+		}
+
+		totals, err := store.GetWorkLogTotals(ctx)
+		if err != nil {
+			FatalErrorRespectJSON("reading work log: %v", err)
+		}
+		report := summarizeWorkLog(totals)
+		if jsonOutput {
+			outputJSON(report)
+			return
+		}
+		if len(report.ByIssue) == 0 {
+			fmt.Println("No time logged")
+			return
+		}
+		fmt.Printf("%s %s logged\n\n", ui.RenderBold("Total:"), formatMinutes(report.TotalMinutes))
+		fmt.Println(ui.RenderBold("BY ISSUE"))
+		for _, t := range report.ByIssue {
+			fmt.Printf("  %-10s %s\n", formatMinutes(t.Minutes), ui.RenderID(t.Key))
+		}
+		fmt.Printf("\n%s\n", ui.RenderBold("BY ACTOR"))
+		for _, t := range report.ByActor {
+			fmt.Printf("  %-10s %s\n", formatMinutes(t.Minutes), t.Key)
+		}
+	},
+}
+
+// workLogReport is the database-wide 'bd worklog' summary.
+type workLogReport struct {
+	TotalMinutes int            `json:"total_minutes"`
+	ByIssue      []workLogTotal `json:"by_issue"`
+	ByActor      []workLogTotal `json:"by_actor"`
+}
+
+type workLogTotal struct {
+	Key     string `json:"key"`
+	Minutes int    `json:"minutes"`
+}
+
+// summarizeWorkLog folds per-issue, per-actor totals into per-issue and
+// per-actor totals, each largest first.
+func summarizeWorkLog(totals []*types.WorkLogTotal) *workLogReport {
+	byIssue := make(map[string]int)
+	byActor := make(map[string]int)
+	report := &workLogReport{ByIssue: []workLogTotal{}, ByActor: []workLogTotal{}}
+	for _, t := range totals {
+		byIssue[t.IssueID] += t.Minutes
+		byActor[t.Actor] += t.Minutes
+		report.TotalMinutes += t.Minutes
+	}
+	report.ByIssue = sortedWorkLogTotals(byIssue)
+	report.ByActor = sortedWorkLogTotals(byActor)
+	return report
+}
+
+func sortedWorkLogTotals(m map[string]int) []workLogTotal {
+	out := make([]workLogTotal, 0, len(m))
+	for k, v := range m {
+		out = append(out, workLogTotal{Key: k, Minutes: v})
+	}
+	sort.Slice(out, func(i, j int) bool {
+		if out[i].Minutes != out[j].Minutes {
+			return out[i].Minutes > out[j].Minutes
+		}
+		return out[i].Key < out[j].Key
+	})
+	return out
+}
+
+func showIssueWorkLogs(ctx context.Context, args []string) {
+	ids := make([]string, 0, len(args))
+	for _, arg := range args {
+		id, err := utils.ResolvePartialID(ctx, store, arg)
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", arg, err)
+		}
+		ids = append(ids, id)
+	}
+	entries, err := store.GetWorkLogForIssues(ctx, ids)
+	if err != nil {
+		FatalErrorRespectJSON("reading work log: %v", err)
+	}
+	if jsonOutput {
+		out := make(map[string][]*types.WorkLog, len(ids))
+		for _, id := range ids {
+			out[id] = entries[id]
+			if out[id] == nil {
+				out[id] = []*types.WorkLog{}
+			}
+		}
+		outputJSON(out)
+		return
+	}
+	for i, id := range ids {
+		if i > 0 {
+			fmt.Println()
+		}
+		fmt.Printf("%s\n", ui.RenderID(id))
+		if len(entries[id]) == 0 {
+			fmt.Printf("  %s\n", ui.RenderMuted("no time logged"))
+			continue
+		}
+		for _, e := range entries[id] {
+			line := fmt.Sprintf("  %s  %-8s %s", ui.RenderMuted(e.LoggedAt.Local().Format("2006-01-02 15:04")), formatMinutes(e.Minutes), e.Actor)
+			if e.Note != "" {
+				line += " — " + e.Note
+			}
+			fmt.Println(line)
+		}
+		if summary, err := issueWorkSummary(ctx, store, id); err == nil {
+			fmt.Printf("  %s\n", summary)
+		}
+	}
+}
+
+// issueWorkSummary describes the time logged on an issue against its
+// estimate, e.g. "3h 15m logged of 4h estimated".
+func issueWorkSummary(ctx context.Context, s storage.DoltStorage, issueID string) (string, error) {
+	entries, err := s.GetWorkLogForIssues(ctx, []string{issueID})
+	if err != nil {
+		return "", err
+	}
+	issue, err := s.GetIssue(ctx, issueID)
+	if err != nil {
+		return "", err
+	}
+	return formatWorkSummary(sumWorkLog(entries[issueID]), issue.EstimatedMinutes), nil
+}
+
+func sumWorkLog(entries []*types.WorkLog) int {
+	total := 0
+	for _, e := range entries {
+		total += e.Minutes
+	}
+	return total
+}
+
+func formatWorkSummary(logged int, estimate *int) string {
+	if estimate == nil || *estimate <= 0 {
+		return fmt.Sprintf("%s logged", formatMinutes(logged))
+	}
+	summary := fmt.Sprintf("%s logged of %s estimated", formatMinutes(logged), formatMinutes(*estimate))
+	if logged > *estimate {
+		summary += " " + ui.RenderWarn(fmt.Sprintf("(%s over)", formatMinutes(logged-*estimate)))
+	}
+	return summary
+}
+
+// printWorkLogSummary prints the TIME section of 'bd show' when any time
+// has been logged on the issue.
+func printWorkLogSummary(ctx context.Context, s storage.DoltStorage, issue *types.Issue) {
+	entries, err := s.GetWorkLogForIssues(ctx, []string{issue.ID})
+	if err != nil || len(entries[issue.ID]) == 0 {
+		return
+	}
+	fmt.Printf("\n%s\n", ui.RenderBold("TIME"))
+	fmt.Printf("  %s\n", formatWorkSummary(sumWorkLog(entries[issue.ID]), issue.EstimatedMinutes))
+}
+
+// parseWorkDuration parses a logged duration into whole minutes: a bare
+// number of minutes, or a Go duration such as 45m, 1h30m or 1.5h.
+func parseWorkDuration(s string) (int, error) {
+	s = strings.TrimSpace(s)
+	if n, err := strconv.Atoi(s); err == nil {
+		if n <= 0 {
+			return 0, fmt.Errorf("duration must be positive, got %q", s)
+		}
+		return n, nil
+	}
+	d, err := time.ParseDuration(s)
+	if err != nil {
+		return 0, fmt.Errorf("invalid duration %q (examples: 45, 45m, 1h30m, 1.5h)", s)
+	}
+	minutes := int(math.Round(d.Minutes()))
+	if minutes <= 0 {
+		return 0, fmt.Errorf("duration must be at least a minute, got %q", s)
+	}
+	return minutes, nil
+}
+
+// formatMinutes renders minutes as "45m", "2h" or "2h 15m".
+func formatMinutes(minutes int) string {
+	h, m := minutes/60, minutes%60
+	switch {
+	case h == 0:
+		return fmt.Sprintf("%dm", m)
+	case m == 0:
+		return fmt.Sprintf("%dh", h)
+	default:
+		return fmt.Sprintf("%dh %dm", h, m)
+	}
+}
+
+func init() {
+	logTimeCmd.Flags().StringP("note", "n", "", "What the time was spent on")
+	logTimeCmd.Flags().String("at", "", "When the work happened (default: now). Formats: -2h, yesterday, 2025-01-15")
+	logTimeCmd.ValidArgsFunction = issueIDCompletion
+	worklogCmd.ValidArgsFunction = issueIDCompletion
+	rootCmd.AddCommand(logTimeCmd)
+	rootCmd.AddCommand(worklogCmd)
+}
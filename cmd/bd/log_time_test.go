@@ -0,0 +1,82 @@
+package main
+
+import (
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseWorkDuration(t *testing.T) {
+	tests := []struct {
+		in      string
+		want    int
+		wantErr bool
+	}{
+		{in: "45", want: 45},
+		{in: "45m", want: 45},
+		{in: "1h30m", want: 90},
+		{in: "1.5h", want: 90},
+		{in: " 2h ", want: 120},
+		{in: "0", wantErr: true},
+		{in: "-10m", wantErr: true},
+		{in: "20s", wantErr: true},
+		{in: "soon", wantErr: true},
+	}
+	for _, tt := range tests {
+		got, err := parseWorkDuration(tt.in)
+		if (err != nil) != tt.wantErr || got != tt.want {
+			t.Errorf("parseWorkDuration(%q) = %d, %v; want %d, wantErr %v", tt.in, got, err, tt.want, tt.wantErr)
+		}
+	}
+}
+
+func TestFormatMinutes(t *testing.T) {
+	for in, want := range map[int]string{0: "0m", 45: "45m", 60: "1h", 135: "2h 15m"} {
+		if got := formatMinutes(in); got != want {
+			t.Errorf("formatMinutes(%d) = %q, want %q", in, got, want)
+		}
+	}
+}
+
+func TestSummarizeWorkLog(t *testing.T) {
+	report := summarizeWorkLog([]*types.WorkLogTotal{
+		{IssueID: "bd-1", Actor: "alice", Minutes: 30},
+		{IssueID: "bd-1", Actor: "bob", Minutes: 60},
+		{IssueID: "bd-2", Actor: "alice", Minutes: 120},
+	})
+	if report.TotalMinutes != 210 {
+		t.Errorf("TotalMinutes = %d, want 210", report.TotalMinutes)
+	}
+	wantIssues := []workLogTotal{{Key: "bd-2", Minutes: 120}, {Key: "bd-1", Minutes: 90}}
+	wantActors := []workLogTotal{{Key: "alice", Minutes: 150}, {Key: "bob", Minutes: 60}}
+	for i := range wantIssues {
+		if report.ByIssue[i] != wantIssues[i] {
+			t.Errorf("ByIssue = %+v, want %+v", report.ByIssue, wantIssues)
+		}
+		if report.ByActor[i] != wantActors[i] {
+			t.Errorf("ByActor = %+v, want %+v", report.ByActor, wantActors)
+		}
+	}
+}
+
+func TestSummarizeTimeTracking(t *testing.T) {
+	est := func(m int) *int { return &m }
+	summary := summarizeTimeTracking(
+		map[string]int{"bd-1": 90, "bd-2": 30, "bd-3": 45},
+		[]*types.Issue{
+			{ID: "bd-1", EstimatedMinutes: est(60)},
+			{ID: "bd-2", EstimatedMinutes: est(60)},
+			{ID: "bd-3"},
+		},
+	)
+	if summary.LoggedMinutes != 165 || summary.IssuesWithTime != 3 {
+		t.Errorf("logged = %d on %d issues, want 165 on 3", summary.LoggedMinutes, summary.IssuesWithTime)
+	}
+	if summary.EstimatedIssues != 2 || summary.EstimatedMinutes != 120 || summary.ActualMinutes != 120 {
+		t.Errorf("estimate comparison = %d issues, %d estimated, %d actual; want 2, 120, 120",
+			summary.EstimatedIssues, summary.EstimatedMinutes, summary.ActualMinutes)
+	}
+	if summary.ActualToEstimate != 1 {
+		t.Errorf("ActualToEstimate = %v, want 1", summary.ActualToEstimate)
+	}
+}
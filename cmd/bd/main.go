@@ -77,6 +77,7 @@ var (
 	readonlyMode      bool               // Read-only mode: block write operations (for worker sandboxes)
 	storeIsReadOnly   bool               // Track if store was opened read-only (for staleness checks)
 	ignoreSchemaSkew  bool               // Proceed despite forward schema drift
+	useDaemon         bool               // Answer supported commands from an auto-started daemon
 	lockTimeout       = 30 * time.Second // Dolt open timeout (fixed default)
 	profileEnabled    bool
 	profileFile       *os.File
@@ -119,20 +120,26 @@ var (
 // readOnlyCommands lists commands that only read from the database.
 // These commands open the store in read-only mode. See GH#804.
 var readOnlyCommands = map[string]bool{
-	"list":       true,
-	"ready":      true,
-	"show":       true,
-	"stats":      true,
-	"blocked":    true,
-	"count":      true,
-	"search":     true,
-	"graph":      true,
-	"duplicates": true,
-	"comments":   true, // list comments (not add)
-	"current":    true, // bd sync mode current
-	"ping":       true,
-	"backup":     true, // reads from Dolt, writes only to .beads/backup/
-	"export":     true, // reads from Dolt, writes JSONL to file/stdout
+	"list":        true,
+	"ready":       true,
+	"show":        true,
+	"stats":       true,
+	"blocked":     true,
+	"count":       true,
+	"search":      true,
+	"graph":       true,
+	"duplicates":  true,
+	"comments":    true, // list comments (not add)
+	"current":     true, // bd sync mode current
+	"ping":        true,
+	"backup":      true, // reads from Dolt, writes only to .beads/backup/
+	"export":      true, // reads from Dolt, writes JSONL to file/stdout
+	"explain":     true, // bd debug explain: EXPLAIN only
+	"verify-sync": true, // imports into a scratch database, not the live one
+	"view":        true, // runs a saved view or shows issues
+	"similar":     true,
+	"log":         true, // bd session log, bd daemon log
+	"handoff":     true,
 }
 
 // isReadOnlyCommand returns true if the command only reads from the database.
@@ -532,6 +539,7 @@ func init() {
 	rootCmd.PersistentFlags().BoolVarP(&verboseFlag, "verbose", "v", false, "Enable verbose/debug output")
 	rootCmd.PersistentFlags().BoolVarP(&quietFlag, "quiet", "q", false, "Suppress non-essential output (errors only)")
 	rootCmd.PersistentFlags().BoolVar(&ignoreSchemaSkew, "ignore-schema-skew", false, "Proceed despite forward schema drift (some queries may fail)")
+	rootCmd.PersistentFlags().BoolVar(&useDaemon, "use-daemon", false, "Answer supported commands from bd daemon, starting it if needed (default: config daemon.auto)")
 
 	// Add --version flag to root command (same behavior as version subcommand)
 	rootCmd.Flags().BoolP("version", "V", false, "Print version information")
@@ -723,6 +731,10 @@ var rootCmd = &cobra.Command{
 			}{doltAutoCommit, true}
 		}
 
+		if !cmd.Root().PersistentFlags().Changed("use-daemon") {
+			useDaemon = config.GetBool("daemon.auto")
+		}
+
 		// --ignore-schema-skew sets BD_IGNORE_SCHEMA_SKEW so the env-var escape
 		// hatch works uniformly for all store open paths (dolt, embedded).
 		if ignoreSchemaSkew {
@@ -758,6 +770,7 @@ var rootCmd = &cobra.Command{
 			"human",
 			"init",
 			"merge",
+			"merge-file", // git merge driver; runs mid-merge without the store
 			"onboard",
 			"powershell",
 			"prime",
@@ -765,6 +778,7 @@ var rootCmd = &cobra.Command{
 			"setup",
 			"version",
 			"where",
+			"workspace", // edits the per-machine registry only
 			"zsh",
 		}
 
@@ -798,6 +812,16 @@ var rootCmd = &cobra.Command{
 			skipsStoreInit = true
 		}
 
+		// The global daemon opens each workspace's store itself, and
+		// 'bd daemon status' only talks to a running daemon.
+		if cmdName == "daemon" && !isSubcommand {
+			if global, _ := cmd.Flags().GetBool("global"); global {
+				skipsStoreInit = true
+			}
+		} else if cmd.Parent() != nil && cmd.Parent().Name() == "daemon" {
+			skipsStoreInit = true
+		}
+
 		// Skip for root command with no subcommand (just shows help)
 		if cmd.Parent() == nil && cmdName == cmd.Use {
 			skipsStoreInit = true
@@ -932,18 +956,55 @@ var rootCmd = &cobra.Command{
 		// Best-effort tracking - failures are silent
 		trackBdVersion()
 
+		// --use-daemon / daemon.auto: answer from the workspace daemon,
+		// starting it if needed. Anything it can't serve runs directly.
+		if useDaemon && forwardToDaemon(cmd, args, beadsDir) {
+			os.Exit(0)
+		}
+
 		// Check if this is a read-only command (GH#804)
 		// Read-only commands open the store in read-only mode to avoid modifying
 		// the database (which breaks file watchers).
 		useReadOnly := isReadOnlyCommand(cmd.Name())
+		schemaAsFound := schemaLeftAsFound(cmd)
+		if cmd == migrateSchemaCmd {
+			// A read-write open would apply every pending migration; --to
+			// applies its own under the write lock taken below.
+			useReadOnly = schemaAsFound
+		}
+
+		// Serialize writers on .beads/lock so that concurrent bd processes
+		// (an agent and a human, say) don't interleave database writes and
+		// JSONL exports. Read-only commands don't wait.
+		if !useReadOnly || schemaAsFound && cmd.Flags().Changed("to") {
+			commandWriteLockDir = beadsDir
+			if !writeLockDeferredCommands[cmd.CommandPath()] {
+				acquireCommandWriteLock(beadsDir)
+			}
+		}
+
+		// Snapshot before pending schema migrations run: bd migrate schema
+		// applies them (--status excepted), and so does the version-bump
+		// migration below. See backup.snapshot-before-write.
+		switch {
+		case cmd == migrateSchemaCmd:
+			if statusOnly, _ := cmd.Flags().GetBool("status"); !statusOnly {
+				snapshotBeforeSchemaMigration(rootCtx, beadsDir, "bd migrate schema")
+			}
+		case versionUpgradeDetected:
+			snapshotBeforeSchemaMigration(rootCtx, beadsDir, "upgrade to bd "+Version)
+		}
 
 		// Auto-migrate database on version bump (bd-jgxi).
 		// Runs for ALL commands (including read-only ones) because the migration
 		// opens its own store connection, writes the version metadata, commits it,
 		// and closes BEFORE the main store is opened. This ensures bd doctor and
 		// read-only commands see the correct version after a CLI upgrade.
-
-		autoMigrateOnVersionBump(beadsDir)
+		// Skipped for bd migrate schema --status/--to, which must see the
+		// schema as found.
+		if !schemaAsFound {
+			autoMigrateOnVersionBump(beadsDir)
+		}
 
 		// Initialize direct storage access
 		var err error
@@ -1102,6 +1163,12 @@ var rootCmd = &cobra.Command{
 			maybeAutoImportJSONL(rootCtx, store, beadsDir)
 		}
 
+		// Re-import issues.jsonl when it changed behind the database's back,
+		// e.g. after a git pull without the hooks, rather than serve stale data.
+		if shouldCheckJSONLDivergence(cmd, store, globalFlag, doltCfg.ServerMode) {
+			checkJSONLDivergence(rootCtx, store, beadsDir, useReadOnly)
+		}
+
 		// Validate workspace identity for write commands (GH#2438, GH#2372)
 		// Skip for read-only commands since they can't corrupt data.
 		// Skip for --global: the global database uses a sentinel project ID
@@ -1145,6 +1212,12 @@ var rootCmd = &cobra.Command{
 		// Sync all state to CommandContext for unified access.
 		syncCommandContext()
 
+		// Check the actor against the registry (validation.on-actor). The
+		// 'bd actor' commands are exempt so an actor can register itself.
+		if !useReadOnly && cmd != actorCmd && cmd.Parent() != actorCmd {
+			checkRegisteredActor(rootCtx, actor)
+		}
+
 		// Tips (including sync conflict proactive checks) are shown via maybeShowTip()
 		// after successful command execution, not in PreRun
 	},
@@ -1157,6 +1230,16 @@ var rootCmd = &cobra.Command{
 				uowProvider = nil
 			}
 		} else {
+			// Commands that lock only around their writes take the lock for
+			// the commit and export below.
+			lockCommandWrites()
+
+			// Log the write in the actor's open work session ('bd session'),
+			// ahead of the auto-commit so both land in the same commit.
+			if commandDidWrite.Load() {
+				recordSessionCommand(rootCtx, cmd)
+			}
+
 			// Dolt auto-commit: after a successful write command (and after final flush),
 			// create a Dolt commit so changes don't remain only in the working set.
 			if commandDidWrite.Load() && !commandDidExplicitDoltCommit {
@@ -1199,7 +1282,7 @@ var rootCmd = &cobra.Command{
 			// Read-only commands must not perform post-run maintenance writes or emit
 			// sync guidance after machine-readable output.
 			if shouldRunPostCommandAutoExport(cmd) {
-				if err := maybeAutoExport(rootCtx, serverMode, commandAllowsEmptyAutoExport(cmd)); err != nil {
+				if err := maybeAutoExport(rootCtx, cmd.Name(), serverMode, commandAllowsEmptyAutoExport(cmd)); err != nil {
 					FatalError("%v", err)
 				}
 			}
@@ -1220,6 +1303,7 @@ var rootCmd = &cobra.Command{
 				_ = store.Close() // Best effort cleanup
 			}
 		}
+		releaseCommandWriteLock()
 
 		// End the command span and flush OTel data before process exit.
 		if commandSpan != nil {
@@ -374,6 +374,9 @@ func createIssuesFromMarkdown(_ *cobra.Command, filepath string) {
 				Type:        depType,
 			})
 		}
+		if err := checkValidationRules(issue); err != nil {
+			FatalError("issue '%s': %v", template.Title, err)
+		}
 
 		issues = append(issues, issue)
 	}
@@ -0,0 +1,555 @@
+package main
+
+import (
+	"bufio"
+	"bytes"
+	"crypto/sha256"
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/git"
+)
+
+// mergeDriverName is the name of the merge driver in git config and
+// .gitattributes (merge=beads).
+const mergeDriverName = "beads"
+
+var mergeFileCmd = &cobra.Command{
+	Use:     "merge-file <base> <ours> <theirs>",
+	GroupID: "sync",
+	Short:   "Three-way merge of a JSONL export (git merge driver)",
+	Long: `Merge two versions of a JSONL export against their common ancestor,
+record by record, and write the result over <ours>. This is the git merge
+driver 'bd merge-file install' registers for the export file, run by git as
+'bd merge-file %O %A %B'.
+
+Records are matched by issue ID (memories by key). A record changed on one
+side only takes that side; a record changed on both is merged field by
+field. A field changed on both sides takes the value from the side with the
+later updated_at; labels, dependencies, and comments are merged as sets, so
+additions from both sides are kept. A record deleted on one side and
+changed on the other is kept.
+
+Only records changed on both sides at the same updated_at, with different
+values, conflict: they are written between conflict markers and the command
+exits 1, leaving the file for you to resolve.
+
+EXAMPLES:
+  bd merge-file install                  # Register the driver for this repo
+  bd merge-file -o merged.jsonl base.jsonl ours.jsonl theirs.jsonl
+  bd merge-file uninstall                # Remove it again`,
+	Args: cobra.ExactArgs(3),
+	Run: func(cmd *cobra.Command, args []string) {
+		output, _ := cmd.Flags().GetString("output")
+		if output == "" {
+			output = args[1]
+		}
+		base, err := readMergeFile(args[0])
+		if err != nil {
+			FatalError("%v", err)
+		}
+		ours, err := readMergeFile(args[1])
+		if err != nil {
+			FatalError("%v", err)
+		}
+		theirs, err := readMergeFile(args[2])
+		if err != nil {
+			FatalError("%v", err)
+		}
+
+		merged, conflicts := mergeJSONL(base, ours, theirs)
+		// #nosec G306 -- same permissions git gives the file it merges
+		if err := os.WriteFile(output, merged, 0644); err != nil {
+			FatalError("failed to write %s: %v", output, err)
+		}
+		for _, c := range conflicts {
+			fmt.Fprintf(os.Stderr, "bd merge-file: conflict in %s\n", c)
+		}
+		if len(conflicts) > 0 {
+			os.Exit(1)
+		}
+	},
+}
+
+var mergeFileInstallCmd = &cobra.Command{
+	Use:   "install",
+	Short: "Register bd merge-file as the git merge driver for the JSONL export",
+	Long: `Configure this repository to merge the JSONL export with 'bd merge-file':
+set merge.beads.driver in the repository's git config, and add
+'<export path> merge=beads' to the .gitattributes at the repository root.
+
+Commit .gitattributes so that clones use the driver once they run this
+command too; git config is not shared.`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		root, pattern, err := mergeDriverTarget()
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if err := installMergeDriver(root, pattern); err != nil {
+			FatalErrorRespectJSON("installing merge driver: %v", err)
+		}
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"success": true, "path": pattern})
+			return
+		}
+		fmt.Printf("✓ Merge driver installed for %s\n", pattern)
+		fmt.Println("  Commit .gitattributes to share it with your team.")
+	},
+}
+
+var mergeFileUninstallCmd = &cobra.Command{
+	Use:   "uninstall",
+	Short: "Remove the bd merge driver from git config and .gitattributes",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		root, _, err := mergeDriverTarget()
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if err := uninstallMergeDriver(root); err != nil {
+			FatalErrorRespectJSON("uninstalling merge driver: %v", err)
+		}
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"success": true})
+			return
+		}
+		fmt.Println("✓ Merge driver uninstalled")
+	},
+}
+
+func init() {
+	mergeFileCmd.Flags().StringP("output", "o", "", "Write the result here instead of over <ours> (for jj)")
+	mergeFileCmd.AddCommand(mergeFileInstallCmd)
+	mergeFileCmd.AddCommand(mergeFileUninstallCmd)
+	rootCmd.AddCommand(mergeFileCmd)
+}
+
+// readMergeFile reads one version of a merged file. A missing file is an
+// empty one: git passes an empty base when both sides added the file.
+func readMergeFile(path string) ([]byte, error) {
+	data, err := os.ReadFile(path) // #nosec G304 -- paths given by git
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", path, err)
+	}
+	return data, nil
+}
+
+// mergeRecord is one line of a JSONL export, with its fields in order.
+type mergeRecord struct {
+	line   string
+	hash   [sha256.Size]byte // of the canonical record; see canonicalJSON
+	keys   []string
+	fields map[string]json.RawMessage
+}
+
+// parseMergeRecord parses line, or returns nil if it is not a JSON object.
+func parseMergeRecord(line string) *mergeRecord {
+	dec := json.NewDecoder(strings.NewReader(line))
+	if tok, err := dec.Token(); err != nil || tok != json.Delim('{') {
+		return nil
+	}
+	r := &mergeRecord{line: line, fields: make(map[string]json.RawMessage)}
+	for dec.More() {
+		tok, err := dec.Token()
+		if err != nil {
+			return nil
+		}
+		key, _ := tok.(string)
+		var v json.RawMessage
+		if err := dec.Decode(&v); err != nil {
+			return nil
+		}
+		if _, dup := r.fields[key]; !dup {
+			r.keys = append(r.keys, key)
+		}
+		r.fields[key] = v
+	}
+	r.hash = sha256.Sum256(canonicalJSON(json.RawMessage(line)))
+	return r
+}
+
+// id identifies the record across versions: its type and issue ID, or key
+// for a memory.
+func (r *mergeRecord) id() string {
+	var typ, id string
+	_ = json.Unmarshal(r.fields["_type"], &typ)
+	if typ == "" {
+		typ = "issue"
+	}
+	if typ == "memory" {
+		_ = json.Unmarshal(r.fields["key"], &id)
+		return "memory " + id
+	}
+	_ = json.Unmarshal(r.fields["id"], &id)
+	if typ == "issue" {
+		return id
+	}
+	return typ + " " + id
+}
+
+func (r *mergeRecord) updatedAt() time.Time {
+	var t time.Time
+	_ = json.Unmarshal(r.fields["updated_at"], &t)
+	return t
+}
+
+// encode writes the record with fields in the given order.
+func (r *mergeRecord) encode(keys []string) string {
+	var b strings.Builder
+	b.WriteByte('{')
+	for i, k := range keys {
+		if i > 0 {
+			b.WriteByte(',')
+		}
+		name, _ := json.Marshal(k)
+		b.Write(name)
+		b.WriteByte(':')
+		b.Write(r.fields[k])
+	}
+	b.WriteByte('}')
+	return b.String()
+}
+
+// mergeVersion is one side of a merge: its records by id, in file order.
+type mergeVersion struct {
+	byID  map[string]*mergeRecord
+	order []string
+	other []string // lines that are not records, kept as they are
+}
+
+func parseMergeVersion(data []byte) *mergeVersion {
+	v := &mergeVersion{byID: make(map[string]*mergeRecord)}
+	scanner := bufio.NewScanner(bytes.NewReader(data))
+	scanner.Buffer(make([]byte, 0, 1024*1024), 64*1024*1024)
+	for scanner.Scan() {
+		line := strings.TrimRight(scanner.Text(), "\r")
+		if strings.TrimSpace(line) == "" {
+			continue
+		}
+		r := parseMergeRecord(line)
+		if r == nil {
+			v.other = append(v.other, line)
+			continue
+		}
+		id := r.id()
+		if _, dup := v.byID[id]; !dup {
+			v.order = append(v.order, id)
+		}
+		v.byID[id] = r
+	}
+	return v
+}
+
+// mergeJSONL merges the JSONL exports ours and theirs against base, and
+// returns the result and the IDs of the records that conflict. Records
+// keep the order of ours, with those only in theirs after them; issues
+// come before other records, as in an export.
+func mergeJSONL(base, ours, theirs []byte) ([]byte, []string) {
+	o, a, b := parseMergeVersion(base), parseMergeVersion(ours), parseMergeVersion(theirs)
+
+	ids := slices.Clone(a.order)
+	for _, id := range b.order {
+		if _, ok := a.byID[id]; !ok {
+			ids = append(ids, id)
+		}
+	}
+	slices.SortStableFunc(ids, func(x, y string) int {
+		return boolRank(strings.Contains(x, " ")) - boolRank(strings.Contains(y, " "))
+	})
+
+	var out bytes.Buffer
+	var conflicts []string
+	for _, id := range ids {
+		line, conflict := mergeRecords(o.byID[id], a.byID[id], b.byID[id])
+		if conflict {
+			conflicts = append(conflicts, id)
+			fmt.Fprintf(&out, "<<<<<<< ours\n%s\n=======\n%s\n>>>>>>> theirs\n", a.byID[id].line, b.byID[id].line)
+			continue
+		}
+		if line != "" {
+			out.WriteString(line)
+			out.WriteByte('\n')
+		}
+	}
+	for _, line := range a.other {
+		out.WriteString(line)
+		out.WriteByte('\n')
+	}
+	for _, line := range b.other {
+		if !slices.Contains(a.other, line) {
+			out.WriteString(line)
+			out.WriteByte('\n')
+		}
+	}
+	return out.Bytes(), conflicts
+}
+
+func boolRank(b bool) int {
+	if b {
+		return 1
+	}
+	return 0
+}
+
+// mergeRecords merges one record. Any of o (base), a (ours), and b
+// (theirs) may be nil, for a record the version lacks. It returns the
+// merged line, "" for a deleted record, or conflict.
+func mergeRecords(o, a, b *mergeRecord) (line string, conflict bool) {
+	same := func(x, y *mergeRecord) bool {
+		if x == nil || y == nil {
+			return x == y
+		}
+		return x.hash == y.hash
+	}
+	switch {
+	case same(a, b) || same(b, o):
+		if a == nil {
+			return "", false
+		}
+		return a.line, false
+	case same(a, o):
+		if b == nil {
+			return "", false
+		}
+		return b.line, false
+	case a == nil: // deleted by ours, changed by theirs: keep the change
+		return b.line, false
+	case b == nil:
+		return a.line, false
+	}
+
+	// Changed on both sides: merge field by field.
+	merged := &mergeRecord{fields: make(map[string]json.RawMessage)}
+	keys := slices.Clone(a.keys)
+	for _, k := range b.keys {
+		if _, ok := a.fields[k]; !ok {
+			keys = append(keys, k)
+		}
+	}
+	if o == nil {
+		o = &mergeRecord{fields: map[string]json.RawMessage{}}
+	}
+	newer := a.updatedAt().Compare(b.updatedAt())
+	var outKeys []string
+	for _, k := range keys {
+		v, ok := mergeField(k, o.fields[k], a.fields[k], b.fields[k], newer)
+		if !ok {
+			return "", true
+		}
+		if v != nil {
+			merged.fields[k] = v
+			outKeys = append(outKeys, k)
+		}
+	}
+	return merged.encode(outKeys), false
+}
+
+// mergeField merges one field of a record changed on both sides; nil
+// values are absent. newer compares ours' updated_at with theirs'. It
+// reports false if the field conflicts.
+func mergeField(key string, o, a, b json.RawMessage, newer int) (json.RawMessage, bool) {
+	switch {
+	case jsonEqual(a, b) || jsonEqual(b, o):
+		return a, true
+	case jsonEqual(a, o):
+		return b, true
+	}
+	switch key {
+	case "labels":
+		return mergeJSONSet(o, a, b, func(v json.RawMessage) string { return string(v) })
+	case "dependencies":
+		return mergeJSONSet(o, a, b, func(v json.RawMessage) string {
+			var dep struct {
+				DependsOnID string `json:"depends_on_id"`
+				Type        string `json:"type"`
+			}
+			_ = json.Unmarshal(v, &dep)
+			return dep.DependsOnID + " " + dep.Type
+		})
+	case "comments":
+		return mergeJSONSet(o, a, b, func(v json.RawMessage) string {
+			var c struct {
+				ID json.RawMessage `json:"id"`
+			}
+			_ = json.Unmarshal(v, &c)
+			return string(c.ID)
+		})
+	}
+	switch {
+	case newer > 0:
+		return a, true
+	case newer < 0:
+		return b, true
+	}
+	return nil, false
+}
+
+// jsonEqual reports whether x and y are the same JSON value; nil is
+// absent.
+func jsonEqual(x, y json.RawMessage) bool {
+	if x == nil || y == nil {
+		return x == nil && y == nil
+	}
+	return bytes.Equal(canonicalJSON(x), canonicalJSON(y))
+}
+
+// mergeJSONSet merges JSON arrays as sets of elements identified by key:
+// an element either side added is kept and one either side removed is
+// dropped. An element changed on both sides conflicts.
+func mergeJSONSet(o, a, b json.RawMessage, key func(json.RawMessage) string) (json.RawMessage, bool) {
+	decode := func(raw json.RawMessage) (map[string]json.RawMessage, []string, bool) {
+		var list []json.RawMessage
+		if raw != nil && json.Unmarshal(raw, &list) != nil {
+			return nil, nil, false
+		}
+		m := make(map[string]json.RawMessage, len(list))
+		var order []string
+		for _, v := range list {
+			k := key(v)
+			if _, dup := m[k]; !dup {
+				order = append(order, k)
+			}
+			m[k] = v
+		}
+		return m, order, true
+	}
+	om, _, ok1 := decode(o)
+	am, aOrder, ok2 := decode(a)
+	bm, bOrder, ok3 := decode(b)
+	if !ok1 || !ok2 || !ok3 {
+		return nil, false
+	}
+
+	result := []json.RawMessage{}
+	seen := make(map[string]bool)
+	for _, k := range append(aOrder, bOrder...) {
+		if seen[k] {
+			continue
+		}
+		seen[k] = true
+		av, inA := am[k]
+		bv, inB := bm[k]
+		ov, inO := om[k]
+		switch {
+		case inA && inB:
+			v, ok := mergeField("", ov, av, bv, 0)
+			if !ok {
+				return nil, false
+			}
+			result = append(result, v)
+		case inA && (!inO || !jsonEqual(av, ov)):
+			result = append(result, av) // added by ours, or changed by ours and removed by theirs
+		case inB && (!inO || !jsonEqual(bv, ov)):
+			result = append(result, bv)
+		}
+	}
+	out, err := json.Marshal(result)
+	if err != nil {
+		return nil, false
+	}
+	return out, true
+}
+
+// mergeDriverTarget returns the repository root and the .gitattributes
+// pattern of the JSONL export in it.
+func mergeDriverTarget() (root, pattern string, err error) {
+	root = git.GetRepoRoot()
+	if root == "" {
+		return "", "", fmt.Errorf("not in a git repository")
+	}
+	beadsDir := beads.FindBeadsDir()
+	if beadsDir == "" {
+		beadsDir = filepath.Join(root, ".beads")
+	}
+	exportPath := config.GetString("export.path")
+	if exportPath == "" {
+		exportPath = "issues.jsonl"
+	}
+	rel, err := filepath.Rel(root, filepath.Join(beadsDir, exportPath))
+	if err != nil || rel == ".." || strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+		return "", "", fmt.Errorf("the JSONL export %s is outside the repository", filepath.Join(beadsDir, exportPath))
+	}
+	return root, filepath.ToSlash(rel), nil
+}
+
+// installMergeDriver sets merge.beads.* in the git config of the
+// repository at root and maps pattern to it in root/.gitattributes.
+func installMergeDriver(root, pattern string) error {
+	settings := [][2]string{
+		{"merge." + mergeDriverName + ".name", "bd JSONL merge driver (record-level three-way merge)"},
+		{"merge." + mergeDriverName + ".driver", "bd merge-file %O %A %B"},
+	}
+	for _, kv := range settings {
+		cmd := exec.Command("git", "config", kv[0], kv[1]) // #nosec G204 -- fixed keys and values
+		cmd.Dir = root
+		if output, err := cmd.CombinedOutput(); err != nil {
+			return fmt.Errorf("git config failed: %w (output: %s)", err, string(output))
+		}
+	}
+
+	path := filepath.Join(root, ".gitattributes")
+	content, err := os.ReadFile(path) // #nosec G304 -- repository root
+	if err != nil && !os.IsNotExist(err) {
+		return fmt.Errorf("failed to read .gitattributes: %w", err)
+	}
+	entry := pattern + " merge=" + mergeDriverName
+	for _, line := range strings.Split(string(content), "\n") {
+		if strings.TrimSpace(line) == entry {
+			return nil
+		}
+	}
+	if len(content) > 0 && !bytes.HasSuffix(content, []byte("\n")) {
+		content = append(content, '\n')
+	}
+	content = append(content, entry+"\n"...)
+	// #nosec G306 -- .gitattributes is a tracked, world-readable file
+	if err := os.WriteFile(path, content, 0644); err != nil {
+		return fmt.Errorf("failed to write .gitattributes: %w", err)
+	}
+	return nil
+}
+
+// uninstallMergeDriver undoes installMergeDriver: it removes the
+// merge.beads section and every merge=beads line of root/.gitattributes,
+// deleting the file if nothing else is left in it.
+func uninstallMergeDriver(root string) error {
+	cmd := exec.Command("git", "config", "--remove-section", "merge."+mergeDriverName) // #nosec G204 -- fixed section name
+	cmd.Dir = root
+	if output, err := cmd.CombinedOutput(); err != nil && !strings.Contains(string(output), "no such section") {
+		return fmt.Errorf("git config failed: %w (output: %s)", err, string(output))
+	}
+
+	path := filepath.Join(root, ".gitattributes")
+	content, err := os.ReadFile(path) // #nosec G304 -- repository root
+	if os.IsNotExist(err) {
+		return nil
+	}
+	if err != nil {
+		return fmt.Errorf("failed to read .gitattributes: %w", err)
+	}
+	var kept []string
+	for _, line := range strings.Split(strings.TrimSuffix(string(content), "\n"), "\n") {
+		if slices.Contains(strings.Fields(line), "merge="+mergeDriverName) {
+			continue
+		}
+		kept = append(kept, line)
+	}
+	if strings.TrimSpace(strings.Join(kept, "")) == "" {
+		return os.Remove(path)
+	}
+	// #nosec G306 -- .gitattributes is a tracked, world-readable file
+	return os.WriteFile(path, []byte(strings.Join(kept, "\n")+"\n"), 0644)
+}
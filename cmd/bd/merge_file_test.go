@@ -0,0 +1,125 @@
+package main
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestMergeJSONL(t *testing.T) {
+	lines := func(ls ...string) []byte { return []byte(strings.Join(ls, "\n") + "\n") }
+
+	cases := []struct {
+		name          string
+		base, a, b    []byte
+		want          []string
+		wantConflicts []string
+	}{
+		{
+			name: "each side changes a different issue",
+			base: lines(`{"id":"bd-1","title":"a","updated_at":"2026-01-01T00:00:00Z"}`, `{"id":"bd-2","title":"b","updated_at":"2026-01-01T00:00:00Z"}`),
+			a:    lines(`{"id":"bd-1","title":"a2","updated_at":"2026-01-02T00:00:00Z"}`, `{"id":"bd-2","title":"b","updated_at":"2026-01-01T00:00:00Z"}`),
+			b:    lines(`{"id":"bd-1","title":"a","updated_at":"2026-01-01T00:00:00Z"}`, `{"id":"bd-2","title":"b2","updated_at":"2026-01-03T00:00:00Z"}`),
+			want: []string{`{"id":"bd-1","title":"a2","updated_at":"2026-01-02T00:00:00Z"}`, `{"id":"bd-2","title":"b2","updated_at":"2026-01-03T00:00:00Z"}`},
+		},
+		{
+			name: "both sides add issues",
+			base: lines(`{"id":"bd-1","title":"a"}`),
+			a:    lines(`{"id":"bd-1","title":"a"}`, `{"id":"bd-2","title":"ours"}`),
+			b:    lines(`{"id":"bd-1","title":"a"}`, `{"id":"bd-3","title":"theirs"}`, `{"_type":"memory","key":"k","value":"v"}`),
+			want: []string{`{"id":"bd-1","title":"a"}`, `{"id":"bd-2","title":"ours"}`, `{"id":"bd-3","title":"theirs"}`, `{"_type":"memory","key":"k","value":"v"}`},
+		},
+		{
+			name: "deleted on one side, unchanged on the other",
+			base: lines(`{"id":"bd-1","title":"a"}`, `{"id":"bd-2","title":"b"}`),
+			a:    lines(`{"id":"bd-1","title":"a"}`),
+			b:    lines(`{"id":"bd-1","title":"a"}`, `{"id":"bd-2","title":"b"}`),
+			want: []string{`{"id":"bd-1","title":"a"}`},
+		},
+		{
+			name: "deleted on one side, changed on the other",
+			base: lines(`{"id":"bd-1","title":"a"}`),
+			a:    lines(),
+			b:    lines(`{"id":"bd-1","title":"a2"}`),
+			want: []string{`{"id":"bd-1","title":"a2"}`},
+		},
+		{
+			name: "different fields of one issue",
+			base: lines(`{"id":"bd-1","title":"a","status":"open","priority":2,"updated_at":"2026-01-01T00:00:00Z"}`),
+			a:    lines(`{"id":"bd-1","title":"a","status":"closed","priority":2,"updated_at":"2026-01-02T00:00:00Z"}`),
+			b:    lines(`{"id":"bd-1","title":"a","status":"open","priority":0,"updated_at":"2026-01-03T00:00:00Z"}`),
+			want: []string{`{"id":"bd-1","title":"a","status":"closed","priority":0,"updated_at":"2026-01-03T00:00:00Z"}`},
+		},
+		{
+			name: "same field: later updated_at wins",
+			base: lines(`{"id":"bd-1","title":"a","updated_at":"2026-01-01T00:00:00Z"}`),
+			a:    lines(`{"id":"bd-1","title":"ours","updated_at":"2026-01-03T00:00:00Z"}`),
+			b:    lines(`{"id":"bd-1","title":"theirs","updated_at":"2026-01-02T00:00:00Z"}`),
+			want: []string{`{"id":"bd-1","title":"ours","updated_at":"2026-01-03T00:00:00Z"}`},
+		},
+		{
+			name: "labels and dependencies merge as sets",
+			base: lines(`{"id":"bd-1","labels":["x","y"],"dependencies":[{"depends_on_id":"bd-9","type":"blocks"}],"updated_at":"2026-01-01T00:00:00Z"}`),
+			a:    lines(`{"id":"bd-1","labels":["x","y","ours"],"dependencies":[],"updated_at":"2026-01-02T00:00:00Z"}`),
+			b:    lines(`{"id":"bd-1","labels":["y","theirs"],"dependencies":[{"depends_on_id":"bd-9","type":"blocks"},{"depends_on_id":"bd-8","type":"blocks"}],"updated_at":"2026-01-02T00:00:00Z"}`),
+			want: []string{`{"id":"bd-1","labels":["y","ours","theirs"],"dependencies":[{"depends_on_id":"bd-8","type":"blocks"}],"updated_at":"2026-01-02T00:00:00Z"}`},
+		},
+		{
+			name:          "same field at the same updated_at conflicts",
+			base:          lines(`{"id":"bd-1","title":"a","updated_at":"2026-01-01T00:00:00Z"}`),
+			a:             lines(`{"id":"bd-1","title":"ours","updated_at":"2026-01-02T00:00:00Z"}`),
+			b:             lines(`{"id":"bd-1","title":"theirs","updated_at":"2026-01-02T00:00:00Z"}`),
+			want:          []string{"<<<<<<< ours", `{"id":"bd-1","title":"ours","updated_at":"2026-01-02T00:00:00Z"}`, "=======", `{"id":"bd-1","title":"theirs","updated_at":"2026-01-02T00:00:00Z"}`, ">>>>>>> theirs"},
+			wantConflicts: []string{"bd-1"},
+		},
+	}
+	for _, tc := range cases {
+		t.Run(tc.name, func(t *testing.T) {
+			got, conflicts := mergeJSONL(tc.base, tc.a, tc.b)
+			if want := strings.Join(tc.want, "\n") + "\n"; string(got) != want {
+				t.Errorf("merged:\n%s\nwant:\n%s", got, want)
+			}
+			if strings.Join(conflicts, ",") != strings.Join(tc.wantConflicts, ",") {
+				t.Errorf("conflicts = %v, want %v", conflicts, tc.wantConflicts)
+			}
+		})
+	}
+}
+
+func TestInstallMergeDriver(t *testing.T) {
+	root := newGitRepo(t)
+	attrs := filepath.Join(root, ".gitattributes")
+	if err := os.WriteFile(attrs, []byte("*.png binary"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	for range 2 { // installing twice adds one line
+		if err := installMergeDriver(root, ".beads/issues.jsonl"); err != nil {
+			t.Fatalf("installMergeDriver: %v", err)
+		}
+	}
+	content, _ := os.ReadFile(attrs)
+	if want := "*.png binary\n.beads/issues.jsonl merge=beads\n"; string(content) != want {
+		t.Errorf(".gitattributes = %q, want %q", content, want)
+	}
+	cmd := exec.Command("git", "config", "merge.beads.driver")
+	cmd.Dir = root
+	if out, err := cmd.Output(); err != nil || strings.TrimSpace(string(out)) != "bd merge-file %O %A %B" {
+		t.Errorf("merge.beads.driver = %q, %v", out, err)
+	}
+
+	if err := uninstallMergeDriver(root); err != nil {
+		t.Fatalf("uninstallMergeDriver: %v", err)
+	}
+	content, _ = os.ReadFile(attrs)
+	if want := "*.png binary\n"; string(content) != want {
+		t.Errorf(".gitattributes after uninstall = %q, want %q", content, want)
+	}
+	cmd = exec.Command("git", "config", "merge.beads.driver")
+	cmd.Dir = root
+	if out, err := cmd.Output(); err == nil {
+		t.Errorf("merge.beads.driver still set after uninstall: %q", out)
+	}
+}
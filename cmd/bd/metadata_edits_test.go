@@ -299,6 +299,55 @@ func TestMergeMetadata_NonObjectIncoming(t *testing.T) {
 	}
 }
 
+func TestApplyCustomFields_KeepsTopLevelKeys(t *testing.T) {
+	t.Parallel()
+	existing := json.RawMessage(`{"points":"jira","custom_fields":{"team":"core"}}`)
+	result, err := applyCustomFields(existing, []string{"points=3", "team=platform"})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	var data struct {
+		Points       string                     `json:"points"`
+		CustomFields map[string]json.RawMessage `json:"custom_fields"`
+	}
+	if err := json.Unmarshal(result, &data); err != nil {
+		t.Fatalf("unmarshal: %v", err)
+	}
+	if data.Points != "jira" {
+		t.Errorf("top-level points = %q, want it left alone", data.Points)
+	}
+	if string(data.CustomFields["points"]) != "3" || string(data.CustomFields["team"]) != `"platform"` {
+		t.Errorf("custom_fields = %s", result)
+	}
+}
+
+func TestApplyCustomFields_EmptyExisting(t *testing.T) {
+	t.Parallel()
+	result, err := applyCustomFields(nil, []string{"points=3"})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if string(result) != `{"custom_fields":{"points":3}}` {
+		t.Errorf("got %s", result)
+	}
+}
+
+func TestApplyCustomFields_Invalid(t *testing.T) {
+	t.Parallel()
+	for _, tc := range []struct {
+		existing string
+		flag     string
+	}{
+		{"", "points"},
+		{"", "bad key=1"},
+		{`{"custom_fields":[1]}`, "points=3"},
+	} {
+		if _, err := applyCustomFields(json.RawMessage(tc.existing), []string{tc.flag}); err == nil {
+			t.Errorf("applyCustomFields(%q, %q) succeeded, want an error", tc.existing, tc.flag)
+		}
+	}
+}
+
 func TestToJSONValue(t *testing.T) {
 	t.Parallel()
 	tests := []struct {
@@ -1,6 +1,7 @@
 package main
 
 import (
+	"context"
 	"fmt"
 	"os"
 	"strings"
@@ -482,18 +483,31 @@ func handleInspect() {
 		warnings = append(warnings, fmt.Sprintf("schema version mismatch (current: %s, expected: %s)", schemaVersion, Version))
 	}
 
+	// bd_version is the binary that last touched the database; the applied
+	// migration number is what actually determines the table layout.
+	migState, migErr := readSchemaMigrationState(ctx, store)
+	if w := schemaMigrationWarning(migState, migErr); w != "" {
+		warnings = append(warnings, w)
+	}
+
 	// Output result
+	currentState := map[string]interface{}{
+		"schema_version": schemaVersion,
+		"issue_count":    issueCount,
+		"config":         configMap,
+		"missing_config": missingConfig,
+		"db_exists":      true,
+	}
+	if migErr == nil {
+		currentState["migration_version"] = migState.Current
+		currentState["latest_migration_version"] = migState.Latest
+		currentState["pending_migrations"] = migState.Pending
+	}
 	result := map[string]interface{}{
 		"registered_migrations": registeredMigrations,
-		"current_state": map[string]interface{}{
-			"schema_version": schemaVersion,
-			"issue_count":    issueCount,
-			"config":         configMap,
-			"missing_config": missingConfig,
-			"db_exists":      true,
-		},
-		"warnings":            warnings,
-		"invariants_to_check": []string{},
+		"current_state":         currentState,
+		"warnings":              warnings,
+		"invariants_to_check":   []string{},
 	}
 
 	if jsonOutput {
@@ -502,6 +516,9 @@ func handleInspect() {
 		fmt.Println("\nMigration Inspection")
 		fmt.Println("====================")
 		fmt.Printf("Schema Version: %s\n", schemaVersion)
+		if migErr == nil {
+			fmt.Printf("Migration Version: v%d (latest v%d)\n", migState.Current, migState.Latest)
+		}
 		fmt.Printf("Issue Count: %d\n", issueCount)
 		fmt.Printf("Registered Migrations: %d\n", len(registeredMigrations))
 
@@ -522,7 +539,9 @@ func handleInspect() {
 	}
 }
 
-func handleSchemaMigrate() {
+// handleSchemaMigrate applies pending schema migrations, up to version to
+// when it is positive (bd migrate schema --to N).
+func handleSchemaMigrate(to int) {
 	beadsDir := beads.FindBeadsDir()
 	if beadsDir == "" {
 		if jsonOutput {
@@ -559,7 +578,16 @@ func handleSchemaMigrate() {
 		FatalError("current storage backend does not support schema migration")
 	}
 
-	applied, err := migrator.ApplySchemaMigrations(rootCtx)
+	var applied int
+	var err error
+	latest := schema.LatestVersion()
+	target := latest
+	if to > 0 {
+		applied, err = migrator.ApplySchemaMigrationsTo(rootCtx, to)
+		target = to
+	} else {
+		applied, err = migrator.ApplySchemaMigrations(rootCtx)
+	}
 	if err != nil {
 		if jsonOutput {
 			outputJSON(map[string]interface{}{
@@ -571,27 +599,103 @@ func handleSchemaMigrate() {
 		FatalError("schema migration failed: %v", err)
 	}
 
-	latest := schema.LatestVersion()
 	status := "current"
 	if applied > 0 {
 		status = "applied"
-		commandDidWrite.Store(true)
+		// With --to the store is open read-only; the migrations have
+		// committed themselves.
+		if to == 0 {
+			commandDidWrite.Store(true)
+		}
 	}
 
 	if jsonOutput {
 		outputJSON(map[string]interface{}{
 			"status":         status,
 			"applied":        applied,
+			"schema_version": target,
 			"latest_version": latest,
 		})
 		return
 	}
 
 	if applied == 0 {
-		fmt.Printf("%s\n", ui.RenderPass(fmt.Sprintf("✓ Schema already at v%d", latest)))
+		fmt.Printf("%s\n", ui.RenderPass(fmt.Sprintf("✓ Schema already at v%d", target)))
 		return
 	}
-	fmt.Printf("%s\n", ui.RenderPass(fmt.Sprintf("✓ Applied %d schema migration(s); schema now at v%d", applied, latest)))
+	fmt.Printf("%s\n", ui.RenderPass(fmt.Sprintf("✓ Applied %d schema migration(s); schema now at v%d", applied, target)))
+}
+
+// handleSchemaStatus prints the schema migration state (bd migrate schema --status).
+func handleSchemaStatus() {
+	store := getStore()
+	if store == nil {
+		FatalErrorRespectJSON("no database — run 'bd init' to create a new database")
+	}
+	state, err := readSchemaMigrationState(rootCtx, store)
+	if err != nil {
+		FatalErrorRespectJSON("%v", err)
+	}
+
+	if jsonOutput {
+		outputJSON(state)
+		return
+	}
+	fmt.Printf("Applied: v%d\n", state.Current)
+	fmt.Printf("Latest:  v%d\n", state.Latest)
+	if len(state.Pending) == 0 {
+		fmt.Printf("%s\n", ui.RenderPass("✓ No pending schema migrations"))
+		return
+	}
+	versions := make([]string, len(state.Pending))
+	for i, v := range state.Pending {
+		versions[i] = fmt.Sprintf("v%d", v)
+	}
+	fmt.Printf("Pending: %s\n", strings.Join(versions, ", "))
+	fmt.Println("Run 'bd migrate schema' to apply them.")
+}
+
+// schemaMigrationState describes how far a database's numbered schema
+// migrations (schema_migrations table) lag the ones embedded in this binary.
+type schemaMigrationState struct {
+	Current int   `json:"current_version"`
+	Latest  int   `json:"latest_version"`
+	Pending []int `json:"pending_versions"`
+}
+
+// readSchemaMigrationState reads the applied migration version without
+// applying anything. It needs raw DB access, so embedded mode reports an error.
+func readSchemaMigrationState(ctx context.Context, st storage.DoltStorage) (*schemaMigrationState, error) {
+	accessor, ok := storage.UnwrapStore(st).(storage.RawDBAccessor)
+	if !ok || accessor.UnderlyingDB() == nil {
+		return nil, fmt.Errorf("storage backend does not expose schema migration state")
+	}
+	db := accessor.UnderlyingDB()
+	current, err := schema.CurrentVersion(ctx, db)
+	if err != nil {
+		return nil, err
+	}
+	pending, err := schema.PendingVersions(ctx, db)
+	if err != nil {
+		return nil, err
+	}
+	if pending == nil {
+		pending = []int{}
+	}
+	return &schemaMigrationState{Current: current, Latest: schema.LatestVersion(), Pending: pending}, nil
+}
+
+// schemaMigrationWarning returns the --inspect warning for a database's
+// migration state, or "" when the schema is current.
+func schemaMigrationWarning(state *schemaMigrationState, err error) string {
+	if err != nil {
+		return fmt.Sprintf("could not read schema migration state: %v", err)
+	}
+	if len(state.Pending) == 0 {
+		return ""
+	}
+	return fmt.Sprintf("database schema is at migration v%d, binary expects v%d (%d pending) - run 'bd migrate schema'",
+		state.Current, state.Latest, len(state.Pending))
 }
 
 // handleToSeparateBranch configures separate branch workflow for existing repos
@@ -741,16 +845,48 @@ Schema migrations also run automatically on store open, so this subcommand
 is typically a no-op. It exists to make migration explicit and observable
 in CI, release gates, and recovery scenarios.
 
+Use --status to print the applied and pending migration versions without
+applying anything, and --to N to apply only the migrations up to version N.
+Neither runs the migrations that a bd upgrade or a read-write open would
+otherwise apply first. Migrations only go forward, so N cannot be below the
+applied version; any later bd command that opens the database read-write
+applies the rest.
+
 Example:
   bd migrate schema
+  bd migrate schema --status
+  bd migrate schema --to 57
   bd migrate schema --json`,
 	Args: cobra.NoArgs,
 	Run: func(cmd *cobra.Command, _ []string) {
+		to, _ := cmd.Flags().GetInt("to")
+		if statusOnly, _ := cmd.Flags().GetBool("status"); statusOnly {
+			if cmd.Flags().Changed("to") {
+				FatalErrorRespectJSON("--status and --to cannot be used together")
+			}
+			handleSchemaStatus()
+			return
+		}
+		if cmd.Flags().Changed("to") && to < 1 {
+			FatalErrorRespectJSON("--to must be a schema version of at least 1")
+		}
 		CheckReadonly("migrate schema")
-		handleSchemaMigrate()
+		handleSchemaMigrate(to)
 	},
 }
 
+// schemaLeftAsFound reports whether cmd must see the schema as it is on
+// disk: bd migrate schema --status and --to inspect or stop at a version,
+// so neither the version-bump migration nor a read-write open, both of
+// which apply every pending migration, may run first.
+func schemaLeftAsFound(cmd *cobra.Command) bool {
+	if cmd != migrateSchemaCmd {
+		return false
+	}
+	status, _ := cmd.Flags().GetBool("status")
+	return status || cmd.Flags().Changed("to")
+}
+
 func init() {
 	migrateCmd.Flags().Bool("yes", false, "Auto-confirm prompts")
 	migrateCmd.Flags().Bool("dry-run", false, "Show what would be done without making changes")
@@ -768,6 +904,8 @@ func init() {
 	migrateHooksCmd.Flags().BoolVar(&jsonOutput, "json", false, "Output in JSON format")
 	migrateCmd.AddCommand(migrateHooksCmd)
 
+	migrateSchemaCmd.Flags().Bool("status", false, "Show applied and pending schema migrations without applying them")
+	migrateSchemaCmd.Flags().Int("to", 0, "Apply pending schema migrations only up to this version")
 	migrateSchemaCmd.Flags().BoolVar(&jsonOutput, "json", false, "Output in JSON format")
 	migrateCmd.AddCommand(migrateSchemaCmd)
 
@@ -212,6 +212,7 @@ func executeMigrateIssues(ctx context.Context, p migrateIssuesParams) error {
 			}
 		}
 
+		snapshotBeforeWrite(ctx, "bd migrate issues")
 		if err := executeMigration(ctx, s, migrationSet, p.to); err != nil {
 			return fmt.Errorf("migration failed: %w", err)
 		}
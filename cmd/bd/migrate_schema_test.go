@@ -0,0 +1,55 @@
+package main
+
+import (
+	"errors"
+	"strings"
+	"testing"
+)
+
+func TestSchemaMigrationWarning(t *testing.T) {
+	if w := schemaMigrationWarning(&schemaMigrationState{Current: 59, Latest: 59, Pending: []int{}}, nil); w != "" {
+		t.Errorf("current schema warned: %q", w)
+	}
+	w := schemaMigrationWarning(&schemaMigrationState{Current: 57, Latest: 59, Pending: []int{58, 59}}, nil)
+	for _, want := range []string{"v57", "v59", "2 pending", "bd migrate schema"} {
+		if !strings.Contains(w, want) {
+			t.Errorf("pending warning %q missing %q", w, want)
+		}
+	}
+	if w := schemaMigrationWarning(nil, errors.New("no raw access")); !strings.Contains(w, "no raw access") {
+		t.Errorf("read error warning = %q", w)
+	}
+}
+
+func TestSchemaLeftAsFound(t *testing.T) {
+	flags := migrateSchemaCmd.Flags()
+	reset := func() {
+		for name, def := range map[string]string{"status": "false", "to": "0"} {
+			f := flags.Lookup(name)
+			_ = f.Value.Set(def)
+			f.Changed = false
+		}
+	}
+	reset()
+	t.Cleanup(reset)
+
+	if schemaLeftAsFound(migrateSchemaCmd) {
+		t.Error("plain 'bd migrate schema' should migrate on open as usual")
+	}
+	if schemaLeftAsFound(migrateCmd) {
+		t.Error("other commands should migrate on open as usual")
+	}
+	if err := flags.Set("status", "true"); err != nil {
+		t.Fatal(err)
+	}
+	if !schemaLeftAsFound(migrateSchemaCmd) {
+		t.Error("--status must see the schema as found")
+	}
+	reset()
+	if err := flags.Set("to", "57"); err != nil {
+		t.Fatal(err)
+	}
+	if !schemaLeftAsFound(migrateSchemaCmd) {
+		t.Error("--to must see the schema as found")
+	}
+}
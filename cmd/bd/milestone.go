@@ -0,0 +1,421 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/timeparsing"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+var milestoneCmd = &cobra.Command{
+	Use:     "milestone",
+	GroupID: "deps",
+	Short:   "Milestone management commands",
+	Long: `Group issues into milestones (releases, deadlines) and track their progress.
+
+A milestone is an issue of type milestone, usually with a due date. Issues
+are assigned to it with non-blocking 'tracks' dependencies, so a milestone
+can span several epics and assigning work to one never changes what is
+ready. An issue belongs to at most one milestone.`,
+}
+
+var milestoneCreateCmd = &cobra.Command{
+	Use:   "create <title>",
+	Short: "Create a milestone",
+	Example: `  bd milestone create "v1.0" --due 2025-07-01
+  bd milestone create "Beta" --due +3w -d "Feature-complete for beta users"`,
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("milestone create")
+		ctx := rootCtx
+
+		milestone := &types.Issue{
+			Title:     args[0],
+			Status:    types.StatusOpen,
+			Priority:  2,
+			IssueType: types.TypeMilestone,
+			Owner:     getOwner(),
+			CreatedBy: getActor(),
+		}
+		milestone.Description, _ = cmd.Flags().GetString("description")
+		if dueStr, _ := cmd.Flags().GetString("due"); dueStr != "" {
+			t, err := timeparsing.ParseRelativeTime(dueStr, time.Now())
+			if err != nil {
+				FatalErrorRespectJSON("invalid --due: %v", err)
+			}
+			milestone.DueAt = &t
+		}
+
+		if err := store.CreateIssue(ctx, milestone, getActor()); err != nil {
+			FatalErrorRespectJSON("creating milestone: %v", err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(milestone)
+			return
+		}
+		fmt.Printf("%s Created milestone %s: %s\n", ui.RenderPass("✓"), ui.RenderID(milestone.ID), milestone.Title)
+		if milestone.DueAt != nil {
+			fmt.Printf("  Due: %s\n", milestone.DueAt.Format("2006-01-02"))
+		}
+	},
+}
+
+var milestoneAddCmd = &cobra.Command{
+	Use:   "add <milestone-id> <issue-id>...",
+	Short: "Assign issues to a milestone",
+	Long: `Assign issues to a milestone.
+
+An issue already assigned to a different milestone is moved.`,
+	Args: cobra.MinimumNArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("milestone add")
+		ctx := rootCtx
+		milestone := resolveMilestone(ctx, args[0])
+		actor := getActor()
+
+		var added []string
+		for _, arg := range args[1:] {
+			issueID, err := utils.ResolvePartialID(ctx, store, arg)
+			if err != nil {
+				FatalErrorRespectJSON("resolving %s: %v", arg, err)
+			}
+			current, err := issueMilestones(ctx, issueID)
+			if err != nil {
+				FatalErrorRespectJSON("looking up milestone of %s: %v", issueID, err)
+			}
+			already := false
+			for _, id := range current {
+				if id == milestone.ID {
+					already = true
+					continue
+				}
+				if err := store.RemoveDependency(ctx, id, issueID, actor); err != nil {
+					FatalErrorRespectJSON("removing %s from milestone %s: %v", issueID, id, err)
+				}
+				if !jsonOutput {
+					fmt.Printf("  Moved %s out of %s\n", issueID, id)
+				}
+			}
+			if !already {
+				dep := &types.Dependency{
+					IssueID:     milestone.ID,
+					DependsOnID: issueID,
+					Type:        types.DepTracks,
+					CreatedBy:   actor,
+				}
+				if err := store.AddDependency(ctx, dep, actor); err != nil {
+					FatalErrorRespectJSON("adding %s to milestone %s: %v", issueID, milestone.ID, err)
+				}
+			}
+			added = append(added, issueID)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{
+				"milestone": milestone.ID,
+				"added":     added,
+			})
+			return
+		}
+		fmt.Printf("%s Added %d issue(s) to %s: %s\n", ui.RenderPass("✓"), len(added), ui.RenderID(milestone.ID), milestone.Title)
+	},
+}
+
+var milestoneRemoveCmd = &cobra.Command{
+	Use:   "remove <milestone-id> <issue-id>...",
+	Short: "Remove issues from a milestone",
+	Args:  cobra.MinimumNArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("milestone remove")
+		ctx := rootCtx
+		milestone := resolveMilestone(ctx, args[0])
+
+		var removed []string
+		for _, arg := range args[1:] {
+			issueID, err := utils.ResolvePartialID(ctx, store, arg)
+			if err != nil {
+				FatalErrorRespectJSON("resolving %s: %v", arg, err)
+			}
+			if err := store.RemoveDependency(ctx, milestone.ID, issueID, getActor()); err != nil {
+				FatalErrorRespectJSON("removing %s from milestone %s: %v", issueID, milestone.ID, err)
+			}
+			removed = append(removed, issueID)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{
+				"milestone": milestone.ID,
+				"removed":   removed,
+			})
+			return
+		}
+		fmt.Printf("%s Removed %d issue(s) from %s\n", ui.RenderPass("✓"), len(removed), ui.RenderID(milestone.ID))
+	},
+}
+
+var milestoneStatusCmd = &cobra.Command{
+	Use:   "status [milestone-id]",
+	Short: "Show milestone completion status",
+	Long: `Show completion status for one milestone, or for every open milestone.
+
+For each milestone this reports how many assigned issues are closed, in
+progress, ready and blocked, the remaining time estimate, and (in --json) a
+burndown series: the number of issues still open after each day on which
+assigned issues were closed.`,
+	Args: cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		var milestones []*types.Issue
+		if len(args) == 1 {
+			milestones = []*types.Issue{resolveMilestone(ctx, args[0])}
+		} else {
+			all, _ := cmd.Flags().GetBool("all")
+			milestoneType := types.TypeMilestone
+			filter := types.IssueFilter{IssueType: &milestoneType}
+			if !all {
+				filter.ExcludeStatus = []types.Status{types.StatusClosed}
+			}
+			found, err := store.SearchIssues(ctx, "", filter)
+			if err != nil {
+				FatalErrorRespectJSON("listing milestones: %v", err)
+			}
+			milestones = found
+			sort.SliceStable(milestones, func(i, j int) bool {
+				return milestoneDueBefore(milestones[i], milestones[j])
+			})
+		}
+
+		now := time.Now()
+		statuses := make([]*MilestoneStatus, 0, len(milestones))
+		for _, milestone := range milestones {
+			issues, err := milestoneIssues(ctx, milestone.ID)
+			if err != nil {
+				FatalErrorRespectJSON("loading issues of %s: %v", milestone.ID, err)
+			}
+			blocked, err := blockedIssueIDs(ctx, issues)
+			if err != nil {
+				FatalErrorRespectJSON("checking blockers for %s: %v", milestone.ID, err)
+			}
+			statuses = append(statuses, computeMilestoneStatus(milestone, issues, blocked, now))
+		}
+
+		if jsonOutput {
+			outputJSON(statuses)
+			return
+		}
+		if len(statuses) == 0 {
+			fmt.Println("No open milestones found")
+			return
+		}
+		for _, status := range statuses {
+			renderMilestoneStatus(status, now)
+		}
+	},
+}
+
+// MilestoneStatus summarizes progress on a milestone's assigned issues.
+type MilestoneStatus struct {
+	Milestone        *types.Issue             `json:"milestone"`
+	Total            int                      `json:"total"`
+	Closed           int                      `json:"closed"`
+	InProgress       int                      `json:"in_progress"`
+	Ready            int                      `json:"ready"`
+	Blocked          int                      `json:"blocked"`
+	PercentComplete  int                      `json:"percent_complete"`
+	RemainingMinutes int                      `json:"remaining_estimate_minutes"`
+	Overdue          bool                     `json:"overdue"`
+	Burndown         []MilestoneBurndownPoint `json:"burndown"`
+}
+
+// MilestoneBurndownPoint is the state of a milestone at the end of a day on
+// which at least one of its issues was closed.
+type MilestoneBurndownPoint struct {
+	Date      string `json:"date"`
+	Closed    int    `json:"closed"`
+	Remaining int    `json:"remaining"`
+}
+
+// resolveMilestone resolves a (partial) ID and checks that it is a milestone.
+func resolveMilestone(ctx context.Context, arg string) *types.Issue {
+	id, err := utils.ResolvePartialID(ctx, store, arg)
+	if err != nil {
+		FatalErrorRespectJSON("resolving %s: %v", arg, err)
+	}
+	issue, err := store.GetIssue(ctx, id)
+	if err != nil || issue == nil {
+		FatalErrorRespectJSON("milestone %s not found", id)
+	}
+	if issue.IssueType != types.TypeMilestone {
+		FatalErrorRespectJSON("'%s' is not a milestone (type: %s)", id, issue.IssueType)
+	}
+	return issue
+}
+
+// milestoneIssues returns the issues a milestone tracks.
+func milestoneIssues(ctx context.Context, milestoneID string) ([]*types.Issue, error) {
+	deps, err := store.GetDependenciesWithMetadata(ctx, milestoneID)
+	if err != nil {
+		return nil, err
+	}
+	var issues []*types.Issue
+	for _, dep := range deps {
+		if dep.DependencyType == types.DepTracks {
+			issue := dep.Issue
+			issues = append(issues, &issue)
+		}
+	}
+	return issues, nil
+}
+
+// issueMilestones returns the IDs of the milestones tracking an issue.
+// Convoys also use tracks edges, so only milestone-typed trackers count.
+func issueMilestones(ctx context.Context, issueID string) ([]string, error) {
+	dependents, err := store.GetDependentsWithMetadata(ctx, issueID)
+	if err != nil {
+		return nil, err
+	}
+	var ids []string
+	for _, dep := range dependents {
+		if dep.DependencyType == types.DepTracks && dep.IssueType == types.TypeMilestone {
+			ids = append(ids, dep.ID)
+		}
+	}
+	return ids, nil
+}
+
+// blockedIssueIDs returns the open issues that have an unclosed blocker.
+func blockedIssueIDs(ctx context.Context, issues []*types.Issue) (map[string]bool, error) {
+	blocked := make(map[string]bool)
+	for _, issue := range issues {
+		if issue.Status == types.StatusClosed {
+			continue
+		}
+		deps, err := store.GetDependenciesWithMetadata(ctx, issue.ID)
+		if err != nil {
+			return nil, err
+		}
+		for _, dep := range deps {
+			if dep.DependencyType.IsBlockingEdge() && dep.Status != types.StatusClosed {
+				blocked[issue.ID] = true
+				break
+			}
+		}
+	}
+	return blocked, nil
+}
+
+// computeMilestoneStatus tallies a milestone's issues and builds its burndown.
+func computeMilestoneStatus(milestone *types.Issue, issues []*types.Issue, blocked map[string]bool, now time.Time) *MilestoneStatus {
+	status := &MilestoneStatus{
+		Milestone: milestone,
+		Total:     len(issues),
+		Burndown:  []MilestoneBurndownPoint{},
+	}
+	closedPerDay := make(map[string]int)
+	for _, issue := range issues {
+		switch {
+		case issue.Status == types.StatusClosed:
+			status.Closed++
+			if issue.ClosedAt != nil {
+				closedPerDay[issue.ClosedAt.Local().Format("2006-01-02")]++
+			}
+			continue
+		case issue.Status == types.StatusInProgress:
+			status.InProgress++
+		case blocked[issue.ID]:
+			status.Blocked++
+		default:
+			status.Ready++
+		}
+		if issue.EstimatedMinutes != nil {
+			status.RemainingMinutes += *issue.EstimatedMinutes
+		}
+	}
+	if status.Total > 0 {
+		status.PercentComplete = (status.Closed * 100) / status.Total
+	}
+	status.Overdue = milestone.Status != types.StatusClosed && milestone.DueAt != nil &&
+		milestone.DueAt.Before(now) && status.Closed < status.Total
+
+	days := make([]string, 0, len(closedPerDay))
+	for day := range closedPerDay {
+		days = append(days, day)
+	}
+	sort.Strings(days)
+	remaining := status.Total
+	for _, day := range days {
+		remaining -= closedPerDay[day]
+		status.Burndown = append(status.Burndown, MilestoneBurndownPoint{
+			Date:      day,
+			Closed:    closedPerDay[day],
+			Remaining: remaining,
+		})
+	}
+	return status
+}
+
+// milestoneDueBefore orders milestones by due date, undated ones last.
+func milestoneDueBefore(a, b *types.Issue) bool {
+	if (a.DueAt == nil) != (b.DueAt == nil) {
+		return a.DueAt != nil
+	}
+	if a.DueAt != nil && !a.DueAt.Equal(*b.DueAt) {
+		return a.DueAt.Before(*b.DueAt)
+	}
+	return a.CreatedAt.Before(b.CreatedAt)
+}
+
+func renderMilestoneStatus(status *MilestoneStatus, now time.Time) {
+	milestone := status.Milestone
+	statusIcon := "○"
+	switch {
+	case status.Total > 0 && status.Closed == status.Total:
+		statusIcon = ui.RenderPass("✓")
+	case status.Overdue:
+		statusIcon = ui.RenderFail("●")
+	case status.Closed > 0:
+		statusIcon = ui.RenderWarn("○")
+	}
+	fmt.Printf("%s %s %s\n", statusIcon, ui.RenderAccent(milestone.ID), ui.RenderBold(milestone.Title))
+	if milestone.DueAt != nil {
+		due := milestone.DueAt.Format("2006-01-02")
+		days := int(milestone.DueAt.Sub(now).Hours() / 24)
+		switch {
+		case status.Overdue:
+			fmt.Printf("   Due: %s %s\n", due, ui.RenderFail(fmt.Sprintf("(overdue by %d day(s))", -days)))
+		case milestone.DueAt.After(now):
+			fmt.Printf("   Due: %s %s\n", due, ui.RenderMuted(fmt.Sprintf("(in %d day(s))", days)))
+		default:
+			fmt.Printf("   Due: %s\n", due)
+		}
+	}
+	fmt.Printf("   Progress: %d/%d issues closed (%d%%)\n", status.Closed, status.Total, status.PercentComplete)
+	if remaining := status.Total - status.Closed; remaining > 0 {
+		fmt.Printf("   Remaining: %d in progress, %d ready, %d blocked\n", status.InProgress, status.Ready, status.Blocked)
+		if status.RemainingMinutes > 0 {
+			fmt.Printf("   Estimate: %s\n", formatDuration(float64(status.RemainingMinutes)/60))
+		}
+	}
+	fmt.Println()
+}
+
+func init() {
+	milestoneCreateCmd.Flags().String("due", "", "Due date/time. Formats: +6h, +1d, +2w, tomorrow, next monday, 2025-01-15")
+	milestoneCreateCmd.Flags().StringP("description", "d", "", "Milestone description")
+	milestoneStatusCmd.Flags().Bool("all", false, "Include closed milestones")
+	milestoneCmd.AddCommand(milestoneCreateCmd)
+	milestoneCmd.AddCommand(milestoneAddCmd)
+	milestoneCmd.AddCommand(milestoneRemoveCmd)
+	milestoneCmd.AddCommand(milestoneStatusCmd)
+	rootCmd.AddCommand(milestoneCmd)
+}
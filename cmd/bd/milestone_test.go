@@ -0,0 +1,54 @@
+package main
+
+import (
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestComputeMilestoneStatus(t *testing.T) {
+	now := time.Date(2025, 7, 10, 12, 0, 0, 0, time.Local)
+	day1 := time.Date(2025, 7, 1, 9, 0, 0, 0, time.Local)
+	day2 := time.Date(2025, 7, 3, 9, 0, 0, 0, time.Local)
+	due := now.Add(-24 * time.Hour)
+	est := 90
+
+	milestone := &types.Issue{ID: "bd-m", IssueType: types.TypeMilestone, Status: types.StatusOpen, DueAt: &due}
+	issues := []*types.Issue{
+		{ID: "bd-1", Status: types.StatusClosed, ClosedAt: &day1},
+		{ID: "bd-2", Status: types.StatusClosed, ClosedAt: &day1},
+		{ID: "bd-3", Status: types.StatusClosed, ClosedAt: &day2},
+		{ID: "bd-4", Status: types.StatusInProgress, EstimatedMinutes: &est},
+		{ID: "bd-5", Status: types.StatusOpen, EstimatedMinutes: &est},
+		{ID: "bd-6", Status: types.StatusOpen},
+	}
+
+	status := computeMilestoneStatus(milestone, issues, map[string]bool{"bd-6": true}, now)
+
+	if status.Total != 6 || status.Closed != 3 || status.InProgress != 1 || status.Ready != 1 || status.Blocked != 1 {
+		t.Errorf("counts = total %d closed %d in_progress %d ready %d blocked %d; want 6/3/1/1/1",
+			status.Total, status.Closed, status.InProgress, status.Ready, status.Blocked)
+	}
+	if status.PercentComplete != 50 {
+		t.Errorf("PercentComplete = %d, want 50", status.PercentComplete)
+	}
+	if status.RemainingMinutes != 180 {
+		t.Errorf("RemainingMinutes = %d, want 180", status.RemainingMinutes)
+	}
+	if !status.Overdue {
+		t.Error("Overdue = false, want true for an unfinished milestone past its due date")
+	}
+	want := []MilestoneBurndownPoint{
+		{Date: "2025-07-01", Closed: 2, Remaining: 4},
+		{Date: "2025-07-03", Closed: 1, Remaining: 3},
+	}
+	if len(status.Burndown) != len(want) {
+		t.Fatalf("Burndown = %+v, want %+v", status.Burndown, want)
+	}
+	for i := range want {
+		if status.Burndown[i] != want[i] {
+			t.Errorf("Burndown[%d] = %+v, want %+v", i, status.Burndown[i], want[i])
+		}
+	}
+}
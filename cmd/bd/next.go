@@ -0,0 +1,143 @@
+package main
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+var nextCmd = &cobra.Command{
+	Use:     "next",
+	GroupID: "issues",
+	Short:   "Claim and show the best ready issue",
+	Long: `Pick the top ready issue, claim it for the current actor, and show it.
+
+Selection, claim and assignment happen in one transaction, so two agents
+running 'bd next' at the same time never get the same issue. With --queue,
+the queue's issues are selected first and then claimed one at a time with an
+atomic compare-and-set, skipping any another agent took in between, so they
+never share an issue either. This replaces the 'bd ready' / 'bd update
+--claim' / 'bd show' sequence, which can race.
+
+Issues are ranked by the same sort policies as 'bd ready'. Only open,
+unassigned issues with no open blockers, including blockers in other
+registered repositories, are considered.
+
+With --json, prints the claimed issue as an object. When there is no ready
+work the object has no "id".
+
+Examples:
+  bd next                     # Claim the highest-priority ready issue
+  bd next --json              # Same, as JSON for agent loops
+  bd next --sort oldest       # Claim the oldest ready issue
+  bd next --type bug -l area/storage
+  bd next --parent bd-a1b2    # Next ready step under an epic
+  bd next --queue frontend    # Next issue routed to a queue (see 'bd queue')`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("next")
+
+		sortPolicy, _ := cmd.Flags().GetString("sort")
+		issueType, _ := cmd.Flags().GetString("type")
+		labels, _ := cmd.Flags().GetStringSlice("label")
+		labelsAny, _ := cmd.Flags().GetStringSlice("label-any")
+		excludeLabels, _ := cmd.Flags().GetStringSlice("exclude-label")
+		parentID, _ := cmd.Flags().GetString("parent")
+		excludeTypeStrs, _ := cmd.Flags().GetStringSlice("exclude-type")
+		includeDeferred, _ := cmd.Flags().GetBool("include-deferred")
+
+		labels = utils.NormalizeLabels(labels)
+		labelsAny = utils.NormalizeLabels(labelsAny)
+		excludeLabels = utils.NormalizeLabels(excludeLabels)
+
+		// Apply directory-aware label scoping like 'bd ready' (GH#541)
+		if len(labels) == 0 && len(labelsAny) == 0 {
+			if dirLabels := config.GetDirectoryLabels(); len(dirLabels) > 0 {
+				labelsAny = dirLabels
+			}
+		}
+
+		var excludeTypes []types.IssueType
+		for _, raw := range excludeTypeStrs {
+			for _, t := range strings.Split(raw, ",") {
+				if t = strings.TrimSpace(t); t != "" {
+					excludeTypes = append(excludeTypes, types.IssueType(utils.NormalizeIssueType(t)))
+				}
+			}
+		}
+
+		filter := types.WorkFilter{
+			Status:          types.StatusOpen,
+			Type:            utils.NormalizeIssueType(issueType),
+			SortPolicy:      types.SortPolicy(sortPolicy),
+			Labels:          labels,
+			LabelsAny:       labelsAny,
+			ExcludeLabels:   excludeLabels,
+			IncludeDeferred: includeDeferred,
+			ExcludeTypes:    excludeTypes,
+			PriorityAging:   priorityAgingInterval(),
+		}
+		if !filter.SortPolicy.IsValid() {
+			FatalErrorRespectJSON("invalid sort policy '%s'. Valid values: hybrid, priority, oldest, due", sortPolicy)
+		}
+		if cmd.Flags().Changed("priority") {
+			priority, _ := cmd.Flags().GetInt("priority")
+			filter.Priority = &priority
+		}
+		ctx := rootCtx
+		if parentID != "" {
+			resolved, err := utils.ResolvePartialID(ctx, store, parentID)
+			if err != nil {
+				FatalErrorRespectJSON("resolving parent %s: %v", parentID, err)
+			}
+			filter.ParentID = &resolved
+		}
+
+		var claimed *types.Issue
+		var err error
+		if queueName, _ := cmd.Flags().GetString("queue"); queueName != "" {
+			claimed, err = claimFromQueue(ctx, store, filter, loadQueuesFor(ctx, queueName), queueName, actor)
+		} else {
+			claimed, err = claimReadyIssue(ctx, store, filter, actor)
+		}
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if claimed == nil {
+			if jsonOutput {
+				outputJSON(nil)
+			} else {
+				fmt.Printf("\n%s No ready work to claim\n\n", ui.RenderWarn("○"))
+			}
+			return
+		}
+		commandDidWrite.Store(true)
+		SetLastTouchedID(claimed.ID)
+
+		if jsonOutput {
+			outputJSON(buildReadyIssueOutput(ctx, store, []*types.Issue{claimed})[0])
+			return
+		}
+		fmt.Printf("%s Claimed issue: %s\n\n", ui.RenderPass("✓"), formatFeedbackID(claimed.ID, claimed.Title))
+		displayShowIssue(ctx, claimed.ID)
+	},
+}
+
+func init() {
+	nextCmd.Flags().StringP("sort", "s", "priority", "Sort policy: priority (default), hybrid, oldest, due")
+	nextCmd.Flags().StringP("type", "t", "", "Only consider this issue type")
+	nextCmd.Flags().IntP("priority", "p", 0, "Only consider this priority")
+	nextCmd.Flags().StringSliceP("label", "l", []string{}, "Only consider issues with ALL of these labels ('area/*' matches a namespace)")
+	nextCmd.Flags().StringSlice("label-any", []string{}, "Only consider issues with AT LEAST ONE of these labels")
+	nextCmd.Flags().StringSlice("exclude-label", []string{}, "Skip issues that have ANY of these labels")
+	nextCmd.Flags().StringSlice("exclude-type", nil, "Skip these issue types (comma-separated or repeatable)")
+	nextCmd.Flags().String("parent", "", "Only consider descendants of this bead/epic")
+	nextCmd.Flags().Bool("include-deferred", false, "Include issues with future defer_until timestamps")
+	nextCmd.Flags().String("queue", "", "Only consider issues routed to this queue (see 'bd queue')")
+	rootCmd.AddCommand(nextCmd)
+}
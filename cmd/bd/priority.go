@@ -4,6 +4,7 @@ import (
 	"fmt"
 
 	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/ui"
 	"github.com/steveyegge/beads/internal/validation"
 )
@@ -61,10 +62,8 @@ Examples:
 			FatalErrorRespectJSON("%s", err)
 		}
 
-		updates := map[string]interface{}{
-			"priority": priority,
-		}
-		if err := issueStore.UpdateIssue(ctx, result.ResolvedID, updates, actor); err != nil {
+		update := storage.IssueUpdate{Priority: &priority}
+		if err := storage.ApplyIssueUpdate(ctx, issueStore, result.ResolvedID, update, actor); err != nil {
 			FatalErrorRespectJSON("updating %s: %v", id, err)
 		}
 
@@ -0,0 +1,56 @@
+package main
+
+import (
+	"fmt"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// priorityAgingInterval returns the priority.aging interval from config, or
+// 0 when priority aging is off (the default). Every full interval an open
+// issue goes without an update bumps its effective priority one level.
+func priorityAgingInterval() time.Duration {
+	raw := strings.TrimSpace(config.GetString("priority.aging"))
+	if raw == "" || raw == "0" || raw == "off" {
+		return 0
+	}
+	days, err := parseHumanDuration(raw)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: ignoring invalid priority.aging %q: %v\n", raw, err)
+		return 0
+	}
+	return time.Duration(days) * 24 * time.Hour
+}
+
+// setEffectivePriorities fills in EffectivePriority for bd list --json.
+func setEffectivePriorities(items []*types.IssueWithCounts, aging time.Duration, now time.Time) {
+	for _, item := range items {
+		if item == nil || item.Issue == nil {
+			continue
+		}
+		effective := item.Issue.EffectivePriority(aging, now)
+		item.EffectivePriority = &effective
+	}
+}
+
+// formatEffectivePriorityList renders bd list --effective-priority: each
+// issue's stored priority next to the priority it ranks at as ready work.
+func formatEffectivePriorityList(buf *strings.Builder, issues []*types.Issue, aging time.Duration, now time.Time) {
+	if aging <= 0 {
+		buf.WriteString(ui.RenderMuted("Priority aging is off; set priority.aging (e.g. 14d) to enable it.") + "\n")
+	}
+	for _, issue := range issues {
+		effective := issue.EffectivePriority(aging, now)
+		priority := fmt.Sprintf("P%d", issue.Priority)
+		if effective != issue.Priority {
+			priority = fmt.Sprintf("P%d → %s", issue.Priority, ui.RenderPriorityCompact(effective))
+		}
+		fmt.Fprintf(buf, "%s [%s] %s (updated %s)\n",
+			ui.RenderID(issue.ID), priority, issue.Title, formatTimeAgo(issue.UpdatedAt))
+	}
+}
@@ -232,6 +232,10 @@ func runPurgeOrPrune(cmd *cobra.Command, scope purgeScope) {
 	if err != nil {
 		FatalError("%s failed: %v", scope.cmdName, err)
 	}
+	if !scope.ephemeralOnly {
+		// Wisps never reach the JSONL; closed beads do and need tombstones.
+		recordDeletedTombstones(ctx, store, issueIDs)
+	}
 
 	commandDidWrite.Store(true)
 
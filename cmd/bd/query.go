@@ -29,16 +29,20 @@ Syntax:
   field>=value      Greater than or equal
   field<value       Less than
   field<=value      Less than or equal
+  field:value       Same as field=value
+  field:>value      Same as field>value (also :>=, :<, :<=, :!=)
+  word, "a phrase"  Free text, matched against title and ID
 
 Boolean operators (case-insensitive):
   expr AND expr     Both conditions must match
+  expr expr         Same as AND (terms next to each other)
   expr OR expr      Either condition can match
-  NOT expr          Negates the condition
+  NOT expr, -expr   Negates the condition
   (expr)            Grouping with parentheses
 
 Supported fields:
   status            Stored status (open, in_progress, blocked, deferred, closed). Note: dependency-blocked issues stay "open"; use 'bd blocked' to find them
-  priority          Priority level (0-4)
+  priority, p       Priority level (0-4)
   type              Issue type (bug, feature, task, epic, chore, decision)
   assignee          Assigned user (use "none" for unassigned)
   owner             Issue owner
@@ -52,6 +56,7 @@ Supported fields:
   closed            Close date/time
   id                Issue ID (supports wildcards: bd-*)
   spec              Spec ID (supports wildcards)
+  severity          Bug severity (S0-S3)
   pinned            Boolean (true/false)
   ephemeral         Boolean (true/false)
   template          Boolean (true/false)
@@ -72,7 +77,9 @@ Examples:
   bd query "assignee=none AND type=task"
   bd query "created>30d AND status!=closed"
   bd query "label=frontend OR label=backend"
-  bd query "title=authentication AND priority=0"`,
+  bd query "title=authentication AND priority=0"
+  bd query "status:open p<=1 label:infra -label:wontfix"
+  bd query "type:bug created:>7d login"`,
 	Run: func(cmd *cobra.Command, args []string) {
 		// Get query from args
 		if len(args) == 0 {
@@ -138,7 +145,7 @@ Examples:
 		}
 
 		if jsonOutput {
-			iwc, err := store.SearchIssuesWithCounts(ctx, "", searchFilter)
+			iwc, err := store.SearchIssuesWithCounts(ctx, result.Text, searchFilter)
 			if err != nil {
 				FatalError("%v", err)
 			}
@@ -165,7 +172,7 @@ Examples:
 			return
 		}
 
-		issues, err := store.SearchIssues(ctx, "", searchFilter)
+		issues, err := store.SearchIssues(ctx, result.Text, searchFilter)
 		if err != nil {
 			FatalError("%v", err)
 		}
@@ -0,0 +1,391 @@
+package main
+
+import (
+	"cmp"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+// queueConfigPrefix prefixes the config keys work queues are stored under
+// (queue.<name>), so every agent sharing the database routes work the same way.
+const queueConfigPrefix = "queue."
+
+// queueLabelPrefix is the label namespace that pins an issue to a queue
+// (queue/<name>), overriding the queues' matching rules.
+const queueLabelPrefix = "queue/"
+
+// unqueued is the stats row for issues that no queue matches.
+const unqueued = "(unqueued)"
+
+// workQueue is a named slice of the backlog. An issue matches a queue when
+// it has any of Labels (if set) and is any of Types (if set); a queue with
+// neither only receives issues pinned to it with a queue/<name> label.
+type workQueue struct {
+	Name   string   `json:"name"`
+	Labels []string `json:"labels,omitempty"`
+	Types  []string `json:"types,omitempty"`
+	Order  int      `json:"order,omitempty"`
+}
+
+// matches reports whether an issue of issueType with labels satisfies the
+// queue's rules.
+func (q *workQueue) matches(issueType types.IssueType, labels []string) bool {
+	if len(q.Labels) == 0 && len(q.Types) == 0 {
+		return false
+	}
+	if len(q.Types) > 0 && !slices.Contains(q.Types, string(issueType)) {
+		return false
+	}
+	if len(q.Labels) > 0 && !slices.ContainsFunc(q.Labels, func(pattern string) bool {
+		return slices.ContainsFunc(labels, func(label string) bool { return labelMatches(pattern, label) })
+	}) {
+		return false
+	}
+	return true
+}
+
+// labelMatches reports whether label satisfies a label filter, where a
+// trailing "*" matches any label with that prefix.
+func labelMatches(pattern, label string) bool {
+	if prefix, ok := strings.CutSuffix(pattern, "*"); ok {
+		return strings.HasPrefix(label, prefix)
+	}
+	return label == pattern
+}
+
+// routeIssue returns the queue an issue belongs to, or "" if none. A
+// queue/<name> label naming a defined queue wins; otherwise the first queue
+// whose rules match, in (order, name) order. queues must already be sorted,
+// so every issue lands in exactly one queue.
+func routeIssue(queues []*workQueue, issueType types.IssueType, labels []string) string {
+	for _, label := range labels {
+		if name, ok := strings.CutPrefix(label, queueLabelPrefix); ok {
+			if slices.ContainsFunc(queues, func(q *workQueue) bool { return q.Name == name }) {
+				return name
+			}
+		}
+	}
+	for _, q := range queues {
+		if q.matches(issueType, labels) {
+			return q.Name
+		}
+	}
+	return ""
+}
+
+// inWorkQueue keeps the items routed to the queue called name, preserving
+// their order.
+func inWorkQueue[T any](ctx context.Context, s storage.DoltStorage, queues []*workQueue, name string, items []T, issueOf func(T) *types.Issue) ([]T, error) {
+	ids := make([]string, len(items))
+	for i, item := range items {
+		ids[i] = issueOf(item).ID
+	}
+	labelsByIssue, err := s.GetLabelsForIssues(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("getting labels: %w", err)
+	}
+	var kept []T
+	for _, item := range items {
+		issue := issueOf(item)
+		if routeIssue(queues, issue.IssueType, labelsByIssue[issue.ID]) == name {
+			kept = append(kept, item)
+		}
+	}
+	return kept, nil
+}
+
+func readyIssueWithCountsIssue(issue *types.IssueWithCounts) *types.Issue { return issue.Issue }
+
+func readyIssueSelf(issue *types.Issue) *types.Issue { return issue }
+
+// claimFromQueue claims the first ready, unassigned issue in the named queue,
+// skipping issues still blocked across repositories. Each claim is an atomic
+// compare-and-set, so an issue another agent took in the meantime is skipped.
+// Returns nil when there is nothing to claim.
+func claimFromQueue(ctx context.Context, s storage.DoltStorage, filter types.WorkFilter, queues []*workQueue, name, actor string) (*types.Issue, error) {
+	filter.Status = types.StatusOpen
+	filter.Unassigned = true
+	filter.Assignee = nil
+	filter.Limit = 0
+	candidates, err := s.GetReadyWork(ctx, filter)
+	if err != nil {
+		return nil, err
+	}
+	candidates, err = inWorkQueue(ctx, s, queues, name, candidates, readyIssueSelf)
+	if err != nil {
+		return nil, err
+	}
+	candidates = withoutCrossRepoBlocked(ctx, s, candidates, readyIssueID)
+	for _, issue := range candidates {
+		if err := s.ClaimIssue(ctx, issue.ID, actor); err != nil {
+			if errors.Is(err, storage.ErrAlreadyClaimed) || errors.Is(err, storage.ErrNotClaimable) {
+				continue
+			}
+			return nil, err
+		}
+		return s.GetIssue(ctx, issue.ID)
+	}
+	return nil, nil
+}
+
+// loadWorkQueues returns all defined queues in routing order.
+func loadWorkQueues(ctx context.Context) ([]*workQueue, error) {
+	all, err := getStore().GetAllConfig(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("loading queues: %w", err)
+	}
+	var queues []*workQueue
+	for key, value := range all {
+		name, ok := strings.CutPrefix(key, queueConfigPrefix)
+		if !ok || value == "" {
+			continue
+		}
+		var q workQueue
+		if err := json.Unmarshal([]byte(value), &q); err != nil {
+			return nil, fmt.Errorf("queue %s: invalid config value: %w", name, err)
+		}
+		q.Name = name
+		queues = append(queues, &q)
+	}
+	slices.SortFunc(queues, func(a, b *workQueue) int {
+		return cmp.Or(cmp.Compare(a.Order, b.Order), strings.Compare(a.Name, b.Name))
+	})
+	return queues, nil
+}
+
+// loadQueuesFor loads the queues and checks that name is one of them, for
+// the --queue flag of bd ready and bd next.
+func loadQueuesFor(ctx context.Context, name string) []*workQueue {
+	queues, err := loadWorkQueues(ctx)
+	if err != nil {
+		FatalErrorRespectJSON("%v", err)
+	}
+	if !slices.ContainsFunc(queues, func(q *workQueue) bool { return q.Name == name }) {
+		FatalErrorRespectJSON("no queue named %q (see 'bd queue list')", name)
+	}
+	return queues
+}
+
+// formatQueueRules describes a queue's rules for 'bd queue list'.
+func formatQueueRules(q *workQueue) string {
+	var parts []string
+	if len(q.Labels) > 0 {
+		parts = append(parts, "label "+strings.Join(q.Labels, "|"))
+	}
+	if len(q.Types) > 0 {
+		parts = append(parts, "type "+strings.Join(q.Types, "|"))
+	}
+	if len(parts) == 0 {
+		parts = append(parts, "pinned only")
+	}
+	if q.Order != 0 {
+		parts = append(parts, fmt.Sprintf("order %d", q.Order))
+	}
+	return strings.Join(parts, ", ")
+}
+
+var queueCmd = &cobra.Command{
+	Use:     "queue",
+	GroupID: "issues",
+	Short:   "Partition ready work into queues for specialized agents",
+	Long: `Define work queues so several specialized agents can split the backlog
+without stepping on each other.
+
+A queue matches issues by label and/or type. Every issue belongs to at most
+one queue: an explicit queue/<name> label wins, otherwise the first queue whose
+rules match, ordered by --order and then name. Queues are stored in the
+database config (queue.<name>), so every agent sees the same routing.
+
+  bd queue define frontend --label area/ui,area/web
+  bd queue define triage --type bug --order -1   # bugs go to triage first
+  bd label add bd-a1b2 queue/frontend            # pin one issue explicitly
+  bd ready --queue frontend                      # ready work in the queue
+  bd next --queue frontend --json                # claim the next one
+  bd queue stats`,
+}
+
+var queueDefineCmd = &cobra.Command{
+	Use:   "define <name>",
+	Short: "Define or replace a work queue",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("queue define")
+		q := &workQueue{Name: args[0]}
+		labels, _ := cmd.Flags().GetStringSlice("label")
+		typeStrs, _ := cmd.Flags().GetStringSlice("type")
+		q.Order, _ = cmd.Flags().GetInt("order")
+		q.Labels = utils.NormalizeLabels(labels)
+		for _, t := range typeStrs {
+			if t = strings.TrimSpace(t); t != "" {
+				q.Types = append(q.Types, utils.NormalizeIssueType(t))
+			}
+		}
+		if !viewNamePattern.MatchString(q.Name) {
+			FatalErrorRespectJSON("invalid queue name %q: use letters, digits, '-' and '_'", q.Name)
+		}
+
+		data, err := json.Marshal(q)
+		if err != nil {
+			FatalErrorRespectJSON("encoding queue: %v", err)
+		}
+		if err := getStore().SetConfig(rootCtx, queueConfigPrefix+q.Name, string(data)); err != nil {
+			FatalErrorRespectJSON("saving queue %s: %v", q.Name, err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(q)
+			return
+		}
+		fmt.Printf("%s Defined queue %s (%s)\n", ui.RenderPass("✓"), ui.RenderBold(q.Name), formatQueueRules(q))
+	},
+}
+
+var queueListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List work queues in routing order",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		queues, err := loadWorkQueues(rootCtx)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			if queues == nil {
+				queues = []*workQueue{}
+			}
+			outputJSON(queues)
+			return
+		}
+		if len(queues) == 0 {
+			fmt.Println("No queues defined. Define one with: bd queue define <name> --label <label>")
+			return
+		}
+		for _, q := range queues {
+			fmt.Printf("%s  %s\n", ui.RenderBold(q.Name), ui.RenderMuted(formatQueueRules(q)))
+		}
+	},
+}
+
+var queueDeleteCmd = &cobra.Command{
+	Use:     "delete <name>",
+	Aliases: []string{"rm"},
+	Short:   "Delete a work queue",
+	Args:    cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("queue delete")
+		name := args[0]
+		loadQueuesFor(rootCtx, name)
+		if err := getStore().DeleteConfig(rootCtx, queueConfigPrefix+name); err != nil {
+			FatalErrorRespectJSON("deleting queue %s: %v", name, err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"name": name, "deleted": true})
+			return
+		}
+		fmt.Printf("%s Deleted queue %s\n", ui.RenderPass("✓"), name)
+	},
+}
+
+// queueStats counts the open work routed to one queue.
+type queueStats struct {
+	Queue      string `json:"queue"`
+	Ready      int    `json:"ready"`
+	InProgress int    `json:"in_progress"`
+	Open       int    `json:"open"` // every non-closed issue, including ready and in-progress ones
+}
+
+var queueStatsCmd = &cobra.Command{
+	Use:   "stats",
+	Short: "Show how much work each queue holds",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		queues, err := loadWorkQueues(ctx)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		issues, err := getStore().SearchIssues(ctx, "", types.IssueFilter{ExcludeStatus: []types.Status{types.StatusClosed}})
+		if err != nil {
+			FatalErrorRespectJSON("fetching issues: %v", err)
+		}
+		ready, err := getStore().GetReadyWork(ctx, types.WorkFilter{Status: types.StatusOpen})
+		if err != nil {
+			FatalErrorRespectJSON("fetching ready work: %v", err)
+		}
+		labelsByIssue, err := getStore().GetLabelsForIssues(ctx, collectIssueIDs(issues))
+		if err != nil {
+			FatalErrorRespectJSON("getting labels: %v", err)
+		}
+		readyIDs := make(map[string]bool, len(ready))
+		for _, issue := range ready {
+			readyIDs[issue.ID] = true
+		}
+		stats := computeQueueStats(queues, issues, labelsByIssue, readyIDs)
+
+		if jsonOutput {
+			outputJSON(stats)
+			return
+		}
+		fmt.Printf("%-20s %8s %12s %8s\n", "QUEUE", "READY", "IN PROGRESS", "OPEN")
+		for _, st := range stats {
+			fmt.Printf("%-20s %8d %12d %8d\n", st.Queue, st.Ready, st.InProgress, st.Open)
+		}
+	},
+}
+
+// computeQueueStats tallies issues per queue in routing order, with an
+// unqueued row last when any issue matches no queue.
+func computeQueueStats(queues []*workQueue, issues []*types.Issue, labelsByIssue map[string][]string, readyIDs map[string]bool) []*queueStats {
+	byQueue := make(map[string]*queueStats, len(queues)+1)
+	stats := make([]*queueStats, 0, len(queues)+1)
+	for _, q := range queues {
+		byQueue[q.Name] = &queueStats{Queue: q.Name}
+		stats = append(stats, byQueue[q.Name])
+	}
+	var rest *queueStats
+	for _, issue := range issues {
+		st := byQueue[routeIssue(queues, issue.IssueType, labelsByIssue[issue.ID])]
+		if st == nil {
+			if rest == nil {
+				rest = &queueStats{Queue: unqueued}
+			}
+			st = rest
+		}
+		st.Open++
+		if issue.Status == types.StatusInProgress {
+			st.InProgress++
+		}
+		if readyIDs[issue.ID] {
+			st.Ready++
+		}
+	}
+	if rest != nil {
+		stats = append(stats, rest)
+	}
+	return stats
+}
+
+func init() {
+	queueDefineCmd.Flags().StringSliceP("label", "l", nil, "Match issues with any of these labels ('area/*' matches a namespace)")
+	queueDefineCmd.Flags().StringSliceP("type", "t", nil, "Match issues of any of these types")
+	queueDefineCmd.Flags().Int("order", 0, "Routing order: lower values claim matching issues first (ties go by name)")
+
+	queueCmd.AddCommand(queueDefineCmd)
+	queueCmd.AddCommand(queueListCmd)
+	queueCmd.AddCommand(queueDeleteCmd)
+	queueCmd.AddCommand(queueStatsCmd)
+	rootCmd.AddCommand(queueCmd)
+}
@@ -0,0 +1,64 @@
+package main
+
+import (
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestRouteIssue(t *testing.T) {
+	// Already in routing order: triage (order -1) before frontend and infra.
+	queues := []*workQueue{
+		{Name: "triage", Types: []string{"bug"}, Order: -1},
+		{Name: "frontend", Labels: []string{"area/ui*", "web"}},
+		{Name: "infra", Labels: []string{"area/ops"}, Types: []string{"task", "chore"}},
+		{Name: "special"},
+	}
+
+	tests := []struct {
+		name      string
+		issueType types.IssueType
+		labels    []string
+		want      string
+	}{
+		{"type rule", types.TypeBug, []string{"area/ui"}, "triage"},
+		{"label wildcard", types.TypeFeature, []string{"area/ui/forms"}, "frontend"},
+		{"label and type both required", types.TypeFeature, []string{"area/ops"}, ""},
+		{"label and type match", types.TypeTask, []string{"area/ops"}, "infra"},
+		{"pin wins over rules", types.TypeBug, []string{"queue/special"}, "special"},
+		{"pin to unknown queue ignored", types.TypeTask, []string{"queue/nope", "web"}, "frontend"},
+		{"pinned-only queue gets nothing else", types.TypeEpic, nil, ""},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := routeIssue(queues, tt.issueType, tt.labels); got != tt.want {
+				t.Errorf("routeIssue(%s, %v) = %q, want %q", tt.issueType, tt.labels, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestComputeQueueStats(t *testing.T) {
+	queues := []*workQueue{{Name: "frontend", Labels: []string{"ui"}}, {Name: "empty", Labels: []string{"nothing"}}}
+	issues := []*types.Issue{
+		{ID: "bd-1", Status: types.StatusOpen},
+		{ID: "bd-2", Status: types.StatusInProgress},
+		{ID: "bd-3", Status: types.StatusOpen},
+	}
+	labels := map[string][]string{"bd-1": {"ui"}, "bd-2": {"ui"}}
+	stats := computeQueueStats(queues, issues, labels, map[string]bool{"bd-1": true, "bd-3": true})
+
+	want := []queueStats{
+		{Queue: "frontend", Ready: 1, InProgress: 1, Open: 2},
+		{Queue: "empty"},
+		{Queue: unqueued, Ready: 1, Open: 1},
+	}
+	if len(stats) != len(want) {
+		t.Fatalf("got %d rows, want %d: %+v", len(stats), len(want), stats)
+	}
+	for i := range want {
+		if *stats[i] != want[i] {
+			t.Errorf("row %d = %+v, want %+v", i, *stats[i], want[i])
+		}
+	}
+}
@@ -45,6 +45,7 @@ Example:
 			IssueType: types.IssueType(issueType).Normalize(),
 			Labels:    mergeCreateLabels(labels, nil),
 		}
+		enforceValidationRules(issue)
 
 		ctx := rootCtx
 		if err := store.CreateIssue(ctx, issue, actor); err != nil {
@@ -34,9 +34,24 @@ Use --gated to find molecules ready for gate-resume dispatch:
 Use --claim to atomically claim the first ready issue matching the filters:
   bd ready --claim --json
 
+Use --queue to show only the ready work routed to a queue (see 'bd queue'):
+  bd ready --queue frontend --claim --json
+
+When priority.aging is configured (e.g. "bd config set priority.aging 14d"),
+open issues rank one priority level higher for every interval they go without
+an update. See 'bd list --effective-priority'.
+
 This is useful for agents executing molecules to see which steps can run next.`,
 	Run: func(cmd *cobra.Command, args []string) {
 		claimReady, _ := cmd.Flags().GetBool("claim")
+		queueName, _ := cmd.Flags().GetString("queue")
+		if queueName != "" {
+			for _, flag := range []string{"gated", "mol", "explain", "all-repos"} {
+				if cmd.Flags().Changed(flag) {
+					FatalErrorRespectJSON("--queue cannot be combined with --%s", flag)
+				}
+			}
+		}
 
 		// Handle --gated flag (gate-resume discovery)
 		gated, _ := cmd.Flags().GetBool("gated")
@@ -131,6 +146,7 @@ This is useful for agents executing molecules to see which steps can run next.`,
 			IncludeDeferred:  includeDeferred,  // GH#820: respect --include-deferred flag
 			IncludeEphemeral: includeEphemeral, // bd-i5k5x: allow ephemeral issues (e.g., merge-requests)
 			ExcludeTypes:     excludeTypes,
+			PriorityAging:    priorityAgingInterval(),
 		}
 		// Use Changed() to properly handle P0 (priority=0)
 		if cmd.Flags().Changed("priority") {
@@ -175,11 +191,19 @@ This is useful for agents executing molecules to see which steps can run next.`,
 
 		// Validate sort policy
 		if !filter.SortPolicy.IsValid() {
-			FatalError("invalid sort policy '%s'. Valid values: hybrid, priority, oldest", sortPolicy)
+			FatalError("invalid sort policy '%s'. Valid values: hybrid, priority, oldest, due", sortPolicy)
 		}
 		// Direct mode
 		ctx := rootCtx
 
+		if allRepos, _ := cmd.Flags().GetBool("all-repos"); allRepos {
+			if claimReady {
+				FatalErrorRespectJSON("--claim cannot be combined with --all-repos")
+			}
+			readyAllRepos(ctx, filter)
+			return
+		}
+
 		activeStore := store
 		if claimReady {
 			CheckReadonly("ready --claim")
@@ -195,8 +219,23 @@ This is useful for agents executing molecules to see which steps can run next.`,
 			}
 		}
 
+		// With --queue, fetch everything and keep the queue's share; the
+		// limit applies afterwards.
+		var queues []*workQueue
+		queueLimit := filter.Limit
+		if queueName != "" {
+			queues = loadQueuesFor(ctx, queueName)
+			filter.Limit = 0
+		}
+
 		if claimReady {
-			claimed, err := activeStore.ClaimReadyIssue(ctx, filter, actor)
+			var claimed *types.Issue
+			var err error
+			if queues != nil {
+				claimed, err = claimFromQueue(ctx, activeStore, filter, queues, queueName, actor)
+			} else {
+				claimed, err = claimReadyIssue(ctx, activeStore, filter, actor)
+			}
 			if err != nil {
 				FatalErrorRespectJSON("%v", err)
 			}
@@ -219,16 +258,26 @@ This is useful for agents executing molecules to see which steps can run next.`,
 		}
 
 		if jsonOutput {
-			results, err := activeStore.GetReadyWorkWithCounts(ctx, filter)
+			results, err := getReadyWorkWithCounts(ctx, activeStore, filter)
 			if err != nil {
 				FatalError("%v", err)
 			}
 			totalReady := len(results)
 			truncated := false
-			if filter.Limit > 0 && len(results) == filter.Limit {
+			if queues != nil {
+				results, err = inWorkQueue(ctx, activeStore, queues, queueName, results, readyIssueWithCountsIssue)
+				if err != nil {
+					FatalError("%v", err)
+				}
+				totalReady = len(results)
+				if queueLimit > 0 && len(results) > queueLimit {
+					results = results[:queueLimit]
+					truncated = true
+				}
+			} else if filter.Limit > 0 && len(results) == filter.Limit {
 				countFilter := filter
 				countFilter.Limit = 0
-				all, countErr := activeStore.GetReadyWorkWithCounts(ctx, countFilter)
+				all, countErr := getReadyWorkWithCounts(ctx, activeStore, countFilter)
 				if countErr == nil && len(all) > len(results) {
 					totalReady = len(all)
 					truncated = true
@@ -244,17 +293,27 @@ This is useful for agents executing molecules to see which steps can run next.`,
 			return
 		}
 
-		issues, err := activeStore.GetReadyWork(ctx, filter)
+		issues, err := getReadyWork(ctx, activeStore, filter)
 		if err != nil {
 			FatalError("%v", err)
 		}
 
 		totalReady := len(issues)
 		truncated := false
-		if !jsonOutput && filter.Limit > 0 && len(issues) == filter.Limit {
+		if queues != nil {
+			issues, err = inWorkQueue(ctx, activeStore, queues, queueName, issues, readyIssueSelf)
+			if err != nil {
+				FatalError("%v", err)
+			}
+			totalReady = len(issues)
+			if queueLimit > 0 && len(issues) > queueLimit {
+				issues = issues[:queueLimit]
+				truncated = true
+			}
+		} else if !jsonOutput && filter.Limit > 0 && len(issues) == filter.Limit {
 			countFilter := filter
 			countFilter.Limit = 0
-			allIssues, countErr := activeStore.GetReadyWork(ctx, countFilter)
+			allIssues, countErr := getReadyWork(ctx, activeStore, countFilter)
 			if countErr == nil && len(allIssues) > len(issues) {
 				totalReady = len(allIssues)
 				truncated = true
@@ -263,6 +322,10 @@ This is useful for agents executing molecules to see which steps can run next.`,
 		// Show upgrade notification if needed
 		maybeShowUpgradeNotification()
 
+		if len(issues) == 0 && queues != nil {
+			fmt.Printf("\n%s No ready work in queue %s\n\n", ui.RenderWarn("✨"), queueName)
+			return
+		}
 		if len(issues) == 0 {
 			// Check if there are any open issues at all
 			hasOpenIssues := false
@@ -328,6 +391,7 @@ var blockedCmd = &cobra.Command{
 		if err != nil {
 			FatalErrorRespectJSON("%v", err)
 		}
+		blocked = addCrossRepoBlocked(ctx, store, blocked, blockedFilter)
 		if jsonOutput {
 			// Always output array, even if empty
 			if blocked == nil {
@@ -729,8 +793,8 @@ func init() {
 	readyCmd.Flags().IntP("priority", "p", 0, "Filter by priority")
 	readyCmd.Flags().StringP("assignee", "a", "", "Filter by assignee")
 	readyCmd.Flags().BoolP("unassigned", "u", false, "Show only unassigned issues")
-	readyCmd.Flags().StringP("sort", "s", "priority", "Sort policy: priority (default), hybrid, oldest")
-	readyCmd.Flags().StringSliceP("label", "l", []string{}, "Filter by labels (AND: must have ALL). Can combine with --label-any")
+	readyCmd.Flags().StringP("sort", "s", "priority", "Sort policy: priority (default), hybrid, oldest, due")
+	readyCmd.Flags().StringSliceP("label", "l", []string{}, "Filter by labels (AND: must have ALL; 'area/*' matches a namespace). Can combine with --label-any")
 	readyCmd.Flags().StringSlice("label-any", []string{}, "Filter by labels (OR: must have AT LEAST ONE). Can combine with --label")
 	readyCmd.Flags().StringSlice("exclude-label", []string{}, "Exclude issues that have ANY of these labels")
 	readyCmd.Flags().StringP("type", "t", "", "Filter by issue type (task, bug, feature, epic, decision, merge-request). Aliases: mr→merge-request, feat→feature, mol→molecule, dec/adr→decision")
@@ -745,6 +809,8 @@ func init() {
 	readyCmd.Flags().StringSlice("exclude-type", nil, "Exclude issue types from results (comma-separated or repeatable, e.g., --exclude-type=convoy,epic)")
 	readyCmd.Flags().Bool("explain", false, "Show dependency-aware reasoning for why issues are ready or blocked")
 	readyCmd.Flags().Bool("claim", false, "Atomically claim the first ready issue matching the filters")
+	readyCmd.Flags().String("queue", "", "Show only ready work routed to this queue (see 'bd queue')")
+	readyCmd.Flags().Bool("all-repos", false, "Aggregate ready work across this and every registered repository (see 'bd workspace')")
 	// Metadata filtering (GH#1406)
 	readyCmd.Flags().StringArray("metadata-field", nil, "Filter by metadata field (key=value, repeatable)")
 	readyCmd.Flags().String("has-metadata-key", "", "Filter issues that have this metadata key set")
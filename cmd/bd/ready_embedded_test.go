@@ -155,6 +155,34 @@ func TestEmbeddedReady(t *testing.T) {
 		}
 	})
 
+	t.Run("next_json", func(t *testing.T) {
+		low := bdCreate(t, bd, dir, "Next low", "--type", "task", "--priority", "3", "--label", "next-json")
+		high := bdCreate(t, bd, dir, "Next high", "--type", "task", "--priority", "1", "--label", "next-json")
+
+		for _, want := range []string{high.ID, low.ID, ""} {
+			cmd := exec.Command(bd, "next", "--json", "--label", "next-json")
+			cmd.Dir = dir
+			cmd.Env = bdEnv(dir)
+			stdout, stderr, err := runCommandBuffers(t, cmd)
+			if err != nil {
+				t.Fatalf("bd next --json failed: %v\nstdout:\n%s\nstderr:\n%s", err, stdout.String(), stderr.String())
+			}
+			var claimed types.IssueWithCounts
+			if err := json.Unmarshal(bytes.TrimSpace(stdout.Bytes()), &claimed); err != nil {
+				t.Fatalf("parse next JSON: %v\n%s", err, stdout.String())
+			}
+			if claimed.Issue == nil {
+				claimed.Issue = &types.Issue{}
+			}
+			if claimed.ID != want {
+				t.Fatalf("bd next claimed %q, want %q", claimed.ID, want)
+			}
+			if want != "" && claimed.Status != types.StatusInProgress {
+				t.Fatalf("claimed status = %s, want %s", claimed.Status, types.StatusInProgress)
+			}
+		}
+	})
+
 	// ===== With Blockers =====
 
 	t.Run("ready_excludes_blocked", func(t *testing.T) {
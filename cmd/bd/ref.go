@@ -0,0 +1,388 @@
+package main
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/git"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+var refCmd = &cobra.Command{
+	Use:     "ref",
+	GroupID: "issues",
+	Short:   "Manage code references (file and line ranges) on issues",
+	Long: `Attach structured references to files and line ranges to issues.
+
+References are stored relative to the repository root, exported to JSONL
+with the issue (code_refs), and shown by 'bd show'. They are breadcrumbs:
+'bd ref check' reports the ones whose files or lines no longer exist.
+
+Locations are written path, path:line or path:start-end. Paths are relative
+to the current directory, like any other file argument.`,
+}
+
+var refAddCmd = &cobra.Command{
+	Use:   "add <issue-id> <path[:line[-end]]>",
+	Short: "Add a code reference to an issue",
+	Example: `  bd ref add bd-12 src/foo.rs:42
+  bd ref add bd-12 src/foo.rs:42-60 --note "retry loop to replace"
+  bd ref add bd-12 docs/DESIGN.md`,
+	Args: cobra.ExactArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("ref add")
+		ctx := rootCtx
+		issueID, err := utils.ResolvePartialID(ctx, store, args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		ref, err := codeRefFromArg(args[1])
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		ref.IssueID = issueID
+		ref.Note, _ = cmd.Flags().GetString("note")
+		ref.CreatedBy = getActor()
+
+		if err := store.AddCodeRef(ctx, ref); err != nil {
+			FatalErrorRespectJSON("adding code reference: %v", err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(ref)
+			return
+		}
+		fmt.Printf("%s Added reference %s to %s\n", ui.RenderPass("✓"), ref.String(), issueID)
+		if problem := codeRefProblem(codeRefRoot(), ref); problem != "" {
+			fmt.Fprintf(os.Stderr, "%s %s: %s\n", ui.RenderWarn("⚠"), ref.String(), problem)
+		}
+	},
+}
+
+var refRemoveCmd = &cobra.Command{
+	Use:   "remove <issue-id> <path[:line[-end]]>",
+	Short: "Remove a code reference from an issue",
+	Long: `Remove a code reference from an issue.
+
+Given a bare path, every reference the issue has to that file is removed.
+Given a line or range, only the reference at exactly that location is.`,
+	Args: cobra.ExactArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("ref remove")
+		ctx := rootCtx
+		issueID, err := utils.ResolvePartialID(ctx, store, args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		ref, err := codeRefFromArg(args[1])
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+
+		removed, err := store.RemoveCodeRefs(ctx, issueID, ref.Path, ref.LineStart, ref.LineEnd)
+		if err != nil {
+			FatalErrorRespectJSON("removing code reference: %v", err)
+		}
+		if removed == 0 {
+			FatalErrorRespectJSON("%s has no reference to %s", issueID, ref.String())
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{
+				"issue_id": issueID,
+				"ref":      ref.String(),
+				"removed":  removed,
+			})
+			return
+		}
+		fmt.Printf("%s Removed %d reference(s) to %s from %s\n", ui.RenderPass("✓"), removed, ref.String(), issueID)
+	},
+}
+
+var refListCmd = &cobra.Command{
+	Use:   "list <issue-id>...",
+	Short: "List the code references on issues",
+	Args:  cobra.MinimumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		ids := make([]string, 0, len(args))
+		for _, arg := range args {
+			id, err := utils.ResolvePartialID(ctx, store, arg)
+			if err != nil {
+				FatalErrorRespectJSON("resolving %s: %v", arg, err)
+			}
+			ids = append(ids, id)
+		}
+		refs, err := store.GetCodeRefsForIssues(ctx, ids)
+		if err != nil {
+			FatalErrorRespectJSON("listing code references: %v", err)
+		}
+
+		if jsonOutput {
+			out := make(map[string][]*types.CodeRef, len(ids))
+			for _, id := range ids {
+				out[id] = refs[id]
+				if out[id] == nil {
+					out[id] = []*types.CodeRef{}
+				}
+			}
+			outputJSON(out)
+			return
+		}
+		for _, id := range ids {
+			if len(ids) > 1 {
+				fmt.Printf("%s\n", ui.RenderID(id))
+			}
+			if len(refs[id]) == 0 {
+				fmt.Printf("  %s\n", ui.RenderMuted("(no code references)"))
+				continue
+			}
+			for _, ref := range refs[id] {
+				fmt.Println(formatCodeRefLine(ref))
+			}
+		}
+	},
+}
+
+// staleCodeRef is a reference 'bd ref check' could not find.
+type staleCodeRef struct {
+	IssueID string `json:"issue_id"`
+	Ref     string `json:"ref"`
+	Problem string `json:"problem"`
+}
+
+var refCheckCmd = &cobra.Command{
+	Use:   "check [issue-id...]",
+	Short: "Report code references whose files or lines no longer exist",
+	Long: `Report code references whose files or lines no longer exist.
+
+Checks the given issues, or every open issue (every issue with --all).
+Exits with status 1 when any reference is stale, so it can run in CI.`,
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		all, _ := cmd.Flags().GetBool("all")
+		ids, err := codeRefCheckIDs(ctx, store, args, all)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		refs, err := store.GetCodeRefsForIssues(ctx, ids)
+		if err != nil {
+			FatalErrorRespectJSON("reading code references: %v", err)
+		}
+
+		root := codeRefRoot()
+		checked := 0
+		stale := []staleCodeRef{}
+		for _, id := range ids {
+			for _, ref := range refs[id] {
+				checked++
+				if problem := codeRefProblem(root, ref); problem != "" {
+					stale = append(stale, staleCodeRef{IssueID: id, Ref: ref.String(), Problem: problem})
+				}
+			}
+		}
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{
+				"checked": checked,
+				"stale":   stale,
+			})
+		} else if len(stale) == 0 {
+			fmt.Printf("%s All %d code reference(s) resolve\n", ui.RenderPass("✓"), checked)
+		} else {
+			fmt.Printf("Stale code references (%d of %d):\n\n", len(stale), checked)
+			for _, s := range stale {
+				fmt.Printf("  %s %s %s: %s\n", ui.RenderWarn("⚠"), ui.RenderID(s.IssueID), s.Ref, s.Problem)
+			}
+		}
+		if len(stale) > 0 {
+			os.Exit(1)
+		}
+	},
+}
+
+// codeRefCheckIDs resolves the issues 'bd ref check' looks at: args, or
+// every non-closed issue (every issue when all is set).
+func codeRefCheckIDs(ctx context.Context, s storage.DoltStorage, args []string, all bool) ([]string, error) {
+	if len(args) > 0 {
+		ids := make([]string, 0, len(args))
+		for _, arg := range args {
+			id, err := utils.ResolvePartialID(ctx, s, arg)
+			if err != nil {
+				return nil, fmt.Errorf("resolving %s: %w", arg, err)
+			}
+			ids = append(ids, id)
+		}
+		return ids, nil
+	}
+	filter := types.IssueFilter{SkipLabels: true}
+	if !all {
+		filter.ExcludeStatus = []types.Status{types.StatusClosed}
+	}
+	issues, err := s.SearchIssues(ctx, "", filter)
+	if err != nil {
+		return nil, fmt.Errorf("listing issues: %w", err)
+	}
+	ids := make([]string, 0, len(issues))
+	for _, issue := range issues {
+		ids = append(ids, issue.ID)
+	}
+	return ids, nil
+}
+
+var codeRefLinesPattern = regexp.MustCompile(`^(\d+)(?:-(\d+))?$`)
+
+// parseCodeRefSpec splits path, path:line or path:start-end. A suffix that
+// is not a line number stays part of the path.
+func parseCodeRefSpec(spec string) (path string, lineStart, lineEnd int, err error) {
+	path = spec
+	if i := strings.LastIndex(spec, ":"); i > 0 {
+		if m := codeRefLinesPattern.FindStringSubmatch(spec[i+1:]); m != nil {
+			path = spec[:i]
+			lineStart, _ = strconv.Atoi(m[1])
+			lineEnd = lineStart
+			if m[2] != "" {
+				lineEnd, _ = strconv.Atoi(m[2])
+			}
+			if lineStart < 1 || lineEnd < lineStart {
+				return "", 0, 0, fmt.Errorf("invalid line range in %q", spec)
+			}
+		}
+	}
+	if strings.TrimSpace(path) == "" {
+		return "", 0, 0, fmt.Errorf("missing path in %q", spec)
+	}
+	return path, lineStart, lineEnd, nil
+}
+
+// codeRefFromArg parses a location argument into a reference whose path is
+// relative to the repository root.
+func codeRefFromArg(spec string) (*types.CodeRef, error) {
+	path, lineStart, lineEnd, err := parseCodeRefSpec(spec)
+	if err != nil {
+		return nil, err
+	}
+	cwd, err := os.Getwd()
+	if err != nil {
+		return nil, err
+	}
+	rel, err := repoRelativePath(codeRefRoot(), cwd, path)
+	if err != nil {
+		return nil, err
+	}
+	return &types.CodeRef{Path: rel, LineStart: lineStart, LineEnd: lineEnd}, nil
+}
+
+// codeRefRoot is the directory references are relative to: the repository
+// root, or the current directory outside a git repository.
+func codeRefRoot() string {
+	if root := git.GetRepoRoot(); root != "" {
+		return root
+	}
+	cwd, _ := os.Getwd()
+	return cwd
+}
+
+// repoRelativePath turns path, relative to cwd or absolute, into a
+// slash-separated path relative to root.
+func repoRelativePath(root, cwd, path string) (string, error) {
+	if resolved, err := filepath.EvalSymlinks(root); err == nil {
+		root = resolved
+	}
+	if !filepath.IsAbs(path) {
+		if resolved, err := filepath.EvalSymlinks(cwd); err == nil {
+			cwd = resolved
+		}
+		path = filepath.Join(cwd, path)
+	}
+	rel, err := filepath.Rel(root, filepath.Clean(path))
+	if err != nil || rel == ".." || strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+		return "", fmt.Errorf("%s is outside the repository (%s)", path, root)
+	}
+	return filepath.ToSlash(rel), nil
+}
+
+// codeRefProblem reports why ref no longer resolves under root, or "" when
+// it does.
+func codeRefProblem(root string, ref *types.CodeRef) string {
+	full := filepath.Join(root, filepath.FromSlash(ref.Path))
+	info, err := os.Stat(full)
+	if errors.Is(err, os.ErrNotExist) {
+		return "file no longer exists"
+	}
+	if err != nil {
+		return err.Error()
+	}
+	if ref.LineStart <= 0 {
+		return ""
+	}
+	if info.IsDir() {
+		return "is a directory"
+	}
+	data, err := os.ReadFile(full) // #nosec G304 -- path comes from a reference inside the repository
+	if err != nil {
+		return err.Error()
+	}
+	lines := bytes.Count(data, []byte("\n"))
+	if len(data) > 0 && data[len(data)-1] != '\n' {
+		lines++
+	}
+	last := ref.LineEnd
+	if last < ref.LineStart {
+		last = ref.LineStart
+	}
+	if last > lines {
+		return fmt.Sprintf("line %d is past the end of the file (%d lines)", last, lines)
+	}
+	return ""
+}
+
+func formatCodeRefLine(ref *types.CodeRef) string {
+	line := "  " + ref.String()
+	if ref.Note != "" {
+		line += "  " + ui.RenderMuted(ref.Note)
+	}
+	return line
+}
+
+// printCodeRefs prints the CODE REFERENCES section of 'bd show'. Best
+// effort: an issue still shows if its references cannot be read.
+func printCodeRefs(ctx context.Context, s storage.DoltStorage, issueID string) {
+	refs, err := s.GetCodeRefsForIssues(ctx, []string{issueID})
+	if err != nil || len(refs[issueID]) == 0 {
+		return
+	}
+	fmt.Printf("\n%s\n", ui.RenderBold("CODE REFERENCES"))
+	for _, ref := range refs[issueID] {
+		fmt.Println(formatCodeRefLine(ref))
+	}
+}
+
+func init() {
+	refAddCmd.Flags().StringP("note", "n", "", "Why the location matters")
+	refCheckCmd.Flags().Bool("all", false, "Also check closed issues")
+
+	refCmd.AddCommand(refAddCmd)
+	refCmd.AddCommand(refRemoveCmd)
+	refCmd.AddCommand(refListCmd)
+	refCmd.AddCommand(refCheckCmd)
+	rootCmd.AddCommand(refCmd)
+
+	refAddCmd.ValidArgsFunction = issueIDCompletion
+	refRemoveCmd.ValidArgsFunction = issueIDCompletion
+	refListCmd.ValidArgsFunction = issueIDCompletion
+	refCheckCmd.ValidArgsFunction = issueIDCompletion
+}
@@ -0,0 +1,69 @@
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseCodeRefSpec(t *testing.T) {
+	tests := []struct {
+		spec       string
+		path       string
+		start, end int
+		wantErr    bool
+	}{
+		{spec: "src/foo.rs", path: "src/foo.rs"},
+		{spec: "src/foo.rs:42", path: "src/foo.rs", start: 42, end: 42},
+		{spec: "src/foo.rs:42-60", path: "src/foo.rs", start: 42, end: 60},
+		{spec: "notes/12:30.txt", path: "notes/12:30.txt"},
+		{spec: "src/foo.rs:60-42", wantErr: true},
+		{spec: "src/foo.rs:0", wantErr: true},
+		{spec: ":42", path: ":42"},
+		{spec: " ", wantErr: true},
+	}
+	for _, tt := range tests {
+		path, start, end, err := parseCodeRefSpec(tt.spec)
+		if (err != nil) != tt.wantErr {
+			t.Errorf("parseCodeRefSpec(%q) err = %v, wantErr %v", tt.spec, err, tt.wantErr)
+			continue
+		}
+		if !tt.wantErr && (path != tt.path || start != tt.start || end != tt.end) {
+			t.Errorf("parseCodeRefSpec(%q) = %q, %d, %d; want %q, %d, %d", tt.spec, path, start, end, tt.path, tt.start, tt.end)
+		}
+	}
+}
+
+func TestCodeRefProblem(t *testing.T) {
+	root := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(root, "src"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(root, "src", "foo.go"), []byte("a\nb\nc"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	rel, err := repoRelativePath(root, filepath.Join(root, "src"), "foo.go")
+	if err != nil || rel != "src/foo.go" {
+		t.Fatalf("repoRelativePath = %q, %v; want src/foo.go", rel, err)
+	}
+	if _, err := repoRelativePath(root, root, "../elsewhere.go"); err == nil {
+		t.Error("repoRelativePath accepted a path outside the repository")
+	}
+
+	for _, tt := range []struct {
+		ref   types.CodeRef
+		stale bool
+	}{
+		{ref: types.CodeRef{Path: "src/foo.go"}},
+		{ref: types.CodeRef{Path: "src/foo.go", LineStart: 2, LineEnd: 3}},
+		{ref: types.CodeRef{Path: "src/foo.go", LineStart: 3, LineEnd: 4}, stale: true},
+		{ref: types.CodeRef{Path: "src/gone.go"}, stale: true},
+	} {
+		if got := codeRefProblem(root, &tt.ref); (got != "") != tt.stale {
+			t.Errorf("codeRefProblem(%s) = %q, want stale=%v", tt.ref.String(), got, tt.stale)
+		}
+	}
+}
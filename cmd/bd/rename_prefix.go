@@ -87,7 +87,11 @@ NOTE: This is a rare operation. Most users never need this command.`,
 			FatalError("failed to list issues: %v", err)
 		}
 
-		prefixes := detectPrefixes(issues)
+		prefixOf, err := storeIssuePrefixFunc(ctx, store)
+		if err != nil {
+			FatalError("%v", err)
+		}
+		prefixes := detectPrefixes(issues, prefixOf)
 
 		if len(prefixes) > 1 {
 			// Multiple prefixes detected - requires repair mode
@@ -106,7 +110,7 @@ NOTE: This is a rare operation. Most users never need this command.`,
 			}
 
 			// Repair mode: consolidate all prefixes to newPrefix
-			if err := repairPrefixes(ctx, store, actor, newPrefix, issues, prefixes, dryRun); err != nil {
+			if err := repairPrefixes(ctx, store, actor, newPrefix, issues, prefixes, prefixOf, dryRun); err != nil {
 				FatalError("failed to repair prefixes: %v", err)
 			}
 			if !dryRun {
@@ -189,11 +193,12 @@ func validatePrefix(prefix string) error {
 	return nil
 }
 
-// detectPrefixes analyzes all issues and returns a map of prefix -> count
-func detectPrefixes(issues []*types.Issue) map[string]int {
+// detectPrefixes analyzes all issues and returns a map of prefix -> count,
+// reading each ID's prefix with prefixOf
+func detectPrefixes(issues []*types.Issue, prefixOf func(string) string) map[string]int {
 	prefixes := make(map[string]int)
 	for _, issue := range issues {
-		prefix := utils.ExtractIssuePrefix(issue.ID)
+		prefix := prefixOf(issue.ID)
 		if prefix != "" {
 			prefixes[prefix]++
 		}
@@ -211,14 +216,14 @@ type issueSort struct {
 // repairPrefixes consolidates multiple prefixes into a single target prefix
 // Issues with the correct prefix are left unchanged.
 // Issues with incorrect prefixes get new hash-based IDs.
-func repairPrefixes(ctx context.Context, st storage.DoltStorage, actorName string, targetPrefix string, issues []*types.Issue, prefixes map[string]int, dryRun bool) error {
+func repairPrefixes(ctx context.Context, st storage.DoltStorage, actorName string, targetPrefix string, issues []*types.Issue, prefixes map[string]int, prefixOf func(string) string, dryRun bool) error {
 
 	// Separate issues into correct and incorrect prefix groups
 	var correctIssues []*types.Issue
 	var incorrectIssues []issueSort
 
 	for _, issue := range issues {
-		prefix := utils.ExtractIssuePrefix(issue.ID)
+		prefix := prefixOf(issue.ID)
 		number := utils.ExtractIssueNumber(issue.ID)
 
 		if prefix == targetPrefix {
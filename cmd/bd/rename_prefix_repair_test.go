@@ -9,6 +9,7 @@ import (
 
 	"github.com/steveyegge/beads/internal/storage/dolt"
 	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/utils"
 )
 
 func TestRepairMultiplePrefixes(t *testing.T) {
@@ -65,14 +66,14 @@ func TestRepairMultiplePrefixes(t *testing.T) {
 		t.Fatalf("failed to search issues: %v", err)
 	}
 
-	prefixes := detectPrefixes(allIssues)
+	prefixes := detectPrefixes(allIssues, utils.ExtractIssuePrefix)
 	if len(prefixes) != 3 {
 		t.Fatalf("expected 3 prefixes, got %d: %v", len(prefixes), prefixes)
 	}
 
 	// Test repair — now uses UpdateIssueID (Dolt rename semantics)
 	// instead of the old CreateIssue+DeleteIssue approach that caused deadlocks
-	if err := repairPrefixes(ctx, testStore, "test", "test", allIssues, prefixes, false); err != nil {
+	if err := repairPrefixes(ctx, testStore, "test", "test", allIssues, prefixes, utils.ExtractIssuePrefix, false); err != nil {
 		t.Fatalf("repair failed: %v", err)
 	}
 
@@ -82,7 +83,7 @@ func TestRepairMultiplePrefixes(t *testing.T) {
 		t.Fatalf("failed to search issues after repair: %v", err)
 	}
 
-	prefixes = detectPrefixes(allIssues)
+	prefixes = detectPrefixes(allIssues, utils.ExtractIssuePrefix)
 	if len(prefixes) != 1 {
 		t.Fatalf("expected 1 prefix after repair, got %d: %v", len(prefixes), prefixes)
 	}
@@ -8,6 +8,7 @@ import (
 	"path/filepath"
 	"testing"
 
+	"github.com/steveyegge/beads/internal/idgen"
 	"github.com/steveyegge/beads/internal/storage/dolt"
 	"github.com/steveyegge/beads/internal/types"
 )
@@ -40,6 +41,19 @@ func TestValidatePrefix(t *testing.T) {
 	}
 }
 
+func TestDetectPrefixesActorScheme(t *testing.T) {
+	issues := []*types.Issue{{ID: "bd-alice-1"}, {ID: "bd-bob-3"}, {ID: "bd-alice-2.1"}, {ID: "bd-a3f"}}
+
+	prefixes := detectPrefixes(issues, issuePrefixFunc(idgen.SchemeActor))
+	if len(prefixes) != 1 || prefixes["bd"] != 4 {
+		t.Errorf("actor scheme: prefixes = %v, want bd: 4", prefixes)
+	}
+	// Without the scheme, each actor namespace reads as a prefix.
+	if prefixes := detectPrefixes(issues, issuePrefixFunc(idgen.SchemeCounter)); len(prefixes) != 3 {
+		t.Errorf("counter scheme: prefixes = %v, want bd-alice, bd-bob and bd", prefixes)
+	}
+}
+
 func TestRenamePrefixCommand(t *testing.T) {
 	tmpDir := t.TempDir()
 	dbPath := filepath.Join(tmpDir, "test.db")
@@ -15,14 +15,17 @@ import (
 var restoreCmd = &cobra.Command{
 	Use:     "restore <issue-id>",
 	GroupID: "sync",
-	Short:   "Restore full history of a compacted issue from Dolt history",
-	Long: `Restore full history of a compacted issue from Dolt version history.
+	Short:   "Restore a deleted issue, or show a compacted issue's full history",
+	Long: `Restore a deleted issue from the trash, or show the full history of a
+compacted issue from Dolt version history.
+
+If the issue was removed with 'bd delete', it is re-created from the trash
+with its labels, comments, and dependencies (see 'bd trash').
 
 When an issue is compacted, its description and notes are truncated.
 This command queries Dolt's history tables to find the pre-compaction
-version and displays the full issue content.
-
-This is read-only and does not modify the database.`,
+version and displays the full issue content. Showing compacted history is
+read-only and does not modify the database.`,
 	Args: cobra.ExactArgs(1),
 	Run: func(cmd *cobra.Command, args []string) {
 		issueID := args[0]
@@ -32,6 +35,10 @@ This is read-only and does not modify the database.`,
 		issue, err := store.GetIssue(ctx, issueID)
 		if err != nil {
 			if errors.Is(err, storage.ErrNotFound) {
+				if entry, trashErr := store.GetTrash(ctx, issueID); trashErr == nil && entry != nil {
+					runTrashRestore(ctx, issueID)
+					return
+				}
 				fmt.Fprintf(os.Stderr, "Error: issue '%s' not found\n", issueID)
 			} else {
 				fmt.Fprintf(os.Stderr, "Error: issue '%s' not found: %v\n", issueID, err)
@@ -0,0 +1,428 @@
+package main
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// scanExternalRefPrefix marks issues filed by 'bd scan'. The rest of the
+// external_ref is a hash of the comment's file and text, which is how a
+// later scan finds the issue again after the comment moves within the file.
+const scanExternalRefPrefix = "scan:"
+
+// scanMaxFileSize skips files too large to be hand-written source.
+const scanMaxFileSize = 1 << 20
+
+// scanCommentPattern matches TODO(bd): and FIXME(bd): comments.
+var scanCommentPattern = regexp.MustCompile(`\b(TODO|FIXME)\(bd\):?\s*(.*)$`)
+
+var scanCmd = &cobra.Command{
+	Use:     "scan [path...]",
+	GroupID: "issues",
+	Short:   "File issues for TODO(bd): and FIXME(bd): comments in the source",
+	Long: `Scan the repository for TODO(bd): and FIXME(bd): comments and keep an issue
+for each one.
+
+Files are those git tracks or would track, so .gitignore is respected; the
+.beads directory, binary files and files over 1 MiB are skipped.
+
+  - A new comment gets a new issue (task for TODO, bug for FIXME) with a
+    code reference to its file and line (see 'bd ref').
+  - When a comment moves within its file, the issue's reference follows it.
+  - When a comment disappears, its open issue is closed.
+
+Given paths, only those files or directories are scanned, and only issues
+for comments under them are closed.`,
+	Example: `  bd scan
+  bd scan --dry-run
+  bd scan src/`,
+	Run: func(cmd *cobra.Command, args []string) {
+		dryRun, _ := cmd.Flags().GetBool("dry-run")
+		if !dryRun {
+			CheckReadonly("scan")
+		}
+		ctx := rootCtx
+
+		root := codeRefRoot()
+		cwd, err := os.Getwd()
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		scope := make([]string, 0, len(args))
+		for _, arg := range args {
+			rel, err := repoRelativePath(root, cwd, arg)
+			if err != nil {
+				FatalErrorRespectJSON("%v", err)
+			}
+			scope = append(scope, rel)
+		}
+
+		files, err := scanListFiles(ctx, root, scope)
+		if err != nil {
+			FatalErrorRespectJSON("listing files: %v", err)
+		}
+		findings, err := scanTodoComments(root, files)
+		if err != nil {
+			FatalErrorRespectJSON("scanning: %v", err)
+		}
+		existing, err := store.SearchIssues(ctx, "", types.IssueFilter{ExternalRefContains: scanExternalRefPrefix, SkipLabels: true})
+		if err != nil {
+			FatalErrorRespectJSON("finding scanned issues: %v", err)
+		}
+		ids := make([]string, 0, len(existing))
+		for _, issue := range existing {
+			ids = append(ids, issue.ID)
+		}
+		refs, err := store.GetCodeRefsForIssues(ctx, ids)
+		if err != nil {
+			FatalErrorRespectJSON("reading code references: %v", err)
+		}
+
+		plan := planScan(findings, existing, refs, scope)
+		if !dryRun {
+			if err := applyScanPlan(ctx, store, &plan); err != nil {
+				FatalErrorRespectJSON("%v", err)
+			}
+			if len(plan.Create)+len(plan.Close)+len(plan.Move) > 0 {
+				commandDidWrite.Store(true)
+			}
+		}
+
+		if jsonOutput {
+			outputJSON(plan.summary(dryRun))
+			return
+		}
+		printScanPlan(plan, dryRun)
+	},
+}
+
+// scanFinding is one TODO(bd): or FIXME(bd): comment.
+type scanFinding struct {
+	Path string `json:"path"`
+	Line int    `json:"line"`
+	Kind string `json:"kind"` // "TODO" or "FIXME"
+	Text string `json:"text"`
+}
+
+// key identifies the comment across scans: its file, kind and text, but not
+// its line.
+func (f scanFinding) key() string {
+	sum := sha256.Sum256([]byte(f.Path + "\x00" + f.Kind + "\x00" + f.Text))
+	return scanExternalRefPrefix + hex.EncodeToString(sum[:8])
+}
+
+func (f scanFinding) ref() *types.CodeRef {
+	return &types.CodeRef{Path: f.Path, LineStart: f.Line, LineEnd: f.Line}
+}
+
+// scanListFiles lists the files under root that git tracks or would track,
+// limited to scope when it is non-empty.
+func scanListFiles(ctx context.Context, root string, scope []string) ([]string, error) {
+	args := []string{"ls-files", "-z", "--cached", "--others", "--exclude-standard", "--"}
+	args = append(args, scope...)
+	cmd := exec.CommandContext(ctx, "git", args...) //nolint:gosec // G204: fixed git subcommand; args are repository paths
+	cmd.Dir = root
+	out, err := cmd.Output()
+	if err != nil {
+		return nil, fmt.Errorf("git ls-files: %w", err)
+	}
+	seen := make(map[string]bool)
+	var files []string
+	for _, name := range strings.Split(string(out), "\x00") {
+		if name == "" || seen[name] || name == ".beads" || strings.HasPrefix(name, ".beads/") {
+			continue
+		}
+		seen[name] = true
+		files = append(files, name)
+	}
+	sort.Strings(files)
+	return files, nil
+}
+
+// scanTodoComments finds the TODO(bd): and FIXME(bd): comments in files,
+// given relative to root. A comment repeated within one file is reported
+// once, at its first line.
+func scanTodoComments(root string, files []string) ([]scanFinding, error) {
+	var findings []scanFinding
+	for _, name := range files {
+		full := filepath.Join(root, filepath.FromSlash(name))
+		info, err := os.Lstat(full)
+		if err != nil || !info.Mode().IsRegular() || info.Size() > scanMaxFileSize {
+			continue
+		}
+		data, err := os.ReadFile(full) // #nosec G304 -- path comes from git ls-files inside the repository
+		if err != nil {
+			return nil, err
+		}
+		if bytes.IndexByte(data[:min(len(data), 8000)], 0) >= 0 {
+			continue // binary
+		}
+		seen := make(map[string]bool)
+		scanner := bufio.NewScanner(bytes.NewReader(data))
+		scanner.Buffer(make([]byte, 0, 64*1024), scanMaxFileSize)
+		for line := 1; scanner.Scan(); line++ {
+			m := scanCommentPattern.FindStringSubmatch(scanner.Text())
+			if m == nil {
+				continue
+			}
+			text := scanCommentText(m[2])
+			if text == "" {
+				continue
+			}
+			f := scanFinding{Path: name, Line: line, Kind: m[1], Text: text}
+			if seen[f.key()] {
+				continue
+			}
+			seen[f.key()] = true
+			findings = append(findings, f)
+		}
+	}
+	return findings, nil
+}
+
+// scanCommentText trims a comment's text and any block-comment closer.
+func scanCommentText(s string) string {
+	s = strings.TrimSpace(s)
+	for _, closer := range []string{"*/", "-->", "#}", "%}"} {
+		s = strings.TrimSpace(strings.TrimSuffix(s, closer))
+	}
+	return s
+}
+
+// scanPlan is what a scan will change.
+type scanPlan struct {
+	Create []scanFinding
+	Move   []scanMove
+	Close  []*types.Issue
+	// Created holds the IDs of the issues filed for Create, once applied.
+	Created []string
+	// Unchanged counts comments whose issue is already up to date, or was
+	// closed by hand while the comment stayed.
+	Unchanged int
+}
+
+// scanMove is an open issue whose comment moved within its file.
+type scanMove struct {
+	IssueID string
+	From    *types.CodeRef
+	To      scanFinding
+}
+
+// planScan matches findings to the issues earlier scans filed (existing,
+// with their code references) and decides what to create, move and close.
+// Only issues for comments inside scope (all, when empty) are closed.
+func planScan(findings []scanFinding, existing []*types.Issue, refs map[string][]*types.CodeRef, scope []string) scanPlan {
+	byKey := make(map[string]*types.Issue, len(existing))
+	for _, issue := range existing {
+		if issue.ExternalRef != nil && strings.HasPrefix(*issue.ExternalRef, scanExternalRefPrefix) {
+			byKey[*issue.ExternalRef] = issue
+		}
+	}
+
+	var plan scanPlan
+	found := make(map[string]bool, len(findings))
+	for _, f := range findings {
+		found[f.key()] = true
+		issue := byKey[f.key()]
+		switch {
+		case issue == nil:
+			plan.Create = append(plan.Create, f)
+		case issue.Status == types.StatusClosed:
+			plan.Unchanged++
+		default:
+			var from *types.CodeRef
+			for _, r := range refs[issue.ID] {
+				if r.Path == f.Path {
+					from = r
+					break
+				}
+			}
+			if from != nil && from.LineStart == f.Line {
+				plan.Unchanged++
+				continue
+			}
+			plan.Move = append(plan.Move, scanMove{IssueID: issue.ID, From: from, To: f})
+		}
+	}
+
+	for _, issue := range existing {
+		if issue.Status == types.StatusClosed || issue.ExternalRef == nil || found[*issue.ExternalRef] {
+			continue
+		}
+		if !scanIssueInScope(refs[issue.ID], scope) {
+			continue
+		}
+		plan.Close = append(plan.Close, issue)
+	}
+	sort.Slice(plan.Close, func(i, j int) bool { return plan.Close[i].ID < plan.Close[j].ID })
+	return plan
+}
+
+// scanIssueInScope reports whether a scanned issue's comment was in a file
+// this scan looked at.
+func scanIssueInScope(refs []*types.CodeRef, scope []string) bool {
+	if len(scope) == 0 {
+		return true
+	}
+	for _, r := range refs {
+		for _, s := range scope {
+			if s == "." || r.Path == s || strings.HasPrefix(r.Path, s+"/") {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+func scanIssueTitle(f scanFinding) string {
+	const maxTitle = 200
+	title := f.Text
+	if r := []rune(title); len(r) > maxTitle {
+		title = string(r[:maxTitle-1]) + "…"
+	}
+	return title
+}
+
+// applyScanPlan files, moves and closes issues as planned, recording the
+// IDs it files in plan.Created.
+func applyScanPlan(ctx context.Context, s storage.DoltStorage, plan *scanPlan) error {
+	actor := getActorWithGit()
+	for _, f := range plan.Create {
+		issueType := types.TypeTask
+		if f.Kind == "FIXME" {
+			issueType = types.TypeBug
+		}
+		key := f.key()
+		issue := &types.Issue{
+			Title:       scanIssueTitle(f),
+			Description: fmt.Sprintf("Filed by `bd scan` from a %s(bd) comment at %s.\n\n%s", f.Kind, f.ref().String(), f.Text),
+			Priority:    2,
+			IssueType:   issueType,
+			Status:      types.StatusOpen,
+			Owner:       getOwner(),
+			CreatedBy:   actor,
+			ExternalRef: &key,
+			CodeRefs:    []*types.CodeRef{f.ref()},
+		}
+		issue.CodeRefs[0].CreatedBy = actor
+		if err := s.CreateIssue(ctx, issue, actor); err != nil {
+			return fmt.Errorf("filing issue for %s: %w", f.ref().String(), err)
+		}
+		plan.Created = append(plan.Created, issue.ID)
+	}
+	for _, m := range plan.Move {
+		if m.From != nil {
+			if _, err := s.RemoveCodeRefs(ctx, m.IssueID, m.From.Path, 0, 0); err != nil {
+				return fmt.Errorf("moving reference on %s: %w", m.IssueID, err)
+			}
+		}
+		ref := m.To.ref()
+		ref.IssueID = m.IssueID
+		ref.CreatedBy = actor
+		if m.From != nil {
+			ref.Note = m.From.Note
+		}
+		if err := s.AddCodeRef(ctx, ref); err != nil {
+			return fmt.Errorf("moving reference on %s: %w", m.IssueID, err)
+		}
+	}
+	for _, issue := range plan.Close {
+		if err := s.CloseIssue(ctx, issue.ID, "bd scan: comment removed from source", actor, ""); err != nil {
+			return fmt.Errorf("closing %s: %w", issue.ID, err)
+		}
+	}
+	return nil
+}
+
+// scanSummary is the 'bd scan --json' output.
+type scanSummary struct {
+	DryRun    bool          `json:"dry_run"`
+	Created   []scanCreated `json:"created"`
+	Moved     []scanMoved   `json:"moved"`
+	Closed    []string      `json:"closed"`
+	Unchanged int           `json:"unchanged"`
+}
+
+type scanCreated struct {
+	ID string `json:"id,omitempty"` // empty on a dry run
+	scanFinding
+}
+
+type scanMoved struct {
+	ID   string `json:"id"`
+	From string `json:"from,omitempty"`
+	To   string `json:"to"`
+}
+
+func (p scanPlan) summary(dryRun bool) scanSummary {
+	out := scanSummary{DryRun: dryRun, Created: []scanCreated{}, Moved: []scanMoved{}, Closed: []string{}, Unchanged: p.Unchanged}
+	for i, f := range p.Create {
+		c := scanCreated{scanFinding: f}
+		if i < len(p.Created) {
+			c.ID = p.Created[i]
+		}
+		out.Created = append(out.Created, c)
+	}
+	for _, m := range p.Move {
+		moved := scanMoved{ID: m.IssueID, To: m.To.ref().String()}
+		if m.From != nil {
+			moved.From = m.From.String()
+		}
+		out.Moved = append(out.Moved, moved)
+	}
+	for _, issue := range p.Close {
+		out.Closed = append(out.Closed, issue.ID)
+	}
+	return out
+}
+
+func printScanPlan(p scanPlan, dryRun bool) {
+	if len(p.Create)+len(p.Move)+len(p.Close) == 0 {
+		fmt.Printf("%s Nothing to do (%d comment(s) already tracked)\n", ui.RenderPass("✓"), p.Unchanged)
+		return
+	}
+	created, moved, closed := "Created", "Moved", "Closed"
+	if dryRun {
+		created, moved, closed = "Would create", "Would move", "Would close"
+	}
+	for i, f := range p.Create {
+		id := ""
+		if i < len(p.Created) {
+			id = ui.RenderID(p.Created[i]) + " "
+		}
+		fmt.Printf("%s %s: %s%s %s\n", created, f.ref().String(), id, f.Kind, f.Text)
+	}
+	for _, m := range p.Move {
+		from := "(no reference)"
+		if m.From != nil {
+			from = m.From.String()
+		}
+		fmt.Printf("%s %s: %s → %s\n", moved, ui.RenderID(m.IssueID), from, m.To.ref().String())
+	}
+	for _, issue := range p.Close {
+		fmt.Printf("%s %s: %s (comment removed)\n", closed, ui.RenderID(issue.ID), issue.Title)
+	}
+	if dryRun {
+		fmt.Printf("\n%s\n", ui.RenderMuted("Dry run: no changes made"))
+	}
+}
+
+func init() {
+	scanCmd.Flags().Bool("dry-run", false, "Show what would change without filing or closing issues")
+	rootCmd.AddCommand(scanCmd)
+}
@@ -0,0 +1,78 @@
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestScanTodoComments(t *testing.T) {
+	root := t.TempDir()
+	src := "package x\n\n// TODO(bd): handle retries\nfunc f() {}\n/* FIXME(bd): leaks on error */\n// TODO(bd): handle retries\n// TODO: not ours\n"
+	if err := os.WriteFile(filepath.Join(root, "x.go"), []byte(src), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(root, "blob.bin"), []byte("TODO(bd): no\x00"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	got, err := scanTodoComments(root, []string{"blob.bin", "x.go"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := []scanFinding{
+		{Path: "x.go", Line: 3, Kind: "TODO", Text: "handle retries"},
+		{Path: "x.go", Line: 5, Kind: "FIXME", Text: "leaks on error"},
+	}
+	if len(got) != len(want) {
+		t.Fatalf("scanTodoComments = %+v, want %+v", got, want)
+	}
+	for i := range want {
+		if got[i] != want[i] {
+			t.Errorf("finding %d = %+v, want %+v", i, got[i], want[i])
+		}
+	}
+}
+
+func TestPlanScan(t *testing.T) {
+	moved := scanFinding{Path: "a.go", Line: 20, Kind: "TODO", Text: "moved"}
+	same := scanFinding{Path: "a.go", Line: 5, Kind: "TODO", Text: "same"}
+	fresh := scanFinding{Path: "b/c.go", Line: 1, Kind: "FIXME", Text: "new"}
+	doneByHand := scanFinding{Path: "a.go", Line: 9, Kind: "TODO", Text: "closed by hand"}
+	gone := scanFinding{Path: "b/c.go", Line: 7, Kind: "TODO", Text: "removed"}
+	outside := scanFinding{Path: "d.go", Line: 2, Kind: "TODO", Text: "not scanned"}
+
+	issue := func(id string, f scanFinding, status types.Status) *types.Issue {
+		key := f.key()
+		return &types.Issue{ID: id, Status: status, ExternalRef: &key}
+	}
+	existing := []*types.Issue{
+		issue("bd-1", moved, types.StatusOpen),
+		issue("bd-2", same, types.StatusOpen),
+		issue("bd-3", doneByHand, types.StatusClosed),
+		issue("bd-4", gone, types.StatusInProgress),
+		issue("bd-5", outside, types.StatusOpen),
+	}
+	refs := map[string][]*types.CodeRef{
+		"bd-1": {{Path: "a.go", LineStart: 12, LineEnd: 12}},
+		"bd-2": {same.ref()},
+		"bd-4": {gone.ref()},
+		"bd-5": {outside.ref()},
+	}
+
+	plan := planScan([]scanFinding{moved, same, fresh, doneByHand}, existing, refs, []string{"a.go", "b"})
+	if len(plan.Create) != 1 || plan.Create[0] != fresh {
+		t.Errorf("Create = %+v, want only %+v", plan.Create, fresh)
+	}
+	if len(plan.Move) != 1 || plan.Move[0].IssueID != "bd-1" || plan.Move[0].From.LineStart != 12 || plan.Move[0].To != moved {
+		t.Errorf("Move = %+v, want bd-1 from line 12 to 20", plan.Move)
+	}
+	if len(plan.Close) != 1 || plan.Close[0].ID != "bd-4" {
+		t.Errorf("Close = %v, want only bd-4 (bd-5 is outside the scanned paths)", plan.Close)
+	}
+	if plan.Unchanged != 2 {
+		t.Errorf("Unchanged = %d, want 2", plan.Unchanged)
+	}
+}
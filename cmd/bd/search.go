@@ -1,6 +1,7 @@
 package main
 
 import (
+	"cmp"
 	"fmt"
 	"os"
 	"strings"
@@ -19,8 +20,11 @@ var searchCmd = &cobra.Command{
 	Long: `Search issues across title and ID (excludes closed issues by default).
 
 ID-like queries (e.g., "bd-123", "hq-319") use fast exact/prefix matching.
-Text queries search titles. Use --desc-contains for description search.
-Use --status all to include closed issues.
+Text queries search titles. Use --desc-contains for description search, or
+--title-regex / --description-regex to match a regular expression (these can
+replace the query). Use --in comments,events to also match comment text and
+event history, including close reasons. Use --status all to include closed
+issues.
 
 Examples:
   bd search "authentication bug"
@@ -34,7 +38,12 @@ Examples:
   bd search "bug" --sort priority
   bd search "task" --sort created --reverse
   bd search "api" --desc-contains "endpoint"
-  bd search "cleanup" --no-assignee --no-labels`,
+  bd search --title-regex 'v[0-9]+\.[0-9]+' --status all
+  bd search --description-regex 'E[0-9]{4}'
+  bd search "flaky" --in comments  # Also match comment text
+  bd search "duplicate" --in events --status all  # Also match close reasons
+  bd search "cleanup" --no-assignee --no-labels
+  bd search "login" --not-label backlog --not-assignee alice`,
 	Run: func(cmd *cobra.Command, args []string) {
 		// Get query from args or --query flag
 		queryFlag, _ := cmd.Flags().GetString("query")
@@ -45,8 +54,12 @@ Examples:
 			query = queryFlag
 		}
 
+		// A regex filter can stand in for the query
+		titleRegex, _ := cmd.Flags().GetString("title-regex")
+		descRegex, _ := cmd.Flags().GetString("description-regex")
+
 		// If no query provided, show help
-		if query == "" {
+		if query == "" && titleRegex == "" && descRegex == "" {
 			if err := cmd.Help(); err != nil {
 				fmt.Fprintf(os.Stderr, "Error displaying help: %v\n", err)
 			}
@@ -86,6 +99,18 @@ Examples:
 		noAssignee, _ := cmd.Flags().GetBool("no-assignee")
 		noLabels, _ := cmd.Flags().GetBool("no-labels")
 
+		// Extra text sources for the query
+		searchIn, _ := cmd.Flags().GetStringSlice("in")
+		for i, source := range searchIn {
+			searchIn[i] = strings.ToLower(strings.TrimSpace(source))
+			if searchIn[i] != "comments" && searchIn[i] != "events" {
+				FatalError("invalid --in value %q (valid: comments, events)", source)
+			}
+		}
+		if len(searchIn) > 0 && query == "" {
+			FatalError("--in needs a search query")
+		}
+
 		// Normalize labels
 		labels = utils.NormalizeLabels(labels)
 		labelsAny = utils.NormalizeLabels(labelsAny)
@@ -133,6 +158,9 @@ Examples:
 		if externalContains != "" {
 			filter.ExternalRefContains = externalContains
 		}
+		filter.TitleRegex = titleRegex
+		filter.DescriptionRegex = descRegex
+		filter.SearchIn = searchIn
 
 		// Empty/null checks
 		if emptyDesc {
@@ -147,47 +175,49 @@ Examples:
 
 		// Date ranges
 		if createdAfter != "" {
-			t, err := parseTimeFlag(createdAfter)
+			t, err := parseDateFilterFlag(createdAfter)
 			if err != nil {
 				FatalError("parsing --created-after: %v", err)
 			}
 			filter.CreatedAfter = &t
 		}
 		if createdBefore != "" {
-			t, err := parseTimeFlag(createdBefore)
+			t, err := parseDateFilterFlag(createdBefore)
 			if err != nil {
 				FatalError("parsing --created-before: %v", err)
 			}
 			filter.CreatedBefore = &t
 		}
 		if updatedAfter != "" {
-			t, err := parseTimeFlag(updatedAfter)
+			t, err := parseDateFilterFlag(updatedAfter)
 			if err != nil {
 				FatalError("parsing --updated-after: %v", err)
 			}
 			filter.UpdatedAfter = &t
 		}
 		if updatedBefore != "" {
-			t, err := parseTimeFlag(updatedBefore)
+			t, err := parseDateFilterFlag(updatedBefore)
 			if err != nil {
 				FatalError("parsing --updated-before: %v", err)
 			}
 			filter.UpdatedBefore = &t
 		}
 		if closedAfter != "" {
-			t, err := parseTimeFlag(closedAfter)
+			t, err := parseDateFilterFlag(closedAfter)
 			if err != nil {
 				FatalError("parsing --closed-after: %v", err)
 			}
 			filter.ClosedAfter = &t
 		}
 		if closedBefore != "" {
-			t, err := parseTimeFlag(closedBefore)
+			t, err := parseDateFilterFlag(closedBefore)
 			if err != nil {
 				FatalError("parsing --closed-before: %v", err)
 			}
 			filter.ClosedBefore = &t
 		}
+		applyUpdatedWindowFlags(cmd, &filter)
+		applyExclusionFlags(rootCtx, cmd, &filter)
 
 		// Priority ranges
 		if cmd.Flags().Changed("priority-min") {
@@ -298,7 +328,8 @@ Examples:
 			issue.Labels = labelsMap[issue.ID]
 		}
 
-		outputSearchResults(issues, query, longFormat)
+		// With only a regex filter, report matches against the pattern
+		outputSearchResults(issues, cmp.Or(query, titleRegex, descRegex), longFormat)
 	},
 }
 
@@ -347,7 +378,7 @@ func init() {
 	searchCmd.Flags().StringP("status", "s", "", "Filter by stored status (open, in_progress, blocked, deferred, closed, all). Default excludes closed; use 'all' to include closed. Note: dependency-blocked issues use 'bd blocked'")
 	searchCmd.Flags().StringP("assignee", "a", "", "Filter by assignee")
 	searchCmd.Flags().StringP("type", "t", "", "Filter by type (bug, feature, task, epic, chore, decision, merge-request, molecule, gate)")
-	searchCmd.Flags().StringSliceP("label", "l", []string{}, "Filter by labels (AND: must have ALL)")
+	searchCmd.Flags().StringSliceP("label", "l", []string{}, "Filter by labels (AND: must have ALL; 'area/*' matches a namespace)")
 	searchCmd.Flags().StringSlice("label-any", []string{}, "Filter by labels (OR: must have AT LEAST ONE)")
 	searchCmd.Flags().IntP("limit", "n", 50, "Limit results (default: 50)")
 	searchCmd.Flags().Bool("long", false, "Show detailed multi-line output for each issue")
@@ -355,12 +386,14 @@ func init() {
 	searchCmd.Flags().BoolP("reverse", "r", false, "Reverse sort order")
 
 	// Date range flags
-	searchCmd.Flags().String("created-after", "", "Filter issues created after date (YYYY-MM-DD or RFC3339)")
-	searchCmd.Flags().String("created-before", "", "Filter issues created before date (YYYY-MM-DD or RFC3339)")
-	searchCmd.Flags().String("updated-after", "", "Filter issues updated after date (YYYY-MM-DD or RFC3339)")
-	searchCmd.Flags().String("updated-before", "", "Filter issues updated before date (YYYY-MM-DD or RFC3339)")
-	searchCmd.Flags().String("closed-after", "", "Filter issues closed after date (YYYY-MM-DD or RFC3339)")
-	searchCmd.Flags().String("closed-before", "", "Filter issues closed before date (YYYY-MM-DD or RFC3339)")
+	searchCmd.Flags().String("created-after", "", "Filter issues created after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	searchCmd.Flags().String("created-before", "", "Filter issues created before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	searchCmd.Flags().String("updated-after", "", "Filter issues updated after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	searchCmd.Flags().String("updated-before", "", "Filter issues updated before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	searchCmd.Flags().String("closed-after", "", "Filter issues closed after date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	searchCmd.Flags().String("closed-before", "", "Filter issues closed before date (YYYY-MM-DD, RFC3339, or a duration ago like 2w)")
+	registerUpdatedWindowFlags(searchCmd)
+	registerExclusionFlags(searchCmd)
 
 	// Priority range flags
 	searchCmd.Flags().String("priority-min", "", "Filter by minimum priority (inclusive, 0-4 or P0-P4)")
@@ -370,6 +403,9 @@ func init() {
 	searchCmd.Flags().String("desc-contains", "", "Filter by description substring (case-insensitive)")
 	searchCmd.Flags().String("notes-contains", "", "Filter by notes substring (case-insensitive)")
 	searchCmd.Flags().String("external-contains", "", "Filter by external ref substring (case-insensitive)")
+	searchCmd.Flags().String("title-regex", "", "Filter by title regular expression (case-insensitive, e.g. 'v[0-9]+\\.[0-9]+')")
+	searchCmd.Flags().String("description-regex", "", "Filter by description regular expression (case-insensitive, e.g. 'E[0-9]{4}')")
+	searchCmd.Flags().StringSlice("in", nil, "Also match the query against: comments, events (event history and close reasons); comma-separated")
 
 	// Empty/null check flags
 	searchCmd.Flags().Bool("empty-description", false, "Filter issues with empty or missing description")
@@ -65,7 +65,8 @@ func TestEmbeddedSearch(t *testing.T) {
 	taskC := bdCreate(t, bd, dir, "Gamma feature", "--type", "feature", "--priority", "2", "--label", "urgent", "--label", "frontend")
 	taskD := bdCreate(t, bd, dir, "Delta task no desc", "--type", "task")
 	closedTask := bdCreate(t, bd, dir, "Closed epsilon", "--type", "task")
-	bdClose(t, bd, dir, closedTask.ID)
+	bdClose(t, bd, dir, closedTask.ID, "--reason", "superseded by the zephyr rewrite")
+	bdComment(t, bd, dir, taskB.ID, "repro needs the xylophone fixture")
 
 	// ===== Basic Search =====
 
@@ -293,6 +294,27 @@ func TestEmbeddedSearch(t *testing.T) {
 		}
 	})
 
+	t.Run("search_regex_without_query", func(t *testing.T) {
+		results := bdSearchJSON(t, bd, dir, "--title-regex", "^(alpha|beta) ", "--description-regex", "ALPHA w")
+		if len(results) != 1 || results[0]["id"] != taskA.ID {
+			t.Errorf("expected only taskA for title ^(alpha|beta) and description 'ALPHA w', got %v", results)
+		}
+	})
+
+	t.Run("search_in_comments_and_events", func(t *testing.T) {
+		if results := bdSearchJSON(t, bd, dir, "xylophone"); len(results) != 0 {
+			t.Errorf("comment text should not match without --in, got %v", results)
+		}
+		results := bdSearchJSON(t, bd, dir, "xylophone", "--in", "comments")
+		if len(results) != 1 || results[0]["id"] != taskB.ID {
+			t.Errorf("expected only taskB for a term in its comment, got %v", results)
+		}
+		results = bdSearchJSON(t, bd, dir, "zephyr", "--in", "comments,events", "--status", "all")
+		if len(results) != 1 || results[0]["id"] != closedTask.ID {
+			t.Errorf("expected only the closed task for a term in its close reason, got %v", results)
+		}
+	})
+
 	t.Run("search_empty_description", func(t *testing.T) {
 		results := bdSearchJSON(t, bd, dir, "sr-", "--empty-description")
 		found := false
@@ -0,0 +1,490 @@
+package main
+
+import (
+	"context"
+	"crypto/subtle"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"mime"
+	"net"
+	"net/http"
+	"os"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+var serveCmd = &cobra.Command{
+	Use:     "serve",
+	GroupID: "advanced",
+	Short:   "Serve issues over an HTTP JSON API",
+	Long: `Run in the foreground, serving the issue database as a REST API for
+dashboards and scripts. Response bodies are the same JSON the matching CLI
+command prints with --json.
+
+  GET   /issues                 bd list (status, priority, type, assignee,
+                                label, q, limit, all query parameters)
+  POST  /issues                 bd create (title, description, type, priority,
+                                assignee, labels, parent, deps, id)
+  GET   /issues/{id}            bd show
+  PATCH /issues/{id}            bd update (title, description, status, priority,
+                                assignee, notes, design, acceptance_criteria,
+                                force)
+  POST  /issues/{id}/close      bd close (reason, force)
+  GET   /issues/{id}/comments   bd comments
+  POST  /issues/{id}/comments   bd comments add (text, author)
+  GET   /ready                  bd ready
+  GET   /blocked                bd blocked
+  GET   /stats                  bd status
+  GET   /metrics                Prometheus metrics: requests and errors by
+                                route, open/in-progress/ready/blocked issues
+
+Errors are returned as {"error": "..."} with a 4xx or 5xx status. Writes are
+committed to Dolt and auto-exported as after the matching CLI command; the
+X-Beads-Actor header sets the actor. POST and PATCH requests must be sent
+with "Content-Type: application/json".
+
+Every request must send "Authorization: Bearer <token>". The token is
+--token, or BD_SERVE_TOKEN, or else one generated at startup and printed to
+stderr. On a loopback address, --no-token serves without one; requests
+must then name a loopback host, so that a web page can't reach the API
+through a DNS name that resolves to 127.0.0.1.
+
+Stop it with Ctrl+C or SIGTERM.`,
+	Args: cobra.NoArgs,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		if store == nil {
+			return fmt.Errorf("no store available")
+		}
+		beadsDir := beads.FindBeadsDir()
+		if beadsDir == "" {
+			return fmt.Errorf("no .beads directory found")
+		}
+		listenAddr, _ := cmd.Flags().GetString("listen")
+		token, _ := cmd.Flags().GetString("token")
+		if token == "" {
+			token = os.Getenv("BD_SERVE_TOKEN")
+		}
+		noToken, _ := cmd.Flags().GetBool("no-token")
+		host, _, err := net.SplitHostPort(listenAddr)
+		if err != nil {
+			return fmt.Errorf("invalid --listen address %q: %w", listenAddr, err)
+		}
+		loopback := isLoopbackHost(host)
+		generated := false
+		switch {
+		case noToken && token != "":
+			return fmt.Errorf("--no-token conflicts with --token or BD_SERVE_TOKEN")
+		case noToken && !loopback:
+			return fmt.Errorf("refusing to serve %s with --no-token: the API can modify issues", listenAddr)
+		case !noToken && token == "":
+			if token, err = newDaemonToken(); err != nil {
+				return err
+			}
+			generated = true
+		}
+
+		ln, err := net.Listen("tcp", listenAddr)
+		if err != nil {
+			return fmt.Errorf("failed to listen on %s: %w", listenAddr, err)
+		}
+		api := &serveAPI{d: &daemonServer{beadsDir: beadsDir, store: store, startedAt: time.Now().UTC()}, token: token, loopbackOnly: loopback}
+		srv := &http.Server{Handler: api.routes(), ReadHeaderTimeout: 10 * time.Second}
+		go func() {
+			<-rootCtx.Done()
+			shutdownCtx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+			defer cancel()
+			_ = srv.Shutdown(shutdownCtx)
+		}()
+
+		if !isQuiet() {
+			fmt.Fprintf(os.Stderr, "bd serve listening on http://%s (Ctrl+C to stop)\n", ln.Addr())
+		}
+		if generated {
+			// Printed even with --quiet: without it the server is unusable.
+			fmt.Fprintf(os.Stderr, "Bearer token: %s\n", token)
+		}
+		if err := srv.Serve(ln); err != nil && !errors.Is(err, http.ErrServerClosed) {
+			return err
+		}
+		return nil
+	},
+}
+
+func init() {
+	serveCmd.Flags().String("listen", "127.0.0.1:7380", "Address to serve HTTP on (host:port)")
+	serveCmd.Flags().String("token", "", "Require this bearer token on every request (default: $BD_SERVE_TOKEN, or a generated one)")
+	serveCmd.Flags().Bool("no-token", false, "Serve without a bearer token (loopback addresses only)")
+	rootCmd.AddCommand(serveCmd)
+}
+
+// serveAPI maps HTTP routes onto the store. Writes go through the daemon's
+// handlers, which create, update, and close issues with the same helpers
+// as the CLI commands, so the checks and guards match theirs; they also
+// share the daemon's Dolt commits and one-request-at-a-time lock.
+type serveAPI struct {
+	d     *daemonServer
+	token string
+	// loopbackOnly rejects requests whose Host header is not a loopback
+	// name, guarding a loopback listener against DNS rebinding.
+	loopbackOnly bool
+}
+
+// serveError is an error with the HTTP status to report it with.
+type serveError struct {
+	status int
+	err    error
+}
+
+func (e *serveError) Error() string { return e.err.Error() }
+
+func badRequest(format string, args ...interface{}) error {
+	return &serveError{status: http.StatusBadRequest, err: fmt.Errorf(format, args...)}
+}
+
+func (a *serveAPI) routes() http.Handler {
+	mux := http.NewServeMux()
+	mux.HandleFunc("GET /issues", a.wrap(a.listIssues))
+	mux.HandleFunc("POST /issues", a.wrap(a.writeOp("create", "")))
+	mux.HandleFunc("GET /issues/{id}", a.wrap(a.showIssue))
+	mux.HandleFunc("PATCH /issues/{id}", a.wrap(a.writeOp("update", "id")))
+	mux.HandleFunc("POST /issues/{id}/close", a.wrap(a.writeOp("close", "id")))
+	mux.HandleFunc("GET /issues/{id}/comments", a.wrap(a.listComments))
+	mux.HandleFunc("POST /issues/{id}/comments", a.wrap(a.addComment))
+	mux.HandleFunc("GET /ready", a.wrap(a.ready))
+	mux.HandleFunc("GET /blocked", a.wrap(a.blocked))
+	mux.HandleFunc("GET /stats", a.wrap(a.stats))
+	mux.HandleFunc("GET /metrics", func(w http.ResponseWriter, r *http.Request) {
+		if a.authorized(w, r) {
+			serveMetrics(a.d)(w, r)
+		}
+	})
+	if !a.loopbackOnly {
+		return mux
+	}
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		host, _, err := net.SplitHostPort(r.Host)
+		if err != nil {
+			host = strings.Trim(r.Host, "[]")
+		}
+		if !isLoopbackHost(host) {
+			writeServeJSON(w, http.StatusForbidden, map[string]string{"error": fmt.Sprintf("host %q is not a loopback address", r.Host)})
+			return
+		}
+		mux.ServeHTTP(w, r)
+	})
+}
+
+// isLoopbackHost reports whether host is "localhost" or a loopback IP.
+func isLoopbackHost(host string) bool {
+	if strings.EqualFold(host, "localhost") {
+		return true
+	}
+	ip := net.ParseIP(host)
+	return ip != nil && ip.IsLoopback()
+}
+
+// authorized checks the bearer token, answering 401 if it is wrong.
+func (a *serveAPI) authorized(w http.ResponseWriter, r *http.Request) bool {
+	if a.token == "" {
+		return true
+	}
+	got, _ := strings.CutPrefix(r.Header.Get("Authorization"), "Bearer ")
+	if subtle.ConstantTimeCompare([]byte(got), []byte(a.token)) != 1 {
+		writeServeJSON(w, http.StatusUnauthorized, map[string]string{"error": "invalid or missing bearer token"})
+		return false
+	}
+	return true
+}
+
+// wrap checks the request, runs h, and writes its result or error as JSON.
+// Each request is counted under its route in /metrics.
+func (a *serveAPI) wrap(h func(*http.Request) (int, interface{}, error)) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		if !a.authorized(w, r) {
+			return
+		}
+		var status int
+		var data interface{}
+		err := requireJSONBody(r)
+		if err == nil {
+			status, data, err = h(r)
+		}
+		a.d.stats.record(r.Pattern, err)
+		if err != nil {
+			status = http.StatusInternalServerError
+			var se *serveError
+			switch {
+			case errors.As(err, &se):
+				status = se.status
+			case errors.Is(err, storage.ErrNotFound):
+				status = http.StatusNotFound
+			}
+			writeServeJSON(w, status, map[string]string{"error": err.Error()})
+			return
+		}
+		writeServeJSON(w, status, wrapWithSchemaVersion(data))
+	}
+}
+
+// requireJSONBody rejects a write whose body is not declared as JSON. A
+// browser can send a cross-site form or text/plain POST without a CORS
+// preflight, but not an application/json one.
+func requireJSONBody(r *http.Request) error {
+	if r.Method != http.MethodPost && r.Method != http.MethodPatch {
+		return nil
+	}
+	mediaType, _, err := mime.ParseMediaType(r.Header.Get("Content-Type"))
+	if err != nil || mediaType != "application/json" {
+		return &serveError{status: http.StatusUnsupportedMediaType, err: fmt.Errorf("%s requests must have Content-Type: application/json", r.Method)}
+	}
+	return nil
+}
+
+func writeServeJSON(w http.ResponseWriter, status int, v interface{}) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	enc := json.NewEncoder(w)
+	enc.SetIndent("", "  ")
+	if err := enc.Encode(v); err != nil {
+		debug.Logf("serve: write response: %v", err)
+	}
+}
+
+// serveActor is the X-Beads-Actor header, or the server's own actor.
+func serveActor(r *http.Request) string {
+	if a := strings.TrimSpace(r.Header.Get("X-Beads-Actor")); a != "" {
+		return a
+	}
+	return getActor()
+}
+
+// writeOp returns a handler that runs a daemon write operation with the
+// request body as its args. When idArg is set, the {id} path value is
+// stored under that key, overriding the body.
+func (a *serveAPI) writeOp(op, idArg string) func(*http.Request) (int, interface{}, error) {
+	return func(r *http.Request) (int, interface{}, error) {
+		args := map[string]interface{}{}
+		body, err := io.ReadAll(io.LimitReader(r.Body, 16<<20))
+		if err != nil {
+			return 0, nil, badRequest("failed to read body: %v", err)
+		}
+		if len(strings.TrimSpace(string(body))) > 0 {
+			if err := json.Unmarshal(body, &args); err != nil {
+				return 0, nil, badRequest("invalid JSON body: %v", err)
+			}
+		}
+		if idArg != "" {
+			args[idArg] = r.PathValue("id")
+		}
+		raw, err := json.Marshal(args)
+		if err != nil {
+			return 0, nil, err
+		}
+		data, err := a.d.handle(r.Context(), &daemonRequest{Operation: op, Args: raw, Actor: serveActor(r)})
+		if err != nil {
+			if errors.Is(err, storage.ErrNotFound) {
+				return 0, nil, err
+			}
+			return 0, nil, &serveError{status: http.StatusBadRequest, err: err}
+		}
+		if op == "create" {
+			return http.StatusCreated, data, nil
+		}
+		return http.StatusOK, data, nil
+	}
+}
+
+// serveIssueFilter builds the 'bd list' filter from query parameters.
+func serveIssueFilter(q map[string][]string) (string, types.IssueFilter, error) {
+	get := func(key string) string {
+		if v := q[key]; len(v) > 0 {
+			return strings.TrimSpace(v[0])
+		}
+		return ""
+	}
+	// Same default limit as 'bd list'.
+	filter := types.IssueFilter{Limit: 50, Labels: q["label"]}
+	if s := get("limit"); s != "" {
+		n, err := strconv.Atoi(s)
+		if err != nil || n < 0 {
+			return "", filter, badRequest("invalid limit %q", s)
+		}
+		filter.Limit = n
+	}
+	all, _ := strconv.ParseBool(get("all"))
+	if status := get("status"); status != "" {
+		for _, s := range strings.Split(status, ",") {
+			filter.Statuses = append(filter.Statuses, types.Status(strings.TrimSpace(s)))
+		}
+	} else if !all {
+		filter.ExcludeStatus = []types.Status{types.StatusClosed, types.StatusPinned}
+	}
+	if s := get("priority"); s != "" {
+		p, err := strconv.Atoi(strings.TrimPrefix(strings.ToUpper(s), "P"))
+		if err != nil {
+			return "", filter, badRequest("invalid priority %q", s)
+		}
+		filter.Priority = &p
+	}
+	if s := get("type"); s != "" {
+		t := types.IssueType(s).Normalize()
+		filter.IssueType = &t
+	}
+	if s := get("assignee"); s != "" {
+		filter.Assignee = &s
+	}
+	return get("q"), filter, nil
+}
+
+func (a *serveAPI) listIssues(r *http.Request) (int, interface{}, error) {
+	query, filter, err := serveIssueFilter(r.URL.Query())
+	if err != nil {
+		return 0, nil, err
+	}
+	a.d.mu.RLock()
+	defer a.d.mu.RUnlock()
+	issues, err := a.d.store.SearchIssuesWithCounts(r.Context(), query, filter)
+	if err != nil {
+		return 0, nil, err
+	}
+	if issues == nil {
+		issues = []*types.IssueWithCounts{}
+	}
+	return http.StatusOK, issues, nil
+}
+
+func (a *serveAPI) showIssue(r *http.Request) (int, interface{}, error) {
+	a.d.mu.RLock()
+	defer a.d.mu.RUnlock()
+	issue, err := a.d.store.GetIssue(r.Context(), r.PathValue("id"))
+	if err != nil {
+		return 0, nil, err
+	}
+	return http.StatusOK, showIssueDetails(r.Context(), a.d.store, issue), nil
+}
+
+func (a *serveAPI) listComments(r *http.Request) (int, interface{}, error) {
+	id := r.PathValue("id")
+	a.d.mu.RLock()
+	defer a.d.mu.RUnlock()
+	if _, err := a.d.store.GetIssue(r.Context(), id); err != nil {
+		return 0, nil, err
+	}
+	comments, err := a.d.store.GetIssueComments(r.Context(), id)
+	if err != nil {
+		return 0, nil, err
+	}
+	if comments == nil {
+		comments = []*types.Comment{}
+	}
+	return http.StatusOK, comments, nil
+}
+
+func (a *serveAPI) addComment(r *http.Request) (int, interface{}, error) {
+	var body struct {
+		Text   string `json:"text"`
+		Author string `json:"author"`
+	}
+	if err := json.NewDecoder(io.LimitReader(r.Body, 16<<20)).Decode(&body); err != nil {
+		return 0, nil, badRequest("invalid JSON body: %v", err)
+	}
+	if strings.TrimSpace(body.Text) == "" {
+		return 0, nil, badRequest("text is required")
+	}
+	author := body.Author
+	if author == "" {
+		author = serveActor(r)
+	}
+	id := r.PathValue("id")
+	ctx := r.Context()
+
+	a.d.mu.Lock()
+	defer a.d.mu.Unlock()
+	lock, err := acquireWriteLock(a.d.beadsDir, writeLockTimeout())
+	if err != nil {
+		return 0, nil, err
+	}
+	defer lock.Release()
+	if _, err := a.d.store.GetIssue(ctx, id); err != nil {
+		return 0, nil, err
+	}
+	comment, err := a.d.store.AddIssueComment(ctx, id, author, body.Text)
+	if err != nil {
+		return 0, nil, err
+	}
+	if err := maybeAutoCommitStore(ctx, a.d.store, doltAutoCommitParams{Command: "comment", IssueIDs: []string{id}}); err != nil {
+		return 0, nil, fmt.Errorf("dolt auto-commit failed: %w", err)
+	}
+	if err := maybeAutoExport(ctx, "comment", serverMode, false); err != nil {
+		debug.Logf("serve: auto-export: %v", err)
+	}
+	return http.StatusCreated, comment, nil
+}
+
+func (a *serveAPI) ready(r *http.Request) (int, interface{}, error) {
+	_, list, err := serveIssueFilter(r.URL.Query())
+	if err != nil {
+		return 0, nil, err
+	}
+	// Same defaults as 'bd ready'.
+	filter := types.WorkFilter{
+		Status:     "open",
+		Priority:   list.Priority,
+		Assignee:   list.Assignee,
+		Labels:     list.Labels,
+		Limit:      100,
+		SortPolicy: types.SortPolicyPriority,
+	}
+	if list.IssueType != nil {
+		filter.Type = string(*list.IssueType)
+	}
+	if r.URL.Query().Has("limit") {
+		filter.Limit = list.Limit
+	}
+	a.d.mu.RLock()
+	defer a.d.mu.RUnlock()
+	issues, err := getReadyWorkWithCounts(r.Context(), a.d.store, filter)
+	if err != nil {
+		return 0, nil, err
+	}
+	if issues == nil {
+		issues = []*types.IssueWithCounts{}
+	}
+	return http.StatusOK, issues, nil
+}
+
+func (a *serveAPI) blocked(r *http.Request) (int, interface{}, error) {
+	var filter types.WorkFilter
+	if parent := r.URL.Query().Get("parent"); parent != "" {
+		filter.ParentID = &parent
+	}
+	a.d.mu.RLock()
+	defer a.d.mu.RUnlock()
+	blocked, err := a.d.store.GetBlockedIssues(r.Context(), filter)
+	if err != nil {
+		return 0, nil, err
+	}
+	if blocked == nil {
+		blocked = []*types.BlockedIssue{}
+	}
+	return http.StatusOK, blocked, nil
+}
+
+func (a *serveAPI) stats(r *http.Request) (int, interface{}, error) {
+	a.d.mu.RLock()
+	defer a.d.mu.RUnlock()
+	stats, err := a.d.store.GetStatistics(r.Context())
+	if err != nil {
+		return 0, nil, err
+	}
+	return http.StatusOK, &StatusOutput{Summary: stats}, nil
+}
@@ -0,0 +1,159 @@
+package main
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestServeIssueFilter(t *testing.T) {
+	t.Parallel()
+	q, _ := url.ParseQuery("status=open,in_progress&priority=P1&type=task&assignee=alice&label=a&label=b&q=login&limit=5")
+	query, filter, err := serveIssueFilter(q)
+	if err != nil {
+		t.Fatalf("serveIssueFilter: %v", err)
+	}
+	if query != "login" || filter.Limit != 5 || len(filter.Statuses) != 2 || filter.Statuses[1] != types.StatusInProgress {
+		t.Errorf("query = %q, filter = %+v", query, filter)
+	}
+	if filter.Priority == nil || *filter.Priority != 1 || filter.Assignee == nil || *filter.Assignee != "alice" {
+		t.Errorf("priority/assignee = %v/%v", filter.Priority, filter.Assignee)
+	}
+	if len(filter.Labels) != 2 || filter.IssueType == nil || *filter.IssueType != types.TypeTask {
+		t.Errorf("labels/type = %v/%v", filter.Labels, filter.IssueType)
+	}
+
+	_, filter, _ = serveIssueFilter(url.Values{})
+	if filter.Limit != 50 || len(filter.ExcludeStatus) != 2 {
+		t.Errorf("default filter = %+v, want bd list defaults", filter)
+	}
+	_, filter, _ = serveIssueFilter(url.Values{"all": {"true"}})
+	if len(filter.ExcludeStatus) != 0 {
+		t.Errorf("all=true should not exclude statuses, got %v", filter.ExcludeStatus)
+	}
+	if _, _, err := serveIssueFilter(url.Values{"limit": {"-1"}}); err == nil {
+		t.Error("expected an error for a negative limit")
+	}
+}
+
+// serveDo sends a request to srv and decodes the JSON response. A body is
+// sent as application/json unless contentType says otherwise.
+func serveDo(t *testing.T, srv *httptest.Server, method, path, token, contentType, body string) (int, map[string]interface{}) {
+	t.Helper()
+	req, err := http.NewRequest(method, srv.URL+path, strings.NewReader(body))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if token != "" {
+		req.Header.Set("Authorization", "Bearer "+token)
+	}
+	if contentType == "" && body != "" {
+		contentType = "application/json"
+	}
+	if contentType != "" {
+		req.Header.Set("Content-Type", contentType)
+	}
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+	var out map[string]interface{}
+	_ = json.NewDecoder(resp.Body).Decode(&out)
+	return resp.StatusCode, out
+}
+
+func TestServeRoutes(t *testing.T) {
+	t.Parallel()
+	api := &serveAPI{d: &daemonServer{beadsDir: "/tmp/project/.beads"}, token: "secret"}
+	srv := httptest.NewServer(api.routes())
+	defer srv.Close()
+	do := func(method, path, token, body string) (int, map[string]interface{}) {
+		t.Helper()
+		return serveDo(t, srv, method, path, token, "", body)
+	}
+
+	if status, out := do("GET", "/issues", "", ""); status != http.StatusUnauthorized || out["error"] == nil {
+		t.Errorf("no token: %d %v", status, out)
+	}
+	if status, _ := do("GET", "/issues", "wrong", ""); status != http.StatusUnauthorized {
+		t.Errorf("wrong token: %d", status)
+	}
+	if status, out := do("GET", "/issues?priority=high", "secret", ""); status != http.StatusBadRequest || !strings.Contains(out["error"].(string), "priority") {
+		t.Errorf("bad priority: %d %v", status, out)
+	}
+	if status, _ := do("POST", "/issues/bd-1/comments", "secret", `{"text":"  "}`); status != http.StatusBadRequest {
+		t.Errorf("empty comment: %d", status)
+	}
+	if status, _ := do("POST", "/issues", "secret", `not json`); status != http.StatusBadRequest {
+		t.Errorf("invalid body: %d", status)
+	}
+	if status, _ := do("DELETE", "/issues/bd-1", "secret", ""); status != http.StatusMethodNotAllowed {
+		t.Errorf("DELETE: %d, want 405", status)
+	}
+	if status, _ := do("GET", "/metrics", "", ""); status != http.StatusUnauthorized {
+		t.Errorf("metrics without token: %d", status)
+	}
+	if status, _ := do("GET", "/nope", "secret", ""); status != http.StatusNotFound {
+		t.Errorf("unknown route: %d, want 404", status)
+	}
+}
+
+func TestServeRequiresJSONWrites(t *testing.T) {
+	t.Parallel()
+	api := &serveAPI{d: &daemonServer{beadsDir: "/tmp/project/.beads"}, token: "secret"}
+	srv := httptest.NewServer(api.routes())
+	defer srv.Close()
+
+	for _, tc := range []struct{ method, path, contentType, body string }{
+		{"POST", "/issues", "text/plain", `{"title":"x"}`},
+		{"POST", "/issues/bd-1/close", "", ""},
+		{"POST", "/issues/bd-1/comments", "application/x-www-form-urlencoded", "text=hi"},
+		{"PATCH", "/issues/bd-1", "multipart/form-data; boundary=x", "--x--"},
+	} {
+		status, out := serveDo(t, srv, tc.method, tc.path, "secret", tc.contentType, tc.body)
+		if status != http.StatusUnsupportedMediaType || out["error"] == nil {
+			t.Errorf("%s %s as %q: %d %v, want 415", tc.method, tc.path, tc.contentType, status, out)
+		}
+	}
+	// A charset parameter is fine; the body is then checked as usual.
+	if status, _ := serveDo(t, srv, "POST", "/issues", "secret", "application/json; charset=utf-8", "not json"); status != http.StatusBadRequest {
+		t.Errorf("JSON with charset: %d, want 400", status)
+	}
+}
+
+func TestServeRejectsNonLoopbackHost(t *testing.T) {
+	t.Parallel()
+	api := &serveAPI{d: &daemonServer{beadsDir: "/tmp/project/.beads"}, loopbackOnly: true}
+	srv := httptest.NewServer(api.routes())
+	defer srv.Close()
+
+	for host, want := range map[string]int{
+		"evil.example":      http.StatusForbidden,
+		"evil.example:7380": http.StatusForbidden,
+		"192.168.1.5:7380":  http.StatusForbidden,
+		"localhost:7380":    http.StatusNotFound,
+		"127.0.0.1":         http.StatusNotFound,
+		"[::1]:7380":        http.StatusNotFound,
+		"LOCALHOST":         http.StatusNotFound,
+	} {
+		req, err := http.NewRequest("GET", srv.URL+"/nope", nil)
+		if err != nil {
+			t.Fatal(err)
+		}
+		req.Host = host
+		resp, err := http.DefaultClient.Do(req)
+		if err != nil {
+			t.Fatal(err)
+		}
+		_ = resp.Body.Close()
+		if resp.StatusCode != want {
+			t.Errorf("Host %q: %d, want %d", host, resp.StatusCode, want)
+		}
+	}
+}
@@ -0,0 +1,311 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/storage/issueops"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// maxSessionArgsLen caps the command line kept for each command in a
+// session's log.
+const maxSessionArgsLen = 500
+
+// sessionIssue summarizes what a session did to one issue.
+type sessionIssue struct {
+	ID     string                  `json:"id"`
+	Title  string                  `json:"title,omitempty"`
+	Status types.Status            `json:"status,omitempty"`
+	Events map[types.EventType]int `json:"events"`
+	order  []types.EventType
+}
+
+// sessionLog is a session with the issues it touched, for 'bd session log'.
+type sessionLog struct {
+	*types.Session
+	Issues []*sessionIssue `json:"issues"`
+}
+
+// groupSessionEvents groups a session's events by issue, in the order the
+// issues were first touched.
+func groupSessionEvents(events []*types.Event) []*sessionIssue {
+	byID := make(map[string]*sessionIssue)
+	var issues []*sessionIssue
+	for _, e := range events {
+		si := byID[e.IssueID]
+		if si == nil {
+			si = &sessionIssue{ID: e.IssueID, Events: make(map[types.EventType]int)}
+			byID[e.IssueID] = si
+			issues = append(issues, si)
+		}
+		if si.Events[e.EventType] == 0 {
+			si.order = append(si.order, e.EventType)
+		}
+		si.Events[e.EventType]++
+	}
+	return issues
+}
+
+// describe lists the issue's event types in the order they first happened,
+// e.g. "created, status_changed×2".
+func (si *sessionIssue) describe() string {
+	parts := make([]string, len(si.order))
+	for i, t := range si.order {
+		parts[i] = string(t)
+		if n := si.Events[t]; n > 1 {
+			parts[i] += fmt.Sprintf("×%d", n)
+		}
+	}
+	return strings.Join(parts, ", ")
+}
+
+// sessionCommandLine renders a command line for the session log, quoting
+// arguments that would not survive a round trip through the shell.
+func sessionCommandLine(args []string) string {
+	quoted := make([]string, len(args))
+	for i, arg := range args {
+		if arg == "" || strings.ContainsAny(arg, " \t\n\"'\\$`") {
+			arg = strconv.Quote(arg)
+		}
+		quoted[i] = arg
+	}
+	return truncate(strings.Join(quoted, " "), maxSessionArgsLen)
+}
+
+// recordSessionCommand adds a write command to the actor's open session, if
+// any. It runs after the command, before the auto-commit, so the entry is
+// committed with the command's changes. Failures are only logged: a session
+// log must never fail the command it describes.
+func recordSessionCommand(ctx context.Context, cmd *cobra.Command) {
+	if store == nil || cmd == sessionCmd || cmd.Parent() == sessionCmd {
+		return
+	}
+	session, err := store.GetOpenSession(ctx, getActor())
+	if err != nil || session == nil {
+		if err != nil {
+			debug.Logf("session: %v", err)
+		}
+		return
+	}
+	entry := &types.SessionCommand{
+		SessionID: session.ID,
+		Command:   strings.TrimPrefix(cmd.CommandPath(), cmd.Root().Name()+" "),
+		Args:      sessionCommandLine(os.Args[1:]),
+	}
+	if err := store.RecordSessionCommand(ctx, entry); err != nil {
+		debug.Logf("session: %v", err)
+	}
+}
+
+var sessionCmd = &cobra.Command{
+	Use:     "session",
+	GroupID: "views",
+	Short:   "Record agent work sessions",
+	Long: `Record what an actor does to the tracker over a stretch of work, so a
+human can reconstruct what an unattended agent run did.
+
+While a session is open, every write command the actor runs is logged with
+its command line. The issues the session touched come from the events the
+actor made between start and end. Sessions are stored in the database, so
+they travel with 'bd dolt push' and 'bd dolt pull'.
+
+Examples:
+  bd session start --note "overnight triage run"
+  bd session end --note "closed 12, filed 3 follow-ups"
+  bd session list
+  bd session log                # the current or most recent session
+  bd session log ses-1a2b --json`,
+}
+
+var sessionStartCmd = &cobra.Command{
+	Use:   "start",
+	Short: "Open a work session for the current actor",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("session start")
+		note, _ := cmd.Flags().GetString("note")
+		session := &types.Session{Actor: actor, Note: note}
+		if err := store.StartSession(rootCtx, session); err != nil {
+			if errors.Is(err, issueops.ErrSessionOpen) {
+				FatalErrorRespectJSON("%v (end it with 'bd session end')", err)
+			}
+			FatalErrorRespectJSON("starting session: %v", err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(session)
+			return
+		}
+		fmt.Printf("%s Started session %s for %s\n", ui.RenderPass("✓"), ui.RenderID(session.ID), actor)
+	},
+}
+
+var sessionEndCmd = &cobra.Command{
+	Use:   "end",
+	Short: "Close the current actor's work session and summarize it",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("session end")
+		note, _ := cmd.Flags().GetString("note")
+		session, err := store.EndSession(rootCtx, actor, note)
+		if err != nil {
+			FatalErrorRespectJSON("ending session: %v", err)
+		}
+		if session == nil {
+			FatalErrorRespectJSON("%s has no open session (start one with 'bd session start')", actor)
+		}
+		commandDidWrite.Store(true)
+		showSessionLog(rootCtx, session.ID)
+	},
+}
+
+var sessionListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List work sessions, newest first",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		by, _ := cmd.Flags().GetString("actor")
+		limit, _ := cmd.Flags().GetInt("limit")
+		sessions, err := store.ListSessions(rootCtx, by, limit)
+		if err != nil {
+			FatalErrorRespectJSON("listing sessions: %v", err)
+		}
+		if jsonOutput {
+			if sessions == nil {
+				sessions = []*types.Session{}
+			}
+			outputJSON(sessions)
+			return
+		}
+		if len(sessions) == 0 {
+			fmt.Println("No sessions recorded. Start one with: bd session start")
+			return
+		}
+		for _, s := range sessions {
+			fmt.Printf("%s  %-20s %s  %s\n", ui.RenderID(s.ID), s.Actor,
+				s.StartedAt.Local().Format("2006-01-02 15:04"), ui.RenderMuted(sessionSpan(s)))
+		}
+	},
+}
+
+var sessionLogCmd = &cobra.Command{
+	Use:   "log [session-id]",
+	Short: "Summarize a session: commands run and issues touched",
+	Long: `Summarize a work session: when it ran, the write commands run during it,
+and the issues it touched with what happened to each.
+
+With no ID, shows the current actor's open session, or else their most
+recent one. IDs may be abbreviated to any unique prefix.`,
+	Args: cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		if len(args) > 0 {
+			showSessionLog(ctx, args[0])
+			return
+		}
+		sessions, err := store.ListSessions(ctx, actor, 1)
+		if err != nil {
+			FatalErrorRespectJSON("listing sessions: %v", err)
+		}
+		if len(sessions) == 0 {
+			FatalErrorRespectJSON("%s has no sessions (start one with 'bd session start')", actor)
+		}
+		showSessionLog(ctx, sessions[0].ID)
+	},
+}
+
+// sessionSpan describes how long a session ran, or that it is still open.
+func sessionSpan(s *types.Session) string {
+	if s.EndedAt == nil {
+		return "open"
+	}
+	return formatMinutes(int(s.EndedAt.Sub(s.StartedAt).Round(time.Minute) / time.Minute))
+}
+
+// showSessionLog prints the summary of session id.
+func showSessionLog(ctx context.Context, id string) {
+	session, err := store.GetSession(ctx, id)
+	if err != nil {
+		FatalErrorRespectJSON("loading session %s: %v", id, err)
+	}
+	if session == nil {
+		FatalErrorRespectJSON("no session %q (see 'bd session list')", id)
+	}
+	events, err := store.GetSessionEvents(ctx, session)
+	if err != nil {
+		FatalErrorRespectJSON("loading session %s: %v", id, err)
+	}
+	result := &sessionLog{Session: session, Issues: groupSessionEvents(events)}
+	ids := make([]string, len(result.Issues))
+	for i, si := range result.Issues {
+		ids[i] = si.ID
+	}
+	issues, err := store.GetIssuesByIDs(ctx, ids)
+	if err != nil {
+		FatalErrorRespectJSON("loading session issues: %v", err)
+	}
+	byID := make(map[string]*types.Issue, len(issues))
+	for _, issue := range issues {
+		byID[issue.ID] = issue
+	}
+	for _, si := range result.Issues {
+		if issue := byID[si.ID]; issue != nil {
+			si.Title, si.Status = issue.Title, issue.Status
+		}
+	}
+
+	if jsonOutput {
+		if result.Issues == nil {
+			result.Issues = []*sessionIssue{}
+		}
+		outputJSON(result)
+		return
+	}
+
+	fmt.Printf("%s %s by %s\n", ui.RenderBold("Session"), ui.RenderID(session.ID), session.Actor)
+	fmt.Printf("  Started: %s\n", session.StartedAt.Local().Format("2006-01-02 15:04"))
+	if session.EndedAt != nil {
+		fmt.Printf("  Ended:   %s (%s)\n", session.EndedAt.Local().Format("2006-01-02 15:04"), sessionSpan(session))
+	} else {
+		fmt.Printf("  Ended:   %s\n", ui.RenderWarn("still open"))
+	}
+	if session.Note != "" {
+		fmt.Printf("  Note:    %s\n", strings.ReplaceAll(session.Note, "\n", "\n           "))
+	}
+
+	fmt.Printf("\n%s (%d)\n", ui.RenderBold("Commands"), len(session.Commands))
+	for _, c := range session.Commands {
+		fmt.Printf("  %s  bd %s\n", ui.RenderMuted(c.CreatedAt.Local().Format("15:04:05")), c.Args)
+	}
+	fmt.Printf("\n%s (%d)\n", ui.RenderBold("Issues touched"), len(result.Issues))
+	for _, si := range result.Issues {
+		title := si.Title
+		if title == "" {
+			title = ui.RenderMuted("(deleted)")
+		}
+		fmt.Printf("  %s %s\n      %s\n", ui.RenderID(si.ID), title, ui.RenderMuted(si.describe()))
+	}
+}
+
+func init() {
+	sessionStartCmd.Flags().StringP("note", "n", "", "What the session is for")
+	sessionEndCmd.Flags().StringP("note", "n", "", "How the session went (appended to the start note)")
+	sessionListCmd.Flags().String("actor", "", "Only list this actor's sessions")
+	sessionListCmd.Flags().IntP("limit", "n", 20, "Maximum sessions to show (0 = all)")
+
+	sessionCmd.AddCommand(sessionStartCmd)
+	sessionCmd.AddCommand(sessionEndCmd)
+	sessionCmd.AddCommand(sessionListCmd)
+	sessionCmd.AddCommand(sessionLogCmd)
+	rootCmd.AddCommand(sessionCmd)
+}
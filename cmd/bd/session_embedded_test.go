@@ -0,0 +1,67 @@
+//go:build cgo
+
+package main
+
+import (
+	"bytes"
+	"encoding/json"
+	"os"
+	"os/exec"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestEmbeddedSession(t *testing.T) {
+	if os.Getenv("BEADS_TEST_EMBEDDED_DOLT") != "1" {
+		t.Skip("set BEADS_TEST_EMBEDDED_DOLT=1 to run embedded dolt integration tests")
+	}
+	t.Parallel()
+
+	bd := buildEmbeddedBD(t)
+	dir, _, _ := bdInit(t, bd, "--prefix", "se")
+
+	run := func(t *testing.T, args ...string) string {
+		t.Helper()
+		cmd := exec.Command(bd, args...)
+		cmd.Dir = dir
+		cmd.Env = append(bdEnv(dir), "BEADS_ACTOR=night-agent")
+		stdout, stderr, err := runCommandBuffers(t, cmd)
+		if err != nil {
+			t.Fatalf("bd %s failed: %v\nstdout:\n%s\nstderr:\n%s", strings.Join(args, " "), err, stdout.String(), stderr.String())
+		}
+		return stdout.String()
+	}
+
+	t.Run("session_records_commands_and_issues", func(t *testing.T) {
+		run(t, "session", "start", "--note", "overnight run")
+		var created types.Issue
+		if err := json.Unmarshal(bytes.TrimSpace([]byte(run(t, "create", "Session work", "--json"))), &created); err != nil {
+			t.Fatalf("parse create JSON: %v", err)
+		}
+		run(t, "update", created.ID, "--status", "in_progress")
+		run(t, "list")
+
+		var summary sessionLog
+		if err := json.Unmarshal(bytes.TrimSpace([]byte(run(t, "session", "end", "--note", "done", "--json"))), &summary); err != nil {
+			t.Fatalf("parse session end JSON: %v", err)
+		}
+		if summary.Session == nil || summary.EndedAt == nil || summary.Actor != "night-agent" || summary.Note != "overnight run\ndone" {
+			t.Fatalf("session = %+v", summary.Session)
+		}
+		if len(summary.Commands) != 2 || summary.Commands[0].Command != "create" || summary.Commands[1].Command != "update" {
+			t.Fatalf("commands = %+v, want create and update only", summary.Commands)
+		}
+		if len(summary.Issues) != 1 || summary.Issues[0].ID != created.ID || summary.Issues[0].Events[types.EventCreated] != 1 {
+			t.Fatalf("issues = %+v", summary.Issues)
+		}
+
+		cmd := exec.Command(bd, "session", "end")
+		cmd.Dir = dir
+		cmd.Env = append(bdEnv(dir), "BEADS_ACTOR=night-agent")
+		if out, err := cmd.CombinedOutput(); err == nil {
+			t.Fatalf("expected ending a closed session to fail:\n%s", out)
+		}
+	})
+}
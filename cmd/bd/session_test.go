@@ -0,0 +1,37 @@
+package main
+
+import (
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestGroupSessionEvents(t *testing.T) {
+	events := []*types.Event{
+		{IssueID: "bd-2", EventType: types.EventCreated},
+		{IssueID: "bd-1", EventType: types.EventStatusChanged},
+		{IssueID: "bd-2", EventType: types.EventCommented},
+		{IssueID: "bd-1", EventType: types.EventCommented},
+		{IssueID: "bd-1", EventType: types.EventStatusChanged},
+	}
+	issues := groupSessionEvents(events)
+	if len(issues) != 2 || issues[0].ID != "bd-2" || issues[1].ID != "bd-1" {
+		t.Fatalf("groupSessionEvents order = %+v", issues)
+	}
+	if got, want := issues[0].describe(), "created, commented"; got != want {
+		t.Errorf("bd-2 describe() = %q, want %q", got, want)
+	}
+	if got, want := issues[1].describe(), "status_changed×2, commented"; got != want {
+		t.Errorf("bd-1 describe() = %q, want %q", got, want)
+	}
+	if issues[1].Events[types.EventStatusChanged] != 2 {
+		t.Errorf("bd-1 events = %v", issues[1].Events)
+	}
+}
+
+func TestSessionCommandLine(t *testing.T) {
+	got := sessionCommandLine([]string{"update", "bd-1", "--notes", "it's done", "--status=closed", ""})
+	if want := `update bd-1 --notes "it's done" --status=closed ""`; got != want {
+		t.Errorf("sessionCommandLine = %s, want %s", got, want)
+	}
+}
@@ -0,0 +1,76 @@
+package main
+
+import (
+	"cmp"
+	"fmt"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+// parseSeverityFlag parses a --severity value for an issue of issueType,
+// which must be a bug unless the value is empty.
+func parseSeverityFlag(value, issueType string) (types.Severity, error) {
+	if value == "" {
+		return "", nil
+	}
+	severity, err := types.ParseSeverity(value)
+	if err != nil {
+		return "", err
+	}
+	if utils.NormalizeIssueType(issueType) != string(types.TypeBug) {
+		return "", fmt.Errorf("--severity applies only to bugs (use --type bug)")
+	}
+	return severity, nil
+}
+
+// parseSeverityList parses a comma-separated --severity filter.
+func parseSeverityList(value string) ([]types.Severity, error) {
+	var severities []types.Severity
+	for _, part := range strings.Split(value, ",") {
+		if strings.TrimSpace(part) == "" {
+			continue
+		}
+		severity, err := types.ParseSeverity(part)
+		if err != nil {
+			return nil, err
+		}
+		severities = append(severities, severity)
+	}
+	return severities, nil
+}
+
+// compareTriage orders issues for triage: by severity (S0 first, issues
+// without one last), then priority.
+func compareTriage(a, b *types.Issue) int {
+	if c := cmp.Compare(a.Severity.Rank(), b.Severity.Rank()); c != 0 {
+		return c
+	}
+	return cmp.Compare(a.Priority, b.Priority)
+}
+
+// checkSeverityUpdate rejects setting a severity on an issue that will not
+// be a bug once updates apply.
+func checkSeverityUpdate(issue *types.Issue, updates map[string]interface{}) error {
+	severity, _ := updates["severity"].(string)
+	if severity == "" {
+		return nil
+	}
+	issueType := string(issue.IssueType)
+	if t, ok := updates["issue_type"].(string); ok {
+		issueType = t
+	}
+	if issueType != string(types.TypeBug) {
+		return fmt.Errorf("--severity applies only to bugs (%s is a %s)", issue.ID, issueType)
+	}
+	return nil
+}
+
+// severityTag returns " [S1]" for a bug with a severity, for list lines.
+func severityTag(issue *types.Issue) string {
+	if issue.Severity == "" {
+		return ""
+	}
+	return fmt.Sprintf(" [%s]", issue.Severity)
+}
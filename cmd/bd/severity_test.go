@@ -0,0 +1,53 @@
+package main
+
+import (
+	"slices"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseSeverityFlag(t *testing.T) {
+	if got, err := parseSeverityFlag("s1", "bug"); err != nil || got != types.SeverityS1 {
+		t.Errorf("parseSeverityFlag(s1, bug) = %q, %v", got, err)
+	}
+	if got, err := parseSeverityFlag("", "task"); err != nil || got != "" {
+		t.Errorf("parseSeverityFlag(\"\", task) = %q, %v", got, err)
+	}
+	if _, err := parseSeverityFlag("S1", "task"); err == nil {
+		t.Error("parseSeverityFlag(S1, task) succeeded, want bugs-only error")
+	}
+	if _, err := parseSeverityFlag("S9", "bug"); err == nil {
+		t.Error("parseSeverityFlag(S9, bug) succeeded, want invalid severity error")
+	}
+}
+
+func TestSortIssuesTriage(t *testing.T) {
+	issues := []*types.Issue{
+		{ID: "bd-1", Priority: 0},
+		{ID: "bd-2", Priority: 3, Severity: types.SeverityS1},
+		{ID: "bd-3", Priority: 1, Severity: types.SeverityS1},
+		{ID: "bd-4", Priority: 4, Severity: types.SeverityS0},
+	}
+	sortIssues(issues, "triage", false)
+	var got []string
+	for _, issue := range issues {
+		got = append(got, issue.ID)
+	}
+	if want := []string{"bd-4", "bd-3", "bd-2", "bd-1"}; !slices.Equal(got, want) {
+		t.Errorf("triage order = %v, want %v", got, want)
+	}
+}
+
+func TestCheckSeverityUpdate(t *testing.T) {
+	task := &types.Issue{ID: "bd-1", IssueType: types.TypeTask}
+	if err := checkSeverityUpdate(task, map[string]interface{}{"severity": "S2"}); err == nil {
+		t.Error("severity on a task accepted")
+	}
+	if err := checkSeverityUpdate(task, map[string]interface{}{"severity": "S2", "issue_type": "bug"}); err != nil {
+		t.Errorf("severity with --type bug rejected: %v", err)
+	}
+	if err := checkSeverityUpdate(task, map[string]interface{}{"severity": ""}); err != nil {
+		t.Errorf("clearing severity rejected: %v", err)
+	}
+}
@@ -8,6 +8,7 @@ import (
 	"time"
 
 	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/ui"
 	"github.com/steveyegge/beads/internal/uimd"
@@ -15,7 +16,6 @@ import (
 
 var showCmd = &cobra.Command{
 	Use:     "show [id...] [--id=<id>...] [--current]",
-	Aliases: []string{"view"},
 	GroupID: "issues",
 	Short:   "Show issue details",
 	Args:    cobra.ArbitraryArgs, // Allow zero positional args when --id is used
@@ -32,6 +32,7 @@ var showCmd = &cobra.Command{
 		currentMode, _ := cmd.Flags().GetBool("current")
 		includeDepends, _ := cmd.Flags().GetBool("include-dependents")
 		includeComments, _ := cmd.Flags().GetBool("include-comments")
+		showCommits, _ := cmd.Flags().GetBool("commits")
 		ctx := rootCtx
 
 		// Helper to format timestamp based on --local-time flag
@@ -145,17 +146,7 @@ var showCmd = &cobra.Command{
 			if jsonOutput {
 				// be-ijck6q: default is count-only (no dependents/comments slice in output).
 				// Use --include-dependents / --include-comments to stream the full lists.
-				details := &types.IssueDetails{Issue: *issue}
-				details.Labels, _ = issueStore.GetLabels(ctx, issue.ID)
-				details.Dependencies, _ = issueStore.GetDependenciesWithMetadata(ctx, issue.ID)
-
-				// Aggregate counts — O(1) queries, no row materialization.
-				depCount, _ := issueStore.CountDependents(ctx, issue.ID)
-				details.DependentCount = &depCount
-				depnCount, _ := issueStore.CountDependencies(ctx, issue.ID)
-				details.DependencyCount = &depnCount
-				cmtCount, _ := issueStore.CountIssueComments(ctx, issue.ID)
-				details.CommentCount = &cmtCount
+				details := showIssueDetails(ctx, issueStore, issue)
 
 				// --include-dependents: stream via Iter, shallow-copy each item.
 				// May be slow on hub beads with many dependents.
@@ -211,15 +202,21 @@ var showCmd = &cobra.Command{
 						}
 					}
 				}
-
-				// Compute parent from dependencies.
-				for _, dep := range details.Dependencies {
-					if dep.DependencyType == types.DepParentChild {
-						details.Parent = &dep.ID
-						break
+				if showCommits {
+					history, err := issueCommitHistory(issue)
+					if err != nil {
+						WarnError("could not read commit history: %v", err)
+					}
+					if history == nil {
+						history = []issueHistoryCommit{}
 					}
+					allDetails = append(allDetails, struct {
+						*types.IssueDetails
+						CommitHistory []issueHistoryCommit `json:"commit_history"`
+					}{details, history})
+				} else {
+					allDetails = append(allDetails, details)
 				}
-				allDetails = append(allDetails, details)
 				result.Close()
 				continue
 			}
@@ -231,7 +228,11 @@ var showCmd = &cobra.Command{
 			}
 
 			// Metadata: Owner · Type | Created · Updated
+			loadCoAssignees(ctx, issueStore, issue)
 			fmt.Println(formatIssueMetadata(issue))
+			if lease := currentClaimLease(ctx, issueStore, issue); lease != nil {
+				fmt.Println(ui.RenderMuted(formatClaimLease(lease, time.Now())))
+			}
 
 			// Compaction info (if applicable)
 			if issue.CompactionLevel > 0 {
@@ -261,7 +262,7 @@ var showCmd = &cobra.Command{
 				fmt.Printf("\n%s\n%s\n", ui.RenderBold("NOTES"), uimd.RenderMarkdown(issue.Notes))
 			}
 			if issue.AcceptanceCriteria != "" {
-				fmt.Printf("\n%s\n%s\n", ui.RenderBold("ACCEPTANCE CRITERIA"), uimd.RenderMarkdown(issue.AcceptanceCriteria))
+				fmt.Printf("\n%s\n%s\n", ui.RenderBold(acceptanceCriteriaHeader(issue)), uimd.RenderMarkdown(issue.AcceptanceCriteria))
 			}
 
 			// Show labels
@@ -270,6 +271,20 @@ var showCmd = &cobra.Command{
 				fmt.Printf("\n%s %s\n", ui.RenderBold("LABELS:"), strings.Join(labels, ", "))
 			}
 
+			if showCommits {
+				history, err := issueCommitHistory(issue)
+				if err != nil {
+					WarnError("could not read commit history: %v", err)
+				}
+				fmt.Printf("\n%s\n", formatIssueCommitHistory(history))
+			} else if commitsStr := formatIssueCommits(issue); commitsStr != "" {
+				fmt.Printf("\n%s\n", commitsStr)
+			}
+
+			if attachmentsStr := formatIssueAttachments(issue); attachmentsStr != "" {
+				fmt.Printf("\n%s\n", attachmentsStr)
+			}
+
 			// Show custom metadata (GH#1406)
 			if metaStr := formatIssueCustomMetadata(issue); metaStr != "" {
 				fmt.Printf("\n%s\n", metaStr)
@@ -284,7 +299,7 @@ var showCmd = &cobra.Command{
 
 			if len(depsWithMeta) > 0 {
 				// Group by dependency type
-				var blocks, parent, discovered []*types.IssueWithDependencyMetadata
+				var blocks, parent, discovered, semantic []*types.IssueWithDependencyMetadata
 				for _, dep := range depsWithMeta {
 					switch dep.DependencyType {
 					case types.DepBlocks:
@@ -293,6 +308,8 @@ var showCmd = &cobra.Command{
 						parent = append(parent, dep)
 					case types.DepRelated, types.DepRelatesTo:
 						relatedSeen[dep.ID] = dep
+					case types.DepDuplicates, types.DepSupersedes:
+						semantic = append(semantic, dep)
 					case types.DepDiscoveredFrom:
 						discovered = append(discovered, dep)
 					default:
@@ -318,13 +335,14 @@ var showCmd = &cobra.Command{
 						fmt.Println(formatDependencyLine("◊", dep))
 					}
 				}
+				printSemanticLinks(semantic, true)
 			}
 
 			// Show dependents - grouped by dependency type for clarity
 			dependentsWithMeta, _ := issueStore.GetDependentsWithMetadata(ctx, issue.ID) // Best effort: show issue even if dependents unavailable
 			if len(dependentsWithMeta) > 0 {
 				// Group by dependency type
-				var blocks, children, discovered []*types.IssueWithDependencyMetadata
+				var blocks, children, discovered, semantic []*types.IssueWithDependencyMetadata
 				for _, dep := range dependentsWithMeta {
 					switch dep.DependencyType {
 					case types.DepBlocks:
@@ -333,6 +351,8 @@ var showCmd = &cobra.Command{
 						children = append(children, dep)
 					case types.DepRelated, types.DepRelatesTo:
 						relatedSeen[dep.ID] = dep
+					case types.DepDuplicates, types.DepSupersedes:
+						semantic = append(semantic, dep)
 					case types.DepDiscoveredFrom:
 						discovered = append(discovered, dep)
 					default:
@@ -376,6 +396,7 @@ var showCmd = &cobra.Command{
 						fmt.Println(formatDependencyLine("◊", dep))
 					}
 				}
+				printSemanticLinks(semantic, false)
 			}
 
 			// Print deduplicated RELATED section (bidirectional links shown once)
@@ -386,12 +407,17 @@ var showCmd = &cobra.Command{
 				}
 			}
 
+			printCodeRefs(ctx, issueStore, issue.ID)
+			printWorkLogSummary(ctx, issueStore, issue)
+			printTodoChecklist(ctx, issueStore, issue.ID)
+
 			// Show comments
 			comments, _ := issueStore.GetIssueComments(ctx, issue.ID) // Best effort: show issue even if comments unavailable
 			if len(comments) > 0 {
 				fmt.Printf("\n%s\n", ui.RenderBold("COMMENTS"))
 				for _, comment := range comments {
-					fmt.Printf("  %s %s\n", ui.RenderMuted(formatTime(comment.CreatedAt)), comment.Author)
+					fmt.Printf("  %s %s %s\n", ui.RenderMuted(formatTime(comment.CreatedAt)), comment.Author,
+						ui.RenderMuted(types.ShortCommentID(comment.ID)))
 					rendered := uimd.RenderMarkdown(comment.Text)
 					// TrimRight removes trailing newlines that Glamour adds, preventing extra blank lines
 					for _, line := range strings.Split(strings.TrimRight(rendered, "\n"), "\n") {
@@ -432,6 +458,46 @@ var showCmd = &cobra.Command{
 	},
 }
 
+// showIssueDetails builds the default 'bd show --json' record for issue:
+// its labels, co-assignees, claim lease, dependencies, code references,
+// work log, checklist items, and parent, and
+// counts of its dependents, dependencies, and comments.
+func showIssueDetails(ctx context.Context, s storage.DoltStorage, issue *types.Issue) *types.IssueDetails {
+	details := &types.IssueDetails{Issue: *issue}
+	details.Labels, _ = s.GetLabels(ctx, issue.ID)
+	details.Dependencies, _ = s.GetDependenciesWithMetadata(ctx, issue.ID)
+	if refs, err := s.GetCodeRefsForIssues(ctx, []string{issue.ID}); err == nil {
+		details.CodeRefs = refs[issue.ID]
+	}
+	if entries, err := s.GetWorkLogForIssues(ctx, []string{issue.ID}); err == nil {
+		details.WorkLog = entries[issue.ID]
+	}
+	if todos, err := s.GetTodosForIssues(ctx, []string{issue.ID}); err == nil {
+		details.Todos = todos[issue.ID]
+	}
+	if assignees, err := s.GetAssigneesForIssues(ctx, []string{issue.ID}); err == nil {
+		details.Assignees = assignees[issue.ID]
+	}
+	details.ClaimLease = currentClaimLease(ctx, s, issue)
+
+	// Aggregate counts — O(1) queries, no row materialization.
+	depCount, _ := s.CountDependents(ctx, issue.ID)
+	details.DependentCount = &depCount
+	depnCount, _ := s.CountDependencies(ctx, issue.ID)
+	details.DependencyCount = &depnCount
+	cmtCount, _ := s.CountIssueComments(ctx, issue.ID)
+	details.CommentCount = &cmtCount
+
+	// Compute parent from dependencies.
+	for _, dep := range details.Dependencies {
+		if dep.DependencyType == types.DepParentChild {
+			details.Parent = &dep.ID
+			break
+		}
+	}
+	return details
+}
+
 // shallowDependentsForJSON returns a copy of raw with each embedded Issue
 // stripped down to identity-and-shape fields (ID, Status, IssueType, Priority,
 // Title). The heavy fields (Description, Design, Notes, AcceptanceCriteria,
@@ -478,6 +544,7 @@ func init() {
 	showCmd.Flags().BoolP("watch", "w", false, "Watch for changes and auto-refresh display")
 	showCmd.Flags().Bool("current", false, "Show the currently active issue (in-progress, hooked, or last touched)")
 	showCmd.Flags().Bool("include-dependents", false, "Stream full dependent issues in JSON output (--json only; may be slow on hub beads)")
+	showCmd.Flags().Bool("commits", false, "Show commits linked to the issue or mentioning its ID (via git log --grep)")
 	showCmd.Flags().Bool("include-comments", false, "Stream full comment bodies in JSON output (--json only; may be slow on issues with many comments)")
 	showCmd.ValidArgsFunction = issueIDCompletion
 	rootCmd.AddCommand(showCmd)
@@ -99,7 +99,11 @@ func displayShowIssueReturn(ctx context.Context, issueID string) *types.Issue {
 
 	// Display the issue header and metadata
 	fmt.Println(formatIssueHeader(issue))
+	loadCoAssignees(ctx, issueStore, issue)
 	fmt.Println(formatIssueMetadata(issue))
+	if lease := currentClaimLease(ctx, issueStore, issue); lease != nil {
+		fmt.Println(ui.RenderMuted(formatClaimLease(lease, time.Now())))
+	}
 
 	// Content sections (matches standard bd show order)
 	if issue.Description != "" {
@@ -112,7 +116,7 @@ func displayShowIssueReturn(ctx context.Context, issueID string) *types.Issue {
 		fmt.Printf("\n%s\n%s\n", ui.RenderBold("NOTES"), uimd.RenderMarkdown(issue.Notes))
 	}
 	if issue.AcceptanceCriteria != "" {
-		fmt.Printf("\n%s\n%s\n", ui.RenderBold("ACCEPTANCE CRITERIA"), uimd.RenderMarkdown(issue.AcceptanceCriteria))
+		fmt.Printf("\n%s\n%s\n", ui.RenderBold(acceptanceCriteriaHeader(issue)), uimd.RenderMarkdown(issue.AcceptanceCriteria))
 	}
 
 	// Labels
@@ -126,7 +130,7 @@ func displayShowIssueReturn(ctx context.Context, issueID string) *types.Issue {
 	depsWithMeta, _ := issueStore.GetDependenciesWithMetadata(ctx, issue.ID)
 
 	if len(depsWithMeta) > 0 {
-		var blocks, parent, discovered []*types.IssueWithDependencyMetadata
+		var blocks, parent, discovered, semantic []*types.IssueWithDependencyMetadata
 		for _, dep := range depsWithMeta {
 			switch dep.DependencyType {
 			case types.DepBlocks:
@@ -135,6 +139,8 @@ func displayShowIssueReturn(ctx context.Context, issueID string) *types.Issue {
 				parent = append(parent, dep)
 			case types.DepRelated, types.DepRelatesTo:
 				relatedSeen[dep.ID] = dep
+			case types.DepDuplicates, types.DepSupersedes:
+				semantic = append(semantic, dep)
 			case types.DepDiscoveredFrom:
 				discovered = append(discovered, dep)
 			default:
@@ -159,12 +165,13 @@ func displayShowIssueReturn(ctx context.Context, issueID string) *types.Issue {
 				fmt.Println(formatDependencyLine("◊", dep))
 			}
 		}
+		printSemanticLinks(semantic, true)
 	}
 
 	// Dependents (what depends on this issue)
 	dependentsWithMeta, _ := issueStore.GetDependentsWithMetadata(ctx, issue.ID)
 	if len(dependentsWithMeta) > 0 {
-		var blocks, children, discovered []*types.IssueWithDependencyMetadata
+		var blocks, children, discovered, semantic []*types.IssueWithDependencyMetadata
 		for _, dep := range dependentsWithMeta {
 			switch dep.DependencyType {
 			case types.DepBlocks:
@@ -173,6 +180,8 @@ func displayShowIssueReturn(ctx context.Context, issueID string) *types.Issue {
 				children = append(children, dep)
 			case types.DepRelated, types.DepRelatesTo:
 				relatedSeen[dep.ID] = dep
+			case types.DepDuplicates, types.DepSupersedes:
+				semantic = append(semantic, dep)
 			case types.DepDiscoveredFrom:
 				discovered = append(discovered, dep)
 			default:
@@ -197,6 +206,7 @@ func displayShowIssueReturn(ctx context.Context, issueID string) *types.Issue {
 				fmt.Println(formatDependencyLine("◊", dep))
 			}
 		}
+		printSemanticLinks(semantic, false)
 	}
 
 	// Related (bidirectional, deduplicated)
@@ -207,12 +217,17 @@ func displayShowIssueReturn(ctx context.Context, issueID string) *types.Issue {
 		}
 	}
 
+	printCodeRefs(ctx, issueStore, issue.ID)
+	printWorkLogSummary(ctx, issueStore, issue)
+	printTodoChecklist(ctx, issueStore, issue.ID)
+
 	// Comments
 	comments, _ := issueStore.GetIssueComments(ctx, issue.ID)
 	if len(comments) > 0 {
 		fmt.Printf("\n%s\n", ui.RenderBold("COMMENTS"))
 		for _, comment := range comments {
-			fmt.Printf("  %s %s\n", ui.RenderMuted(comment.CreatedAt.UTC().Format("2006-01-02 15:04")), comment.Author)
+			fmt.Printf("  %s %s %s\n", ui.RenderMuted(comment.CreatedAt.UTC().Format("2006-01-02 15:04")), comment.Author,
+				ui.RenderMuted(types.ShortCommentID(comment.ID)))
 			rendered := uimd.RenderMarkdown(comment.Text)
 			for _, line := range strings.Split(strings.TrimRight(rendered, "\n"), "\n") {
 				fmt.Printf("    %s\n", line)
@@ -87,7 +87,9 @@ func formatIssueMetadata(issue *types.Issue) string {
 	if issue.CreatedBy != "" {
 		metaParts = append(metaParts, fmt.Sprintf("Owner: %s", issue.CreatedBy))
 	}
-	if issue.Assignee != "" {
+	if len(issue.Assignees) > 0 {
+		metaParts = append(metaParts, fmt.Sprintf("Assignees: %s", strings.Join(allAssignees(issue), ", ")))
+	} else if issue.Assignee != "" {
 		metaParts = append(metaParts, fmt.Sprintf("Assignee: %s", issue.Assignee))
 	}
 
@@ -100,6 +102,9 @@ func formatIssueMetadata(issue *types.Issue) string {
 		typeStr = ui.TypeBugStyle.Render("bug")
 	}
 	metaParts = append(metaParts, fmt.Sprintf("Type: %s", typeStr))
+	if issue.Severity != "" {
+		metaParts = append(metaParts, fmt.Sprintf("Severity: %s", issue.Severity))
+	}
 
 	if len(metaParts) > 0 {
 		lines = append(lines, strings.Join(metaParts, " · "))
@@ -197,6 +202,40 @@ func formatSimpleDependencyLine(prefix string, dep *types.Issue) string {
 	return fmt.Sprintf("  %s %s %s: %s %s", prefix, statusIcon, idStr, dep.Title, priorityTag)
 }
 
+// semanticLinkSections names the bd show sections for the non-blocking
+// duplicates and supersedes links, seen from either end of the edge.
+var semanticLinkSections = []struct {
+	depType            types.DependencyType
+	outgoing, incoming string
+	prefix             string
+}{
+	{types.DepDuplicates, "DUPLICATE OF", "DUPLICATES", "≡"},
+	{types.DepSupersedes, "SUPERSEDED BY", "SUPERSEDES", "⇒"},
+}
+
+// printSemanticLinks prints the duplicates/supersedes sections of bd show.
+// outgoing is true for the issue's own edges and false for edges pointing
+// at it.
+func printSemanticLinks(deps []*types.IssueWithDependencyMetadata, outgoing bool) {
+	for _, section := range semanticLinkSections {
+		header := section.incoming
+		if outgoing {
+			header = section.outgoing
+		}
+		printed := false
+		for _, dep := range deps {
+			if dep.DependencyType != section.depType {
+				continue
+			}
+			if !printed {
+				fmt.Printf("\n%s\n", ui.RenderBold(header))
+				printed = true
+			}
+			fmt.Println(formatDependencyLine(section.prefix, dep))
+		}
+	}
+}
+
 // formatIssueCustomMetadata renders the issue's custom JSON metadata field
 // for bd show output. Returns empty string if no metadata is set.
 // Top-level keys are displayed sorted alphabetically, one per line.
@@ -216,6 +255,12 @@ func formatIssueCustomMetadata(issue *types.Issue) string {
 		// Not a JSON object — show raw value
 		return fmt.Sprintf("%s\n  %s", ui.RenderBold("METADATA"), trimmed)
 	}
+	if len(issueCommits(issue)) > 0 {
+		delete(data, issueCommitsKey) // shown under COMMITS
+	}
+	if len(issueAttachments(issue)) > 0 {
+		delete(data, issueAttachmentsKey) // shown under ATTACHMENTS
+	}
 	if len(data) == 0 {
 		return ""
 	}
@@ -3,7 +3,6 @@ package main
 import (
 	"context"
 
-	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/validation"
 )
@@ -27,7 +26,24 @@ func validateIssueClosable(id string, issue *types.Issue, force bool) error {
 	)(id, issue)
 }
 
-func applyLabelUpdates(ctx context.Context, st storage.DoltStorage, issueID, actor string, setLabels, addLabels, removeLabels []string) error {
+// issueWriteStore is what the shared create, update, and close helpers
+// write to: the store itself, or a transaction for the items of a daemon
+// batch.
+type issueWriteStore interface {
+	CreateIssue(ctx context.Context, issue *types.Issue, actor string) error
+	UpdateIssue(ctx context.Context, id string, updates map[string]interface{}, actor string) error
+	CloseIssue(ctx context.Context, id string, reason string, actor string, session string) error
+	GetIssue(ctx context.Context, id string) (*types.Issue, error)
+	GetConfig(ctx context.Context, key string) (string, error)
+	AddDependency(ctx context.Context, dep *types.Dependency, actor string) error
+	RemoveDependency(ctx context.Context, issueID, dependsOnID string, actor string) error
+	GetDependencyRecords(ctx context.Context, issueID string) ([]*types.Dependency, error)
+	AddLabel(ctx context.Context, issueID, label, actor string) error
+	RemoveLabel(ctx context.Context, issueID, label, actor string) error
+	GetLabels(ctx context.Context, issueID string) ([]string, error)
+}
+
+func applyLabelUpdates(ctx context.Context, st issueWriteStore, issueID, actor string, setLabels, addLabels, removeLabels []string) error {
 	// Set labels (replaces all existing labels)
 	if len(setLabels) > 0 {
 		currentLabels, err := st.GetLabels(ctx, issueID)
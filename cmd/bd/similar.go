@@ -0,0 +1,202 @@
+package main
+
+import (
+	"fmt"
+	"math"
+	"sort"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+var similarCmd = &cobra.Command{
+	Use:     "similar <id|text...>",
+	GroupID: "views",
+	Short:   "Rank issues by textual similarity to an issue or free text",
+	Long: `Rank existing issues by how similar their title and description are to
+a given issue or to free text.
+
+Useful for triage and deduping, and for agents looking for prior art before
+starting work. Unlike 'bd find-duplicates', which compares every pair of
+issues, similar compares one issue or phrase against all others.
+
+A single argument that resolves to an issue ID uses that issue's title and
+description as the query; anything else is treated as free text. Use --text
+to force free text when a word happens to look like an ID.
+
+Scoring is TF-IDF cosine similarity over title and description words, so
+words that appear in many issues count for less than rare ones. Closed
+issues are skipped unless --status is given.
+
+Examples:
+  bd similar bd-a1b2                        # Issues similar to bd-a1b2
+  bd similar "login page times out"         # Issues similar to free text
+  bd similar --status all "flaky test"      # Include closed issues
+  bd similar -n 5 --threshold 0.3 bd-a1b2   # Top 5 at 30% or more
+  bd similar bd-a1b2 --json                 # JSON output`,
+	Args: cobra.MinimumNArgs(1),
+	Run:  runSimilar,
+}
+
+func init() {
+	similarCmd.Flags().IntP("limit", "n", 10, "Maximum number of issues to show")
+	similarCmd.Flags().Float64("threshold", 0.1, "Minimum similarity (0.0-1.0) to show an issue")
+	similarCmd.Flags().StringP("status", "s", "", "Filter by status, or 'all' (default: non-closed)")
+	similarCmd.Flags().Bool("text", false, "Treat arguments as free text even if they look like an issue ID")
+	rootCmd.AddCommand(similarCmd)
+}
+
+// similarIssue is an issue ranked by 'bd similar'.
+type similarIssue struct {
+	ID         string       `json:"id"`
+	Title      string       `json:"title"`
+	Status     types.Status `json:"status"`
+	Priority   int          `json:"priority"`
+	Similarity float64      `json:"similarity"`
+}
+
+func runSimilar(cmd *cobra.Command, args []string) {
+	limit, _ := cmd.Flags().GetInt("limit")
+	threshold, _ := cmd.Flags().GetFloat64("threshold")
+	status, _ := cmd.Flags().GetString("status")
+	forceText, _ := cmd.Flags().GetBool("text")
+
+	ctx := rootCtx
+
+	// Resolve the query: an issue when a single argument names one,
+	// free text otherwise.
+	var source *types.Issue
+	queryText := strings.Join(args, " ")
+	if !forceText && len(args) == 1 && !strings.ContainsAny(args[0], " \t\n") {
+		if id, err := utils.ResolvePartialID(ctx, store, args[0]); err == nil {
+			issue, err := store.GetIssue(ctx, id)
+			if err != nil {
+				FatalErrorRespectJSON("loading %s: %v", id, err)
+			}
+			source = issue
+			queryText = issueText(issue)
+		}
+	}
+
+	filter := types.IssueFilter{}
+	if status != "" && status != "all" {
+		s := types.Status(status)
+		filter.Status = &s
+	}
+	issues, err := store.SearchIssues(ctx, "", filter)
+	if err != nil {
+		FatalErrorRespectJSON("fetching issues: %v", err)
+	}
+	if status == "" {
+		var filtered []*types.Issue
+		for _, issue := range issues {
+			if issue.Status != types.StatusClosed {
+				filtered = append(filtered, issue)
+			}
+		}
+		issues = filtered
+	}
+
+	excludeID := ""
+	if source != nil {
+		excludeID = source.ID
+	}
+	results := rankSimilarIssues(queryText, issues, excludeID, threshold)
+	if limit > 0 && len(results) > limit {
+		results = results[:limit]
+	}
+
+	if jsonOutput {
+		out := map[string]interface{}{
+			"query":     queryText,
+			"results":   results,
+			"count":     len(results),
+			"threshold": threshold,
+		}
+		if source != nil {
+			out["source_id"] = source.ID
+		}
+		outputJSON(out)
+		return
+	}
+
+	label := fmt.Sprintf("%q", queryText)
+	if source != nil {
+		label = fmt.Sprintf("%s %s", ui.RenderID(source.ID), source.Title)
+	}
+	if len(results) == 0 {
+		fmt.Printf("No issues similar to %s (threshold: %.0f%%)\n", label, threshold*100)
+		return
+	}
+	fmt.Printf("Issues similar to %s:\n\n", label)
+	for _, r := range results {
+		fmt.Printf("  %3.0f%%  %s [P%d] [%s] %s\n", r.Similarity*100, ui.RenderID(r.ID), r.Priority, r.Status, r.Title)
+	}
+}
+
+// rankSimilarIssues scores issues by TF-IDF cosine similarity between
+// queryText and each issue's title and description, skipping excludeID and
+// anything below threshold. Document frequencies come from issues, so terms
+// shared by most of the tracker carry little weight.
+func rankSimilarIssues(queryText string, issues []*types.Issue, excludeID string, threshold float64) []similarIssue {
+	docs := make([]map[string]int, len(issues))
+	df := make(map[string]int)
+	for i, issue := range issues {
+		docs[i] = tokenize(issueText(issue))
+		for token := range docs[i] {
+			df[token]++
+		}
+	}
+	idf := func(token string) float64 {
+		// Smoothed so a term in every issue still counts a little.
+		return math.Log(1 + float64(len(issues))/float64(df[token]+1))
+	}
+	weigh := func(tokens map[string]int) map[string]float64 {
+		weights := make(map[string]float64, len(tokens))
+		for token, count := range tokens {
+			weights[token] = float64(count) * idf(token)
+		}
+		return weights
+	}
+
+	query := weigh(tokenize(queryText))
+	var results []similarIssue
+	for i, issue := range issues {
+		if issue.ID == excludeID {
+			continue
+		}
+		similarity := weightedCosine(query, weigh(docs[i]))
+		if similarity > 0 && similarity >= threshold {
+			results = append(results, similarIssue{
+				ID:         issue.ID,
+				Title:      issue.Title,
+				Status:     issue.Status,
+				Priority:   issue.Priority,
+				Similarity: similarity,
+			})
+		}
+	}
+	sort.SliceStable(results, func(i, j int) bool {
+		return results[i].Similarity > results[j].Similarity
+	})
+	return results
+}
+
+// weightedCosine computes the cosine similarity between two weighted term vectors.
+func weightedCosine(a, b map[string]float64) float64 {
+	dot, magA, magB := 0.0, 0.0, 0.0
+	for token, wa := range a {
+		magA += wa * wa
+		dot += wa * b[token]
+	}
+	for _, wb := range b {
+		magB += wb * wb
+	}
+	if magA == 0 || magB == 0 {
+		return 0
+	}
+	return dot / (math.Sqrt(magA) * math.Sqrt(magB))
+}
@@ -0,0 +1,35 @@
+package main
+
+import (
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestRankSimilarIssues(t *testing.T) {
+	issues := []*types.Issue{
+		{ID: "bd-1", Title: "Login page times out", Description: "Users get a timeout on the login page after 30 seconds"},
+		{ID: "bd-2", Title: "Fix the release script"},
+		{ID: "bd-3", Title: "Session timeout too short", Description: "The session times out after idle"},
+		{ID: "bd-4", Title: "Update the docs"},
+	}
+
+	got := rankSimilarIssues("login timeout", issues, "", 0.1)
+	if len(got) != 2 || got[0].ID != "bd-1" || got[1].ID != "bd-3" {
+		t.Fatalf("free text: got %+v, want bd-1 then bd-3", got)
+	}
+
+	got = rankSimilarIssues(issueText(issues[0]), issues, "bd-1", 0)
+	if len(got) == 0 || got[0].ID != "bd-3" {
+		t.Fatalf("from issue: got %+v, want bd-3 first", got)
+	}
+	for _, r := range got {
+		if r.ID == "bd-1" {
+			t.Errorf("source issue bd-1 should be excluded: %+v", got)
+		}
+	}
+
+	if got := rankSimilarIssues("kubernetes", issues, "", 0); len(got) != 0 {
+		t.Errorf("unrelated text: got %+v, want none", got)
+	}
+}
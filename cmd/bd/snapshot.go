@@ -0,0 +1,398 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// gitSnapshotsKey is the local metadata key holding the restore points
+// made by 'bd snapshot create'. They name commits of this clone, so they
+// stay out of Dolt history.
+const gitSnapshotsKey = "git_snapshots"
+
+// gitSnapshot is a named restore point: a git commit and the JSONL
+// export it contains.
+type gitSnapshot struct {
+	Name      string    `json:"name"`
+	Commit    string    `json:"commit"`
+	Path      string    `json:"path"` // JSONL path relative to the repository root
+	JSONLHash string    `json:"jsonl_hash"`
+	Issues    int       `json:"issues"`
+	CreatedAt time.Time `json:"created_at"`
+}
+
+var snapshotCmd = &cobra.Command{
+	Use:     "snapshot",
+	GroupID: "sync",
+	Short:   "Name a git commit's JSONL export as a restore point",
+	Long: `Record restore points before big bulk operations, and roll the database
+back to one if the operation goes wrong.
+
+A restore point names the current git commit and the hash of the JSONL
+export committed in it. Restoring rebuilds the database from that
+commit's export: issues it has are written back, and issues it lacks are
+deleted. Restore points are kept per clone and never committed.
+
+Unlike 'bd backup snapshot', which copies the database into .beads/backups/,
+a restore point copies nothing: git already has the export.
+
+Examples:
+  bd snapshot create "before refactor"
+  bd snapshot list
+  bd snapshot restore "before refactor" --force`,
+}
+
+var snapshotCreateCmd = &cobra.Command{
+	Use:   "create <name>",
+	Short: "Record the current commit's JSONL export as a restore point",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		name := strings.TrimSpace(args[0])
+		if name == "" {
+			FatalErrorRespectJSON("snapshot name must not be empty")
+		}
+		snapshots, err := loadGitSnapshots(ctx, store)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if findGitSnapshot(snapshots, name) != nil {
+			FatalErrorRespectJSON("a snapshot named %q already exists; delete it first with 'bd snapshot delete'", name)
+		}
+
+		path, relPath := snapshotJSONLPath()
+		commit, err := gitOutput(ctx, "rev-parse", "--verify", "HEAD^{commit}")
+		if err != nil {
+			FatalErrorRespectJSON("no commit to snapshot: %v", err)
+		}
+		tmp, err := gitShowToTemp(ctx, commit, relPath)
+		if err != nil {
+			FatalErrorRespectJSON("%s is not committed in HEAD; commit it (or run 'bd sync') first", relPath)
+		}
+		defer func() { _ = os.Remove(tmp) }()
+		hash, err := jsonlFileHash(tmp)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		lines, err := jsonlIssueLines(tmp)
+		if err != nil {
+			FatalErrorRespectJSON("failed to read %s at %s: %v", relPath, shortSHA(commit), err)
+		}
+		if working, _ := jsonlFileHash(path); working != hash {
+			fmt.Fprintf(os.Stderr, "%s %s has uncommitted changes; the snapshot records the committed version\n", ui.RenderWarn("⚠"), relPath)
+		}
+
+		snap := gitSnapshot{Name: name, Commit: commit, Path: relPath, JSONLHash: hash, Issues: len(lines), CreatedAt: time.Now().UTC()}
+		snapshots = append(snapshots, snap)
+		if err := saveGitSnapshots(ctx, store, snapshots); err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			outputJSON(snap)
+			return
+		}
+		fmt.Printf("%s Snapshot %q at %s (%d issues)\n", ui.RenderPass("✓"), name, shortSHA(commit), snap.Issues)
+	},
+}
+
+var snapshotListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List restore points",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		snapshots, err := loadGitSnapshots(rootCtx, store)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			if snapshots == nil {
+				snapshots = []gitSnapshot{}
+			}
+			outputJSON(snapshots)
+			return
+		}
+		if len(snapshots) == 0 {
+			fmt.Println("No snapshots. Record one with: bd snapshot create <name>")
+			return
+		}
+		for _, s := range snapshots {
+			fmt.Printf("%-24s %s  %s  %5d issues\n", s.Name, shortSHA(s.Commit), s.CreatedAt.Local().Format("2006-01-02 15:04"), s.Issues)
+		}
+	},
+}
+
+var snapshotDeleteCmd = &cobra.Command{
+	Use:   "delete <name>",
+	Short: "Delete a restore point",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		snapshots, err := loadGitSnapshots(ctx, store)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		n := len(snapshots)
+		snapshots = slices.DeleteFunc(snapshots, func(s gitSnapshot) bool { return s.Name == args[0] })
+		if len(snapshots) == n {
+			FatalErrorRespectJSON("no snapshot named %q", args[0])
+		}
+		if err := saveGitSnapshots(ctx, store, snapshots); err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"deleted": true, "name": args[0]})
+			return
+		}
+		fmt.Printf("%s Deleted snapshot %q\n", ui.RenderPass("✓"), args[0])
+	},
+}
+
+var snapshotRestoreCmd = &cobra.Command{
+	Use:   "restore <name>",
+	Short: "Rebuild the database from a restore point's JSONL export",
+	Long: `Rebuild the database from the JSONL export committed in a restore point's
+commit. Issues in that export are written back as they were; issues created
+since are deleted. Without --force, only shows what would change.
+
+A 'bd backup snapshot' of the current database is taken first (unless
+backup.snapshot-before-write is off), so a restore can itself be undone.`,
+	Args: cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("snapshot restore")
+		ctx := rootCtx
+		force, _ := cmd.Flags().GetBool("force")
+
+		snapshots, err := loadGitSnapshots(ctx, store)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		snap := findGitSnapshot(snapshots, args[0])
+		if snap == nil {
+			FatalErrorRespectJSON("no snapshot named %q (see 'bd snapshot list')", args[0])
+		}
+
+		tmp, err := gitShowToTemp(ctx, snap.Commit, snap.Path)
+		if err != nil {
+			FatalErrorRespectJSON("cannot read %s at %s: %v (was the commit removed from history?)", snap.Path, shortSHA(snap.Commit), err)
+		}
+		defer func() { _ = os.Remove(tmp) }()
+		if hash, err := jsonlFileHash(tmp); err != nil || hash != snap.JSONLHash {
+			FatalErrorRespectJSON("%s at %s no longer matches the snapshot's hash", snap.Path, shortSHA(snap.Commit))
+		}
+
+		plan, err := planSnapshotRestore(ctx, tmp)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if !force {
+			if jsonOutput {
+				outputJSON(map[string]interface{}{"snapshot": snap, "restore": len(plan.restore), "delete": plan.remove, "dry_run": true})
+				return
+			}
+			fmt.Printf("Restoring %q (%s) would write back %d issues and delete %d created since",
+				snap.Name, shortSHA(snap.Commit), len(plan.restore), len(plan.remove))
+			if len(plan.remove) > 0 {
+				fmt.Printf(": %s", strings.Join(sampleStrings(plan.remove, 10), ", "))
+			}
+			fmt.Printf(".\nTo proceed, run: %s\n", ui.RenderWarn(fmt.Sprintf("bd snapshot restore %q --force", snap.Name)))
+			return
+		}
+
+		snapshotBeforeWrite(ctx, "snapshot restore "+snap.Name)
+		if err := runSnapshotRestore(ctx, store, tmp, plan, snap); err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"snapshot": snap, "restored": len(plan.restore), "deleted": plan.remove})
+			return
+		}
+		fmt.Printf("%s Restored %q (%s): %d issues written back, %d deleted\n",
+			ui.RenderPass("✓"), snap.Name, shortSHA(snap.Commit), len(plan.restore), len(plan.remove))
+	},
+}
+
+func init() {
+	snapshotRestoreCmd.Flags().Bool("force", false, "Rebuild the database (default: show what would change)")
+	snapshotCmd.AddCommand(snapshotCreateCmd)
+	snapshotCmd.AddCommand(snapshotListCmd)
+	snapshotCmd.AddCommand(snapshotDeleteCmd)
+	snapshotCmd.AddCommand(snapshotRestoreCmd)
+	rootCmd.AddCommand(snapshotCmd)
+}
+
+// loadGitSnapshots returns the restore points recorded in st.
+func loadGitSnapshots(ctx context.Context, st storage.DoltStorage) ([]gitSnapshot, error) {
+	if st == nil {
+		return nil, fmt.Errorf("no store available")
+	}
+	raw, err := st.GetLocalMetadata(ctx, gitSnapshotsKey)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read snapshots: %w", err)
+	}
+	if raw == "" {
+		return nil, nil
+	}
+	var snapshots []gitSnapshot
+	if err := json.Unmarshal([]byte(raw), &snapshots); err != nil {
+		return nil, fmt.Errorf("failed to parse snapshots: %w", err)
+	}
+	return snapshots, nil
+}
+
+func saveGitSnapshots(ctx context.Context, st storage.DoltStorage, snapshots []gitSnapshot) error {
+	data, err := json.Marshal(snapshots)
+	if err != nil {
+		return err
+	}
+	if err := st.SetLocalMetadata(ctx, gitSnapshotsKey, string(data)); err != nil {
+		return fmt.Errorf("failed to save snapshots: %w", err)
+	}
+	return nil
+}
+
+func findGitSnapshot(snapshots []gitSnapshot, name string) *gitSnapshot {
+	for i := range snapshots {
+		if snapshots[i].Name == name {
+			return &snapshots[i]
+		}
+	}
+	return nil
+}
+
+// snapshotJSONLPath returns the JSONL export's path and its path relative
+// to the repository root. Exits if there is no repository.
+func snapshotJSONLPath() (path, relPath string) {
+	rc, err := beads.GetRepoContext()
+	if err != nil {
+		FatalErrorRespectJSON("snapshots need a git repository: %v", err)
+	}
+	path = autoExportPath(rc.BeadsDir)
+	relPath, err = filepath.Rel(rc.RepoRoot, path)
+	if err != nil || strings.HasPrefix(relPath, "..") {
+		FatalErrorRespectJSON("%s is outside the repository at %s", path, rc.RepoRoot)
+	}
+	return path, filepath.ToSlash(relPath)
+}
+
+// gitOutput runs a git command in the beads repository and returns its
+// trimmed output.
+func gitOutput(ctx context.Context, args ...string) (string, error) {
+	rc, err := beads.GetRepoContext()
+	if err != nil {
+		return "", err
+	}
+	out, err := rc.GitCmd(ctx, args...).Output()
+	if err != nil {
+		return "", err
+	}
+	return strings.TrimSpace(string(out)), nil
+}
+
+// gitShowToTemp writes relPath as of commit to a temporary file and
+// returns its path. The caller removes it.
+func gitShowToTemp(ctx context.Context, commit, relPath string) (string, error) {
+	rc, err := beads.GetRepoContext()
+	if err != nil {
+		return "", err
+	}
+	data, err := rc.GitCmd(ctx, "show", commit+":"+relPath).Output()
+	if err != nil {
+		return "", err
+	}
+	f, err := os.CreateTemp("", "bd-snapshot-*.jsonl")
+	if err != nil {
+		return "", err
+	}
+	if _, err := f.Write(data); err != nil {
+		_ = f.Close()
+		_ = os.Remove(f.Name())
+		return "", err
+	}
+	if err := f.Close(); err != nil {
+		_ = os.Remove(f.Name())
+		return "", err
+	}
+	return f.Name(), nil
+}
+
+// snapshotRestorePlan is what restoring a snapshot changes.
+type snapshotRestorePlan struct {
+	restore map[string]string // snapshot issue ID -> JSONL line
+	remove  []string          // exported issues the snapshot lacks
+}
+
+// planSnapshotRestore compares the snapshot export at path with an export
+// of the current database. Only issues an export includes are candidates
+// for deletion, so infrastructure issues and wisps are left alone.
+func planSnapshotRestore(ctx context.Context, path string) (*snapshotRestorePlan, error) {
+	restore, err := jsonlIssueLines(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read snapshot export: %w", err)
+	}
+	current, err := os.CreateTemp("", "bd-snapshot-current-*.jsonl")
+	if err != nil {
+		return nil, err
+	}
+	_ = current.Close()
+	defer func() { _ = os.Remove(current.Name()) }()
+	if _, _, _, err := exportToFile(ctx, current.Name(), false); err != nil {
+		return nil, fmt.Errorf("failed to export the current database: %w", err)
+	}
+	live, err := jsonlIssueLines(current.Name())
+	if err != nil {
+		return nil, err
+	}
+	plan := &snapshotRestorePlan{restore: restore}
+	for id := range live {
+		if _, ok := restore[id]; !ok {
+			plan.remove = append(plan.remove, id)
+		}
+	}
+	slices.Sort(plan.remove)
+	return plan, nil
+}
+
+// runSnapshotRestore applies plan to st: it deletes the issues the
+// snapshot lacks, keeping them in the trash, imports the snapshot export at
+// path, and commits.
+func runSnapshotRestore(ctx context.Context, st storage.DoltStorage, path string, plan *snapshotRestorePlan, snap *gitSnapshot) error {
+	if len(plan.remove) > 0 {
+		actor := getActor()
+		_, err := deleteToTrashInTx(ctx, st, plan.remove, actor, func(tx storage.Transaction, deleted []string) error {
+			return recordTombstones(ctx, tx, deleted, actor)
+		})
+		if err != nil {
+			return fmt.Errorf("failed to delete issues created since the snapshot: %w", err)
+		}
+		commandMayEmptyJSONLExport.Store(true)
+	}
+	// Issues deleted since the snapshot come back with it.
+	restored := make([]string, 0, len(plan.restore))
+	for id := range plan.restore {
+		restored = append(restored, id)
+	}
+	if err := clearTombstones(ctx, st, restored); err != nil {
+		return err
+	}
+	if _, err := importFromLocalJSONLFull(ctx, st, path); err != nil {
+		return fmt.Errorf("failed to import the snapshot: %w", err)
+	}
+	commandDidWrite.Store(true)
+	msg := fmt.Sprintf("bd: snapshot restore %q (%s) by %s", snap.Name, shortSHA(snap.Commit), getActor())
+	if err := st.Commit(ctx, msg); err != nil && !isDoltNothingToCommit(err) {
+		return fmt.Errorf("failed to commit the restore: %w", err)
+	}
+	commandDidExplicitDoltCommit = true
+	return nil
+}
@@ -0,0 +1,38 @@
+package main
+
+import (
+	"context"
+	"testing"
+	"time"
+)
+
+func TestGitSnapshots_RoundTrip(t *testing.T) {
+	ctx := context.Background()
+	st := &localMetadataStore{local: map[string]string{}}
+
+	snapshots, err := loadGitSnapshots(ctx, st)
+	if err != nil || len(snapshots) != 0 {
+		t.Fatalf("loadGitSnapshots(empty) = %v, %v", snapshots, err)
+	}
+
+	created := time.Date(2026, 1, 2, 3, 4, 5, 0, time.UTC)
+	snapshots = append(snapshots,
+		gitSnapshot{Name: "before refactor", Commit: "0123456789abcdef", Path: ".beads/issues.jsonl", JSONLHash: "aa", Issues: 3, CreatedAt: created},
+		gitSnapshot{Name: "later", Commit: "fedcba9876543210", Path: ".beads/issues.jsonl", JSONLHash: "bb", Issues: 4, CreatedAt: created},
+	)
+	if err := saveGitSnapshots(ctx, st, snapshots); err != nil {
+		t.Fatal(err)
+	}
+
+	loaded, err := loadGitSnapshots(ctx, st)
+	if err != nil {
+		t.Fatal(err)
+	}
+	got := findGitSnapshot(loaded, "before refactor")
+	if got == nil || got.Commit != "0123456789abcdef" || got.Issues != 3 || !got.CreatedAt.Equal(created) {
+		t.Errorf("findGitSnapshot = %+v", got)
+	}
+	if findGitSnapshot(loaded, "missing") != nil {
+		t.Error("findGitSnapshot found a snapshot that was never saved")
+	}
+}
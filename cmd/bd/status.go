@@ -1,6 +1,7 @@
 package main
 
 import (
+	"context"
 	"fmt"
 
 	"github.com/spf13/cobra"
@@ -12,6 +13,18 @@ import (
 type StatusOutput struct {
 	Summary        *types.Statistics      `json:"summary"`
 	RecentActivity *RecentActivitySummary `json:"recent_activity,omitempty"`
+	TimeTracking   *TimeTrackingSummary   `json:"time_tracking,omitempty"`
+}
+
+// TimeTrackingSummary compares time logged with 'bd log-time' against
+// estimates. The estimate comparison only covers issues that have both.
+type TimeTrackingSummary struct {
+	LoggedMinutes    int     `json:"logged_minutes"`
+	IssuesWithTime   int     `json:"issues_with_time"`
+	EstimatedIssues  int     `json:"estimated_issues"`
+	EstimatedMinutes int     `json:"estimated_minutes"`
+	ActualMinutes    int     `json:"actual_minutes"`
+	ActualToEstimate float64 `json:"actual_to_estimate,omitempty"`
 }
 
 // RecentActivitySummary represents activity from git history
@@ -34,7 +47,8 @@ var statusCmd = &cobra.Command{
 
 This command provides a summary of issue counts by state (open, in_progress,
 blocked, closed), ready work, extended statistics (pinned issues,
-average lead time), and recent activity over the last 24 hours from git history.
+average lead time), logged time against estimates (see 'bd log-time'), and
+recent activity over the last 24 hours from git history.
 
 Similar to how 'git status' shows working tree state, 'bd status' gives you
 a quick overview of your issue database without needing multiple queries.
@@ -50,7 +64,8 @@ Examples:
   bd status --no-activity      # Skip git activity (faster)
   bd status --json             # JSON format output
   bd status --assigned         # Show issues assigned to current user
-  bd stats                     # Alias for bd status`,
+  bd stats                     # Alias for bd status
+  bd stats --all-repos         # Per-repo and total counts for registered repos`,
 	Run: func(cmd *cobra.Command, args []string) {
 		showAll, _ := cmd.Flags().GetBool("all")
 		showAssigned, _ := cmd.Flags().GetBool("assigned")
@@ -68,6 +83,11 @@ Examples:
 
 		ctx := rootCtx
 
+		if allRepos, _ := cmd.Flags().GetBool("all-repos"); allRepos {
+			statusAllRepos(ctx)
+			return
+		}
+
 		// Direct mode
 		stats, err = store.GetStatistics(ctx)
 		if err != nil {
@@ -91,6 +111,7 @@ Examples:
 		output := &StatusOutput{
 			Summary:        stats,
 			RecentActivity: recentActivity,
+			TimeTracking:   getTimeTrackingSummary(ctx),
 		}
 
 		// JSON output
@@ -125,6 +146,15 @@ Examples:
 			}
 		}
 
+		if tt := output.TimeTracking; tt != nil {
+			fmt.Printf("\nTime Tracking:\n")
+			fmt.Printf("  Time Logged:            %s on %d issue(s)\n", formatMinutes(tt.LoggedMinutes), tt.IssuesWithTime)
+			if tt.EstimatedIssues > 0 {
+				fmt.Printf("  Estimate vs Actual:     %s vs %s on %d issue(s) (%.0f%%)\n",
+					formatMinutes(tt.EstimatedMinutes), formatMinutes(tt.ActualMinutes), tt.EstimatedIssues, tt.ActualToEstimate*100)
+			}
+		}
+
 		if recentActivity != nil {
 			fmt.Printf("\nRecent Activity (last %d hours):\n", recentActivity.HoursTracked)
 			fmt.Printf("  Commits:                %d\n", recentActivity.CommitCount)
@@ -151,6 +181,47 @@ func getGitActivity(_ int) *RecentActivitySummary {
 	return nil
 }
 
+// getTimeTrackingSummary totals logged time and compares it with estimates.
+// Returns nil when no time has been logged.
+func getTimeTrackingSummary(ctx context.Context) *TimeTrackingSummary {
+	totals, err := store.GetWorkLogTotals(ctx)
+	if err != nil || len(totals) == 0 {
+		return nil
+	}
+	logged := make(map[string]int)
+	for _, t := range totals {
+		logged[t.IssueID] += t.Minutes
+	}
+	ids := make([]string, 0, len(logged))
+	for id := range logged {
+		ids = append(ids, id)
+	}
+	issues, err := store.GetIssuesByIDs(ctx, ids)
+	if err != nil {
+		return nil
+	}
+	return summarizeTimeTracking(logged, issues)
+}
+
+func summarizeTimeTracking(logged map[string]int, issues []*types.Issue) *TimeTrackingSummary {
+	summary := &TimeTrackingSummary{IssuesWithTime: len(logged)}
+	for _, minutes := range logged {
+		summary.LoggedMinutes += minutes
+	}
+	for _, issue := range issues {
+		if issue.EstimatedMinutes == nil || *issue.EstimatedMinutes <= 0 {
+			continue
+		}
+		summary.EstimatedIssues++
+		summary.EstimatedMinutes += *issue.EstimatedMinutes
+		summary.ActualMinutes += logged[issue.ID]
+	}
+	if summary.EstimatedMinutes > 0 {
+		summary.ActualToEstimate = float64(summary.ActualMinutes) / float64(summary.EstimatedMinutes)
+	}
+	return summary
+}
+
 // getAssignedStatistics returns statistics for issues assigned to a specific user
 func getAssignedStatistics(assignee string) *types.Statistics {
 	if store == nil {
@@ -206,6 +277,7 @@ func init() {
 	statusCmd.Flags().Bool("all", false, "Show all issues (default behavior)")
 	statusCmd.Flags().Bool("assigned", false, "Show issues assigned to current user")
 	statusCmd.Flags().Bool("no-activity", false, "Skip git activity tracking (faster)")
+	statusCmd.Flags().Bool("all-repos", false, "Summarize this and every registered repository (see 'bd workspace')")
 	// Note: --json flag is defined as a persistent flag in main.go, not here
 	rootCmd.AddCommand(statusCmd)
 }
@@ -0,0 +1,46 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"os"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/validation"
+)
+
+// loadStatusGuards returns the guards enabled by the status.guards config
+// key. An invalid value is reported and enables none.
+func loadStatusGuards(ctx context.Context, st storage.DoltStorage) []validation.Guard {
+	if st == nil {
+		return nil
+	}
+	value, err := st.GetConfig(ctx, "status.guards")
+	if err != nil || value == "" {
+		return nil
+	}
+	guards, err := validation.ParseGuards(value)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "%s ignoring status.guards: %v\n", ui.RenderWarn("!"), err)
+		return nil
+	}
+	return guards
+}
+
+// checkStatusGuards checks moving issue to status against guards, looking
+// up its blockers in st when a guard needs them. assignee is the issue's
+// assignee once the update applies.
+func checkStatusGuards(ctx context.Context, st storage.DoltStorage, guards []validation.Guard, issue *types.Issue, status types.Status, assignee string) error {
+	return validation.CheckTransition(guards, validation.Transition{
+		ID:       issue.ID,
+		From:     issue.Status,
+		To:       status,
+		Assignee: assignee,
+		Blockers: func() ([]string, error) {
+			_, blockers, err := st.IsBlocked(ctx, issue.ID)
+			return blockers, err
+		},
+	})
+}
@@ -0,0 +1,311 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"slices"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/storage"
+)
+
+var syncCmd = &cobra.Command{
+	Use:     "sync",
+	GroupID: "sync",
+	Short:   "Make the local tracker consistent with the team's in one step",
+	Long: `Bring the local database and the team's copy together: commit pending
+changes, pull and merge what others pushed, and (with --push) push the
+result. Run it at session boundaries.
+
+With a Dolt remote (sync.remote), sync pulls from it and, with --push,
+pushes to it; Dolt merges the histories. Without one, in a git repository,
+sync carries the JSONL export through git instead:
+
+  1. export the database to the JSONL file and commit it if it changed
+  2. git pull, merging the JSONL with 'bd merge-file' if it is installed
+  3. import the merged JSONL: issues changed upstream are updated, issues
+     added upstream are created, and issues deleted upstream are deleted
+  4. export and commit again, so the file matches the database
+  5. with --push, git push
+
+Issues changed both locally and upstream are resolved by the merge of the
+JSONL file. If the merge stops with conflicts, sync lists the conflicted
+files and exits 1: resolve them, commit, and run 'bd sync' again.
+
+Sync refuses to start if the JSONL file holds issues the database lacks
+(e.g. after a git pull without the hooks), since exporting would drop
+them: take them with 'bd import' or drop them with 'bd export', then sync.
+
+EXAMPLES:
+  bd sync                   # Pull, merge, export, and commit
+  bd sync --push            # ... and push
+  bd sync --no-pull --push  # Only export, commit, and push`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("sync")
+		push, _ := cmd.Flags().GetBool("push")
+		noPull, _ := cmd.Flags().GetBool("no-pull")
+
+		report, err := runSync(rootCtx, getStore(), push, !noPull)
+		if err != nil {
+			report.Error = err.Error()
+		}
+		if jsonOutput {
+			outputJSON(report)
+		}
+		if err == nil {
+			if !jsonOutput {
+				fmt.Println("✓ Sync complete")
+			}
+			return
+		}
+		if !jsonOutput {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			if len(report.Conflicts) > 0 {
+				fmt.Fprintln(os.Stderr, "\nConflicts:")
+				for _, c := range report.Conflicts {
+					fmt.Fprintf(os.Stderr, "  %s\n", c)
+				}
+				fmt.Fprintln(os.Stderr, "\nResolve them, commit, and run 'bd sync' again.")
+			}
+		}
+		os.Exit(1)
+	},
+}
+
+func init() {
+	syncCmd.Flags().Bool("push", false, "Push to the Dolt remote and git upstream after merging")
+	syncCmd.Flags().Bool("no-pull", false, "Don't pull; only export, commit, and (with --push) push")
+	rootCmd.AddCommand(syncCmd)
+}
+
+// syncReport is what a sync did, for --json.
+type syncReport struct {
+	Steps     []string `json:"steps"`
+	Pulled    []string `json:"pulled,omitempty"`    // issues the git pull changed
+	Committed []string `json:"committed,omitempty"` // issues committed to git
+	Deleted   []string `json:"deleted,omitempty"`   // issues deleted upstream
+	Pushed    bool     `json:"pushed"`
+	Conflicts []string `json:"conflicts,omitempty"`
+	Error     string   `json:"error,omitempty"`
+}
+
+func (r *syncReport) step(format string, args ...interface{}) {
+	msg := fmt.Sprintf(format, args...)
+	r.Steps = append(r.Steps, msg)
+	if !jsonOutput {
+		fmt.Println(msg)
+	}
+}
+
+// runSync syncs st with its Dolt remote, if it has one, and its JSONL
+// export with git, if it is in a repository. The report covers the steps
+// taken before any error.
+func runSync(ctx context.Context, st storage.DoltStorage, push, pull bool) (*syncReport, error) {
+	r := &syncReport{}
+	if st == nil {
+		return r, fmt.Errorf("no store available")
+	}
+	// Sync takes the write lock only around its writes, not while it waits
+	// on the network.
+	err := withCommandWriteLock(func() error {
+		return st.Commit(ctx, fmt.Sprintf("bd: sync (auto-commit) by %s", getActor()))
+	})
+	if err != nil && !isDoltNothingToCommit(err) {
+		return r, fmt.Errorf("failed to commit pending changes: %w", err)
+	}
+	commandDidExplicitDoltCommit = true
+
+	doltRemote := resolveSyncRemote() != ""
+	if doltRemote && pull {
+		if err := st.Pull(ctx); err != nil {
+			if isDivergedHistoryErr(err) {
+				printDivergedHistoryGuidance("pull")
+			}
+			return r, fmt.Errorf("dolt pull failed: %w", err)
+		}
+		r.step("Pulled from the Dolt remote")
+	}
+
+	// With a Dolt remote, Dolt history is what syncs; the JSONL export is
+	// only refreshed, by the post-command auto-export.
+	beadsDir := beads.FindBeadsDir()
+	useGit := !doltRemote && beadsDir != "" && !config.GetBool("no-git-ops") && isGitRepo()
+	if useGit {
+		if err := syncJSONLWithGit(ctx, st, r, autoExportPath(beadsDir), push, pull); err != nil {
+			return r, err
+		}
+	}
+
+	if doltRemote && push {
+		if err := st.Push(ctx); err != nil {
+			if isDivergedHistoryErr(err) {
+				printDivergedHistoryGuidance("push")
+			}
+			return r, fmt.Errorf("dolt push failed: %w", err)
+		}
+		r.Pushed = true
+		r.step("Pushed to the Dolt remote")
+	}
+	if !doltRemote && !useGit {
+		r.step("No Dolt remote and no git repository: nothing to sync with")
+	}
+	return r, nil
+}
+
+// syncJSONLWithGit carries the JSONL export at path through git: export
+// and commit, pull, import what the pull changed, export and commit the
+// result, and push.
+func syncJSONLWithGit(ctx context.Context, st storage.DoltStorage, r *syncReport, path string, push, pull bool) error {
+	missing, err := missingJSONLIssueIDsInStore(ctx, path)
+	if err != nil {
+		return fmt.Errorf("failed to compare %s with the database: %w", path, err)
+	}
+	if len(missing) > 0 {
+		return fmt.Errorf("%s holds %d issue(s) the database lacks (%s); run 'bd import %s' to take them or 'bd export -o %s' to drop them, then sync again",
+			path, len(missing), strings.Join(sampleStrings(missing, 5), ", "), path, path)
+	}
+
+	if err := withCommandWriteLock(func() error {
+		return syncExportAndCommit(ctx, r, path, "export")
+	}); err != nil {
+		return err
+	}
+
+	upstream := gitHasUpstream()
+	if pull && upstream {
+		before, err := jsonlIssueLines(path)
+		if err != nil {
+			return fmt.Errorf("failed to read %s: %w", path, err)
+		}
+		if err := syncGit(ctx, "pull", "--no-edit"); err != nil {
+			r.Conflicts = gitConflictedFiles(ctx)
+			return fmt.Errorf("git pull failed: %w", err)
+		}
+		after, err := jsonlIssueLines(path)
+		if err != nil {
+			return fmt.Errorf("failed to read %s: %w", path, err)
+		}
+		r.Pulled = changedJSONLIssues(before, after)
+		slices.Sort(r.Pulled)
+		r.step("Pulled from git (%d issue(s) changed)", len(r.Pulled))
+
+		if len(r.Pulled) > 0 {
+			if err := withCommandWriteLock(func() error {
+				if err := syncImportPulled(ctx, st, r, path, after); err != nil {
+					return err
+				}
+				return syncExportAndCommit(ctx, r, path, "merge")
+			}); err != nil {
+				return err
+			}
+		}
+	} else if pull {
+		r.step("No git upstream for this branch; skipped git pull")
+	}
+
+	if push && upstream {
+		if err := syncGit(ctx, "push"); err != nil {
+			return fmt.Errorf("git push failed: %w", err)
+		}
+		r.Pushed = true
+		r.step("Pushed to git")
+	}
+	return nil
+}
+
+// syncImportPulled applies a pull to st. It re-imports the whole JSONL
+// file, relying on importIssuesCore to keep whichever copy of each issue
+// is newer, so only the issues the pull changed are updated. Then it
+// deletes the pulled issues the file no longer has, keeping them in the
+// trash as 'bd delete' does.
+func syncImportPulled(ctx context.Context, st storage.DoltStorage, r *syncReport, path string, after map[string]string) error {
+	if _, err := importFromLocalJSONLFull(ctx, st, path); err != nil {
+		return fmt.Errorf("failed to import %s: %w", path, err)
+	}
+	recordJSONLFileHash(ctx, st, path)
+	var removed []string
+	for _, id := range r.Pulled {
+		if _, ok := after[id]; !ok {
+			removed = append(removed, id)
+		}
+	}
+	gone, err := deleteToTrash(ctx, st, removed, getActor())
+	r.Deleted = append(r.Deleted, gone...)
+	if err != nil {
+		return fmt.Errorf("failed to delete pulled issues: %w", err)
+	}
+	msg := formatDoltAutoCommitMessage("sync", getActor(), r.Pulled)
+	if err := st.Commit(ctx, msg); err != nil && !isDoltNothingToCommit(err) {
+		return fmt.Errorf("failed to commit the import: %w", err)
+	}
+	r.step("Imported the pulled issues")
+	return nil
+}
+
+// syncExportAndCommit exports the database to path and commits the file
+// if that changed it.
+func syncExportAndCommit(ctx context.Context, r *syncReport, path, what string) error {
+	before, err := jsonlIssueLines(path)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", path, err)
+	}
+	_, _, unchanged, err := exportToFile(ctx, path, false)
+	if err != nil {
+		return fmt.Errorf("failed to export: %w", err)
+	}
+	recordJSONLFileHash(ctx, store, path)
+	if unchanged {
+		return nil
+	}
+	after, err := jsonlIssueLines(path)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", path, err)
+	}
+	ids := changedJSONLIssues(before, after)
+	slices.Sort(ids)
+	if err := gitAddFile(path); err != nil {
+		return fmt.Errorf("git add failed: %w", err)
+	}
+	msg := formatDoltAutoCommitMessage("sync "+what, getActor(), ids)
+	if err := gitCommitFile(path, msg); err != nil {
+		return fmt.Errorf("git commit failed: %w", err)
+	}
+	r.Committed = append(r.Committed, ids...)
+	r.step("Committed %d changed issue(s) to git", len(ids))
+	return nil
+}
+
+// syncGit runs a git command in the beads repository, returning its
+// output in the error.
+func syncGit(ctx context.Context, args ...string) error {
+	rc, err := beads.GetRepoContext()
+	if err != nil {
+		return err
+	}
+	out, err := rc.GitCmd(ctx, args...).CombinedOutput()
+	if err != nil {
+		if trimmed := strings.TrimSpace(string(out)); trimmed != "" {
+			return fmt.Errorf("%w: %s", err, trimmed)
+		}
+		return err
+	}
+	return nil
+}
+
+// gitConflictedFiles lists the files left unmerged in the beads repository.
+func gitConflictedFiles(ctx context.Context) []string {
+	rc, err := beads.GetRepoContext()
+	if err != nil {
+		return nil
+	}
+	out, err := rc.GitCmd(ctx, "diff", "--name-only", "--diff-filter=U").Output()
+	if err != nil {
+		return nil
+	}
+	return strings.Fields(string(out))
+}
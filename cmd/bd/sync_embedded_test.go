@@ -0,0 +1,110 @@
+//go:build cgo
+
+package main
+
+import (
+	"encoding/json"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+)
+
+// bdSync runs "bd sync --json" with the given args and returns the report.
+func bdSync(t *testing.T, bd, dir string, args ...string) *syncReport {
+	t.Helper()
+	fullArgs := append([]string{"sync", "--json"}, args...)
+	cmd := exec.Command(bd, fullArgs...)
+	cmd.Dir = dir
+	cmd.Env = bdEnv(dir)
+	stdout, stderr, err := runCommandBuffers(t, cmd)
+	if err != nil {
+		t.Fatalf("bd sync %s failed: %v\nstdout:\n%s\nstderr:\n%s", strings.Join(args, " "), err, stdout.String(), stderr.String())
+	}
+	s := stdout.String()
+	start := strings.Index(s, "{")
+	if start < 0 {
+		t.Fatalf("no JSON report in output:\n%s", s)
+	}
+	var report syncReport
+	if err := json.NewDecoder(strings.NewReader(s[start:])).Decode(&report); err != nil {
+		t.Fatalf("parse sync report: %v\n%s", err, s)
+	}
+	return &report
+}
+
+// TestEmbeddedSyncGit syncs two clones through a temporary bare git remote.
+func TestEmbeddedSyncGit(t *testing.T) {
+	if os.Getenv("BEADS_TEST_EMBEDDED_DOLT") != "1" {
+		t.Skip("set BEADS_TEST_EMBEDDED_DOLT=1 to run embedded dolt integration tests")
+	}
+	t.Parallel()
+
+	bd := buildEmbeddedBD(t)
+
+	// Clone A starts the project and publishes its JSONL to the remote.
+	dirA, _, _ := bdInit(t, bd, "--prefix", "sy")
+	kept := bdCreate(t, bd, dirA, "Kept upstream", "--type", "task")
+	doomed := bdCreate(t, bd, dirA, "Deleted upstream", "--type", "task")
+	bdSync(t, bd, dirA)
+
+	bareDir := filepath.Join(t.TempDir(), "origin.git")
+	runGitForSyncTest(t, "", "init", "--bare", "--initial-branch=main", bareDir)
+	runGitForSyncTest(t, dirA, "branch", "-M", "main")
+	runGitForSyncTest(t, dirA, "remote", "add", "origin", bareDir)
+	runGitForSyncTest(t, dirA, "push", "-u", "origin", "HEAD")
+
+	// Clone B takes the published JSONL into its own database.
+	dirB := filepath.Join(t.TempDir(), "b")
+	runGitForSyncTest(t, "", "clone", bareDir, dirB)
+	for _, args := range [][]string{
+		{"config", "user.email", "test@test.com"},
+		{"config", "user.name", "Test"},
+		{"config", "core.hooksPath", ".git/hooks"},
+	} {
+		runGitForSyncTest(t, dirB, args...)
+	}
+	runBDInit(t, bd, dirB, "--prefix", "sy", "--from-jsonl")
+	if got := bdShow(t, bd, dirB, doomed.ID); got.Title != "Deleted upstream" {
+		t.Fatalf("clone B is missing %s before the sync: %+v", doomed.ID, got)
+	}
+
+	// A changes one issue, deletes another, creates a third, and pushes.
+	bdUpdate(t, bd, dirA, kept.ID, "--title", "Renamed upstream")
+	bdDelete(t, bd, dirA, doomed.ID, "--force")
+	added := bdCreate(t, bd, dirA, "Added upstream", "--type", "task")
+	if report := bdSync(t, bd, dirA, "--push"); !report.Pushed {
+		t.Fatalf("clone A did not push: %+v", report)
+	}
+
+	t.Run("pull_applies_upstream_changes", func(t *testing.T) {
+		report := bdSync(t, bd, dirB)
+		for _, id := range []string{kept.ID, doomed.ID, added.ID} {
+			if !slices.Contains(report.Pulled, id) {
+				t.Errorf("pulled = %v, want %s", report.Pulled, id)
+			}
+		}
+		// The deletion is applied by the import; the issue being gone
+		// already must not fail the sync.
+		if !slices.Contains(report.Deleted, doomed.ID) {
+			t.Errorf("deleted = %v, want %s", report.Deleted, doomed.ID)
+		}
+
+		if got := bdShow(t, bd, dirB, kept.ID); got.Title != "Renamed upstream" {
+			t.Errorf("%s title = %q, want the upstream rename", kept.ID, got.Title)
+		}
+		if got := bdShow(t, bd, dirB, added.ID); got.Title != "Added upstream" {
+			t.Errorf("%s title = %q, want the upstream issue", added.ID, got.Title)
+		}
+		bdShowFail(t, bd, dirB, doomed.ID)
+	})
+
+	t.Run("second_sync_is_a_no_op", func(t *testing.T) {
+		report := bdSync(t, bd, dirB)
+		if len(report.Pulled) != 0 || len(report.Committed) != 0 || len(report.Deleted) != 0 {
+			t.Errorf("second sync changed something: %+v", report)
+		}
+	})
+}
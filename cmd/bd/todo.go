@@ -1,13 +1,16 @@
 package main
 
 import (
+	"context"
 	"encoding/json"
 	"fmt"
 	"os"
 	"slices"
+	"strconv"
 	"strings"
 
 	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
 	"github.com/steveyegge/beads/internal/ui"
 	"github.com/steveyegge/beads/internal/utils"
@@ -25,7 +28,15 @@ TODOs are regular task-type issues with convenient shortcuts:
   bd todo done <id>      -> bd close <id>
 
 TODOs can be promoted to full issues by changing type or priority:
-  bd update todo-123 --type bug --priority 0`,
+  bd update todo-123 --type bug --priority 0
+
+Any issue can also carry a checklist: small steps that don't deserve an
+issue of their own. Items are numbered from 1, exported to JSONL with the
+issue (todos), and shown by 'bd show':
+  bd todo item bd-12 "write tests"  -> add item to bd-12's checklist
+  bd todo items bd-12               -> show bd-12's checklist
+  bd todo check bd-12 3             -> tick item 3 (--undo to untick)
+  bd todo remove bd-12 3            -> delete item 3`,
 	Run: func(cmd *cobra.Command, args []string) {
 		// Default action: list todos
 		listTodosCmd.Run(cmd, args)
@@ -190,11 +201,225 @@ var doneTodoCmd = &cobra.Command{
 	},
 }
 
+var itemTodoCmd = &cobra.Command{
+	Use:   "item <issue-id> <text>",
+	Short: "Add a checklist item to an issue",
+	Args:  cobra.MinimumNArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("todo item")
+		issueID, err := utils.ResolvePartialID(rootCtx, getStore(), args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		addChecklistItem(issueID, strings.Join(args[1:], " "))
+	},
+}
+
+var itemsTodoCmd = &cobra.Command{
+	Use:   "items <issue-id>",
+	Short: "Show the checklist of an issue",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		issueID, err := utils.ResolvePartialID(rootCtx, getStore(), args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		listChecklist(issueID)
+	},
+}
+
+var checkTodoCmd = &cobra.Command{
+	Use:   "check <issue-id> <n> [<n>...]",
+	Short: "Tick checklist items on an issue",
+	Args:  cobra.MinimumNArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("todo check")
+		seqs, ok := parseTodoSeqs(args[1:])
+		if !ok {
+			FatalErrorRespectJSON("checklist item numbers must be positive integers, got %s", strings.Join(args[1:], " "))
+		}
+		issueID, err := utils.ResolvePartialID(rootCtx, getStore(), args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+		undo, _ := cmd.Flags().GetBool("undo")
+		setChecklistDone(issueID, seqs, !undo)
+	},
+}
+
+var removeTodoCmd = &cobra.Command{
+	Use:     "remove <issue-id> <n> [<n>...]",
+	Aliases: []string{"rm"},
+	Short:   "Delete checklist items from an issue",
+	Long: `Delete checklist items from an issue.
+
+The remaining items keep their numbers; new items are numbered after the
+highest one left.`,
+	Args: cobra.MinimumNArgs(2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("todo remove")
+		ctx := rootCtx
+		seqs, ok := parseTodoSeqs(args[1:])
+		if !ok {
+			FatalErrorRespectJSON("checklist item numbers must be positive integers, got %s", strings.Join(args[1:], " "))
+		}
+		issueID, err := utils.ResolvePartialID(ctx, getStore(), args[0])
+		if err != nil {
+			FatalErrorRespectJSON("resolving %s: %v", args[0], err)
+		}
+
+		removed := []int{}
+		for _, seq := range seqs {
+			ok, err := getStore().RemoveTodo(ctx, issueID, seq)
+			if err != nil {
+				FatalErrorRespectJSON("removing checklist item: %v", err)
+			}
+			if !ok {
+				fmt.Fprintf(os.Stderr, "Error: %s has no checklist item %d\n", issueID, seq)
+				continue
+			}
+			removed = append(removed, seq)
+		}
+		if len(removed) > 0 {
+			commandDidWrite.Store(true)
+		}
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{
+				"issue_id": issueID,
+				"removed":  removed,
+			})
+			return
+		}
+		for _, seq := range removed {
+			fmt.Printf("%s Removed item %d from %s\n", ui.RenderPass("✓"), seq, issueID)
+		}
+	},
+}
+
+// parseTodoSeqs parses checklist item numbers. It reports false when args
+// is empty or any of them is not a positive integer.
+func parseTodoSeqs(args []string) ([]int, bool) {
+	if len(args) == 0 {
+		return nil, false
+	}
+	seqs := make([]int, 0, len(args))
+	for _, arg := range args {
+		n, err := strconv.Atoi(arg)
+		if err != nil || n < 1 {
+			return nil, false
+		}
+		seqs = append(seqs, n)
+	}
+	return seqs, true
+}
+
+func addChecklistItem(issueID, text string) {
+	todo := &types.TodoItem{
+		IssueID:   issueID,
+		Text:      text,
+		CreatedBy: getActorWithGit(),
+	}
+	if err := getStore().AddTodo(rootCtx, todo); err != nil {
+		FatalErrorRespectJSON("adding checklist item: %v", err)
+	}
+	commandDidWrite.Store(true)
+
+	if jsonOutput {
+		outputJSON(todo)
+		return
+	}
+	fmt.Printf("%s Added item %d to %s: %s\n", ui.RenderPass("✓"), todo.Seq, ui.RenderID(issueID), todo.Text)
+}
+
+func setChecklistDone(issueID string, seqs []int, done bool) {
+	updated := []*types.TodoItem{}
+	for _, seq := range seqs {
+		todo, err := getStore().SetTodoDone(rootCtx, issueID, seq, done)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			continue
+		}
+		updated = append(updated, todo)
+	}
+	if len(updated) > 0 {
+		commandDidWrite.Store(true)
+	}
+
+	if jsonOutput {
+		outputJSON(updated)
+		return
+	}
+	for _, todo := range updated {
+		fmt.Println(formatTodoLine(todo))
+	}
+}
+
+func listChecklist(issueID string) {
+	todos, err := getStore().GetTodosForIssues(rootCtx, []string{issueID})
+	if err != nil {
+		FatalErrorRespectJSON("listing checklist items: %v", err)
+	}
+	items := todos[issueID]
+
+	if jsonOutput {
+		if items == nil {
+			items = []*types.TodoItem{}
+		}
+		outputJSON(items)
+		return
+	}
+	if len(items) == 0 {
+		fmt.Printf("%s has no checklist items\n", ui.RenderID(issueID))
+		return
+	}
+	fmt.Printf("%s %s\n", ui.RenderID(issueID), ui.RenderMuted(todoProgress(items)))
+	for _, todo := range items {
+		fmt.Println(formatTodoLine(todo))
+	}
+}
+
+// todoProgress summarizes a checklist as "(done/total done)".
+func todoProgress(items []*types.TodoItem) string {
+	done := 0
+	for _, todo := range items {
+		if todo.Done {
+			done++
+		}
+	}
+	return fmt.Sprintf("(%d/%d done)", done, len(items))
+}
+
+// formatTodoLine renders one checklist item, muted once it is ticked.
+func formatTodoLine(todo *types.TodoItem) string {
+	if todo.Done {
+		return ui.RenderMuted(fmt.Sprintf("  [x] %d. %s", todo.Seq, todo.Text))
+	}
+	return fmt.Sprintf("  [ ] %d. %s", todo.Seq, todo.Text)
+}
+
+// printTodoChecklist prints the CHECKLIST section of 'bd show' when the
+// issue has checklist items.
+func printTodoChecklist(ctx context.Context, s storage.DoltStorage, issueID string) {
+	todos, err := s.GetTodosForIssues(ctx, []string{issueID})
+	if err != nil || len(todos[issueID]) == 0 {
+		return
+	}
+	fmt.Printf("\n%s %s\n", ui.RenderBold("CHECKLIST"), ui.RenderMuted(todoProgress(todos[issueID])))
+	for _, todo := range todos[issueID] {
+		fmt.Println(formatTodoLine(todo))
+	}
+}
+
 func init() {
 	// Add subcommands
 	todoCmd.AddCommand(addTodoCmd)
 	todoCmd.AddCommand(listTodosCmd)
 	todoCmd.AddCommand(doneTodoCmd)
+	todoCmd.AddCommand(itemTodoCmd)
+	todoCmd.AddCommand(itemsTodoCmd)
+	todoCmd.AddCommand(checkTodoCmd)
+	todoCmd.AddCommand(removeTodoCmd)
 
 	// Add flags
 	addTodoCmd.Flags().IntP("priority", "p", 2, "Priority (0-4, default 2)")
@@ -204,6 +429,8 @@ func init() {
 
 	doneTodoCmd.Flags().String("reason", "", "Reason for closing (default: Completed)")
 
+	checkTodoCmd.Flags().Bool("undo", false, "Untick the given items instead")
+
 	// Register with root
 	rootCmd.AddCommand(todoCmd)
 }
@@ -276,6 +276,65 @@ func TestEmbeddedTodo(t *testing.T) {
 		}
 	})
 
+	// ===== Checklist items =====
+
+	t.Run("todo_checklist", func(t *testing.T) {
+		issue := bdCreate(t, bd, dir, "Checklist parent", "--type", "feature")
+
+		out := bdTodo(t, bd, dir, "item", issue.ID, "write", "tests")
+		if !strings.Contains(out, "Added item 1") {
+			t.Errorf("expected 'Added item 1' in output: %s", out)
+		}
+		bdTodo(t, bd, dir, "item", issue.ID, "update docs")
+		bdTodo(t, bd, dir, "item", issue.ID, "changelog")
+
+		bdTodo(t, bd, dir, "check", issue.ID, "1", "3")
+		bdTodo(t, bd, dir, "check", issue.ID, "3", "--undo")
+		bdTodo(t, bd, dir, "remove", issue.ID, "2")
+
+		cmd := exec.Command(bd, "todo", "items", issue.ID, "--json")
+		cmd.Dir = dir
+		cmd.Env = bdEnv(dir)
+		stdout, stderr, err := runCommandBuffers(t, cmd)
+		if err != nil {
+			t.Fatalf("bd todo items %s --json failed: %v\nstdout:\n%s\nstderr:\n%s", issue.ID, err, stdout.String(), stderr.String())
+		}
+		s := strings.TrimSpace(stdout.String())
+		start := strings.Index(s, "[")
+		if start < 0 {
+			t.Fatalf("no JSON array in output: %s", s)
+		}
+		var items []struct {
+			Seq  int    `json:"seq"`
+			Text string `json:"text"`
+			Done bool   `json:"done"`
+		}
+		if err := json.Unmarshal([]byte(s[start:]), &items); err != nil {
+			t.Fatalf("parse checklist JSON: %v\n%s", err, s)
+		}
+		if len(items) != 2 {
+			t.Fatalf("expected 2 checklist items, got %+v", items)
+		}
+		if items[0].Seq != 1 || items[0].Text != "write tests" || !items[0].Done {
+			t.Errorf("item 1 = %+v, want ticked 'write tests'", items[0])
+		}
+		if items[1].Seq != 3 || items[1].Done {
+			t.Errorf("item 3 = %+v, want unticked", items[1])
+		}
+
+		// The parent stays open: ticking items never closes the issue.
+		if got := bdShow(t, bd, dir, issue.ID); got.Status != "open" {
+			t.Errorf("expected parent to stay open, got %s", got.Status)
+		}
+
+		// 'bd todo add' keeps creating TODOs even when the title starts
+		// with an existing issue ID.
+		out = bdTodo(t, bd, dir, "add", issue.ID, "follow-up")
+		if !strings.Contains(out, "Created") || !strings.Contains(out, issue.ID+" follow-up") {
+			t.Errorf("expected a TODO titled %q: %s", issue.ID+" follow-up", out)
+		}
+	})
+
 	// ===== Lifecycle =====
 
 	t.Run("todo_lifecycle", func(t *testing.T) {
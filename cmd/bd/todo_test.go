@@ -0,0 +1,41 @@
+package main
+
+import (
+	"slices"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseTodoSeqs(t *testing.T) {
+	tests := []struct {
+		args []string
+		want []int
+		ok   bool
+	}{
+		{args: []string{"3"}, want: []int{3}, ok: true},
+		{args: []string{"1", "4"}, want: []int{1, 4}, ok: true},
+		{args: nil},
+		{args: []string{"bd-12"}},
+		{args: []string{"2", "bd-12"}},
+		{args: []string{"0"}},
+		{args: []string{"-1"}},
+	}
+	for _, tt := range tests {
+		got, ok := parseTodoSeqs(tt.args)
+		if ok != tt.ok || !slices.Equal(got, tt.want) {
+			t.Errorf("parseTodoSeqs(%q) = %v, %v; want %v, %v", tt.args, got, ok, tt.want, tt.ok)
+		}
+	}
+}
+
+func TestTodoProgress(t *testing.T) {
+	items := []*types.TodoItem{
+		{Seq: 1, Text: "write tests", Done: true},
+		{Seq: 2, Text: "update docs"},
+		{Seq: 4, Text: "changelog"},
+	}
+	if got := todoProgress(items); got != "(1/3 done)" {
+		t.Errorf("todoProgress = %q, want (1/3 done)", got)
+	}
+}
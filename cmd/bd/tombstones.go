@@ -0,0 +1,326 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// tombstoneConfigPrefix prefixes the config keys that record deleted
+// issues, one per issue ID. Like memories, they live in the config table so
+// they travel with the database and round-trip through JSONL.
+const tombstoneConfigPrefix = "tombstone."
+
+// tombstone records that an issue was deleted, so a JSONL file that still
+// has the issue does not bring it back. Export writes each one as a
+// {"_type":"deletion"} record and import applies it.
+type tombstone struct {
+	ID        string    `json:"id"`
+	DeletedAt time.Time `json:"deleted_at"`
+	Actor     string    `json:"actor,omitempty"`
+}
+
+// buries reports whether issue is the copy that was deleted: one last
+// updated no later than the deletion. An issue updated after it was
+// deleted (re-created elsewhere) survives.
+func (t tombstone) buries(issue *types.Issue) bool {
+	return !issue.UpdatedAt.After(t.DeletedAt)
+}
+
+// configSetter is the part of storage.DoltStorage and storage.Transaction
+// that records tombstones.
+type configSetter interface {
+	SetConfig(ctx context.Context, key, value string) error
+}
+
+func setTombstone(ctx context.Context, s configSetter, t tombstone) error {
+	data, err := json.Marshal(tombstone{DeletedAt: t.DeletedAt.UTC(), Actor: t.Actor})
+	if err != nil {
+		return err
+	}
+	return s.SetConfig(ctx, tombstoneConfigPrefix+t.ID, string(data))
+}
+
+// recordTombstones records the deletion of ids by actor.
+func recordTombstones(ctx context.Context, s configSetter, ids []string, actor string) error {
+	now := time.Now().UTC()
+	for _, id := range ids {
+		if err := setTombstone(ctx, s, tombstone{ID: id, DeletedAt: now, Actor: actor}); err != nil {
+			return fmt.Errorf("recording deletion of %s: %w", id, err)
+		}
+	}
+	return nil
+}
+
+// recordDeletedTombstones records tombstones for the candidates that no
+// longer exist, after a delete that may not report exactly what it removed
+// (a cascade, or a loop that skips failures), and returns the IDs deleted.
+// Failure is only a warning: the issues are already gone.
+func recordDeletedTombstones(ctx context.Context, s storage.DoltStorage, candidates []string) []string {
+	if len(candidates) == 0 {
+		return nil
+	}
+	remaining, err := s.GetIssuesByIDs(ctx, candidates)
+	if err != nil {
+		WarnError("could not record deletions for sync: %v", err)
+		return nil
+	}
+	exists := make(map[string]bool, len(remaining))
+	for _, issue := range remaining {
+		exists[issue.ID] = true
+	}
+	var gone []string
+	for _, id := range candidates {
+		if !exists[id] {
+			gone = append(gone, id)
+		}
+	}
+	if err := recordTombstones(ctx, s, gone, getActor()); err != nil {
+		WarnError("could not record deletions for sync: %v", err)
+	}
+	return gone
+}
+
+// clearTombstones forgets the deletion of ids, for a restore that brings
+// them back on purpose.
+func clearTombstones(ctx context.Context, s storage.DoltStorage, ids []string) error {
+	recorded, err := loadTombstones(ctx, s)
+	if err != nil {
+		return err
+	}
+	for _, id := range ids {
+		if _, ok := recorded[id]; !ok {
+			continue
+		}
+		if err := s.DeleteConfig(ctx, tombstoneConfigPrefix+id); err != nil {
+			return fmt.Errorf("clearing deletion record for %s: %w", id, err)
+		}
+	}
+	return nil
+}
+
+// expiredTombstones returns, sorted, the IDs of tombstones recorded before
+// cutoff. Tombstones without a deletion time never expire here: they are
+// never written, only read from old JSONL.
+func expiredTombstones(recorded map[string]tombstone, cutoff time.Time) []string {
+	var expired []string
+	for id, t := range recorded {
+		if !t.DeletedAt.IsZero() && t.DeletedAt.Before(cutoff) {
+			expired = append(expired, id)
+		}
+	}
+	sort.Strings(expired)
+	return expired
+}
+
+// pruneTombstones forgets deletions recorded before cutoff and returns the
+// IDs forgotten. Once a tombstone is gone, a JSONL file that still has the
+// issue brings it back, so cutoff should be well past the point where any
+// clone still holds such a file. With dryRun it only reports.
+func pruneTombstones(ctx context.Context, s storage.DoltStorage, cutoff time.Time, dryRun bool) ([]string, error) {
+	recorded, err := loadTombstones(ctx, s)
+	if err != nil {
+		return nil, err
+	}
+	expired := expiredTombstones(recorded, cutoff)
+	if dryRun {
+		return expired, nil
+	}
+	for _, id := range expired {
+		if err := s.DeleteConfig(ctx, tombstoneConfigPrefix+id); err != nil {
+			return nil, fmt.Errorf("pruning deletion record for %s: %w", id, err)
+		}
+	}
+	return expired, nil
+}
+
+// tombstonesIn returns the tombstones among config entries, sorted by ID.
+func tombstonesIn(entries map[string]string) []tombstone {
+	var out []tombstone
+	for key, value := range entries {
+		if !strings.HasPrefix(key, tombstoneConfigPrefix) {
+			continue
+		}
+		var t tombstone
+		if err := json.Unmarshal([]byte(value), &t); err != nil || t.DeletedAt.IsZero() {
+			continue
+		}
+		t.ID = strings.TrimPrefix(key, tombstoneConfigPrefix)
+		if t.ID != "" {
+			out = append(out, t)
+		}
+	}
+	sort.Slice(out, func(i, j int) bool { return out[i].ID < out[j].ID })
+	return out
+}
+
+// loadTombstones returns the tombstones recorded in s, keyed by issue ID.
+func loadTombstones(ctx context.Context, s storage.DoltStorage) (map[string]tombstone, error) {
+	all, err := s.GetAllConfig(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("reading deletion records: %w", err)
+	}
+	out := make(map[string]tombstone)
+	for _, t := range tombstonesIn(all) {
+		out[t.ID] = t
+	}
+	return out, nil
+}
+
+// withoutTombstoned drops the issues a recorded tombstone buries, returning
+// the rest and the IDs dropped.
+func withoutTombstoned(ctx context.Context, s storage.DoltStorage, issues []*types.Issue) ([]*types.Issue, []string, error) {
+	tombstones, err := loadTombstones(ctx, s)
+	if err != nil || len(tombstones) == 0 {
+		return issues, nil, err
+	}
+	kept := issues[:0:0]
+	var buried []string
+	for _, issue := range issues {
+		if t, ok := tombstones[issue.ID]; ok && t.buries(issue) {
+			buried = append(buried, issue.ID)
+			continue
+		}
+		kept = append(kept, issue)
+	}
+	return kept, buried, nil
+}
+
+// applyTombstones records incoming tombstones (keeping the later of two for
+// the same issue) and deletes the local issues they bury, keeping them in
+// the trash as 'bd delete' does. The tombstones are recorded in the same
+// transaction as the delete. It returns the IDs deleted.
+func applyTombstones(ctx context.Context, s storage.DoltStorage, incoming []tombstone) ([]string, error) {
+	if len(incoming) == 0 {
+		return nil, nil
+	}
+	existing, err := loadTombstones(ctx, s)
+	if err != nil {
+		return nil, err
+	}
+	effective := make([]tombstone, 0, len(incoming))
+	var newer []tombstone
+	for _, t := range incoming {
+		if e, ok := existing[t.ID]; ok && !t.DeletedAt.After(e.DeletedAt) {
+			effective = append(effective, e)
+			continue
+		}
+		newer = append(newer, t)
+		effective = append(effective, t)
+	}
+	record := func(tx configSetter) error {
+		for _, t := range newer {
+			if err := setTombstone(ctx, tx, t); err != nil {
+				return fmt.Errorf("recording deletion of %s: %w", t.ID, err)
+			}
+		}
+		return nil
+	}
+
+	doomed, err := localIssuesBuried(ctx, s, effective)
+	if err != nil {
+		return nil, err
+	}
+	if len(doomed) == 0 {
+		return nil, record(s)
+	}
+	deleted, err := deleteToTrashInTx(ctx, s, doomed, getActor(), func(tx storage.Transaction, _ []string) error {
+		return record(tx)
+	})
+	if err != nil {
+		return nil, fmt.Errorf("applying deletions: %w", err)
+	}
+	return deleted, nil
+}
+
+// localIssuesBuried returns, sorted, the IDs of local issues that
+// tombstones bury.
+func localIssuesBuried(ctx context.Context, s storage.DoltStorage, tombstones []tombstone) ([]string, error) {
+	byID := make(map[string]tombstone, len(tombstones))
+	ids := make([]string, 0, len(tombstones))
+	for _, t := range tombstones {
+		byID[t.ID] = t
+		ids = append(ids, t.ID)
+	}
+	local, err := s.GetIssuesByIDs(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("check existing issues for deletions: %w", err)
+	}
+	var buried []string
+	for _, issue := range local {
+		if t, ok := byID[issue.ID]; ok && t.buries(issue) {
+			buried = append(buried, issue.ID)
+		}
+	}
+	sort.Strings(buried)
+	return buried, nil
+}
+
+// tombstonesFor returns a tombstone per id, with the recorded deletion time
+// and actor where there is one.
+func tombstonesFor(ids []string, recorded map[string]tombstone) []tombstone {
+	out := make([]tombstone, 0, len(ids))
+	for _, id := range ids {
+		t, ok := recorded[id]
+		if !ok {
+			t = tombstone{ID: id}
+		}
+		out = append(out, t)
+	}
+	return out
+}
+
+// sortedTombstones returns recorded in ID order.
+func sortedTombstones(recorded map[string]tombstone) []tombstone {
+	out := make([]tombstone, 0, len(recorded))
+	for _, t := range recorded {
+		out = append(out, t)
+	}
+	sort.Slice(out, func(i, j int) bool { return out[i].ID < out[j].ID })
+	return out
+}
+
+// writeJSONLDeletions writes a deletion record per tombstone. 'bd import'
+// deletes the issue unless it was updated after deleted_at; a record
+// without deleted_at (an issue deleted before tombstones were recorded)
+// is informational only.
+func writeJSONLDeletions(w io.Writer, tombstones []tombstone) error {
+	for _, t := range tombstones {
+		record := map[string]interface{}{
+			"_type":   "deletion",
+			"_format": jsonlFormatVersion,
+			"id":      t.ID,
+		}
+		if !t.DeletedAt.IsZero() {
+			record["deleted_at"] = t.DeletedAt.UTC()
+		}
+		if t.Actor != "" {
+			record["actor"] = t.Actor
+		}
+		data, err := json.Marshal(record)
+		if err != nil {
+			return err
+		}
+		if _, err := w.Write(append(data, '\n')); err != nil {
+			return fmt.Errorf("failed to write: %w", err)
+		}
+	}
+	return nil
+}
+
+// parseJSONLDeletion decodes a deletion record, reporting false for one
+// without deleted_at.
+func parseJSONLDeletion(line []byte) (tombstone, bool) {
+	var t tombstone
+	if err := json.Unmarshal(line, &t); err != nil || t.ID == "" || t.DeletedAt.IsZero() {
+		return tombstone{}, false
+	}
+	return t, true
+}
@@ -0,0 +1,317 @@
+package main
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// snapshotForTrash captures the issues among ids that exist in s as they
+// are just before deletion, loaded as for export, with the dependencies
+// other issues have on them, for the trash once they are deleted.
+func snapshotForTrash(ctx context.Context, s storage.DoltStorage, ids []string, actor string) ([]*types.TrashedIssue, error) {
+	issues, err := s.GetIssuesByIDs(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("reading issues to delete: %w", err)
+	}
+	loadExportRelationsFrom(ctx, s, issues)
+	now := time.Now().UTC()
+	entries := make([]*types.TrashedIssue, 0, len(issues))
+	for _, issue := range issues {
+		dependents, err := s.GetDependentsWithMetadata(ctx, issue.ID)
+		if err != nil {
+			return nil, fmt.Errorf("reading dependents of %s: %w", issue.ID, err)
+		}
+		entry := &types.TrashedIssue{ID: issue.ID, DeletedAt: now, Actor: actor, Issue: issue}
+		for _, d := range dependents {
+			entry.Dependents = append(entry.Dependents, &types.Dependency{
+				IssueID:     d.ID,
+				DependsOnID: issue.ID,
+				Type:        d.DependencyType,
+			})
+		}
+		entries = append(entries, entry)
+	}
+	return entries, nil
+}
+
+// putInTrash keeps entries in the trash ahead of the delete they are for,
+// so an issue is never removed without a copy 'bd restore' can bring back.
+// The caller aborts the delete when this fails.
+func putInTrash(ctx context.Context, s storage.TrashStore, entries []*types.TrashedIssue) error {
+	if len(entries) == 0 {
+		return nil
+	}
+	if err := s.PutTrash(ctx, entries); err != nil {
+		return fmt.Errorf("keeping deleted issues in the trash: %w", err)
+	}
+	return nil
+}
+
+// dropUndeletedFromTrash takes back the entries putInTrash wrote whose
+// issues are not in gone, after a delete that failed or did not remove
+// everything it was asked to. Failure is only a warning: restore refuses
+// an issue that still exists.
+func dropUndeletedFromTrash(ctx context.Context, s storage.TrashStore, entries []*types.TrashedIssue, gone []string) {
+	deleted := make(map[string]bool, len(gone))
+	for _, id := range gone {
+		deleted[id] = true
+	}
+	var kept []string
+	for _, e := range entries {
+		if !deleted[e.ID] {
+			kept = append(kept, e.ID)
+		}
+	}
+	if len(kept) == 0 {
+		return
+	}
+	if err := s.RemoveTrash(ctx, kept); err != nil {
+		WarnError("could not take issues that were not deleted out of the trash: %v", err)
+	}
+}
+
+// deleteToTrash deletes ids from s one at a time, keeping a copy of each in
+// the trash first, and returns the IDs it deleted. An ID that is already
+// gone counts as deleted.
+func deleteToTrash(ctx context.Context, s storage.DoltStorage, ids []string, actor string) ([]string, error) {
+	trashed, err := snapshotForTrash(ctx, s, ids, actor)
+	if err != nil {
+		return nil, err
+	}
+	if err := putInTrash(ctx, s, trashed); err != nil {
+		return nil, err
+	}
+	var gone []string
+	for _, id := range ids {
+		if err := s.DeleteIssue(ctx, id); err != nil && !errors.Is(err, storage.ErrNotFound) {
+			dropUndeletedFromTrash(ctx, s, trashed, gone)
+			return gone, fmt.Errorf("deleting %s: %w", id, err)
+		}
+		gone = append(gone, id)
+	}
+	return gone, nil
+}
+
+// deleteToTrashInTx deletes ids from s in one transaction, keeping a copy
+// of each in the trash first, and returns the IDs it deleted. record runs
+// in the same transaction with those IDs, so the deletion and its
+// tombstones are written together.
+func deleteToTrashInTx(ctx context.Context, s storage.DoltStorage, ids []string, actor string, record func(tx storage.Transaction, deleted []string) error) ([]string, error) {
+	trashed, err := snapshotForTrash(ctx, s, ids, actor)
+	if err != nil {
+		return nil, err
+	}
+	if err := putInTrash(ctx, s, trashed); err != nil {
+		return nil, err
+	}
+	var deleted []string
+	err = s.RunInTransaction(ctx, "", func(tx storage.Transaction) error {
+		result, err := tx.DeleteIssues(ctx, ids, false, true)
+		if err != nil {
+			return err
+		}
+		deleted = result.DeletedIDs
+		return record(tx, deleted)
+	})
+	if err != nil {
+		deleted = nil
+	}
+	dropUndeletedFromTrash(ctx, s, trashed, deleted)
+	return deleted, err
+}
+
+// restoreFromTrash re-creates the deleted issue id from the trash, with its
+// relations and the dependencies of issues that still exist, and forgets
+// its deletion. It returns the restored entry and the dependents that could
+// not be re-linked.
+func restoreFromTrash(ctx context.Context, s storage.DoltStorage, id string) (*types.TrashedIssue, []string, error) {
+	entry, err := s.GetTrash(ctx, id)
+	if err != nil {
+		return nil, nil, err
+	}
+	if entry == nil {
+		return nil, nil, fmt.Errorf("%s is not in the trash (see 'bd trash list')", id)
+	}
+	if _, err := s.GetIssue(ctx, id); err == nil {
+		return nil, nil, fmt.Errorf("%s already exists; remove it from the trash with 'bd trash empty'", id)
+	} else if !isNotFoundErr(err) {
+		return nil, nil, err
+	}
+
+	if err := clearTombstones(ctx, s, []string{id}); err != nil {
+		return nil, nil, err
+	}
+	opts := ImportOptions{SkipPrefixValidation: true}
+	if _, err := importIssuesCore(ctx, "", s, []*types.Issue{entry.Issue}, opts); err != nil {
+		return nil, nil, fmt.Errorf("restoring %s: %w", id, err)
+	}
+
+	var unlinked []string
+	for _, dep := range entry.Dependents {
+		if err := s.AddDependency(ctx, dep, actor); err != nil {
+			unlinked = append(unlinked, dep.IssueID)
+		}
+	}
+	if err := s.RemoveTrash(ctx, []string{id}); err != nil {
+		return nil, nil, err
+	}
+	return entry, unlinked, nil
+}
+
+// runTrashRestore restores id from the trash and reports the result.
+func runTrashRestore(ctx context.Context, id string) {
+	CheckReadonly("restore")
+	entry, unlinked, err := restoreFromTrash(ctx, store, id)
+	if err != nil {
+		FatalErrorRespectJSON("%v", err)
+	}
+	commandDidWrite.Store(true)
+	SetLastTouchedID(entry.ID)
+
+	if jsonOutput {
+		outputJSON(map[string]interface{}{
+			"restored":            entry.ID,
+			"deleted_at":          entry.DeletedAt,
+			"unlinked_dependents": unlinked,
+		})
+		return
+	}
+	fmt.Printf("%s Restored %s from the trash\n", ui.RenderPass("✓"), formatFeedbackID(entry.ID, entry.Issue.Title))
+	if len(unlinked) > 0 {
+		fmt.Printf("  %s Could not re-link dependents: %s\n", ui.RenderWarn("⚠"), strings.Join(unlinked, ", "))
+	}
+	fmt.Printf("  Text references in other issues still read [deleted:%s]\n", entry.ID)
+}
+
+var trashCmd = &cobra.Command{
+	Use:     "trash",
+	GroupID: "issues",
+	Short:   "List, restore, or empty deleted issues",
+	Long: `Manage issues removed by 'bd delete'.
+
+Deleted issues are hidden from list, search, and every other view, but a
+copy stays in the trash until it is emptied. The trash is local to this
+clone: pushing shares the deletion, not the copy. 'bd restore <id>' (or
+'bd trash restore <id>') brings one back with its labels, comments, and
+dependencies. Only 'bd trash empty --older-than <age>' discards them for
+good.
+
+Examples:
+  bd trash list
+  bd trash restore bd-123
+  bd trash empty --older-than 30d`,
+}
+
+var trashListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List deleted issues in the trash",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		entries, err := store.ListTrash(rootCtx)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			type listed struct {
+				ID        string    `json:"id"`
+				Title     string    `json:"title"`
+				DeletedAt time.Time `json:"deleted_at"`
+				Actor     string    `json:"actor,omitempty"`
+			}
+			out := make([]listed, 0, len(entries))
+			for _, e := range entries {
+				out = append(out, listed{ID: e.ID, Title: e.Issue.Title, DeletedAt: e.DeletedAt, Actor: e.Actor})
+			}
+			outputJSON(out)
+			return
+		}
+		if len(entries) == 0 {
+			fmt.Println("Trash is empty")
+			return
+		}
+		for _, e := range entries {
+			by := ""
+			if e.Actor != "" {
+				by = " by " + e.Actor
+			}
+			fmt.Printf("%s %s %s\n", ui.RenderID(e.ID), e.Issue.Title,
+				ui.RenderMuted(fmt.Sprintf("(deleted %s%s)", formatTimeAgo(e.DeletedAt), by)))
+		}
+	},
+}
+
+var trashRestoreCmd = &cobra.Command{
+	Use:   "restore <issue-id>",
+	Short: "Restore a deleted issue from the trash",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		runTrashRestore(rootCtx, args[0])
+	},
+}
+
+var trashEmptyCmd = &cobra.Command{
+	Use:   "empty",
+	Short: "Permanently discard old issues from the trash",
+	Long: `Permanently discard issues deleted more than --older-than ago.
+
+Their deletion records stay, so a JSONL import still does not bring them
+back.
+
+Examples:
+  bd trash empty --older-than 30d
+  bd trash empty --older-than 2w`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("trash empty")
+		ctx := rootCtx
+		olderThan, _ := cmd.Flags().GetString("older-than")
+		if olderThan == "" {
+			FatalErrorRespectJSON("--older-than is required (e.g. --older-than 30d)")
+		}
+		days, err := parseHumanDuration(olderThan)
+		if err != nil {
+			FatalErrorRespectJSON("invalid --older-than %q: %v", olderThan, err)
+		}
+		cutoff := time.Now().Add(-time.Duration(days) * 24 * time.Hour)
+
+		entries, err := store.ListTrash(ctx)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		emptied := []string{}
+		for _, e := range entries {
+			if e.DeletedAt.Before(cutoff) {
+				emptied = append(emptied, e.ID)
+			}
+		}
+		if err := store.RemoveTrash(ctx, emptied); err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{
+				"emptied":   emptied,
+				"remaining": len(entries) - len(emptied),
+			})
+			return
+		}
+		fmt.Printf("%s Discarded %d issue(s) deleted more than %d day(s) ago; %d remain in the trash\n",
+			ui.RenderPass("✓"), len(emptied), days, len(entries)-len(emptied))
+	},
+}
+
+func init() {
+	trashEmptyCmd.Flags().String("older-than", "", "Discard issues deleted more than this long ago (e.g., 30d, 2w, 30)")
+	trashCmd.AddCommand(trashListCmd)
+	trashCmd.AddCommand(trashRestoreCmd)
+	trashCmd.AddCommand(trashEmptyCmd)
+	rootCmd.AddCommand(trashCmd)
+}
@@ -0,0 +1,42 @@
+package main
+
+import (
+	"context"
+	"slices"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+type fakeTrashStore struct {
+	storage.TrashStore
+	removed []string
+}
+
+func (f *fakeTrashStore) RemoveTrash(_ context.Context, ids []string) error {
+	f.removed = append(f.removed, ids...)
+	return nil
+}
+
+func TestDropUndeletedFromTrash(t *testing.T) {
+	at := time.Date(2026, 3, 4, 0, 0, 0, 0, time.UTC)
+	entries := []*types.TrashedIssue{
+		{ID: "bd-1", DeletedAt: at, Issue: &types.Issue{ID: "bd-1"}},
+		{ID: "bd-2", DeletedAt: at, Issue: &types.Issue{ID: "bd-2"}},
+	}
+
+	// Only the issues the delete left in place come back out of the trash.
+	st := &fakeTrashStore{}
+	dropUndeletedFromTrash(context.Background(), st, entries, []string{"bd-2"})
+	if !slices.Equal(st.removed, []string{"bd-1"}) {
+		t.Errorf("removed %v, want only bd-1", st.removed)
+	}
+
+	st = &fakeTrashStore{}
+	dropUndeletedFromTrash(context.Background(), st, entries, []string{"bd-1", "bd-2"})
+	if st.removed != nil {
+		t.Errorf("removed %v when everything was deleted", st.removed)
+	}
+}
@@ -2,10 +2,15 @@ package main
 
 import (
 	"context"
+	"encoding/json"
 	"fmt"
 	"os"
+	"slices"
+	"sort"
+	"strings"
 
 	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
 )
 
@@ -94,6 +99,77 @@ type typeInfo struct {
 	Description string `json:"description"`
 }
 
+// ensureCustomTypes registers, in types.custom, the issue types of issues
+// that are neither built in nor already configured, so issues carrying a
+// project's custom types (from another clone's JSONL, or a formula) are
+// accepted rather than failing validation. It returns the types it added,
+// sorted.
+func ensureCustomTypes(ctx context.Context, s storage.DoltStorage, issues []*types.Issue) ([]string, error) {
+	needed := make(map[string]bool)
+	for _, issue := range issues {
+		if issue == nil {
+			continue
+		}
+		if t := issue.IssueType; t != "" && !t.IsValid() {
+			needed[string(t)] = true
+		}
+	}
+	if len(needed) == 0 {
+		return nil, nil
+	}
+
+	// GetCustomTypes also covers types.custom in config.yaml; only the
+	// database setting is written back.
+	configured, err := s.GetCustomTypes(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("reading custom types: %w", err)
+	}
+	var toAdd []string
+	for t := range needed {
+		if !slices.Contains(configured, t) {
+			toAdd = append(toAdd, t)
+		}
+	}
+	if len(toAdd) == 0 {
+		return nil, nil
+	}
+	sort.Strings(toAdd)
+
+	existing, _ := s.GetConfig(ctx, "types.custom")
+	current := parseCustomTypesValue(existing)
+	for _, t := range toAdd {
+		if !slices.Contains(current, t) {
+			current = append(current, t)
+		}
+	}
+	// Stored as a JSON array, like bd config set. SetConfig also syncs the
+	// custom_types table that issue validation reads.
+	value, err := json.Marshal(current)
+	if err != nil {
+		return nil, err
+	}
+	if err := s.SetConfig(ctx, "types.custom", string(value)); err != nil {
+		return nil, fmt.Errorf("registering custom types %s: %w", strings.Join(toAdd, ", "), err)
+	}
+	return toAdd, nil
+}
+
+// parseCustomTypesValue reads a types.custom value, either a JSON array or
+// a comma-separated list.
+func parseCustomTypesValue(value string) []string {
+	var list []string
+	if err := json.Unmarshal([]byte(value), &list); err != nil {
+		list = strings.Split(value, ",")
+	}
+	out := make([]string, 0, len(list))
+	for _, t := range list {
+		if t = strings.TrimSpace(t); t != "" {
+			out = append(out, t)
+		}
+	}
+	return out
+}
+
 func init() {
 	rootCmd.AddCommand(typesCmd)
 }
@@ -0,0 +1,55 @@
+package main
+
+import (
+	"context"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// customTypesStore serves types.custom from a config map, plus types
+// configured elsewhere (config.yaml).
+type customTypesStore struct {
+	configMapStore
+	yamlTypes []string
+}
+
+func (s *customTypesStore) GetConfig(_ context.Context, key string) (string, error) {
+	return s.config[key], nil
+}
+
+func (s *customTypesStore) GetCustomTypes(_ context.Context) ([]string, error) {
+	return append(parseCustomTypesValue(s.config["types.custom"]), s.yamlTypes...), nil
+}
+
+func TestEnsureCustomTypes(t *testing.T) {
+	ctx := context.Background()
+	st := &customTypesStore{
+		configMapStore: configMapStore{config: map[string]string{"types.custom": "agent, molecule"}},
+		yamlTypes:      []string{"docs"},
+	}
+	issues := []*types.Issue{
+		{IssueType: types.TypeTask},
+		{IssueType: "incident"},
+		{IssueType: "agent"},
+		{IssueType: "docs"},
+		{IssueType: "incident"},
+		{IssueType: "rfc"},
+	}
+
+	added, err := ensureCustomTypes(ctx, st, issues)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := strings.Join(added, ","); got != "incident,rfc" {
+		t.Errorf("added = %q, want incident,rfc", got)
+	}
+	if got := st.config["types.custom"]; got != `["agent","molecule","incident","rfc"]` {
+		t.Errorf("types.custom = %s", got)
+	}
+
+	if added, err := ensureCustomTypes(ctx, st, issues); err != nil || added != nil {
+		t.Errorf("second ensureCustomTypes = %v, %v; want nothing to add", added, err)
+	}
+}
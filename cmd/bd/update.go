@@ -1,6 +1,7 @@
 package main
 
 import (
+	"context"
 	"encoding/json"
 	"fmt"
 	"os"
@@ -139,6 +140,17 @@ create, update, show, or close operation).`,
 			specID, _ := cmd.Flags().GetString("spec-id")
 			updates["spec_id"] = specID
 		}
+		if cmd.Flags().Changed("severity") {
+			severityStr, _ := cmd.Flags().GetString("severity")
+			var severity types.Severity
+			if severityStr != "" {
+				var err error
+				if severity, err = types.ParseSeverity(severityStr); err != nil {
+					FatalErrorRespectJSON("%v", err)
+				}
+			}
+			updates["severity"] = string(severity)
+		}
 		if cmd.Flags().Changed("estimate") {
 			estimate, _ := cmd.Flags().GetInt("estimate")
 			if estimate < 0 {
@@ -280,9 +292,21 @@ create, update, show, or close operation).`,
 			updates["_set_metadata"] = setMetadataFlags
 			updates["_unset_metadata"] = unsetMetadataFlags
 		}
+		// Custom fields live in their own metadata object (--field points=3)
+		if fieldFlags, _ := cmd.Flags().GetStringArray("field"); len(fieldFlags) > 0 {
+			updates["_set_fields"] = fieldFlags
+		}
 
 		// Get claim flag
 		claimFlag, _ := cmd.Flags().GetBool("claim")
+		leaseFlag, _ := cmd.Flags().GetString("lease")
+		lease, err := parseLeaseFlag(leaseFlag)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if lease > 0 && !claimFlag {
+			FatalErrorRespectJSON("--lease requires --claim")
+		}
 
 		if len(updates) == 0 && !claimFlag {
 			fmt.Println("No updates specified")
@@ -291,6 +315,8 @@ create, update, show, or close operation).`,
 
 		ctx := rootCtx
 
+		force, _ := cmd.Flags().GetBool("force")
+
 		updatedIssues := []*types.Issue{}
 		var firstUpdatedID string // Track first successful update for last-touched
 		for _, id := range args {
@@ -313,146 +339,18 @@ create, update, show, or close operation).`,
 			issue := result.Issue
 			issueStore := result.Store
 
-			if err := validateIssueUpdatable(id, issue); err != nil {
-				fmt.Fprintf(os.Stderr, "%s\n", err)
+			after, err := updateIssueChecked(ctx, issueStore, issueStore, issue, updates, issueUpdateOptions{
+				Claim:            claimFlag,
+				Lease:            lease,
+				Force:            force,
+				ClearDeferStatus: clearDeferStatus,
+			}, actor)
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "Error updating %s: %v\n", id, err)
 				result.Close()
 				continue
 			}
-
-			// Handle claim operation atomically using compare-and-swap semantics
-			if claimFlag {
-				if err := issueStore.ClaimIssue(ctx, result.ResolvedID, actor); err != nil {
-					fmt.Fprintf(os.Stderr, "Error claiming %s: %v\n", id, err)
-					result.Close()
-					continue
-				}
-			}
-
-			// Apply regular field updates if any
-			regularUpdates := make(map[string]interface{})
-			for k, v := range updates {
-				if k != "add_labels" && k != "remove_labels" && k != "set_labels" && k != "parent" && k != "append_notes" &&
-					k != "_set_metadata" && k != "_unset_metadata" {
-					regularUpdates[k] = v
-				}
-			}
-			// GH#3233: --defer="" restores ready visibility only if the issue
-			// was actually deferred. Other statuses (blocked, in_progress, …)
-			// shouldn't be clobbered just because defer_until was stale.
-			if clearDeferStatus && issue.Status == types.StatusDeferred {
-				regularUpdates["status"] = string(types.StatusOpen)
-			}
-
-			// Handle --metadata: merge with existing metadata instead of replacing
-			if newMeta, ok := regularUpdates["metadata"].(json.RawMessage); ok && len(issue.Metadata) > 0 {
-				merged, err := mergeMetadata(issue.Metadata, newMeta)
-				if err != nil {
-					FatalErrorRespectJSON("metadata merge failed for %s: %v", id, err)
-				}
-				regularUpdates["metadata"] = merged
-			}
-			// Handle incremental metadata edits (GH#1406)
-			if setMeta, ok := updates["_set_metadata"].([]string); ok {
-				unsetMeta, _ := updates["_unset_metadata"].([]string)
-				merged, err := applyMetadataEdits(issue.Metadata, setMeta, unsetMeta)
-				if err != nil {
-					FatalErrorRespectJSON("metadata edit failed for %s: %v", id, err)
-				}
-				regularUpdates["metadata"] = merged
-			}
-			// Handle append_notes: combine existing notes with new content
-			if appendNotes, ok := updates["append_notes"].(string); ok {
-				combined := issue.Notes
-				if combined != "" {
-					combined += "\n"
-				}
-				combined += appendNotes
-				regularUpdates["notes"] = combined
-			}
-			if len(regularUpdates) > 0 {
-				if err := issueStore.UpdateIssue(ctx, result.ResolvedID, regularUpdates, actor); err != nil {
-					fmt.Fprintf(os.Stderr, "Error updating %s: %v\n", id, err)
-					result.Close()
-					continue
-				}
-				// Audit log key field changes (survives Dolt GC flatten)
-				if s, ok := regularUpdates["status"].(string); ok {
-					audit.LogFieldChange(result.ResolvedID, "status", string(issue.Status), s, actor, "")
-				}
-				if a, ok := regularUpdates["assignee"].(string); ok {
-					audit.LogFieldChange(result.ResolvedID, "assignee", issue.Assignee, a, actor, "")
-				}
-				if p, ok := regularUpdates["priority"].(int); ok {
-					audit.LogFieldChange(result.ResolvedID, "priority", fmt.Sprintf("%d", issue.Priority), fmt.Sprintf("%d", p), actor, "")
-				}
-			}
-
-			// Handle label operations
-			var setLabels, addLabels, removeLabels []string
-			if v, ok := updates["set_labels"].([]string); ok {
-				setLabels = v
-			}
-			if v, ok := updates["add_labels"].([]string); ok {
-				addLabels = v
-			}
-			if v, ok := updates["remove_labels"].([]string); ok {
-				removeLabels = v
-			}
-			if len(setLabels) > 0 || len(addLabels) > 0 || len(removeLabels) > 0 {
-				if err := applyLabelUpdates(ctx, issueStore, result.ResolvedID, actor, setLabels, addLabels, removeLabels); err != nil {
-					fmt.Fprintf(os.Stderr, "Error updating labels for %s: %v\n", id, err)
-					result.Close()
-					continue
-				}
-			}
-
-			// Handle parent reparenting
-			if newParent, ok := updates["parent"].(string); ok {
-				// Validate new parent exists (unless empty string to remove parent)
-				if newParent != "" {
-					parentIssue, err := issueStore.GetIssue(ctx, newParent)
-					if err != nil {
-						fmt.Fprintf(os.Stderr, "Error getting parent %s: %v\n", newParent, err)
-						result.Close()
-						continue
-					}
-					if parentIssue == nil {
-						fmt.Fprintf(os.Stderr, "Error: parent issue %s not found\n", newParent)
-						result.Close()
-						continue
-					}
-				}
-
-				// Find and remove existing parent-child dependency
-				deps, err := issueStore.GetDependencyRecords(ctx, result.ResolvedID)
-				if err != nil {
-					fmt.Fprintf(os.Stderr, "Error getting dependencies for %s: %v\n", id, err)
-					result.Close()
-					continue
-				}
-				for _, dep := range deps {
-					if dep.Type == types.DepParentChild {
-						if err := issueStore.RemoveDependency(ctx, result.ResolvedID, dep.DependsOnID, actor); err != nil {
-							fmt.Fprintf(os.Stderr, "Error removing old parent dependency: %v\n", err)
-						}
-						break
-					}
-				}
-
-				// Add new parent-child dependency (if not removing parent)
-				if newParent != "" {
-					newDep := &types.Dependency{
-						IssueID:     result.ResolvedID,
-						DependsOnID: newParent,
-						Type:        types.DepParentChild,
-					}
-					if err := issueStore.AddDependency(ctx, newDep, actor); err != nil {
-						fmt.Fprintf(os.Stderr, "Error adding parent dependency: %v\n", err)
-						result.Close()
-						continue
-					}
-				}
-			}
+			after()
 
 			// Re-fetch for display
 			updatedIssue, _ := issueStore.GetIssue(ctx, result.ResolvedID)
@@ -497,6 +395,198 @@ create, update, show, or close operation).`,
 	},
 }
 
+// issueUpdateOptions are the parts of a 'bd update' besides its field
+// updates.
+type issueUpdateOptions struct {
+	Claim bool          // claim the issue first, atomically
+	Lease time.Duration // with Claim, how long the claim holds; 0 = until released
+	Force bool          // skip the status guards and, when closing, the close guards
+
+	// ClearDeferStatus reopens a deferred issue, for --defer="" without
+	// --status (GH#3233).
+	ClearDeferStatus bool
+}
+
+// updateIssueChecked applies updates to issue as 'bd update' does. updates
+// holds UpdateIssue fields plus the edits applied around them: add_labels,
+// remove_labels, set_labels, parent, append_notes, and the metadata edits
+// _set_metadata, _unset_metadata, and _set_fields. issue is its state
+// before the update.
+//
+// The update is refused if the issue is a template, or breaks the severity
+// or validation rules; unless opts.Force, also if it breaks the guards the
+// status.guards config of q enables or, closing the issue, the guards of
+// 'bd close'. w takes the writes; q answers the guard queries and is w
+// itself, or the store behind w when w is a transaction. A claim needs w
+// to be the store, since it commits on its own.
+//
+// The returned function records status, assignee, and priority changes in
+// the audit log; call it once the update is committed.
+func updateIssueChecked(ctx context.Context, w issueWriteStore, q storage.DoltStorage, issue *types.Issue, updates map[string]interface{}, opts issueUpdateOptions, actorName string) (func(), error) {
+	id := issue.ID
+	if err := validateIssueUpdatable(id, issue); err != nil {
+		return nil, err
+	}
+	if err := checkSeverityUpdate(issue, updates); err != nil {
+		return nil, err
+	}
+	if err := checkValidationRulesUpdate(issue, updates); err != nil {
+		return nil, err
+	}
+	if status, ok := updates["status"].(string); ok && !opts.Force {
+		if guards := loadStatusGuards(ctx, q); len(guards) > 0 {
+			assignee := issue.Assignee
+			if a, ok := updates["assignee"].(string); ok {
+				assignee = a
+			}
+			if err := checkStatusGuards(ctx, q, guards, issue, types.Status(status), assignee); err != nil {
+				return nil, err
+			}
+		}
+		if types.Status(status) == types.StatusClosed && issue.Status != types.StatusClosed {
+			if err := checkIssueClosable(ctx, q, id, issue, false); err != nil {
+				return nil, err
+			}
+		}
+	}
+
+	// Handle claim operation atomically using compare-and-swap semantics
+	if opts.Claim {
+		if issueWriteStore(q) != w {
+			return nil, fmt.Errorf("a claim commits on its own and can't be part of a transaction")
+		}
+		if err := q.ClaimIssueWithLease(ctx, id, actorName, opts.Lease); err != nil {
+			return nil, fmt.Errorf("claiming %s: %w", id, err)
+		}
+	}
+
+	// Apply regular field updates if any
+	regularUpdates := make(map[string]interface{})
+	for k, v := range updates {
+		if k != "add_labels" && k != "remove_labels" && k != "set_labels" && k != "parent" && k != "append_notes" &&
+			k != "_set_metadata" && k != "_unset_metadata" && k != "_set_fields" {
+			regularUpdates[k] = v
+		}
+	}
+	// GH#3233: --defer="" restores ready visibility only if the issue
+	// was actually deferred. Other statuses (blocked, in_progress, …)
+	// shouldn't be clobbered just because defer_until was stale.
+	if opts.ClearDeferStatus && issue.Status == types.StatusDeferred {
+		regularUpdates["status"] = string(types.StatusOpen)
+	}
+
+	// Handle --metadata: merge with existing metadata instead of replacing
+	if newMeta, ok := regularUpdates["metadata"].(json.RawMessage); ok && len(issue.Metadata) > 0 {
+		merged, err := mergeMetadata(issue.Metadata, newMeta)
+		if err != nil {
+			return nil, fmt.Errorf("metadata merge failed: %w", err)
+		}
+		regularUpdates["metadata"] = merged
+	}
+	// Handle incremental metadata edits (GH#1406)
+	if setMeta, ok := updates["_set_metadata"].([]string); ok {
+		unsetMeta, _ := updates["_unset_metadata"].([]string)
+		merged, err := applyMetadataEdits(issue.Metadata, setMeta, unsetMeta)
+		if err != nil {
+			return nil, fmt.Errorf("metadata edit failed: %w", err)
+		}
+		regularUpdates["metadata"] = merged
+	}
+	if setFields, ok := updates["_set_fields"].([]string); ok {
+		base := issue.Metadata
+		if edited, ok := regularUpdates["metadata"].(json.RawMessage); ok {
+			base = edited
+		}
+		merged, err := applyCustomFields(base, setFields)
+		if err != nil {
+			return nil, fmt.Errorf("custom field edit failed: %w", err)
+		}
+		regularUpdates["metadata"] = merged
+	}
+	// Handle append_notes: combine existing notes with new content
+	if appendNotes, ok := updates["append_notes"].(string); ok {
+		combined := issue.Notes
+		if combined != "" {
+			combined += "\n"
+		}
+		combined += appendNotes
+		regularUpdates["notes"] = combined
+	}
+	if len(regularUpdates) > 0 {
+		if err := w.UpdateIssue(ctx, id, regularUpdates, actorName); err != nil {
+			return nil, err
+		}
+	}
+
+	// Handle label operations
+	setLabels, _ := updates["set_labels"].([]string)
+	addLabels, _ := updates["add_labels"].([]string)
+	removeLabels, _ := updates["remove_labels"].([]string)
+	if len(setLabels) > 0 || len(addLabels) > 0 || len(removeLabels) > 0 {
+		if err := applyLabelUpdates(ctx, w, id, actorName, setLabels, addLabels, removeLabels); err != nil {
+			return nil, fmt.Errorf("updating labels: %w", err)
+		}
+	}
+
+	// Handle parent reparenting
+	if newParent, ok := updates["parent"].(string); ok {
+		if err := reparentIssue(ctx, w, id, newParent, actorName); err != nil {
+			return nil, err
+		}
+	}
+
+	return func() {
+		// Audit log key field changes (survives Dolt GC flatten)
+		if s, ok := regularUpdates["status"].(string); ok {
+			audit.LogFieldChange(id, "status", string(issue.Status), s, actorName, "")
+		}
+		if a, ok := regularUpdates["assignee"].(string); ok {
+			audit.LogFieldChange(id, "assignee", issue.Assignee, a, actorName, "")
+		}
+		if p, ok := regularUpdates["priority"].(int); ok {
+			audit.LogFieldChange(id, "priority", fmt.Sprintf("%d", issue.Priority), fmt.Sprintf("%d", p), actorName, "")
+		}
+	}, nil
+}
+
+// reparentIssue replaces the parent-child dependency of id with one on
+// newParent, or removes it when newParent is empty.
+func reparentIssue(ctx context.Context, w issueWriteStore, id, newParent, actorName string) error {
+	// Validate new parent exists (unless empty string to remove parent)
+	if newParent != "" {
+		if _, err := w.GetIssue(ctx, newParent); err != nil {
+			return fmt.Errorf("getting parent %s: %w", newParent, err)
+		}
+	}
+
+	// Find and remove existing parent-child dependency
+	deps, err := w.GetDependencyRecords(ctx, id)
+	if err != nil {
+		return fmt.Errorf("getting dependencies: %w", err)
+	}
+	for _, dep := range deps {
+		if dep.Type == types.DepParentChild {
+			if err := w.RemoveDependency(ctx, id, dep.DependsOnID, actorName); err != nil {
+				fmt.Fprintf(os.Stderr, "Error removing old parent dependency: %v\n", err)
+			}
+			break
+		}
+	}
+
+	// Add new parent-child dependency (if not removing parent)
+	if newParent != "" {
+		newDep := &types.Dependency{
+			IssueID:     id,
+			DependsOnID: newParent,
+			Type:        types.DepParentChild,
+		}
+		if err := w.AddDependency(ctx, newDep, actorName); err != nil {
+			return fmt.Errorf("adding parent dependency: %w", err)
+		}
+	}
+	return nil
+}
+
 // mergeMetadata merges new metadata JSON into existing metadata.
 // Keys from newMeta overwrite keys in existing; keys only in existing are preserved.
 func mergeMetadata(existing, newMeta json.RawMessage) (json.RawMessage, error) {
@@ -568,6 +658,48 @@ func applyMetadataEdits(existing json.RawMessage, setFlags, unsetFlags []string)
 	return json.RawMessage(result), nil
 }
 
+// applyCustomFields sets --field name=value pairs in the custom_fields
+// object of existing metadata, leaving its top-level keys alone.
+func applyCustomFields(existing json.RawMessage, fieldFlags []string) (json.RawMessage, error) {
+	data := make(map[string]json.RawMessage)
+	if len(existing) > 0 {
+		trimmed := strings.TrimSpace(string(existing))
+		if trimmed != "" && trimmed != "null" {
+			if err := json.Unmarshal(existing, &data); err != nil {
+				return nil, fmt.Errorf("existing metadata is not a JSON object: %w", err)
+			}
+		}
+	}
+
+	fields := make(map[string]json.RawMessage)
+	if raw, ok := data[storage.CustomFieldsMetadataKey]; ok && strings.TrimSpace(string(raw)) != "null" {
+		if err := json.Unmarshal(raw, &fields); err != nil {
+			return nil, fmt.Errorf("metadata %s is not a JSON object: %w", storage.CustomFieldsMetadataKey, err)
+		}
+	}
+	for _, kv := range fieldFlags {
+		k, v, ok := strings.Cut(kv, "=")
+		if !ok || k == "" {
+			return nil, fmt.Errorf("invalid --field: expected name=value, got %q", kv)
+		}
+		if err := storage.ValidateMetadataKey(k); err != nil {
+			return nil, err
+		}
+		fields[k] = toJSONValue(v)
+	}
+
+	encoded, err := json.Marshal(fields)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal custom fields: %w", err)
+	}
+	data[storage.CustomFieldsMetadataKey] = encoded
+	result, err := json.Marshal(data)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal metadata: %w", err)
+	}
+	return json.RawMessage(result), nil
+}
+
 // toJSONValue converts a string value to its most appropriate JSON representation.
 // Recognizes numbers, booleans, and null; everything else becomes a JSON string.
 func toJSONValue(s string) json.RawMessage {
@@ -594,6 +726,7 @@ func toJSONValue(s string) json.RawMessage {
 func init() {
 	updateCmd.Flags().StringP("status", "s", "", "New status")
 	registerPriorityFlag(updateCmd, "")
+	updateCmd.Flags().String("severity", "", "Bug severity (S0-S3, S0=most severe; \"\" to clear); bugs only")
 	updateCmd.Flags().String("title", "", "New title")
 	updateCmd.Flags().StringP("type", "t", "", "New type (bug|feature|task|epic|chore|decision); custom types require types.custom config")
 	registerCommonIssueFlags(updateCmd)
@@ -607,7 +740,9 @@ func init() {
 	updateCmd.Flags().StringSlice("set-labels", nil, "Set labels, replacing all existing (repeatable)")
 	updateCmd.Flags().String("parent", "", "New parent issue ID (reparents the issue, use empty string to remove parent)")
 	updateCmd.Flags().Bool("claim", false, "Atomically claim the issue (sets assignee to you, status to in_progress; idempotent if already claimed by you)")
+	updateCmd.Flags().String("lease", "", "With --claim: hold the claim only this long (e.g. 2h); afterwards bd ready lists the issue again and others may claim it")
 	updateCmd.Flags().String("session", "", "Claude Code session ID for status=closed (or set CLAUDE_SESSION_ID env var)")
+	updateCmd.Flags().BoolP("force", "f", false, "Bypass the status guards enabled by the status.guards config and, with --status closed, the guards of bd close")
 	// Time-based scheduling flags (GH#820)
 	// Examples:
 	//   --due=+6h           Due in 6 hours
@@ -630,6 +765,7 @@ func init() {
 	updateCmd.Flags().String("metadata", "", "Set custom metadata (JSON string or @file.json to read from file)")
 	// Incremental metadata edits (GH#1406)
 	updateCmd.Flags().StringArray("set-metadata", nil, "Set metadata key=value (repeatable, e.g., --set-metadata team=platform)")
+	updateCmd.Flags().StringArray("field", nil, "Set a custom field, stored under metadata.custom_fields (repeatable, e.g., --field points=3)")
 	updateCmd.Flags().StringArray("unset-metadata", nil, "Remove metadata key (repeatable, e.g., --unset-metadata team)")
 	updateCmd.ValidArgsFunction = issueIDCompletion
 	rootCmd.AddCommand(updateCmd)
@@ -0,0 +1,105 @@
+package main
+
+import (
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/validation"
+)
+
+// enforceValidationRules checks an issue about to be created against the
+// configured validation rules and exits on a violation under strict.
+func enforceValidationRules(issue *types.Issue) {
+	if err := checkValidationRules(issue); err != nil {
+		FatalError("%v", err)
+	}
+}
+
+// checkValidationRules checks an issue about to be created against the
+// configured validation rules. Under warn the violations are printed and nil
+// is returned.
+func checkValidationRules(issue *types.Issue) error {
+	rules := validation.RulesFromConfig()
+	err := rules.Check(issue)
+	if err != nil && rules.Strictness == validation.StrictnessWarn {
+		fmt.Fprintf(os.Stderr, "%s %v\n", ui.RenderWarn("⚠"), err)
+		return nil
+	}
+	return err
+}
+
+// checkValidationRulesUpdate checks updates to issue against the configured
+// validation rules. Only rules on fields the update touches count, so an
+// issue that predates a rule can still be edited; changing the type checks
+// every field the new type requires. Under warn the violations are printed
+// and nil is returned.
+func checkValidationRulesUpdate(issue *types.Issue, updates map[string]interface{}) error {
+	rules := validation.RulesFromConfig()
+	if rules.Strictness == validation.StrictnessRelaxed {
+		return nil
+	}
+	_, typeChanged := updates["issue_type"]
+	var touched []validation.Violation
+	for _, v := range rules.Violations(issueAfterUpdates(issue, updates)) {
+		if _, ok := updates[v.Field]; ok || typeChanged {
+			touched = append(touched, v)
+		}
+	}
+	err := validation.ViolationsError(touched)
+	if err != nil && rules.Strictness == validation.StrictnessWarn {
+		fmt.Fprintf(os.Stderr, "%s %s: %v\n", ui.RenderWarn("⚠"), issue.ID, err)
+		return nil
+	}
+	return err
+}
+
+// issueAfterUpdates returns a copy of issue with the field updates the
+// validation rules look at applied.
+func issueAfterUpdates(issue *types.Issue, updates map[string]interface{}) *types.Issue {
+	after := *issue
+	for field, value := range updates {
+		switch field {
+		case "title":
+			after.Title, _ = value.(string)
+		case "priority":
+			after.Priority, _ = value.(int)
+		case "issue_type":
+			s, _ := value.(string)
+			after.IssueType = types.IssueType(s)
+		case "severity":
+			s, _ := value.(string)
+			after.Severity = types.Severity(s)
+		case "description":
+			after.Description, _ = value.(string)
+		case "design":
+			after.Design, _ = value.(string)
+		case "acceptance_criteria":
+			after.AcceptanceCriteria, _ = value.(string)
+		case "notes":
+			after.Notes, _ = value.(string)
+		case "assignee":
+			after.Assignee, _ = value.(string)
+		case "spec_id":
+			after.SpecID, _ = value.(string)
+		case "external_ref":
+			after.ExternalRef = nil
+			if s, ok := value.(string); ok {
+				after.ExternalRef = &s
+			}
+		case "estimated_minutes":
+			after.EstimatedMinutes = nil
+			if n, ok := value.(int); ok {
+				after.EstimatedMinutes = &n
+			}
+		case "due_at":
+			after.DueAt = nil
+			if t, ok := value.(time.Time); ok {
+				after.DueAt = &t
+			}
+		}
+	}
+	return &after
+}
@@ -0,0 +1,292 @@
+package main
+
+import (
+	"bytes"
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/storage"
+	"github.com/steveyegge/beads/internal/storage/dolt"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+var verifySyncCmd = &cobra.Command{
+	Use:     "verify-sync",
+	GroupID: "sync",
+	Short:   "Check that exporting and re-importing preserves every issue",
+	Long: `Check the invariant git sync relies on: exporting the database to JSONL
+and importing that JSONL reproduces the same issues.
+
+The issues 'bd export' would write are exported to a temporary file and
+imported into a scratch database. Each issue is then compared with its live
+copy by content hash, field by field, and by its labels, dependencies, and
+comments. Any issue that is lost or changed by the round trip is reported.
+
+The live database is not modified. Exits with status 1 if any issue differs.`,
+	Example: `  bd verify-sync
+  bd verify-sync --json`,
+	Args: cobra.NoArgs,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		if store == nil {
+			return fmt.Errorf("no store available")
+		}
+		result, err := verifySyncRoundTrip(rootCtx)
+		if err != nil {
+			return err
+		}
+
+		if jsonOutput {
+			outputJSON(result)
+		} else if result.OK {
+			fmt.Printf("%s Round trip clean: %d issues match after export and re-import\n", ui.RenderPass("✓"), result.Issues)
+		} else {
+			fmt.Printf("%s %d of %d issues differ after export and re-import:\n\n", ui.RenderFail("✗"), len(result.Mismatches), result.Issues)
+			for _, m := range result.Mismatches {
+				fmt.Printf("  %s  %s\n", m.ID, m.Problem)
+				for _, f := range m.Fields {
+					fmt.Printf("      %s\n", f)
+				}
+			}
+		}
+		if !result.OK {
+			os.Exit(1)
+		}
+		return nil
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(verifySyncCmd)
+}
+
+// verifySyncResult is the report of 'bd verify-sync'.
+type verifySyncResult struct {
+	OK         bool           `json:"ok"`
+	Issues     int            `json:"issues"`
+	Mismatches []syncMismatch `json:"mismatches"`
+}
+
+// syncMismatch is one issue the round trip lost or changed. Fields names
+// what differs; the hashes are types.Issue.ComputeContentHash of each copy.
+type syncMismatch struct {
+	ID           string   `json:"id"`
+	Problem      string   `json:"problem"`
+	Fields       []string `json:"fields,omitempty"`
+	LiveHash     string   `json:"live_hash,omitempty"`
+	ImportedHash string   `json:"imported_hash,omitempty"`
+}
+
+// verifySyncRoundTrip exports the live issues, imports the export into a
+// scratch database in a temporary directory, and compares the two.
+func verifySyncRoundTrip(ctx context.Context) (*verifySyncResult, error) {
+	filter, _ := buildAutoExportFilter(ctx)
+	live, err := store.SearchIssues(ctx, "", filter)
+	if err != nil {
+		return nil, fmt.Errorf("failed to search issues: %w", err)
+	}
+	var buf bytes.Buffer
+	if _, err := writeJSONLIssues(&buf, live, loadExportRelations(ctx, live)); err != nil {
+		return nil, err
+	}
+
+	tmpDir, err := os.MkdirTemp("", "bd-verify-sync-")
+	if err != nil {
+		return nil, fmt.Errorf("failed to create scratch directory: %w", err)
+	}
+	defer func() { _ = os.RemoveAll(tmpDir) }()
+	exportPath := filepath.Join(tmpDir, "issues.jsonl")
+	if err := os.WriteFile(exportPath, buf.Bytes(), 0o600); err != nil {
+		return nil, fmt.Errorf("failed to write export: %w", err)
+	}
+
+	scratchDir := filepath.Join(tmpDir, ".beads")
+	if err := os.MkdirAll(scratchDir, 0o750); err != nil {
+		return nil, fmt.Errorf("failed to create scratch directory: %w", err)
+	}
+	scratch, err := newDoltStore(ctx, &dolt.Config{
+		BeadsDir:        scratchDir,
+		Database:        "verify_sync",
+		CreateIfMissing: true,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to create scratch database: %w", err)
+	}
+	defer func() { _ = scratch.Close() }()
+	if prefix, err := store.GetConfig(ctx, "issue_prefix"); err == nil && prefix != "" {
+		if err := scratch.SetConfig(ctx, "issue_prefix", prefix); err != nil {
+			return nil, fmt.Errorf("failed to configure scratch database: %w", err)
+		}
+	}
+	if _, err := importFromLocalJSONLFull(ctx, scratch, exportPath); err != nil {
+		return nil, fmt.Errorf("re-import failed: %w", err)
+	}
+
+	imported, err := loadRoundTripIssues(ctx, scratch, live)
+	if err != nil {
+		return nil, err
+	}
+	mismatches := compareRoundTrip(live, imported)
+	return &verifySyncResult{
+		OK:         len(mismatches) == 0,
+		Issues:     len(live),
+		Mismatches: mismatches,
+	}, nil
+}
+
+// loadRoundTripIssues reads the re-imported copies of live from s, with
+// their labels, dependencies, comments, code references, and work log,
+// keyed by ID.
+func loadRoundTripIssues(ctx context.Context, s storage.DoltStorage, live []*types.Issue) (map[string]*types.Issue, error) {
+	ids := make([]string, len(live))
+	for i, issue := range live {
+		ids[i] = issue.ID
+	}
+	imported := make(map[string]*types.Issue, len(ids))
+	if len(ids) == 0 {
+		return imported, nil
+	}
+	issues, err := s.GetIssuesByIDs(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read re-imported issues: %w", err)
+	}
+	labels, err := s.GetLabelsForIssues(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read re-imported labels: %w", err)
+	}
+	deps, err := s.GetDependencyRecordsForIssues(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read re-imported dependencies: %w", err)
+	}
+	comments, err := s.GetCommentsForIssues(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read re-imported comments: %w", err)
+	}
+	codeRefs, err := s.GetCodeRefsForIssues(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read re-imported code references: %w", err)
+	}
+	workLog, err := s.GetWorkLogForIssues(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read re-imported work log: %w", err)
+	}
+	todos, err := s.GetTodosForIssues(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read re-imported checklist items: %w", err)
+	}
+	assignees, err := s.GetAssigneesForIssues(ctx, ids)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read re-imported assignees: %w", err)
+	}
+	for _, issue := range issues {
+		issue.Labels = labels[issue.ID]
+		issue.Dependencies = deps[issue.ID]
+		issue.Comments = comments[issue.ID]
+		issue.CodeRefs = codeRefs[issue.ID]
+		issue.WorkLog = workLog[issue.ID]
+		issue.Todos = todos[issue.ID]
+		issue.Assignees = assignees[issue.ID]
+		canonicalizeExportIssue(issue)
+		imported[issue.ID] = issue
+	}
+	return imported, nil
+}
+
+// compareRoundTrip reports each live issue whose re-imported copy is
+// missing or differs. Both sides must already be canonical (see
+// canonicalizeExportIssue); updated_at is not compared, since importing
+// is itself an update.
+func compareRoundTrip(live []*types.Issue, imported map[string]*types.Issue) []syncMismatch {
+	mismatches := []syncMismatch{}
+	for _, issue := range live {
+		got := imported[issue.ID]
+		if got == nil {
+			mismatches = append(mismatches, syncMismatch{ID: issue.ID, Problem: "missing after re-import"})
+			continue
+		}
+
+		var fields []string
+		for _, c := range diffImportIssue(issue, got) {
+			if c.Field != "labels" {
+				fields = append(fields, fmt.Sprintf("%s: %s → %s", c.Field, previewValue(c.Old), previewValue(c.New)))
+			}
+		}
+		if !slices.Equal(issue.Labels, got.Labels) {
+			fields = append(fields, fmt.Sprintf("labels: %v → %v", issue.Labels, got.Labels))
+		}
+		if l, g := roundTripDeps(issue), roundTripDeps(got); !slices.Equal(l, g) {
+			fields = append(fields, fmt.Sprintf("dependencies: %v → %v", l, g))
+		}
+		if l, g := roundTripComments(issue), roundTripComments(got); !slices.Equal(l, g) {
+			fields = append(fields, fmt.Sprintf("comments: %d → %d", len(l), len(g)))
+		}
+		if l, g := roundTripCodeRefs(issue), roundTripCodeRefs(got); !slices.Equal(l, g) {
+			fields = append(fields, fmt.Sprintf("code_refs: %v → %v", l, g))
+		}
+		if !slices.Equal(issue.Assignees, got.Assignees) {
+			fields = append(fields, fmt.Sprintf("assignees: %v → %v", issue.Assignees, got.Assignees))
+		}
+		if l, g := roundTripWorkLog(issue), roundTripWorkLog(got); !slices.Equal(l, g) {
+			fields = append(fields, fmt.Sprintf("work_log: %d → %d", len(l), len(g)))
+		}
+		if l, g := roundTripTodos(issue), roundTripTodos(got); !slices.Equal(l, g) {
+			fields = append(fields, fmt.Sprintf("todos: %v → %v", l, g))
+		}
+
+		liveHash, gotHash := issue.ComputeContentHash(), got.ComputeContentHash()
+		if len(fields) == 0 && liveHash == gotHash {
+			continue
+		}
+		m := syncMismatch{ID: issue.ID, Problem: "changed by round trip", Fields: fields}
+		if liveHash != gotHash {
+			m.Problem = "content hash differs"
+			m.LiveHash, m.ImportedHash = liveHash, gotHash
+		}
+		mismatches = append(mismatches, m)
+	}
+	return mismatches
+}
+
+func roundTripDeps(issue *types.Issue) []string {
+	deps := make([]string, 0, len(issue.Dependencies))
+	for _, dep := range issue.Dependencies {
+		deps = append(deps, fmt.Sprintf("%s:%s", dep.Type, dep.DependsOnID))
+	}
+	return deps
+}
+
+func roundTripComments(issue *types.Issue) []string {
+	comments := make([]string, 0, len(issue.Comments))
+	for _, c := range issue.Comments {
+		comments = append(comments, c.Author+"\x00"+c.Text)
+	}
+	return comments
+}
+
+func roundTripCodeRefs(issue *types.Issue) []string {
+	refs := make([]string, 0, len(issue.CodeRefs))
+	for _, r := range issue.CodeRefs {
+		refs = append(refs, r.String()+"\x00"+r.Note)
+	}
+	return refs
+}
+
+func roundTripWorkLog(issue *types.Issue) []string {
+	entries := make([]string, 0, len(issue.WorkLog))
+	for _, w := range issue.WorkLog {
+		entries = append(entries, fmt.Sprintf("%s\x00%s\x00%d\x00%s", w.ID, w.Actor, w.Minutes, w.Note))
+	}
+	return entries
+}
+
+func roundTripTodos(issue *types.Issue) []string {
+	todos := make([]string, 0, len(issue.Todos))
+	for _, todo := range issue.Todos {
+		todos = append(todos, fmt.Sprintf("%d:%t:%s", todo.Seq, todo.Done, todo.Text))
+	}
+	return todos
+}
@@ -0,0 +1,53 @@
+package main
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestCompareRoundTrip(t *testing.T) {
+	t.Parallel()
+	newIssue := func(id string) *types.Issue {
+		return &types.Issue{
+			ID:           id,
+			Title:        "Issue " + id,
+			Status:       types.StatusOpen,
+			Priority:     2,
+			IssueType:    types.TypeTask,
+			Labels:       []string{"backend"},
+			Dependencies: []*types.Dependency{{IssueID: id, DependsOnID: "bd-9", Type: types.DepBlocks}},
+		}
+	}
+	live := []*types.Issue{newIssue("bd-1"), newIssue("bd-2"), newIssue("bd-3"), newIssue("bd-4")}
+
+	same := newIssue("bd-1")
+	retitled := newIssue("bd-2")
+	retitled.Title = "Renamed"
+	unlabeled := newIssue("bd-3")
+	unlabeled.Labels = nil
+	unlabeled.Dependencies = nil
+	imported := map[string]*types.Issue{"bd-1": same, "bd-2": retitled, "bd-3": unlabeled}
+
+	got := compareRoundTrip(live, imported)
+	if len(got) != 3 {
+		t.Fatalf("mismatches = %+v, want bd-2, bd-3, bd-4", got)
+	}
+	if got[0].ID != "bd-2" || got[0].Problem != "content hash differs" || got[0].LiveHash == got[0].ImportedHash {
+		t.Errorf("retitled issue = %+v", got[0])
+	}
+	if len(got[0].Fields) != 1 || !strings.HasPrefix(got[0].Fields[0], "title: ") {
+		t.Errorf("retitled fields = %q", got[0].Fields)
+	}
+	if got[1].ID != "bd-3" || got[1].Problem != "changed by round trip" || len(got[1].Fields) != 2 {
+		t.Errorf("issue that lost labels and dependencies = %+v", got[1])
+	}
+	if got[2].ID != "bd-4" || got[2].Problem != "missing after re-import" {
+		t.Errorf("missing issue = %+v", got[2])
+	}
+
+	if got := compareRoundTrip(live[:1], imported); len(got) != 0 {
+		t.Errorf("identical round trip reported %+v", got)
+	}
+}
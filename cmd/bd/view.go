@@ -0,0 +1,297 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"regexp"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/query"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+// viewConfigPrefix prefixes the config keys saved views are stored under
+// (view.<name>), so they sync with the database like other shared config.
+const viewConfigPrefix = "view."
+
+// viewNamePattern is the form view names take.
+var viewNamePattern = regexp.MustCompile(`^[A-Za-z0-9][A-Za-z0-9_-]*$`)
+
+// savedView is a named 'bd list' invocation: a query expression plus the
+// sort, limit and columns to show it with.
+type savedView struct {
+	Name    string   `json:"name"`
+	Query   string   `json:"query,omitempty"`
+	Sort    string   `json:"sort,omitempty"`
+	Reverse bool     `json:"reverse,omitempty"`
+	All     bool     `json:"all,omitempty"`
+	Limit   *int     `json:"limit,omitempty"`
+	Columns []string `json:"columns,omitempty"`
+}
+
+// listArgs returns the 'bd list' flags that run the view.
+func (v *savedView) listArgs() []string {
+	var args []string
+	if v.Query != "" {
+		args = append(args, "--query", v.Query)
+	}
+	if v.Sort != "" {
+		args = append(args, "--sort", v.Sort)
+	}
+	if v.Reverse {
+		args = append(args, "--reverse")
+	}
+	if v.All {
+		args = append(args, "--all")
+	}
+	if v.Limit != nil {
+		args = append(args, "--limit", strconv.Itoa(*v.Limit))
+	}
+	if len(v.Columns) > 0 {
+		args = append(args, "--columns", strings.Join(v.Columns, ","))
+	}
+	return args
+}
+
+var viewCmd = &cobra.Command{
+	Use:     "view [name | id...]",
+	GroupID: "views",
+	Short:   "Run a saved view (or show issues)",
+	Long: `Run a saved view: a named query with its sort, limit and columns.
+
+Views are stored in the database config (view.<name>), so everyone working
+on the project, humans and agents alike, sees the same slice of the backlog.
+
+  bd view save triage 'status:open p<=1 -label:wontfix' --sort triage
+  bd view triage              # same as bd list --query ... --sort triage
+  bd view triage --json       # machine-readable, like bd list --json
+  bd view list                # show saved views
+  bd view delete triage
+
+Given anything other than a saved view name, 'bd view' shows issues like
+'bd show' (it used to be an alias of it).`,
+	Args: cobra.ArbitraryArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		if len(args) == 0 && !cmd.Flags().Changed("id") && !cmd.Flags().Changed("current") {
+			viewListCmd.Run(cmd, args)
+			return
+		}
+		if len(args) == 1 && viewNamePattern.MatchString(args[0]) {
+			v, err := loadView(rootCtx, args[0])
+			if err != nil {
+				FatalErrorRespectJSON("%v", err)
+			}
+			if v != nil {
+				runSavedView(v)
+				return
+			}
+		}
+		showCmd.Run(cmd, args)
+	},
+}
+
+var viewSaveCmd = &cobra.Command{
+	Use:   "save <name> [query]",
+	Short: "Save a named view",
+	Long: `Save a named view. The query uses the 'bd query' language and is
+combined with the sort, limit and columns given here. Saving an existing
+name replaces it.`,
+	Args: cobra.RangeArgs(1, 2),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("view save")
+		v := &savedView{Name: args[0]}
+		if len(args) > 1 {
+			v.Query = args[1]
+		}
+		v.Sort, _ = cmd.Flags().GetString("sort")
+		v.Reverse, _ = cmd.Flags().GetBool("reverse")
+		v.All, _ = cmd.Flags().GetBool("all")
+		if cmd.Flags().Changed("limit") {
+			limit, _ := cmd.Flags().GetInt("limit")
+			v.Limit = &limit
+		}
+		columns, _ := cmd.Flags().GetStringSlice("columns")
+		if err := validateView(v, columns); err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+
+		data, err := json.Marshal(v)
+		if err != nil {
+			FatalErrorRespectJSON("encoding view: %v", err)
+		}
+		if err := getStore().SetConfig(rootCtx, viewConfigPrefix+v.Name, string(data)); err != nil {
+			FatalErrorRespectJSON("saving view %s: %v", v.Name, err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(v)
+			return
+		}
+		fmt.Printf("%s Saved view %s (run it with 'bd view %s')\n", ui.RenderPass("✓"), ui.RenderBold(v.Name), v.Name)
+	},
+}
+
+var viewListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List saved views",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		views, err := loadViews(rootCtx)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			if views == nil {
+				views = []*savedView{}
+			}
+			outputJSON(views)
+			return
+		}
+		if len(views) == 0 {
+			fmt.Println("No saved views. Save one with: bd view save <name> '<query>'")
+			return
+		}
+		for _, v := range views {
+			fmt.Printf("%s  %s\n", ui.RenderBold(v.Name), ui.RenderMuted("bd list "+formatListArgs(v.listArgs())))
+		}
+	},
+}
+
+var viewDeleteCmd = &cobra.Command{
+	Use:     "delete <name>",
+	Aliases: []string{"rm"},
+	Short:   "Delete a saved view",
+	Args:    cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("view delete")
+		name := args[0]
+		v, err := loadView(rootCtx, name)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if v == nil {
+			FatalErrorRespectJSON("no saved view named %q", name)
+		}
+		if err := getStore().DeleteConfig(rootCtx, viewConfigPrefix+name); err != nil {
+			FatalErrorRespectJSON("deleting view %s: %v", name, err)
+		}
+		commandDidWrite.Store(true)
+
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"name": name, "deleted": true})
+			return
+		}
+		fmt.Printf("%s Deleted view %s\n", ui.RenderPass("✓"), name)
+	},
+}
+
+// validateView checks a view before it is saved, normalizing its columns.
+func validateView(v *savedView, columns []string) error {
+	if !viewNamePattern.MatchString(v.Name) {
+		return fmt.Errorf("invalid view name %q: use letters, digits, '-' and '_'", v.Name)
+	}
+	if slices.ContainsFunc(viewCmd.Commands(), func(c *cobra.Command) bool { return c.Name() == v.Name || c.HasAlias(v.Name) }) {
+		return fmt.Errorf("%q is a 'bd view' subcommand and cannot name a view", v.Name)
+	}
+	if v.Query != "" {
+		if _, err := query.Parse(v.Query); err != nil {
+			return fmt.Errorf("invalid query: %w", err)
+		}
+	}
+	if v.Sort != "" && !slices.Contains(listSortFields, v.Sort) {
+		return fmt.Errorf("invalid sort field %q (valid: %s)", v.Sort, strings.Join(listSortFields, ", "))
+	}
+	if v.Limit != nil && *v.Limit < 0 {
+		return fmt.Errorf("--limit must be non-negative")
+	}
+	cols, err := parseListColumns(columns)
+	if err != nil {
+		return err
+	}
+	v.Columns = cols
+	return nil
+}
+
+// loadView returns the saved view called name, or nil if there is none.
+func loadView(ctx context.Context, name string) (*savedView, error) {
+	value, err := getStore().GetConfig(ctx, viewConfigPrefix+name)
+	if err != nil {
+		return nil, fmt.Errorf("loading view %s: %w", name, err)
+	}
+	if value == "" {
+		return nil, nil
+	}
+	return decodeView(name, value)
+}
+
+// loadViews returns all saved views, sorted by name.
+func loadViews(ctx context.Context) ([]*savedView, error) {
+	all, err := getStore().GetAllConfig(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("loading views: %w", err)
+	}
+	var views []*savedView
+	for key, value := range all {
+		name, ok := strings.CutPrefix(key, viewConfigPrefix)
+		if !ok || value == "" {
+			continue
+		}
+		v, err := decodeView(name, value)
+		if err != nil {
+			return nil, err
+		}
+		views = append(views, v)
+	}
+	slices.SortFunc(views, func(a, b *savedView) int { return strings.Compare(a.Name, b.Name) })
+	return views, nil
+}
+
+func decodeView(name, value string) (*savedView, error) {
+	var v savedView
+	if err := json.Unmarshal([]byte(value), &v); err != nil {
+		return nil, fmt.Errorf("view %s: invalid config value: %w", name, err)
+	}
+	v.Name = name
+	return &v, nil
+}
+
+// runSavedView runs v through 'bd list' with the view's flags set.
+func runSavedView(v *savedView) {
+	if err := listCmd.ParseFlags(v.listArgs()); err != nil {
+		FatalErrorRespectJSON("view %s: %v", v.Name, err)
+	}
+	listCmd.Run(listCmd, nil)
+}
+
+// formatListArgs renders list flags for display, quoting values that need it.
+func formatListArgs(args []string) string {
+	quoted := make([]string, len(args))
+	for i, arg := range args {
+		if arg == "" || strings.ContainsAny(arg, " \t'\"<>|&;") {
+			arg = "'" + strings.ReplaceAll(arg, "'", `'\''`) + "'"
+		}
+		quoted[i] = arg
+	}
+	return strings.Join(quoted, " ")
+}
+
+func init() {
+	// 'bd view <id>' keeps working as 'bd show <id>'.
+	viewCmd.Flags().AddFlagSet(showCmd.Flags())
+
+	viewSaveCmd.Flags().String("sort", "", "Sort by field: "+strings.Join(listSortFields, ", "))
+	viewSaveCmd.Flags().BoolP("reverse", "r", false, "Reverse sort order")
+	viewSaveCmd.Flags().BoolP("all", "a", false, "Include closed issues")
+	viewSaveCmd.Flags().IntP("limit", "n", 0, "Limit results (0 for unlimited; default: the bd list default)")
+	viewSaveCmd.Flags().StringSlice("columns", nil, "Show a table of these columns (see 'bd list --columns')")
+
+	viewCmd.AddCommand(viewSaveCmd)
+	viewCmd.AddCommand(viewListCmd)
+	viewCmd.AddCommand(viewDeleteCmd)
+	rootCmd.AddCommand(viewCmd)
+}
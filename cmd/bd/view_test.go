@@ -0,0 +1,65 @@
+package main
+
+import (
+	"bytes"
+	"slices"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestSavedViewListArgs(t *testing.T) {
+	limit := 0
+	v := &savedView{Name: "triage", Query: "status:open p<=1", Sort: "triage", Reverse: true, Limit: &limit, Columns: []string{"id", "title"}}
+	want := []string{"--query", "status:open p<=1", "--sort", "triage", "--reverse", "--limit", "0", "--columns", "id,title"}
+	if got := v.listArgs(); !slices.Equal(got, want) {
+		t.Errorf("listArgs() = %q, want %q", got, want)
+	}
+	if got := formatListArgs(want[:4]); got != "--query 'status:open p<=1' --sort triage" {
+		t.Errorf("formatListArgs = %q", got)
+	}
+}
+
+func TestValidateView(t *testing.T) {
+	v := &savedView{Name: "triage", Query: "status:open -label:wontfix", Sort: "priority"}
+	if err := validateView(v, []string{" ID", "priority"}); err != nil {
+		t.Fatalf("validateView: %v", err)
+	}
+	if !slices.Equal(v.Columns, []string{"id", "priority"}) {
+		t.Errorf("Columns = %v, want [id priority]", v.Columns)
+	}
+
+	bad := []struct {
+		view    savedView
+		columns []string
+		want    string
+	}{
+		{savedView{Name: "my view"}, nil, "invalid view name"},
+		{savedView{Name: "save"}, nil, "subcommand"},
+		{savedView{Name: "rm"}, nil, "subcommand"},
+		{savedView{Name: "t", Query: "status:open AND"}, nil, "invalid query"},
+		{savedView{Name: "t", Sort: "color"}, nil, "invalid sort field"},
+		{savedView{Name: "t"}, []string{"color"}, "unknown column"},
+	}
+	for _, tt := range bad {
+		if err := validateView(&tt.view, tt.columns); err == nil || !strings.Contains(err.Error(), tt.want) {
+			t.Errorf("validateView(%+v, %v) = %v, want error containing %q", tt.view, tt.columns, err, tt.want)
+		}
+	}
+}
+
+func TestOutputColumns(t *testing.T) {
+	issues := []*types.Issue{
+		{ID: "bd-1", Title: "Crash on login", Priority: 0, Labels: []string{"auth", "infra"}},
+		{ID: "bd-22", Title: "Docs", Priority: 3},
+	}
+	var buf bytes.Buffer
+	outputColumns(&buf, issues, []string{"id", "priority", "labels", "title"})
+	want := "ID     PRIORITY  LABELS      TITLE\n" +
+		"bd-1   P0        auth,infra  Crash on login\n" +
+		"bd-22  P3                    Docs\n"
+	if buf.String() != want {
+		t.Errorf("outputColumns =\n%s\nwant\n%s", buf.String(), want)
+	}
+}
@@ -0,0 +1,237 @@
+package main
+
+import (
+	"encoding/json"
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/types"
+	"github.com/steveyegge/beads/internal/ui"
+)
+
+var watchCmd = &cobra.Command{
+	Use:     "watch [id...]",
+	GroupID: "issues",
+	Short:   "Watch issues for updates",
+	Long: `Subscribe to updates on issues.
+
+Watches are stored per actor (see --actor / BEADS_ACTOR). Changes other people
+make to watched issues show up in 'bd inbox'. With no arguments, lists the
+issues you are watching.
+
+Examples:
+  bd watch bd-123 bd-456
+  bd watch                  # list watched issues
+  bd unwatch bd-123`,
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		if len(args) == 0 {
+			ids, err := store.GetWatchedIssueIDs(ctx, actor)
+			if err != nil {
+				FatalErrorRespectJSON("listing watched issues: %v", err)
+			}
+			issues, err := store.GetIssuesByIDs(ctx, ids)
+			if err != nil {
+				FatalErrorRespectJSON("listing watched issues: %v", err)
+			}
+			if jsonOutput {
+				if issues == nil {
+					issues = []*types.Issue{}
+				}
+				outputJSON(issues)
+				return
+			}
+			if len(issues) == 0 {
+				fmt.Printf("%s is not watching any issues\n", actor)
+				return
+			}
+			for _, issue := range issues {
+				fmt.Printf("%s [%s] %s\n", ui.RenderID(issue.ID), issue.Status, issue.Title)
+			}
+			return
+		}
+
+		CheckReadonly("watch")
+		setWatching(cmd, args, true)
+	},
+}
+
+var unwatchCmd = &cobra.Command{
+	Use:     "unwatch <id...>",
+	GroupID: "issues",
+	Short:   "Stop watching issues",
+	Args:    cobra.MinimumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		CheckReadonly("unwatch")
+		setWatching(cmd, args, false)
+	},
+}
+
+// setWatching adds or removes the current actor's watch on each issue.
+func setWatching(cmd *cobra.Command, args []string, watch bool) {
+	ctx := rootCtx
+	type watchResult struct {
+		ID       string `json:"id"`
+		Watching bool   `json:"watching"`
+		Changed  bool   `json:"changed"`
+	}
+	var results []watchResult
+	for _, id := range args {
+		result, err := resolveAndGetIssueWithRouting(ctx, store, id)
+		if err != nil || result == nil || result.Issue == nil {
+			if result != nil {
+				result.Close()
+			}
+			if err == nil {
+				err = fmt.Errorf("issue %s not found", id)
+			}
+			FatalErrorRespectJSON("resolving %s: %v", id, err)
+		}
+		var changed bool
+		if watch {
+			changed, err = result.Store.WatchIssue(ctx, result.ResolvedID, actor)
+		} else {
+			changed, err = result.Store.UnwatchIssue(ctx, result.ResolvedID, actor)
+		}
+		title := result.Issue.Title
+		result.Close()
+		if err != nil {
+			FatalErrorRespectJSON("%s %s: %v", cmd.Name(), id, err)
+		}
+		if changed {
+			commandDidWrite.Store(true)
+		}
+		results = append(results, watchResult{ID: result.ResolvedID, Watching: watch, Changed: changed})
+
+		if jsonOutput {
+			continue
+		}
+		switch {
+		case watch && changed:
+			fmt.Printf("%s Watching %s\n", ui.RenderPass("✓"), formatFeedbackID(result.ResolvedID, title))
+		case watch:
+			fmt.Printf("Already watching %s\n", formatFeedbackID(result.ResolvedID, title))
+		case changed:
+			fmt.Printf("%s Stopped watching %s\n", ui.RenderPass("✓"), formatFeedbackID(result.ResolvedID, title))
+		default:
+			fmt.Printf("Not watching %s\n", formatFeedbackID(result.ResolvedID, title))
+		}
+	}
+	if jsonOutput {
+		outputJSON(results)
+	}
+}
+
+var inboxCmd = &cobra.Command{
+	Use:     "inbox",
+	GroupID: "views",
+	Short:   "Show recent updates on watched issues",
+	Long: `Show what changed on the issues you watch since you last checked.
+
+Your own changes are left out. Showing the inbox marks everything in it as
+read; use --peek to leave it unread.
+
+Examples:
+  bd inbox
+  bd inbox --peek
+  bd inbox --json`,
+	Args: cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		ctx := rootCtx
+		peek, _ := cmd.Flags().GetBool("peek")
+
+		events, err := store.GetInbox(ctx, actor)
+		if err != nil {
+			FatalErrorRespectJSON("reading inbox: %v", err)
+		}
+		annotateActorKinds(ctx, events)
+
+		if jsonOutput {
+			if events == nil {
+				events = []*types.Event{}
+			}
+			outputJSON(events)
+		} else if len(events) == 0 {
+			fmt.Println("No new updates on watched issues")
+		} else {
+			for _, e := range events {
+				fmt.Printf("%s %s %s %s\n",
+					ui.RenderMuted(formatTimeAgo(e.CreatedAt)), ui.RenderID(e.IssueID),
+					formatActor(e.Actor, e.ActorKind), describeInboxEvent(e))
+			}
+		}
+
+		if peek || len(events) == 0 || readonlyMode {
+			return
+		}
+		if err := store.MarkInboxRead(ctx, actor, events[len(events)-1].CreatedAt); err != nil {
+			FatalErrorRespectJSON("marking inbox read: %v", err)
+		}
+		commandDidWrite.Store(true)
+	},
+}
+
+// describeInboxEvent summarizes an event as a short phrase for bd inbox.
+func describeInboxEvent(e *types.Event) string {
+	text := func(s *string) string {
+		if s == nil {
+			return ""
+		}
+		line, _, _ := strings.Cut(strings.TrimSpace(*s), "\n")
+		return truncate(line, 60)
+	}
+
+	switch e.EventType {
+	case types.EventCreated:
+		return "created the issue"
+	case types.EventCommented:
+		return "commented: " + text(e.Comment)
+	case types.EventCommentEdited:
+		return "edited a comment: " + text(e.NewValue)
+	case types.EventCommentDeleted:
+		return "deleted a comment"
+	case types.EventLabelAdded, types.EventLabelRemoved,
+		types.EventAssigneeAdded, types.EventAssigneeRemoved:
+		// These events carry a ready-made description ("Added label: x").
+		if c := text(e.Comment); c != "" {
+			return c
+		}
+	}
+
+	// Update events store the applied changes as a JSON object; close
+	// events recorded directly store the close reason instead.
+	var changes map[string]interface{}
+	if e.NewValue != nil && json.Unmarshal([]byte(*e.NewValue), &changes) == nil {
+		if status, ok := changes["status"].(string); ok && e.EventType != types.EventUpdated {
+			return "set status to " + status
+		}
+		fields := make([]string, 0, len(changes))
+		for field := range changes {
+			if field != "updated_at" {
+				fields = append(fields, field)
+			}
+		}
+		slices.Sort(fields)
+		if len(fields) > 0 {
+			return "updated " + strings.Join(fields, ", ")
+		}
+	}
+	if e.EventType == types.EventClosed {
+		if reason := text(e.NewValue); reason != "" {
+			return "closed: " + reason
+		}
+		return "closed the issue"
+	}
+	return strings.ReplaceAll(string(e.EventType), "_", " ")
+}
+
+func init() {
+	inboxCmd.Flags().Bool("peek", false, "Show updates without marking them read")
+	watchCmd.ValidArgsFunction = issueIDCompletion
+	unwatchCmd.ValidArgsFunction = issueIDCompletion
+	rootCmd.AddCommand(watchCmd)
+	rootCmd.AddCommand(unwatchCmd)
+	rootCmd.AddCommand(inboxCmd)
+}
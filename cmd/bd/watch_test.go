@@ -0,0 +1,30 @@
+package main
+
+import (
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestDescribeInboxEvent(t *testing.T) {
+	str := func(s string) *string { return &s }
+	tests := []struct {
+		event *types.Event
+		want  string
+	}{
+		{&types.Event{EventType: types.EventCommented, Comment: str("Repro attached\nmore detail")}, "commented: Repro attached"},
+		{&types.Event{EventType: types.EventStatusChanged, NewValue: str(`{"status":"in_progress"}`)}, "set status to in_progress"},
+		{&types.Event{EventType: types.EventUpdated, NewValue: str(`{"title":"x","priority":1,"updated_at":"now"}`)}, "updated priority, title"},
+		{&types.Event{EventType: types.EventClosed, OldValue: str("open"), NewValue: str("Fixed in v2")}, "closed: Fixed in v2"},
+		{&types.Event{EventType: types.EventLabelAdded, Comment: str("Added label: urgent")}, "Added label: urgent"},
+		{&types.Event{EventType: types.EventLabelRemoved}, "label removed"},
+		{&types.Event{EventType: types.EventDependencyAdded}, "dependency added"},
+		{&types.Event{EventType: types.EventCommentEdited, OldValue: str("typo"), NewValue: str("fixed")}, "edited a comment: fixed"},
+		{&types.Event{EventType: types.EventCommentDeleted, OldValue: str("spam")}, "deleted a comment"},
+	}
+	for _, tt := range tests {
+		if got := describeInboxEvent(tt.event); got != tt.want {
+			t.Errorf("describeInboxEvent(%s) = %q, want %q", tt.event.EventType, got, tt.want)
+		}
+	}
+}
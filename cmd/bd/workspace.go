@@ -0,0 +1,149 @@
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/spf13/cobra"
+	"github.com/steveyegge/beads/internal/beads"
+	"github.com/steveyegge/beads/internal/configfile"
+	"github.com/steveyegge/beads/internal/routing"
+	"github.com/steveyegge/beads/internal/ui"
+	"github.com/steveyegge/beads/internal/utils"
+)
+
+var workspaceCmd = &cobra.Command{
+	Use:     "workspace",
+	GroupID: "advanced",
+	Short:   "Manage the registry of beads repositories on this machine",
+	Long: `Register the beads repositories on this machine so that commands can
+reach across them: 'bd list --all-repos', 'bd ready --all-repos', and
+'bd status --all-repos' aggregate over the current repository and every
+registered one, tagging each result with the repository it came from.
+
+The registry is ~/.config/beads/workspaces.toml (%APPDATA%\beads on
+Windows, or BEADS_WORKSPACES_FILE). It is per machine and never committed;
+unlike 'bd repo', nothing is copied between databases.
+
+Examples:
+  bd workspace add                    # Register the current repository
+  bd workspace add ~/src/api --name api
+  bd workspace list
+  bd workspace remove api`,
+}
+
+var workspaceAddCmd = &cobra.Command{
+	Use:   "add [path]",
+	Short: "Register a beads repository (default: the current one)",
+	Args:  cobra.MaximumNArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		var root string
+		if len(args) > 0 {
+			root = routing.ExpandPath(args[0])
+		} else if beadsDir := beads.FindBeadsDir(); beadsDir != "" {
+			root = filepath.Dir(beadsDir)
+		} else {
+			FatalErrorRespectJSON("not in a beads workspace; pass the repository path")
+		}
+		if _, err := os.Stat(filepath.Join(root, ".beads")); err != nil {
+			FatalErrorRespectJSON("no beads workspace found at %s", root)
+		}
+		name, _ := cmd.Flags().GetString("name")
+		if name == "" {
+			name = filepath.Base(root)
+		}
+
+		path := configfile.WorkspacesPath()
+		reg, err := configfile.LoadWorkspaces(path)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if err := reg.Add(configfile.Workspace{Name: name, Path: root}); err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if err := reg.Save(path); err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"added": true, "name": name, "path": root})
+			return
+		}
+		fmt.Printf("%s Registered %s as %q\n", ui.RenderPass("✓"), root, name)
+	},
+}
+
+var workspaceListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List the registered repositories",
+	Args:  cobra.NoArgs,
+	Run: func(cmd *cobra.Command, args []string) {
+		reg, err := configfile.LoadWorkspaces(configfile.WorkspacesPath())
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			ws := reg.Workspaces
+			if ws == nil {
+				ws = []configfile.Workspace{}
+			}
+			outputJSON(ws)
+			return
+		}
+		if len(reg.Workspaces) == 0 {
+			fmt.Println("No workspaces registered. Run 'bd workspace add' in a beads repository.")
+			return
+		}
+		for _, w := range reg.Workspaces {
+			note := ""
+			if _, err := os.Stat(filepath.Join(routing.ExpandPath(w.Path), ".beads")); err != nil {
+				note = " " + ui.RenderWarn("(missing)")
+			}
+			fmt.Printf("%-16s %s%s\n", w.Name, w.Path, note)
+		}
+	},
+}
+
+var workspaceRemoveCmd = &cobra.Command{
+	Use:   "remove <name|path>",
+	Short: "Unregister a repository",
+	Args:  cobra.ExactArgs(1),
+	Run: func(cmd *cobra.Command, args []string) {
+		path := configfile.WorkspacesPath()
+		reg, err := configfile.LoadWorkspaces(path)
+		if err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if !reg.Remove(args[0]) {
+			FatalErrorRespectJSON("no workspace named %q is registered", args[0])
+		}
+		if err := reg.Save(path); err != nil {
+			FatalErrorRespectJSON("%v", err)
+		}
+		if jsonOutput {
+			outputJSON(map[string]interface{}{"removed": true, "name": args[0]})
+			return
+		}
+		fmt.Printf("%s Unregistered %s\n", ui.RenderPass("✓"), args[0])
+	},
+}
+
+func init() {
+	workspaceAddCmd.Flags().String("name", "", "Name to tag results with (default: the directory name)")
+	workspaceCmd.AddCommand(workspaceAddCmd)
+	workspaceCmd.AddCommand(workspaceListCmd)
+	workspaceCmd.AddCommand(workspaceRemoveCmd)
+	rootCmd.AddCommand(workspaceCmd)
+}
+
+// currentWorkspaceName is the name results from the current repository
+// are tagged with: its registered name, or its directory name.
+func currentWorkspaceName(reg *configfile.WorkspaceRegistry, beadsDir string) string {
+	root := filepath.Dir(beadsDir)
+	for _, w := range reg.Workspaces {
+		if utils.PathsEqual(routing.ExpandPath(w.Path), root) {
+			return w.Name
+		}
+	}
+	return filepath.Base(root)
+}
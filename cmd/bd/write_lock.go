@@ -0,0 +1,215 @@
+package main
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/debug"
+	"github.com/steveyegge/beads/internal/lockfile"
+)
+
+// writeLockFileName is the advisory lock in .beads/ that serializes bd
+// processes which change the database or write the JSONL export.
+const writeLockFileName = "lock"
+
+// writeLockHolderEnv is set for the children of a process holding the
+// write lock (git hooks run by 'bd sync', for example) so they run under
+// it instead of waiting for their own parent.
+const writeLockHolderEnv = "BD_WRITE_LOCK_PID"
+
+// commandWriteLock is the write lock held for the current command, if any.
+var commandWriteLock *writeLock
+
+// commandWriteLockDir is the .beads directory of a command that writes,
+// for the commands that take the write lock only around their writes
+// (see writeLockDeferredCommands).
+var commandWriteLockDir string
+
+// writeLock is a held .beads/lock. The file records the holder's PID and
+// start time for the "held by" error; the flock itself is what excludes,
+// so a crashed holder never leaves a stale lock behind.
+type writeLock struct {
+	file *os.File
+}
+
+// writeLockHeldError reports a write lock that was still held by another
+// process when the wait ran out.
+type writeLockHeldError struct {
+	Path    string
+	PID     int
+	Started time.Time
+	Waited  time.Duration
+}
+
+func (e *writeLockHeldError) Error() string {
+	holder := "another bd process"
+	if e.PID > 0 {
+		holder = fmt.Sprintf("PID %d", e.PID)
+		if !e.Started.IsZero() {
+			holder += fmt.Sprintf(", started %s", e.Started.Local().Format(time.RFC3339))
+		}
+	}
+	return fmt.Sprintf("%s is held by %s; gave up after %s (raise lock.timeout to wait longer)", e.Path, holder, e.Waited)
+}
+
+// writeLockTimeout is how long to wait for the write lock (lock.timeout).
+func writeLockTimeout() time.Duration {
+	if d := config.GetDuration("lock.timeout"); d >= 0 {
+		return d
+	}
+	return 0
+}
+
+// acquireWriteLock takes the write lock in beadsDir, waiting up to timeout
+// for another bd process to release it.
+func acquireWriteLock(beadsDir string, timeout time.Duration) (*writeLock, error) {
+	path := filepath.Join(beadsDir, writeLockFileName)
+	f, err := os.OpenFile(path, os.O_CREATE|os.O_RDWR, 0o600) // #nosec G304 -- path is inside the beads directory
+	if err != nil {
+		return nil, fmt.Errorf("opening write lock: %w", err)
+	}
+
+	start := time.Now()
+	for {
+		err := lockfile.FlockExclusiveNonBlocking(f)
+		if err == nil {
+			break
+		}
+		if !lockfile.IsLocked(err) && !errors.Is(err, lockfile.ErrLockBusy) {
+			_ = f.Close()
+			return nil, fmt.Errorf("acquiring write lock: %w", err)
+		}
+		if time.Since(start) >= timeout {
+			_ = f.Close()
+			pid, started := readWriteLockHolder(path)
+			return nil, &writeLockHeldError{Path: path, PID: pid, Started: started, Waited: timeout}
+		}
+		time.Sleep(50 * time.Millisecond)
+	}
+	if waited := time.Since(start); waited > time.Second {
+		debug.Logf("write lock: waited %s for %s", waited.Round(time.Millisecond), path)
+	}
+
+	// Record the holder while holding the flock. Failure only costs the
+	// PID in another process's error message.
+	if err := f.Truncate(0); err == nil {
+		_, _ = f.WriteAt([]byte(fmt.Sprintf("pid=%d\nstarted=%s\n", os.Getpid(), time.Now().UTC().Format(time.RFC3339))), 0)
+	}
+	return &writeLock{file: f}, nil
+}
+
+// Release clears the holder and unlocks. The file is kept: removing it
+// would let a waiter lock the old inode while a newcomer creates a new one.
+func (l *writeLock) Release() {
+	if l == nil || l.file == nil {
+		return
+	}
+	_ = l.file.Truncate(0)
+	_ = lockfile.FlockUnlock(l.file)
+	_ = l.file.Close()
+	l.file = nil
+}
+
+// readWriteLockHolder returns the PID and start time recorded in the lock
+// file at path, or zero values if there are none.
+func readWriteLockHolder(path string) (pid int, started time.Time) {
+	data, err := os.ReadFile(path) // #nosec G304 -- path is inside the beads directory
+	if err != nil {
+		return 0, time.Time{}
+	}
+	for _, line := range strings.Split(string(data), "\n") {
+		k, v, ok := strings.Cut(strings.TrimSpace(line), "=")
+		if !ok {
+			continue
+		}
+		switch k {
+		case "pid":
+			pid, _ = strconv.Atoi(v)
+		case "started":
+			started, _ = time.Parse(time.RFC3339, v)
+		}
+	}
+	return pid, started
+}
+
+// writeLockHeldByParent reports whether this process was started by the
+// process that holds the write lock in beadsDir.
+func writeLockHeldByParent(beadsDir string) bool {
+	env := os.Getenv(writeLockHolderEnv)
+	if env == "" {
+		return false
+	}
+	pid, _ := readWriteLockHolder(filepath.Join(beadsDir, writeLockFileName))
+	return pid > 0 && strconv.Itoa(pid) == env
+}
+
+// acquireCommandWriteLock takes the write lock for the rest of the current
+// command, unless the parent process already holds it. Without a .beads
+// directory there is no export to protect, so no lock is taken. Exits on
+// timeout.
+func acquireCommandWriteLock(beadsDir string) {
+	if commandWriteLock != nil || writeLockHeldByParent(beadsDir) {
+		return
+	}
+	if info, err := os.Stat(beadsDir); err != nil || !info.IsDir() {
+		debug.Logf("write lock: no beads directory at %s; not locking", beadsDir)
+		return
+	}
+	lock, err := acquireWriteLock(beadsDir, writeLockTimeout())
+	if err != nil {
+		FatalErrorRespectJSON("%v", err)
+	}
+	commandWriteLock = lock
+	_ = os.Setenv(writeLockHolderEnv, strconv.Itoa(os.Getpid())) // inherited by hooks and other child bd processes
+}
+
+// releaseCommandWriteLock releases the lock taken by acquireCommandWriteLock.
+func releaseCommandWriteLock() {
+	if commandWriteLock == nil {
+		return
+	}
+	commandWriteLock.Release()
+	commandWriteLock = nil
+	_ = os.Unsetenv(writeLockHolderEnv)
+}
+
+// lockCommandWrites takes the write lock for the rest of a command in
+// writeLockDeferredCommands, once it reaches its writes. It does nothing
+// for commands that don't write or already hold the lock.
+func lockCommandWrites() {
+	if commandWriteLockDir != "" {
+		acquireCommandWriteLock(commandWriteLockDir)
+	}
+}
+
+// withCommandWriteLock runs fn holding the write lock, releasing it
+// afterwards unless the command already held it.
+func withCommandWriteLock(fn func() error) error {
+	if commandWriteLock != nil || commandWriteLockDir == "" {
+		return fn()
+	}
+	acquireCommandWriteLock(commandWriteLockDir)
+	defer releaseCommandWriteLock()
+	return fn()
+}
+
+// writeLockDeferredCommands spend most of their run waiting on something
+// other than the database: an editor, the network, or git. Rather than
+// hold the write lock from the start, they take it around their writes
+// (see lockCommandWrites and withCommandWriteLock), and the post-command
+// commit and export take it too. The daemon and bd serve lock each write
+// request instead.
+var writeLockDeferredCommands = map[string]bool{
+	"bd daemon":    true,
+	"bd serve":     true,
+	"bd edit":      true,
+	"bd dolt push": true,
+	"bd dolt pull": true,
+	"bd sync":      true,
+}
@@ -0,0 +1,87 @@
+package main
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"strconv"
+	"testing"
+	"time"
+)
+
+func TestWriteLock(t *testing.T) {
+	dir := t.TempDir()
+	held, err := acquireWriteLock(dir, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	_, err = acquireWriteLock(dir, 100*time.Millisecond)
+	var heldErr *writeLockHeldError
+	if !errors.As(err, &heldErr) {
+		t.Fatalf("second acquire: err = %v, want writeLockHeldError", err)
+	}
+	if heldErr.PID != os.Getpid() || heldErr.Started.IsZero() {
+		t.Errorf("holder = PID %d started %v, want this process", heldErr.PID, heldErr.Started)
+	}
+
+	t.Setenv(writeLockHolderEnv, strconv.Itoa(os.Getpid()))
+	if !writeLockHeldByParent(dir) {
+		t.Error("writeLockHeldByParent = false for the recorded holder")
+	}
+
+	held.Release()
+	if writeLockHeldByParent(dir) {
+		t.Error("writeLockHeldByParent = true after release")
+	}
+	again, err := acquireWriteLock(dir, 0)
+	if err != nil {
+		t.Fatalf("acquire after release: %v", err)
+	}
+	again.Release()
+}
+
+func TestCommandWriteLock(t *testing.T) {
+	t.Setenv(writeLockHolderEnv, "")
+	t.Cleanup(func() {
+		releaseCommandWriteLock()
+		commandWriteLockDir = ""
+	})
+
+	// Without a .beads directory there is nothing to lock.
+	commandWriteLockDir = filepath.Join(t.TempDir(), "missing")
+	lockCommandWrites()
+	if commandWriteLock != nil {
+		t.Fatal("locked a missing beads directory")
+	}
+
+	dir := t.TempDir()
+	commandWriteLockDir = dir
+	err := withCommandWriteLock(func() error {
+		if _, err := acquireWriteLock(dir, 0); err == nil {
+			t.Error("write lock not held inside withCommandWriteLock")
+		}
+		return nil
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if commandWriteLock != nil || os.Getenv(writeLockHolderEnv) != "" {
+		t.Error("withCommandWriteLock kept the lock")
+	}
+	other, err := acquireWriteLock(dir, 0)
+	if err != nil {
+		t.Fatalf("acquire after withCommandWriteLock: %v", err)
+	}
+	other.Release()
+
+	// Once a command holds the lock for good, a locked section leaves it held.
+	lockCommandWrites()
+	if commandWriteLock == nil {
+		t.Fatal("lockCommandWrites did not take the lock")
+	}
+	_ = withCommandWriteLock(func() error { return nil })
+	if commandWriteLock == nil {
+		t.Error("withCommandWriteLock released a lock it did not take")
+	}
+}
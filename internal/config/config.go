@@ -185,6 +185,14 @@ func Initialize() error {
 	// Values: off | on
 	v.SetDefault("dolt.auto-commit", "on")
 
+	// Write lock: how long a write command waits for .beads/lock when
+	// another bd process holds it (0 = fail immediately)
+	v.SetDefault("lock.timeout", "10s")
+
+	// Daemon configuration (bd daemon, --use-daemon)
+	v.SetDefault("daemon.auto", false)
+	v.SetDefault("daemon.idle_timeout", "30m")
+
 	// Routing configuration defaults
 	v.SetDefault("routing.mode", "")
 	v.SetDefault("routing.default", ".")
@@ -214,6 +222,7 @@ func Initialize() error {
 	v.SetDefault("validation.on-create", "none")
 	v.SetDefault("validation.on-close", "none")
 	v.SetDefault("validation.on-sync", "none")
+	v.SetDefault("validation.on-actor", "none") // write commands by actors missing from 'bd actor list'
 
 	// Metadata schema validation (GH#1416 Phase 2)
 	// - "none": no metadata schema validation (default)
@@ -221,14 +230,25 @@ func Initialize() error {
 	// - "error": validate and reject invalid metadata
 	v.SetDefault("validation.metadata.mode", "none")
 
+	// Field rules (see validation.RulesFromConfig): title length, allowed
+	// priorities, and validation.required.<type> field lists, enforced at
+	// validation.strictness ("strict", "warn", or "relaxed").
+	v.SetDefault("validation.strictness", "strict")
+
 	// Hierarchy configuration defaults (GH#995)
 	// Maximum nesting depth for hierarchical IDs (e.g., bd-abc.1.2.3)
 	// Default matches types.MaxHierarchyDepth constant
 	v.SetDefault("hierarchy.max-depth", 3)
 
+	// Priority aging: rank open issues one priority level higher in ready
+	// work for every interval they go without an update (e.g. "14d").
+	// Empty = off; the stored priority is never rewritten.
+	v.SetDefault("priority.aging", "")
+
 	// Git configuration defaults (GH#600)
-	v.SetDefault("git.author", "")         // Override commit author (e.g., "beads-bot <beads@example.com>")
-	v.SetDefault("git.no-gpg-sign", false) // Disable GPG signing for beads commits
+	v.SetDefault("git.author", "")            // Override commit author (e.g., "beads-bot <beads@example.com>")
+	v.SetDefault("git.no-gpg-sign", false)    // Disable GPG signing for beads commits
+	v.SetDefault("git.close-on-fixes", false) // post-commit hook closes issues named in Fixes: trailers
 
 	// Directory-aware label scoping (GH#541)
 	// Maps directory patterns to labels for automatic filtering in monorepos
@@ -239,6 +259,8 @@ func Initialize() error {
 	v.SetDefault("backup.interval", "15m")
 	v.SetDefault("backup.git-push", false)
 	v.SetDefault("backup.git-repo", "")
+	v.SetDefault("backup.snapshot-keep", 10)
+	v.SetDefault("backup.snapshot-before-write", true)
 
 	// Auto-export: optional JSONL export after mutations for viewers,
 	// interchange, and backup. It is not cross-machine sync; Dolt remotes are
@@ -248,6 +270,11 @@ func Initialize() error {
 	v.SetDefault("export.path", "issues.jsonl") // relative to .beads/; canonical name
 	v.SetDefault("export.git-add", false)
 
+	// Sync-to-git: export after every write, without export.interval's
+	// throttle, and optionally commit the export to git.
+	v.SetDefault("sync.auto_export", false)
+	v.SetDefault("sync.auto_commit", false)
+
 	// Auto-import: legacy compatibility fallback for projects that have not
 	// configured a Dolt remote yet. Hook code skips this path when sync.remote
 	// is configured because JSONL import is upsert-only, not reconciliation.
@@ -939,6 +966,26 @@ func MetadataSchemaFields() map[string]interface{} {
 	return nil
 }
 
+// ValidationRequiredFields returns the validation.required.<type> entries:
+// issue type → field names its issues must set. Each entry may be a YAML
+// list or a comma-separated string. Returns nil if none are configured.
+func ValidationRequiredFields() map[string][]string {
+	var required map[string][]string
+	for _, key := range AllKeys() {
+		issueType, ok := strings.CutPrefix(key, "validation.required.")
+		if !ok || issueType == "" {
+			continue
+		}
+		if fields := getConfigList(key); len(fields) > 0 {
+			if required == nil {
+				required = make(map[string][]string)
+			}
+			required[issueType] = fields
+		}
+	}
+	return required
+}
+
 // DefaultAgentsFile is the default filename for agent instructions.
 const DefaultAgentsFile = "AGENTS.md"
 
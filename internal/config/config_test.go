@@ -1111,6 +1111,11 @@ func TestValidationConfigDefaults(t *testing.T) {
 	if got := GetString("validation.on-sync"); got != "none" {
 		t.Errorf("GetString(validation.on-sync) = %q, want \"none\"", got)
 	}
+
+	// Test validation.on-actor default is "none"
+	if got := GetString("validation.on-actor"); got != "none" {
+		t.Errorf("GetString(validation.on-actor) = %q, want \"none\"", got)
+	}
 }
 
 func TestValidationConfigFromFile(t *testing.T) {
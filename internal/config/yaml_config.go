@@ -29,16 +29,20 @@ var YamlOnlyKeys = map[string]bool{
 	"identity": true,
 
 	// Git settings
-	"git.author":      true,
-	"git.no-gpg-sign": true,
-	"no-push":         true,
-	"no-git-ops":      true, // Disable git ops in bd prime session close protocol (GH#593)
+	"git.author":         true,
+	"git.no-gpg-sign":    true,
+	"git.close-on-fixes": true,
+	"no-push":            true,
+	"no-git-ops":         true, // Disable git ops in bd prime session close protocol (GH#593)
 
 	// Sync settings
 	"sync.remote":     true, // Primary: any Dolt-compatible remote URL
 	"sync.git-remote": true, // Deprecated: falls back from sync.remote
 	"sync.require_confirmation_on_mass_delete": true,
 
+	"sync.auto_export": true, // JSONL export after every write
+	"sync.auto_commit": true, // git commit of that export
+
 	// Routing settings
 	"routing.mode":        true,
 	"routing.default":     true,
@@ -53,6 +57,7 @@ var YamlOnlyKeys = map[string]bool{
 	"validation.on-create": true,
 	"validation.on-close":  true,
 	"validation.on-sync":   true,
+	"validation.on-actor":  true,
 
 	// Hierarchy settings (GH#995)
 	"hierarchy.max-depth": true,
@@ -63,13 +68,24 @@ var YamlOnlyKeys = map[string]bool{
 	"backup.git-push": true,
 	"backup.git-repo": true,
 
+	"backup.snapshot-keep":         true,
+	"backup.snapshot-before-write": true,
+
 	// Import settings
 	"import.path": true,
 
+	// Write lock wait (read before the database is opened)
+	"lock.timeout": true,
+
+	// Ready-work ordering (read through viper, like the other tool settings)
+	"priority.aging": true,
+
 	// Dolt server settings
-	"dolt.shared-server": true, // Shared Dolt server at ~/.beads/shared-server/ (GH#2377)
-	"dolt.max-conns":     true, // Connection pool size override (default 10, GH#3140)
-	"dolt.debug":         true, // Debug-mode dolt sql-server: --loglevel=debug + --prof cpu
+	"dolt.shared-server":     true, // Shared Dolt server at ~/.beads/shared-server/ (GH#2377)
+	"dolt.max-conns":         true, // Connection pool size override (default 10, GH#3140)
+	"dolt.max-idle-conns":    true, // Idle pooled connections kept warm (default 5)
+	"dolt.conn-max-lifetime": true, // Pooled connection reuse limit (default 1h)
+	"dolt.debug":             true, // Debug-mode dolt sql-server: --loglevel=debug + --prof cpu
 
 	// Secrets: tokens and API keys must NOT be stored in the Dolt database
 	// because that data is pushed to remotes, triggering secret-scanning
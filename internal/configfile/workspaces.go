@@ -0,0 +1,120 @@
+package configfile
+
+import (
+	"bytes"
+	"fmt"
+	"os"
+	"path/filepath"
+	"runtime"
+	"slices"
+
+	"github.com/BurntSushi/toml"
+)
+
+// WorkspacesFileName is the filename of the workspace registry.
+const WorkspacesFileName = "workspaces.toml"
+
+// Workspace is a beads repository registered on this machine, so that
+// commands like 'bd list --all-repos' can reach it from any other.
+type Workspace struct {
+	Name string `toml:"name"`
+	Path string `toml:"path"` // repository root; may start with ~
+}
+
+// WorkspaceRegistry is the list of registered workspaces.
+//
+// File format:
+//
+//	[[workspace]]
+//	name = "api"
+//	path = "/home/me/src/api"
+type WorkspaceRegistry struct {
+	Workspaces []Workspace `toml:"workspace"`
+}
+
+// WorkspacesPath returns the path of the workspace registry:
+// BEADS_WORKSPACES_FILE if set, else the platform default.
+// Linux/macOS: ~/.config/beads/workspaces.toml
+// Windows: %APPDATA%\beads\workspaces.toml
+func WorkspacesPath() string {
+	if path := os.Getenv("BEADS_WORKSPACES_FILE"); path != "" {
+		return path
+	}
+	if runtime.GOOS == "windows" {
+		if appdata := os.Getenv("APPDATA"); appdata != "" {
+			return filepath.Join(appdata, "beads", WorkspacesFileName)
+		}
+	}
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return ""
+	}
+	return filepath.Join(home, ".config", "beads", WorkspacesFileName)
+}
+
+// LoadWorkspaces reads the workspace registry at path. A missing file is
+// an empty registry.
+func LoadWorkspaces(path string) (*WorkspaceRegistry, error) {
+	data, err := os.ReadFile(path) // #nosec G304 - path from WorkspacesPath
+	if os.IsNotExist(err) {
+		return &WorkspaceRegistry{}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("reading workspace registry: %w", err)
+	}
+	var reg WorkspaceRegistry
+	if err := toml.Unmarshal(data, &reg); err != nil {
+		return nil, fmt.Errorf("parsing workspace registry %s: %w", path, err)
+	}
+	return &reg, nil
+}
+
+// Save writes the registry to path, creating its directory if needed.
+func (r *WorkspaceRegistry) Save(path string) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0o750); err != nil {
+		return fmt.Errorf("creating workspace registry directory: %w", err)
+	}
+	var buf bytes.Buffer
+	if err := toml.NewEncoder(&buf).Encode(r); err != nil {
+		return fmt.Errorf("encoding workspace registry: %w", err)
+	}
+	if err := os.WriteFile(path, buf.Bytes(), 0o600); err != nil {
+		return fmt.Errorf("writing workspace registry: %w", err)
+	}
+	return nil
+}
+
+// Find returns the workspace named name, or nil.
+func (r *WorkspaceRegistry) Find(name string) *Workspace {
+	for i := range r.Workspaces {
+		if r.Workspaces[i].Name == name {
+			return &r.Workspaces[i]
+		}
+	}
+	return nil
+}
+
+// Add registers w. It fails if the name or the path is already
+// registered.
+func (r *WorkspaceRegistry) Add(w Workspace) error {
+	for _, existing := range r.Workspaces {
+		if existing.Name == w.Name {
+			return fmt.Errorf("a workspace named %q is already registered (%s)", w.Name, existing.Path)
+		}
+		if existing.Path == w.Path {
+			return fmt.Errorf("%s is already registered as %q", w.Path, existing.Name)
+		}
+	}
+	r.Workspaces = append(r.Workspaces, w)
+	return nil
+}
+
+// Remove unregisters the workspace with the given name or path, and
+// reports whether there was one.
+func (r *WorkspaceRegistry) Remove(nameOrPath string) bool {
+	n := len(r.Workspaces)
+	r.Workspaces = slices.DeleteFunc(r.Workspaces, func(w Workspace) bool {
+		return w.Name == nameOrPath || w.Path == nameOrPath
+	})
+	return len(r.Workspaces) < n
+}
@@ -0,0 +1,44 @@
+package configfile
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestWorkspaceRegistry_RoundTrip(t *testing.T) {
+	path := filepath.Join(t.TempDir(), ".config", "beads", WorkspacesFileName)
+
+	reg, err := LoadWorkspaces(path)
+	if err != nil || len(reg.Workspaces) != 0 {
+		t.Fatalf("LoadWorkspaces(missing) = %+v, %v; want empty registry", reg, err)
+	}
+	if err := reg.Add(Workspace{Name: "api", Path: "/src/api"}); err != nil {
+		t.Fatal(err)
+	}
+	if err := reg.Add(Workspace{Name: "web", Path: "/src/web"}); err != nil {
+		t.Fatal(err)
+	}
+	if err := reg.Add(Workspace{Name: "api", Path: "/src/other"}); err == nil {
+		t.Error("Add accepted a duplicate name")
+	}
+	if err := reg.Add(Workspace{Name: "again", Path: "/src/web"}); err == nil {
+		t.Error("Add accepted a duplicate path")
+	}
+	if err := reg.Save(path); err != nil {
+		t.Fatal(err)
+	}
+
+	loaded, err := LoadWorkspaces(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(loaded.Workspaces) != 2 || loaded.Find("web") == nil || loaded.Find("web").Path != "/src/web" {
+		t.Fatalf("loaded registry = %+v", loaded.Workspaces)
+	}
+	if !loaded.Remove("/src/api") || loaded.Remove("api") {
+		t.Error("Remove by path should remove api exactly once")
+	}
+	if len(loaded.Workspaces) != 1 || loaded.Workspaces[0].Name != "web" {
+		t.Errorf("after Remove = %+v", loaded.Workspaces)
+	}
+}
@@ -0,0 +1,339 @@
+package github
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// GitHub Projects (v2) are only reachable through the GraphQL API. The
+// methods below cover what an export needs: reading a board's single-select
+// fields, adding issues and draft issues to it, and setting field values.
+
+// ProjectV2 is a GitHub Projects (v2) board.
+type ProjectV2 struct {
+	ID     string
+	Title  string
+	URL    string
+	Fields []*ProjectV2Field // single-select fields only
+}
+
+// ProjectV2Field is a single-select field of a board, such as Status.
+type ProjectV2Field struct {
+	ID      string
+	Name    string
+	Options []ProjectV2Option
+}
+
+// ProjectV2Option is one choice of a single-select field.
+type ProjectV2Option struct {
+	ID   string `json:"id"`
+	Name string `json:"name"`
+}
+
+// ProjectV2Item is an item on a board. ContentType is "Issue",
+// "PullRequest", or "DraftIssue"; ContentID is that content's node ID.
+type ProjectV2Item struct {
+	ID          string
+	ContentType string
+	ContentID   string
+}
+
+// Field returns the single-select field with the given name, compared
+// case-insensitively, or nil.
+func (p *ProjectV2) Field(name string) *ProjectV2Field {
+	for _, f := range p.Fields {
+		if strings.EqualFold(f.Name, name) {
+			return f
+		}
+	}
+	return nil
+}
+
+// Option returns the first of names that the field offers as an option.
+// Names match case-insensitively, with "_" and "-" equal to a space, so
+// "in_progress" finds an "In Progress" option.
+func (f *ProjectV2Field) Option(names ...string) (ProjectV2Option, bool) {
+	norm := strings.NewReplacer("_", " ", "-", " ")
+	for _, name := range names {
+		want := norm.Replace(strings.ToLower(strings.TrimSpace(name)))
+		if want == "" {
+			continue
+		}
+		for _, opt := range f.Options {
+			if norm.Replace(strings.ToLower(opt.Name)) == want {
+				return opt, true
+			}
+		}
+	}
+	return ProjectV2Option{}, false
+}
+
+// ProjectStatusOptionNames lists the Status option names a beads status
+// maps to, most specific first: the status itself, then the column of
+// GitHub's default board template (Todo, In Progress, Done) it belongs in.
+func ProjectStatusOptionNames(status types.Status) []string {
+	switch status {
+	case types.StatusInProgress:
+		return []string{string(status), "In Progress"}
+	case types.StatusClosed:
+		return []string{string(status), "Done"}
+	default:
+		return []string{string(status), "Todo"}
+	}
+}
+
+// ProjectPriorityOptionNames lists the Priority option names a beads
+// priority maps to. GitHub's template uses P0, P1, P2.
+func ProjectPriorityOptionNames(priority int) []string {
+	return []string{fmt.Sprintf("P%d", priority)}
+}
+
+// graphQLURL returns the GraphQL endpoint for the client's API base URL.
+// GitHub Enterprise serves REST at /api/v3 and GraphQL at /api/graphql.
+func (c *Client) graphQLURL() string {
+	if base, ok := strings.CutSuffix(c.BaseURL, "/api/v3"); ok {
+		return base + "/api/graphql"
+	}
+	return c.BaseURL + "/graphql"
+}
+
+// graphQL runs a GraphQL query and decodes its data into out.
+func (c *Client) graphQL(ctx context.Context, query string, vars map[string]interface{}, out interface{}) error {
+	respBody, _, err := c.doRequest(ctx, http.MethodPost, c.graphQLURL(), map[string]interface{}{
+		"query":     query,
+		"variables": vars,
+	})
+	if err != nil {
+		return err
+	}
+	var resp struct {
+		Data   json.RawMessage `json:"data"`
+		Errors []struct {
+			Message string `json:"message"`
+		} `json:"errors"`
+	}
+	if err := json.Unmarshal(respBody, &resp); err != nil {
+		return fmt.Errorf("failed to parse GraphQL response: %w", err)
+	}
+	if len(resp.Errors) > 0 {
+		msgs := make([]string, len(resp.Errors))
+		for i, e := range resp.Errors {
+			msgs[i] = e.Message
+		}
+		return fmt.Errorf("GraphQL error: %s", strings.Join(msgs, "; "))
+	}
+	if out == nil {
+		return nil
+	}
+	if err := json.Unmarshal(resp.Data, out); err != nil {
+		return fmt.Errorf("failed to parse GraphQL data: %w", err)
+	}
+	return nil
+}
+
+const projectV2Query = `query($owner: String!, $number: Int!) {
+  repositoryOwner(login: $owner) {
+    ... on Organization { projectV2(number: $number) { ...board } }
+    ... on User { projectV2(number: $number) { ...board } }
+  }
+}
+fragment board on ProjectV2 {
+  id
+  title
+  url
+  fields(first: 50) {
+    nodes {
+      ... on ProjectV2SingleSelectField { id name options { id name } }
+    }
+  }
+}`
+
+// GetProjectV2 fetches board number of the user or organization owner.
+func (c *Client) GetProjectV2(ctx context.Context, owner string, number int) (*ProjectV2, error) {
+	var data struct {
+		RepositoryOwner *struct {
+			ProjectV2 *struct {
+				ID     string `json:"id"`
+				Title  string `json:"title"`
+				URL    string `json:"url"`
+				Fields struct {
+					Nodes []struct {
+						ID      string            `json:"id"`
+						Name    string            `json:"name"`
+						Options []ProjectV2Option `json:"options"`
+					} `json:"nodes"`
+				} `json:"fields"`
+			} `json:"projectV2"`
+		} `json:"repositoryOwner"`
+	}
+	vars := map[string]interface{}{"owner": owner, "number": number}
+	if err := c.graphQL(ctx, projectV2Query, vars, &data); err != nil {
+		return nil, fmt.Errorf("failed to fetch project %s/%d: %w", owner, number, err)
+	}
+	if data.RepositoryOwner == nil || data.RepositoryOwner.ProjectV2 == nil {
+		return nil, fmt.Errorf("project %d not found for %s", number, owner)
+	}
+	p := data.RepositoryOwner.ProjectV2
+	project := &ProjectV2{ID: p.ID, Title: p.Title, URL: p.URL}
+	for _, f := range p.Fields.Nodes {
+		// Fields of other kinds come back as empty objects.
+		if f.ID != "" {
+			project.Fields = append(project.Fields, &ProjectV2Field{ID: f.ID, Name: f.Name, Options: f.Options})
+		}
+	}
+	return project, nil
+}
+
+const projectV2ItemsQuery = `query($project: ID!, $after: String) {
+  node(id: $project) {
+    ... on ProjectV2 {
+      items(first: 100, after: $after) {
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          id
+          content {
+            __typename
+            ... on Issue { id }
+            ... on PullRequest { id }
+            ... on DraftIssue { id }
+          }
+        }
+      }
+    }
+  }
+}`
+
+// ListProjectV2Items returns every item on a board.
+func (c *Client) ListProjectV2Items(ctx context.Context, projectID string) ([]ProjectV2Item, error) {
+	var items []ProjectV2Item
+	var after interface{}
+	for page := 0; page < MaxPages; page++ {
+		var data struct {
+			Node struct {
+				Items struct {
+					PageInfo struct {
+						HasNextPage bool   `json:"hasNextPage"`
+						EndCursor   string `json:"endCursor"`
+					} `json:"pageInfo"`
+					Nodes []struct {
+						ID      string `json:"id"`
+						Content *struct {
+							Typename string `json:"__typename"`
+							ID       string `json:"id"`
+						} `json:"content"`
+					} `json:"nodes"`
+				} `json:"items"`
+			} `json:"node"`
+		}
+		vars := map[string]interface{}{"project": projectID, "after": after}
+		if err := c.graphQL(ctx, projectV2ItemsQuery, vars, &data); err != nil {
+			return nil, fmt.Errorf("failed to list project items: %w", err)
+		}
+		for _, n := range data.Node.Items.Nodes {
+			item := ProjectV2Item{ID: n.ID}
+			if n.Content != nil {
+				item.ContentType, item.ContentID = n.Content.Typename, n.Content.ID
+			}
+			items = append(items, item)
+		}
+		if !data.Node.Items.PageInfo.HasNextPage {
+			return items, nil
+		}
+		after = data.Node.Items.PageInfo.EndCursor
+	}
+	return nil, fmt.Errorf("pagination limit exceeded: stopped after %d pages", MaxPages)
+}
+
+// IssueNodeID returns the GraphQL node ID of issue number in the client's
+// repository, which is what a board item refers to.
+func (c *Client) IssueNodeID(ctx context.Context, number int) (string, error) {
+	var data struct {
+		Repository struct {
+			Issue *struct {
+				ID string `json:"id"`
+			} `json:"issue"`
+		} `json:"repository"`
+	}
+	query := `query($owner: String!, $repo: String!, $number: Int!) {
+  repository(owner: $owner, name: $repo) { issue(number: $number) { id } }
+}`
+	vars := map[string]interface{}{"owner": c.Owner, "repo": c.Repo, "number": number}
+	if err := c.graphQL(ctx, query, vars, &data); err != nil {
+		return "", fmt.Errorf("failed to look up issue #%d: %w", number, err)
+	}
+	if data.Repository.Issue == nil {
+		return "", fmt.Errorf("issue #%d not found in %s/%s", number, c.Owner, c.Repo)
+	}
+	return data.Repository.Issue.ID, nil
+}
+
+// AddProjectV2Item adds an existing issue or pull request to a board and
+// returns the item ID. Adding content that is already on the board returns
+// its existing item.
+func (c *Client) AddProjectV2Item(ctx context.Context, projectID, contentID string) (string, error) {
+	var data struct {
+		AddProjectV2ItemByID struct {
+			Item struct {
+				ID string `json:"id"`
+			} `json:"item"`
+		} `json:"addProjectV2ItemById"`
+	}
+	query := `mutation($project: ID!, $content: ID!) {
+  addProjectV2ItemById(input: {projectId: $project, contentId: $content}) { item { id } }
+}`
+	vars := map[string]interface{}{"project": projectID, "content": contentID}
+	if err := c.graphQL(ctx, query, vars, &data); err != nil {
+		return "", fmt.Errorf("failed to add item to project: %w", err)
+	}
+	return data.AddProjectV2ItemByID.Item.ID, nil
+}
+
+// AddProjectV2DraftIssue creates a draft issue on a board and returns the
+// item ID.
+func (c *Client) AddProjectV2DraftIssue(ctx context.Context, projectID, title, body string) (string, error) {
+	var data struct {
+		AddProjectV2DraftIssue struct {
+			ProjectItem struct {
+				ID string `json:"id"`
+			} `json:"projectItem"`
+		} `json:"addProjectV2DraftIssue"`
+	}
+	query := `mutation($project: ID!, $title: String!, $body: String) {
+  addProjectV2DraftIssue(input: {projectId: $project, title: $title, body: $body}) { projectItem { id } }
+}`
+	vars := map[string]interface{}{"project": projectID, "title": title, "body": body}
+	if err := c.graphQL(ctx, query, vars, &data); err != nil {
+		return "", fmt.Errorf("failed to add draft issue to project: %w", err)
+	}
+	return data.AddProjectV2DraftIssue.ProjectItem.ID, nil
+}
+
+// UpdateProjectV2DraftIssue sets the title and body of a draft issue, given
+// its content ID (not its item ID).
+func (c *Client) UpdateProjectV2DraftIssue(ctx context.Context, draftID, title, body string) error {
+	query := `mutation($draft: ID!, $title: String!, $body: String) {
+  updateProjectV2DraftIssue(input: {draftIssueId: $draft, title: $title, body: $body}) { draftIssue { id } }
+}`
+	vars := map[string]interface{}{"draft": draftID, "title": title, "body": body}
+	if err := c.graphQL(ctx, query, vars, nil); err != nil {
+		return fmt.Errorf("failed to update draft issue: %w", err)
+	}
+	return nil
+}
+
+// SetProjectV2SingleSelect sets a single-select field of a board item.
+func (c *Client) SetProjectV2SingleSelect(ctx context.Context, projectID, itemID, fieldID, optionID string) error {
+	query := `mutation($project: ID!, $item: ID!, $field: ID!, $option: String!) {
+  updateProjectV2ItemFieldValue(input: {projectId: $project, itemId: $item, fieldId: $field, value: {singleSelectOptionId: $option}}) { projectV2Item { id } }
+}`
+	vars := map[string]interface{}{"project": projectID, "item": itemID, "field": fieldID, "option": optionID}
+	if err := c.graphQL(ctx, query, vars, nil); err != nil {
+		return fmt.Errorf("failed to set project field: %w", err)
+	}
+	return nil
+}
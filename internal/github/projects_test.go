@@ -0,0 +1,78 @@
+package github
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestGetProjectV2(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/graphql" || r.Method != http.MethodPost {
+			t.Errorf("request = %s %s, want POST /graphql", r.Method, r.URL.Path)
+		}
+		var req struct {
+			Query     string                 `json:"query"`
+			Variables map[string]interface{} `json:"variables"`
+		}
+		_ = json.NewDecoder(r.Body).Decode(&req)
+		if req.Variables["owner"] != "acme" || req.Variables["number"] != float64(3) {
+			t.Errorf("variables = %v", req.Variables)
+		}
+		_, _ = w.Write([]byte(`{"data":{"repositoryOwner":{"projectV2":{"id":"PVT_1","title":"Roadmap","url":"https://github.com/orgs/acme/projects/3",
+			"fields":{"nodes":[{},{"id":"F_status","name":"Status","options":[{"id":"o1","name":"Todo"},{"id":"o2","name":"In Progress"},{"id":"o3","name":"Done"}]}]}}}}}`))
+	}))
+	defer srv.Close()
+
+	c := NewClient("test-token", "acme", "app").WithBaseURL(srv.URL)
+	project, err := c.GetProjectV2(context.Background(), "acme", 3)
+	if err != nil {
+		t.Fatalf("GetProjectV2: %v", err)
+	}
+	if project.ID != "PVT_1" || project.Title != "Roadmap" || len(project.Fields) != 1 {
+		t.Fatalf("project = %+v", project)
+	}
+	status := project.Field("status")
+	if status == nil {
+		t.Fatal("Field(status) = nil")
+	}
+	if opt, ok := status.Option(ProjectStatusOptionNames(types.StatusInProgress)...); !ok || opt.ID != "o2" {
+		t.Errorf("in_progress option = %+v, %v", opt, ok)
+	}
+	if opt, ok := status.Option(ProjectStatusOptionNames(types.StatusBlocked)...); !ok || opt.Name != "Todo" {
+		t.Errorf("blocked option = %+v, %v", opt, ok)
+	}
+	if project.Field("Priority") != nil {
+		t.Error("Field(Priority) should be nil")
+	}
+}
+
+func TestGraphQLErrors(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		_, _ = w.Write([]byte(`{"data":null,"errors":[{"message":"Could not resolve to a ProjectV2 with the number 9."}]}`))
+	}))
+	defer srv.Close()
+
+	c := NewClient("test-token", "acme", "app").WithBaseURL(srv.URL)
+	_, err := c.GetProjectV2(context.Background(), "acme", 9)
+	if err == nil || !strings.Contains(err.Error(), "Could not resolve") {
+		t.Errorf("err = %v, want the GraphQL error message", err)
+	}
+}
+
+func TestGraphQLURL(t *testing.T) {
+	tests := map[string]string{
+		DefaultBaseURL:                   "https://api.github.com/graphql",
+		"https://ghe.example.com/api/v3": "https://ghe.example.com/api/graphql",
+	}
+	for base, want := range tests {
+		if got := NewClient("t", "o", "r").WithBaseURL(base).graphQLURL(); got != want {
+			t.Errorf("graphQLURL(%s) = %s, want %s", base, got, want)
+		}
+	}
+}
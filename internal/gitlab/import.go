@@ -0,0 +1,87 @@
+package gitlab
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"strconv"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// ReadIssuesJSON decodes issues saved from the GitLab issues API, e.g. the
+// output of 'glab api projects/:id/issues --paginate' or 'glab issue list
+// --output json'. The input may hold one JSON array, several concatenated
+// arrays (one per page), or a stream of issue objects.
+func ReadIssuesJSON(r io.Reader) ([]Issue, error) {
+	dec := json.NewDecoder(r)
+	var issues []Issue
+	for {
+		var raw json.RawMessage
+		if err := dec.Decode(&raw); err == io.EOF {
+			break
+		} else if err != nil {
+			return nil, fmt.Errorf("parse GitLab issues JSON: %w", err)
+		}
+		if len(raw) > 0 && raw[0] == '[' {
+			var page []Issue
+			if err := json.Unmarshal(raw, &page); err != nil {
+				return nil, fmt.Errorf("parse GitLab issues JSON: %w", err)
+			}
+			issues = append(issues, page...)
+			continue
+		}
+		var issue Issue
+		if err := json.Unmarshal(raw, &issue); err != nil {
+			return nil, fmt.Errorf("parse GitLab issues JSON: %w", err)
+		}
+		issues = append(issues, issue)
+	}
+	return issues, nil
+}
+
+// IssueExternalRef returns the external_ref 'bd gitlab sync' uses for an
+// issue: its web URL, or the "gitlab:{iid}" shorthand when the URL is unknown.
+func IssueExternalRef(gl *Issue) string {
+	if gl.WebURL != "" {
+		return gl.WebURL
+	}
+	return "gitlab:" + strconv.Itoa(gl.IID)
+}
+
+// MilestoneToEpic converts a GitLab milestone to the beads epic that push
+// maps back to it. The epic's external_ref is the milestone URL, which is
+// how push finds the milestone to assign the epic's children to.
+func MilestoneToEpic(ms *Milestone) *types.Issue {
+	webURL := ms.WebURL
+	epic := &types.Issue{
+		Title:        ms.Title,
+		Description:  ms.Description,
+		ExternalRef:  &webURL,
+		SourceSystem: fmt.Sprintf("gitlab:milestone:%d", ms.ID),
+		IssueType:    types.TypeEpic,
+		Priority:     2,
+		Status:       types.StatusOpen,
+	}
+	if ms.CreatedAt != nil {
+		epic.CreatedAt = *ms.CreatedAt
+	}
+	if ms.UpdatedAt != nil {
+		epic.UpdatedAt = *ms.UpdatedAt
+	}
+	if ms.State == "closed" {
+		epic.Status = types.StatusClosed
+		closedAt := epic.UpdatedAt
+		if closedAt.IsZero() {
+			closedAt = time.Now().UTC()
+		}
+		epic.ClosedAt = &closedAt
+	}
+	if ms.DueDate != "" {
+		if due, err := time.Parse("2006-01-02", ms.DueDate); err == nil {
+			epic.DueAt = &due
+		}
+	}
+	return epic
+}
@@ -0,0 +1,61 @@
+package gitlab
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestReadIssuesJSONAcceptsPagesAndObjects(t *testing.T) {
+	input := `[{"iid": 1, "title": "First"}, {"iid": 2, "title": "Second"}]
+[{"iid": 3, "title": "Third", "milestone": {"id": 7, "iid": 2, "title": "v1.0", "state": "active"}}]
+{"iid": 4, "title": "Fourth"}
+`
+	issues, err := ReadIssuesJSON(strings.NewReader(input))
+	if err != nil {
+		t.Fatalf("ReadIssuesJSON: %v", err)
+	}
+	if len(issues) != 4 {
+		t.Fatalf("got %d issues, want 4", len(issues))
+	}
+	if issues[2].Milestone == nil || issues[2].Milestone.Title != "v1.0" {
+		t.Errorf("milestone = %+v, want v1.0", issues[2].Milestone)
+	}
+	if issues[3].IID != 4 {
+		t.Errorf("issues[3].IID = %d, want 4", issues[3].IID)
+	}
+
+	if _, err := ReadIssuesJSON(strings.NewReader(`[{"iid": 1}`)); err == nil {
+		t.Error("expected an error for truncated JSON")
+	}
+}
+
+func TestIssueExternalRef(t *testing.T) {
+	if got := IssueExternalRef(&Issue{IID: 5, WebURL: "https://gitlab.com/g/p/-/issues/5"}); got != "https://gitlab.com/g/p/-/issues/5" {
+		t.Errorf("IssueExternalRef = %q, want web URL", got)
+	}
+	if got := IssueExternalRef(&Issue{IID: 5}); got != "gitlab:5" {
+		t.Errorf("IssueExternalRef without URL = %q, want gitlab:5", got)
+	}
+}
+
+func TestMilestoneToEpic(t *testing.T) {
+	epic := MilestoneToEpic(&Milestone{
+		ID:      7,
+		IID:     2,
+		Title:   "v1.0",
+		State:   "closed",
+		DueDate: "2024-03-01",
+		WebURL:  "https://gitlab.com/g/p/-/milestones/2",
+	})
+	if epic.IssueType != types.TypeEpic || epic.Status != types.StatusClosed || epic.ClosedAt == nil {
+		t.Errorf("epic type/status/closed_at = %s/%s/%v", epic.IssueType, epic.Status, epic.ClosedAt)
+	}
+	if epic.ExternalRef == nil || *epic.ExternalRef != "https://gitlab.com/g/p/-/milestones/2" {
+		t.Errorf("ExternalRef = %v, want milestone URL", epic.ExternalRef)
+	}
+	if epic.DueAt == nil || epic.DueAt.Format("2006-01-02") != "2024-03-01" {
+		t.Errorf("DueAt = %v, want 2024-03-01", epic.DueAt)
+	}
+}
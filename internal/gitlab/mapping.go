@@ -4,6 +4,7 @@ package gitlab
 import (
 	"fmt"
 	"strings"
+	"time"
 
 	"github.com/steveyegge/beads/internal/types"
 )
@@ -153,6 +154,17 @@ func GitLabIssueToBeads(gl *Issue, config *MappingConfig) *IssueConversion {
 	if gl.UpdatedAt != nil {
 		issue.UpdatedAt = *gl.UpdatedAt
 	}
+	if issue.Status == types.StatusClosed && gl.ClosedAt != nil {
+		closedAt := *gl.ClosedAt
+		issue.ClosedAt = &closedAt
+	}
+
+	// Set due date (GitLab sends a plain YYYY-MM-DD date)
+	if gl.DueDate != "" {
+		if due, err := time.Parse("2006-01-02", gl.DueDate); err == nil {
+			issue.DueAt = &due
+		}
+	}
 
 	// Convert issue links to dependencies if available.
 	deps := issueLinksToDependencies(gl.IID, gl.IssueLinksData, config)
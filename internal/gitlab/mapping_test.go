@@ -309,6 +309,11 @@ func TestGitLabIssueToBeads(t *testing.T) {
 	if issue.EstimatedMinutes == nil || *issue.EstimatedMinutes != 180 {
 		t.Errorf("EstimatedMinutes = %v, want 180 (from weight 3)", issue.EstimatedMinutes)
 	}
+
+	// Verify due date
+	if issue.DueAt == nil || !issue.DueAt.Equal(time.Date(2024, 1, 20, 0, 0, 0, 0, time.UTC)) {
+		t.Errorf("DueAt = %v, want 2024-01-20", issue.DueAt)
+	}
 }
 
 // TestGitLabIssueToBeads_ClosedIssue verifies closed issues are converted correctly.
@@ -335,6 +340,9 @@ func TestGitLabIssueToBeads_ClosedIssue(t *testing.T) {
 	if issue.Status != types.StatusClosed {
 		t.Errorf("Status = %q, want %q (closed state overrides labels)", issue.Status, types.StatusClosed)
 	}
+	if issue.ClosedAt == nil || !issue.ClosedAt.Equal(closedAt) {
+		t.Errorf("ClosedAt = %v, want %v", issue.ClosedAt, closedAt)
+	}
 }
 
 // TestBeadsIssueToGitLabFields verifies conversion from beads Issue to GitLab update fields.
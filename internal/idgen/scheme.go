@@ -0,0 +1,218 @@
+package idgen
+
+import (
+	"context"
+	"crypto/rand"
+	"encoding/binary"
+	"fmt"
+	"strings"
+	"time"
+)
+
+// Scheme names an ID generation strategy, as set by the issue_id_mode
+// config key.
+type Scheme string
+
+// ID schemes.
+const (
+	// SchemeHash mints short content hashes ("bd-a3f2"), lengthened as the
+	// database grows. This is the default.
+	SchemeHash Scheme = "hash"
+	// SchemeCounter mints sequential numbers per prefix ("bd-42").
+	SchemeCounter Scheme = "counter"
+	// SchemeULID mints lexically time-ordered ULIDs ("bd-01j9z3...").
+	SchemeULID Scheme = "ulid"
+	// SchemeActor mints sequential numbers in a namespace per actor
+	// ("bd-alice-3"), so people working apart never mint the same ID.
+	SchemeActor Scheme = "actor"
+)
+
+// Schemes lists the valid schemes, default first.
+var Schemes = []Scheme{SchemeHash, SchemeCounter, SchemeULID, SchemeActor}
+
+// ParseScheme returns the scheme named s. The empty string means the
+// default, SchemeHash.
+func ParseScheme(s string) (Scheme, error) {
+	if s == "" {
+		return SchemeHash, nil
+	}
+	for _, scheme := range Schemes {
+		if Scheme(s) == scheme {
+			return scheme, nil
+		}
+	}
+	names := make([]string, len(Schemes))
+	for i, scheme := range Schemes {
+		names[i] = string(scheme)
+	}
+	return "", fmt.Errorf("invalid issue_id_mode %q (valid values: %s)", s, strings.Join(names, ", "))
+}
+
+// Sequential reports whether the scheme numbers issues from a counter.
+// Two clones using a sequential scheme mint the same IDs for different
+// issues, so import treats a matching ID with a different creation time as
+// a collision rather than an update.
+func (s Scheme) Sequential() bool {
+	return s == SchemeCounter || s == SchemeActor
+}
+
+// Store is the database access a Strategy needs.
+type Store interface {
+	// Exists reports whether an issue already has id.
+	Exists(ctx context.Context, id string) (bool, error)
+	// NextCounter increments the counter named key and returns its new
+	// value, seeding it from existing "key-N" IDs the first time.
+	NextCounter(ctx context.Context, key string) (int, error)
+	// HashLengths returns the hash length to start at for prefix, given
+	// the number of issues already using it, and the longest to try.
+	HashLengths(ctx context.Context, prefix string) (base, max int, err error)
+}
+
+// Request describes the issue an ID is minted for.
+type Request struct {
+	Prefix      string
+	Title       string
+	Description string
+	Actor       string
+	CreatedAt   time.Time
+}
+
+// Strategy mints new top-level issue IDs.
+type Strategy interface {
+	NewID(ctx context.Context, st Store, req Request) (string, error)
+}
+
+// Strategy returns the strategy implementing the scheme.
+func (s Scheme) Strategy() Strategy {
+	switch s {
+	case SchemeCounter:
+		return counterStrategy{}
+	case SchemeULID:
+		return ulidStrategy{}
+	case SchemeActor:
+		return actorStrategy{}
+	default:
+		return hashStrategy{}
+	}
+}
+
+type hashStrategy struct{}
+
+func (hashStrategy) NewID(ctx context.Context, st Store, req Request) (string, error) {
+	baseLength, maxLength, err := st.HashLengths(ctx, req.Prefix)
+	if err != nil {
+		return "", err
+	}
+	if baseLength > maxLength {
+		baseLength = maxLength
+	}
+
+	for length := baseLength; length <= maxLength; length++ {
+		for nonce := 0; nonce < 10; nonce++ {
+			candidate := GenerateHashID(req.Prefix, req.Title, req.Description, req.Actor, req.CreatedAt, length, nonce)
+			exists, err := st.Exists(ctx, candidate)
+			if err != nil {
+				return "", fmt.Errorf("failed to check for ID collision: %w", err)
+			}
+			if !exists {
+				return candidate, nil
+			}
+		}
+	}
+	return "", fmt.Errorf("failed to generate unique ID for prefix %q after trying lengths %d-%d with 10 nonces each", req.Prefix, baseLength, maxLength)
+}
+
+type counterStrategy struct{}
+
+func (counterStrategy) NewID(ctx context.Context, st Store, req Request) (string, error) {
+	n, err := st.NextCounter(ctx, req.Prefix)
+	if err != nil {
+		return "", err
+	}
+	return fmt.Sprintf("%s-%d", req.Prefix, n), nil
+}
+
+type actorStrategy struct{}
+
+func (actorStrategy) NewID(ctx context.Context, st Store, req Request) (string, error) {
+	namespace := req.Prefix + "-" + ActorSlug(req.Actor)
+	n, err := st.NextCounter(ctx, namespace)
+	if err != nil {
+		return "", err
+	}
+	return fmt.Sprintf("%s-%d", namespace, n), nil
+}
+
+// actorSlugMaxLen caps the actor namespace so IDs stay typeable.
+const actorSlugMaxLen = 16
+
+// ActorSlug reduces an actor name to the lowercase letters and digits used
+// as its ID namespace: "Alice Smith <alice@example.com>" becomes
+// "alicesmith". An actor with none of those gets "anon".
+func ActorSlug(actor string) string {
+	if i := strings.IndexAny(actor, "<@"); i > 0 {
+		actor = actor[:i]
+	}
+	var b strings.Builder
+	for _, r := range strings.ToLower(actor) {
+		if (r >= 'a' && r <= 'z') || (r >= '0' && r <= '9') {
+			b.WriteRune(r)
+			if b.Len() == actorSlugMaxLen {
+				break
+			}
+		}
+	}
+	if b.Len() == 0 {
+		return "anon"
+	}
+	return b.String()
+}
+
+type ulidStrategy struct{}
+
+func (ulidStrategy) NewID(ctx context.Context, st Store, req Request) (string, error) {
+	for attempt := 0; attempt < 3; attempt++ {
+		ulid, err := NewULID(req.CreatedAt)
+		if err != nil {
+			return "", err
+		}
+		candidate := req.Prefix + "-" + ulid
+		exists, err := st.Exists(ctx, candidate)
+		if err != nil {
+			return "", fmt.Errorf("failed to check for ID collision: %w", err)
+		}
+		if !exists {
+			return candidate, nil
+		}
+	}
+	return "", fmt.Errorf("failed to generate unique ULID for prefix %q", req.Prefix)
+}
+
+// crockfordAlphabet is Crockford's base32 alphabet, lowercased to match
+// the rest of bd's IDs.
+const crockfordAlphabet = "0123456789abcdefghjkmnpqrstvwxyz"
+
+// NewULID returns a 26-character ULID: the millisecond timestamp t (now if
+// zero) followed by 80 random bits, in lowercase Crockford base32, so IDs
+// sort by creation time.
+func NewULID(t time.Time) (string, error) {
+	if t.IsZero() {
+		t = time.Now()
+	}
+	var data [16]byte
+	binary.BigEndian.PutUint64(data[:8], uint64(t.UnixMilli())<<16) //nolint:gosec // G115: pre-epoch times are not meaningful here
+	if _, err := rand.Read(data[6:]); err != nil {
+		return "", fmt.Errorf("failed to read random bytes for ULID: %w", err)
+	}
+
+	// 128 bits as 26 base32 digits: the first digit carries the top 3 bits.
+	hi := binary.BigEndian.Uint64(data[:8])
+	lo := binary.BigEndian.Uint64(data[8:])
+	out := make([]byte, 26)
+	for i := 25; i >= 0; i-- {
+		out[i] = crockfordAlphabet[lo&31]
+		lo = lo>>5 | hi<<59
+		hi >>= 5
+	}
+	return string(out), nil
+}
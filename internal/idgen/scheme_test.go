@@ -0,0 +1,101 @@
+package idgen
+
+import (
+	"context"
+	"strings"
+	"testing"
+	"time"
+)
+
+// memStore is an in-memory Store.
+type memStore struct {
+	ids      map[string]bool
+	counters map[string]int
+}
+
+func (m *memStore) Exists(_ context.Context, id string) (bool, error) {
+	return m.ids[id], nil
+}
+
+func (m *memStore) NextCounter(_ context.Context, key string) (int, error) {
+	m.counters[key]++
+	return m.counters[key], nil
+}
+
+func (m *memStore) HashLengths(_ context.Context, _ string) (int, int, error) {
+	return 4, 8, nil
+}
+
+func TestParseScheme(t *testing.T) {
+	for in, want := range map[string]Scheme{"": SchemeHash, "hash": SchemeHash, "counter": SchemeCounter, "ulid": SchemeULID, "actor": SchemeActor} {
+		got, err := ParseScheme(in)
+		if err != nil || got != want {
+			t.Errorf("ParseScheme(%q) = %q, %v; want %q", in, got, err, want)
+		}
+	}
+	if _, err := ParseScheme("uuid"); err == nil {
+		t.Error("ParseScheme(uuid) succeeded, want error")
+	}
+}
+
+func TestStrategies(t *testing.T) {
+	ctx := context.Background()
+	req := Request{Prefix: "bd", Title: "Fix login", Actor: "Alice Smith <alice@example.com>", CreatedAt: time.Date(2024, 1, 2, 3, 4, 5, 0, time.UTC)}
+	st := &memStore{ids: map[string]bool{}, counters: map[string]int{}}
+
+	hashID, err := SchemeHash.Strategy().NewID(ctx, st, req)
+	if err != nil || hashID != GenerateHashID("bd", req.Title, "", req.Actor, req.CreatedAt, 4, 0) {
+		t.Errorf("hash NewID = %q, %v", hashID, err)
+	}
+	st.ids[hashID] = true
+	if next, _ := SchemeHash.Strategy().NewID(ctx, st, req); next == hashID {
+		t.Errorf("hash NewID reused taken ID %q", next)
+	}
+
+	for _, want := range []string{"bd-1", "bd-2"} {
+		if got, err := SchemeCounter.Strategy().NewID(ctx, st, req); err != nil || got != want {
+			t.Errorf("counter NewID = %q, %v; want %q", got, err, want)
+		}
+	}
+	if got, err := SchemeActor.Strategy().NewID(ctx, st, req); err != nil || got != "bd-alicesmith-1" {
+		t.Errorf("actor NewID = %q, %v; want bd-alicesmith-1", got, err)
+	}
+
+	ulidID, err := SchemeULID.Strategy().NewID(ctx, st, req)
+	if err != nil || !strings.HasPrefix(ulidID, "bd-") || len(ulidID) != len("bd-")+26 {
+		t.Errorf("ulid NewID = %q, %v", ulidID, err)
+	}
+}
+
+func TestNewULIDSortsByTime(t *testing.T) {
+	t1 := time.Date(2024, 1, 2, 3, 4, 5, 0, time.UTC)
+	a, err := NewULID(t1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	b, err := NewULID(t1.Add(time.Millisecond))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if a >= b {
+		t.Errorf("NewULID not time-ordered: %s >= %s", a, b)
+	}
+	if strings.Trim(a, crockfordAlphabet) != "" {
+		t.Errorf("NewULID(%v) = %q has characters outside the alphabet", t1, a)
+	}
+}
+
+func TestActorSlug(t *testing.T) {
+	for in, want := range map[string]string{
+		"Alice Smith <alice@example.com>": "alicesmith",
+		"bob@example.com":                 "bob",
+		"agent-7":                         "agent7",
+		"":                                "anon",
+		"!!!":                             "anon",
+		"averyveryverylongactorname":      "averyveryverylon",
+	} {
+		if got := ActorSlug(in); got != want {
+			t.Errorf("ActorSlug(%q) = %q, want %q", in, got, want)
+		}
+	}
+}
@@ -0,0 +1,285 @@
+package jira
+
+import (
+	"encoding/csv"
+	"fmt"
+	"io"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/tracker"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// CSVRow is one issue read from a Jira CSV export ("Export > CSV (all
+// fields)"). Err is set when the row could not be converted; Issue is nil
+// in that case.
+type CSVRow struct {
+	Line  int
+	Key   string
+	Issue *types.Issue
+	Err   error
+}
+
+// issueLinkHeader matches Jira's link columns, e.g.
+// "Outward issue link (Blocks)" or "Inward issue link (Duplicate)".
+var issueLinkHeader = regexp.MustCompile(`(?i)^(inward|outward) issue link \((.+)\)$`)
+
+// jiraKeyPattern matches an issue key such as "PROJ-123".
+var jiraKeyPattern = regexp.MustCompile(`^[A-Z][A-Z0-9_]*-\d+$`)
+
+// ReadCSVExport converts a Jira CSV export into beads issues using mapper
+// for status, type, and priority. Multi-valued fields (Labels, issue links)
+// appear as repeated columns and are all collected.
+//
+// Issue links and parents are returned as dependencies between Jira keys;
+// the caller resolves the keys to local issues once the rows are imported.
+// Rows are reported by the line they start on.
+func ReadCSVExport(r io.Reader, mapper tracker.FieldMapper) ([]CSVRow, []tracker.DependencyInfo, error) {
+	cr := csv.NewReader(r)
+	cr.FieldsPerRecord = -1
+	header, err := cr.Read()
+	if err == io.EOF {
+		return nil, nil, nil
+	}
+	if err != nil {
+		return nil, nil, fmt.Errorf("read Jira CSV header: %w", err)
+	}
+
+	cols := make(map[string][]int)
+	for i, name := range header {
+		name = strings.ToLower(strings.TrimSpace(strings.TrimPrefix(name, "\ufeff")))
+		cols[name] = append(cols[name], i)
+	}
+	if len(cols["issue key"]) == 0 || len(cols["summary"]) == 0 {
+		return nil, nil, fmt.Errorf("not a Jira CSV export: missing \"Issue key\" or \"Summary\" column")
+	}
+
+	type pendingParent struct {
+		childKey string
+		parent   string // key, or numeric issue id resolved after all rows are read
+	}
+	var rows []CSVRow
+	var deps []tracker.DependencyInfo
+	var parents []pendingParent
+	keyByID := make(map[string]string)
+
+	for {
+		record, err := cr.Read()
+		if err == io.EOF {
+			break
+		}
+		if err != nil {
+			return nil, nil, fmt.Errorf("read Jira CSV: %w", err)
+		}
+		line, _ := cr.FieldPos(0)
+
+		all := func(name string) []string {
+			var values []string
+			for _, i := range cols[name] {
+				if i < len(record) {
+					if v := strings.TrimSpace(record[i]); v != "" {
+						values = append(values, v)
+					}
+				}
+			}
+			return values
+		}
+		get := func(name string) string {
+			if values := all(name); len(values) > 0 {
+				return values[0]
+			}
+			return ""
+		}
+
+		key := get("issue key")
+		if key == "" {
+			if strings.TrimSpace(strings.Join(record, "")) == "" {
+				continue
+			}
+			rows = append(rows, CSVRow{Line: line, Err: fmt.Errorf("missing issue key")})
+			continue
+		}
+		if id := get("issue id"); id != "" {
+			keyByID[id] = key
+		}
+
+		issue, err := csvRowToIssue(mapper, get, all)
+		if err != nil {
+			rows = append(rows, CSVRow{Line: line, Key: key, Err: err})
+			continue
+		}
+		rows = append(rows, CSVRow{Line: line, Key: key, Issue: issue})
+
+		if parent := get("parent"); parent != "" {
+			parents = append(parents, pendingParent{childKey: key, parent: parent})
+		} else if parent := get("parent id"); parent != "" {
+			parents = append(parents, pendingParent{childKey: key, parent: parent})
+		}
+
+		for i, name := range header {
+			m := issueLinkHeader.FindStringSubmatch(strings.TrimSpace(name))
+			if m == nil || i >= len(record) {
+				continue
+			}
+			other := strings.TrimSpace(record[i])
+			if other == "" {
+				continue
+			}
+			if dep, ok := linkToDependency(key, other, strings.EqualFold(m[1], "outward"), m[2]); ok {
+				deps = append(deps, dep)
+			}
+		}
+	}
+
+	for _, p := range parents {
+		parentKey := p.parent
+		if !jiraKeyPattern.MatchString(parentKey) {
+			parentKey = keyByID[p.parent]
+		}
+		if parentKey == "" {
+			continue
+		}
+		deps = append(deps, tracker.DependencyInfo{
+			FromExternalID: p.childKey,
+			ToExternalID:   parentKey,
+			Type:           string(types.DepParentChild),
+			Source:         tracker.DependencySourceParent,
+		})
+	}
+	return rows, dedupeDependencies(deps), nil
+}
+
+func csvRowToIssue(mapper tracker.FieldMapper, get func(string) string, all func(string) []string) (*types.Issue, error) {
+	summary := get("summary")
+	if summary == "" {
+		return nil, fmt.Errorf("missing summary")
+	}
+	issue := &types.Issue{
+		Title:       summary,
+		Description: get("description"),
+		Status:      mapper.StatusToBeads(get("status")),
+		IssueType:   mapper.TypeToBeads(get("issue type")),
+		Priority:    mapper.PriorityToBeads(get("priority")),
+		Owner:       get("assignee"),
+		Labels:      all("labels"),
+	}
+
+	for _, f := range []struct {
+		column string
+		dst    *time.Time
+	}{
+		{"created", &issue.CreatedAt},
+		{"updated", &issue.UpdatedAt},
+	} {
+		if v := get(f.column); v != "" {
+			t, err := ParseCSVTimestamp(v)
+			if err != nil {
+				return nil, fmt.Errorf("%s: %w", f.column, err)
+			}
+			*f.dst = t
+		}
+	}
+	if v := get("due date"); v != "" {
+		t, err := ParseCSVTimestamp(v)
+		if err != nil {
+			return nil, fmt.Errorf("due date: %w", err)
+		}
+		issue.DueAt = &t
+	}
+	if issue.Status == types.StatusClosed {
+		closed := issue.UpdatedAt
+		if v := get("resolved"); v != "" {
+			t, err := ParseCSVTimestamp(v)
+			if err != nil {
+				return nil, fmt.Errorf("resolved: %w", err)
+			}
+			closed = t
+		}
+		if closed.IsZero() {
+			closed = time.Now().UTC()
+		}
+		issue.ClosedAt = &closed
+		issue.CloseReason = get("resolution")
+	}
+	return issue, nil
+}
+
+// linkToDependency maps a Jira issue link seen on issue key to a beads
+// dependency. outward is true for "Outward issue link" columns, where the
+// link reads "key <verb> other" (e.g. key blocks other).
+func linkToDependency(key, other string, outward bool, linkType string) (tracker.DependencyInfo, bool) {
+	from, to := key, other
+	var depType types.DependencyType
+	switch strings.ToLower(linkType) {
+	case "blocks":
+		depType = types.DepBlocks
+		if outward {
+			// key blocks other: other depends on key.
+			from, to = other, key
+		}
+	case "duplicate", "duplicates":
+		depType = types.DepDuplicates
+		if !outward {
+			// other duplicates key.
+			from, to = other, key
+		}
+	default:
+		// Relates, Cloners, and custom link types carry no ordering.
+		depType = types.DepRelated
+		if !outward {
+			return tracker.DependencyInfo{}, false // recorded on the outward side
+		}
+	}
+	return tracker.DependencyInfo{
+		FromExternalID: from,
+		ToExternalID:   to,
+		Type:           string(depType),
+		Source:         tracker.DependencySourceRelation,
+	}, true
+}
+
+func dedupeDependencies(deps []tracker.DependencyInfo) []tracker.DependencyInfo {
+	seen := make(map[tracker.DependencyInfo]bool, len(deps))
+	out := deps[:0]
+	for _, d := range deps {
+		if seen[d] {
+			continue
+		}
+		seen[d] = true
+		out = append(out, d)
+	}
+	return out
+}
+
+// ParseCSVTimestamp parses the date formats found in Jira CSV exports: the
+// default "02/Jan/06 3:04 PM" display format (in local time), plus ISO 8601
+// and plain dates.
+func ParseCSVTimestamp(ts string) (time.Time, error) {
+	if t, err := ParseTimestamp(ts); err == nil {
+		return t.UTC(), nil
+	}
+	for _, layout := range []string{
+		"02/Jan/06 3:04 PM",
+		"2/Jan/06 3:04 PM",
+		"02/Jan/06 15:04",
+		"02/Jan/06",
+		"2006-01-02 15:04",
+		"2006-01-02",
+	} {
+		if t, err := time.ParseInLocation(layout, ts, time.Local); err == nil {
+			return t.UTC(), nil
+		}
+	}
+	return time.Time{}, fmt.Errorf("unrecognized timestamp format: %s", ts)
+}
+
+// BrowseURL returns the external_ref used for a Jira issue: the browse URL
+// when the Jira base URL is known, otherwise the bare key.
+func BrowseURL(jiraURL, key string) string {
+	if jiraURL == "" {
+		return key
+	}
+	return strings.TrimSuffix(jiraURL, "/") + "/browse/" + key
+}
@@ -0,0 +1,87 @@
+package jira
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/tracker"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+const sampleJiraCSV = `Summary,Issue key,Issue id,Issue Type,Status,Priority,Assignee,Created,Updated,Resolved,Resolution,Labels,Labels,Description,Parent id,Outward issue link (Blocks),Inward issue link (Duplicate),Outward issue link (Relates)
+Login fails,PROJ-1,10001,Bug,Done,High,Alice,15/Jan/24 10:30 AM,16/Jan/24 9:00 AM,16/Jan/24 9:00 AM,Fixed,auth,web,"Steps:
+1. log in",,PROJ-2,PROJ-9,
+Retry logic,PROJ-2,10002,Sub-task,In Progress,Lowest,,2024-01-15T11:00:00.000+0000,,,,,,,10001,,,PROJ-1
+,,,,,,,,,,,,,,,,,
+,PROJ-3,10003,Task,To Do,Medium,,,,,,,,,,,,
+`
+
+func TestReadCSVExport(t *testing.T) {
+	mapper, err := NewFieldMapper(map[string]string{"jira.status_map.in_progress": "In Progress"})
+	if err != nil {
+		t.Fatalf("NewFieldMapper: %v", err)
+	}
+	rows, deps, err := ReadCSVExport(strings.NewReader(sampleJiraCSV), mapper)
+	if err != nil {
+		t.Fatalf("ReadCSVExport: %v", err)
+	}
+	if len(rows) != 3 {
+		t.Fatalf("got %d rows, want 3 (blank row skipped): %+v", len(rows), rows)
+	}
+
+	bug := rows[0]
+	if bug.Err != nil || bug.Key != "PROJ-1" || bug.Line != 2 {
+		t.Fatalf("row 0 = %+v", bug)
+	}
+	if bug.Issue.IssueType != types.TypeBug || bug.Issue.Status != types.StatusClosed || bug.Issue.Priority != 1 {
+		t.Errorf("mapped type/status/priority = %s/%s/%d", bug.Issue.IssueType, bug.Issue.Status, bug.Issue.Priority)
+	}
+	if bug.Issue.Description != "Steps:\n1. log in" {
+		t.Errorf("description = %q", bug.Issue.Description)
+	}
+	if strings.Join(bug.Issue.Labels, ",") != "auth,web" {
+		t.Errorf("labels = %v", bug.Issue.Labels)
+	}
+	if bug.Issue.ClosedAt == nil || bug.Issue.CloseReason != "Fixed" {
+		t.Errorf("closed_at = %v, close_reason = %q", bug.Issue.ClosedAt, bug.Issue.CloseReason)
+	}
+
+	sub := rows[1]
+	if sub.Line != 4 || sub.Issue.Status != types.StatusInProgress || sub.Issue.Priority != 4 {
+		t.Errorf("row 1 = line %d, status %s, priority %d", sub.Line, sub.Issue.Status, sub.Issue.Priority)
+	}
+
+	if rows[2].Err == nil || rows[2].Key != "PROJ-3" {
+		t.Errorf("row without summary should fail, got %+v", rows[2])
+	}
+
+	want := map[tracker.DependencyInfo]bool{
+		{FromExternalID: "PROJ-2", ToExternalID: "PROJ-1", Type: "blocks", Source: tracker.DependencySourceRelation}:     true,
+		{FromExternalID: "PROJ-9", ToExternalID: "PROJ-1", Type: "duplicates", Source: tracker.DependencySourceRelation}: true,
+		{FromExternalID: "PROJ-2", ToExternalID: "PROJ-1", Type: "related", Source: tracker.DependencySourceRelation}:    true,
+		{FromExternalID: "PROJ-2", ToExternalID: "PROJ-1", Type: "parent-child", Source: tracker.DependencySourceParent}: true,
+	}
+	if len(deps) != len(want) {
+		t.Fatalf("got %d deps, want %d: %+v", len(deps), len(want), deps)
+	}
+	for _, d := range deps {
+		if !want[d] {
+			t.Errorf("unexpected dependency %+v", d)
+		}
+	}
+}
+
+func TestReadCSVExportRejectsNonJiraCSV(t *testing.T) {
+	if _, _, err := ReadCSVExport(strings.NewReader("id,title\nbd-1,x\n"), &jiraFieldMapper{}); err == nil {
+		t.Fatal("expected an error for a CSV without Jira columns")
+	}
+}
+
+func TestBrowseURL(t *testing.T) {
+	if got := BrowseURL("https://acme.atlassian.net/", "PROJ-1"); got != "https://acme.atlassian.net/browse/PROJ-1" {
+		t.Errorf("BrowseURL = %q", got)
+	}
+	if got := BrowseURL("", "PROJ-1"); got != "PROJ-1" {
+		t.Errorf("BrowseURL without base = %q", got)
+	}
+}
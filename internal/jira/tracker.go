@@ -92,84 +92,102 @@ func (t *Tracker) Init(ctx context.Context, store storage.Storage) error {
 	t.apiVersion = apiVersion
 	t.client.APIVersion = apiVersion
 
-	// Load optional custom status map from all jira.status_map.* config keys.
-	// Using GetAllConfig supports arbitrary (including custom) beads status names.
 	if allConfig, err := t.store.GetAllConfig(ctx); err == nil {
-		const statusPrefix = "jira.status_map."
-		statusMap := make(map[string]string)
-		for key, val := range allConfig {
-			if strings.HasPrefix(key, statusPrefix) && val != "" {
-				statusMap[strings.TrimPrefix(key, statusPrefix)] = val
-			}
+		if err := t.loadFieldMaps(allConfig); err != nil {
+			return err
 		}
-		if len(statusMap) > 0 {
-			t.statusMap = statusMap
+	}
+
+	return nil
+}
+
+// loadFieldMaps loads the optional jira.status_map.*, jira.type_map.*,
+// jira.priority_map.*, and jira.custom_fields.* config keys. Scanning all
+// config supports arbitrary (including custom) beads status names.
+func (t *Tracker) loadFieldMaps(allConfig map[string]string) error {
+	const statusPrefix = "jira.status_map."
+	statusMap := make(map[string]string)
+	for key, val := range allConfig {
+		if strings.HasPrefix(key, statusPrefix) && val != "" {
+			statusMap[strings.TrimPrefix(key, statusPrefix)] = val
 		}
+	}
+	if len(statusMap) > 0 {
+		t.statusMap = statusMap
+	}
 
-		const typePrefix = "jira.type_map."
-		typeMap := make(map[string]string)
-		for key, val := range allConfig {
-			if strings.HasPrefix(key, typePrefix) && val != "" {
-				typeMap[strings.TrimPrefix(key, typePrefix)] = val
-			}
+	const typePrefix = "jira.type_map."
+	typeMap := make(map[string]string)
+	for key, val := range allConfig {
+		if strings.HasPrefix(key, typePrefix) && val != "" {
+			typeMap[strings.TrimPrefix(key, typePrefix)] = val
 		}
-		if len(typeMap) > 0 {
-			t.typeMap = typeMap
+	}
+	if len(typeMap) > 0 {
+		t.typeMap = typeMap
+	}
+
+	const priorityPrefix = "jira.priority_map."
+	priorityMap := make(map[string]string)
+	for key, val := range allConfig {
+		if strings.HasPrefix(key, priorityPrefix) && val != "" {
+			priorityMap[strings.TrimPrefix(key, priorityPrefix)] = val
 		}
+	}
+	if len(priorityMap) > 0 {
+		t.priorityMap = priorityMap
+	}
 
-		const priorityPrefix = "jira.priority_map."
-		priorityMap := make(map[string]string)
-		for key, val := range allConfig {
-			if strings.HasPrefix(key, priorityPrefix) && val != "" {
-				priorityMap[strings.TrimPrefix(key, priorityPrefix)] = val
-			}
+	const customFieldPrefix = "jira.custom_fields."
+	customFields := make(map[string]interface{})
+	typeCustomFields := make(map[string]map[string]interface{})
+	for key, val := range allConfig {
+		if !strings.HasPrefix(key, customFieldPrefix) || strings.TrimSpace(val) == "" {
+			continue
 		}
-		if len(priorityMap) > 0 {
-			t.priorityMap = priorityMap
+
+		suffix := strings.TrimPrefix(key, customFieldPrefix)
+		if suffix == "" {
+			continue
 		}
 
-		const customFieldPrefix = "jira.custom_fields."
-		customFields := make(map[string]interface{})
-		typeCustomFields := make(map[string]map[string]interface{})
-		for key, val := range allConfig {
-			if !strings.HasPrefix(key, customFieldPrefix) || strings.TrimSpace(val) == "" {
-				continue
-			}
+		parsed, err := parseJiraCustomFieldValue(val)
+		if err != nil {
+			return fmt.Errorf("parse %s: %w", key, err)
+		}
 
-			suffix := strings.TrimPrefix(key, customFieldPrefix)
-			if suffix == "" {
+		parts := strings.SplitN(suffix, ".", 2)
+		if len(parts) == 2 {
+			if parts[0] == "" || parts[1] == "" {
 				continue
 			}
-
-			parsed, err := parseJiraCustomFieldValue(val)
-			if err != nil {
-				return fmt.Errorf("parse %s: %w", key, err)
+			if typeCustomFields[parts[0]] == nil {
+				typeCustomFields[parts[0]] = make(map[string]interface{})
 			}
-
-			parts := strings.SplitN(suffix, ".", 2)
-			if len(parts) == 2 {
-				if parts[0] == "" || parts[1] == "" {
-					continue
-				}
-				if typeCustomFields[parts[0]] == nil {
-					typeCustomFields[parts[0]] = make(map[string]interface{})
-				}
-				typeCustomFields[parts[0]][parts[1]] = parsed
-				continue
-			}
-			customFields[suffix] = parsed
-		}
-		if len(customFields) > 0 {
-			t.customFields = customFields
-		}
-		if len(typeCustomFields) > 0 {
-			t.typeCustomFields = typeCustomFields
+			typeCustomFields[parts[0]][parts[1]] = parsed
+			continue
 		}
+		customFields[suffix] = parsed
+	}
+	if len(customFields) > 0 {
+		t.customFields = customFields
+	}
+	if len(typeCustomFields) > 0 {
+		t.typeCustomFields = typeCustomFields
 	}
-
 	return nil
 }
 
+// NewFieldMapper builds the Jira field mapper from config alone, without
+// connecting to Jira, for file-based imports of Jira exports.
+func NewFieldMapper(allConfig map[string]string) (tracker.FieldMapper, error) {
+	t := &Tracker{}
+	if err := t.loadFieldMaps(allConfig); err != nil {
+		return nil, err
+	}
+	return t.FieldMapper(), nil
+}
+
 func (t *Tracker) Validate() error {
 	if t.client == nil {
 		return fmt.Errorf("Jira tracker not initialized")
@@ -377,6 +377,48 @@ func ResolveStateIDForBeadsStatus(cache *StateCache, status types.Status, config
 	return "", fmt.Errorf("linear.state_map has no configured Linear state for beads status %q", status)
 }
 
+// SuggestStateMap proposes explicit linear.state_map.* entries for a team's
+// workflow states, keyed by lowercase state name. It picks one state per
+// beads status so push resolves unambiguously: the first unstarted state
+// (or backlog) for open, the first started state for in_progress, the first
+// completed state for closed, and a state named "Blocked" if the team has
+// one. Pull keeps using the type-based defaults for every other state.
+func SuggestStateMap(states []State) map[string]string {
+	isBlocked := func(state State) bool {
+		return strings.EqualFold(strings.TrimSpace(state.Name), "blocked")
+	}
+	firstOfType := func(stateType string) *State {
+		for i := range states {
+			if strings.EqualFold(states[i].Type, stateType) && !isBlocked(states[i]) {
+				return &states[i]
+			}
+		}
+		return nil
+	}
+
+	suggested := make(map[string]string)
+	add := func(state *State, status types.Status) {
+		if state != nil {
+			suggested[strings.ToLower(strings.TrimSpace(state.Name))] = string(status)
+		}
+	}
+
+	open := firstOfType("unstarted")
+	if open == nil {
+		open = firstOfType("backlog")
+	}
+	add(open, types.StatusOpen)
+	add(firstOfType("started"), types.StatusInProgress)
+	add(firstOfType("completed"), types.StatusClosed)
+	for i := range states {
+		if isBlocked(states[i]) {
+			add(&states[i], types.StatusBlocked)
+			break
+		}
+	}
+	return suggested
+}
+
 // ParseBeadsStatus converts a status string to types.Status.
 func ParseBeadsStatus(s string) types.Status {
 	switch strings.ToLower(s) {
@@ -774,6 +774,48 @@ func TestResolveStateIDForBeadsStatusPrefersExplicitStateName(t *testing.T) {
 	}
 }
 
+func TestSuggestStateMapPicksOneStatePerStatus(t *testing.T) {
+	states := []State{
+		{ID: "s1", Name: "Backlog", Type: "backlog"},
+		{ID: "s2", Name: "Todo", Type: "unstarted"},
+		{ID: "s3", Name: "Triage", Type: "unstarted"},
+		{ID: "s4", Name: "Blocked", Type: "started"},
+		{ID: "s5", Name: "In Progress", Type: "started"},
+		{ID: "s6", Name: "Done", Type: "completed"},
+		{ID: "s7", Name: "Canceled", Type: "canceled"},
+	}
+	got := SuggestStateMap(states)
+	want := map[string]string{
+		"todo":        "open",
+		"in progress": "in_progress",
+		"blocked":     "blocked",
+		"done":        "closed",
+	}
+	if len(got) != len(want) {
+		t.Fatalf("SuggestStateMap() = %v, want %v", got, want)
+	}
+	for name, status := range want {
+		if got[name] != status {
+			t.Errorf("SuggestStateMap()[%q] = %q, want %q", name, got[name], status)
+		}
+	}
+
+	config := DefaultMappingConfig()
+	config.ExplicitStateMap = got
+	cache := &StateCache{States: states}
+	for status, wantID := range map[types.Status]string{
+		types.StatusOpen:       "s2",
+		types.StatusInProgress: "s5",
+		types.StatusBlocked:    "s4",
+		types.StatusClosed:     "s6",
+	} {
+		id, err := ResolveStateIDForBeadsStatus(cache, status, config)
+		if err != nil || id != wantID {
+			t.Errorf("ResolveStateIDForBeadsStatus(%s) = %q, %v; want %q", status, id, err, wantID)
+		}
+	}
+}
+
 func TestPushFieldsEqualIgnoresLocalOnlyDifferences(t *testing.T) {
 	config := DefaultMappingConfig()
 	local := &types.Issue{
@@ -29,6 +29,12 @@ type QueryResult struct {
 	// RequiresPredicate indicates if in-memory filtering is needed.
 	// True when the query contains OR or complex NOT expressions.
 	RequiresPredicate bool
+
+	// Text is the query's free-text term when it has exactly one and no
+	// predicate is needed. Pass it as the SearchIssues query string, which
+	// matches it against title and ID in SQL. Otherwise free-text terms are
+	// matched by Predicate.
+	Text string
 }
 
 // Evaluator converts a query AST to an IssueFilter and/or predicate function.
@@ -43,16 +49,30 @@ func NewEvaluator(now time.Time) *Evaluator {
 
 // Evaluate evaluates the query AST and returns a QueryResult.
 func (e *Evaluator) Evaluate(node Node) (*QueryResult, error) {
+	return e.EvaluateWith(node, types.IssueFilter{})
+}
+
+// EvaluateWith evaluates the query AST on top of base, a filter already
+// built from other options (such as 'bd list' flags). The query narrows
+// base: list-valued filters are added to, and where both set the same
+// single-valued filter the query's value is used.
+func (e *Evaluator) EvaluateWith(node Node, base types.IssueFilter) (*QueryResult, error) {
 	result := &QueryResult{
-		Filter: types.IssueFilter{},
+		Filter: base,
 	}
 
-	// Check if we can use Filter-only mode (simple AND chains)
+	// Check if we can use Filter-only mode (simple AND chains with at most
+	// one free-text term, which goes to SQL as the search string)
 	if e.canUseFilterOnly(node) {
-		if err := e.buildFilter(node, &result.Filter); err != nil {
-			return nil, err
+		if texts := collectTextTerms(node); len(texts) <= 1 {
+			if err := e.buildFilter(node, &result.Filter); err != nil {
+				return nil, err
+			}
+			if len(texts) == 1 {
+				result.Text = texts[0]
+			}
+			return result, nil
 		}
-		return result, nil
 	}
 
 	// Complex query: build predicate and extract base filters
@@ -76,7 +96,7 @@ func (e *Evaluator) Evaluate(node Node) (*QueryResult, error) {
 // - NOT with certain fields
 func (e *Evaluator) canUseFilterOnly(node Node) bool {
 	switch n := node.(type) {
-	case *ComparisonNode:
+	case *ComparisonNode, *TextNode:
 		return true
 	case *AndNode:
 		return e.canUseFilterOnly(n.Left) && e.canUseFilterOnly(n.Right)
@@ -88,6 +108,8 @@ func (e *Evaluator) canUseFilterOnly(node Node) bool {
 				return comp.Op == OpEquals
 			case "type":
 				return comp.Op == OpEquals
+			case "label", "labels":
+				return comp.Op == OpEquals && !isNoneValue(comp.Value)
 			default:
 				return false
 			}
@@ -128,11 +150,30 @@ func (e *Evaluator) collectOrLabels(node Node) []string {
 	}
 }
 
+// collectTextTerms returns the free-text terms of an AND chain.
+func collectTextTerms(node Node) []string {
+	switch n := node.(type) {
+	case *TextNode:
+		return []string{n.Text}
+	case *AndNode:
+		return append(collectTextTerms(n.Left), collectTextTerms(n.Right)...)
+	default:
+		return nil
+	}
+}
+
+func isNoneValue(value string) bool {
+	return value == "" || strings.EqualFold(value, "none") || strings.EqualFold(value, "null")
+}
+
 // buildFilter populates the IssueFilter from a filter-compatible AST.
+// Free-text terms are left to the caller (see QueryResult.Text).
 func (e *Evaluator) buildFilter(node Node, filter *types.IssueFilter) error {
 	switch n := node.(type) {
 	case *ComparisonNode:
 		return e.applyComparison(n, filter)
+	case *TextNode:
+		return nil
 	case *AndNode:
 		if err := e.buildFilter(n.Left, filter); err != nil {
 			return err
@@ -186,6 +227,8 @@ func (e *Evaluator) applyComparison(comp *ComparisonNode, filter *types.IssueFil
 		return e.applyIDFilter(comp, filter)
 	case "spec", "spec_id":
 		return e.applySpecFilter(comp, filter)
+	case "severity":
+		return e.applySeverityFilter(comp, filter)
 	case "parent":
 		return e.applyParentFilter(comp, filter)
 	case "pinned":
@@ -450,6 +493,18 @@ func (e *Evaluator) applySpecFilter(comp *ComparisonNode, filter *types.IssueFil
 	return nil
 }
 
+func (e *Evaluator) applySeverityFilter(comp *ComparisonNode, filter *types.IssueFilter) error {
+	if comp.Op != OpEquals {
+		return fmt.Errorf("severity only supports = operator")
+	}
+	severity, err := types.ParseSeverity(comp.Value)
+	if err != nil {
+		return err
+	}
+	filter.Severities = []types.Severity{severity}
+	return nil
+}
+
 func (e *Evaluator) applyParentFilter(comp *ComparisonNode, filter *types.IssueFilter) error {
 	if comp.Op != OpEquals {
 		return fmt.Errorf("parent only supports = operator")
@@ -601,6 +656,12 @@ func (e *Evaluator) applyNot(not *NotNode, filter *types.IssueFilter) error {
 		issueType := types.IssueType(strings.ToLower(comp.Value))
 		filter.ExcludeTypes = append(filter.ExcludeTypes, issueType)
 		return nil
+	case "label", "labels":
+		if comp.Op != OpEquals || isNoneValue(comp.Value) {
+			return fmt.Errorf("NOT label only supports = with a label name")
+		}
+		filter.ExcludeLabels = append(filter.ExcludeLabels, comp.Value)
+		return nil
 	default:
 		return fmt.Errorf("NOT not supported for field %s in filter mode", comp.Field)
 	}
@@ -649,6 +710,8 @@ func (e *Evaluator) buildPredicate(node Node) (func(*types.Issue) bool, error) {
 	switch n := node.(type) {
 	case *ComparisonNode:
 		return e.buildComparisonPredicate(n)
+	case *TextNode:
+		return e.buildTextPredicate(n), nil
 	case *AndNode:
 		left, err := e.buildPredicate(n.Left)
 		if err != nil {
@@ -686,6 +749,15 @@ func (e *Evaluator) buildPredicate(node Node) (func(*types.Issue) bool, error) {
 	}
 }
 
+// buildTextPredicate matches a free-text term the way the SearchIssues
+// query string does: a case-insensitive substring of the title or ID.
+func (e *Evaluator) buildTextPredicate(text *TextNode) func(*types.Issue) bool {
+	needle := strings.ToLower(text.Text)
+	return func(i *types.Issue) bool {
+		return strings.Contains(strings.ToLower(i.Title), needle) || strings.Contains(strings.ToLower(i.ID), needle)
+	}
+}
+
 // buildComparisonPredicate builds a predicate for a single comparison.
 func (e *Evaluator) buildComparisonPredicate(comp *ComparisonNode) (func(*types.Issue) bool, error) {
 	switch comp.Field {
@@ -719,6 +791,8 @@ func (e *Evaluator) buildComparisonPredicate(comp *ComparisonNode) (func(*types.
 		return e.buildIDPredicate(comp)
 	case "spec", "spec_id":
 		return e.buildSpecPredicate(comp)
+	case "severity":
+		return e.buildSeverityPredicate(comp)
 	case "pinned":
 		return e.buildBoolPredicate(comp, func(i *types.Issue) bool { return i.Pinned })
 	case "ephemeral":
@@ -1021,6 +1095,21 @@ func (e *Evaluator) buildSpecPredicate(comp *ComparisonNode) (func(*types.Issue)
 	}
 }
 
+func (e *Evaluator) buildSeverityPredicate(comp *ComparisonNode) (func(*types.Issue) bool, error) {
+	severity, err := types.ParseSeverity(comp.Value)
+	if err != nil {
+		return nil, err
+	}
+	switch comp.Op {
+	case OpEquals:
+		return func(i *types.Issue) bool { return i.Severity == severity }, nil
+	case OpNotEquals:
+		return func(i *types.Issue) bool { return i.Severity != severity }, nil
+	default:
+		return nil, fmt.Errorf("severity does not support %s operator", comp.Op.String())
+	}
+}
+
 func (e *Evaluator) buildBoolPredicate(comp *ComparisonNode, getter func(*types.Issue) bool) (func(*types.Issue) bool, error) {
 	val := strings.ToLower(comp.Value)
 	var boolVal bool
@@ -2,15 +2,20 @@
 //
 // The query language supports:
 //   - Field comparisons: status=open, priority>1, updated>7d
-//   - Boolean operators: AND, OR, NOT
+//   - Field matchers: status:open, label:infra, created:>7d (":" is "=",
+//     or introduces an operator)
+//   - Boolean operators: AND, OR, NOT; terms side by side are ANDed
+//   - Negation with a leading "-": -label:wontfix
 //   - Parentheses for grouping: (status=open OR status=blocked) AND priority<2
 //   - Date-relative expressions: updated>7d, created<30d
+//   - Free-text terms matched against title and ID: login "null pointer"
 //
 // Example queries:
 //   - status=open AND priority>1
 //   - (status=open OR status=blocked) AND updated>7d
 //   - NOT status=closed
 //   - type=bug AND priority=0
+//   - status:open p<=1 label:infra -label:wontfix
 package query
 
 import (
@@ -36,7 +41,7 @@ const (
 	TokenGreaterEq           // >=
 	TokenAnd                 // AND
 	TokenOr                  // OR
-	TokenNot                 // NOT
+	TokenNot                 // NOT, or a leading -
 	TokenLParen              // (
 	TokenRParen              // )
 	TokenComma               // , (for lists)
@@ -180,6 +185,11 @@ func (l *Lexer) NextToken() (Token, error) {
 	case '"', '\'':
 		return l.readString(r, startPos)
 	default:
+		// A '-' directly before a term negates it (-label:wontfix); before
+		// a digit it is a sign (-7d).
+		if r == '-' && (isIdentStart(l.peek()) || l.peek() == '(' || l.peek() == '"' || l.peek() == '\'') {
+			return Token{Type: TokenNot, Value: "-", Pos: startPos}, nil
+		}
 		if unicode.IsDigit(r) || r == '-' || r == '+' {
 			l.backup()
 			return l.readNumberOrDuration(startPos)
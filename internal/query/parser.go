@@ -2,6 +2,7 @@ package query
 
 import (
 	"fmt"
+	"strconv"
 	"strings"
 )
 
@@ -56,6 +57,16 @@ func (n *ComparisonNode) String() string {
 	return fmt.Sprintf("%s%s%s", n.Field, n.Op.String(), n.Value)
 }
 
+// TextNode represents a free-text term, matched against title and ID.
+type TextNode struct {
+	Text string
+}
+
+func (n *TextNode) node() {}
+func (n *TextNode) String() string {
+	return strconv.Quote(n.Text)
+}
+
 // AndNode represents a logical AND operation.
 type AndNode struct {
 	Left  Node
@@ -171,16 +182,19 @@ func (p *Parser) parseOr() (Node, error) {
 	return left, nil
 }
 
-// parseAnd parses AND expressions.
+// parseAnd parses AND expressions. Terms written side by side with no
+// operator between them are ANDed too.
 func (p *Parser) parseAnd() (Node, error) {
 	left, err := p.parseNot()
 	if err != nil {
 		return nil, err
 	}
 
-	for p.current.Type == TokenAnd {
-		if err := p.advance(); err != nil {
-			return nil, err
+	for p.current.Type == TokenAnd || p.startsTerm() {
+		if p.current.Type == TokenAnd {
+			if err := p.advance(); err != nil {
+				return nil, err
+			}
 		}
 		right, err := p.parseNot()
 		if err != nil {
@@ -192,6 +206,15 @@ func (p *Parser) parseAnd() (Node, error) {
 	return left, nil
 }
 
+// startsTerm reports whether the current token can begin a term.
+func (p *Parser) startsTerm() bool {
+	switch p.current.Type {
+	case TokenIdent, TokenString, TokenNumber, TokenNot, TokenLParen:
+		return true
+	}
+	return false
+}
+
 // parseNot parses NOT expressions.
 func (p *Parser) parseNot() (Node, error) {
 	if p.current.Type == TokenNot {
@@ -230,69 +253,101 @@ func (p *Parser) parsePrimary() (Node, error) {
 	return p.parseComparison()
 }
 
-// parseComparison parses a field comparison.
+// parseComparison parses a field comparison (status=open), a field
+// matcher (status:open, created:>7d), or a free-text term.
 func (p *Parser) parseComparison() (Node, error) {
-	if p.current.Type != TokenIdent {
+	switch p.current.Type {
+	case TokenString, TokenNumber:
+		text := p.current.Value
+		if err := p.advance(); err != nil {
+			return nil, err
+		}
+		return &TextNode{Text: text}, nil
+	case TokenIdent:
+	default:
 		return nil, fmt.Errorf("expected field name at position %d, got %s", p.current.Pos, p.current.Type.String())
 	}
 
-	field := strings.ToLower(p.current.Value)
+	ident := p.current.Value
 	if err := p.advance(); err != nil {
 		return nil, err
 	}
 
-	var op ComparisonOp
-	switch p.current.Type {
+	// field:value and field:<op>value. The lexer keeps colons inside
+	// identifiers (label=gt:merge-request), so split at the first one when
+	// what precedes it names a field; otherwise the whole word is text.
+	if name, rest, ok := strings.Cut(ident, ":"); ok && isQueryField(name) {
+		field := normalizeField(name)
+		if rest != "" {
+			return &ComparisonNode{Field: field, Op: OpEquals, Value: rest, ValueType: TokenIdent}, nil
+		}
+		if _, isOp := comparisonOp(p.current.Type); !isOp {
+			return p.parseValue(field, OpEquals)
+		}
+		return p.parseOperatorAndValue(field)
+	}
+
+	if _, isOp := comparisonOp(p.current.Type); !isOp {
+		// A bare field name is almost certainly a comparison missing its
+		// operator; quote it to search for the word itself.
+		if isQueryField(ident) {
+			return nil, fmt.Errorf("expected comparison operator after field %q at position %d, got %s (quote it to search for the word)", ident, p.current.Pos, p.current.Type.String())
+		}
+		return &TextNode{Text: ident}, nil
+	}
+	return p.parseOperatorAndValue(normalizeField(ident))
+}
+
+// comparisonOp returns the operator a token stands for.
+func comparisonOp(t TokenType) (ComparisonOp, bool) {
+	switch t {
 	case TokenEquals:
-		op = OpEquals
+		return OpEquals, true
 	case TokenNotEquals:
-		op = OpNotEquals
+		return OpNotEquals, true
 	case TokenLess:
-		op = OpLess
+		return OpLess, true
 	case TokenLessEq:
-		op = OpLessEq
+		return OpLessEq, true
 	case TokenGreater:
-		op = OpGreater
+		return OpGreater, true
 	case TokenGreaterEq:
-		op = OpGreaterEq
-	default:
-		return nil, fmt.Errorf("expected comparison operator at position %d, got %s", p.current.Pos, p.current.Type.String())
+		return OpGreaterEq, true
 	}
+	return 0, false
+}
 
+// parseOperatorAndValue parses the operator and value of a comparison on
+// field.
+func (p *Parser) parseOperatorAndValue(field string) (Node, error) {
+	op, ok := comparisonOp(p.current.Type)
+	if !ok {
+		return nil, fmt.Errorf("expected comparison operator at position %d, got %s", p.current.Pos, p.current.Type.String())
+	}
 	if err := p.advance(); err != nil {
 		return nil, err
 	}
+	return p.parseValue(field, op)
+}
 
+// parseValue parses the value of a comparison on field.
+func (p *Parser) parseValue(field string, op ComparisonOp) (Node, error) {
 	// Value can be identifier, string, number, or duration
-	var value string
-	var valueType TokenType
 	switch p.current.Type {
-	case TokenIdent:
-		value = p.current.Value
-		valueType = TokenIdent
-	case TokenString:
-		value = p.current.Value
-		valueType = TokenString
-	case TokenNumber:
-		value = p.current.Value
-		valueType = TokenNumber
-	case TokenDuration:
-		value = p.current.Value
-		valueType = TokenDuration
+	case TokenIdent, TokenString, TokenNumber, TokenDuration:
 	default:
 		return nil, fmt.Errorf("expected value at position %d, got %s", p.current.Pos, p.current.Type.String())
 	}
-
+	node := &ComparisonNode{
+		Field:     field,
+		Op:        op,
+		Value:     p.current.Value,
+		ValueType: p.current.Type,
+	}
 	if err := p.advance(); err != nil {
 		return nil, err
 	}
-
-	return &ComparisonNode{
-		Field:     field,
-		Op:        op,
-		Value:     value,
-		ValueType: valueType,
-	}, nil
+	return node, nil
 }
 
 // Parse is a convenience function that parses a query string.
@@ -301,6 +356,26 @@ func Parse(input string) (Node, error) {
 	return p.Parse()
 }
 
+// fieldAliases maps shorthand field names to the field they stand for.
+var fieldAliases = map[string]string{
+	"p": "priority",
+}
+
+// normalizeField lowercases a field name and expands shorthand aliases.
+func normalizeField(name string) string {
+	name = strings.ToLower(name)
+	if alias, ok := fieldAliases[name]; ok {
+		return alias
+	}
+	return name
+}
+
+// isQueryField reports whether name is a field the query language knows.
+func isQueryField(name string) bool {
+	field := normalizeField(name)
+	return KnownFields[field] || strings.HasPrefix(field, "metadata.")
+}
+
 // KnownFields lists fields that can be queried.
 var KnownFields = map[string]bool{
 	// Core fields
@@ -321,6 +396,8 @@ var KnownFields = map[string]bool{
 	"created_at": true, // alias
 	"updated_at": true, // alias
 	"closed_at":  true, // alias
+	"started":    true,
+	"started_at": true, // alias
 
 	// Labels
 	"label":  true,
@@ -334,6 +411,7 @@ var KnownFields = map[string]bool{
 	// Other
 	"spec":             true,
 	"spec_id":          true, // alias
+	"severity":         true,
 	"parent":           true,
 	"mol_type":         true,
 	"notes":            true,
@@ -207,6 +207,31 @@ func TestParser(t *testing.T) {
 			input:    "NOT (status=closed OR status=deferred)",
 			expected: "NOT (status=closed OR status=deferred)",
 		},
+		{
+			name:     "field matchers with implicit AND and dash negation",
+			input:    "status:open p<=1 label:infra -label:wontfix",
+			expected: "(((status=open AND priority<=1) AND label=infra) AND NOT label=wontfix)",
+		},
+		{
+			name:     "field matcher with operator",
+			input:    "created:>7d",
+			expected: "created>7d",
+		},
+		{
+			name:     "field matcher keeps later colons in the value",
+			input:    "label:gt:merge-request",
+			expected: "label=gt:merge-request",
+		},
+		{
+			name:     "quoted field matcher value",
+			input:    `title:"login page"`,
+			expected: "title=login page",
+		},
+		{
+			name:     "free text",
+			input:    `type:bug crash "login page"`,
+			expected: `((type=bug AND "crash") AND "login page")`,
+		},
 	}
 
 	for _, tt := range tests {
@@ -292,6 +317,13 @@ func TestEvaluatorSimpleQueries(t *testing.T) {
 				return f.PriorityMax != nil && *f.PriorityMax == 2
 			},
 		},
+		{
+			name:  "severity equals",
+			query: "severity=s1",
+			expectFilter: func(f *types.IssueFilter) bool {
+				return len(f.Severities) == 1 && f.Severities[0] == types.SeverityS1
+			},
+		},
 		{
 			name:  "type equals",
 			query: "type=bug",
@@ -482,6 +514,69 @@ func TestEvaluatorComplexQueries(t *testing.T) {
 	}
 }
 
+func TestEvaluatorFieldMatchers(t *testing.T) {
+	now := time.Date(2025, 2, 4, 12, 0, 0, 0, time.UTC)
+
+	result, err := EvaluateAt("status:open p<=1 label:infra -label:wontfix crash", now)
+	if err != nil {
+		t.Fatalf("EvaluateAt() error = %v", err)
+	}
+	if result.RequiresPredicate {
+		t.Fatal("expected filter-only evaluation")
+	}
+	f := result.Filter
+	if f.Status == nil || *f.Status != types.StatusOpen || f.PriorityMax == nil || *f.PriorityMax != 1 {
+		t.Errorf("Filter = %+v, want status=open, priority<=1", f)
+	}
+	if len(f.Labels) != 1 || f.Labels[0] != "infra" || len(f.ExcludeLabels) != 1 || f.ExcludeLabels[0] != "wontfix" {
+		t.Errorf("Labels = %v, ExcludeLabels = %v", f.Labels, f.ExcludeLabels)
+	}
+	if result.Text != "crash" {
+		t.Errorf("Text = %q, want %q", result.Text, "crash")
+	}
+
+	// Several free-text terms are matched in memory.
+	result, err = EvaluateAt("login crash", now)
+	if err != nil {
+		t.Fatalf("EvaluateAt() error = %v", err)
+	}
+	if !result.RequiresPredicate || result.Text != "" {
+		t.Fatalf("RequiresPredicate = %v, Text = %q; want predicate", result.RequiresPredicate, result.Text)
+	}
+	if !result.Predicate(&types.Issue{ID: "bd-1", Title: "Crash on Login"}) {
+		t.Error("predicate should match a title containing both terms")
+	}
+	if result.Predicate(&types.Issue{ID: "bd-2", Title: "Crash on logout"}) {
+		t.Error("predicate should not match a title missing a term")
+	}
+}
+
+func TestEvaluateWithBase(t *testing.T) {
+	node, err := Parse("label:infra type:bug")
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+	task := types.TypeTask
+	base := types.IssueFilter{Labels: []string{"backend"}, IssueType: &task, Limit: 20}
+	result, err := NewEvaluator(time.Now()).EvaluateWith(node, base)
+	if err != nil {
+		t.Fatalf("EvaluateWith() error = %v", err)
+	}
+	f := result.Filter
+	if len(f.Labels) != 2 || f.Labels[0] != "backend" || f.Labels[1] != "infra" {
+		t.Errorf("Labels = %v, want [backend infra]", f.Labels)
+	}
+	if f.IssueType == nil || *f.IssueType != types.TypeBug {
+		t.Errorf("IssueType = %v, want bug (query wins)", f.IssueType)
+	}
+	if f.Limit != 20 {
+		t.Errorf("Limit = %d, want 20 from base", f.Limit)
+	}
+	if base.Labels[0] != "backend" || len(base.Labels) != 1 {
+		t.Errorf("base Labels modified: %v", base.Labels)
+	}
+}
+
 func TestPredicateEvaluation(t *testing.T) {
 	now := time.Date(2025, 2, 4, 12, 0, 0, 0, time.UTC)
 
@@ -490,6 +585,7 @@ func TestPredicateEvaluation(t *testing.T) {
 		Status:    types.StatusOpen,
 		Priority:  1,
 		IssueType: types.TypeBug,
+		Severity:  types.SeverityS1,
 		Labels:    []string{"urgent", "frontend"},
 		CreatedAt: now.AddDate(0, 0, -5),
 		UpdatedAt: now.AddDate(0, 0, -1),
@@ -537,6 +633,12 @@ func TestPredicateEvaluation(t *testing.T) {
 		{"type=bug matches bug", "type=bug", openBug, true},
 		{"type=bug doesn't match task", "type=bug", closedTask, false},
 
+		// Severity tests
+		{"severity=S1 matches S1 bug", "severity=S1", openBug, true},
+		{"severity=S1 doesn't match task", "severity=S1", closedTask, false},
+		{"severity!=S0 matches S1 bug", "severity!=S0", openBug, true},
+		{"severity=S0 OR priority=0 matches P0 feature", "severity=S0 OR priority=0", blockedFeature, true},
+
 		// Label tests
 		{"label=urgent matches issue with urgent", "label=urgent", openBug, true},
 		{"label=urgent doesn't match issue without", "label=urgent", closedTask, false},
@@ -7,11 +7,33 @@ import (
 	"github.com/steveyegge/beads/internal/types"
 )
 
-// AnnotationStore provides comment and label operations, including bulk queries.
+// AnnotationStore provides comment, label, code reference, work log,
+// checklist, co-assignee and watcher operations, including bulk queries.
 type AnnotationStore interface {
 	AddComment(ctx context.Context, issueID, actor, comment string) error
 	ImportIssueComment(ctx context.Context, issueID, author, text string, createdAt time.Time) (*types.Comment, error)
+	GetComment(ctx context.Context, commentID string) (*types.Comment, error)
+	EditComment(ctx context.Context, commentID, text, actor string) (*types.Comment, error)
+	DeleteComment(ctx context.Context, commentID, actor string) (*types.Comment, error)
 	GetCommentCounts(ctx context.Context, issueIDs []string) (map[string]int, error)
 	GetCommentsForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.Comment, error)
 	GetLabelsForIssues(ctx context.Context, issueIDs []string) (map[string][]string, error)
+	AddCodeRef(ctx context.Context, ref *types.CodeRef) error
+	RemoveCodeRefs(ctx context.Context, issueID, path string, lineStart, lineEnd int) (int64, error)
+	GetCodeRefsForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.CodeRef, error)
+	AddWorkLog(ctx context.Context, entry *types.WorkLog) error
+	GetWorkLogForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.WorkLog, error)
+	GetWorkLogTotals(ctx context.Context) ([]*types.WorkLogTotal, error)
+	AddTodo(ctx context.Context, todo *types.TodoItem) error
+	SetTodoDone(ctx context.Context, issueID string, seq int, done bool) (*types.TodoItem, error)
+	RemoveTodo(ctx context.Context, issueID string, seq int) (bool, error)
+	GetTodosForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.TodoItem, error)
+	AddAssignee(ctx context.Context, issueID, assignee, actor string) error
+	RemoveAssignee(ctx context.Context, issueID, assignee, actor string) (bool, error)
+	GetAssigneesForIssues(ctx context.Context, issueIDs []string) (map[string][]string, error)
+	WatchIssue(ctx context.Context, issueID, actor string) (bool, error)
+	UnwatchIssue(ctx context.Context, issueID, actor string) (bool, error)
+	GetWatchedIssueIDs(ctx context.Context, actor string) ([]string, error)
+	GetInbox(ctx context.Context, actor string) ([]*types.Event, error)
+	MarkInboxRead(ctx context.Context, actor string, through time.Time) error
 }
@@ -34,4 +34,10 @@ type BatchCreateOptions struct {
 	SkipDependencyValidationErrors bool
 	// OnSkippedDependency records dependency edges skipped during batch create.
 	OnSkippedDependency func(issueID, dependsOnID, reason string)
+	// RenumberIDs lists issues to create under a newly minted ID instead
+	// of their own, keyed by their incoming ID (import collisions under a
+	// sequential ID scheme). The IDs are minted in the batch's transaction,
+	// the batch's dependencies on an old ID are pointed at the new one, and
+	// each new ID is stored in the map.
+	RenumberIDs map[string]string
 }
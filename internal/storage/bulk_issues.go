@@ -2,6 +2,7 @@ package storage
 
 import (
 	"context"
+	"time"
 
 	"github.com/steveyegge/beads/internal/types"
 )
@@ -13,6 +14,8 @@ type BulkIssueStore interface {
 	DeleteIssuesBySourceRepo(ctx context.Context, sourceRepo string) (int, error)
 	UpdateIssueID(ctx context.Context, oldID, newID string, issue *types.Issue, actor string) error
 	ClaimIssue(ctx context.Context, id string, actor string) error
+	ClaimIssueWithLease(ctx context.Context, id string, actor string, lease time.Duration) error
+	GetClaimLeases(ctx context.Context, issueIDs []string) (map[string]*types.ClaimLease, error)
 	ClaimReadyIssue(ctx context.Context, filter types.WorkFilter, actor string) (*types.Issue, error)
 	PromoteFromEphemeral(ctx context.Context, id string, actor string) error
 	GetNextChildID(ctx context.Context, parentID string) (string, error)
@@ -192,6 +192,30 @@ func TestApplyPoolLimits_ClampsIdleToOpen(t *testing.T) {
 	}
 }
 
+func TestPoolSettings_EnvOverrides(t *testing.T) {
+	t.Setenv("BEADS_DOLT_MAX_IDLE_CONNS", "8")
+	t.Setenv("BEADS_DOLT_CONN_MAX_LIFETIME", "30m")
+
+	if got := poolIntSetting("BEADS_DOLT_MAX_IDLE_CONNS", "dolt.max-idle-conns"); got != 8 {
+		t.Errorf("poolIntSetting = %d, want 8", got)
+	}
+	if got := poolDurationSetting("BEADS_DOLT_CONN_MAX_LIFETIME", "dolt.conn-max-lifetime"); got != 30*time.Minute {
+		t.Errorf("poolDurationSetting = %v, want 30m", got)
+	}
+}
+
+func TestPoolSettings_InvalidValuesFallBackToDefault(t *testing.T) {
+	t.Setenv("BEADS_DOLT_MAX_IDLE_CONNS", "-3")
+	t.Setenv("BEADS_DOLT_CONN_MAX_LIFETIME", "forever")
+
+	if got := poolIntSetting("BEADS_DOLT_MAX_IDLE_CONNS", "dolt.max-idle-conns"); got != 0 {
+		t.Errorf("poolIntSetting = %d, want 0 (default)", got)
+	}
+	if got := poolDurationSetting("BEADS_DOLT_CONN_MAX_LIFETIME", "dolt.conn-max-lifetime"); got != 0 {
+		t.Errorf("poolDurationSetting = %v, want 0 (default)", got)
+	}
+}
+
 // --- pool reuse tests ------------------------------------------------------
 
 // TestPool_SequentialQueriesReuseSingleConnection is the headline test:
@@ -23,7 +23,7 @@ import (
 const depTargetExpr = "COALESCE(depends_on_issue_id, depends_on_wisp_id, depends_on_external)"
 
 // CountIssues returns the number of issues matching query and filter.
-// Filter.Limit and Filter.Offset are ignored; all other fields apply.
+// Filter.Limit, Filter.Offset and Filter.After are ignored; all other fields apply.
 func (s *DoltStore) CountIssues(ctx context.Context, query string, filter types.IssueFilter) (int64, error) {
 	var n int64
 	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
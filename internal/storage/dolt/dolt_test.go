@@ -8,6 +8,7 @@ import (
 	"errors"
 	"fmt"
 	"os"
+	"sort"
 	"strings"
 	"testing"
 	"time"
@@ -1148,6 +1149,54 @@ func TestDeleteIssuesBatchPerformance(t *testing.T) {
 	}
 }
 
+// TestTransactionDeleteIssues verifies that a cascade delete inside a
+// transaction reports what it removed and commits with the other writes of
+// the transaction.
+func TestTransactionDeleteIssues(t *testing.T) {
+	store, cleanup := setupTestStore(t)
+	defer cleanup()
+
+	ctx, cancel := testContext(t)
+	defer cancel()
+
+	for _, id := range []string{"txdel-parent", "txdel-child"} {
+		issue := &types.Issue{ID: id, Title: id, Status: types.StatusOpen, Priority: 1, IssueType: types.TypeTask}
+		if err := store.CreateIssue(ctx, issue, "tester"); err != nil {
+			t.Fatalf("failed to create %s: %v", id, err)
+		}
+	}
+	dep := &types.Dependency{IssueID: "txdel-child", DependsOnID: "txdel-parent", Type: types.DepBlocks}
+	if err := store.AddDependency(ctx, dep, "tester"); err != nil {
+		t.Fatalf("failed to add dependency: %v", err)
+	}
+
+	var result *types.DeleteIssuesResult
+	err := store.RunInTransaction(ctx, "test: delete in tx", func(tx storage.Transaction) error {
+		var err error
+		if result, err = tx.DeleteIssues(ctx, []string{"txdel-parent"}, true, false); err != nil {
+			return err
+		}
+		return tx.SetConfig(ctx, "test.deleted", strings.Join(result.DeletedIDs, ","))
+	})
+	if err != nil {
+		t.Fatalf("transaction failed: %v", err)
+	}
+
+	deleted := append([]string{}, result.DeletedIDs...)
+	sort.Strings(deleted)
+	if strings.Join(deleted, ",") != "txdel-child,txdel-parent" {
+		t.Errorf("DeletedIDs = %v, want both issues", result.DeletedIDs)
+	}
+	for _, id := range deleted {
+		if _, err := store.GetIssue(ctx, id); !errors.Is(err, storage.ErrNotFound) {
+			t.Errorf("expected ErrNotFound for %s after delete, got: %v", id, err)
+		}
+	}
+	if v, err := store.GetConfig(ctx, "test.deleted"); err != nil || v == "" {
+		t.Errorf("config written with the delete = %q, %v", v, err)
+	}
+}
+
 // TestDeleteIssuesEmptyInput verifies that DeleteIssues handles empty input gracefully.
 func TestDeleteIssuesEmptyInput(t *testing.T) {
 	store, cleanup := setupTestStore(t)
@@ -64,6 +64,40 @@ func (s *DoltStore) ImportIssueComment(ctx context.Context, issueID, author, tex
 	return result, err
 }
 
+// GetComment returns a comment by its full or short ID.
+func (s *DoltStore) GetComment(ctx context.Context, commentID string) (*types.Comment, error) {
+	var result *types.Comment
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetCommentInTx(ctx, tx, commentID)
+		return err
+	})
+	return result, err
+}
+
+// EditComment replaces a comment's text, keeping the prior text in the
+// event log.
+func (s *DoltStore) EditComment(ctx context.Context, commentID, text, actor string) (*types.Comment, error) {
+	var result *types.Comment
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.EditCommentInTx(ctx, tx, commentID, text, actor)
+		return err
+	})
+	return result, err
+}
+
+// DeleteComment removes a comment, keeping its text in the event log.
+func (s *DoltStore) DeleteComment(ctx context.Context, commentID, actor string) (*types.Comment, error) {
+	var result *types.Comment
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.DeleteCommentInTx(ctx, tx, commentID, actor)
+		return err
+	})
+	return result, err
+}
+
 // GetIssueComments retrieves all comments for an issue
 func (s *DoltStore) GetIssueComments(ctx context.Context, issueID string) ([]*types.Comment, error) {
 	table := "comments"
@@ -97,6 +131,188 @@ func (s *DoltStore) GetCommentsForIssues(ctx context.Context, issueIDs []string)
 	return result, err
 }
 
+// AddCodeRef records a code reference on an issue.
+func (s *DoltStore) AddCodeRef(ctx context.Context, ref *types.CodeRef) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.AddCodeRefInTx(ctx, tx, ref)
+	})
+}
+
+// RemoveCodeRefs removes an issue's references to path (all of them when
+// lineStart is 0) and returns how many were removed.
+func (s *DoltStore) RemoveCodeRefs(ctx context.Context, issueID, path string, lineStart, lineEnd int) (int64, error) {
+	var removed int64
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		removed, err = issueops.RemoveCodeRefsInTx(ctx, tx, issueID, path, lineStart, lineEnd)
+		return err
+	})
+	return removed, err
+}
+
+// GetCodeRefsForIssues retrieves code references for multiple issues
+func (s *DoltStore) GetCodeRefsForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.CodeRef, error) {
+	var result map[string][]*types.CodeRef
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetCodeRefsForIssuesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
+// AddWorkLog records time spent on an issue.
+func (s *DoltStore) AddWorkLog(ctx context.Context, entry *types.WorkLog) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.AddWorkLogInTx(ctx, tx, entry)
+	})
+}
+
+// GetWorkLogForIssues retrieves work log entries for multiple issues
+func (s *DoltStore) GetWorkLogForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.WorkLog, error) {
+	var result map[string][]*types.WorkLog
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetWorkLogForIssuesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
+// GetWorkLogTotals sums logged time per issue and actor.
+func (s *DoltStore) GetWorkLogTotals(ctx context.Context) ([]*types.WorkLogTotal, error) {
+	var result []*types.WorkLogTotal
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetWorkLogTotalsInTx(ctx, tx)
+		return err
+	})
+	return result, err
+}
+
+// AddTodo appends a checklist item to an issue, filling in its number.
+func (s *DoltStore) AddTodo(ctx context.Context, todo *types.TodoItem) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.AddTodoInTx(ctx, tx, todo)
+	})
+}
+
+// SetTodoDone ticks or unticks a checklist item and returns it.
+func (s *DoltStore) SetTodoDone(ctx context.Context, issueID string, seq int, done bool) (*types.TodoItem, error) {
+	var todo *types.TodoItem
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		todo, err = issueops.SetTodoDoneInTx(ctx, tx, issueID, seq, done)
+		return err
+	})
+	return todo, err
+}
+
+// RemoveTodo deletes a checklist item and reports whether it existed.
+func (s *DoltStore) RemoveTodo(ctx context.Context, issueID string, seq int) (bool, error) {
+	var removed bool
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		removed, err = issueops.RemoveTodoInTx(ctx, tx, issueID, seq)
+		return err
+	})
+	return removed, err
+}
+
+// GetTodosForIssues retrieves checklist items for multiple issues
+func (s *DoltStore) GetTodosForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.TodoItem, error) {
+	var result map[string][]*types.TodoItem
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetTodosForIssuesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
+// AddAssignee adds a co-assignee to an issue.
+func (s *DoltStore) AddAssignee(ctx context.Context, issueID, assignee, actor string) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.AddAssigneeInTx(ctx, tx, issueID, assignee, actor)
+	})
+}
+
+// RemoveAssignee removes a co-assignee from an issue, reporting whether
+// they were on it.
+func (s *DoltStore) RemoveAssignee(ctx context.Context, issueID, assignee, actor string) (bool, error) {
+	var removed bool
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		removed, err = issueops.RemoveAssigneeInTx(ctx, tx, issueID, assignee, actor)
+		return err
+	})
+	return removed, err
+}
+
+// GetAssigneesForIssues retrieves co-assignees for multiple issues
+func (s *DoltStore) GetAssigneesForIssues(ctx context.Context, issueIDs []string) (map[string][]string, error) {
+	var result map[string][]string
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetAssigneesForIssuesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
+// WatchIssue subscribes actor to an issue, reporting whether the actor
+// was not already watching it.
+func (s *DoltStore) WatchIssue(ctx context.Context, issueID, actor string) (bool, error) {
+	var added bool
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		added, err = issueops.WatchIssueInTx(ctx, tx, issueID, actor)
+		return err
+	})
+	return added, err
+}
+
+// UnwatchIssue removes actor's subscription to an issue, reporting
+// whether there was one.
+func (s *DoltStore) UnwatchIssue(ctx context.Context, issueID, actor string) (bool, error) {
+	var removed bool
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		removed, err = issueops.UnwatchIssueInTx(ctx, tx, issueID, actor)
+		return err
+	})
+	return removed, err
+}
+
+// GetWatchedIssueIDs lists the issues actor watches.
+func (s *DoltStore) GetWatchedIssueIDs(ctx context.Context, actor string) ([]string, error) {
+	var ids []string
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		ids, err = issueops.GetWatchedIssueIDsInTx(ctx, tx, actor)
+		return err
+	})
+	return ids, err
+}
+
+// GetInbox returns unread events on the issues actor watches.
+func (s *DoltStore) GetInbox(ctx context.Context, actor string) ([]*types.Event, error) {
+	var events []*types.Event
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		events, err = issueops.GetInboxInTx(ctx, tx, actor)
+		return err
+	})
+	return events, err
+}
+
+// MarkInboxRead marks actor's inbox read through the given time.
+func (s *DoltStore) MarkInboxRead(ctx context.Context, actor string, through time.Time) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.MarkInboxReadInTx(ctx, tx, actor, through)
+	})
+}
+
 // GetCommentCounts returns the number of comments for each issue in a single batch query.
 // Delegates to issueops.GetCommentCountsInTx for shared query logic.
 func (s *DoltStore) GetCommentCounts(ctx context.Context, issueIDs []string) (map[string]int, error) {
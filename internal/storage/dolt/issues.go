@@ -186,7 +186,7 @@ func (s *DoltStore) UpdateIssue(ctx context.Context, id string, updates map[stri
 		return err
 	}
 
-	for _, table := range []string{"issues", "events"} {
+	for _, table := range []string{"issues", "claim_leases", "events"} {
 		_, _ = tx.ExecContext(ctx, "CALL DOLT_ADD(?)", table)
 	}
 	commitMsg := fmt.Sprintf("bd: update %s", id)
@@ -207,9 +207,19 @@ func (s *DoltStore) UpdateIssue(ctx context.Context, id string, updates map[stri
 // Delegates SQL work to issueops.ClaimIssueInTx; handles Dolt-specific concerns
 // (wisp routing, DOLT_ADD/COMMIT, cache invalidation).
 func (s *DoltStore) ClaimIssue(ctx context.Context, id string, actor string) error {
+	return s.ClaimIssueWithLease(ctx, id, actor, 0)
+}
+
+// ClaimIssueWithLease claims an issue like ClaimIssue and, when lease is
+// positive, records a claim lease that lapses after lease. Delegates SQL
+// work to issueops.ClaimIssueWithLeaseInTx.
+func (s *DoltStore) ClaimIssueWithLease(ctx context.Context, id string, actor string, lease time.Duration) error {
 	// Route ephemeral IDs to wisps table (falls through for promoted wisps).
 	// Wisps skip DOLT_COMMIT since they live in dolt_ignored tables.
 	if s.isActiveWisp(ctx, id) {
+		if lease > 0 {
+			return fmt.Errorf("%s is a wisp: claim leases are only supported for persistent issues", id)
+		}
 		return s.claimWisp(ctx, id, actor)
 	}
 
@@ -219,13 +229,13 @@ func (s *DoltStore) ClaimIssue(ctx context.Context, id string, actor string) err
 	}
 	defer func() { _ = tx.Rollback() }()
 
-	if _, err := issueops.ClaimIssueInTx(ctx, tx, id, actor); err != nil {
+	if _, err := issueops.ClaimIssueWithLeaseInTx(ctx, tx, id, actor, lease); err != nil {
 		return err
 	}
 
 	// Dolt versioning for permanent issues.
 	// GH#2455: Stage only the tables we modified, then commit without -A.
-	for _, table := range []string{"issues", "events"} {
+	for _, table := range []string{"issues", "claim_leases", "events"} {
 		_, _ = tx.ExecContext(ctx, "CALL DOLT_ADD(?)", table)
 	}
 	commitMsg := fmt.Sprintf("bd: claim %s", id)
@@ -240,6 +250,17 @@ func (s *DoltStore) ClaimIssue(ctx context.Context, id string, actor string) err
 	return nil
 }
 
+// GetClaimLeases retrieves claim leases on multiple issues.
+func (s *DoltStore) GetClaimLeases(ctx context.Context, issueIDs []string) (map[string]*types.ClaimLease, error) {
+	var result map[string]*types.ClaimLease
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetClaimLeasesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
 // ClaimReadyIssue atomically claims the first ready issue matching filter.
 func (s *DoltStore) ClaimReadyIssue(ctx context.Context, filter types.WorkFilter, actor string) (*types.Issue, error) {
 	tx, err := s.db.BeginTx(ctx, nil)
@@ -256,7 +277,7 @@ func (s *DoltStore) ClaimReadyIssue(ctx context.Context, filter types.WorkFilter
 		return nil, nil
 	}
 
-	for _, table := range []string{"issues", "events"} {
+	for _, table := range []string{"issues", "claim_leases", "events"} {
 		_, _ = tx.ExecContext(ctx, "CALL DOLT_ADD(?)", table)
 	}
 	commitMsg := fmt.Sprintf("bd: claim ready %s", claimed.ID)
@@ -318,7 +339,7 @@ func (s *DoltStore) CloseIssue(ctx context.Context, id string, reason string, ac
 
 	// Dolt versioning for permanent issues.
 	// GH#2455: Stage only the tables we modified, then commit without -A.
-	for _, table := range []string{"issues", "events"} {
+	for _, table := range []string{"issues", "claim_leases", "events"} {
 		_, _ = tx.ExecContext(ctx, "CALL DOLT_ADD(?)", table)
 	}
 	commitMsg := fmt.Sprintf("bd: close %s", id)
@@ -345,7 +366,7 @@ func (s *DoltStore) DeleteIssue(ctx context.Context, id string) error {
 			return err
 		}
 
-		for _, table := range []string{"issues", "dependencies", "labels", "comments", "events", "child_counters", "issue_snapshots", "compaction_snapshots"} {
+		for _, table := range []string{"issues", "dependencies", "labels", "comments", "code_refs", "work_log", "todos", "assignees", "watchers", "claim_leases", "events", "child_counters", "issue_snapshots", "compaction_snapshots"} {
 			_, _ = tx.ExecContext(ctx, "CALL DOLT_ADD(?)", table)
 		}
 		commitMsg := fmt.Sprintf("bd: delete %s", id)
@@ -389,6 +410,7 @@ func (s *DoltStore) DeleteIssues(ctx context.Context, ids []string, cascade bool
 	// to avoid write timeout on large sets — see bd-2ehd, ff-tqm).
 	ephIDs, regularIDs := s.partitionByWispStatus(ctx, ids)
 	wispDeleteCount := 0
+	var deletedWispIDs []string
 	if len(ephIDs) > 0 {
 		var activeWispIDs []string
 		for _, eid := range ephIDs {
@@ -403,11 +425,12 @@ func (s *DoltStore) DeleteIssues(ctx context.Context, ids []string, cascade bool
 				return nil, fmt.Errorf("failed to batch delete wisps: %w", err)
 			}
 			wispDeleteCount = deleted
+			deletedWispIDs = activeWispIDs
 		}
 	}
 	ids = regularIDs
 	if len(ids) == 0 {
-		return &types.DeleteIssuesResult{DeletedCount: wispDeleteCount}, nil
+		return &types.DeleteIssuesResult{DeletedCount: wispDeleteCount, DeletedIDs: deletedWispIDs}, nil
 	}
 
 	var result *types.DeleteIssuesResult
@@ -422,7 +445,7 @@ func (s *DoltStore) DeleteIssues(ctx context.Context, ids []string, cascade bool
 			return nil
 		}
 
-		for _, table := range []string{"issues", "dependencies", "labels", "comments", "events", "child_counters", "issue_snapshots", "compaction_snapshots"} {
+		for _, table := range []string{"issues", "dependencies", "labels", "comments", "code_refs", "work_log", "todos", "assignees", "watchers", "claim_leases", "events", "child_counters", "issue_snapshots", "compaction_snapshots"} {
 			_, _ = tx.ExecContext(ctx, "CALL DOLT_ADD(?)", table)
 		}
 		commitMsg := fmt.Sprintf("bd: delete %d issue(s)", result.DeletedCount)
@@ -439,6 +462,7 @@ func (s *DoltStore) DeleteIssues(ctx context.Context, ids []string, cascade bool
 		return result, err
 	}
 	result.DeletedCount += wispDeleteCount
+	result.DeletedIDs = append(result.DeletedIDs, deletedWispIDs...)
 
 	return result, nil
 }
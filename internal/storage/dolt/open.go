@@ -6,6 +6,7 @@ import (
 	"os"
 	"strconv"
 	"strings"
+	"time"
 
 	"github.com/steveyegge/beads/internal/config"
 	"github.com/steveyegge/beads/internal/configfile"
@@ -234,19 +235,47 @@ func applyResolvedConfig(beadsDir string, fileCfg *configfile.Config, cfg *Confi
 	// Pool size: env var > config.yaml > caller override > default (10).
 	// Useful for shared-server setups with many worktrees (GH#3140).
 	if cfg.MaxOpenConns == 0 {
-		if v := os.Getenv("BEADS_DOLT_MAX_CONNS"); v != "" {
-			if n, err := strconv.Atoi(v); err == nil && n > 0 {
-				cfg.MaxOpenConns = n
-			}
+		cfg.MaxOpenConns = poolIntSetting("BEADS_DOLT_MAX_CONNS", "dolt.max-conns")
+	}
+	// Idle pool size and connection lifetime follow the same precedence.
+	// Concurrent agents sharing one server benefit from a larger warm idle
+	// pool; the lifetime knob exists for servers behind proxies that drop
+	// long-lived connections.
+	if cfg.MaxIdleConns == 0 {
+		cfg.MaxIdleConns = poolIntSetting("BEADS_DOLT_MAX_IDLE_CONNS", "dolt.max-idle-conns")
+	}
+	if cfg.ConnMaxLifetime == 0 {
+		cfg.ConnMaxLifetime = poolDurationSetting("BEADS_DOLT_CONN_MAX_LIFETIME", "dolt.conn-max-lifetime")
+	}
+}
+
+// poolIntSetting resolves a positive integer pool setting from envVar, then
+// the config.yaml key. Returns 0 (use the applyPoolLimits default) when
+// neither is set or the value is not a positive integer.
+func poolIntSetting(envVar, key string) int {
+	for _, v := range []string{os.Getenv(envVar), config.GetString(key)} {
+		if v == "" {
+			continue
+		}
+		if n, err := strconv.Atoi(v); err == nil && n > 0 {
+			return n
 		}
 	}
-	if cfg.MaxOpenConns == 0 {
-		if v := config.GetString("dolt.max-conns"); v != "" {
-			if n, err := strconv.Atoi(v); err == nil && n > 0 {
-				cfg.MaxOpenConns = n
-			}
+	return 0
+}
+
+// poolDurationSetting is the time.Duration counterpart of poolIntSetting.
+// Values use Go duration syntax ("30m", "2h").
+func poolDurationSetting(envVar, key string) time.Duration {
+	for _, v := range []string{os.Getenv(envVar), config.GetString(key)} {
+		if v == "" {
+			continue
+		}
+		if d, err := time.ParseDuration(v); err == nil && d > 0 {
+			return d
 		}
 	}
+	return 0
 }
 
 // applyCentralConfigDefaults loads the central server config from
@@ -1,6 +1,7 @@
 package dolt
 
 import (
+	"encoding/json"
 	"fmt"
 	"strings"
 	"testing"
@@ -1627,6 +1628,85 @@ func TestSearchIssues_ByTitle(t *testing.T) {
 	}
 }
 
+// TestSearchIssues_ByTitleRegex checks how SQL's REGEXP_LIKE treats a
+// pattern that passed RE2 validation: \b and \d classes work and matching
+// ignores case. Lookahead is valid in SQL but not in RE2, so it is rejected
+// before reaching the database.
+func TestSearchIssues_ByTitleRegex(t *testing.T) {
+	store, cleanup := setupTestStore(t)
+	defer cleanup()
+
+	ctx, cancel := testContext(t)
+	defer cancel()
+
+	for _, issue := range []*types.Issue{
+		{ID: "rx-1", Title: "Release V2.10 notes"},
+		{ID: "rx-2", Title: "Release v2 notes"},
+		{ID: "rx-3", Title: "Prereleasev2.1"},
+	} {
+		issue.Status, issue.Priority, issue.IssueType = types.StatusOpen, 2, types.TypeTask
+		if err := store.CreateIssue(ctx, issue, "tester"); err != nil {
+			t.Fatalf("failed to create issue: %v", err)
+		}
+	}
+
+	results, err := store.SearchIssues(ctx, "", types.IssueFilter{TitleRegex: `\bv\d+\.\d+\b`})
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(results) != 1 || results[0].ID != "rx-1" {
+		ids := make([]string, len(results))
+		for i, r := range results {
+			ids[i] = r.ID
+		}
+		t.Errorf("expected only rx-1, got %v", ids)
+	}
+
+	if _, err := store.SearchIssues(ctx, "", types.IssueFilter{TitleRegex: `v(?=2)`}); err == nil || !strings.Contains(err.Error(), "invalid title regex") {
+		t.Errorf("expected lookahead to be rejected before SQL, got %v", err)
+	}
+}
+
+func TestSearchIssues_ByCustomField(t *testing.T) {
+	store, cleanup := setupTestStore(t)
+	defer cleanup()
+
+	ctx, cancel := testContext(t)
+	defer cancel()
+
+	for _, issue := range []*types.Issue{
+		{ID: "cf-1", Title: "Field set", Metadata: json.RawMessage(`{"custom_fields":{"points":3,"team.name":"core"}}`)},
+		{ID: "cf-2", Title: "Top-level key of the same name", Metadata: json.RawMessage(`{"points":3}`)},
+		{ID: "cf-3", Title: "Other value", Metadata: json.RawMessage(`{"custom_fields":{"points":5}}`)},
+	} {
+		issue.Status, issue.Priority, issue.IssueType = types.StatusOpen, 2, types.TypeTask
+		if err := store.CreateIssue(ctx, issue, "tester"); err != nil {
+			t.Fatalf("failed to create issue: %v", err)
+		}
+	}
+
+	for _, fields := range []map[string]string{
+		{"points": "3"},
+		{"team.name": "core"},
+	} {
+		results, err := store.SearchIssues(ctx, "", types.IssueFilter{CustomFields: fields})
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		if len(results) != 1 || results[0].ID != "cf-1" {
+			ids := make([]string, len(results))
+			for i, r := range results {
+				ids[i] = r.ID
+			}
+			t.Errorf("CustomFields %v: expected only cf-1, got %v", fields, ids)
+		}
+	}
+
+	if _, err := store.SearchIssues(ctx, "", types.IssueFilter{CustomFields: map[string]string{"bad'key": "x"}}); err == nil {
+		t.Error("expected an invalid field name to be rejected")
+	}
+}
+
 // TestSearchIssues_ByDescription verifies that DescriptionContains filter finds
 // issues by description text. Free-text search no longer scans descriptions
 // (hq-319 optimization) — use DescriptionContains for explicit description search.
@@ -2941,6 +3021,55 @@ func TestCounterMode_AlreadySeeded(t *testing.T) {
 	}
 }
 
+func TestCounterMode_RenumberIDs(t *testing.T) {
+	store, cleanup := setupTestStore(t)
+	defer cleanup()
+
+	ctx, cancel := testContext(t)
+	defer cancel()
+
+	if err := store.SetConfig(ctx, "issue_id_mode", "counter"); err != nil {
+		t.Fatalf("failed to enable counter mode: %v", err)
+	}
+	local := &types.Issue{Title: "Local one", Status: types.StatusOpen, Priority: 2, IssueType: types.TypeTask}
+	if err := store.CreateIssue(ctx, local, "tester"); err != nil {
+		t.Fatalf("failed to create issue: %v", err)
+	}
+
+	// An import brings a different test-1 and a test-2 that depends on it.
+	incoming := []*types.Issue{
+		{ID: "test-1", Title: "Remote one", Status: types.StatusOpen, Priority: 2, IssueType: types.TypeTask},
+		{ID: "test-2", Title: "Remote two", Status: types.StatusOpen, Priority: 2, IssueType: types.TypeTask,
+			Dependencies: []*types.Dependency{{IssueID: "test-2", DependsOnID: "test-1", Type: types.DepBlocks}}},
+	}
+	renumber := map[string]string{"test-1": ""}
+	if err := store.CreateIssuesWithFullOptions(ctx, incoming, "tester", storage.BatchCreateOptions{
+		OrphanHandling:       storage.OrphanAllow,
+		SkipPrefixValidation: true,
+		RenumberIDs:          renumber,
+	}); err != nil {
+		t.Fatalf("CreateIssuesWithFullOptions: %v", err)
+	}
+
+	// The counter is at 1, but test-2 is taken by the batch itself.
+	if renumber["test-1"] != "test-3" || incoming[0].ID != "test-3" {
+		t.Fatalf("renumber = %v, incoming ID %s; want test-1 -> test-3", renumber, incoming[0].ID)
+	}
+	if got, err := store.GetIssue(ctx, "test-1"); err != nil || got.Title != "Local one" {
+		t.Errorf("local test-1 = %+v, %v; want it untouched", got, err)
+	}
+	if got, err := store.GetIssue(ctx, "test-3"); err != nil || got.Title != "Remote one" {
+		t.Errorf("test-3 = %+v, %v; want the renumbered issue", got, err)
+	}
+	records, err := store.GetDependencyRecords(ctx, "test-2")
+	if err != nil {
+		t.Fatalf("GetDependencyRecords: %v", err)
+	}
+	if len(records) != 1 || records[0].DependsOnID != "test-3" {
+		t.Errorf("test-2 dependencies = %+v, want one on test-3", records)
+	}
+}
+
 // TestSearchIssues_NoDuplicatesWithMultipleBlockers verifies that
 // SearchIssues returns an issue exactly once even when it has multiple
 // blocks dependencies. GH#3567.
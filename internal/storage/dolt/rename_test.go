@@ -634,3 +634,51 @@ func TestUpdateIssueIDStillWorksForRegularIssues(t *testing.T) {
 		t.Fatalf("expected 1 rename event for new ID, got %d", eventCount)
 	}
 }
+
+// TestUpdateIssueIDMovesChildCounter verifies that a renamed parent keeps its
+// child numbering instead of restarting at .1.
+func TestUpdateIssueIDMovesChildCounter(t *testing.T) {
+	store, cleanup := setupTestStore(t)
+	defer cleanup()
+
+	ctx, cancel := testContext(t)
+	defer cancel()
+
+	parent := &types.Issue{
+		ID:        "test-parent-old",
+		Title:     "Parent",
+		Status:    types.StatusOpen,
+		Priority:  2,
+		IssueType: types.TypeEpic,
+	}
+	if err := store.CreateIssue(ctx, parent, "tester"); err != nil {
+		t.Fatalf("CreateIssue failed: %v", err)
+	}
+	for i := 0; i < 2; i++ {
+		if _, err := store.GetNextChildID(ctx, parent.ID); err != nil {
+			t.Fatalf("GetNextChildID failed: %v", err)
+		}
+	}
+
+	newID := "test-parent-new"
+	parent.ID = newID
+	if err := store.UpdateIssueID(ctx, "test-parent-old", newID, parent, "tester"); err != nil {
+		t.Fatalf("UpdateIssueID failed: %v", err)
+	}
+
+	next, err := store.GetNextChildID(ctx, newID)
+	if err != nil {
+		t.Fatalf("GetNextChildID after rename failed: %v", err)
+	}
+	if next != newID+".3" {
+		t.Errorf("next child ID = %q, want %q", next, newID+".3")
+	}
+
+	var stale int
+	if err := store.db.QueryRowContext(ctx, `SELECT COUNT(*) FROM child_counters WHERE parent_id = ?`, "test-parent-old").Scan(&stale); err != nil {
+		t.Fatalf("failed to query child_counters: %v", err)
+	}
+	if stale != 0 {
+		t.Errorf("expected old child counter row to be removed, found %d", stale)
+	}
+}
@@ -111,7 +111,7 @@ func committedSchemaSnapshotQueries() map[string]string {
 SELECT CONCAT('table|', t.table_name, '|', t.table_type) AS line
 FROM information_schema.tables t
 WHERE t.table_schema = DATABASE()
-  AND t.table_name NOT IN ('ignored_schema_migrations', 'local_metadata', 'repo_mtimes', 'wisps')
+  AND t.table_name NOT IN ('ignored_schema_migrations', 'local_metadata', 'repo_mtimes', 'daemon_log', 'trash', 'wisps')
   AND LEFT(t.table_name, 5) <> 'wisp_'
   AND LEFT(t.table_name, 5) <> 'dolt_'`,
 		"columns": `
@@ -123,7 +123,7 @@ FROM information_schema.columns c
 JOIN information_schema.tables t
   ON t.table_schema = c.table_schema AND t.table_name = c.table_name
 WHERE c.table_schema = DATABASE()
-  AND t.table_name NOT IN ('ignored_schema_migrations', 'local_metadata', 'repo_mtimes', 'wisps')
+  AND t.table_name NOT IN ('ignored_schema_migrations', 'local_metadata', 'repo_mtimes', 'daemon_log', 'trash', 'wisps')
   AND LEFT(t.table_name, 5) <> 'wisp_'
   AND LEFT(t.table_name, 5) <> 'dolt_'`,
 		"indexes": `
@@ -134,7 +134,7 @@ FROM information_schema.statistics s
 JOIN information_schema.tables t
   ON t.table_schema = s.table_schema AND t.table_name = s.table_name
 WHERE s.table_schema = DATABASE()
-  AND t.table_name NOT IN ('ignored_schema_migrations', 'local_metadata', 'repo_mtimes', 'wisps')
+  AND t.table_name NOT IN ('ignored_schema_migrations', 'local_metadata', 'repo_mtimes', 'daemon_log', 'trash', 'wisps')
   AND LEFT(t.table_name, 5) <> 'wisp_'
   AND LEFT(t.table_name, 5) <> 'dolt_'`,
 		"constraints": `
@@ -154,7 +154,7 @@ LEFT JOIN information_schema.referential_constraints rc
   ON rc.constraint_schema = tc.constraint_schema
  AND rc.constraint_name = tc.constraint_name
 WHERE tc.constraint_schema = DATABASE()
-  AND t.table_name NOT IN ('ignored_schema_migrations', 'local_metadata', 'repo_mtimes', 'wisps')
+  AND t.table_name NOT IN ('ignored_schema_migrations', 'local_metadata', 'repo_mtimes', 'daemon_log', 'trash', 'wisps')
   AND LEFT(t.table_name, 5) <> 'wisp_'
   AND LEFT(t.table_name, 5) <> 'dolt_'`,
 		"version": `
@@ -159,3 +159,53 @@ func TestSchemaRunsInitWhenMissing(t *testing.T) {
 	_, _ = store.db.ExecContext(dropCtx, fmt.Sprintf("DROP DATABASE IF EXISTS `%s`", dbName))
 	store.Close()
 }
+
+// TestApplySchemaMigrationsTo verifies that migrating to a version stops
+// there, and that versions past the latest or below the applied one fail.
+func TestApplySchemaMigrationsTo(t *testing.T) {
+	store, cleanup := setupTestStore(t)
+	defer cleanup()
+
+	ctx, cancel := testContext(t)
+	defer cancel()
+
+	latest := schema.LatestVersion()
+	if _, err := store.db.ExecContext(ctx,
+		"DELETE FROM schema_migrations WHERE version >= ?", latest-1); err != nil {
+		t.Fatalf("failed to delete the last two migrations: %v", err)
+	}
+	readVersion := func() int {
+		t.Helper()
+		var v int
+		if err := store.db.QueryRowContext(ctx, "SELECT COALESCE(MAX(version), 0) FROM schema_migrations").Scan(&v); err != nil {
+			t.Fatalf("reading max version: %v", err)
+		}
+		return v
+	}
+
+	applied, err := store.ApplySchemaMigrationsTo(ctx, latest-1)
+	if err != nil {
+		t.Fatalf("ApplySchemaMigrationsTo(%d): %v", latest-1, err)
+	}
+	if applied != 1 || readVersion() != latest-1 {
+		t.Errorf("applied %d, version v%d; want 1 applied, v%d", applied, readVersion(), latest-1)
+	}
+
+	if _, err := store.ApplySchemaMigrationsTo(ctx, latest-2); err == nil {
+		t.Error("migrating below the applied version should fail")
+	}
+	if _, err := store.ApplySchemaMigrationsTo(ctx, latest+1); err == nil {
+		t.Error("migrating past the latest version should fail")
+	}
+	if v := readVersion(); v != latest-1 {
+		t.Errorf("failed migrations changed the version to v%d", v)
+	}
+
+	applied, err = store.ApplySchemaMigrations(ctx)
+	if err != nil {
+		t.Fatalf("ApplySchemaMigrations: %v", err)
+	}
+	if applied != 1 || readVersion() != latest {
+		t.Errorf("applied %d, version v%d; want 1 applied, v%d", applied, readVersion(), latest)
+	}
+}
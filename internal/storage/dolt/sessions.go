@@ -0,0 +1,78 @@
+package dolt
+
+import (
+	"context"
+	"database/sql"
+
+	"github.com/steveyegge/beads/internal/storage/issueops"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// StartSession opens a session for session.Actor, filling in its ID and start time.
+func (s *DoltStore) StartSession(ctx context.Context, session *types.Session) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.StartSessionInTx(ctx, tx, session)
+	})
+}
+
+// EndSession closes actor's open session and returns it, or nil if none was open.
+func (s *DoltStore) EndSession(ctx context.Context, actor, note string) (*types.Session, error) {
+	var result *types.Session
+	err := s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.EndSessionInTx(ctx, tx, actor, note)
+		return err
+	})
+	return result, err
+}
+
+// GetOpenSession returns actor's open session, or nil.
+func (s *DoltStore) GetOpenSession(ctx context.Context, actor string) (*types.Session, error) {
+	var result *types.Session
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetOpenSessionInTx(ctx, tx, actor)
+		return err
+	})
+	return result, err
+}
+
+// RecordSessionCommand appends a command to a session's log.
+func (s *DoltStore) RecordSessionCommand(ctx context.Context, command *types.SessionCommand) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.RecordSessionCommandInTx(ctx, tx, command)
+	})
+}
+
+// ListSessions returns sessions newest first, only actor's when actor is set.
+func (s *DoltStore) ListSessions(ctx context.Context, actor string, limit int) ([]*types.Session, error) {
+	var result []*types.Session
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.ListSessionsInTx(ctx, tx, actor, limit)
+		return err
+	})
+	return result, err
+}
+
+// GetSession returns a session, by ID or unique ID prefix, with its commands.
+func (s *DoltStore) GetSession(ctx context.Context, id string) (*types.Session, error) {
+	var result *types.Session
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetSessionInTx(ctx, tx, id)
+		return err
+	})
+	return result, err
+}
+
+// GetSessionEvents returns the events made by the session's actor while it was open.
+func (s *DoltStore) GetSessionEvents(ctx context.Context, session *types.Session) ([]*types.Event, error) {
+	var result []*types.Event
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetSessionEventsInTx(ctx, tx, session)
+		return err
+	})
+	return result, err
+}
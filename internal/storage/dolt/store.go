@@ -1467,6 +1467,14 @@ func databaseExistsOnServer(ctx context.Context, db *sql.DB, name string) (bool,
 // applied versions in schema_migrations and backfills legacy config-driven
 // tables. Returns the number of migrations applied.
 func initSchemaOnDB(ctx context.Context, db *sql.DB) (int, error) {
+	return migrateSchemaOnDB(ctx, db, schema.MigrateUpWithLock)
+}
+
+// schemaMigrateFunc applies migrations on a pinned connection under the
+// database's migration lock; see schema.MigrateUpWithLock.
+type schemaMigrateFunc func(ctx context.Context, conn *sql.Conn, databaseName string) (int, error)
+
+func migrateSchemaOnDB(ctx context.Context, db *sql.DB, migrate schemaMigrateFunc) (int, error) {
 	conn, err := db.Conn(ctx)
 	if err != nil {
 		return 0, fmt.Errorf("schema: pin connection: %w", err)
@@ -1478,7 +1486,7 @@ func initSchemaOnDB(ctx context.Context, db *sql.DB) (int, error) {
 		return 0, fmt.Errorf("schema: read database name: %w", err)
 	}
 
-	applied, err := schema.MigrateUpWithLock(ctx, conn, dbName)
+	applied, err := migrate(ctx, conn, dbName)
 	if err != nil {
 		return applied, fmt.Errorf("schema migration: %w", err)
 	}
@@ -1486,6 +1494,10 @@ func initSchemaOnDB(ctx context.Context, db *sql.DB) (int, error) {
 }
 
 func initSchemaOnDBWithRetry(ctx context.Context, db *sql.DB) (int, error) {
+	return migrateSchemaOnDBWithRetry(ctx, db, schema.MigrateUpWithLock)
+}
+
+func migrateSchemaOnDBWithRetry(ctx context.Context, db *sql.DB, migrate schemaMigrateFunc) (int, error) {
 	// Schema initialization for server mode is idempotent. Retry transient
 	// Dolt startup/catalog races and contended migration-lock attempts so
 	// concurrent bd processes converge instead of failing one unlucky waiter.
@@ -1497,7 +1509,7 @@ func initSchemaOnDBWithRetry(ctx context.Context, db *sql.DB) (int, error) {
 	var applied int
 	err := backoff.Retry(func() error {
 		var schemaErr error
-		applied, schemaErr = initSchemaOnDB(ctx, db)
+		applied, schemaErr = migrateSchemaOnDB(ctx, db, migrate)
 		if schemaErr != nil && isRetryableError(schemaErr) {
 			return schemaErr
 		}
@@ -1538,6 +1550,19 @@ func (s *DoltStore) ApplySchemaMigrations(ctx context.Context) (int, error) {
 	return initSchemaOnDBWithRetry(ctx, migDB)
 }
 
+// ApplySchemaMigrationsTo is ApplySchemaMigrations stopping at version
+// target. Implements storage.SchemaMigrator.
+func (s *DoltStore) ApplySchemaMigrationsTo(ctx context.Context, target int) (int, error) {
+	migDB, err := s.openMigrationDB()
+	if err != nil {
+		return 0, err
+	}
+	defer migDB.Close()
+	return migrateSchemaOnDBWithRetry(ctx, migDB, func(ctx context.Context, conn *sql.Conn, databaseName string) (int, error) {
+		return schema.MigrateUpToWithLock(ctx, conn, databaseName, target)
+	})
+}
+
 // openMigrationDB opens a one-off connection pool for schema migrations with no
 // read/write timeout. Migrations may run far longer than the default 10s pool
 // timeout, and timing out part-way leaves the database in a dirty, half-migrated
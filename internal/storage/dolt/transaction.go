@@ -279,9 +279,11 @@ func (t *doltTransaction) SearchIssues(ctx context.Context, query string, filter
 	// Derive related table names from the main table
 	depTable := "dependencies"
 	labelTable := "labels"
+	tables := issueops.IssuesFilterTables
 	if table == "wisps" {
 		depTable = "wisp_dependencies"
 		labelTable = "wisp_labels"
+		tables = issueops.WispsFilterTables
 	}
 
 	whereClauses := []string{}
@@ -290,14 +292,18 @@ func (t *doltTransaction) SearchIssues(ctx context.Context, query string, filter
 	// Text search — optimized to avoid full-table scans (hq-319).
 	if query != "" {
 		lowerQuery := strings.ToLower(query)
+		var match []string
 		if looksLikeIssueID(query) {
-			whereClauses = append(whereClauses, "(id = ? OR id LIKE ? OR LOWER(title) LIKE ?)")
+			match = []string{"id = ?", "id LIKE ?", "LOWER(title) LIKE ?"}
 			args = append(args, lowerQuery, lowerQuery+"%", "%"+lowerQuery+"%")
 		} else {
-			whereClauses = append(whereClauses, "(LOWER(title) LIKE ? OR id LIKE ?)")
+			match = []string{"LOWER(title) LIKE ?", "id LIKE ?"}
 			pattern := "%" + lowerQuery + "%"
 			args = append(args, pattern, pattern)
 		}
+		inClauses, inArgs := issueops.SearchInClauses("%"+lowerQuery+"%", filter.SearchIn, tables.Comments, tables.Events)
+		whereClauses = append(whereClauses, "("+strings.Join(append(match, inClauses...), " OR ")+")")
+		args = append(args, inArgs...)
 	}
 
 	if filter.TitleSearch != "" {
@@ -320,6 +326,17 @@ func (t *doltTransaction) SearchIssues(ctx context.Context, query string, filter
 		whereClauses = append(whereClauses, "LOWER(external_ref) LIKE ?")
 		args = append(args, "%"+strings.ToLower(filter.ExternalRefContains)+"%")
 	}
+	if err := issueops.ValidateRegexFilters(filter); err != nil {
+		return nil, err
+	}
+	if filter.TitleRegex != "" {
+		whereClauses = append(whereClauses, "REGEXP_LIKE(title, ?, 'i')")
+		args = append(args, filter.TitleRegex)
+	}
+	if filter.DescriptionRegex != "" {
+		whereClauses = append(whereClauses, "REGEXP_LIKE(description, ?, 'i')")
+		args = append(args, filter.DescriptionRegex)
+	}
 
 	// Status
 	if filter.Status != nil {
@@ -358,6 +375,14 @@ func (t *doltTransaction) SearchIssues(ctx context.Context, query string, filter
 		whereClauses = append(whereClauses, "priority <= ?")
 		args = append(args, *filter.PriorityMax)
 	}
+	if len(filter.Severities) > 0 {
+		placeholders := make([]string, len(filter.Severities))
+		for i, sev := range filter.Severities {
+			placeholders[i] = "?"
+			args = append(args, string(sev))
+		}
+		whereClauses = append(whereClauses, fmt.Sprintf("severity IN (%s)", strings.Join(placeholders, ",")))
+	}
 
 	if filter.IssueType != nil {
 		//nolint:gosec // G201: table is hardcoded to "issues" or "wisps"
@@ -365,10 +390,16 @@ func (t *doltTransaction) SearchIssues(ctx context.Context, query string, filter
 		args = append(args, *filter.IssueType)
 	}
 
-	// Assignee
+	// Assignee (primary or co-assignee)
 	if filter.Assignee != nil {
-		whereClauses = append(whereClauses, "assignee = ?")
-		args = append(args, *filter.Assignee)
+		clause, assigneeArgs := issueops.AssigneeMatchClause(table, *filter.Assignee)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, assigneeArgs...)
+	}
+	if len(filter.ExcludeAssignees) > 0 {
+		clause, assigneeArgs := issueops.AssigneeExcludeClause(table, filter.ExcludeAssignees)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, assigneeArgs...)
 	}
 
 	// Date ranges
@@ -428,21 +459,19 @@ func (t *doltTransaction) SearchIssues(ctx context.Context, query string, filter
 	// Label filtering (AND)
 	if len(filter.Labels) > 0 {
 		for _, label := range filter.Labels {
+			cond, arg := issueops.LabelCondition("label", label)
 			//nolint:gosec // G201: labelTable is hardcoded to "labels" or "wisp_labels"
-			whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE label = ?)", labelTable))
-			args = append(args, label)
+			whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE %s)", labelTable, cond))
+			args = append(args, arg)
 		}
 	}
 
 	// Label filtering (OR)
 	if len(filter.LabelsAny) > 0 {
-		placeholders := make([]string, len(filter.LabelsAny))
-		for i, label := range filter.LabelsAny {
-			placeholders[i] = "?"
-			args = append(args, label)
-		}
+		cond, condArgs := issueops.LabelSetCondition("label", filter.LabelsAny)
 		//nolint:gosec // G201: labelTable is hardcoded to "labels" or "wisp_labels"
-		whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE label IN (%s))", labelTable, strings.Join(placeholders, ", ")))
+		whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE %s)", labelTable, cond))
+		args = append(args, condArgs...)
 	}
 
 	// ID filtering
@@ -454,6 +483,14 @@ func (t *doltTransaction) SearchIssues(ctx context.Context, query string, filter
 		}
 		whereClauses = append(whereClauses, fmt.Sprintf("id IN (%s)", strings.Join(placeholders, ", ")))
 	}
+	if len(filter.ExcludeIDs) > 0 {
+		placeholders := make([]string, len(filter.ExcludeIDs))
+		for i, id := range filter.ExcludeIDs {
+			placeholders[i] = "?"
+			args = append(args, id)
+		}
+		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (%s)", strings.Join(placeholders, ", ")))
+	}
 
 	if filter.IDPrefix != "" {
 		whereClauses = append(whereClauses, "id LIKE ?")
@@ -557,6 +594,14 @@ func (t *doltTransaction) SearchIssues(ctx context.Context, query string, filter
 			args = append(args, storage.JSONMetadataPath(k), filter.MetadataFields[k])
 		}
 	}
+	if len(filter.CustomFields) > 0 {
+		clauses, fieldArgs, err := storage.CustomFieldClauses(filter.CustomFields)
+		if err != nil {
+			return nil, err
+		}
+		whereClauses = append(whereClauses, clauses...)
+		args = append(args, fieldArgs...)
+	}
 
 	whereSQL := ""
 	if len(whereClauses) > 0 {
@@ -650,6 +695,19 @@ func (t *doltTransaction) DeleteIssue(ctx context.Context, id string) error {
 	return nil
 }
 
+// DeleteIssues deletes issues within the transaction, with the cascade and
+// force rules of DoltStore.DeleteIssues.
+func (t *doltTransaction) DeleteIssues(ctx context.Context, ids []string, cascade bool, force bool) (*types.DeleteIssuesResult, error) {
+	result, err := issueops.DeleteIssuesInTx(ctx, t.regularTx, ids, cascade, force, false)
+	if err != nil {
+		return result, err
+	}
+	for _, table := range []string{"issues", "dependencies", "labels", "comments", "code_refs", "work_log", "todos", "assignees", "watchers", "claim_leases", "events", "child_counters", "issue_snapshots", "compaction_snapshots"} {
+		t.dirty.MarkDirty(table)
+	}
+	return result, nil
+}
+
 // AddDependency adds a dependency within the transaction.
 // Checks for existing pairs to prevent silent type overwrites.
 func (t *doltTransaction) AddDependency(ctx context.Context, dep *types.Dependency, actor string) error {
@@ -0,0 +1,45 @@
+package dolt
+
+import (
+	"context"
+	"database/sql"
+
+	"github.com/steveyegge/beads/internal/storage/issueops"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// PutTrash records deleted issues in the trash.
+func (s *DoltStore) PutTrash(ctx context.Context, entries []*types.TrashedIssue) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.PutTrashInTx(ctx, tx, entries)
+	})
+}
+
+// GetTrash returns the trash entry for id, or nil if there is none.
+func (s *DoltStore) GetTrash(ctx context.Context, id string) (*types.TrashedIssue, error) {
+	var result *types.TrashedIssue
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetTrashInTx(ctx, tx, id)
+		return err
+	})
+	return result, err
+}
+
+// ListTrash returns the trash, most recently deleted first.
+func (s *DoltStore) ListTrash(ctx context.Context) ([]*types.TrashedIssue, error) {
+	var result []*types.TrashedIssue
+	err := s.withReadTx(ctx, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.ListTrashInTx(ctx, tx)
+		return err
+	})
+	return result, err
+}
+
+// RemoveTrash drops the trash entries for ids.
+func (s *DoltStore) RemoveTrash(ctx context.Context, ids []string) error {
+	return s.withRetryTx(ctx, func(tx *sql.Tx) error {
+		return issueops.RemoveTrashInTx(ctx, tx, ids)
+	})
+}
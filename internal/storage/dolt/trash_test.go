@@ -0,0 +1,58 @@
+package dolt
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestTrash(t *testing.T) {
+	store, cleanup := setupTestStore(t)
+	defer cleanup()
+
+	ctx, cancel := testContext(t)
+	defer cancel()
+
+	earlier := time.Date(2026, 3, 4, 0, 0, 0, 0, time.UTC)
+	entries := []*types.TrashedIssue{
+		{ID: "test-1", DeletedAt: earlier, Actor: "alice",
+			Issue: &types.Issue{ID: "test-1", Title: "first", Labels: []string{"ui"}, Description: strings.Repeat("long ", 20000)}},
+		{ID: "test-2", DeletedAt: earlier.Add(time.Hour), Issue: &types.Issue{ID: "test-2", Title: "second"},
+			Dependents: []*types.Dependency{{IssueID: "test-3", DependsOnID: "test-2", Type: types.DepBlocks}}},
+	}
+	if err := store.PutTrash(ctx, entries); err != nil {
+		t.Fatalf("PutTrash: %v", err)
+	}
+
+	got, err := store.ListTrash(ctx)
+	if err != nil {
+		t.Fatalf("ListTrash: %v", err)
+	}
+	if len(got) != 2 || got[0].ID != "test-2" || got[1].ID != "test-1" {
+		t.Fatalf("ListTrash = %+v, want test-2 then test-1", got)
+	}
+	if got[1].Actor != "alice" || got[1].Issue.Title != "first" || len(got[1].Issue.Labels) != 1 || len(got[1].Issue.Description) != 100000 {
+		t.Errorf("test-1 entry = %+v", got[1])
+	}
+	if deps := got[0].Dependents; len(deps) != 1 || deps[0].IssueID != "test-3" || deps[0].Type != types.DepBlocks {
+		t.Errorf("test-2 dependents = %+v", deps)
+	}
+
+	// The trash is clone-local: it is never staged or committed.
+	var ignored int
+	if err := store.db.QueryRowContext(ctx, "SELECT COUNT(*) FROM dolt_ignore WHERE pattern = 'trash' AND ignored").Scan(&ignored); err != nil || ignored != 1 {
+		t.Errorf("trash in dolt_ignore = %d, %v; want 1", ignored, err)
+	}
+
+	if err := store.RemoveTrash(ctx, []string{"test-1"}); err != nil {
+		t.Fatalf("RemoveTrash: %v", err)
+	}
+	if e, err := store.GetTrash(ctx, "test-1"); err != nil || e != nil {
+		t.Errorf("GetTrash(test-1) after removal = %+v, %v; want none", e, err)
+	}
+	if e, err := store.GetTrash(ctx, "test-2"); err != nil || e == nil || e.Issue.Title != "second" {
+		t.Errorf("GetTrash(test-2) = %+v, %v", e, err)
+	}
+}
@@ -239,7 +239,7 @@ func (s *DoltStore) deleteWisp(ctx context.Context, id string) error {
 		return fmt.Errorf("failed to get rows affected: %w", err)
 	}
 	if rows == 0 {
-		return fmt.Errorf("wisp not found: %s", id)
+		return fmt.Errorf("%w: wisp %s", storage.ErrNotFound, id)
 	}
 
 	if err := issueops.DeleteWispFromDependenciesInTx(ctx, tx, id); err != nil {
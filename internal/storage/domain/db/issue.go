@@ -37,12 +37,12 @@ const issueSelectColumns = `id, content_hash, title, description, design, accept
 	mol_type,
 	event_kind, actor, target, payload,
 	due_at, defer_until,
-	work_type, source_system, metadata`
+	work_type, source_system, metadata, severity`
 
 var allowedUpdateFields = map[string]struct{}{
 	"status": {}, "priority": {}, "title": {}, "assignee": {},
 	"description": {}, "design": {}, "acceptance_criteria": {}, "notes": {},
-	"issue_type": {}, "estimated_minutes": {}, "external_ref": {}, "spec_id": {},
+	"issue_type": {}, "estimated_minutes": {}, "external_ref": {}, "spec_id": {}, "severity": {},
 	"started_at": {}, "closed_at": {}, "close_reason": {}, "closed_by_session": {},
 	"source_repo": {}, "sender": {}, "wisp_type": {}, "no_history": {}, "pinned": {},
 	"mol_type": {}, "event_kind": {}, "actor": {}, "target": {}, "payload": {},
@@ -344,8 +344,9 @@ func buildSearchQuery(filter types.IssueFilter, table string) (string, []any) {
 		args = append(args, string(*filter.IssueType))
 	}
 	if filter.Assignee != nil {
-		where = append(where, "assignee = ?")
-		args = append(args, *filter.Assignee)
+		clause, assigneeArgs := assigneeMatchClause(table, *filter.Assignee)
+		where = append(where, clause)
+		args = append(args, assigneeArgs...)
 	}
 	if filter.TitleSearch != "" {
 		where = append(where, "title LIKE ?")
@@ -413,7 +414,7 @@ func insertIssueRow(ctx context.Context, runner Runner, table string, issue *typ
 			mol_type, work_type, source_system, source_repo, close_reason,
 			event_kind, actor, target, payload,
 			await_type, await_id, timeout_ns, waiters,
-			due_at, defer_until, metadata
+			due_at, defer_until, metadata, severity
 		) VALUES (
 			?, ?, ?, ?, ?, ?, ?,
 			?, ?, ?, ?, ?,
@@ -423,7 +424,7 @@ func insertIssueRow(ctx context.Context, runner Runner, table string, issue *typ
 			?, ?, ?, ?, ?,
 			?, ?, ?, ?,
 			?, ?, ?, ?,
-			?, ?, ?
+			?, ?, ?, ?
 		)
 		ON DUPLICATE KEY UPDATE
 			content_hash = VALUES(content_hash),
@@ -443,7 +444,8 @@ func insertIssueRow(ctx context.Context, runner Runner, table string, issue *typ
 			external_ref = VALUES(external_ref),
 			source_repo = VALUES(source_repo),
 			close_reason = VALUES(close_reason),
-			metadata = VALUES(metadata)
+			metadata = VALUES(metadata),
+			severity = VALUES(severity)
 	`, table),
 		issue.ID, issue.ContentHash, issue.Title, issue.Description, issue.Design, issue.AcceptanceCriteria, issue.Notes,
 		string(issue.Status), issue.Priority, string(issue.IssueType), nullString(issue.Assignee), nullIntPtr(issue.EstimatedMinutes),
@@ -453,7 +455,7 @@ func insertIssueRow(ctx context.Context, runner Runner, table string, issue *typ
 		string(issue.MolType), string(issue.WorkType), issue.SourceSystem, issue.SourceRepo, issue.CloseReason,
 		issue.EventKind, issue.Actor, issue.Target, issue.Payload,
 		issue.AwaitType, issue.AwaitID, issue.Timeout.Nanoseconds(), formatJSONStringArray(issue.Waiters),
-		issue.DueAt, issue.DeferUntil, jsonMetadata(issue.Metadata),
+		issue.DueAt, issue.DeferUntil, jsonMetadata(issue.Metadata), string(issue.Severity),
 	)
 	if err != nil {
 		return fmt.Errorf("db: insert into %s: %w", table, err)
@@ -476,7 +478,7 @@ func scanIssue(s issueScanner) (*types.Issue, error) {
 	var sender, wispType, molType, eventKind, actorCol, target, payload sql.NullString
 	var awaitType, awaitID, waiters sql.NullString
 	var ephemeral, noHistory, pinned, isTemplate sql.NullInt64
-	var metadata sql.NullString
+	var metadata, severity sql.NullString
 	var createdAt, updatedAt sql.NullTime
 
 	if err := s.Scan(
@@ -490,7 +492,7 @@ func scanIssue(s issueScanner) (*types.Issue, error) {
 		&molType,
 		&eventKind, &actorCol, &target, &payload,
 		&dueAt, &deferUntil,
-		&workType, &sourceSystem, &metadata,
+		&workType, &sourceSystem, &metadata, &severity,
 	); err != nil {
 		return nil, err
 	}
@@ -529,6 +531,9 @@ func scanIssue(s issueScanner) (*types.Issue, error) {
 	if specID.Valid {
 		issue.SpecID = specID.String
 	}
+	if severity.Valid {
+		issue.Severity = types.Severity(severity.String)
+	}
 	if compactedAt.Valid {
 		issue.CompactedAt = &compactedAt.Time
 	}
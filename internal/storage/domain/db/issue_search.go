@@ -11,6 +11,7 @@ import (
 
 	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/storage/dberrors"
+	"github.com/steveyegge/beads/internal/storage/issueops"
 	"github.com/steveyegge/beads/internal/types"
 )
 
@@ -21,11 +22,12 @@ type filterTables struct {
 	Labels       string
 	Dependencies string
 	Comments     string
+	Events       string
 }
 
 var (
-	issuesFilterTables = filterTables{Main: "issues", Labels: "labels", Dependencies: "dependencies", Comments: "comments"}
-	wispsFilterTables  = filterTables{Main: "wisps", Labels: "wisp_labels", Dependencies: "wisp_dependencies", Comments: "wisp_comments"}
+	issuesFilterTables = filterTables{Main: "issues", Labels: "labels", Dependencies: "dependencies", Comments: "comments", Events: "events"}
+	wispsFilterTables  = filterTables{Main: "wisps", Labels: "wisp_labels", Dependencies: "wisp_dependencies", Comments: "wisp_comments", Events: "wisp_events"}
 )
 
 func (r *issueSQLRepositoryImpl) searchAcrossIssuesAndWisps(ctx context.Context, query string, filter types.IssueFilter) ([]*types.Issue, error) {
@@ -383,19 +385,17 @@ func buildLabelDrivenSearch(filter types.IssueFilter, tables filterTables) (stri
 	for i, label := range labels {
 		alias := fmt.Sprintf("label_filter_%d", i)
 		joins = append(joins, fmt.Sprintf("JOIN %s %s ON %s.issue_id = %s.id", tables.Labels, alias, alias, tables.Main))
-		where = append(where, fmt.Sprintf("%s.label = ?", alias))
-		args = append(args, label)
+		cond, arg := labelCondition(alias+".label", label)
+		where = append(where, cond)
+		args = append(args, arg)
 	}
 
 	if len(labelsAny) > 0 {
 		alias := "label_filter_any"
 		joins = append(joins, fmt.Sprintf("JOIN %s %s ON %s.issue_id = %s.id", tables.Labels, alias, alias, tables.Main))
-		placeholders := make([]string, len(labelsAny))
-		for i, label := range labelsAny {
-			placeholders[i] = "?"
-			args = append(args, label)
-		}
-		where = append(where, fmt.Sprintf("%s.label IN (%s)", alias, strings.Join(placeholders, ", ")))
+		cond, condArgs := labelSetCondition(alias+".label", labelsAny)
+		where = append(where, cond)
+		args = append(args, condArgs...)
 	}
 
 	return tables.Main + " " + strings.Join(joins, " "), where, args, true, filterForClauses
@@ -414,20 +414,50 @@ func compactNonEmptyStrings(values []string) []string {
 	return out
 }
 
+// assigneeMatchClause matches issues in mainTable whose primary assignee or,
+// for persistent issues, any co-assignee is assignee.
+func assigneeMatchClause(mainTable, assignee string) (string, []any) {
+	if mainTable != "issues" {
+		return "assignee = ?", []any{assignee}
+	}
+	return "(assignee = ? OR id IN (SELECT issue_id FROM assignees WHERE assignee = ?))", []any{assignee, assignee}
+}
+
+// assigneeExcludeClause matches issues in mainTable not assigned, as primary
+// or co-assignee, to any of assignees. Unassigned issues always match.
+func assigneeExcludeClause(mainTable string, assignees []string) (string, []any) {
+	placeholders := make([]string, len(assignees))
+	args := make([]any, 0, 2*len(assignees))
+	for i, assignee := range assignees {
+		placeholders[i] = "?"
+		args = append(args, assignee)
+	}
+	in := strings.Join(placeholders, ", ")
+	if mainTable != "issues" {
+		return fmt.Sprintf("COALESCE(assignee, '') NOT IN (%s)", in), args
+	}
+	args = append(args, args...)
+	return fmt.Sprintf("(COALESCE(assignee, '') NOT IN (%s) AND id NOT IN (SELECT issue_id FROM assignees WHERE assignee IN (%s)))", in, in), args
+}
+
 func buildIssueFilterClauses(query string, filter types.IssueFilter, tables filterTables) ([]string, []any, error) {
 	var whereClauses []string
 	var args []any
 
 	if query != "" {
 		lowerQuery := strings.ToLower(query)
+		var match []string
 		if looksLikeIssueID(query) {
-			whereClauses = append(whereClauses, "(id = ? OR id LIKE ? OR LOWER(title) LIKE ? OR LOWER(external_ref) LIKE ?)")
+			match = []string{"id = ?", "id LIKE ?", "LOWER(title) LIKE ?", "LOWER(external_ref) LIKE ?"}
 			args = append(args, lowerQuery, lowerQuery+"%", "%"+lowerQuery+"%", "%"+lowerQuery+"%")
 		} else {
-			whereClauses = append(whereClauses, "(LOWER(title) LIKE ? OR id LIKE ?)")
+			match = []string{"LOWER(title) LIKE ?", "id LIKE ?"}
 			pattern := "%" + lowerQuery + "%"
 			args = append(args, pattern, pattern)
 		}
+		inClauses, inArgs := searchInClauses("%"+lowerQuery+"%", filter.SearchIn, tables.Comments, tables.Events)
+		whereClauses = append(whereClauses, "("+strings.Join(append(match, inClauses...), " OR ")+")")
+		args = append(args, inArgs...)
 	}
 
 	if filter.TitleSearch != "" {
@@ -450,6 +480,17 @@ func buildIssueFilterClauses(query string, filter types.IssueFilter, tables filt
 		whereClauses = append(whereClauses, "LOWER(external_ref) LIKE ?")
 		args = append(args, "%"+strings.ToLower(filter.ExternalRefContains)+"%")
 	}
+	if err := issueops.ValidateRegexFilters(filter); err != nil {
+		return nil, nil, err
+	}
+	if filter.TitleRegex != "" {
+		whereClauses = append(whereClauses, "REGEXP_LIKE(title, ?, 'i')")
+		args = append(args, filter.TitleRegex)
+	}
+	if filter.DescriptionRegex != "" {
+		whereClauses = append(whereClauses, "REGEXP_LIKE(description, ?, 'i')")
+		args = append(args, filter.DescriptionRegex)
+	}
 
 	if filter.Status != nil {
 		whereClauses = append(whereClauses, "status = ?")
@@ -486,8 +527,14 @@ func buildIssueFilterClauses(query string, filter types.IssueFilter, tables filt
 	}
 
 	if filter.Assignee != nil {
-		whereClauses = append(whereClauses, "assignee = ?")
-		args = append(args, *filter.Assignee)
+		clause, assigneeArgs := assigneeMatchClause(tables.Main, *filter.Assignee)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, assigneeArgs...)
+	}
+	if len(filter.ExcludeAssignees) > 0 {
+		clause, assigneeArgs := assigneeExcludeClause(tables.Main, filter.ExcludeAssignees)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, assigneeArgs...)
 	}
 
 	if filter.Priority != nil {
@@ -502,6 +549,14 @@ func buildIssueFilterClauses(query string, filter types.IssueFilter, tables filt
 		whereClauses = append(whereClauses, "priority <= ?")
 		args = append(args, *filter.PriorityMax)
 	}
+	if len(filter.Severities) > 0 {
+		placeholders := make([]string, len(filter.Severities))
+		for i, sev := range filter.Severities {
+			placeholders[i] = "?"
+			args = append(args, string(sev))
+		}
+		whereClauses = append(whereClauses, fmt.Sprintf("severity IN (%s)", strings.Join(placeholders, ",")))
+	}
 
 	if len(filter.IDs) > 0 {
 		placeholders := make([]string, len(filter.IDs))
@@ -511,6 +566,14 @@ func buildIssueFilterClauses(query string, filter types.IssueFilter, tables filt
 		}
 		whereClauses = append(whereClauses, fmt.Sprintf("id IN (%s)", strings.Join(placeholders, ", ")))
 	}
+	if len(filter.ExcludeIDs) > 0 {
+		placeholders := make([]string, len(filter.ExcludeIDs))
+		for i, id := range filter.ExcludeIDs {
+			placeholders[i] = "?"
+			args = append(args, id)
+		}
+		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (%s)", strings.Join(placeholders, ", ")))
+	}
 	if filter.IDPrefix != "" {
 		whereClauses = append(whereClauses, "id LIKE ?")
 		args = append(args, filter.IDPrefix+"%")
@@ -540,25 +603,20 @@ func buildIssueFilterClauses(query string, filter types.IssueFilter, tables filt
 
 	if len(filter.Labels) > 0 {
 		for _, label := range filter.Labels {
-			whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE label = ?)", tables.Labels))
-			args = append(args, label)
+			cond, arg := labelCondition("label", label)
+			whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE %s)", tables.Labels, cond))
+			args = append(args, arg)
 		}
 	}
 	if len(filter.LabelsAny) > 0 {
-		placeholders := make([]string, len(filter.LabelsAny))
-		for i, label := range filter.LabelsAny {
-			placeholders[i] = "?"
-			args = append(args, label)
-		}
-		whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE label IN (%s))", tables.Labels, strings.Join(placeholders, ", ")))
+		cond, condArgs := labelSetCondition("label", filter.LabelsAny)
+		whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE %s)", tables.Labels, cond))
+		args = append(args, condArgs...)
 	}
 	if len(filter.ExcludeLabels) > 0 {
-		placeholders := make([]string, len(filter.ExcludeLabels))
-		for i, label := range filter.ExcludeLabels {
-			placeholders[i] = "?"
-			args = append(args, label)
-		}
-		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (SELECT issue_id FROM %s WHERE label IN (%s))", tables.Labels, strings.Join(placeholders, ", ")))
+		cond, condArgs := labelSetCondition("label", filter.ExcludeLabels)
+		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (SELECT issue_id FROM %s WHERE %s)", tables.Labels, cond))
+		args = append(args, condArgs...)
 	}
 	if filter.NoLabels {
 		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (SELECT DISTINCT issue_id FROM %s)", tables.Labels))
@@ -676,6 +734,14 @@ func buildIssueFilterClauses(query string, filter types.IssueFilter, tables filt
 			args = append(args, storage.JSONMetadataPath(k), filter.MetadataFields[k])
 		}
 	}
+	if len(filter.CustomFields) > 0 {
+		clauses, fieldArgs, err := storage.CustomFieldClauses(filter.CustomFields)
+		if err != nil {
+			return nil, nil, err
+		}
+		whereClauses = append(whereClauses, clauses...)
+		args = append(args, fieldArgs...)
+	}
 
 	return whereClauses, args, nil
 }
@@ -695,3 +761,61 @@ func looksLikeIssueID(query string) bool {
 	}
 	return true
 }
+
+// searchInClauses returns extra OR terms that match a LIKE pattern against the
+// text sources named in searchIn: comment bodies for "comments", and event
+// text plus the close reason for "events".
+func searchInClauses(pattern string, searchIn []string, commentsTable, eventsTable string) ([]string, []any) {
+	var clauses []string
+	var args []any
+	for _, source := range searchIn {
+		switch source {
+		case "comments":
+			clauses = append(clauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE LOWER(text) LIKE ?)", commentsTable))
+			args = append(args, pattern)
+		case "events":
+			clauses = append(clauses,
+				fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE LOWER(comment) LIKE ? OR LOWER(old_value) LIKE ? OR LOWER(new_value) LIKE ?)", eventsTable),
+				"LOWER(close_reason) LIKE ?")
+			args = append(args, pattern, pattern, pattern, pattern)
+		}
+	}
+	return clauses, args
+}
+
+// likeEscaper escapes LIKE metacharacters so a label prefix matches literally.
+var likeEscaper = strings.NewReplacer(`\`, `\\`, `%`, `\%`, `_`, `\_`)
+
+// labelCondition returns a SQL condition matching column against a single
+// label filter. A trailing "*" matches every label with that prefix.
+func labelCondition(column, label string) (string, any) {
+	if prefix, ok := strings.CutSuffix(label, "*"); ok {
+		return column + " LIKE ?", likeEscaper.Replace(prefix) + "%"
+	}
+	return column + " = ?", label
+}
+
+// labelSetCondition returns a SQL condition matching column against any of
+// labels, honouring trailing "*" wildcards like labelCondition.
+func labelSetCondition(column string, labels []string) (string, []any) {
+	var exact, conds []string
+	var exactArgs, args []any
+	for _, label := range labels {
+		if strings.HasSuffix(label, "*") {
+			cond, arg := labelCondition(column, label)
+			conds = append(conds, cond)
+			args = append(args, arg)
+			continue
+		}
+		exact = append(exact, "?")
+		exactArgs = append(exactArgs, label)
+	}
+	if len(exact) > 0 {
+		conds = append([]string{fmt.Sprintf("%s IN (%s)", column, strings.Join(exact, ", "))}, conds...)
+		args = append(exactArgs, args...)
+	}
+	if len(conds) == 1 {
+		return conds[0], args
+	}
+	return "(" + strings.Join(conds, " OR ") + ")", args
+}
@@ -947,59 +947,62 @@ func (u *issueUseCaseImpl) resolveTopLevelPrefix(ctx context.Context, issue *typ
 }
 
 // mintTopLevelID generates a fresh top-level ID for an issue that has no
-// ExplicitID and no ParentID. Honors counter mode for non-wisps (config key
-// issue_id_mode=counter); otherwise uses adaptive hash-mode IDs that mirror
-// issueops.GenerateIssueIDInTable. Reads issue.CreatedAt (caller must have
-// stabilized it before this call so retries hash the same value).
+// ExplicitID and no ParentID, with the strategy for the configured
+// issue_id_mode, mirroring issueops.GenerateIssueIDInTable. Reads
+// issue.CreatedAt (caller must have stabilized it before this call so
+// retries hash the same value).
 func (u *issueUseCaseImpl) mintTopLevelID(ctx context.Context, issue *types.Issue, actor string, useWisp bool) (string, error) {
 	prefix, err := u.resolveTopLevelPrefix(ctx, issue, useWisp)
 	if err != nil {
 		return "", err
 	}
 
-	// Counter mode applies only to the issues table — wisps always hash-mint
-	// because there is no wisp_counter table and ephemeral churn would make
-	// a monotonic counter meaningless.
+	// The configured scheme applies only to the issues table — wisps always
+	// hash-mint because there is no wisp_counter table and ephemeral churn
+	// would make a monotonic counter meaningless.
+	scheme := idgen.SchemeHash
 	if !useWisp {
 		mode, err := u.cfgRepo.GetConfig(ctx, "issue_id_mode")
 		if err != nil {
 			return "", fmt.Errorf("read issue_id_mode: %w", err)
 		}
-		if mode == "counter" {
-			n, err := u.issueRepo.NextCounterID(ctx, prefix)
-			if err != nil {
-				return "", err
-			}
-			return fmt.Sprintf("%s-%d", prefix, n), nil
+		if parsed, err := idgen.ParseScheme(mode); err == nil {
+			scheme = parsed
 		}
 	}
 
-	cfg, err := u.cfgRepo.GetAdaptiveIDConfig(ctx)
-	if err != nil {
-		return "", fmt.Errorf("read adaptive id config: %w", err)
-	}
-	tableOpts := IssueTableOpts{UseWispsTable: useWisp}
+	st := &repoIDStore{u: u, tableOpts: IssueTableOpts{UseWispsTable: useWisp}}
+	return scheme.Strategy().NewID(ctx, st, idgen.Request{
+		Prefix:      prefix,
+		Title:       issue.Title,
+		Description: issue.Description,
+		Actor:       actor,
+		CreatedAt:   issue.CreatedAt,
+	})
+}
 
-	count, err := u.issueRepo.CountForPrefix(ctx, prefix, tableOpts)
+// repoIDStore gives an idgen.Strategy access to the issue repository.
+type repoIDStore struct {
+	u         *issueUseCaseImpl
+	tableOpts IssueTableOpts
+}
+
+func (s *repoIDStore) Exists(ctx context.Context, id string) (bool, error) {
+	return s.u.issueRepo.Exists(ctx, id, s.tableOpts)
+}
+
+func (s *repoIDStore) NextCounter(ctx context.Context, key string) (int, error) {
+	return s.u.issueRepo.NextCounterID(ctx, key)
+}
+
+func (s *repoIDStore) HashLengths(ctx context.Context, prefix string) (int, int, error) {
+	cfg, err := s.u.cfgRepo.GetAdaptiveIDConfig(ctx)
 	if err != nil {
-		return "", err
-	}
-	baseLength := ComputeAdaptiveLength(count, cfg)
-	if baseLength > cfg.MaxLength {
-		baseLength = cfg.MaxLength
+		return 0, 0, fmt.Errorf("read adaptive id config: %w", err)
 	}
-
-	for length := baseLength; length <= cfg.MaxLength; length++ {
-		for nonce := 0; nonce < 10; nonce++ {
-			candidate := idgen.GenerateHashID(prefix, issue.Title, issue.Description, actor, issue.CreatedAt, length, nonce)
-			exists, err := u.issueRepo.Exists(ctx, candidate, tableOpts)
-			if err != nil {
-				return "", err
-			}
-			if !exists {
-				return candidate, nil
-			}
-		}
+	count, err := s.u.issueRepo.CountForPrefix(ctx, prefix, s.tableOpts)
+	if err != nil {
+		return 0, 0, err
 	}
-	return "", fmt.Errorf("failed to generate unique ID for prefix %q after lengths %d..%d with 10 nonces each", prefix, baseLength, cfg.MaxLength)
+	return ComputeAdaptiveLength(count, cfg), cfg.MaxLength, nil
 }
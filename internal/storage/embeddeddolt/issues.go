@@ -7,6 +7,7 @@ import (
 	"database/sql"
 	"encoding/json"
 	"fmt"
+	"time"
 
 	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/storage/issueops"
@@ -16,12 +17,29 @@ import (
 // ClaimIssue atomically claims an issue using compare-and-swap semantics.
 // Delegates SQL work to issueops; EmbeddedDolt auto-commits the transaction.
 func (s *EmbeddedDoltStore) ClaimIssue(ctx context.Context, id string, actor string) error {
+	return s.ClaimIssueWithLease(ctx, id, actor, 0)
+}
+
+// ClaimIssueWithLease claims an issue, recording a claim lease that lapses
+// after lease when it is positive.
+func (s *EmbeddedDoltStore) ClaimIssueWithLease(ctx context.Context, id string, actor string, lease time.Duration) error {
 	return s.withConn(ctx, true, func(tx *sql.Tx) error {
-		_, err := issueops.ClaimIssueInTx(ctx, tx, id, actor)
+		_, err := issueops.ClaimIssueWithLeaseInTx(ctx, tx, id, actor, lease)
 		return err
 	})
 }
 
+// GetClaimLeases retrieves claim leases on multiple issues.
+func (s *EmbeddedDoltStore) GetClaimLeases(ctx context.Context, issueIDs []string) (map[string]*types.ClaimLease, error) {
+	var result map[string]*types.ClaimLease
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetClaimLeasesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
 // ClaimReadyIssue atomically claims the first ready issue matching filter.
 func (s *EmbeddedDoltStore) ClaimReadyIssue(ctx context.Context, filter types.WorkFilter, actor string) (*types.Issue, error) {
 	var claimed *types.Issue
@@ -0,0 +1,77 @@
+//go:build cgo
+
+package embeddeddolt
+
+import (
+	"context"
+	"database/sql"
+
+	"github.com/steveyegge/beads/internal/storage/issueops"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// ---------------------------------------------------------------------------
+// storage.SessionStore
+// ---------------------------------------------------------------------------
+
+func (s *EmbeddedDoltStore) StartSession(ctx context.Context, session *types.Session) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.StartSessionInTx(ctx, tx, session)
+	})
+}
+
+func (s *EmbeddedDoltStore) EndSession(ctx context.Context, actor, note string) (*types.Session, error) {
+	var result *types.Session
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.EndSessionInTx(ctx, tx, actor, note)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) GetOpenSession(ctx context.Context, actor string) (*types.Session, error) {
+	var result *types.Session
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetOpenSessionInTx(ctx, tx, actor)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) RecordSessionCommand(ctx context.Context, command *types.SessionCommand) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.RecordSessionCommandInTx(ctx, tx, command)
+	})
+}
+
+func (s *EmbeddedDoltStore) ListSessions(ctx context.Context, actor string, limit int) ([]*types.Session, error) {
+	var result []*types.Session
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.ListSessionsInTx(ctx, tx, actor, limit)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) GetSession(ctx context.Context, id string) (*types.Session, error) {
+	var result *types.Session
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetSessionInTx(ctx, tx, id)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) GetSessionEvents(ctx context.Context, session *types.Session) ([]*types.Event, error) {
+	var result []*types.Event
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetSessionEventsInTx(ctx, tx, session)
+		return err
+	})
+	return result, err
+}
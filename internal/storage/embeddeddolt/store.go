@@ -144,6 +144,19 @@ func (s *EmbeddedDoltStore) withConn(ctx context.Context, commit bool, fn func(t
 }
 
 func (s *EmbeddedDoltStore) ApplySchemaMigrations(ctx context.Context) (int, error) {
+	return s.applySchemaMigrations(ctx, schema.MigrateUp)
+}
+
+// ApplySchemaMigrationsTo applies the pending migrations up to version
+// target. Opening the store already migrates to the latest version, so
+// only the latest is normally reachable.
+func (s *EmbeddedDoltStore) ApplySchemaMigrationsTo(ctx context.Context, target int) (int, error) {
+	return s.applySchemaMigrations(ctx, func(ctx context.Context, db schema.DBConn) (int, error) {
+		return schema.MigrateUpTo(ctx, db, target)
+	})
+}
+
+func (s *EmbeddedDoltStore) applySchemaMigrations(ctx context.Context, migrate func(context.Context, schema.DBConn) (int, error)) (int, error) {
 	if s.closed.Load() {
 		return 0, errClosed
 	}
@@ -159,7 +172,7 @@ func (s *EmbeddedDoltStore) ApplySchemaMigrations(ctx context.Context) (int, err
 	}
 	defer conn.Close()
 
-	return schema.MigrateUp(ctx, conn)
+	return migrate(ctx, conn)
 }
 
 func (s *EmbeddedDoltStore) initSchema(ctx context.Context) error {
@@ -321,6 +334,36 @@ func (s *EmbeddedDoltStore) AddIssueComment(ctx context.Context, issueID, author
 	return result, err
 }
 
+func (s *EmbeddedDoltStore) GetComment(ctx context.Context, commentID string) (*types.Comment, error) {
+	var result *types.Comment
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetCommentInTx(ctx, tx, commentID)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) EditComment(ctx context.Context, commentID, text, actor string) (*types.Comment, error) {
+	var result *types.Comment
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.EditCommentInTx(ctx, tx, commentID, text, actor)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) DeleteComment(ctx context.Context, commentID, actor string) (*types.Comment, error) {
+	var result *types.Comment
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.DeleteCommentInTx(ctx, tx, commentID, actor)
+		return err
+	})
+	return result, err
+}
+
 func (s *EmbeddedDoltStore) GetIssueComments(ctx context.Context, issueID string) ([]*types.Comment, error) {
 	var result []*types.Comment
 	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
@@ -678,6 +721,166 @@ func (s *EmbeddedDoltStore) GetCommentsForIssues(ctx context.Context, issueIDs [
 	return result, err
 }
 
+func (s *EmbeddedDoltStore) AddCodeRef(ctx context.Context, ref *types.CodeRef) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.AddCodeRefInTx(ctx, tx, ref)
+	})
+}
+
+func (s *EmbeddedDoltStore) RemoveCodeRefs(ctx context.Context, issueID, path string, lineStart, lineEnd int) (int64, error) {
+	var removed int64
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		removed, err = issueops.RemoveCodeRefsInTx(ctx, tx, issueID, path, lineStart, lineEnd)
+		return err
+	})
+	return removed, err
+}
+
+func (s *EmbeddedDoltStore) GetCodeRefsForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.CodeRef, error) {
+	var result map[string][]*types.CodeRef
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetCodeRefsForIssuesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) AddWorkLog(ctx context.Context, entry *types.WorkLog) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.AddWorkLogInTx(ctx, tx, entry)
+	})
+}
+
+func (s *EmbeddedDoltStore) GetWorkLogForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.WorkLog, error) {
+	var result map[string][]*types.WorkLog
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetWorkLogForIssuesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) GetWorkLogTotals(ctx context.Context) ([]*types.WorkLogTotal, error) {
+	var result []*types.WorkLogTotal
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetWorkLogTotalsInTx(ctx, tx)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) AddTodo(ctx context.Context, todo *types.TodoItem) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.AddTodoInTx(ctx, tx, todo)
+	})
+}
+
+func (s *EmbeddedDoltStore) SetTodoDone(ctx context.Context, issueID string, seq int, done bool) (*types.TodoItem, error) {
+	var todo *types.TodoItem
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		todo, err = issueops.SetTodoDoneInTx(ctx, tx, issueID, seq, done)
+		return err
+	})
+	return todo, err
+}
+
+func (s *EmbeddedDoltStore) RemoveTodo(ctx context.Context, issueID string, seq int) (bool, error) {
+	var removed bool
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		removed, err = issueops.RemoveTodoInTx(ctx, tx, issueID, seq)
+		return err
+	})
+	return removed, err
+}
+
+func (s *EmbeddedDoltStore) GetTodosForIssues(ctx context.Context, issueIDs []string) (map[string][]*types.TodoItem, error) {
+	var result map[string][]*types.TodoItem
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetTodosForIssuesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) AddAssignee(ctx context.Context, issueID, assignee, actor string) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.AddAssigneeInTx(ctx, tx, issueID, assignee, actor)
+	})
+}
+
+func (s *EmbeddedDoltStore) RemoveAssignee(ctx context.Context, issueID, assignee, actor string) (bool, error) {
+	var removed bool
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		removed, err = issueops.RemoveAssigneeInTx(ctx, tx, issueID, assignee, actor)
+		return err
+	})
+	return removed, err
+}
+
+func (s *EmbeddedDoltStore) GetAssigneesForIssues(ctx context.Context, issueIDs []string) (map[string][]string, error) {
+	var result map[string][]string
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetAssigneesForIssuesInTx(ctx, tx, issueIDs)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) WatchIssue(ctx context.Context, issueID, actor string) (bool, error) {
+	var added bool
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		added, err = issueops.WatchIssueInTx(ctx, tx, issueID, actor)
+		return err
+	})
+	return added, err
+}
+
+func (s *EmbeddedDoltStore) UnwatchIssue(ctx context.Context, issueID, actor string) (bool, error) {
+	var removed bool
+	err := s.withConn(ctx, true, func(tx *sql.Tx) error {
+		var err error
+		removed, err = issueops.UnwatchIssueInTx(ctx, tx, issueID, actor)
+		return err
+	})
+	return removed, err
+}
+
+func (s *EmbeddedDoltStore) GetWatchedIssueIDs(ctx context.Context, actor string) ([]string, error) {
+	var ids []string
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		ids, err = issueops.GetWatchedIssueIDsInTx(ctx, tx, actor)
+		return err
+	})
+	return ids, err
+}
+
+func (s *EmbeddedDoltStore) GetInbox(ctx context.Context, actor string) ([]*types.Event, error) {
+	var events []*types.Event
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		events, err = issueops.GetInboxInTx(ctx, tx, actor)
+		return err
+	})
+	return events, err
+}
+
+func (s *EmbeddedDoltStore) MarkInboxRead(ctx context.Context, actor string, through time.Time) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.MarkInboxReadInTx(ctx, tx, actor, through)
+	})
+}
+
 // ---------------------------------------------------------------------------
 // storage.ConfigMetadataStore
 // ---------------------------------------------------------------------------
@@ -71,6 +71,7 @@ func (t *embeddedTransaction) CreateIssues(ctx context.Context, issues []*types.
 
 func (t *embeddedTransaction) UpdateIssue(ctx context.Context, id string, updates map[string]interface{}, actor string) error {
 	t.dirty.MarkDirty("issues")
+	t.dirty.MarkDirty("claim_leases")
 	t.dirty.MarkDirty("events")
 	_, err := issueops.UpdateIssueInTx(ctx, t.tx, id, updates, actor)
 	return err
@@ -78,6 +79,7 @@ func (t *embeddedTransaction) UpdateIssue(ctx context.Context, id string, update
 
 func (t *embeddedTransaction) CloseIssue(ctx context.Context, id string, reason string, actor string, session string) error {
 	t.dirty.MarkDirty("issues")
+	t.dirty.MarkDirty("claim_leases")
 	t.dirty.MarkDirty("events")
 	_, err := issueops.CloseIssueInTx(ctx, t.tx, id, reason, actor, session)
 	return err
@@ -88,10 +90,23 @@ func (t *embeddedTransaction) DeleteIssue(ctx context.Context, id string) error
 	t.dirty.MarkDirty("dependencies")
 	t.dirty.MarkDirty("labels")
 	t.dirty.MarkDirty("comments")
+	t.dirty.MarkDirty("code_refs")
+	t.dirty.MarkDirty("work_log")
+	t.dirty.MarkDirty("todos")
+	t.dirty.MarkDirty("assignees")
+	t.dirty.MarkDirty("watchers")
+	t.dirty.MarkDirty("claim_leases")
 	t.dirty.MarkDirty("events")
 	return issueops.DeleteIssueInTx(ctx, t.tx, id)
 }
 
+func (t *embeddedTransaction) DeleteIssues(ctx context.Context, ids []string, cascade bool, force bool) (*types.DeleteIssuesResult, error) {
+	for _, table := range []string{"issues", "dependencies", "labels", "comments", "code_refs", "work_log", "todos", "assignees", "watchers", "claim_leases", "events", "child_counters", "issue_snapshots", "compaction_snapshots"} {
+		t.dirty.MarkDirty(table)
+	}
+	return issueops.DeleteIssuesInTx(ctx, t.tx, ids, cascade, force, false)
+}
+
 func (t *embeddedTransaction) GetIssue(ctx context.Context, id string) (*types.Issue, error) {
 	return issueops.GetIssueInTx(ctx, t.tx, id)
 }
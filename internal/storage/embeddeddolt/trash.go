@@ -0,0 +1,47 @@
+//go:build cgo
+
+package embeddeddolt
+
+import (
+	"context"
+	"database/sql"
+
+	"github.com/steveyegge/beads/internal/storage/issueops"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// ---------------------------------------------------------------------------
+// storage.TrashStore
+// ---------------------------------------------------------------------------
+
+func (s *EmbeddedDoltStore) PutTrash(ctx context.Context, entries []*types.TrashedIssue) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.PutTrashInTx(ctx, tx, entries)
+	})
+}
+
+func (s *EmbeddedDoltStore) GetTrash(ctx context.Context, id string) (*types.TrashedIssue, error) {
+	var result *types.TrashedIssue
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.GetTrashInTx(ctx, tx, id)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) ListTrash(ctx context.Context) ([]*types.TrashedIssue, error) {
+	var result []*types.TrashedIssue
+	err := s.withConn(ctx, false, func(tx *sql.Tx) error {
+		var err error
+		result, err = issueops.ListTrashInTx(ctx, tx)
+		return err
+	})
+	return result, err
+}
+
+func (s *EmbeddedDoltStore) RemoveTrash(ctx context.Context, ids []string) error {
+	return s.withConn(ctx, true, func(tx *sql.Tx) error {
+		return issueops.RemoveTrashInTx(ctx, tx, ids)
+	})
+}
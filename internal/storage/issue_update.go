@@ -0,0 +1,129 @@
+package storage
+
+import (
+	"context"
+	"encoding/json"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// IssueUpdate is a typed description of an issue update. It is the preferred
+// way for new callers to build updates: each field carries its real Go type,
+// so priorities stay ints and timestamps stay time.Time instead of being
+// assembled by hand into the column map accepted by UpdateIssue.
+//
+// Nil pointer fields are left unchanged. Fields that can be cleared have a
+// matching Clear* flag, because a nil pointer already means "don't touch".
+type IssueUpdate struct {
+	Title              *string
+	Description        *string
+	Design             *string
+	AcceptanceCriteria *string
+	Notes              *string
+	SpecID             *string
+
+	Status    *types.Status
+	Priority  *int
+	IssueType *types.IssueType
+	Severity  *types.Severity
+
+	Assignee         *string
+	EstimatedMinutes *int
+	ExternalRef      *string
+	CloseReason      *string
+
+	DueAt      *time.Time
+	DeferUntil *time.Time
+
+	// Metadata replaces the whole metadata JSON blob when non-nil.
+	Metadata json.RawMessage
+
+	ClearExternalRef bool
+	ClearDueAt       bool
+	ClearDeferUntil  bool
+}
+
+// IssueUpdater is the subset of Storage and Transaction needed to apply an
+// IssueUpdate, so the same helper works inside and outside a transaction.
+type IssueUpdater interface {
+	UpdateIssue(ctx context.Context, id string, updates map[string]interface{}, actor string) error
+}
+
+// IsEmpty reports whether the update would change nothing.
+func (u IssueUpdate) IsEmpty() bool {
+	return len(u.Fields()) == 0
+}
+
+// Fields converts the update into the column map understood by
+// UpdateIssue. Only allow-listed column names are produced, and values are
+// passed through as typed SQL parameters.
+func (u IssueUpdate) Fields() map[string]interface{} {
+	fields := make(map[string]interface{})
+	setString := func(key string, v *string) {
+		if v != nil {
+			fields[key] = *v
+		}
+	}
+
+	setString("title", u.Title)
+	setString("description", u.Description)
+	setString("design", u.Design)
+	setString("acceptance_criteria", u.AcceptanceCriteria)
+	setString("notes", u.Notes)
+	setString("spec_id", u.SpecID)
+	setString("assignee", u.Assignee)
+	setString("close_reason", u.CloseReason)
+
+	if u.Status != nil {
+		fields["status"] = string(*u.Status)
+	}
+	if u.Severity != nil {
+		fields["severity"] = string(*u.Severity)
+	}
+	if u.Priority != nil {
+		fields["priority"] = *u.Priority
+	}
+	if u.IssueType != nil {
+		// The update path validates issue_type only when it is a plain string.
+		fields["issue_type"] = string(*u.IssueType)
+	}
+	if u.EstimatedMinutes != nil {
+		fields["estimated_minutes"] = *u.EstimatedMinutes
+	}
+
+	switch {
+	case u.ClearExternalRef:
+		fields["external_ref"] = nil
+	case u.ExternalRef != nil:
+		fields["external_ref"] = *u.ExternalRef
+	}
+	switch {
+	case u.ClearDueAt:
+		fields["due_at"] = nil
+	case u.DueAt != nil:
+		fields["due_at"] = u.DueAt.UTC()
+	}
+	switch {
+	case u.ClearDeferUntil:
+		fields["defer_until"] = nil
+	case u.DeferUntil != nil:
+		fields["defer_until"] = u.DeferUntil.UTC()
+	}
+
+	if u.Metadata != nil {
+		fields["metadata"] = u.Metadata
+	}
+	return fields
+}
+
+// ApplyIssueUpdate applies a typed update through s in a single UPDATE.
+// The storage layer captures the previous row for the audit event, exactly
+// as it does for map-based updates. An empty update is a no-op.
+func ApplyIssueUpdate(ctx context.Context, s IssueUpdater, id string, u IssueUpdate, actor string) error {
+	fields := u.Fields()
+	if len(fields) == 0 {
+		return nil
+	}
+	return s.UpdateIssue(ctx, id, fields, actor)
+}
@@ -0,0 +1,98 @@
+package storage
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+type recordingUpdater struct {
+	calls   int
+	id      string
+	updates map[string]interface{}
+}
+
+func (r *recordingUpdater) UpdateIssue(_ context.Context, id string, updates map[string]interface{}, _ string) error {
+	r.calls++
+	r.id = id
+	r.updates = updates
+	return nil
+}
+
+func TestIssueUpdateFields(t *testing.T) {
+	title := "New title"
+	priority := 0
+	status := types.StatusInProgress
+	issueType := types.TypeBug
+	due := time.Date(2026, 7, 1, 12, 0, 0, 0, time.FixedZone("PDT", -7*3600))
+
+	fields := IssueUpdate{
+		Title:     &title,
+		Priority:  &priority,
+		Status:    &status,
+		IssueType: &issueType,
+		DueAt:     &due,
+	}.Fields()
+
+	if len(fields) != 5 {
+		t.Fatalf("expected 5 fields, got %d: %v", len(fields), fields)
+	}
+	if fields["title"] != "New title" {
+		t.Errorf("title = %v", fields["title"])
+	}
+	if fields["priority"] != 0 {
+		t.Errorf("priority = %v, want int 0 (P0 must not be dropped)", fields["priority"])
+	}
+	if fields["status"] != "in_progress" {
+		t.Errorf("status = %v", fields["status"])
+	}
+	if fields["issue_type"] != "bug" {
+		t.Errorf("issue_type = %#v, want plain string", fields["issue_type"])
+	}
+	if got, ok := fields["due_at"].(time.Time); !ok || got.Location() != time.UTC || !got.Equal(due) {
+		t.Errorf("due_at = %v, want %v in UTC", fields["due_at"], due)
+	}
+}
+
+func TestIssueUpdateClearFlags(t *testing.T) {
+	ref := "gh-9"
+	fields := IssueUpdate{
+		ExternalRef:      &ref,
+		ClearExternalRef: true,
+		ClearDueAt:       true,
+		ClearDeferUntil:  true,
+	}.Fields()
+
+	for _, key := range []string{"external_ref", "due_at", "defer_until"} {
+		v, ok := fields[key]
+		if !ok {
+			t.Errorf("%s missing from fields", key)
+			continue
+		}
+		if v != nil {
+			t.Errorf("%s = %v, want nil (clear wins over set)", key, v)
+		}
+	}
+}
+
+func TestApplyIssueUpdate(t *testing.T) {
+	ctx := context.Background()
+	rec := &recordingUpdater{}
+
+	if err := ApplyIssueUpdate(ctx, rec, "bd-1", IssueUpdate{}, "tester"); err != nil {
+		t.Fatalf("empty update: %v", err)
+	}
+	if rec.calls != 0 {
+		t.Fatalf("empty update should not reach storage, got %d calls", rec.calls)
+	}
+
+	assignee := "alice"
+	if err := ApplyIssueUpdate(ctx, rec, "bd-1", IssueUpdate{Assignee: &assignee}, "tester"); err != nil {
+		t.Fatalf("apply: %v", err)
+	}
+	if rec.calls != 1 || rec.id != "bd-1" || rec.updates["assignee"] != "alice" {
+		t.Errorf("unexpected call: calls=%d id=%s updates=%v", rec.calls, rec.id, rec.updates)
+	}
+}
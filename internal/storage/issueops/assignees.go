@@ -0,0 +1,146 @@
+package issueops
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// AddAssigneeInTx adds a co-assignee to an issue and records an event
+// within an existing transaction. Adding someone already on the issue is a
+// no-op. Wisps only have their primary assignee.
+func AddAssigneeInTx(ctx context.Context, tx *sql.Tx, issueID, assignee, actor string) error {
+	var exists bool
+	if err := tx.QueryRowContext(ctx,
+		`SELECT EXISTS(SELECT 1 FROM issues WHERE id = ?)`, issueID).Scan(&exists); err != nil {
+		return fmt.Errorf("check issue existence: %w", err)
+	}
+	if !exists {
+		if IsActiveWispInTx(ctx, tx, issueID) {
+			return fmt.Errorf("%s is a wisp: co-assignees are only kept on persistent issues", issueID)
+		}
+		return fmt.Errorf("issue %s not found", issueID)
+	}
+	res, err := tx.ExecContext(ctx, `INSERT IGNORE INTO assignees (issue_id, assignee) VALUES (?, ?)`, issueID, assignee)
+	if err != nil {
+		return fmt.Errorf("add assignee: %w", err)
+	}
+	if n, _ := res.RowsAffected(); n == 0 {
+		return nil
+	}
+	if _, err := tx.ExecContext(ctx, `INSERT INTO events (issue_id, event_type, actor, comment) VALUES (?, ?, ?, ?)`,
+		issueID, types.EventAssigneeAdded, actor, "Added assignee: "+assignee); err != nil {
+		return fmt.Errorf("add assignee: record event: %w", err)
+	}
+	return nil
+}
+
+// RemoveAssigneeInTx removes a co-assignee from an issue and records an
+// event within an existing transaction. It reports whether anyone was
+// removed.
+func RemoveAssigneeInTx(ctx context.Context, tx *sql.Tx, issueID, assignee, actor string) (bool, error) {
+	res, err := tx.ExecContext(ctx, `DELETE FROM assignees WHERE issue_id = ? AND assignee = ?`, issueID, assignee)
+	if err != nil {
+		return false, fmt.Errorf("remove assignee: %w", err)
+	}
+	if n, _ := res.RowsAffected(); n == 0 {
+		return false, nil
+	}
+	if _, err := tx.ExecContext(ctx, `INSERT INTO events (issue_id, event_type, actor, comment) VALUES (?, ?, ?, ?)`,
+		issueID, types.EventAssigneeRemoved, actor, "Removed assignee: "+assignee); err != nil {
+		return false, fmt.Errorf("remove assignee: record event: %w", err)
+	}
+	return true, nil
+}
+
+// GetAssigneesForIssuesInTx fetches co-assignees for multiple issues,
+// sorted by name, using batched IN clauses.
+func GetAssigneesForIssuesInTx(ctx context.Context, tx *sql.Tx, issueIDs []string) (map[string][]string, error) {
+	result := make(map[string][]string)
+	for start := 0; start < len(issueIDs); start += queryBatchSize {
+		end := start + queryBatchSize
+		if end > len(issueIDs) {
+			end = len(issueIDs)
+		}
+		batch := issueIDs[start:end]
+		placeholders := make([]string, len(batch))
+		args := make([]any, len(batch))
+		for i, id := range batch {
+			placeholders[i] = "?"
+			args[i] = id
+		}
+		//nolint:gosec // G201: only placeholders are interpolated
+		rows, err := tx.QueryContext(ctx, fmt.Sprintf(`
+			SELECT issue_id, assignee FROM assignees
+			WHERE issue_id IN (%s)
+			ORDER BY issue_id, assignee
+		`, strings.Join(placeholders, ",")), args...)
+		if err != nil {
+			return nil, fmt.Errorf("get assignees: %w", err)
+		}
+		for rows.Next() {
+			var issueID, assignee string
+			if err := rows.Scan(&issueID, &assignee); err != nil {
+				_ = rows.Close()
+				return nil, fmt.Errorf("get assignees: scan: %w", err)
+			}
+			result[issueID] = append(result[issueID], assignee)
+		}
+		_ = rows.Close()
+		if err := rows.Err(); err != nil {
+			return nil, fmt.Errorf("get assignees: rows: %w", err)
+		}
+	}
+	return result, nil
+}
+
+// PersistAssignees writes issue.Assignees during create/import, skipping
+// blanks and the primary assignee. Wisps carry none.
+func PersistAssignees(ctx context.Context, tx *sql.Tx, issue *types.Issue) (CreateIssueResult, error) {
+	var result CreateIssueResult
+	if len(issue.Assignees) == 0 || IsWisp(issue) {
+		return result, nil
+	}
+	for _, assignee := range issue.Assignees {
+		assignee = strings.TrimSpace(assignee)
+		if assignee == "" || assignee == issue.Assignee {
+			continue
+		}
+		if _, err := tx.ExecContext(ctx, `INSERT IGNORE INTO assignees (issue_id, assignee) VALUES (?, ?)`, issue.ID, assignee); err != nil {
+			return result, fmt.Errorf("add assignee %s to %s: %w", assignee, issue.ID, err)
+		}
+		result.markChanged("assignees")
+	}
+	return result, nil
+}
+
+// AssigneeMatchClause returns a WHERE fragment matching issues in mainTable
+// whose primary assignee or, for persistent issues, any co-assignee is
+// assignee.
+func AssigneeMatchClause(mainTable, assignee string) (string, []interface{}) {
+	if mainTable != "issues" {
+		return "assignee = ?", []interface{}{assignee}
+	}
+	return "(assignee = ? OR id IN (SELECT issue_id FROM assignees WHERE assignee = ?))", []interface{}{assignee, assignee}
+}
+
+// AssigneeExcludeClause returns a WHERE fragment matching issues in mainTable
+// that are not assigned, as primary or co-assignee, to any of assignees.
+// Unassigned issues always match.
+func AssigneeExcludeClause(mainTable string, assignees []string) (string, []interface{}) {
+	placeholders := make([]string, len(assignees))
+	args := make([]interface{}, 0, 2*len(assignees))
+	for i, assignee := range assignees {
+		placeholders[i] = "?"
+		args = append(args, assignee)
+	}
+	in := strings.Join(placeholders, ", ")
+	if mainTable != "issues" {
+		return fmt.Sprintf("COALESCE(assignee, '') NOT IN (%s)", in), args
+	}
+	args = append(args, args...)
+	return fmt.Sprintf("(COALESCE(assignee, '') NOT IN (%s) AND id NOT IN (SELECT issue_id FROM assignees WHERE assignee IN (%s)))", in, in), args
+}
@@ -239,6 +239,9 @@ func updateIssueIDInTx(ctx context.Context, tx *sql.Tx, oldID, newID string, iss
 	if err := UpdateIssueIDInDependenciesInTx(ctx, tx, oldID, newID); err != nil {
 		return err
 	}
+	if err := moveChildCounterInTx(ctx, tx, "child_counters", oldID, newID); err != nil {
+		return err
+	}
 
 	_, err = tx.ExecContext(ctx, `
 		INSERT INTO events (issue_id, event_type, actor, old_value, new_value)
@@ -267,10 +270,42 @@ func updateWispIDInTx(ctx context.Context, tx *sql.Tx, oldID, newID string, issu
 	`, newID, actor, oldID, newID); err != nil {
 		return err
 	}
+	if err := moveChildCounterInTx(ctx, tx, "wisp_child_counters", oldID, newID); err != nil {
+		return err
+	}
 
 	return UpdateWispIDInDependenciesInTx(ctx, tx, oldID, newID)
 }
 
+// moveChildCounterInTx carries a parent's child counter over to its new ID.
+// The counter tables have no FK to issues (migration 0039), so nothing
+// cascades on rename; without this the renamed parent would restart child
+// numbering at .1 and could reuse IDs handed out before the rename.
+//
+//nolint:gosec // G201: table is one of two hardcoded constants.
+func moveChildCounterInTx(ctx context.Context, tx *sql.Tx, table, oldID, newID string) error {
+	var lastChild int
+	err := tx.QueryRowContext(ctx,
+		fmt.Sprintf("SELECT last_child FROM %s WHERE parent_id = ?", table), oldID).Scan(&lastChild)
+	if err == sql.ErrNoRows {
+		return nil
+	}
+	if err != nil {
+		return fmt.Errorf("read child counter for %s: %w", oldID, err)
+	}
+	if _, err := tx.ExecContext(ctx, fmt.Sprintf(`
+		INSERT INTO %s (parent_id, last_child) VALUES (?, ?)
+		ON DUPLICATE KEY UPDATE last_child = GREATEST(last_child, ?)
+	`, table), newID, lastChild, lastChild); err != nil {
+		return fmt.Errorf("move child counter %s -> %s: %w", oldID, newID, err)
+	}
+	if _, err := tx.ExecContext(ctx,
+		fmt.Sprintf("DELETE FROM %s WHERE parent_id = ?", table), oldID); err != nil {
+		return fmt.Errorf("delete child counter for %s: %w", oldID, err)
+	}
+	return nil
+}
+
 // FindWispDependentsRecursiveInTx walks wisp_dependencies to find all transitive
 // dependents of the given IDs.
 func FindWispDependentsRecursiveInTx(ctx context.Context, tx *sql.Tx, ids []string) (map[string]bool, error) {
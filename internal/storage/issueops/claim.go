@@ -26,10 +26,22 @@ type ClaimResult struct {
 // success (supports agent retry workflows).
 // Routes to the correct table (issues/wisps) automatically.
 // The caller is responsible for Dolt versioning (DOLT_ADD/COMMIT) if needed.
+func ClaimIssueInTx(ctx context.Context, tx *sql.Tx, id string, actor string) (*ClaimResult, error) {
+	return ClaimIssueWithLeaseInTx(ctx, tx, id, actor, 0)
+}
+
+// ClaimIssueWithLeaseInTx claims an issue like ClaimIssueInTx and, when
+// lease is positive, records a claim lease that lapses after lease. An
+// in_progress issue whose holder's lease has lapsed can be claimed by
+// another actor. Claiming without a lease drops any earlier lease, and
+// re-claiming with one renews it. Only persistent issues take leases.
 //
 //nolint:gosec // G201: table names come from WispTableRouting (hardcoded constants)
-func ClaimIssueInTx(ctx context.Context, tx *sql.Tx, id string, actor string) (*ClaimResult, error) {
+func ClaimIssueWithLeaseInTx(ctx context.Context, tx *sql.Tx, id string, actor string, lease time.Duration) (*ClaimResult, error) {
 	isWisp := IsActiveWispInTx(ctx, tx, id)
+	if isWisp && lease > 0 {
+		return nil, fmt.Errorf("%s is a wisp: claim leases are only supported for persistent issues", id)
+	}
 	issueTable, _, eventTable, _ := WispTableRouting(isWisp)
 
 	// Read old issue inside the transaction for event recording.
@@ -85,12 +97,32 @@ func ClaimIssueInTx(ctx context.Context, tx *sql.Tx, id string, actor string) (*
 		// This supports agent retry workflows where claim may be called multiple
 		// times after transient failures (GH#8).
 		if assignee == actor && currentStatus == types.StatusInProgress {
+			if !isWisp {
+				if _, err := setClaimLeaseInTx(ctx, tx, id, actor, lease, now); err != nil {
+					return nil, err
+				}
+			}
 			return &ClaimResult{OldIssue: oldIssue, IsWisp: isWisp}, nil
 		}
-		if assignee != "" && assignee != actor {
+		if assignee == "" || assignee == actor {
+			return nil, fmt.Errorf("%w: status %s", storage.ErrNotClaimable, currentStatus)
+		}
+		lapsed := false
+		if currentStatus == types.StatusInProgress && !isWisp {
+			if lapsed, err = takeOverLapsedLeaseInTx(ctx, tx, id, assignee, actor, now); err != nil {
+				return nil, err
+			}
+		}
+		if !lapsed {
 			return nil, fmt.Errorf("%w by %s", storage.ErrAlreadyClaimed, assignee)
 		}
-		return nil, fmt.Errorf("%w: status %s", storage.ErrNotClaimable, currentStatus)
+	}
+
+	var expires *time.Time
+	if !isWisp {
+		if expires, err = setClaimLeaseInTx(ctx, tx, id, actor, lease, now); err != nil {
+			return nil, err
+		}
 	}
 
 	// Record the claim event.
@@ -99,6 +131,9 @@ func ClaimIssueInTx(ctx context.Context, tx *sql.Tx, id string, actor string) (*
 		"assignee": actor,
 		"status":   "in_progress",
 	}
+	if expires != nil {
+		newUpdates["lease_expires_at"] = expires.Format(time.RFC3339)
+	}
 	newData, _ := json.Marshal(newUpdates)
 
 	if err := RecordFullEventInTable(ctx, tx, eventTable, id, "claimed", actor, string(oldData), string(newData)); err != nil {
@@ -41,6 +41,18 @@ func closeIssueInTx(ctx context.Context, tx *sql.Tx, id string, reason, actor, s
 		return nil, fmt.Errorf("affected by close for %s: %w", id, aerr)
 	}
 
+	// Capture the pre-close status so the audit event records the transition
+	// (old_value) alongside the close reason (new_value).
+	var oldStatus string
+	if recordEvent {
+		err := tx.QueryRowContext(ctx,
+			fmt.Sprintf(`SELECT status FROM %s WHERE id = ?`, issueTable), id,
+		).Scan(&oldStatus)
+		if err != nil && err != sql.ErrNoRows {
+			return nil, fmt.Errorf("failed to read status before close: %w", err)
+		}
+	}
+
 	now := time.Now().UTC()
 
 	result, err := tx.ExecContext(ctx, fmt.Sprintf(`
@@ -73,7 +85,7 @@ func closeIssueInTx(ctx context.Context, tx *sql.Tx, id string, reason, actor, s
 	}
 
 	if recordEvent {
-		if err := RecordEventInTable(ctx, tx, eventTable, id, types.EventClosed, actor, reason); err != nil {
+		if err := RecordFullEventInTable(ctx, tx, eventTable, id, types.EventClosed, actor, oldStatus, reason); err != nil {
 			return nil, fmt.Errorf("failed to record event: %w", err)
 		}
 	}
@@ -82,5 +94,11 @@ func closeIssueInTx(ctx context.Context, tx *sql.Tx, id string, reason, actor, s
 		return nil, fmt.Errorf("recompute is_blocked after close for %s: %w", id, err)
 	}
 
+	if !isWisp {
+		if err := ReleaseStaleClaimLeaseInTx(ctx, tx, id); err != nil {
+			return nil, err
+		}
+	}
+
 	return &CloseResult{IsWisp: isWisp}, nil
 }
@@ -0,0 +1,123 @@
+package issueops
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// AddCodeRefInTx records a code reference on an issue within an existing
+// transaction. Adding a reference to a location the issue already points at
+// replaces its note. Wisps do not carry code references.
+func AddCodeRefInTx(ctx context.Context, tx *sql.Tx, ref *types.CodeRef) error {
+	var exists bool
+	if err := tx.QueryRowContext(ctx,
+		`SELECT EXISTS(SELECT 1 FROM issues WHERE id = ?)`, ref.IssueID).Scan(&exists); err != nil {
+		return fmt.Errorf("check issue existence: %w", err)
+	}
+	if !exists {
+		if IsActiveWispInTx(ctx, tx, ref.IssueID) {
+			return fmt.Errorf("%s is a wisp: code references are only kept on persistent issues", ref.IssueID)
+		}
+		return fmt.Errorf("issue %s not found", ref.IssueID)
+	}
+	return insertCodeRef(ctx, tx, ref)
+}
+
+func insertCodeRef(ctx context.Context, tx *sql.Tx, ref *types.CodeRef) error {
+	if ref.CreatedAt.IsZero() {
+		ref.CreatedAt = time.Now().UTC()
+	}
+	if _, err := tx.ExecContext(ctx, `
+		INSERT INTO code_refs (issue_id, path, line_start, line_end, note, created_by, created_at)
+		VALUES (?, ?, ?, ?, ?, ?, ?)
+		ON DUPLICATE KEY UPDATE note = VALUES(note)
+	`, ref.IssueID, ref.Path, ref.LineStart, ref.LineEnd, ref.Note, ref.CreatedBy, ref.CreatedAt.UTC()); err != nil {
+		return fmt.Errorf("add code reference %s to %s: %w", ref.String(), ref.IssueID, err)
+	}
+	return nil
+}
+
+// RemoveCodeRefsInTx removes an issue's references to path within an
+// existing transaction. With lineStart 0 every reference to the file goes;
+// otherwise only the one at exactly lineStart-lineEnd. Returns the number
+// of references removed.
+func RemoveCodeRefsInTx(ctx context.Context, tx *sql.Tx, issueID, path string, lineStart, lineEnd int) (int64, error) {
+	query := `DELETE FROM code_refs WHERE issue_id = ? AND path = ?`
+	args := []any{issueID, path}
+	if lineStart > 0 {
+		query += ` AND line_start = ? AND line_end = ?`
+		args = append(args, lineStart, lineEnd)
+	}
+	res, err := tx.ExecContext(ctx, query, args...)
+	if err != nil {
+		return 0, fmt.Errorf("remove code reference from %s: %w", issueID, err)
+	}
+	return res.RowsAffected()
+}
+
+// GetCodeRefsForIssuesInTx fetches code references for multiple issues,
+// ordered by path and line, using batched IN clauses.
+func GetCodeRefsForIssuesInTx(ctx context.Context, tx *sql.Tx, issueIDs []string) (map[string][]*types.CodeRef, error) {
+	result := make(map[string][]*types.CodeRef)
+	for start := 0; start < len(issueIDs); start += queryBatchSize {
+		end := start + queryBatchSize
+		if end > len(issueIDs) {
+			end = len(issueIDs)
+		}
+		batch := issueIDs[start:end]
+		placeholders := make([]string, len(batch))
+		args := make([]any, len(batch))
+		for i, id := range batch {
+			placeholders[i] = "?"
+			args[i] = id
+		}
+		//nolint:gosec // G201: only placeholders are interpolated
+		rows, err := tx.QueryContext(ctx, fmt.Sprintf(`
+			SELECT issue_id, path, line_start, line_end, note, created_by, created_at
+			FROM code_refs
+			WHERE issue_id IN (%s)
+			ORDER BY issue_id, path, line_start, line_end
+		`, strings.Join(placeholders, ",")), args...)
+		if err != nil {
+			return nil, fmt.Errorf("get code references: %w", err)
+		}
+		for rows.Next() {
+			var ref types.CodeRef
+			if err := rows.Scan(&ref.IssueID, &ref.Path, &ref.LineStart, &ref.LineEnd, &ref.Note, &ref.CreatedBy, &ref.CreatedAt); err != nil {
+				_ = rows.Close()
+				return nil, fmt.Errorf("get code references: scan: %w", err)
+			}
+			result[ref.IssueID] = append(result[ref.IssueID], &ref)
+		}
+		_ = rows.Close()
+		if err := rows.Err(); err != nil {
+			return nil, fmt.Errorf("get code references: rows: %w", err)
+		}
+	}
+	return result, nil
+}
+
+// PersistCodeRefs writes issue.CodeRefs during create/import. References
+// already present keep their creation time; wisps carry none.
+func PersistCodeRefs(ctx context.Context, tx *sql.Tx, issue *types.Issue) (CreateIssueResult, error) {
+	var result CreateIssueResult
+	if len(issue.CodeRefs) == 0 || IsWisp(issue) {
+		return result, nil
+	}
+	for _, ref := range issue.CodeRefs {
+		if ref == nil || ref.Path == "" {
+			continue
+		}
+		ref.IssueID = issue.ID
+		if err := insertCodeRef(ctx, tx, ref); err != nil {
+			return result, err
+		}
+		result.markChanged("code_refs")
+	}
+	return result, nil
+}
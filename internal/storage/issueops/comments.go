@@ -8,6 +8,7 @@ import (
 	"time"
 
 	"github.com/google/uuid"
+	"github.com/steveyegge/beads/internal/storage"
 	"github.com/steveyegge/beads/internal/types"
 )
 
@@ -169,3 +170,118 @@ func AddCommentEventInTx(ctx context.Context, tx *sql.Tx, issueID, actor, commen
 	}
 	return nil
 }
+
+// findCommentInTx looks a comment up by its full ID or by the short form
+// types.ShortCommentID prints, in comments and then wisp_comments. It
+// returns the comment and the table holding it; a short ID matching more
+// than one comment is an error.
+//
+//nolint:gosec // G201: table names are hardcoded
+func findCommentInTx(ctx context.Context, tx *sql.Tx, ref string) (*types.Comment, string, error) {
+	ref = strings.TrimPrefix(strings.TrimSpace(ref), "#")
+	if ref == "" || strings.ContainsAny(ref, "%_") {
+		return nil, "", fmt.Errorf("invalid comment ID %q", ref)
+	}
+	type match struct {
+		comment *types.Comment
+		table   string
+	}
+	var matches []match
+	for _, table := range []string{"comments", "wisp_comments"} {
+		rows, err := tx.QueryContext(ctx, fmt.Sprintf(`
+			SELECT id, issue_id, author, text, created_at
+			FROM %s
+			WHERE id = ? OR REPLACE(id, '-', '') LIKE ?
+		`, table), ref, "%"+strings.ReplaceAll(ref, "-", ""))
+		if err != nil {
+			return nil, "", fmt.Errorf("find comment in %s: %w", table, err)
+		}
+		for rows.Next() {
+			var c types.Comment
+			if err := rows.Scan(&c.ID, &c.IssueID, &c.Author, &c.Text, &c.CreatedAt); err != nil {
+				_ = rows.Close()
+				return nil, "", fmt.Errorf("find comment: scan: %w", err)
+			}
+			if c.ID == ref {
+				_ = rows.Close()
+				return &c, table, nil
+			}
+			matches = append(matches, match{&c, table})
+		}
+		_ = rows.Close()
+		if err := rows.Err(); err != nil {
+			return nil, "", fmt.Errorf("find comment: rows: %w", err)
+		}
+	}
+	switch len(matches) {
+	case 0:
+		return nil, "", fmt.Errorf("%w: comment %s", storage.ErrNotFound, ref)
+	case 1:
+		return matches[0].comment, matches[0].table, nil
+	default:
+		return nil, "", fmt.Errorf("comment ID %s is ambiguous (%d matches); use the full ID from 'bd comments <issue-id> --json'", ref, len(matches))
+	}
+}
+
+// commentEventTable returns the events table for a comment table.
+func commentEventTable(commentTable string) string {
+	if commentTable == "wisp_comments" {
+		return "wisp_events"
+	}
+	return "events"
+}
+
+// GetCommentInTx returns a comment by its full or short ID within an
+// existing transaction.
+func GetCommentInTx(ctx context.Context, tx *sql.Tx, ref string) (*types.Comment, error) {
+	c, _, err := findCommentInTx(ctx, tx, ref)
+	return c, err
+}
+
+// EditCommentInTx replaces a comment's text within an existing transaction,
+// recording the prior text in a comment_edited event. It returns the
+// edited comment.
+//
+//nolint:gosec // G201: table names are hardcoded
+func EditCommentInTx(ctx context.Context, tx *sql.Tx, ref, text, actor string) (*types.Comment, error) {
+	c, table, err := findCommentInTx(ctx, tx, ref)
+	if err != nil {
+		return nil, err
+	}
+	if c.Text == text {
+		return c, nil
+	}
+	if _, err := tx.ExecContext(ctx, fmt.Sprintf(`UPDATE %s SET text = ? WHERE id = ?`, table), text, c.ID); err != nil {
+		return nil, fmt.Errorf("edit comment in %s: %w", table, err)
+	}
+	if _, err := tx.ExecContext(ctx, fmt.Sprintf(`
+		INSERT INTO %s (issue_id, event_type, actor, old_value, new_value, comment)
+		VALUES (?, ?, ?, ?, ?, ?)
+	`, commentEventTable(table)), c.IssueID, types.EventCommentEdited, actor, c.Text, text, c.ID); err != nil {
+		return nil, fmt.Errorf("record comment edit: %w", err)
+	}
+	c.Text = text
+	return c, nil
+}
+
+// DeleteCommentInTx removes a comment within an existing transaction,
+// recording its text in a comment_deleted event. It returns the deleted
+// comment.
+//
+//nolint:gosec // G201: table names are hardcoded
+func DeleteCommentInTx(ctx context.Context, tx *sql.Tx, ref, actor string) (*types.Comment, error) {
+	c, table, err := findCommentInTx(ctx, tx, ref)
+	if err != nil {
+		return nil, err
+	}
+	if _, err := tx.ExecContext(ctx, fmt.Sprintf(`DELETE FROM %s WHERE id = ?`, table), c.ID); err != nil {
+		return nil, fmt.Errorf("delete comment from %s: %w", table, err)
+	}
+	if _, err := tx.ExecContext(ctx, fmt.Sprintf(`
+		INSERT INTO %s (issue_id, event_type, actor, old_value, comment)
+		VALUES (?, ?, ?, ?, ?)
+	`, commentEventTable(table)), c.IssueID, types.EventCommentDeleted, actor, c.Text, c.ID); err != nil {
+		return nil, fmt.Errorf("record comment deletion: %w", err)
+	}
+	return c, nil
+}
@@ -134,6 +134,26 @@ func CreateIssueInTxWithResult(ctx context.Context, tx *sql.Tx, bc *BatchContext
 		return result, err
 	}
 	result.ChangedTables = mergeChangedTables(result.ChangedTables, commentResult.ChangedTables)
+	codeRefResult, err := PersistCodeRefs(ctx, tx, issue)
+	if err != nil {
+		return result, err
+	}
+	result.ChangedTables = mergeChangedTables(result.ChangedTables, codeRefResult.ChangedTables)
+	workLogResult, err := PersistWorkLog(ctx, tx, issue)
+	if err != nil {
+		return result, err
+	}
+	result.ChangedTables = mergeChangedTables(result.ChangedTables, workLogResult.ChangedTables)
+	todoResult, err := PersistTodos(ctx, tx, issue)
+	if err != nil {
+		return result, err
+	}
+	result.ChangedTables = mergeChangedTables(result.ChangedTables, todoResult.ChangedTables)
+	assigneeResult, err := PersistAssignees(ctx, tx, issue)
+	if err != nil {
+		return result, err
+	}
+	result.ChangedTables = mergeChangedTables(result.ChangedTables, assigneeResult.ChangedTables)
 	return result, nil
 }
 
@@ -178,12 +198,27 @@ func CreateIssuesInTxWithResult(ctx context.Context, tx *sql.Tx, issues []*types
 	}
 
 	result := CreateIssuesResult{}
-	for _, issue := range issues {
-		issueResult, err := CreateIssueInTxWithResult(ctx, tx, bc, issue, actor)
-		if err != nil {
-			return CreateIssuesResult{}, err
+	create := func(batch []*types.Issue) error {
+		for _, issue := range batch {
+			issueResult, err := CreateIssueInTxWithResult(ctx, tx, bc, issue, actor)
+			if err != nil {
+				return err
+			}
+			result.merge(issueResult.ChangedTables)
 		}
-		result.merge(issueResult.ChangedTables)
+		return nil
+	}
+	// Renumbered issues get their IDs after the rest of the batch is in,
+	// so a new ID can't be one the batch itself brings in.
+	rest, renumbered, oldIDs := splitRenumberedIssues(issues, opts.RenumberIDs)
+	if err := create(rest); err != nil {
+		return CreateIssuesResult{}, err
+	}
+	if err := mintRenumberedIDs(ctx, tx, bc, issues, renumbered, oldIDs, actor); err != nil {
+		return CreateIssuesResult{}, err
+	}
+	if err := create(renumbered); err != nil {
+		return CreateIssuesResult{}, err
 	}
 
 	depResult, err := PersistDependenciesWithOptionsResult(ctx, tx, issues, actor, opts)
@@ -213,6 +248,82 @@ func CreateIssuesInTxWithResult(ctx context.Context, tx *sql.Tx, issues []*types
 	return result, nil
 }
 
+// splitRenumberedIssues separates the issues listed in renumber, keyed by
+// their incoming ID, from the rest of the batch, returning each one's
+// incoming ID. An issue still carrying the ID a rolled-back attempt minted
+// for it is recognized by that ID.
+func splitRenumberedIssues(issues []*types.Issue, renumber map[string]string) (rest, renumbered []*types.Issue, oldIDs []string) {
+	if len(renumber) == 0 {
+		return issues, nil, nil
+	}
+	minted := make(map[string]string, len(renumber))
+	for oldID, newID := range renumber {
+		if newID != "" {
+			minted[newID] = oldID
+		}
+	}
+	for _, issue := range issues {
+		oldID := issue.ID
+		if id, ok := minted[issue.ID]; ok {
+			oldID = id
+		}
+		if _, ok := renumber[oldID]; !ok || oldID == "" {
+			rest = append(rest, issue)
+			continue
+		}
+		renumbered = append(renumbered, issue)
+		oldIDs = append(oldIDs, oldID)
+	}
+	return rest, renumbered, oldIDs
+}
+
+// mintRenumberedIDs gives each renumbered issue a new ID no issue in the
+// database has, records it in bc.Opts.RenumberIDs, and points the batch's
+// dependency edges at it. Text that mentions the old ID is left alone.
+func mintRenumberedIDs(ctx context.Context, tx *sql.Tx, bc *BatchContext, issues, renumbered []*types.Issue, oldIDs []string, actor string) error {
+	remap := make(map[string]string, 2*len(renumbered))
+	for i, issue := range renumbered {
+		prefix := bc.ConfigPrefix
+		if issue.PrefixOverride != "" {
+			prefix = issue.PrefixOverride
+		}
+		table, _ := TableRouting(issue)
+		for {
+			id, err := GenerateIssueIDInTable(ctx, tx, table, prefix, issue, actor)
+			if err != nil {
+				return fmt.Errorf("failed to renumber %s: %w", oldIDs[i], err)
+			}
+			// A counter can lag behind IDs the batch has just inserted.
+			exists, err := txIDStore{tx: tx, table: table}.Exists(ctx, id)
+			if err != nil {
+				return fmt.Errorf("failed to renumber %s: %w", oldIDs[i], err)
+			}
+			if exists {
+				continue
+			}
+			remap[issue.ID] = id
+			remap[oldIDs[i]] = id
+			bc.Opts.RenumberIDs[oldIDs[i]] = id
+			issue.ID = id
+			break
+		}
+	}
+	for _, issue := range issues {
+		for _, dep := range issue.Dependencies {
+			if dep == nil {
+				continue
+			}
+			if id, ok := remap[dep.IssueID]; ok {
+				dep.IssueID = id
+			}
+			if id, ok := remap[dep.DependsOnID]; ok {
+				dep.DependsOnID = id
+			}
+		}
+	}
+	return nil
+}
+
 // CreateIssueDirtyTables returns the regular Dolt tables CreateIssueInTx may
 // dirty for the given issue. Wisp tables are intentionally omitted because they
 // are Dolt-ignored and cannot be staged.
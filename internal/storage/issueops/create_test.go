@@ -231,3 +231,23 @@ func TestPersistDependenciesSkipsValidationErrorsWhenConfigured(t *testing.T) {
 		t.Fatalf("unmet expectations: %v", err)
 	}
 }
+
+func TestSplitRenumberedIssues(t *testing.T) {
+	issues := []*types.Issue{{ID: "bd-1"}, {ID: "bd-2"}, {ID: "bd-7"}}
+
+	// bd-7 is what a rolled-back attempt minted for bd-3.
+	rest, renumbered, oldIDs := splitRenumberedIssues(issues, map[string]string{"bd-1": "", "bd-3": "bd-7"})
+	if len(rest) != 1 || rest[0].ID != "bd-2" {
+		t.Errorf("rest = %v, want bd-2", rest)
+	}
+	if len(renumbered) != 2 || renumbered[0].ID != "bd-1" || renumbered[1].ID != "bd-7" {
+		t.Errorf("renumbered = %v, want bd-1 and bd-7", renumbered)
+	}
+	if strings.Join(oldIDs, ",") != "bd-1,bd-3" {
+		t.Errorf("oldIDs = %v, want bd-1 and bd-3", oldIDs)
+	}
+
+	if rest, renumbered, _ := splitRenumberedIssues(issues, nil); len(rest) != 3 || renumbered != nil {
+		t.Errorf("no renumbering: rest = %v, renumbered = %v", rest, renumbered)
+	}
+}
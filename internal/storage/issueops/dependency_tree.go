@@ -10,6 +10,9 @@ import (
 // GetDependencyTreeInTx returns a flattened dependency tree for visualization.
 // It performs a recursive BFS traversal up to maxDepth, using GetIssueInTx and
 // GetDependenciesInTx/GetDependentsInTx which handle wisp routing.
+//
+// Nodes on the last level that still have tree edges are marked Truncated so
+// JSON consumers and graph exports can tell a leaf from a cut-off subtree.
 func GetDependencyTreeInTx(ctx context.Context, tx *sql.Tx, issueID string, maxDepth int, showAllPaths bool, reverse bool) ([]*types.TreeNode, error) {
 	visited := make(map[string]bool)
 	return buildDependencyTreeInTx(ctx, tx, issueID, 0, maxDepth, reverse, visited, "", "")
@@ -50,6 +53,10 @@ func buildDependencyTreeInTx(ctx context.Context, tx *sql.Tx, issueID string, de
 		if !isDependencyTreeEdge(rel.DependencyType) {
 			continue
 		}
+		if depth+1 >= maxDepth {
+			node.Truncated = true
+			break
+		}
 		children, err := buildDependencyTreeInTx(ctx, tx, rel.ID, depth+1, maxDepth, reverse, visited, issueID, rel.DependencyType)
 		if err != nil {
 			return nil, err
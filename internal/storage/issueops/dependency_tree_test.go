@@ -92,6 +92,49 @@ func TestGetDependencyTreeInTxSkipsRelatesToEdges(t *testing.T) {
 	if tree[1].EdgeFromParent != types.DepBlocks {
 		t.Fatalf("blocker edge = %q, want %q", tree[1].EdgeFromParent, types.DepBlocks)
 	}
+	if tree[0].Truncated || tree[1].Truncated {
+		t.Fatalf("no node should be truncated within max depth: %+v", tree)
+	}
+}
+
+func TestGetDependencyTreeInTxMarksTruncatedAtMaxDepth(t *testing.T) {
+	db, mock, err := sqlmock.New()
+	if err != nil {
+		t.Fatalf("sqlmock.New: %v", err)
+	}
+	defer db.Close()
+
+	mock.ExpectBegin()
+	expectIssue(mock, "root", "Root")
+	expectDependencies(mock, "root", []dependencyRow{
+		{id: "blocker", depType: string(types.DepBlocks)},
+		{id: "related", depType: string(types.DepRelatesTo)},
+	})
+	expectIssueBatch(mock, []string{"blocker", "related"})
+	mock.ExpectRollback()
+
+	tx, err := db.BeginTx(context.Background(), nil)
+	if err != nil {
+		t.Fatalf("BeginTx: %v", err)
+	}
+	tree, err := GetDependencyTreeInTx(context.Background(), tx, "root", 1, false, false)
+	if err != nil {
+		_ = tx.Rollback()
+		t.Fatalf("GetDependencyTreeInTx: %v", err)
+	}
+	if err := tx.Rollback(); err != nil {
+		t.Fatalf("Rollback: %v", err)
+	}
+	if err := mock.ExpectationsWereMet(); err != nil {
+		t.Fatalf("unmet sql expectations: %v", err)
+	}
+
+	if len(tree) != 1 || tree[0].ID != "root" {
+		t.Fatalf("tree IDs = %v, want [root]", treeIDs(tree))
+	}
+	if !tree[0].Truncated {
+		t.Fatalf("root should be marked truncated when its blocker lies past max depth")
+	}
 }
 
 type dependencyRow struct {
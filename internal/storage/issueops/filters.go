@@ -2,6 +2,7 @@ package issueops
 
 import (
 	"fmt"
+	"regexp"
 	"sort"
 	"strings"
 	"time"
@@ -17,11 +18,12 @@ type FilterTables struct {
 	Labels       string // "labels" or "wisp_labels"
 	Dependencies string // "dependencies" or "wisp_dependencies"
 	Comments     string // "comments" or "wisp_comments"
+	Events       string // "events" or "wisp_events"
 }
 
 var (
-	IssuesFilterTables = FilterTables{Main: "issues", Labels: "labels", Dependencies: "dependencies", Comments: "comments"}
-	WispsFilterTables  = FilterTables{Main: "wisps", Labels: "wisp_labels", Dependencies: "wisp_dependencies", Comments: "wisp_comments"}
+	IssuesFilterTables = FilterTables{Main: "issues", Labels: "labels", Dependencies: "dependencies", Comments: "comments", Events: "events"}
+	WispsFilterTables  = FilterTables{Main: "wisps", Labels: "wisp_labels", Dependencies: "wisp_dependencies", Comments: "wisp_comments", Events: "wisp_events"}
 )
 
 // BuildIssueFilterClauses builds WHERE clause fragments and args from a query
@@ -33,14 +35,18 @@ func BuildIssueFilterClauses(query string, filter types.IssueFilter, tables Filt
 
 	if query != "" {
 		lowerQuery := strings.ToLower(query)
+		var match []string
 		if LooksLikeIssueID(query) {
-			whereClauses = append(whereClauses, "(id = ? OR id LIKE ? OR LOWER(title) LIKE ? OR LOWER(external_ref) LIKE ?)")
+			match = []string{"id = ?", "id LIKE ?", "LOWER(title) LIKE ?", "LOWER(external_ref) LIKE ?"}
 			args = append(args, lowerQuery, lowerQuery+"%", "%"+lowerQuery+"%", "%"+lowerQuery+"%")
 		} else {
-			whereClauses = append(whereClauses, "(LOWER(title) LIKE ? OR id LIKE ?)")
+			match = []string{"LOWER(title) LIKE ?", "id LIKE ?"}
 			pattern := "%" + lowerQuery + "%"
 			args = append(args, pattern, pattern)
 		}
+		inClauses, inArgs := SearchInClauses("%"+lowerQuery+"%", filter.SearchIn, tables.Comments, tables.Events)
+		whereClauses = append(whereClauses, "("+strings.Join(append(match, inClauses...), " OR ")+")")
+		args = append(args, inArgs...)
 	}
 
 	if filter.TitleSearch != "" {
@@ -63,6 +69,17 @@ func BuildIssueFilterClauses(query string, filter types.IssueFilter, tables Filt
 		whereClauses = append(whereClauses, "LOWER(external_ref) LIKE ?")
 		args = append(args, "%"+strings.ToLower(filter.ExternalRefContains)+"%")
 	}
+	if err := ValidateRegexFilters(filter); err != nil {
+		return nil, nil, err
+	}
+	if filter.TitleRegex != "" {
+		whereClauses = append(whereClauses, "REGEXP_LIKE(title, ?, 'i')")
+		args = append(args, filter.TitleRegex)
+	}
+	if filter.DescriptionRegex != "" {
+		whereClauses = append(whereClauses, "REGEXP_LIKE(description, ?, 'i')")
+		args = append(args, filter.DescriptionRegex)
+	}
 
 	if filter.Status != nil {
 		whereClauses = append(whereClauses, "status = ?")
@@ -99,8 +116,14 @@ func BuildIssueFilterClauses(query string, filter types.IssueFilter, tables Filt
 	}
 
 	if filter.Assignee != nil {
-		whereClauses = append(whereClauses, "assignee = ?")
-		args = append(args, *filter.Assignee)
+		clause, assigneeArgs := AssigneeMatchClause(tables.Main, *filter.Assignee)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, assigneeArgs...)
+	}
+	if len(filter.ExcludeAssignees) > 0 {
+		clause, assigneeArgs := AssigneeExcludeClause(tables.Main, filter.ExcludeAssignees)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, assigneeArgs...)
 	}
 
 	if filter.Priority != nil {
@@ -115,6 +138,14 @@ func BuildIssueFilterClauses(query string, filter types.IssueFilter, tables Filt
 		whereClauses = append(whereClauses, "priority <= ?")
 		args = append(args, *filter.PriorityMax)
 	}
+	if len(filter.Severities) > 0 {
+		placeholders := make([]string, len(filter.Severities))
+		for i, sev := range filter.Severities {
+			placeholders[i] = "?"
+			args = append(args, string(sev))
+		}
+		whereClauses = append(whereClauses, fmt.Sprintf("severity IN (%s)", strings.Join(placeholders, ",")))
+	}
 
 	if len(filter.IDs) > 0 {
 		placeholders := make([]string, len(filter.IDs))
@@ -124,6 +155,14 @@ func BuildIssueFilterClauses(query string, filter types.IssueFilter, tables Filt
 		}
 		whereClauses = append(whereClauses, fmt.Sprintf("id IN (%s)", strings.Join(placeholders, ", ")))
 	}
+	if len(filter.ExcludeIDs) > 0 {
+		placeholders := make([]string, len(filter.ExcludeIDs))
+		for i, id := range filter.ExcludeIDs {
+			placeholders[i] = "?"
+			args = append(args, id)
+		}
+		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (%s)", strings.Join(placeholders, ", ")))
+	}
 	if filter.IDPrefix != "" {
 		whereClauses = append(whereClauses, "id LIKE ?")
 		args = append(args, filter.IDPrefix+"%")
@@ -153,25 +192,20 @@ func BuildIssueFilterClauses(query string, filter types.IssueFilter, tables Filt
 
 	if len(filter.Labels) > 0 {
 		for _, label := range filter.Labels {
-			whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE label = ?)", tables.Labels))
-			args = append(args, label)
+			cond, arg := LabelCondition("label", label)
+			whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE %s)", tables.Labels, cond))
+			args = append(args, arg)
 		}
 	}
 	if len(filter.LabelsAny) > 0 {
-		placeholders := make([]string, len(filter.LabelsAny))
-		for i, label := range filter.LabelsAny {
-			placeholders[i] = "?"
-			args = append(args, label)
-		}
-		whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE label IN (%s))", tables.Labels, strings.Join(placeholders, ", ")))
+		cond, condArgs := LabelSetCondition("label", filter.LabelsAny)
+		whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE %s)", tables.Labels, cond))
+		args = append(args, condArgs...)
 	}
 	if len(filter.ExcludeLabels) > 0 {
-		placeholders := make([]string, len(filter.ExcludeLabels))
-		for i, label := range filter.ExcludeLabels {
-			placeholders[i] = "?"
-			args = append(args, label)
-		}
-		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (SELECT issue_id FROM %s WHERE label IN (%s))", tables.Labels, strings.Join(placeholders, ", ")))
+		cond, condArgs := LabelSetCondition("label", filter.ExcludeLabels)
+		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (SELECT issue_id FROM %s WHERE %s)", tables.Labels, cond))
+		args = append(args, condArgs...)
 	}
 	if filter.NoLabels {
 		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (SELECT DISTINCT issue_id FROM %s)", tables.Labels))
@@ -289,10 +323,37 @@ func BuildIssueFilterClauses(query string, filter types.IssueFilter, tables Filt
 			args = append(args, storage.JSONMetadataPath(k), filter.MetadataFields[k])
 		}
 	}
+	if len(filter.CustomFields) > 0 {
+		clauses, fieldArgs, err := storage.CustomFieldClauses(filter.CustomFields)
+		if err != nil {
+			return nil, nil, err
+		}
+		whereClauses = append(whereClauses, clauses...)
+		args = append(args, fieldArgs...)
+	}
 
 	return whereClauses, args, nil
 }
 
+// ValidateRegexFilters rejects malformed title and description regexes
+// before they reach SQL, with a clearer error than the database gives. It
+// only checks syntax: REGEXP_LIKE does not use RE2 (the two differ on
+// backreferences, lookaround and some escapes), so a pattern that passes
+// can still be rejected or match differently in SQL.
+func ValidateRegexFilters(filter types.IssueFilter) error {
+	if filter.TitleRegex != "" {
+		if _, err := regexp.Compile(filter.TitleRegex); err != nil {
+			return fmt.Errorf("invalid title regex: %w", err)
+		}
+	}
+	if filter.DescriptionRegex != "" {
+		if _, err := regexp.Compile(filter.DescriptionRegex); err != nil {
+			return fmt.Errorf("invalid description regex: %w", err)
+		}
+	}
+	return nil
+}
+
 // LooksLikeIssueID returns true if the query string looks like a beads issue ID.
 func LooksLikeIssueID(query string) bool {
 	idx := strings.Index(query, "-")
@@ -309,3 +370,62 @@ func LooksLikeIssueID(query string) bool {
 	}
 	return true
 }
+
+// SearchInClauses returns extra OR terms that match a LIKE pattern against the
+// text sources named in searchIn: comment bodies for "comments", and event
+// text plus the close reason for "events".
+func SearchInClauses(pattern string, searchIn []string, commentsTable, eventsTable string) ([]string, []interface{}) {
+	var clauses []string
+	var args []interface{}
+	for _, source := range searchIn {
+		switch source {
+		case "comments":
+			clauses = append(clauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE LOWER(text) LIKE ?)", commentsTable))
+			args = append(args, pattern)
+		case "events":
+			clauses = append(clauses,
+				fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE LOWER(comment) LIKE ? OR LOWER(old_value) LIKE ? OR LOWER(new_value) LIKE ?)", eventsTable),
+				"LOWER(close_reason) LIKE ?")
+			args = append(args, pattern, pattern, pattern, pattern)
+		}
+	}
+	return clauses, args
+}
+
+// likeEscaper escapes LIKE metacharacters so a label prefix matches literally.
+var likeEscaper = strings.NewReplacer(`\`, `\\`, `%`, `\%`, `_`, `\_`)
+
+// LabelCondition returns a SQL condition matching column against a single
+// label filter. A trailing "*" matches every label with that prefix, so
+// "area/*" matches "area/storage" and "area/cli".
+func LabelCondition(column, label string) (string, interface{}) {
+	if prefix, ok := strings.CutSuffix(label, "*"); ok {
+		return column + " LIKE ?", likeEscaper.Replace(prefix) + "%"
+	}
+	return column + " = ?", label
+}
+
+// LabelSetCondition returns a SQL condition matching column against any of
+// labels, honouring trailing "*" wildcards like LabelCondition.
+func LabelSetCondition(column string, labels []string) (string, []interface{}) {
+	var exact, conds []string
+	var exactArgs, args []interface{}
+	for _, label := range labels {
+		if strings.HasSuffix(label, "*") {
+			cond, arg := LabelCondition(column, label)
+			conds = append(conds, cond)
+			args = append(args, arg)
+			continue
+		}
+		exact = append(exact, "?")
+		exactArgs = append(exactArgs, label)
+	}
+	if len(exact) > 0 {
+		conds = append([]string{fmt.Sprintf("%s IN (%s)", column, strings.Join(exact, ", "))}, conds...)
+		args = append(exactArgs, args...)
+	}
+	if len(conds) == 1 {
+		return conds[0], args
+	}
+	return "(" + strings.Join(conds, " OR ") + ")", args
+}
@@ -183,6 +183,69 @@ func TestBuildIssueFilterClauses_PriorityRange(t *testing.T) {
 	}
 }
 
+func TestBuildIssueFilterClauses_Severities(t *testing.T) {
+	t.Parallel()
+
+	filter := types.IssueFilter{Severities: []types.Severity{types.SeverityS0, types.SeverityS1}}
+	clauses, args, err := BuildIssueFilterClauses("", filter, IssuesFilterTables)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(clauses) != 1 || clauses[0] != "severity IN (?,?)" {
+		t.Fatalf("clauses = %v", clauses)
+	}
+	if !reflect.DeepEqual(args, []interface{}{"S0", "S1"}) {
+		t.Fatalf("args = %#v", args)
+	}
+}
+
+func TestBuildIssueFilterClauses_Regex(t *testing.T) {
+	t.Parallel()
+
+	filter := types.IssueFilter{TitleRegex: `v[0-9]+\.[0-9]+`, DescriptionRegex: `E[0-9]{4}`}
+	clauses, args, err := BuildIssueFilterClauses("", filter, IssuesFilterTables)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	want := []string{"REGEXP_LIKE(title, ?, 'i')", "REGEXP_LIKE(description, ?, 'i')"}
+	if !reflect.DeepEqual(clauses, want) {
+		t.Fatalf("clauses = %v, want %v", clauses, want)
+	}
+	if !reflect.DeepEqual(args, []interface{}{`v[0-9]+\.[0-9]+`, `E[0-9]{4}`}) {
+		t.Fatalf("args = %#v", args)
+	}
+
+	if _, _, err := BuildIssueFilterClauses("", types.IssueFilter{TitleRegex: "v(1"}, IssuesFilterTables); err == nil || !strings.Contains(err.Error(), "invalid title regex") {
+		t.Errorf("malformed regex: err = %v", err)
+	}
+}
+
+func TestBuildIssueFilterClauses_AssigneeMatchesCoAssignees(t *testing.T) {
+	t.Parallel()
+
+	assignee := "bob"
+	filter := types.IssueFilter{Assignee: &assignee}
+	clauses, args, err := BuildIssueFilterClauses("", filter, IssuesFilterTables)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(clauses) != 1 || !strings.Contains(clauses[0], "FROM assignees") {
+		t.Fatalf("expected a clause consulting the assignees table, got %v", clauses)
+	}
+	if !reflect.DeepEqual(args, []interface{}{"bob", "bob"}) {
+		t.Errorf("args = %v, want [bob bob]", args)
+	}
+
+	// Wisps carry no co-assignees.
+	clauses, args, err = BuildIssueFilterClauses("", filter, WispsFilterTables)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(clauses) != 1 || clauses[0] != "assignee = ?" || len(args) != 1 {
+		t.Errorf("wisps clauses = %v, args = %v; want a plain assignee match", clauses, args)
+	}
+}
+
 func TestBuildIssueFilterClauses_Labels(t *testing.T) {
 	t.Parallel()
 
@@ -287,6 +350,64 @@ func TestBuildIssueFilterClauses_ExcludeLabelsWithInclude(t *testing.T) {
 	}
 }
 
+func TestBuildIssueFilterClauses_LabelWildcards(t *testing.T) {
+	t.Parallel()
+
+	filter := types.IssueFilter{
+		Labels:        []string{"area/*"},
+		LabelsAny:     []string{"bug", "team_x/*"},
+		ExcludeLabels: []string{"wontfix"},
+	}
+	clauses, args, err := BuildIssueFilterClauses("", filter, IssuesFilterTables)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	want := []string{
+		"id IN (SELECT issue_id FROM labels WHERE label LIKE ?)",
+		"id IN (SELECT issue_id FROM labels WHERE (label IN (?) OR label LIKE ?))",
+		"id NOT IN (SELECT issue_id FROM labels WHERE label IN (?))",
+	}
+	if !reflect.DeepEqual(clauses, want) {
+		t.Fatalf("clauses = %q, want %q", clauses, want)
+	}
+	// The prefix is escaped so "_" matches literally rather than any character.
+	if !reflect.DeepEqual(args, []interface{}{"area/%", "bug", `team\_x/%`, "wontfix"}) {
+		t.Fatalf("args = %#v", args)
+	}
+}
+
+func TestBuildIssueFilterClauses_ExcludeAssigneesAndIDs(t *testing.T) {
+	t.Parallel()
+
+	filter := types.IssueFilter{
+		ExcludeAssignees: []string{"alice", "bob"},
+		ExcludeIDs:       []string{"bd-1"},
+	}
+	clauses, args, err := BuildIssueFilterClauses("", filter, IssuesFilterTables)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	want := []string{
+		"(COALESCE(assignee, '') NOT IN (?, ?) AND id NOT IN (SELECT issue_id FROM assignees WHERE assignee IN (?, ?)))",
+		"id NOT IN (?)",
+	}
+	if !reflect.DeepEqual(clauses, want) {
+		t.Fatalf("clauses = %q, want %q", clauses, want)
+	}
+	if !reflect.DeepEqual(args, []interface{}{"alice", "bob", "alice", "bob", "bd-1"}) {
+		t.Fatalf("args = %#v", args)
+	}
+
+	// Wisps have no co-assignees table.
+	clauses, _, err = BuildIssueFilterClauses("", types.IssueFilter{ExcludeAssignees: []string{"alice"}}, WispsFilterTables)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	if len(clauses) != 1 || clauses[0] != "COALESCE(assignee, '') NOT IN (?)" {
+		t.Errorf("wisp clauses = %q", clauses)
+	}
+}
+
 func TestBuildIssueFilterClauses_DateFilters(t *testing.T) {
 	t.Parallel()
 
@@ -457,3 +578,23 @@ func TestBuildIssueFilterClauses_CombinedFilters(t *testing.T) {
 		t.Errorf("expected 6 args, got %d", len(args))
 	}
 }
+
+func TestBuildIssueFilterClauses_SearchIn(t *testing.T) {
+	t.Parallel()
+
+	filter := types.IssueFilter{SearchIn: []string{"comments", "events"}}
+	clauses, args, err := BuildIssueFilterClauses("Flaky", filter, WispsFilterTables)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+	want := "(LOWER(title) LIKE ? OR id LIKE ?" +
+		" OR id IN (SELECT issue_id FROM wisp_comments WHERE LOWER(text) LIKE ?)" +
+		" OR id IN (SELECT issue_id FROM wisp_events WHERE LOWER(comment) LIKE ? OR LOWER(old_value) LIKE ? OR LOWER(new_value) LIKE ?)" +
+		" OR LOWER(close_reason) LIKE ?)"
+	if len(clauses) != 1 || clauses[0] != want {
+		t.Fatalf("clauses = %v, want [%s]", clauses, want)
+	}
+	if len(args) != 7 || args[6] != "%flaky%" {
+		t.Errorf("args = %#v", args)
+	}
+}
@@ -54,7 +54,7 @@ func InsertIssueIntoTable(ctx context.Context, tx *sql.Tx, table string, issue *
 			mol_type, work_type, source_system, source_repo, close_reason,
 			event_kind, actor, target, payload,
 			await_type, await_id, timeout_ns, waiters,
-			due_at, defer_until, metadata
+			due_at, defer_until, metadata, severity
 		) VALUES (
 			?, ?, ?, ?, ?, ?, ?,
 			?, ?, ?, ?, ?,
@@ -64,7 +64,7 @@ func InsertIssueIntoTable(ctx context.Context, tx *sql.Tx, table string, issue *
 			?, ?, ?, ?, ?,
 			?, ?, ?, ?,
 			?, ?, ?, ?,
-			?, ?, ?
+			?, ?, ?, ?
 		)
 		ON DUPLICATE KEY UPDATE
 			content_hash = VALUES(content_hash),
@@ -84,7 +84,8 @@ func InsertIssueIntoTable(ctx context.Context, tx *sql.Tx, table string, issue *
 			external_ref = VALUES(external_ref),
 			source_repo = VALUES(source_repo),
 			close_reason = VALUES(close_reason),
-			metadata = VALUES(metadata)
+			metadata = VALUES(metadata),
+			severity = VALUES(severity)
 	`, table),
 		issue.ID, issue.ContentHash, issue.Title, issue.Description, issue.Design, issue.AcceptanceCriteria, issue.Notes,
 		issue.Status, issue.Priority, issue.IssueType, NullString(issue.Assignee), NullInt(issue.EstimatedMinutes),
@@ -94,7 +95,7 @@ func InsertIssueIntoTable(ctx context.Context, tx *sql.Tx, table string, issue *
 		issue.MolType, issue.WorkType, issue.SourceSystem, issue.SourceRepo, issue.CloseReason,
 		issue.EventKind, issue.Actor, issue.Target, issue.Payload,
 		issue.AwaitType, issue.AwaitID, issue.Timeout.Nanoseconds(), FormatJSONStringArray(issue.Waiters),
-		issue.DueAt, issue.DeferUntil, JSONMetadata(issue.Metadata),
+		issue.DueAt, issue.DeferUntil, JSONMetadata(issue.Metadata), string(issue.Severity),
 	)
 	if err != nil {
 		return fmt.Errorf("insert issue into %s: %w", table, err)
@@ -117,89 +118,95 @@ func RecordEventInTable(ctx context.Context, tx *sql.Tx, table, issueID string,
 }
 
 // GenerateIssueIDInTable generates a unique ID, checking for collisions
-// in the specified table. Supports counter mode for non-ephemeral issues.
-//
-//nolint:gosec // G201: table is a hardcoded constant
+// in the specified table, with the strategy for the configured
+// issue_id_mode. Wisps always get hash IDs: there is no wisp counter, and
+// ephemeral churn would make one meaningless.
 func GenerateIssueIDInTable(ctx context.Context, tx *sql.Tx, table, prefix string, issue *types.Issue, actor string) (string, error) {
-	// Counter mode only applies to the issues table (not wisps).
+	scheme := idgen.SchemeHash
 	if table == "issues" {
-		counterMode, err := IsCounterModeTx(ctx, tx)
-		if err != nil {
+		var err error
+		if scheme, err = IDSchemeTx(ctx, tx); err != nil {
 			return "", err
 		}
-		if counterMode {
-			return NextCounterIDTx(ctx, tx, prefix)
-		}
-	}
-
-	// Default hash-based ID generation
-	baseLength, err := GetAdaptiveIDLengthTx(ctx, tx, table, prefix)
-	if err != nil {
-		baseLength = 6
 	}
+	return scheme.Strategy().NewID(ctx, txIDStore{tx: tx, table: table}, idgen.Request{
+		Prefix:      prefix,
+		Title:       issue.Title,
+		Description: issue.Description,
+		Actor:       actor,
+		CreatedAt:   issue.CreatedAt,
+	})
+}
 
-	maxLength := 8
-	if baseLength > maxLength {
-		baseLength = maxLength
-	}
+// txIDStore gives an idgen.Strategy access to a table within tx.
+type txIDStore struct {
+	tx    *sql.Tx
+	table string
+}
 
-	for length := baseLength; length <= maxLength; length++ {
-		for nonce := 0; nonce < 10; nonce++ {
-			candidate := idgen.GenerateHashID(prefix, issue.Title, issue.Description, actor, issue.CreatedAt, length, nonce)
+//nolint:gosec // G201: table is a hardcoded constant
+func (s txIDStore) Exists(ctx context.Context, id string) (bool, error) {
+	var count int
+	err := s.tx.QueryRowContext(ctx, fmt.Sprintf(`SELECT COUNT(*) FROM %s WHERE id = ?`, s.table), id).Scan(&count)
+	return count > 0, err
+}
 
-			var count int
-			err = tx.QueryRowContext(ctx, fmt.Sprintf(`SELECT COUNT(*) FROM %s WHERE id = ?`, table), candidate).Scan(&count)
-			if err != nil {
-				return "", fmt.Errorf("failed to check for ID collision: %w", err)
-			}
+func (s txIDStore) NextCounter(ctx context.Context, key string) (int, error) {
+	return NextCounterTx(ctx, s.tx, key)
+}
 
-			if count == 0 {
-				return candidate, nil
-			}
-		}
+func (s txIDStore) HashLengths(ctx context.Context, prefix string) (int, int, error) {
+	baseLength, err := GetAdaptiveIDLengthTx(ctx, s.tx, s.table, prefix)
+	if err != nil {
+		baseLength = 6
 	}
-
-	return "", fmt.Errorf("failed to generate unique ID after trying lengths %d-%d with 10 nonces each", baseLength, maxLength)
+	return baseLength, 8, nil
 }
 
-// IsCounterModeTx checks whether issue_id_mode=counter is configured.
-func IsCounterModeTx(ctx context.Context, tx *sql.Tx) (bool, error) {
+// IDSchemeTx returns the configured issue_id_mode. Values bd config set
+// would reject fall back to hash IDs, as they always have.
+func IDSchemeTx(ctx context.Context, tx *sql.Tx) (idgen.Scheme, error) {
 	var idMode string
 	err := tx.QueryRowContext(ctx, "SELECT value FROM config WHERE `key` = ?", "issue_id_mode").Scan(&idMode)
 	if err != nil && err != sql.ErrNoRows {
-		return false, fmt.Errorf("failed to read issue_id_mode config: %w", err)
+		return "", fmt.Errorf("failed to read issue_id_mode config: %w", err)
+	}
+	scheme, err := idgen.ParseScheme(idMode)
+	if err != nil {
+		return idgen.SchemeHash, nil
 	}
-	return idMode == "counter", nil
+	return scheme, nil
 }
 
-// NextCounterIDTx atomically increments and returns the next sequential issue ID.
-func NextCounterIDTx(ctx context.Context, tx *sql.Tx, prefix string) (string, error) {
+// NextCounterTx atomically increments and returns the issue counter for
+// prefix, which may be an actor namespace such as "bd-alice".
+func NextCounterTx(ctx context.Context, tx *sql.Tx, prefix string) (int, error) {
 	res, err := tx.ExecContext(ctx, "UPDATE issue_counter SET last_id = last_id + 1 WHERE prefix = ?", prefix)
 	if err != nil {
-		return "", fmt.Errorf("failed to increment issue counter for prefix %q: %w", prefix, err)
+		return 0, fmt.Errorf("failed to increment issue counter for prefix %q: %w", prefix, err)
 	}
 
 	rowsAffected, err := res.RowsAffected()
 	if err != nil {
-		return "", fmt.Errorf("failed to check rows affected for issue counter prefix %q: %w", prefix, err)
+		return 0, fmt.Errorf("failed to check rows affected for issue counter prefix %q: %w", prefix, err)
 	}
 
 	if rowsAffected == 0 {
 		if seedErr := SeedCounterFromExistingIssuesTx(ctx, tx, prefix); seedErr != nil {
-			return "", fmt.Errorf("failed to seed issue counter for prefix %q: %w", prefix, seedErr)
+			return 0, fmt.Errorf("failed to seed issue counter for prefix %q: %w", prefix, seedErr)
 		}
 		res, err = tx.ExecContext(ctx, "UPDATE issue_counter SET last_id = last_id + 1 WHERE prefix = ?", prefix)
 		if err != nil {
-			return "", fmt.Errorf("failed to increment issue counter after seeding for prefix %q: %w", prefix, err)
+			return 0, fmt.Errorf("failed to increment issue counter after seeding for prefix %q: %w", prefix, err)
 		}
 		rowsAffected, err = res.RowsAffected()
 		if err != nil {
-			return "", fmt.Errorf("failed to check rows affected after seeding for prefix %q: %w", prefix, err)
+			return 0, fmt.Errorf("failed to check rows affected after seeding for prefix %q: %w", prefix, err)
 		}
 		if rowsAffected == 0 {
 			_, err = tx.ExecContext(ctx, "INSERT INTO issue_counter (prefix, last_id) VALUES (?, 1)", prefix)
 			if err != nil {
-				return "", fmt.Errorf("failed to insert initial issue counter for prefix %q: %w", prefix, err)
+				return 0, fmt.Errorf("failed to insert initial issue counter for prefix %q: %w", prefix, err)
 			}
 		}
 	}
@@ -207,9 +214,9 @@ func NextCounterIDTx(ctx context.Context, tx *sql.Tx, prefix string) (string, er
 	var nextID int
 	err = tx.QueryRowContext(ctx, "SELECT last_id FROM issue_counter WHERE prefix = ?", prefix).Scan(&nextID)
 	if err != nil {
-		return "", fmt.Errorf("failed to read issue counter after increment for prefix %q: %w", prefix, err)
+		return 0, fmt.Errorf("failed to read issue counter after increment for prefix %q: %w", prefix, err)
 	}
-	return fmt.Sprintf("%s-%d", prefix, nextID), nil
+	return nextID, nil
 }
 
 // SeedCounterFromExistingIssuesTx scans existing issues to find the highest numeric suffix
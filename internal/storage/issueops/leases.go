@@ -0,0 +1,107 @@
+package issueops
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// ActiveLeaseExclusionClause keeps issues under an unexpired claim lease
+// out of ready work: they are taken until the lease lapses.
+const ActiveLeaseExclusionClause = "id NOT IN (SELECT issue_id FROM claim_leases WHERE expires_at > UTC_TIMESTAMP())"
+
+// setClaimLeaseInTx records holder's lease on a just-claimed issue, or
+// drops any earlier lease when lease is not positive (a plain claim holds
+// until released). Returns the new expiry, if any.
+func setClaimLeaseInTx(ctx context.Context, tx *sql.Tx, id, holder string, lease time.Duration, now time.Time) (*time.Time, error) {
+	if lease <= 0 {
+		if _, err := tx.ExecContext(ctx, `DELETE FROM claim_leases WHERE issue_id = ?`, id); err != nil {
+			return nil, fmt.Errorf("clear claim lease: %w", err)
+		}
+		return nil, nil
+	}
+	expires := now.Add(lease)
+	if _, err := tx.ExecContext(ctx, `
+		REPLACE INTO claim_leases (issue_id, holder, expires_at) VALUES (?, ?, ?)
+	`, id, holder, expires); err != nil {
+		return nil, fmt.Errorf("record claim lease: %w", err)
+	}
+	return &expires, nil
+}
+
+// takeOverLapsedLeaseInTx reassigns an in_progress issue from holder to
+// actor when holder's claim lease on it has run out. It reports whether
+// the issue was taken over.
+func takeOverLapsedLeaseInTx(ctx context.Context, tx *sql.Tx, id, holder, actor string, now time.Time) (bool, error) {
+	res, err := tx.ExecContext(ctx, `
+		UPDATE issues SET assignee = ?, updated_at = ?
+		WHERE id = ? AND status = 'in_progress' AND assignee = ?
+		  AND id IN (SELECT issue_id FROM claim_leases WHERE holder = ? AND expires_at <= ?)
+	`, actor, now, id, holder, holder, now)
+	if err != nil {
+		return false, fmt.Errorf("take over lapsed claim: %w", err)
+	}
+	n, err := res.RowsAffected()
+	if err != nil {
+		return false, fmt.Errorf("take over lapsed claim: %w", err)
+	}
+	return n > 0, nil
+}
+
+// ReleaseStaleClaimLeaseInTx drops an issue's claim lease once its holder
+// is no longer the in_progress assignee, e.g. after the issue is closed,
+// reassigned or moved back to open.
+func ReleaseStaleClaimLeaseInTx(ctx context.Context, tx *sql.Tx, id string) error {
+	if _, err := tx.ExecContext(ctx, `
+		DELETE FROM claim_leases
+		WHERE issue_id = ?
+		  AND holder NOT IN (SELECT assignee FROM issues WHERE id = ? AND status = 'in_progress' AND assignee IS NOT NULL)
+	`, id, id); err != nil {
+		return fmt.Errorf("release claim lease: %w", err)
+	}
+	return nil
+}
+
+// GetClaimLeasesInTx retrieves the claim leases, lapsed or not, on the
+// given issues, using batched IN clauses.
+func GetClaimLeasesInTx(ctx context.Context, tx *sql.Tx, issueIDs []string) (map[string]*types.ClaimLease, error) {
+	result := make(map[string]*types.ClaimLease)
+	for start := 0; start < len(issueIDs); start += queryBatchSize {
+		end := start + queryBatchSize
+		if end > len(issueIDs) {
+			end = len(issueIDs)
+		}
+		batch := issueIDs[start:end]
+		placeholders := make([]string, len(batch))
+		args := make([]any, len(batch))
+		for i, id := range batch {
+			placeholders[i] = "?"
+			args[i] = id
+		}
+		//nolint:gosec // G201: only placeholders are interpolated
+		rows, err := tx.QueryContext(ctx, fmt.Sprintf(`
+			SELECT issue_id, holder, expires_at FROM claim_leases
+			WHERE issue_id IN (%s)
+		`, strings.Join(placeholders, ",")), args...)
+		if err != nil {
+			return nil, fmt.Errorf("get claim leases: %w", err)
+		}
+		for rows.Next() {
+			var lease types.ClaimLease
+			if err := rows.Scan(&lease.IssueID, &lease.Holder, &lease.ExpiresAt); err != nil {
+				_ = rows.Close()
+				return nil, fmt.Errorf("get claim leases: scan: %w", err)
+			}
+			result[lease.IssueID] = &lease
+		}
+		_ = rows.Close()
+		if err := rows.Err(); err != nil {
+			return nil, fmt.Errorf("get claim leases: rows: %w", err)
+		}
+	}
+	return result, nil
+}
@@ -0,0 +1,63 @@
+package issueops
+
+import (
+	"fmt"
+	"sort"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// cursorClause returns a WHERE fragment selecting rows that sort strictly
+// after c in the default list ordering (priority ASC, created_at DESC,
+// id ASC). alias qualifies the columns so the clause is safe next to label
+// joins.
+func cursorClause(alias string, c *types.IssueCursor) (string, []interface{}) {
+	clause := fmt.Sprintf(
+		"(%[1]s.priority > ? OR (%[1]s.priority = ? AND (%[1]s.created_at < ? OR (%[1]s.created_at = ? AND %[1]s.id > ?))))",
+		alias)
+	return clause, []interface{}{c.Priority, c.Priority, c.CreatedAt, c.CreatedAt, c.ID}
+}
+
+// isPaged reports whether filter asks for a page other than the plain
+// "first Limit rows" window.
+func isPaged(filter types.IssueFilter) bool {
+	return filter.Offset > 0 || filter.After != nil
+}
+
+// pageFetchFilter returns the filter used for the per-table queries of a
+// paged search. Offset is applied once after issues and wisps are merged,
+// so each table has to return enough rows to cover it.
+func pageFetchFilter(filter types.IssueFilter) types.IssueFilter {
+	inner := filter
+	inner.Offset = 0
+	if filter.Limit > 0 && filter.Offset > 0 {
+		inner.Limit = filter.Limit + filter.Offset
+	}
+	return inner
+}
+
+// pageWindow returns the [offset, offset+limit) window of items, clamped to
+// the slice bounds. A non-positive limit means "no limit".
+func pageWindow(n, offset, limit int) (int, int) {
+	if offset < 0 {
+		offset = 0
+	}
+	if offset > n {
+		offset = n
+	}
+	end := n
+	if limit > 0 && offset+limit < n {
+		end = offset + limit
+	}
+	return offset, end
+}
+
+// paginateIssues sorts merged issue/wisp results into list order and cuts
+// the requested page out of them.
+func paginateIssues(issues []*types.Issue, offset, limit int) []*types.Issue {
+	sort.SliceStable(issues, func(i, j int) bool {
+		return types.CompareListOrder(issues[i], issues[j]) < 0
+	})
+	start, end := pageWindow(len(issues), offset, limit)
+	return issues[start:end]
+}
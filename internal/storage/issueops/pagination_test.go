@@ -0,0 +1,46 @@
+package issueops
+
+import (
+	"testing"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestPageFetchFilter(t *testing.T) {
+	got := pageFetchFilter(types.IssueFilter{Limit: 11, Offset: 20})
+	if got.Limit != 31 || got.Offset != 0 {
+		t.Errorf("Limit=%d Offset=%d, want 31 and 0", got.Limit, got.Offset)
+	}
+	got = pageFetchFilter(types.IssueFilter{Offset: 20})
+	if got.Limit != 0 {
+		t.Errorf("unlimited page must stay unlimited, got Limit=%d", got.Limit)
+	}
+}
+
+func TestPaginateIssues(t *testing.T) {
+	base := time.Date(2026, 1, 1, 0, 0, 0, 0, time.UTC)
+	// Issues first, then wisps, as searchIssuesMergedInTx returns them.
+	merged := []*types.Issue{
+		{ID: "bd-1", Priority: 1, CreatedAt: base},
+		{ID: "bd-2", Priority: 2, CreatedAt: base},
+		{ID: "bd-wisp-1", Priority: 0, CreatedAt: base},
+		{ID: "bd-wisp-2", Priority: 1, CreatedAt: base.Add(time.Minute)},
+	}
+
+	page := paginateIssues(merged, 1, 2)
+	if len(page) != 2 || page[0].ID != "bd-wisp-2" || page[1].ID != "bd-1" {
+		t.Fatalf("unexpected page: %v", pageIDs(page))
+	}
+	if page := paginateIssues(merged, 10, 2); len(page) != 0 {
+		t.Errorf("offset past end should be empty, got %v", pageIDs(page))
+	}
+}
+
+func pageIDs(issues []*types.Issue) []string {
+	out := make([]string, len(issues))
+	for i, issue := range issues {
+		out[i] = issue.ID
+	}
+	return out
+}
@@ -37,23 +37,40 @@ func readyWorkPageSize(limit int) int {
 	return limit
 }
 
-func buildReadyWorkOrder(policy types.SortPolicy) readyWorkOrder {
+// readyWorkPriority returns the SQL expression ready work is ranked by in
+// place of the stored priority, applying priority aging when enabled. It
+// mirrors types.Issue.EffectivePriority.
+func readyWorkPriority(aging time.Duration) (string, []interface{}) {
+	if aging <= 0 {
+		return "priority", nil
+	}
+	return "GREATEST(0, LEAST(priority, priority - FLOOR(TIMESTAMPDIFF(SECOND, updated_at, ?) / ?)))",
+		[]interface{}{time.Now().UTC(), int64(aging / time.Second)}
+}
+
+func buildReadyWorkOrder(policy types.SortPolicy, aging time.Duration) readyWorkOrder {
+	priority, priorityArgs := readyWorkPriority(aging)
 	switch policy {
 	case types.SortPolicyOldest:
 		return readyWorkOrder{sql: "ORDER BY created_at ASC, id ASC"}
 	case types.SortPolicyPriority:
-		return readyWorkOrder{sql: "ORDER BY priority ASC, created_at DESC, id ASC"}
+		return readyWorkOrder{sql: "ORDER BY " + priority + " ASC, created_at DESC, id ASC", args: priorityArgs}
+	case types.SortPolicyDue:
+		return readyWorkOrder{
+			sql:  "ORDER BY CASE WHEN due_at IS NULL THEN 1 ELSE 0 END ASC, due_at ASC, " + priority + " ASC, created_at DESC, id ASC",
+			args: priorityArgs,
+		}
 	case types.SortPolicyHybrid, "":
 		recentCutoff := time.Now().UTC().Add(-48 * time.Hour)
 		return readyWorkOrder{
 			sql: `ORDER BY
 			CASE WHEN created_at >= ? THEN 0 ELSE 1 END ASC,
-			CASE WHEN created_at >= ? THEN priority ELSE 999 END ASC,
+			CASE WHEN created_at >= ? THEN ` + priority + ` ELSE 999 END ASC,
 			created_at ASC, id ASC`,
-			args: []interface{}{recentCutoff, recentCutoff},
+			args: append([]interface{}{recentCutoff, recentCutoff}, priorityArgs...),
 		}
 	default:
-		return readyWorkOrder{sql: "ORDER BY priority ASC, created_at DESC, id ASC"}
+		return readyWorkOrder{sql: "ORDER BY " + priority + " ASC, created_at DESC, id ASC", args: priorityArgs}
 	}
 }
 
@@ -96,8 +113,12 @@ func buildReadyWorkPredicates(ctx context.Context, tx *sql.Tx, filter types.Work
 	if filter.Unassigned {
 		whereClauses = append(whereClauses, "(assignee IS NULL OR assignee = '')")
 	} else if filter.Assignee != nil {
-		whereClauses = append(whereClauses, "assignee = ?")
-		args = append(args, *filter.Assignee)
+		clause, assigneeArgs := AssigneeMatchClause(tables.Main, *filter.Assignee)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, assigneeArgs...)
+	}
+	if tables.Main == "issues" {
+		whereClauses = append(whereClauses, ActiveLeaseExclusionClause)
 	}
 
 	var deferredChildIDs []string
@@ -123,17 +144,23 @@ func buildReadyWorkPredicates(ctx context.Context, tx *sql.Tx, filter types.Work
 
 	if len(filter.Labels) > 0 {
 		for _, label := range filter.Labels {
-			whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE label = ?)", tables.Labels))
-			args = append(args, label)
+			cond, arg := LabelCondition("label", label)
+			whereClauses = append(whereClauses, fmt.Sprintf("id IN (SELECT issue_id FROM %s WHERE %s)", tables.Labels, cond))
+			args = append(args, arg)
 		}
 	}
 	if len(filter.ExcludeLabels) > 0 {
-		placeholders := make([]string, len(filter.ExcludeLabels))
-		for i, label := range filter.ExcludeLabels {
+		cond, condArgs := LabelSetCondition("label", filter.ExcludeLabels)
+		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (SELECT issue_id FROM %s WHERE %s)", tables.Labels, cond))
+		args = append(args, condArgs...)
+	}
+	if len(filter.ExcludeIDs) > 0 {
+		placeholders := make([]string, len(filter.ExcludeIDs))
+		for i, id := range filter.ExcludeIDs {
 			placeholders[i] = "?"
-			args = append(args, label)
+			args = append(args, id)
 		}
-		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (SELECT issue_id FROM %s WHERE label IN (%s))", tables.Labels, strings.Join(placeholders, ", ")))
+		whereClauses = append(whereClauses, fmt.Sprintf("id NOT IN (%s)", strings.Join(placeholders, ", ")))
 	}
 
 	// Parent filtering: return all transitive descendants of parentID.
@@ -187,10 +214,18 @@ func buildReadyWorkPredicates(ctx context.Context, tx *sql.Tx, filter types.Work
 			args = append(args, storage.JSONMetadataPath(k), filter.MetadataFields[k])
 		}
 	}
+	if len(filter.CustomFields) > 0 {
+		clauses, fieldArgs, err := storage.CustomFieldClauses(filter.CustomFields)
+		if err != nil {
+			return nil, err
+		}
+		whereClauses = append(whereClauses, clauses...)
+		args = append(args, fieldArgs...)
+	}
 
 	whereSQL := "WHERE " + strings.Join(whereClauses, " AND ")
 
-	orderBy := buildReadyWorkOrder(filter.SortPolicy)
+	orderBy := buildReadyWorkOrder(filter.SortPolicy, filter.PriorityAging)
 	args = append(args, orderBy.args...)
 
 	var limitSQL string
@@ -271,7 +306,7 @@ func mergeReadyWisps(ordered []*types.Issue, wisps []*types.Issue, filter types.
 		}
 		ordered = append(ordered, wisp)
 	}
-	sortReadyIssues(ordered, filter.SortPolicy)
+	sortReadyIssues(ordered, filter.SortPolicy, filter.PriorityAging)
 	if filter.Limit > 0 && len(ordered) > filter.Limit {
 		ordered = ordered[:filter.Limit]
 	}
@@ -301,7 +336,7 @@ func getReadyWispsInTx(ctx context.Context, tx *sql.Tx, filter types.WorkFilter,
 	}
 
 	pageSize := readyWorkPageSize(filter.Limit)
-	orderBy := buildReadyWorkOrder(filter.SortPolicy)
+	orderBy := buildReadyWorkOrder(filter.SortPolicy, filter.PriorityAging)
 	ready := make([]*types.Issue, 0, filter.Limit)
 	for offset := 0; len(ready) < filter.Limit; offset += pageSize {
 		pageIDs, err := queryReadyWispIssueIDPage(ctx, tx, wispFilter, !filter.IncludeDeferred, orderBy, pageSize, offset)
@@ -440,12 +475,14 @@ func readyWorkWispIssueFilter(filter types.WorkFilter) types.IssueFilter {
 		Labels:         filter.Labels,
 		LabelsAny:      filter.LabelsAny,
 		ExcludeLabels:  filter.ExcludeLabels,
+		ExcludeIDs:     filter.ExcludeIDs,
 		Limit:          filter.Limit,
 		MolType:        filter.MolType,
 		WispType:       filter.WispType,
 		Pinned:         &pinnedFalse,
 		MetadataFields: filter.MetadataFields,
 		HasMetadataKey: filter.HasMetadataKey,
+		CustomFields:   filter.CustomFields,
 	}
 	if filter.Status != "" {
 		s := filter.Status
@@ -565,34 +602,38 @@ func filterReadyWispsInTx(ctx context.Context, tx *sql.Tx, filter types.WorkFilt
 	return ready, nil
 }
 
-func sortReadyIssues(issues []*types.Issue, policy types.SortPolicy) {
-	recentCutoff := time.Now().UTC().Add(-48 * time.Hour)
+func sortReadyIssues(issues []*types.Issue, policy types.SortPolicy, aging time.Duration) {
+	now := time.Now().UTC()
+	recentCutoff := now.Add(-48 * time.Hour)
+	rank := func(issue *types.Issue) int { return issue.EffectivePriority(aging, now) }
 	sort.SliceStable(issues, func(i, j int) bool {
 		a, b := issues[i], issues[j]
 		switch policy {
 		case types.SortPolicyOldest:
 			return issueCreatedBefore(a, b)
 		case types.SortPolicyPriority:
-			return issuePriorityBefore(a, b)
+			return issuePriorityBefore(a, b, rank)
+		case types.SortPolicyDue:
+			return issueDueBefore(a, b, rank)
 		case types.SortPolicyHybrid, "":
 			aRecent := !a.CreatedAt.Before(recentCutoff)
 			bRecent := !b.CreatedAt.Before(recentCutoff)
 			if aRecent != bRecent {
 				return aRecent
 			}
-			if aRecent && a.Priority != b.Priority {
-				return a.Priority < b.Priority
+			if aRecent && rank(a) != rank(b) {
+				return rank(a) < rank(b)
 			}
 			return issueCreatedBefore(a, b)
 		default:
-			return issuePriorityBefore(a, b)
+			return issuePriorityBefore(a, b, rank)
 		}
 	})
 }
 
-func issuePriorityBefore(a, b *types.Issue) bool {
-	if a.Priority != b.Priority {
-		return a.Priority < b.Priority
+func issuePriorityBefore(a, b *types.Issue, rank func(*types.Issue) int) bool {
+	if pa, pb := rank(a), rank(b); pa != pb {
+		return pa < pb
 	}
 	if !a.CreatedAt.Equal(b.CreatedAt) {
 		return a.CreatedAt.After(b.CreatedAt)
@@ -600,6 +641,18 @@ func issuePriorityBefore(a, b *types.Issue) bool {
 	return a.ID < b.ID
 }
 
+// issueDueBefore orders issues with a due date first, earliest due first,
+// falling back to priority order for ties and undated issues.
+func issueDueBefore(a, b *types.Issue, rank func(*types.Issue) int) bool {
+	if (a.DueAt == nil) != (b.DueAt == nil) {
+		return a.DueAt != nil
+	}
+	if a.DueAt != nil && !a.DueAt.Equal(*b.DueAt) {
+		return a.DueAt.Before(*b.DueAt)
+	}
+	return issuePriorityBefore(a, b, rank)
+}
+
 func issueCreatedBefore(a, b *types.Issue) bool {
 	if !a.CreatedAt.Equal(b.CreatedAt) {
 		return a.CreatedAt.Before(b.CreatedAt)
@@ -7,6 +7,7 @@ import (
 	"fmt"
 	"sort"
 	"strings"
+	"time"
 
 	"github.com/steveyegge/beads/internal/types"
 )
@@ -67,14 +68,14 @@ func GetReadyWorkWithCountsInTx(ctx context.Context, tx *sql.Tx, filter types.Wo
 		}
 		out = append(out, w)
 	}
-	sortIssuesWithCountsByPolicy(out, filter.SortPolicy)
+	sortIssuesWithCountsByPolicy(out, filter.SortPolicy, filter.PriorityAging)
 	if filter.Limit > 0 && len(out) > filter.Limit {
 		out = out[:filter.Limit]
 	}
 	return out, nil
 }
 
-func sortIssuesWithCountsByPolicy(items []*types.IssueWithCounts, policy types.SortPolicy) {
+func sortIssuesWithCountsByPolicy(items []*types.IssueWithCounts, policy types.SortPolicy, aging time.Duration) {
 	if len(items) <= 1 {
 		return
 	}
@@ -88,7 +89,7 @@ func sortIssuesWithCountsByPolicy(items []*types.IssueWithCounts, policy types.S
 	if len(issues) != len(items) {
 		return
 	}
-	sortReadyIssues(issues, policy)
+	sortReadyIssues(issues, policy, aging)
 	byID := make(map[string]int, len(issues))
 	for i, iss := range issues {
 		byID[iss.ID] = i
@@ -7,6 +7,7 @@ import (
 	"reflect"
 	"strings"
 	"testing"
+	"time"
 
 	"github.com/DATA-DOG/go-sqlmock"
 	"github.com/steveyegge/beads/internal/types"
@@ -240,3 +241,49 @@ func TestGetChildrenOfDeferredParentsInTx_IgnoresMissingWispDependenciesTable(t
 		t.Fatalf("unmet SQL expectations: %v", err)
 	}
 }
+
+func TestSortReadyIssuesByDue(t *testing.T) {
+	now := time.Now().UTC()
+	soon, later := now.Add(24*time.Hour), now.Add(72*time.Hour)
+	issues := []*types.Issue{
+		{ID: "bd-1", Priority: 0, CreatedAt: now},
+		{ID: "bd-2", Priority: 3, DueAt: &later, CreatedAt: now},
+		{ID: "bd-3", Priority: 2, DueAt: &soon, CreatedAt: now},
+		{ID: "bd-4", Priority: 1, DueAt: &soon, CreatedAt: now},
+		{ID: "bd-5", Priority: 1, CreatedAt: now},
+	}
+
+	sortReadyIssues(issues, types.SortPolicyDue, 0)
+
+	var got []string
+	for _, issue := range issues {
+		got = append(got, issue.ID)
+	}
+	want := []string{"bd-4", "bd-3", "bd-2", "bd-1", "bd-5"}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("sortReadyIssues(due) = %v, want %v", got, want)
+	}
+}
+
+func TestSortReadyIssuesWithPriorityAging(t *testing.T) {
+	now := time.Now().UTC()
+	created := now.Add(-90 * 24 * time.Hour)
+	issues := []*types.Issue{
+		{ID: "bd-fresh", Priority: 1, Status: types.StatusOpen, CreatedAt: created, UpdatedAt: now},
+		{ID: "bd-stale", Priority: 3, Status: types.StatusOpen, CreatedAt: created, UpdatedAt: now.Add(-30 * 24 * time.Hour)},
+	}
+
+	sortReadyIssues(issues, types.SortPolicyPriority, 0)
+	if issues[0].ID != "bd-fresh" {
+		t.Fatalf("without aging, first = %s, want bd-fresh", issues[0].ID)
+	}
+
+	// 30 days untouched at a 7-day interval bumps P3 to P0.
+	sortReadyIssues(issues, types.SortPolicyPriority, 7*24*time.Hour)
+	if issues[0].ID != "bd-stale" {
+		t.Errorf("with aging, first = %s, want bd-stale", issues[0].ID)
+	}
+	if issues[0].Priority != 3 {
+		t.Errorf("stored priority = %d, want 3 (aging must not rewrite it)", issues[0].Priority)
+	}
+}
@@ -20,7 +20,7 @@ const IssueSelectColumns = `id, content_hash, title, description, design, accept
 	       mol_type,
 	       event_kind, actor, target, payload,
 	       due_at, defer_until,
-	       work_type, source_system, metadata`
+	       work_type, source_system, metadata, severity`
 
 // IssueScanner is the common interface between *sql.Row and *sql.Rows,
 // allowing a single scan function to work with both single-row and
@@ -43,7 +43,7 @@ func ScanIssueFrom(s IssueScanner) (*types.Issue, error) {
 	var sender, wispType, molType, eventKind, actor, target, payload sql.NullString
 	var awaitType, awaitID, waiters sql.NullString
 	var ephemeral, noHistory, pinned, isTemplate sql.NullInt64
-	var metadata sql.NullString
+	var metadata, severity sql.NullString
 
 	if err := s.Scan(
 		&issue.ID, &contentHash, &issue.Title, &issue.Description, &issue.Design,
@@ -56,7 +56,7 @@ func ScanIssueFrom(s IssueScanner) (*types.Issue, error) {
 		&molType,
 		&eventKind, &actor, &target, &payload,
 		&dueAt, &deferUntil,
-		&workType, &sourceSystem, &metadata,
+		&workType, &sourceSystem, &metadata, &severity,
 	); err != nil {
 		return nil, err
 	}
@@ -98,6 +98,9 @@ func ScanIssueFrom(s IssueScanner) (*types.Issue, error) {
 	if specID.Valid {
 		issue.SpecID = specID.String
 	}
+	if severity.Valid {
+		issue.Severity = types.Severity(severity.String)
+	}
 	if compactedAt.Valid {
 		issue.CompactedAt = &compactedAt.Time
 	}
@@ -15,7 +15,24 @@ import (
 //
 // Set filter.SkipWisps=true for callers that never need ephemeral results; this
 // avoids the unconditional full-table wisps scan (Q2 perf opt).
+//
+// When filter.Offset or filter.After is set, the merged issue and wisp results
+// are re-sorted into list order before the page is cut, so pages are stable
+// across both tables.
 func SearchIssuesInTx(ctx context.Context, tx *sql.Tx, query string, filter types.IssueFilter) ([]*types.Issue, error) {
+	if !isPaged(filter) {
+		return searchIssuesMergedInTx(ctx, tx, query, filter)
+	}
+	results, err := searchIssuesMergedInTx(ctx, tx, query, pageFetchFilter(filter))
+	if err != nil {
+		return nil, err
+	}
+	return paginateIssues(results, filter.Offset, filter.Limit), nil
+}
+
+// searchIssuesMergedInTx is SearchIssuesInTx without pagination: it applies
+// filter.Limit per table and appends wisps after issues.
+func searchIssuesMergedInTx(ctx context.Context, tx *sql.Tx, query string, filter types.IssueFilter) ([]*types.Issue, error) {
 	// Route ephemeral-only queries to wisps table.
 	if filter.Ephemeral != nil && *filter.Ephemeral {
 		results, err := searchTableInTx(ctx, tx, query, filter, WispsFilterTables)
@@ -90,6 +107,11 @@ func searchTableInTx(ctx context.Context, tx *sql.Tx, query string, filter types
 		whereClauses = append(labelWhere, whereClauses...)
 		args = append(labelArgs, args...)
 	}
+	if filter.After != nil {
+		clause, cursorArgs := cursorClause(tables.Main, filter.After)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, cursorArgs...)
+	}
 
 	whereSQL := ""
 	if len(whereClauses) > 0 {
@@ -286,19 +308,17 @@ func buildLabelDrivenSearch(filter types.IssueFilter, tables FilterTables) (stri
 	for i, label := range labels {
 		alias := fmt.Sprintf("label_filter_%d", i)
 		joins = append(joins, fmt.Sprintf("JOIN %s %s ON %s.issue_id = %s.id", tables.Labels, alias, alias, tables.Main))
-		where = append(where, fmt.Sprintf("%s.label = ?", alias))
-		args = append(args, label)
+		cond, arg := LabelCondition(alias+".label", label)
+		where = append(where, cond)
+		args = append(args, arg)
 	}
 
 	if len(labelsAny) > 0 {
 		alias := "label_filter_any"
 		joins = append(joins, fmt.Sprintf("JOIN %s %s ON %s.issue_id = %s.id", tables.Labels, alias, alias, tables.Main))
-		placeholders := make([]string, len(labelsAny))
-		for i, label := range labelsAny {
-			placeholders[i] = "?"
-			args = append(args, label)
-		}
-		where = append(where, fmt.Sprintf("%s.label IN (%s)", alias, strings.Join(placeholders, ", ")))
+		cond, condArgs := LabelSetCondition(alias+".label", labelsAny)
+		where = append(where, cond)
+		args = append(args, condArgs...)
 	}
 
 	return tables.Main + " " + strings.Join(joins, " "), where, args, true, filterForClauses
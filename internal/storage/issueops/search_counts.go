@@ -10,6 +10,19 @@ import (
 )
 
 func SearchIssuesWithCountsInTx(ctx context.Context, tx *sql.Tx, query string, filter types.IssueFilter) ([]*types.IssueWithCounts, error) {
+	if !isPaged(filter) {
+		return searchIssuesWithCountsMergedInTx(ctx, tx, query, filter)
+	}
+	items, err := searchIssuesWithCountsMergedInTx(ctx, tx, query, pageFetchFilter(filter))
+	if err != nil {
+		return nil, err
+	}
+	// Merged results are already sorted into list order.
+	start, end := pageWindow(len(items), filter.Offset, filter.Limit)
+	return items[start:end], nil
+}
+
+func searchIssuesWithCountsMergedInTx(ctx context.Context, tx *sql.Tx, query string, filter types.IssueFilter) ([]*types.IssueWithCounts, error) {
 	limit := filter.Limit
 
 	wispDepsExist, err := optionalTableExistsInTx(ctx, tx, "wisp_dependencies")
@@ -82,6 +95,11 @@ func runFilterSearchQueryInTx(ctx context.Context, tx *sql.Tx, query string, fil
 	if err != nil {
 		return nil, err
 	}
+	if filter.After != nil {
+		clause, cursorArgs := cursorClause("i", filter.After)
+		whereClauses = append(whereClauses, clause)
+		args = append(args, cursorArgs...)
+	}
 	whereSQL := ""
 	if len(whereClauses) > 0 {
 		whereSQL = "WHERE " + joinAnd(whereClauses)
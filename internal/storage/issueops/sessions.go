@@ -0,0 +1,182 @@
+package issueops
+
+import (
+	"context"
+	"crypto/rand"
+	"database/sql"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// ErrSessionOpen is returned when starting a session for an actor that
+// already has one open.
+var ErrSessionOpen = errors.New("session already open")
+
+// StartSessionInTx opens a session for session.Actor within an existing
+// transaction, filling in ID and StartedAt.
+func StartSessionInTx(ctx context.Context, tx *sql.Tx, session *types.Session) error {
+	open, err := GetOpenSessionInTx(ctx, tx, session.Actor)
+	if err != nil {
+		return err
+	}
+	if open != nil {
+		return fmt.Errorf("%w: %s has %s open since %s", ErrSessionOpen, session.Actor, open.ID, open.StartedAt.Format(time.RFC3339))
+	}
+	b := make([]byte, 4)
+	if _, err := rand.Read(b); err != nil {
+		return fmt.Errorf("generate session id: %w", err)
+	}
+	session.ID = "ses-" + hex.EncodeToString(b)
+	session.StartedAt = time.Now().UTC().Truncate(time.Second)
+	session.EndedAt = nil
+	if _, err := tx.ExecContext(ctx, `
+		INSERT INTO sessions (id, actor, note, started_at) VALUES (?, ?, ?, ?)
+	`, session.ID, session.Actor, session.Note, session.StartedAt); err != nil {
+		return fmt.Errorf("start session: %w", err)
+	}
+	return nil
+}
+
+// EndSessionInTx closes actor's open session, appending note to its note
+// when set, and returns it. Returns nil when the actor has no open session.
+func EndSessionInTx(ctx context.Context, tx *sql.Tx, actor, note string) (*types.Session, error) {
+	session, err := GetOpenSessionInTx(ctx, tx, actor)
+	if err != nil || session == nil {
+		return nil, err
+	}
+	endedAt := time.Now().UTC().Truncate(time.Second)
+	session.EndedAt = &endedAt
+	if note != "" {
+		if session.Note != "" {
+			session.Note += "\n"
+		}
+		session.Note += note
+	}
+	if _, err := tx.ExecContext(ctx, `UPDATE sessions SET ended_at = ?, note = ? WHERE id = ?`,
+		endedAt, session.Note, session.ID); err != nil {
+		return nil, fmt.Errorf("end session %s: %w", session.ID, err)
+	}
+	return session, nil
+}
+
+// GetOpenSessionInTx returns actor's open session, or nil if there is none.
+func GetOpenSessionInTx(ctx context.Context, tx *sql.Tx, actor string) (*types.Session, error) {
+	sessions, err := querySessions(ctx, tx, `WHERE actor = ? AND ended_at IS NULL LIMIT 1`, actor)
+	if err != nil || len(sessions) == 0 {
+		return nil, err
+	}
+	return sessions[0], nil
+}
+
+// RecordSessionCommandInTx appends a command to a session's log, numbering
+// it after the session's last command.
+func RecordSessionCommandInTx(ctx context.Context, tx *sql.Tx, command *types.SessionCommand) error {
+	if err := tx.QueryRowContext(ctx,
+		`SELECT COALESCE(MAX(seq), 0) + 1 FROM session_commands WHERE session_id = ?`, command.SessionID).Scan(&command.Seq); err != nil {
+		return fmt.Errorf("number session command: %w", err)
+	}
+	if command.CreatedAt.IsZero() {
+		command.CreatedAt = time.Now().UTC()
+	}
+	if _, err := tx.ExecContext(ctx, `
+		INSERT INTO session_commands (session_id, seq, command, args, created_at) VALUES (?, ?, ?, ?, ?)
+	`, command.SessionID, command.Seq, command.Command, command.Args, command.CreatedAt.UTC()); err != nil {
+		return fmt.Errorf("record session command: %w", err)
+	}
+	return nil
+}
+
+// ListSessionsInTx returns sessions newest first, only actor's when actor is
+// set. limit <= 0 means no limit.
+func ListSessionsInTx(ctx context.Context, tx *sql.Tx, actor string, limit int) ([]*types.Session, error) {
+	var where string
+	var args []interface{}
+	if actor != "" {
+		where, args = "WHERE actor = ?", append(args, actor)
+	}
+	where += " ORDER BY started_at DESC, id"
+	if limit > 0 {
+		where, args = where+" LIMIT ?", append(args, limit)
+	}
+	return querySessions(ctx, tx, where, args...)
+}
+
+// GetSessionInTx returns the session whose ID is id or starts with it,
+// with its commands. Returns nil when none matches and an error when the
+// prefix is ambiguous.
+func GetSessionInTx(ctx context.Context, tx *sql.Tx, id string) (*types.Session, error) {
+	sessions, err := querySessions(ctx, tx, `WHERE id = ? OR id LIKE ? ORDER BY id = ? DESC LIMIT 2`,
+		id, likeEscaper.Replace(id)+"%", id)
+	if err != nil || len(sessions) == 0 {
+		return nil, err
+	}
+	if len(sessions) > 1 && sessions[0].ID != id {
+		return nil, fmt.Errorf("session prefix %q is ambiguous", id)
+	}
+	session := sessions[0]
+
+	rows, err := tx.QueryContext(ctx, `
+		SELECT session_id, seq, command, args, created_at FROM session_commands
+		WHERE session_id = ? ORDER BY seq
+	`, session.ID)
+	if err != nil {
+		return nil, fmt.Errorf("get session commands: %w", err)
+	}
+	defer rows.Close()
+	for rows.Next() {
+		var c types.SessionCommand
+		if err := rows.Scan(&c.SessionID, &c.Seq, &c.Command, &c.Args, &c.CreatedAt); err != nil {
+			return nil, fmt.Errorf("get session commands: scan: %w", err)
+		}
+		session.Commands = append(session.Commands, &c)
+	}
+	return session, rows.Err()
+}
+
+// GetSessionEventsInTx returns the events session.Actor made while the
+// session was open, oldest first.
+func GetSessionEventsInTx(ctx context.Context, tx *sql.Tx, session *types.Session) ([]*types.Event, error) {
+	end := time.Now().UTC()
+	if session.EndedAt != nil {
+		end = *session.EndedAt
+	}
+	// Timestamps are stored to the second; allow for the last command's
+	// events being rounded past ended_at.
+	end = end.Add(time.Second)
+	rows, err := tx.QueryContext(ctx, `
+		SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at
+		FROM events
+		WHERE actor = ? AND created_at >= ? AND created_at < ?
+		ORDER BY created_at ASC, id ASC
+	`, session.Actor, session.StartedAt.UTC(), end.UTC())
+	if err != nil {
+		return nil, fmt.Errorf("get session events: %w", err)
+	}
+	defer rows.Close()
+	return scanEvents(rows)
+}
+
+func querySessions(ctx context.Context, tx *sql.Tx, where string, args ...interface{}) ([]*types.Session, error) {
+	rows, err := tx.QueryContext(ctx, `SELECT id, actor, note, started_at, ended_at FROM sessions `+where, args...)
+	if err != nil {
+		return nil, fmt.Errorf("get sessions: %w", err)
+	}
+	defer rows.Close()
+	var sessions []*types.Session
+	for rows.Next() {
+		var s types.Session
+		var endedAt sql.NullTime
+		if err := rows.Scan(&s.ID, &s.Actor, &s.Note, &s.StartedAt, &endedAt); err != nil {
+			return nil, fmt.Errorf("get sessions: scan: %w", err)
+		}
+		if endedAt.Valid {
+			s.EndedAt = &endedAt.Time
+		}
+		sessions = append(sessions, &s)
+	}
+	return sessions, rows.Err()
+}
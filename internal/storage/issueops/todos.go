@@ -0,0 +1,173 @@
+package issueops
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// AddTodoInTx appends a checklist item to an issue within an existing
+// transaction, numbering it after the issue's last item and filling in
+// CreatedAt when unset. Wisps do not carry checklist items.
+func AddTodoInTx(ctx context.Context, tx *sql.Tx, todo *types.TodoItem) error {
+	if strings.TrimSpace(todo.Text) == "" {
+		return fmt.Errorf("checklist item text cannot be empty")
+	}
+	var exists bool
+	if err := tx.QueryRowContext(ctx,
+		`SELECT EXISTS(SELECT 1 FROM issues WHERE id = ?)`, todo.IssueID).Scan(&exists); err != nil {
+		return fmt.Errorf("check issue existence: %w", err)
+	}
+	if !exists {
+		if IsActiveWispInTx(ctx, tx, todo.IssueID) {
+			return fmt.Errorf("%s is a wisp: checklist items are only kept on persistent issues", todo.IssueID)
+		}
+		return fmt.Errorf("issue %s not found", todo.IssueID)
+	}
+	if err := tx.QueryRowContext(ctx,
+		`SELECT COALESCE(MAX(seq), 0) + 1 FROM todos WHERE issue_id = ?`, todo.IssueID).Scan(&todo.Seq); err != nil {
+		return fmt.Errorf("number checklist item on %s: %w", todo.IssueID, err)
+	}
+	return insertTodo(ctx, tx, todo)
+}
+
+func insertTodo(ctx context.Context, tx *sql.Tx, todo *types.TodoItem) error {
+	if todo.CreatedAt.IsZero() {
+		todo.CreatedAt = time.Now().UTC()
+	}
+	var doneAt any
+	if todo.Done && todo.DoneAt != nil {
+		doneAt = todo.DoneAt.UTC()
+	}
+	// Re-importing an item that is already present takes the imported text
+	// and state.
+	if _, err := tx.ExecContext(ctx, `
+		INSERT INTO todos (issue_id, seq, text, done, done_at, created_by, created_at)
+		VALUES (?, ?, ?, ?, ?, ?, ?)
+		ON DUPLICATE KEY UPDATE text = VALUES(text), done = VALUES(done), done_at = VALUES(done_at)
+	`, todo.IssueID, todo.Seq, todo.Text, todo.Done, doneAt, todo.CreatedBy, todo.CreatedAt.UTC()); err != nil {
+		return fmt.Errorf("add checklist item %d to %s: %w", todo.Seq, todo.IssueID, err)
+	}
+	return nil
+}
+
+// SetTodoDoneInTx ticks (done) or unticks checklist item seq of an issue
+// within an existing transaction and returns the updated item.
+func SetTodoDoneInTx(ctx context.Context, tx *sql.Tx, issueID string, seq int, done bool) (*types.TodoItem, error) {
+	var doneAt any
+	if done {
+		doneAt = time.Now().UTC()
+	}
+	// done_at is assigned first so the CASE still sees the old done value:
+	// re-ticking an item keeps the time it was first ticked.
+	if _, err := tx.ExecContext(ctx, `
+		UPDATE todos SET done_at = CASE WHEN done = ? THEN done_at ELSE ? END, done = ?
+		WHERE issue_id = ? AND seq = ?
+	`, done, doneAt, done, issueID, seq); err != nil {
+		return nil, fmt.Errorf("update checklist item %d on %s: %w", seq, issueID, err)
+	}
+	todos, err := GetTodosForIssuesInTx(ctx, tx, []string{issueID})
+	if err != nil {
+		return nil, err
+	}
+	for _, todo := range todos[issueID] {
+		if todo.Seq == seq {
+			return todo, nil
+		}
+	}
+	return nil, fmt.Errorf("%s has no checklist item %d", issueID, seq)
+}
+
+// RemoveTodoInTx deletes checklist item seq of an issue within an existing
+// transaction. The remaining items keep their numbers. Returns whether an
+// item was removed.
+func RemoveTodoInTx(ctx context.Context, tx *sql.Tx, issueID string, seq int) (bool, error) {
+	res, err := tx.ExecContext(ctx, `DELETE FROM todos WHERE issue_id = ? AND seq = ?`, issueID, seq)
+	if err != nil {
+		return false, fmt.Errorf("remove checklist item %d from %s: %w", seq, issueID, err)
+	}
+	n, err := res.RowsAffected()
+	return n > 0, err
+}
+
+// GetTodosForIssuesInTx fetches checklist items for multiple issues,
+// ordered by number, using batched IN clauses.
+func GetTodosForIssuesInTx(ctx context.Context, tx *sql.Tx, issueIDs []string) (map[string][]*types.TodoItem, error) {
+	result := make(map[string][]*types.TodoItem)
+	for start := 0; start < len(issueIDs); start += queryBatchSize {
+		end := start + queryBatchSize
+		if end > len(issueIDs) {
+			end = len(issueIDs)
+		}
+		batch := issueIDs[start:end]
+		placeholders := make([]string, len(batch))
+		args := make([]any, len(batch))
+		for i, id := range batch {
+			placeholders[i] = "?"
+			args[i] = id
+		}
+		//nolint:gosec // G201: only placeholders are interpolated
+		rows, err := tx.QueryContext(ctx, fmt.Sprintf(`
+			SELECT issue_id, seq, text, done, done_at, created_by, created_at
+			FROM todos
+			WHERE issue_id IN (%s)
+			ORDER BY issue_id, seq
+		`, strings.Join(placeholders, ",")), args...)
+		if err != nil {
+			return nil, fmt.Errorf("get checklist items: %w", err)
+		}
+		for rows.Next() {
+			var todo types.TodoItem
+			var doneAt sql.NullTime
+			if err := rows.Scan(&todo.IssueID, &todo.Seq, &todo.Text, &todo.Done, &doneAt, &todo.CreatedBy, &todo.CreatedAt); err != nil {
+				_ = rows.Close()
+				return nil, fmt.Errorf("get checklist items: scan: %w", err)
+			}
+			if doneAt.Valid {
+				t := doneAt.Time
+				todo.DoneAt = &t
+			}
+			result[todo.IssueID] = append(result[todo.IssueID], &todo)
+		}
+		_ = rows.Close()
+		if err := rows.Err(); err != nil {
+			return nil, fmt.Errorf("get checklist items: rows: %w", err)
+		}
+	}
+	return result, nil
+}
+
+// PersistTodos writes issue.Todos during create/import, keeping their
+// numbers. Items without a number are appended after the numbered ones;
+// wisps carry none.
+func PersistTodos(ctx context.Context, tx *sql.Tx, issue *types.Issue) (CreateIssueResult, error) {
+	var result CreateIssueResult
+	if len(issue.Todos) == 0 || IsWisp(issue) {
+		return result, nil
+	}
+	next := 1
+	for _, todo := range issue.Todos {
+		if todo != nil && todo.Seq >= next {
+			next = todo.Seq + 1
+		}
+	}
+	for _, todo := range issue.Todos {
+		if todo == nil || strings.TrimSpace(todo.Text) == "" {
+			continue
+		}
+		todo.IssueID = issue.ID
+		if todo.Seq <= 0 {
+			todo.Seq = next
+			next++
+		}
+		if err := insertTodo(ctx, tx, todo); err != nil {
+			return result, err
+		}
+		result.markChanged("todos")
+	}
+	return result, nil
+}
@@ -0,0 +1,85 @@
+package issueops
+
+import (
+	"context"
+	"database/sql"
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// trashSnapshot is what the trash table's snapshot column holds.
+type trashSnapshot struct {
+	Issue      *types.Issue        `json:"issue"`
+	Dependents []*types.Dependency `json:"dependents,omitempty"`
+}
+
+// PutTrashInTx records entries in the trash within an existing transaction,
+// replacing any earlier entry for the same issue.
+func PutTrashInTx(ctx context.Context, tx *sql.Tx, entries []*types.TrashedIssue) error {
+	for _, e := range entries {
+		if e == nil || e.Issue == nil {
+			continue
+		}
+		snapshot, err := json.Marshal(trashSnapshot{Issue: e.Issue, Dependents: e.Dependents})
+		if err != nil {
+			return fmt.Errorf("encode %s for the trash: %w", e.ID, err)
+		}
+		if _, err := tx.ExecContext(ctx, `
+			REPLACE INTO trash (issue_id, title, actor, deleted_at, snapshot) VALUES (?, ?, ?, ?, ?)
+		`, e.ID, e.Issue.Title, e.Actor, e.DeletedAt.UTC(), string(snapshot)); err != nil {
+			return fmt.Errorf("move %s to the trash: %w", e.ID, err)
+		}
+	}
+	return nil
+}
+
+// GetTrashInTx returns the trash entry for id, or nil if there is none.
+func GetTrashInTx(ctx context.Context, tx *sql.Tx, id string) (*types.TrashedIssue, error) {
+	entries, err := queryTrash(ctx, tx, ` WHERE issue_id = ?`, id)
+	if err != nil || len(entries) == 0 {
+		return nil, err
+	}
+	return entries[0], nil
+}
+
+// ListTrashInTx returns every trash entry, most recently deleted first.
+func ListTrashInTx(ctx context.Context, tx *sql.Tx) ([]*types.TrashedIssue, error) {
+	return queryTrash(ctx, tx, ` ORDER BY deleted_at DESC, issue_id`)
+}
+
+// RemoveTrashInTx drops the trash entries for ids.
+func RemoveTrashInTx(ctx context.Context, tx *sql.Tx, ids []string) error {
+	for _, id := range ids {
+		if _, err := tx.ExecContext(ctx, `DELETE FROM trash WHERE issue_id = ?`, id); err != nil {
+			return fmt.Errorf("remove %s from the trash: %w", id, err)
+		}
+	}
+	return nil
+}
+
+func queryTrash(ctx context.Context, tx *sql.Tx, clause string, args ...interface{}) ([]*types.TrashedIssue, error) {
+	rows, err := tx.QueryContext(ctx, `SELECT issue_id, actor, deleted_at, snapshot FROM trash`+clause, args...)
+	if err != nil {
+		return nil, fmt.Errorf("read trash: %w", err)
+	}
+	defer rows.Close()
+
+	var entries []*types.TrashedIssue
+	for rows.Next() {
+		var e types.TrashedIssue
+		var snapshot string
+		if err := rows.Scan(&e.ID, &e.Actor, &e.DeletedAt, &snapshot); err != nil {
+			return nil, fmt.Errorf("scan trash: %w", err)
+		}
+		var s trashSnapshot
+		if err := json.Unmarshal([]byte(snapshot), &s); err != nil || s.Issue == nil {
+			return nil, errors.Join(fmt.Errorf("trash entry for %s is unreadable", e.ID), err)
+		}
+		e.Issue, e.Dependents = s.Issue, s.Dependents
+		entries = append(entries, &e)
+	}
+	return entries, rows.Err()
+}
@@ -17,7 +17,7 @@ func IsAllowedUpdateField(key string) bool {
 	allowed := map[string]bool{
 		"status": true, "priority": true, "title": true, "assignee": true,
 		"description": true, "design": true, "acceptance_criteria": true, "notes": true,
-		"issue_type": true, "estimated_minutes": true, "external_ref": true, "spec_id": true,
+		"issue_type": true, "estimated_minutes": true, "external_ref": true, "spec_id": true, "severity": true,
 		"started_at": true,
 		"closed_at":  true, "close_reason": true, "closed_by_session": true,
 		"source_repo": true,
@@ -89,6 +89,9 @@ func ManageStartedAt(oldIssue *types.Issue, updates map[string]interface{}, setC
 }
 
 // DetermineEventType returns the appropriate event type for an update.
+// Status transitions get their own event types (closed, reopened,
+// status_changed); an update that rewrites status to its current value is
+// recorded as a plain update so status history only contains real flips.
 func DetermineEventType(oldIssue *types.Issue, updates map[string]interface{}) types.EventType {
 	statusVal, hasStatus := updates["status"]
 	if !hasStatus {
@@ -105,6 +108,9 @@ func DetermineEventType(oldIssue *types.Issue, updates map[string]interface{}) t
 		return types.EventUpdated
 	}
 
+	if newStatus == string(oldIssue.Status) {
+		return types.EventUpdated
+	}
 	if newStatus == string(types.StatusClosed) {
 		return types.EventClosed
 	}
@@ -234,6 +240,13 @@ func updateIssueInTx(ctx context.Context, tx *sql.Tx, id string, updates map[str
 		}
 	}
 
+	_, hasAssignee := updates["assignee"]
+	if _, hasStatus := updates["status"]; (hasStatus || hasAssignee) && !isWisp {
+		if err := ReleaseStaleClaimLeaseInTx(ctx, tx, id); err != nil {
+			return nil, err
+		}
+	}
+
 	if rawStatus, hasStatus := updates["status"]; hasStatus {
 		var newStatus string
 		switch v := rawStatus.(type) {
@@ -0,0 +1,31 @@
+package issueops
+
+import (
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestDetermineEventType(t *testing.T) {
+	tests := []struct {
+		name      string
+		oldStatus types.Status
+		updates   map[string]interface{}
+		want      types.EventType
+	}{
+		{"no status", types.StatusOpen, map[string]interface{}{"title": "x"}, types.EventUpdated},
+		{"same status", types.StatusInProgress, map[string]interface{}{"status": "in_progress"}, types.EventUpdated},
+		{"status flip", types.StatusOpen, map[string]interface{}{"status": "in_progress"}, types.EventStatusChanged},
+		{"typed status flip", types.StatusOpen, map[string]interface{}{"status": types.StatusBlocked}, types.EventStatusChanged},
+		{"close", types.StatusInProgress, map[string]interface{}{"status": "closed"}, types.EventClosed},
+		{"reopen", types.StatusClosed, map[string]interface{}{"status": "open"}, types.EventReopened},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			old := &types.Issue{ID: "bd-1", Status: tt.oldStatus}
+			if got := DetermineEventType(old, tt.updates); got != tt.want {
+				t.Errorf("DetermineEventType() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
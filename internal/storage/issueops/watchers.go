@@ -0,0 +1,91 @@
+package issueops
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"time"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// WatchIssueInTx subscribes actor to an issue's events within an existing
+// transaction, reporting whether the actor was not already watching. Only
+// persistent issues can be watched.
+func WatchIssueInTx(ctx context.Context, tx *sql.Tx, issueID, actor string) (bool, error) {
+	var exists bool
+	if err := tx.QueryRowContext(ctx,
+		`SELECT EXISTS(SELECT 1 FROM issues WHERE id = ?)`, issueID).Scan(&exists); err != nil {
+		return false, fmt.Errorf("check issue existence: %w", err)
+	}
+	if !exists {
+		if IsActiveWispInTx(ctx, tx, issueID) {
+			return false, fmt.Errorf("%s is a wisp: only persistent issues can be watched", issueID)
+		}
+		return false, fmt.Errorf("issue %s not found", issueID)
+	}
+	res, err := tx.ExecContext(ctx, `INSERT IGNORE INTO watchers (issue_id, actor) VALUES (?, ?)`, issueID, actor)
+	if err != nil {
+		return false, fmt.Errorf("watch %s: %w", issueID, err)
+	}
+	n, _ := res.RowsAffected()
+	return n > 0, nil
+}
+
+// UnwatchIssueInTx removes actor's subscription to an issue, reporting
+// whether there was one.
+func UnwatchIssueInTx(ctx context.Context, tx *sql.Tx, issueID, actor string) (bool, error) {
+	res, err := tx.ExecContext(ctx, `DELETE FROM watchers WHERE issue_id = ? AND actor = ?`, issueID, actor)
+	if err != nil {
+		return false, fmt.Errorf("unwatch %s: %w", issueID, err)
+	}
+	n, _ := res.RowsAffected()
+	return n > 0, nil
+}
+
+// GetWatchedIssueIDsInTx lists the issues actor watches, by ID.
+func GetWatchedIssueIDsInTx(ctx context.Context, tx *sql.Tx, actor string) ([]string, error) {
+	rows, err := tx.QueryContext(ctx, `SELECT issue_id FROM watchers WHERE actor = ? ORDER BY issue_id`, actor)
+	if err != nil {
+		return nil, fmt.Errorf("get watched issues: %w", err)
+	}
+	defer rows.Close()
+	var ids []string
+	for rows.Next() {
+		var id string
+		if err := rows.Scan(&id); err != nil {
+			return nil, fmt.Errorf("get watched issues: scan: %w", err)
+		}
+		ids = append(ids, id)
+	}
+	return ids, rows.Err()
+}
+
+// GetInboxInTx returns events on the issues actor watches that are newer
+// than the actor's last check, oldest first. The actor's own events are
+// left out.
+func GetInboxInTx(ctx context.Context, tx *sql.Tx, actor string) ([]*types.Event, error) {
+	rows, err := tx.QueryContext(ctx, `
+		SELECT e.id, e.issue_id, e.event_type, e.actor, e.old_value, e.new_value, e.comment, e.created_at
+		FROM events e
+		JOIN watchers w ON w.issue_id = e.issue_id
+		WHERE w.actor = ? AND e.actor <> ? AND e.created_at > w.last_seen_at
+		ORDER BY e.created_at ASC, e.id ASC
+	`, actor, actor)
+	if err != nil {
+		return nil, fmt.Errorf("get inbox: %w", err)
+	}
+	defer rows.Close()
+	return scanEvents(rows)
+}
+
+// MarkInboxReadInTx moves actor's inbox cursor on every watched issue
+// forward to through.
+func MarkInboxReadInTx(ctx context.Context, tx *sql.Tx, actor string, through time.Time) error {
+	if _, err := tx.ExecContext(ctx, `
+		UPDATE watchers SET last_seen_at = ? WHERE actor = ? AND last_seen_at < ?
+	`, through, actor, through); err != nil {
+		return fmt.Errorf("mark inbox read: %w", err)
+	}
+	return nil
+}
@@ -0,0 +1,136 @@
+package issueops
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/google/uuid"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// AddWorkLogInTx records time spent on an issue within an existing
+// transaction, filling in the entry's ID and LoggedAt when unset. Wisps do
+// not carry a work log.
+func AddWorkLogInTx(ctx context.Context, tx *sql.Tx, entry *types.WorkLog) error {
+	if entry.Minutes <= 0 {
+		return fmt.Errorf("logged time must be positive, got %d minutes", entry.Minutes)
+	}
+	var exists bool
+	if err := tx.QueryRowContext(ctx,
+		`SELECT EXISTS(SELECT 1 FROM issues WHERE id = ?)`, entry.IssueID).Scan(&exists); err != nil {
+		return fmt.Errorf("check issue existence: %w", err)
+	}
+	if !exists {
+		if IsActiveWispInTx(ctx, tx, entry.IssueID) {
+			return fmt.Errorf("%s is a wisp: time is only logged on persistent issues", entry.IssueID)
+		}
+		return fmt.Errorf("issue %s not found", entry.IssueID)
+	}
+	return insertWorkLog(ctx, tx, entry)
+}
+
+func insertWorkLog(ctx context.Context, tx *sql.Tx, entry *types.WorkLog) error {
+	if entry.ID == "" {
+		entry.ID = uuid.Must(uuid.NewV7()).String()
+	}
+	if entry.LoggedAt.IsZero() {
+		entry.LoggedAt = time.Now().UTC()
+	}
+	// Re-importing an entry that is already present keeps the stored row.
+	if _, err := tx.ExecContext(ctx, `
+		INSERT IGNORE INTO work_log (id, issue_id, actor, minutes, note, logged_at)
+		VALUES (?, ?, ?, ?, ?, ?)
+	`, entry.ID, entry.IssueID, entry.Actor, entry.Minutes, entry.Note, entry.LoggedAt.UTC()); err != nil {
+		return fmt.Errorf("log time on %s: %w", entry.IssueID, err)
+	}
+	return nil
+}
+
+// GetWorkLogForIssuesInTx fetches work log entries for multiple issues,
+// oldest first, using batched IN clauses.
+func GetWorkLogForIssuesInTx(ctx context.Context, tx *sql.Tx, issueIDs []string) (map[string][]*types.WorkLog, error) {
+	result := make(map[string][]*types.WorkLog)
+	for start := 0; start < len(issueIDs); start += queryBatchSize {
+		end := start + queryBatchSize
+		if end > len(issueIDs) {
+			end = len(issueIDs)
+		}
+		batch := issueIDs[start:end]
+		placeholders := make([]string, len(batch))
+		args := make([]any, len(batch))
+		for i, id := range batch {
+			placeholders[i] = "?"
+			args[i] = id
+		}
+		//nolint:gosec // G201: only placeholders are interpolated
+		rows, err := tx.QueryContext(ctx, fmt.Sprintf(`
+			SELECT id, issue_id, actor, minutes, note, logged_at
+			FROM work_log
+			WHERE issue_id IN (%s)
+			ORDER BY issue_id, logged_at, id
+		`, strings.Join(placeholders, ",")), args...)
+		if err != nil {
+			return nil, fmt.Errorf("get work log: %w", err)
+		}
+		for rows.Next() {
+			var entry types.WorkLog
+			if err := rows.Scan(&entry.ID, &entry.IssueID, &entry.Actor, &entry.Minutes, &entry.Note, &entry.LoggedAt); err != nil {
+				_ = rows.Close()
+				return nil, fmt.Errorf("get work log: scan: %w", err)
+			}
+			result[entry.IssueID] = append(result[entry.IssueID], &entry)
+		}
+		_ = rows.Close()
+		if err := rows.Err(); err != nil {
+			return nil, fmt.Errorf("get work log: rows: %w", err)
+		}
+	}
+	return result, nil
+}
+
+// GetWorkLogTotalsInTx sums logged time per issue and actor across the
+// whole database.
+func GetWorkLogTotalsInTx(ctx context.Context, tx *sql.Tx) ([]*types.WorkLogTotal, error) {
+	rows, err := tx.QueryContext(ctx, `
+		SELECT issue_id, actor, SUM(minutes)
+		FROM work_log
+		GROUP BY issue_id, actor
+		ORDER BY issue_id, actor
+	`)
+	if err != nil {
+		return nil, fmt.Errorf("get work log totals: %w", err)
+	}
+	defer rows.Close()
+	var totals []*types.WorkLogTotal
+	for rows.Next() {
+		var total types.WorkLogTotal
+		if err := rows.Scan(&total.IssueID, &total.Actor, &total.Minutes); err != nil {
+			return nil, fmt.Errorf("get work log totals: scan: %w", err)
+		}
+		totals = append(totals, &total)
+	}
+	return totals, rows.Err()
+}
+
+// PersistWorkLog writes issue.WorkLog during create/import. Entries already
+// present (by ID) are left alone; wisps carry none.
+func PersistWorkLog(ctx context.Context, tx *sql.Tx, issue *types.Issue) (CreateIssueResult, error) {
+	var result CreateIssueResult
+	if len(issue.WorkLog) == 0 || IsWisp(issue) {
+		return result, nil
+	}
+	for _, entry := range issue.WorkLog {
+		if entry == nil || entry.Minutes <= 0 {
+			continue
+		}
+		entry.IssueID = issue.ID
+		if err := insertWorkLog(ctx, tx, entry); err != nil {
+			return result, err
+		}
+		result.markChanged("work_log")
+	}
+	return result, nil
+}
@@ -5,6 +5,7 @@ import (
 	"encoding/json"
 	"fmt"
 	"regexp"
+	"sort"
 	"strings"
 )
 
@@ -229,3 +230,34 @@ func JSONMetadataPath(key string) string {
 	}
 	return "$." + key
 }
+
+// CustomFieldsMetadataKey is the metadata sub-object that holds the custom
+// fields set with --field. Keeping them apart from the top-level keys means
+// a field name can never overwrite a key an integration stores there.
+const CustomFieldsMetadataKey = "custom_fields"
+
+// JSONCustomFieldPath returns the JSON path expression for a custom field.
+// The name is always quoted, so a dotted name is one key, not a nested path.
+func JSONCustomFieldPath(name string) string {
+	return `$.` + CustomFieldsMetadataKey + `."` + name + `"`
+}
+
+// CustomFieldClauses returns WHERE clauses and arguments matching issues
+// whose custom fields equal fields, in name order.
+func CustomFieldClauses(fields map[string]string) ([]string, []interface{}, error) {
+	names := make([]string, 0, len(fields))
+	for name := range fields {
+		if err := ValidateMetadataKey(name); err != nil {
+			return nil, nil, err
+		}
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	clauses := make([]string, 0, len(names))
+	args := make([]interface{}, 0, 2*len(names))
+	for _, name := range names {
+		clauses = append(clauses, "JSON_UNQUOTE(JSON_EXTRACT(metadata, ?)) = ?")
+		args = append(args, JSONCustomFieldPath(name), fields[name])
+	}
+	return clauses, args, nil
+}
@@ -38,6 +38,12 @@ func cliCompatibleMigrationSQL(name, sqlText string) string {
 		return cliMigration0046AddIsBlocked
 	case "0049_longtext_large_content_columns.up.sql":
 		return cliMigration0049LongtextLargeContentColumns
+	case "0050_add_issues_updated_at_index.up.sql":
+		return cliMigration0050AddIssuesUpdatedAtIndex
+	case "0057_add_severity.up.sql":
+		// Runtime adds the wisps column in ignored migration 0010, which
+		// this bundle does not run, so both tables get it here.
+		return cliMigration0057AddSeverity
 	default:
 		return sqlText
 	}
@@ -114,3 +120,8 @@ ALTER TABLE issues MODIFY COLUMN close_reason LONGTEXT DEFAULT '';
 ALTER TABLE wisps MODIFY COLUMN description LONGTEXT NOT NULL DEFAULT '', MODIFY COLUMN design LONGTEXT NOT NULL DEFAULT '', MODIFY COLUMN acceptance_criteria LONGTEXT NOT NULL DEFAULT '', MODIFY COLUMN notes LONGTEXT NOT NULL DEFAULT '';
 ALTER TABLE wisps MODIFY COLUMN close_reason LONGTEXT DEFAULT '';
 ALTER TABLE comments MODIFY COLUMN text LONGTEXT NOT NULL;`
+
+const cliMigration0050AddIssuesUpdatedAtIndex = `CREATE INDEX idx_issues_updated_at ON issues(updated_at);`
+
+const cliMigration0057AddSeverity = `ALTER TABLE issues ADD COLUMN severity VARCHAR(2) DEFAULT '';
+ALTER TABLE wisps ADD COLUMN severity VARCHAR(2) DEFAULT '';`
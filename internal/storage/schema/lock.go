@@ -54,6 +54,19 @@ func MigrationLockName(databaseName string) string {
 // relies on the embedded driver's file/concurrency controls instead of
 // sql-server session locks.
 func MigrateUpWithLock(ctx context.Context, conn *sql.Conn, databaseName string) (applied int, err error) {
+	return withMigrationLock(ctx, conn, databaseName, func() (int, error) {
+		return MigrateUp(ctx, conn)
+	})
+}
+
+// MigrateUpToWithLock is MigrateUpTo under the same lock as MigrateUpWithLock.
+func MigrateUpToWithLock(ctx context.Context, conn *sql.Conn, databaseName string, target int) (applied int, err error) {
+	return withMigrationLock(ctx, conn, databaseName, func() (int, error) {
+		return MigrateUpTo(ctx, conn, target)
+	})
+}
+
+func withMigrationLock(ctx context.Context, conn *sql.Conn, databaseName string, migrate func() (int, error)) (applied int, err error) {
 	lockName := MigrationLockName(databaseName)
 	if err := AcquireMigrationLock(ctx, conn, lockName); err != nil {
 		return 0, err
@@ -64,7 +77,7 @@ func MigrateUpWithLock(ctx context.Context, conn *sql.Conn, databaseName string)
 		}
 	}()
 
-	return MigrateUp(ctx, conn)
+	return migrate()
 }
 
 // AcquireMigrationLock acquires the named schema migration lock on the pinned
@@ -0,0 +1 @@
+DROP INDEX idx_issues_updated_at ON issues;
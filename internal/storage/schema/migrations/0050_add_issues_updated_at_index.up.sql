@@ -0,0 +1,11 @@
+SET @needs_index = (
+    SELECT IF(COUNT(*) = 0, 1, 0)
+    FROM INFORMATION_SCHEMA.STATISTICS
+    WHERE TABLE_SCHEMA = DATABASE()
+      AND TABLE_NAME = 'issues'
+      AND INDEX_NAME = 'idx_issues_updated_at'
+);
+SET @sql = IF(@needs_index = 1,
+    'CREATE INDEX idx_issues_updated_at ON issues(updated_at)',
+    'SELECT 1');
+PREPARE stmt FROM @sql; EXECUTE stmt; DEALLOCATE PREPARE stmt;
@@ -0,0 +1,6 @@
+-- Migration 0051: Register the daemon's request log as dolt-ignored.
+--
+-- daemon_log records which client performed which daemon request. It is
+-- clone-local audit state, so it is never committed; the table itself is
+-- created by ignored migration 0009.
+REPLACE INTO dolt_ignore VALUES ('daemon_log', true);
@@ -0,0 +1,15 @@
+-- Migration 0052: Structured code references on issues ('bd ref').
+--
+-- Each row points an issue at a file, or a line range within it, by path
+-- relative to the repository root. line_start 0 means the whole file.
+CREATE TABLE IF NOT EXISTS code_refs (
+    issue_id VARCHAR(255) NOT NULL,
+    path VARCHAR(500) NOT NULL,
+    line_start INT NOT NULL DEFAULT 0,
+    line_end INT NOT NULL DEFAULT 0,
+    note TEXT NOT NULL DEFAULT '',
+    created_by VARCHAR(255) NOT NULL DEFAULT '',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (issue_id, path, line_start, line_end),
+    CONSTRAINT fk_code_refs_issue FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+);
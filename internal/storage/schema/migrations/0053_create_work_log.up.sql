@@ -0,0 +1,15 @@
+-- Migration 0053: Time actually spent on issues ('bd log-time').
+--
+-- One row per logged stretch of work. logged_at is when the work happened,
+-- which may be backdated; minutes is always positive.
+CREATE TABLE IF NOT EXISTS work_log (
+    id CHAR(36) NOT NULL PRIMARY KEY DEFAULT (UUID()),
+    issue_id VARCHAR(255) NOT NULL,
+    actor VARCHAR(255) NOT NULL,
+    minutes INT NOT NULL,
+    note TEXT NOT NULL DEFAULT '',
+    logged_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_work_log_issue (issue_id),
+    INDEX idx_work_log_actor (actor),
+    CONSTRAINT fk_work_log_issue FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+);
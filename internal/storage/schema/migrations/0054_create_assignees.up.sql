@@ -0,0 +1,11 @@
+-- Migration 0054: Co-assignees on issues ('bd assign --add').
+--
+-- issues.assignee stays the primary assignee; this table holds everyone
+-- else sharing the issue (pairing, human+agent co-ownership).
+CREATE TABLE IF NOT EXISTS assignees (
+    issue_id VARCHAR(255) NOT NULL,
+    assignee VARCHAR(255) NOT NULL,
+    PRIMARY KEY (issue_id, assignee),
+    INDEX idx_assignees_assignee (assignee),
+    CONSTRAINT fk_assignees_issue FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+);
@@ -0,0 +1,13 @@
+-- Migration 0055: Per-actor issue subscriptions ('bd watch', 'bd inbox').
+--
+-- last_seen_at is the actor's inbox cursor for the issue: events newer
+-- than it are unread. It starts at the time the watch was added.
+CREATE TABLE IF NOT EXISTS watchers (
+    issue_id VARCHAR(255) NOT NULL,
+    actor VARCHAR(255) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_seen_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (issue_id, actor),
+    INDEX idx_watchers_actor (actor),
+    CONSTRAINT fk_watchers_issue FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+);
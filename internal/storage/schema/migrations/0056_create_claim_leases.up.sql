@@ -0,0 +1,13 @@
+-- Migration 0056: Expiring claim leases ('bd update --claim --lease').
+--
+-- A lease keeps a claimed issue out of other actors' ready work until
+-- expires_at. Once it lapses, the issue shows up in 'bd ready' again and
+-- anyone may re-claim it. Leases only apply while holder is still the
+-- issue's in_progress assignee.
+CREATE TABLE IF NOT EXISTS claim_leases (
+    issue_id VARCHAR(255) NOT NULL PRIMARY KEY,
+    holder VARCHAR(255) NOT NULL,
+    expires_at DATETIME NOT NULL,
+    INDEX idx_claim_leases_expires_at (expires_at),
+    CONSTRAINT fk_claim_leases_issue FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+);
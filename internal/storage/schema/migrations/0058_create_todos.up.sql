@@ -0,0 +1,15 @@
+-- Migration 0058: Checklist items on issues ('bd todo item <id> <text>').
+--
+-- seq numbers an issue's items from 1 in the order they were added; it is
+-- what 'bd todo check <id> <n>' refers to. done_at is NULL until ticked.
+CREATE TABLE IF NOT EXISTS todos (
+    issue_id VARCHAR(255) NOT NULL,
+    seq INT NOT NULL,
+    text TEXT NOT NULL,
+    done TINYINT(1) NOT NULL DEFAULT 0,
+    done_at DATETIME NULL,
+    created_by VARCHAR(255) NOT NULL DEFAULT '',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (issue_id, seq),
+    CONSTRAINT fk_todos_issue FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+);
@@ -0,0 +1,24 @@
+-- Migration 0059: Agent work sessions ('bd session start/end/log').
+--
+-- A session is one actor's stretch of work; ended_at is NULL while it is
+-- open, and an actor has at most one open session. session_commands lists
+-- the write commands run during it, numbered by seq. The issues a session
+-- touched are read from the events the actor made between its start and end.
+CREATE TABLE IF NOT EXISTS sessions (
+    id VARCHAR(64) NOT NULL PRIMARY KEY,
+    actor VARCHAR(255) NOT NULL,
+    note TEXT NOT NULL,
+    started_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    ended_at DATETIME NULL,
+    INDEX idx_sessions_actor (actor, ended_at)
+);
+
+CREATE TABLE IF NOT EXISTS session_commands (
+    session_id VARCHAR(64) NOT NULL,
+    seq INT NOT NULL,
+    command VARCHAR(255) NOT NULL,
+    args TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (session_id, seq),
+    CONSTRAINT fk_session_commands_session FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+DELETE FROM dolt_ignore WHERE pattern = 'trash';
@@ -0,0 +1,7 @@
+-- Migration 0060: Register the trash of deleted issues as dolt-ignored.
+--
+-- trash keeps a snapshot of each issue 'bd delete' removed so 'bd restore'
+-- can bring it back. It is clone-local, so it is never committed or pushed;
+-- only the deletion's tombstone travels. The table itself is created by
+-- ignored migration 0011.
+REPLACE INTO dolt_ignore VALUES ('trash', true);
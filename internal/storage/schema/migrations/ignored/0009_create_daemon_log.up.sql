@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS daemon_log (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    created_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    operation VARCHAR(64) NOT NULL DEFAULT '',
+    actor VARCHAR(255) NOT NULL DEFAULT '',
+    peer VARCHAR(255) NOT NULL DEFAULT '',
+    token VARCHAR(64) NOT NULL DEFAULT '',
+    cwd VARCHAR(1024) NOT NULL DEFAULT '',
+    issue_ids TEXT NOT NULL DEFAULT '',
+    success TINYINT(1) NOT NULL DEFAULT 1,
+    error TEXT NOT NULL DEFAULT '',
+    duration_ms BIGINT NOT NULL DEFAULT 0,
+    INDEX idx_daemon_log_created_at (created_at),
+    INDEX idx_daemon_log_actor (actor)
+);
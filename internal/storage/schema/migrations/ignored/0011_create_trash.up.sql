@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS trash (
+    issue_id VARCHAR(255) PRIMARY KEY,
+    title VARCHAR(500) NOT NULL DEFAULT '',
+    actor VARCHAR(255) NOT NULL DEFAULT '',
+    deleted_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    snapshot LONGTEXT NOT NULL,
+    INDEX idx_trash_deleted_at (deleted_at)
+);
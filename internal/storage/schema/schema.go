@@ -201,7 +201,36 @@ func MigrateUp(ctx context.Context, db DBConn) (int, error) {
 	if !needed {
 		return 0, nil
 	}
+	return migrateUpTo(ctx, db, LatestVersion())
+}
+
+// MigrateUpTo applies the pending schema migrations up to and including
+// version target, which must lie between the database's current version and
+// LatestVersion. Ignored-table migrations and backfills only run once the
+// schema reaches the latest version, since they assume it.
+func MigrateUpTo(ctx context.Context, db DBConn, target int) (int, error) {
+	if target > LatestVersion() {
+		return 0, fmt.Errorf("schema migration v%d does not exist; latest is v%d", target, LatestVersion())
+	}
+	current, err := mainSource.currentVersion(ctx, db)
+	if err != nil {
+		return 0, err
+	}
+	if target < current {
+		return 0, fmt.Errorf("schema is already at v%d; migrations cannot be rolled back to v%d", current, target)
+	}
+	if target == LatestVersion() {
+		return MigrateUp(ctx, db)
+	}
+	if target == current {
+		return 0, nil
+	}
+	return migrateUpTo(ctx, db, target)
+}
 
+// migrateUpTo applies and commits the main migrations up to target, and the
+// ignored-table migrations and backfills when target is the latest version.
+func migrateUpTo(ctx context.Context, db DBConn, target int) (int, error) {
 	dirtyBeforeAll, err := dirtyTables(ctx, db, false)
 	if err != nil {
 		return 0, fmt.Errorf("reading pre-migration status: %w", err)
@@ -224,10 +253,13 @@ func MigrateUp(ctx context.Context, db DBConn) (int, error) {
 	if err != nil {
 		return 0, fmt.Errorf("reading pre-migration dirty table diffs: %w", err)
 	}
-	applied, err := mainSource.migrate(ctx, db)
+	applied, err := mainSource.migrate(ctx, db, target)
 	if err != nil {
 		return applied, err
 	}
+	if target < LatestVersion() {
+		return applied, commitSchemaMigrations(ctx, db, dirtyBefore, dirtyBeforeSignatures)
+	}
 
 	backfilled, err := ensureBackfilledCustomStatusesCustomTypes(ctx, db)
 	if err != nil {
@@ -246,7 +278,7 @@ func MigrateUp(ctx context.Context, db DBConn) (int, error) {
 		return applied, fmt.Errorf("pending ignored schema migrations alter pre-existing dirty tables: %s", strings.Join(touchedIgnoredDirtyTables, ", "))
 	}
 
-	appliedIgnored, err := ignoredSource.migrate(ctx, db)
+	appliedIgnored, err := ignoredSource.migrate(ctx, db, ignoredSource.latest())
 	if err != nil {
 		return applied, fmt.Errorf("ignored migrations: %w", err)
 	}
@@ -257,28 +289,34 @@ func MigrateUp(ctx context.Context, db DBConn) (int, error) {
 	if applied == 0 && !backfilled && appliedIgnored == 0 {
 		return applied, nil
 	}
+	return applied, commitSchemaMigrations(ctx, db, dirtyBefore, dirtyBeforeSignatures)
+}
+
+// commitSchemaMigrations stages the tables the migrations changed and
+// commits them, after checking that the tables that were already dirty are
+// untouched.
+func commitSchemaMigrations(ctx context.Context, db DBConn, dirtyBefore map[string]dirtyTableState, dirtyBeforeSignatures map[string]string) error {
 	changedDirtyTables, err := changedDirtyTableSignatures(ctx, db, dirtyBeforeSignatures)
 	if err != nil {
-		return applied, fmt.Errorf("checking pre-existing dirty table diffs: %w", err)
+		return fmt.Errorf("checking pre-existing dirty table diffs: %w", err)
 	}
 	if len(changedDirtyTables) > 0 {
-		return applied, fmt.Errorf("pre-existing dirty tables changed during schema migration: %s", strings.Join(changedDirtyTables, ", "))
+		return fmt.Errorf("pre-existing dirty tables changed during schema migration: %s", strings.Join(changedDirtyTables, ", "))
 	}
 
 	staged, err := stageSchemaTables(ctx, db, dirtyBefore)
 	if err != nil {
-		return applied, fmt.Errorf("staging migrations: %w", err)
+		return fmt.Errorf("staging migrations: %w", err)
 	}
 	if !staged {
-		return applied, nil
+		return nil
 	}
 	if _, err := db.ExecContext(ctx, "CALL DOLT_COMMIT('-m', 'schema: apply migrations')"); err != nil {
 		if !strings.Contains(strings.ToLower(err.Error()), "nothing to commit") {
-			return applied, fmt.Errorf("committing migrations: %w", err)
+			return fmt.Errorf("committing migrations: %w", err)
 		}
 	}
-
-	return applied, nil
+	return nil
 }
 
 func migrationWorkNeeded(ctx context.Context, db DBConn) (bool, error) {
@@ -732,7 +770,8 @@ func migrationSQLTouchesTable(sqlText, table string) bool {
 	return false
 }
 
-func (m migrationSource) migrate(ctx context.Context, db DBConn) (int, error) {
+// migrate applies m's pending migrations up to and including version target.
+func (m migrationSource) migrate(ctx context.Context, db DBConn, target int) (int, error) {
 	if _, err := db.ExecContext(ctx, m.bootstrapSQL()); err != nil {
 		return 0, fmt.Errorf("creating %s: %w", m.cursorTable, err)
 	}
@@ -743,7 +782,7 @@ func (m migrationSource) migrate(ctx context.Context, db DBConn) (int, error) {
 		return 0, fmt.Errorf("reading %s version: %w", m.cursorTable, err)
 	}
 
-	if current >= m.latest() {
+	if current >= target {
 		return 0, nil
 	}
 
@@ -752,6 +791,9 @@ func (m migrationSource) migrate(ctx context.Context, db DBConn) (int, error) {
 		if mf.version <= current {
 			continue
 		}
+		if mf.version > target {
+			break
+		}
 		data, err := m.files.ReadFile(m.dir + "/" + mf.name)
 		if err != nil {
 			return count, fmt.Errorf("reading migration %s: %w", mf.name, err)
@@ -277,6 +277,7 @@ func TestCLICompatibleMigration0049UsesDirectLongtextDDL(t *testing.T) {
 		"DEALLOCATE",
 		"@issues_needs_fix",
 		"@comments_needs_fix",
+		"COLUMN_NAME = 'severity'",
 	} {
 		if strings.Contains(got, forbidden) {
 			t.Fatalf("0049 CLI migration contains prepared-DDL marker %q", forbidden)
@@ -298,6 +299,8 @@ func TestAllMigrationsSQLUsesDirectDDLForKnownCLIIncompatibilities(t *testing.T)
 		"ALTER TABLE schema_migrations DROP COLUMN applied_at",
 		"ALTER TABLE issues MODIFY COLUMN close_reason LONGTEXT DEFAULT ''",
 		"ALTER TABLE comments MODIFY COLUMN text LONGTEXT NOT NULL",
+		"ALTER TABLE issues ADD COLUMN severity VARCHAR(2) DEFAULT ''",
+		"ALTER TABLE wisps ADD COLUMN severity VARCHAR(2) DEFAULT ''",
 	} {
 		if !strings.Contains(got, want) {
 			t.Fatalf("AllMigrationsSQL missing direct CLI DDL %q", want)
@@ -352,6 +355,8 @@ WHERE table_schema = DATABASE()
 	requireDoltColumnShape(t, dir, "wisps", "no_history", "tinyint(1)", "YES")
 	requireDoltColumnShape(t, dir, "wisps", "started_at", "datetime", "YES")
 	requireDoltColumnShape(t, dir, "wisps", "wisp_type", "varchar(32)", "YES")
+	requireDoltColumnShape(t, dir, "issues", "severity", "varchar(2)", "YES")
+	requireDoltColumnShape(t, dir, "wisps", "severity", "varchar(2)", "YES")
 }
 
 func runDoltCommand(t *testing.T, dir string, args ...string) {
@@ -0,0 +1,18 @@
+package storage
+
+import (
+	"context"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// SessionStore records actors' work sessions and the commands run in them.
+type SessionStore interface {
+	StartSession(ctx context.Context, session *types.Session) error
+	EndSession(ctx context.Context, actor, note string) (*types.Session, error)
+	GetOpenSession(ctx context.Context, actor string) (*types.Session, error)
+	RecordSessionCommand(ctx context.Context, command *types.SessionCommand) error
+	ListSessions(ctx context.Context, actor string, limit int) ([]*types.Session, error)
+	GetSession(ctx context.Context, id string) (*types.Session, error)
+	GetSessionEvents(ctx context.Context, session *types.Session) ([]*types.Event, error)
+}
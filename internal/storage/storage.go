@@ -84,7 +84,7 @@ type Storage interface {
 	GetAllEventsSince(ctx context.Context, since time.Time) ([]*types.Event, error)
 
 	// Aggregate counts — cheaper than materializing rows when only cardinality is needed.
-	// Filter.Limit and Filter.Offset are ignored by CountIssues; all others apply.
+	// Filter.Limit, Filter.Offset and Filter.After are ignored by CountIssues; all others apply.
 
 	// CountIssues returns the number of issues matching query and filter.
 	CountIssues(ctx context.Context, query string, filter types.IssueFilter) (int64, error)
@@ -210,6 +210,8 @@ type DoltStorage interface {
 	ConfigMetadataStore
 	CompactionStore
 	AdvancedQueryStore
+	SessionStore
+	TrashStore
 }
 
 // RawDBAccessor provides raw *sql.DB access for diagnostics and migrations.
@@ -240,6 +242,9 @@ type Flattener interface {
 
 type SchemaMigrator interface {
 	ApplySchemaMigrations(ctx context.Context) (applied int, err error)
+	// ApplySchemaMigrationsTo applies pending migrations up to and including
+	// version target, failing if the schema is already past it.
+	ApplySchemaMigrationsTo(ctx context.Context, target int) (applied int, err error)
 }
 
 // Compactor squashes old Dolt commits while preserving recent ones.
@@ -312,6 +317,7 @@ type Transaction interface {
 	UpdateIssue(ctx context.Context, id string, updates map[string]interface{}, actor string) error
 	CloseIssue(ctx context.Context, id string, reason string, actor string, session string) error
 	DeleteIssue(ctx context.Context, id string) error
+	DeleteIssues(ctx context.Context, ids []string, cascade bool, force bool) (*types.DeleteIssuesResult, error)
 	GetIssue(ctx context.Context, id string) (*types.Issue, error)                                    // For read-your-writes within transaction
 	SearchIssues(ctx context.Context, query string, filter types.IssueFilter) ([]*types.Issue, error) // For read-your-writes within transaction
 
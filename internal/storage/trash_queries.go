@@ -0,0 +1,16 @@
+package storage
+
+import (
+	"context"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// TrashStore keeps the issues 'bd delete' removed so they can be restored.
+// The trash is clone-local: it is never committed or pushed.
+type TrashStore interface {
+	PutTrash(ctx context.Context, entries []*types.TrashedIssue) error
+	GetTrash(ctx context.Context, id string) (*types.TrashedIssue, error)
+	ListTrash(ctx context.Context) ([]*types.TrashedIssue, error)
+	RemoveTrash(ctx context.Context, ids []string) error
+}
@@ -225,3 +225,45 @@ func TestParseCompactDuration_PreservesTimezone(t *testing.T) {
 		t.Errorf("timezone not preserved: got %v, want %v", got.Location(), loc)
 	}
 }
+
+func TestParsePastTime(t *testing.T) {
+	now := time.Date(2025, 6, 15, 12, 0, 0, 0, time.UTC)
+
+	tests := []struct {
+		input string
+		want  time.Time
+	}{
+		{"7d", time.Date(2025, 6, 8, 12, 0, 0, 0, time.UTC)},
+		{"2w", time.Date(2025, 6, 1, 12, 0, 0, 0, time.UTC)},
+		{"1M", time.Date(2025, 5, 15, 12, 0, 0, 0, time.UTC)},
+		{"24h", time.Date(2025, 6, 14, 12, 0, 0, 0, time.UTC)},
+		{"+1d", time.Date(2025, 6, 16, 12, 0, 0, 0, time.UTC)},
+		{"-1d", time.Date(2025, 6, 14, 12, 0, 0, 0, time.UTC)},
+		{"2025-01-15T10:00:00Z", time.Date(2025, 1, 15, 10, 0, 0, 0, time.UTC)},
+	}
+	for _, tt := range tests {
+		t.Run(tt.input, func(t *testing.T) {
+			got, err := ParsePastTime(tt.input, now)
+			if err != nil {
+				t.Fatalf("ParsePastTime(%q) error: %v", tt.input, err)
+			}
+			if !got.Equal(tt.want) {
+				t.Errorf("ParsePastTime(%q) = %v, want %v", tt.input, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestParseDurationAgo(t *testing.T) {
+	now := time.Date(2025, 6, 15, 12, 0, 0, 0, time.UTC)
+
+	got, err := ParseDurationAgo("3m", now)
+	if err != nil || !got.Equal(time.Date(2025, 3, 15, 12, 0, 0, 0, time.UTC)) {
+		t.Errorf("ParseDurationAgo(3m) = %v, %v", got, err)
+	}
+	for _, input := range []string{"", "+7d", "-7d", "tomorrow", "2025-01-15", "7"} {
+		if _, err := ParseDurationAgo(input, now); err == nil {
+			t.Errorf("ParseDurationAgo(%q) should fail", input)
+		}
+	}
+}
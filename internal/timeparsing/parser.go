@@ -17,19 +17,19 @@ import (
 	"github.com/olebedev/when/rules/en"
 )
 
-// compactDurationRe matches compact duration patterns: [+-]?(\d+)([hdwmy])
-// Examples: +6h, -1d, +2w, 3m, 1y
-var compactDurationRe = regexp.MustCompile(`^([+-]?)(\d+)([hdwmy])$`)
+// compactDurationRe matches compact duration patterns: [+-]?(\d+)([hdwmMy])
+// Examples: +6h, -1d, +2w, 3m, 1M, 1y
+var compactDurationRe = regexp.MustCompile(`^([+-]?)(\d+)([hdwmMy])$`)
 
 // ParseCompactDuration parses compact duration syntax and returns the resulting time.
 //
-// Format: [+-]?(\d+)([hdwmy])
+// Format: [+-]?(\d+)([hdwmMy])
 //
 // Units:
 //   - h = hours
 //   - d = days
 //   - w = weeks
-//   - m = months
+//   - m, M = months
 //   - y = years
 //
 // Examples:
@@ -73,7 +73,7 @@ func applyDuration(base time.Time, amount int, unit string) time.Time {
 		return base.AddDate(0, 0, amount)
 	case "w":
 		return base.AddDate(0, 0, amount*7)
-	case "m":
+	case "m", "M":
 		return base.AddDate(0, amount, 0)
 	case "y":
 		return base.AddDate(amount, 0, 0)
@@ -178,3 +178,28 @@ func ParseRelativeTime(s string, now time.Time) (time.Time, error) {
 
 	return time.Time{}, fmt.Errorf("cannot parse time expression: %q (examples: +6h, tomorrow, 2025-01-15)", s)
 }
+
+// ParseDurationAgo parses an unsigned compact duration ("7d", "2w", "1M") and
+// returns that long before now. It backs flags such as --updated-within that
+// take a window rather than a point in time.
+func ParseDurationAgo(s string, now time.Time) (time.Time, error) {
+	if s == "" || s[0] == '+' || s[0] == '-' {
+		return time.Time{}, fmt.Errorf("invalid duration %q (examples: 24h, 7d, 2w, 1M, 1y)", s)
+	}
+	t, err := ParseCompactDuration("-"+s, now)
+	if err != nil {
+		return time.Time{}, fmt.Errorf("invalid duration %q (examples: 24h, 7d, 2w, 1M, 1y)", s)
+	}
+	return t, nil
+}
+
+// ParsePastTime parses a time expression for filters over past events, such
+// as --created-before or --updated-after. An unsigned compact duration counts
+// back from now, so "2w" means two weeks ago; everything else is parsed by
+// ParseRelativeTime, so "+1d" or "2025-01-15" keep their usual meaning.
+func ParsePastTime(s string, now time.Time) (time.Time, error) {
+	if t, err := ParseDurationAgo(s, now); err == nil {
+		return t, nil
+	}
+	return ParseRelativeTime(s, now)
+}
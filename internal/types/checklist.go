@@ -0,0 +1,68 @@
+package types
+
+import (
+	"fmt"
+	"regexp"
+	"strings"
+)
+
+// ChecklistItem is one checkable acceptance criterion. Checklists are
+// stored in AcceptanceCriteria as Markdown task-list lines ("- [ ] item",
+// "- [x] item"), so they sync, export and render like any other text;
+// lines that are not task items are kept as free-form notes.
+type ChecklistItem struct {
+	Text string `json:"text"`
+	Done bool   `json:"done"`
+}
+
+var checklistLineRE = regexp.MustCompile(`^(\s*[-*+]\s+\[)([ xX])(\]\s+)(.*)$`)
+
+// ParseChecklist returns the task-list items in text, in order.
+func ParseChecklist(text string) []ChecklistItem {
+	var items []ChecklistItem
+	for _, line := range strings.Split(text, "\n") {
+		if m := checklistLineRE.FindStringSubmatch(strings.TrimRight(line, "\r")); m != nil {
+			items = append(items, ChecklistItem{Text: strings.TrimSpace(m[4]), Done: m[2] != " "})
+		}
+	}
+	return items
+}
+
+// SetChecklistItem marks the n-th (1-based) task-list item in text done or
+// not done, leaving every other line untouched.
+func SetChecklistItem(text string, n int, done bool) (string, error) {
+	lines := strings.Split(text, "\n")
+	count := 0
+	for i, line := range lines {
+		m := checklistLineRE.FindStringSubmatchIndex(line)
+		if m == nil {
+			continue
+		}
+		count++
+		if count != n {
+			continue
+		}
+		mark := " "
+		if done {
+			mark = "x"
+		}
+		lines[i] = line[:m[4]] + mark + line[m[5]:]
+		return strings.Join(lines, "\n"), nil
+	}
+	if count == 0 {
+		return "", fmt.Errorf("acceptance criteria have no checklist items")
+	}
+	return "", fmt.Errorf("item %d out of range (1-%d)", n, count)
+}
+
+// ChecklistProgress reports how many of the issue's acceptance checklist
+// items are done, out of how many.
+func (i *Issue) ChecklistProgress() (done, total int) {
+	for _, item := range ParseChecklist(i.AcceptanceCriteria) {
+		total++
+		if item.Done {
+			done++
+		}
+	}
+	return done, total
+}
@@ -0,0 +1,45 @@
+package types
+
+import "testing"
+
+func TestParseChecklist(t *testing.T) {
+	text := "Must hold:\n- [ ] parses input\n* [x] handles errors\n  - [X] nested item\n- not a task\n"
+	items := ParseChecklist(text)
+	want := []ChecklistItem{
+		{Text: "parses input"},
+		{Text: "handles errors", Done: true},
+		{Text: "nested item", Done: true},
+	}
+	if len(items) != len(want) {
+		t.Fatalf("ParseChecklist = %+v, want %+v", items, want)
+	}
+	for i := range want {
+		if items[i] != want[i] {
+			t.Errorf("item %d = %+v, want %+v", i+1, items[i], want[i])
+		}
+	}
+
+	issue := &Issue{AcceptanceCriteria: text}
+	if done, total := issue.ChecklistProgress(); done != 2 || total != 3 {
+		t.Errorf("ChecklistProgress = %d/%d, want 2/3", done, total)
+	}
+}
+
+func TestSetChecklistItem(t *testing.T) {
+	text := "Notes first\n- [ ] one\n- [x] two"
+
+	got, err := SetChecklistItem(text, 1, true)
+	if err != nil || got != "Notes first\n- [x] one\n- [x] two" {
+		t.Errorf("check item 1 = %q, %v", got, err)
+	}
+	got, err = SetChecklistItem(text, 2, false)
+	if err != nil || got != "Notes first\n- [ ] one\n- [ ] two" {
+		t.Errorf("uncheck item 2 = %q, %v", got, err)
+	}
+	if _, err := SetChecklistItem(text, 3, true); err == nil {
+		t.Error("expected an error for an out-of-range item")
+	}
+	if _, err := SetChecklistItem("plain text", 1, true); err == nil {
+		t.Error("expected an error when there is no checklist")
+	}
+}
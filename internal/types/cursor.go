@@ -0,0 +1,79 @@
+package types
+
+import (
+	"encoding/base64"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// IssueCursor marks a position in the default issue list ordering
+// (priority ASC, created_at DESC, id ASC). It is handed to clients as an
+// opaque string (see Encode) and passed back via IssueFilter.After to fetch
+// the next page deterministically, even while other issues are being added.
+type IssueCursor struct {
+	Priority  int
+	CreatedAt time.Time
+	ID        string
+}
+
+// IssueCursorFor returns the cursor positioned at issue, so that a query
+// with After set to it resumes with the row that follows issue.
+func IssueCursorFor(issue *Issue) *IssueCursor {
+	return &IssueCursor{
+		Priority:  issue.Priority,
+		CreatedAt: issue.CreatedAt.UTC(),
+		ID:        issue.ID,
+	}
+}
+
+// Encode serializes the cursor as a URL-safe opaque token.
+func (c *IssueCursor) Encode() string {
+	raw := fmt.Sprintf("%d|%d|%s", c.Priority, c.CreatedAt.UnixNano(), c.ID)
+	return base64.RawURLEncoding.EncodeToString([]byte(raw))
+}
+
+// ParseIssueCursor decodes a token produced by IssueCursor.Encode.
+func ParseIssueCursor(token string) (*IssueCursor, error) {
+	raw, err := base64.RawURLEncoding.DecodeString(strings.TrimSpace(token))
+	if err != nil {
+		return nil, fmt.Errorf("invalid cursor %q: %w", token, err)
+	}
+	parts := strings.SplitN(string(raw), "|", 3)
+	if len(parts) != 3 || parts[2] == "" {
+		return nil, fmt.Errorf("invalid cursor %q", token)
+	}
+	priority, err := strconv.Atoi(parts[0])
+	if err != nil {
+		return nil, fmt.Errorf("invalid cursor %q: bad priority", token)
+	}
+	nanos, err := strconv.ParseInt(parts[1], 10, 64)
+	if err != nil {
+		return nil, fmt.Errorf("invalid cursor %q: bad timestamp", token)
+	}
+	return &IssueCursor{
+		Priority:  priority,
+		CreatedAt: time.Unix(0, nanos).UTC(),
+		ID:        parts[2],
+	}, nil
+}
+
+// CompareListOrder orders two issues by the default list ordering
+// (priority ASC, created_at DESC, id ASC). It returns a negative number when
+// a sorts before b, a positive number when after, and 0 when equal.
+func CompareListOrder(a, b *Issue) int {
+	if a.Priority != b.Priority {
+		if a.Priority < b.Priority {
+			return -1
+		}
+		return 1
+	}
+	if !a.CreatedAt.Equal(b.CreatedAt) {
+		if a.CreatedAt.After(b.CreatedAt) {
+			return -1
+		}
+		return 1
+	}
+	return strings.Compare(a.ID, b.ID)
+}
@@ -0,0 +1,57 @@
+package types
+
+import (
+	"sort"
+	"testing"
+	"time"
+)
+
+func TestIssueCursorRoundTrip(t *testing.T) {
+	created := time.Date(2026, 3, 4, 5, 6, 7, 891011000, time.FixedZone("CET", 3600))
+	issue := &Issue{ID: "bd-a1b|2", Priority: 3, CreatedAt: created}
+
+	token := IssueCursorFor(issue).Encode()
+	got, err := ParseIssueCursor(token)
+	if err != nil {
+		t.Fatalf("ParseIssueCursor(%q): %v", token, err)
+	}
+	if got.Priority != 3 || got.ID != "bd-a1b|2" || !got.CreatedAt.Equal(created) {
+		t.Errorf("round trip mismatch: %+v", got)
+	}
+	if got.CreatedAt.Location() != time.UTC {
+		t.Errorf("CreatedAt location = %v, want UTC", got.CreatedAt.Location())
+	}
+}
+
+func TestParseIssueCursorInvalid(t *testing.T) {
+	for _, token := range []string{
+		"",
+		"not base64!",
+		"MXwy",     // "1|2": missing id
+		"eHwxfGJk", // "x|1|bd": bad priority
+		"MXx5fGJk", // "1|y|bd": bad timestamp
+	} {
+		if _, err := ParseIssueCursor(token); err == nil {
+			t.Errorf("ParseIssueCursor(%q) succeeded, want error", token)
+		}
+	}
+}
+
+func TestCompareListOrder(t *testing.T) {
+	older := time.Date(2026, 1, 1, 0, 0, 0, 0, time.UTC)
+	newer := older.Add(time.Hour)
+	issues := []*Issue{
+		{ID: "bd-4", Priority: 2, CreatedAt: older},
+		{ID: "bd-3", Priority: 1, CreatedAt: newer},
+		{ID: "bd-2", Priority: 1, CreatedAt: older},
+		{ID: "bd-1", Priority: 1, CreatedAt: older},
+	}
+	sort.Slice(issues, func(i, j int) bool { return CompareListOrder(issues[i], issues[j]) < 0 })
+
+	want := []string{"bd-3", "bd-1", "bd-2", "bd-4"}
+	for i, id := range want {
+		if issues[i].ID != id {
+			t.Fatalf("position %d = %s, want %s (order %v)", i, issues[i].ID, id, want)
+		}
+	}
+}
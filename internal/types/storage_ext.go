@@ -22,4 +22,5 @@ type DeleteIssuesResult struct {
 	LabelsCount       int
 	EventsCount       int
 	OrphanedIssues    []string
+	DeletedIDs        []string // Issues and wisps removed, cascade included (empty on a dry run)
 }
@@ -30,6 +30,7 @@ type Issue struct {
 	Status    Status    `json:"status,omitempty"`
 	Priority  int       `json:"priority"` // No omitempty: 0 is valid (P0/critical)
 	IssueType IssueType `json:"issue_type,omitempty"`
+	Severity  Severity  `json:"severity,omitempty"`
 
 	// ===== Assignment =====
 	Assignee         string `json:"assignee,omitempty"`
@@ -73,6 +74,10 @@ type Issue struct {
 	Labels       []string      `json:"labels,omitempty"`
 	Dependencies []*Dependency `json:"dependencies,omitempty"`
 	Comments     []*Comment    `json:"comments,omitempty"`
+	CodeRefs     []*CodeRef    `json:"code_refs,omitempty"`
+	WorkLog      []*WorkLog    `json:"work_log,omitempty"`
+	Todos        []*TodoItem   `json:"todos,omitempty"`
+	Assignees    []string      `json:"assignees,omitempty"` // Co-assignees besides Assignee
 
 	// ===== Messaging Fields (inter-agent communication) =====
 	Sender    string   `json:"sender,omitempty"`     // Who sent this (for messages)
@@ -129,6 +134,10 @@ func (i *Issue) ComputeContentHash() string {
 	w.str(string(i.Status))
 	w.int(i.Priority)
 	w.str(string(i.IssueType))
+	if i.Severity != "" {
+		// Only when set, so issues without one keep their hashes.
+		w.str("severity:" + string(i.Severity))
+	}
 	w.str(i.Assignee)
 	w.str(i.Owner)
 	w.str(i.CreatedBy)
@@ -234,6 +243,9 @@ func (i *Issue) ValidateWithCustom(customStatuses, customTypes []string) error {
 	if !i.IssueType.IsValidWithCustom(customTypes) {
 		return fmt.Errorf("invalid issue type: %s", i.IssueType)
 	}
+	if err := i.validateSeverity(); err != nil {
+		return err
+	}
 	if i.EstimatedMinutes != nil && *i.EstimatedMinutes < 0 {
 		return fmt.Errorf("estimated_minutes cannot be negative")
 	}
@@ -282,6 +294,9 @@ func (i *Issue) ValidateForImport(customStatuses []string) error {
 	} else if i.IssueType != "" && !i.IssueType.IsValid() {
 		// Non-built-in type - trust it (child repo already validated)
 	}
+	if i.Severity != "" && !i.Severity.IsValid() {
+		return fmt.Errorf("invalid severity: %s (valid: S0, S1, S2, S3)", i.Severity)
+	}
 	if i.EstimatedMinutes != nil && *i.EstimatedMinutes < 0 {
 		return fmt.Errorf("estimated_minutes cannot be negative")
 	}
@@ -319,6 +334,80 @@ func (i *Issue) SetDefaults() {
 	}
 }
 
+// EffectivePriority returns the priority the issue ranks at under priority
+// aging: one level more urgent for every full aging interval since it was
+// last updated, never past P0. Closed issues, and aging <= 0, keep the
+// stored priority.
+func (i *Issue) EffectivePriority(aging time.Duration, now time.Time) int {
+	if aging <= 0 || i.Status == StatusClosed || i.UpdatedAt.IsZero() {
+		return i.Priority
+	}
+	bump := int(now.Sub(i.UpdatedAt) / aging)
+	if bump <= 0 {
+		return i.Priority
+	}
+	if bump >= i.Priority {
+		return 0
+	}
+	return i.Priority - bump
+}
+
+// validateSeverity checks that a severity is valid and set only on a bug.
+func (i *Issue) validateSeverity() error {
+	if i.Severity == "" {
+		return nil
+	}
+	if !i.Severity.IsValid() {
+		return fmt.Errorf("invalid severity: %s (valid: S0, S1, S2, S3)", i.Severity)
+	}
+	if i.IssueType != TypeBug {
+		return fmt.Errorf("severity applies only to bugs (issue type is %s)", i.IssueType)
+	}
+	return nil
+}
+
+// Severity is how bad a bug is, independent of its priority (when it will
+// be worked on): S0 is critical, S3 minor.
+type Severity string
+
+// Severity constants
+const (
+	SeverityS0 Severity = "S0" // Critical: outage, data loss, security
+	SeverityS1 Severity = "S1" // Major: core function broken, no workaround
+	SeverityS2 Severity = "S2" // Moderate: broken with a workaround
+	SeverityS3 Severity = "S3" // Minor: cosmetic or edge case
+)
+
+// IsValid checks if the severity is one of S0-S3.
+func (s Severity) IsValid() bool {
+	switch s {
+	case SeverityS0, SeverityS1, SeverityS2, SeverityS3:
+		return true
+	}
+	return false
+}
+
+// ParseSeverity parses a severity written as "S1", "s1", or "1".
+func ParseSeverity(v string) (Severity, error) {
+	v = strings.ToUpper(strings.TrimSpace(v))
+	if len(v) == 1 {
+		v = "S" + v
+	}
+	if s := Severity(v); s.IsValid() {
+		return s, nil
+	}
+	return "", fmt.Errorf("invalid severity %q (valid: S0, S1, S2, S3)", v)
+}
+
+// Rank orders severities for sorting: 0 for S0 through 3 for S3, and 4
+// for no severity, which sorts after every bug that has one.
+func (s Severity) Rank() int {
+	if !s.IsValid() {
+		return 4
+	}
+	return int(s[1] - '0')
+}
+
 // Status represents the current state of an issue
 type Status string
 
@@ -747,9 +836,13 @@ type IssueWithCounts struct {
 	DependentCount  int     `json:"dependent_count"`
 	CommentCount    int     `json:"comment_count"`
 	Parent          *string `json:"parent,omitempty"` // Computed parent from parent-child dep (bd-ym8c)
+
+	// EffectivePriority is the aged priority, set by bd list --effective-priority
+	EffectivePriority *int `json:"effective_priority,omitempty"`
 }
 
-// IssueDetails extends Issue with labels, dependencies, dependents, and comments.
+// IssueDetails extends Issue with labels, dependencies, dependents, comments,
+// code references, logged time, and checklist items.
 // Used for JSON serialization in bd show and RPC responses.
 type IssueDetails struct {
 	Issue
@@ -757,6 +850,10 @@ type IssueDetails struct {
 	Dependencies []*IssueWithDependencyMetadata `json:"dependencies,omitempty"`
 	Dependents   []*IssueWithDependencyMetadata `json:"dependents,omitempty"`
 	Comments     []*Comment                     `json:"comments,omitempty"`
+	CodeRefs     []*CodeRef                     `json:"code_refs,omitempty"`
+	WorkLog      []*WorkLog                     `json:"work_log,omitempty"`
+	Todos        []*TodoItem                    `json:"todos,omitempty"`
+	ClaimLease   *ClaimLease                    `json:"claim_lease,omitempty"`
 	Parent       *string                        `json:"parent,omitempty"`
 
 	// Cardinality fields — emitted by default (count-only mode).
@@ -951,6 +1048,22 @@ type Comment struct {
 	CreatedAt time.Time `json:"created_at"`
 }
 
+// commentShortIDLen is how many trailing characters of a comment ID
+// ShortCommentID keeps. The tail of a UUIDv7 is random; its head is a
+// timestamp shared by comments made close together.
+const commentShortIDLen = 8
+
+// ShortCommentID returns the short form of a comment ID that bd show
+// prints and 'bd comment edit' and 'bd comment delete' accept: the last
+// eight characters, without hyphens. Short legacy IDs are returned as is.
+func ShortCommentID(id string) string {
+	compact := strings.ReplaceAll(id, "-", "")
+	if len(compact) <= commentShortIDLen {
+		return id
+	}
+	return compact[len(compact)-commentShortIDLen:]
+}
+
 // UnmarshalJSON handles backward compatibility for Comment.
 // Pre-v1.0 exported Comment.ID as int64; current schema uses string.
 func (c *Comment) UnmarshalJSON(data []byte) error {
@@ -978,6 +1091,110 @@ func (c *Comment) UnmarshalJSON(data []byte) error {
 	return nil
 }
 
+// CodeRef points an issue at a file, or a line range within it, by path
+// relative to the repository root. LineStart 0 means the whole file; a
+// single line has LineEnd == LineStart.
+type CodeRef struct {
+	IssueID   string    `json:"issue_id"`
+	Path      string    `json:"path"`
+	LineStart int       `json:"line_start,omitempty"`
+	LineEnd   int       `json:"line_end,omitempty"`
+	Note      string    `json:"note,omitempty"`
+	CreatedBy string    `json:"created_by,omitempty"`
+	CreatedAt time.Time `json:"created_at"`
+}
+
+// String formats the reference as path, path:line or path:start-end.
+func (r *CodeRef) String() string {
+	switch {
+	case r.LineStart <= 0:
+		return r.Path
+	case r.LineEnd <= r.LineStart:
+		return fmt.Sprintf("%s:%d", r.Path, r.LineStart)
+	default:
+		return fmt.Sprintf("%s:%d-%d", r.Path, r.LineStart, r.LineEnd)
+	}
+}
+
+// WorkLog records time actually spent on an issue ('bd log-time').
+// LoggedAt is when the work happened, which may be backdated.
+type WorkLog struct {
+	ID       string    `json:"id"`
+	IssueID  string    `json:"issue_id"`
+	Actor    string    `json:"actor"`
+	Minutes  int       `json:"minutes"`
+	Note     string    `json:"note,omitempty"`
+	LoggedAt time.Time `json:"logged_at"`
+}
+
+// TodoItem is a checklist entry on an issue ('bd todo item <id> <text>'),
+// for steps too small to deserve an issue of their own. Seq numbers the
+// items of one issue from 1 and is never reused while the item exists.
+type TodoItem struct {
+	IssueID   string     `json:"issue_id"`
+	Seq       int        `json:"seq"`
+	Text      string     `json:"text"`
+	Done      bool       `json:"done,omitempty"`
+	DoneAt    *time.Time `json:"done_at,omitempty"`
+	CreatedBy string     `json:"created_by,omitempty"`
+	CreatedAt time.Time  `json:"created_at"`
+}
+
+// Session is one actor's stretch of work on the tracker ('bd session
+// start/end'). EndedAt is nil while the session is open. Commands is only
+// filled in when a single session is loaded.
+type Session struct {
+	ID        string            `json:"id"`
+	Actor     string            `json:"actor"`
+	Note      string            `json:"note,omitempty"`
+	StartedAt time.Time         `json:"started_at"`
+	EndedAt   *time.Time        `json:"ended_at,omitempty"`
+	Commands  []*SessionCommand `json:"commands,omitempty"`
+}
+
+// SessionCommand is a write command run during a session. Seq numbers a
+// session's commands from 1 in the order they ran.
+type SessionCommand struct {
+	SessionID string    `json:"session_id"`
+	Seq       int       `json:"seq"`
+	Command   string    `json:"command"`
+	Args      string    `json:"args,omitempty"`
+	CreatedAt time.Time `json:"created_at"`
+}
+
+// TrashedIssue is an issue 'bd delete' removed, as it was just before: the
+// issue with its labels, dependencies, comments and other relations, plus
+// the dependencies other issues had on it, so 'bd restore' can bring it
+// back.
+type TrashedIssue struct {
+	ID         string        `json:"id"`
+	DeletedAt  time.Time     `json:"deleted_at"`
+	Actor      string        `json:"actor,omitempty"`
+	Issue      *Issue        `json:"issue"`
+	Dependents []*Dependency `json:"dependents,omitempty"`
+}
+
+// ClaimLease is a time-limited claim on an issue ('bd update --claim
+// --lease'). Until ExpiresAt the issue is taken by Holder; afterwards it
+// is ready work again and can be re-claimed by anyone.
+type ClaimLease struct {
+	IssueID   string    `json:"issue_id"`
+	Holder    string    `json:"holder"`
+	ExpiresAt time.Time `json:"expires_at"`
+}
+
+// Expired reports whether the lease has lapsed at now.
+func (l *ClaimLease) Expired(now time.Time) bool {
+	return !now.Before(l.ExpiresAt)
+}
+
+// WorkLogTotal is the time one actor has logged against one issue.
+type WorkLogTotal struct {
+	IssueID string `json:"issue_id"`
+	Actor   string `json:"actor"`
+	Minutes int    `json:"minutes"`
+}
+
 // Event represents an audit trail entry
 type Event struct {
 	ID        string    `json:"id"`
@@ -988,6 +1205,11 @@ type Event struct {
 	NewValue  *string   `json:"new_value,omitempty"`
 	Comment   *string   `json:"comment,omitempty"`
 	CreatedAt time.Time `json:"created_at"`
+
+	// ActorKind is "human" or "agent" when Actor is in the actor registry
+	// ('bd actor'). It is not stored with the event: the commands that
+	// output events ('bd inbox', 'bd handoff') fill it in from the registry.
+	ActorKind string `json:"actor_kind,omitempty"`
 }
 
 // EventType categorizes audit trail events
@@ -1005,7 +1227,11 @@ const (
 	EventDependencyRemoved EventType = "dependency_removed"
 	EventLabelAdded        EventType = "label_added"
 	EventLabelRemoved      EventType = "label_removed"
+	EventAssigneeAdded     EventType = "assignee_added"
+	EventAssigneeRemoved   EventType = "assignee_removed"
 	EventCompacted         EventType = "compacted"
+	EventCommentEdited     EventType = "comment_edited"
+	EventCommentDeleted    EventType = "comment_deleted"
 )
 
 // BlockedIssue extends Issue with blocking information
@@ -1196,6 +1422,7 @@ type IssueFilter struct {
 	Status        *Status
 	Statuses      []Status // Multiple status OR filter (from comma-separated --status)
 	Priority      *int
+	Severities    []Severity // Bug severity OR filter (from comma-separated --severity)
 	IssueType     *IssueType
 	Assignee      *string
 	Labels        []string // AND semantics: issue must have ALL these labels
@@ -1209,11 +1436,21 @@ type IssueFilter struct {
 	SpecIDPrefix  string   // Filter by spec_id prefix
 	Limit         int
 
+	// Pagination over the default ordering (priority, created_at DESC, id).
+	// Offset skips that many matching rows; After resumes strictly after a
+	// cursor (see IssueCursorFor). Both compose with Limit and are ignored
+	// by the Count* queries.
+	Offset int
+	After  *IssueCursor
+
 	// Pattern matching
 	TitleContains       string
 	DescriptionContains string
 	NotesContains       string
 	ExternalRefContains string
+	TitleRegex          string   // Regular expression matched against the title (case-insensitive)
+	DescriptionRegex    string   // Regular expression matched against the description (case-insensitive)
+	SearchIn            []string // More text the search query matches: "comments", "events" (history and close reasons)
 
 	// Date ranges
 	CreatedAfter  *time.Time
@@ -1262,6 +1499,10 @@ type IssueFilter struct {
 	// Type exclusion (for hiding internal types like gates)
 	ExcludeTypes []IssueType // Exclude issues with these types
 
+	// Assignee and ID exclusion
+	ExcludeAssignees []string // Exclude issues assigned or co-assigned to any of these
+	ExcludeIDs       []string // Exclude these issue IDs
+
 	// Time-based scheduling filters (GH#820)
 	Deferred    bool       // Filter issues that are scheduled later: defer_until set OR status is deferred
 	DeferAfter  *time.Time // Filter issues with defer_until > this time
@@ -1273,6 +1514,7 @@ type IssueFilter struct {
 	// Metadata field filtering (GH#1406)
 	MetadataFields map[string]string // Top-level key=value equality; AND semantics (all must match)
 	HasMetadataKey string            // Existence check: issue has this top-level key set (non-null)
+	CustomFields   map[string]string // --field name=value equality inside metadata.custom_fields; AND semantics
 
 	// Hydration options — control which relational data is populated on returned issues.
 	// Labels are always hydrated. Dependencies are not by default (for performance).
@@ -1305,12 +1547,17 @@ const (
 	// SortPolicyOldest always sorts by creation date (oldest first)
 	// Use for backlog clearing, preventing issue starvation
 	SortPolicyOldest SortPolicy = "oldest"
+
+	// SortPolicyDue sorts issues with a due date first (earliest due first),
+	// then everything else by priority
+	// Use to surface time-sensitive work before it is late
+	SortPolicyDue SortPolicy = "due"
 )
 
 // IsValid checks if the sort policy value is valid
 func (s SortPolicy) IsValid() bool {
 	switch s {
-	case SortPolicyHybrid, SortPolicyPriority, SortPolicyOldest, "":
+	case SortPolicyHybrid, SortPolicyPriority, SortPolicyOldest, SortPolicyDue, "":
 		return true
 	}
 	return false
@@ -1326,11 +1573,16 @@ type WorkFilter struct {
 	Labels        []string // AND semantics: issue must have ALL these labels
 	LabelsAny     []string // OR semantics: issue must have AT LEAST ONE of these labels
 	ExcludeLabels []string // Exclusion: issue must NOT have ANY of these labels
+	ExcludeIDs    []string // Exclusion: skip these issue IDs
 	LabelPattern  string   // Glob pattern for label matching (e.g., "tech-*")
 	LabelRegex    string   // Regex pattern for label matching (e.g., "tech-(debt|legacy)")
 	Limit         int
 	SortPolicy    SortPolicy
 
+	// Priority aging: rank issues by EffectivePriority instead of the stored
+	// priority (0 = off)
+	PriorityAging time.Duration
+
 	// Parent filtering: filter to descendants of a bead/epic (recursive)
 	ParentID *string // Show all descendants of this issue
 
@@ -1360,6 +1612,7 @@ type WorkFilter struct {
 	// Metadata field filtering (GH#1406)
 	MetadataFields map[string]string // Top-level key=value equality; AND semantics (all must match)
 	HasMetadataKey string            // Existence check: issue has this top-level key set (non-null)
+	CustomFields   map[string]string // --field name=value equality inside metadata.custom_fields; AND semantics
 }
 
 // StaleFilter is used to filter stale issue queries
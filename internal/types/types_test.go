@@ -361,6 +361,32 @@ func TestValidateWithCustomStatuses(t *testing.T) {
 // TestValidateForImport tests the federation trust model (bd-9ji4z):
 // - Built-in types are validated (catch typos)
 // - Non-built-in types are trusted (child repo already validated)
+func TestSeverity(t *testing.T) {
+	for in, want := range map[string]Severity{"S0": SeverityS0, "s2": SeverityS2, "3": SeverityS3} {
+		if got, err := ParseSeverity(in); err != nil || got != want {
+			t.Errorf("ParseSeverity(%q) = %q, %v; want %q", in, got, err, want)
+		}
+	}
+	if _, err := ParseSeverity("S4"); err == nil {
+		t.Error("ParseSeverity(S4) succeeded")
+	}
+	if SeverityS0.Rank() >= SeverityS3.Rank() || SeverityS3.Rank() >= Severity("").Rank() {
+		t.Error("Rank should order S0 < S3 < none")
+	}
+
+	bug := Issue{Title: "Crash", Status: StatusOpen, IssueType: TypeBug, Severity: SeverityS1}
+	if err := bug.Validate(); err != nil {
+		t.Errorf("bug with severity: %v", err)
+	}
+	task := Issue{Title: "Chore", Status: StatusOpen, IssueType: TypeTask, Severity: SeverityS1}
+	if err := task.Validate(); err == nil {
+		t.Error("task with severity validated")
+	}
+	if hash := bug.ComputeContentHash(); hash == (&Issue{Title: "Crash", Status: StatusOpen, IssueType: TypeBug}).ComputeContentHash() {
+		t.Error("severity should change the content hash")
+	}
+}
+
 func TestValidateForImport(t *testing.T) {
 	tests := []struct {
 		name    string
@@ -996,6 +1022,7 @@ func TestSortPolicyIsValid(t *testing.T) {
 		{SortPolicyHybrid, true},
 		{SortPolicyPriority, true},
 		{SortPolicyOldest, true},
+		{SortPolicyDue, true},
 		{SortPolicy(""), true}, // empty is valid
 		{SortPolicy("invalid"), false},
 	}
@@ -1551,6 +1578,19 @@ func TestCommentUnmarshalJSON(t *testing.T) {
 	}
 }
 
+func TestShortCommentID(t *testing.T) {
+	tests := map[string]string{
+		"0190a5c2-7d3e-7b1a-9f00-3c2d1e0fa9b8": "1e0fa9b8",
+		"42":                                   "42",
+		"abcd1234":                             "abcd1234",
+	}
+	for id, want := range tests {
+		if got := ShortCommentID(id); got != want {
+			t.Errorf("ShortCommentID(%q) = %q, want %q", id, got, want)
+		}
+	}
+}
+
 func TestBondRefUnmarshalJSON(t *testing.T) {
 	tests := []struct {
 		name         string
@@ -1612,3 +1652,29 @@ func TestBondRefUnmarshalJSON(t *testing.T) {
 		})
 	}
 }
+
+func TestEffectivePriority(t *testing.T) {
+	now := time.Date(2025, 7, 30, 12, 0, 0, 0, time.UTC)
+	aging := 7 * 24 * time.Hour
+	tests := []struct {
+		name  string
+		issue Issue
+		aging time.Duration
+		want  int
+	}{
+		{"aging off", Issue{Priority: 3, Status: StatusOpen, UpdatedAt: now.Add(-60 * 24 * time.Hour)}, 0, 3},
+		{"recently updated", Issue{Priority: 3, Status: StatusOpen, UpdatedAt: now.Add(-6 * 24 * time.Hour)}, aging, 3},
+		{"one interval", Issue{Priority: 3, Status: StatusOpen, UpdatedAt: now.Add(-8 * 24 * time.Hour)}, aging, 2},
+		{"two intervals", Issue{Priority: 3, Status: StatusInProgress, UpdatedAt: now.Add(-15 * 24 * time.Hour)}, aging, 1},
+		{"capped at P0", Issue{Priority: 2, Status: StatusOpen, UpdatedAt: now.Add(-90 * 24 * time.Hour)}, aging, 0},
+		{"closed keeps stored priority", Issue{Priority: 3, Status: StatusClosed, UpdatedAt: now.Add(-90 * 24 * time.Hour)}, aging, 3},
+		{"updated in the future", Issue{Priority: 3, Status: StatusOpen, UpdatedAt: now.Add(24 * time.Hour)}, aging, 3},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := tt.issue.EffectivePriority(tt.aging, now); got != tt.want {
+				t.Errorf("EffectivePriority() = %d, want %d", got, tt.want)
+			}
+		})
+	}
+}
@@ -589,6 +589,27 @@ func TestExtractIssuePrefixKnown(t *testing.T) {
 	}
 }
 
+func TestExtractIssuePrefixActor(t *testing.T) {
+	tests := []struct {
+		issueID  string
+		expected string
+	}{
+		{"bd-alice-3", "bd"},
+		{"bd-alice-3.1.2", "bd"},
+		{"my-app-bob2-17", "my-app"},
+		{"bd-42", "bd"},     // counter ID from before the switch
+		{"bd-a3f8e9", "bd"}, // hash ID from before the switch
+		{"web-app-a3f8e9", "web-app"},
+		{"invalid", ""},
+	}
+
+	for _, tt := range tests {
+		if got := ExtractIssuePrefixActor(tt.issueID); got != tt.expected {
+			t.Errorf("ExtractIssuePrefixActor(%q) = %q; want %q", tt.issueID, got, tt.expected)
+		}
+	}
+}
+
 func TestExtractIssueNumber(t *testing.T) {
 	tests := []struct {
 		name     string
@@ -134,6 +134,24 @@ func ExtractIssuePrefixKnown(issueID string, knownPrefixes []string) string {
 	return ExtractIssuePrefix(issueID)
 }
 
+// ExtractIssuePrefixActor extracts the prefix from an ID minted by the actor
+// ID scheme (issue_id_mode=actor), which puts the actor's namespace between
+// the prefix and the number: "bd-alice-3" -> "bd", "bd-alice-3.1" -> "bd".
+// Other IDs, such as hash IDs created before the scheme was switched, fall
+// back to ExtractIssuePrefix.
+func ExtractIssuePrefixActor(issueID string) string {
+	prefix := ExtractIssuePrefix(issueID)
+	suffix := strings.TrimPrefix(issueID, prefix+"-")
+	if dotIdx := strings.Index(suffix, "."); dotIdx > 0 {
+		suffix = suffix[:dotIdx]
+	}
+	idx := strings.LastIndex(prefix, "-")
+	if !isNumeric(suffix) || idx <= 0 {
+		return prefix
+	}
+	return prefix[:idx]
+}
+
 // NaturalCompareIDs compares two issue IDs with numeric-aware sorting.
 // Segments separated by "." or "-" are compared numerically when both
 // are pure digits, otherwise lexicographically. This ensures bd-E.4
@@ -0,0 +1,87 @@
+package validation
+
+import (
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// Guard is a status-transition rule a project turns on with the
+// status.guards config key. A guard blocks one kind of status change unless
+// the command is run with --force.
+type Guard string
+
+// Guards a project can enable.
+const (
+	// GuardInProgressNeedsAssignee keeps an issue from moving to
+	// in_progress without an assignee.
+	GuardInProgressNeedsAssignee Guard = "in_progress-needs-assignee"
+	// GuardCloseNeedsUnblocked keeps an issue from closing while issues
+	// blocking it are still open.
+	GuardCloseNeedsUnblocked Guard = "close-needs-unblocked"
+)
+
+// Guards lists the known guards.
+var Guards = []Guard{GuardInProgressNeedsAssignee, GuardCloseNeedsUnblocked}
+
+// ParseGuards parses a comma-separated status.guards value. The empty
+// string enables no guards.
+func ParseGuards(value string) ([]Guard, error) {
+	var guards []Guard
+	for _, part := range strings.Split(value, ",") {
+		name := strings.TrimSpace(part)
+		if name == "" {
+			continue
+		}
+		if !slices.Contains(Guards, Guard(name)) {
+			names := make([]string, len(Guards))
+			for i, g := range Guards {
+				names[i] = string(g)
+			}
+			return nil, fmt.Errorf("unknown status guard %q (valid guards: %s)", name, strings.Join(names, ", "))
+		}
+		guards = append(guards, Guard(name))
+	}
+	return guards, nil
+}
+
+// Transition is a status change to check against the enabled guards.
+type Transition struct {
+	ID   string
+	From types.Status
+	To   types.Status
+	// Assignee is the issue's assignee once the change applies.
+	Assignee string
+	// Blockers returns the open issues blocking this one. It is called
+	// only when a guard needs it.
+	Blockers func() ([]string, error)
+}
+
+// CheckTransition returns an error naming the first guard t breaks, or nil.
+func CheckTransition(guards []Guard, t Transition) error {
+	if t.From == t.To {
+		return nil
+	}
+	for _, g := range guards {
+		switch g {
+		case GuardInProgressNeedsAssignee:
+			if t.To == types.StatusInProgress && t.Assignee == "" {
+				return fmt.Errorf("cannot move %s to in_progress without an assignee (status guard %s): set --assignee, use --claim, or --force to override", t.ID, g)
+			}
+		case GuardCloseNeedsUnblocked:
+			if t.To != types.StatusClosed || t.Blockers == nil {
+				continue
+			}
+			blockers, err := t.Blockers()
+			if err != nil {
+				return fmt.Errorf("checking blockers for %s: %w", t.ID, err)
+			}
+			if len(blockers) > 0 {
+				return fmt.Errorf("cannot close %s while it is blocked by open issues %s (status guard %s): close them first or use --force to override", t.ID, strings.Join(blockers, ", "), g)
+			}
+		}
+	}
+	return nil
+}
@@ -0,0 +1,59 @@
+package validation
+
+import (
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestParseGuards(t *testing.T) {
+	guards, err := ParseGuards(" in_progress-needs-assignee, close-needs-unblocked ,")
+	if err != nil || len(guards) != 2 || guards[0] != GuardInProgressNeedsAssignee || guards[1] != GuardCloseNeedsUnblocked {
+		t.Errorf("ParseGuards = %v, %v", guards, err)
+	}
+	if guards, err := ParseGuards(""); err != nil || guards != nil {
+		t.Errorf("ParseGuards(\"\") = %v, %v; want none", guards, err)
+	}
+	if _, err := ParseGuards("close-needs-reason"); err == nil {
+		t.Error("ParseGuards(close-needs-reason) succeeded, want error")
+	}
+}
+
+func TestCheckTransition(t *testing.T) {
+	all := []Guard{GuardInProgressNeedsAssignee, GuardCloseNeedsUnblocked}
+	blockedBy := func(ids ...string) func() ([]string, error) {
+		return func() ([]string, error) { return ids, nil }
+	}
+
+	start := Transition{ID: "bd-1", From: types.StatusOpen, To: types.StatusInProgress}
+	if err := CheckTransition(all, start); err == nil || !strings.Contains(err.Error(), "in_progress-needs-assignee") {
+		t.Errorf("start without assignee: err = %v", err)
+	}
+	start.Assignee = "alice"
+	if err := CheckTransition(all, start); err != nil {
+		t.Errorf("start with assignee: err = %v", err)
+	}
+	if err := CheckTransition(nil, Transition{ID: "bd-1", From: types.StatusOpen, To: types.StatusInProgress}); err != nil {
+		t.Errorf("no guards: err = %v", err)
+	}
+
+	closing := Transition{ID: "bd-1", From: types.StatusOpen, To: types.StatusClosed, Blockers: blockedBy("bd-2", "bd-3")}
+	if err := CheckTransition(all, closing); err == nil || !strings.Contains(err.Error(), "bd-2, bd-3") {
+		t.Errorf("close while blocked: err = %v", err)
+	}
+	closing.Blockers = blockedBy()
+	if err := CheckTransition(all, closing); err != nil {
+		t.Errorf("close unblocked: err = %v", err)
+	}
+	closing.Blockers = func() ([]string, error) { return nil, errors.New("boom") }
+	if err := CheckTransition(all, closing); err == nil || !strings.Contains(err.Error(), "boom") {
+		t.Errorf("blocker lookup failure: err = %v", err)
+	}
+
+	// Staying in the same status is not a transition.
+	if err := CheckTransition(all, Transition{ID: "bd-1", From: types.StatusInProgress, To: types.StatusInProgress}); err != nil {
+		t.Errorf("no-op transition: err = %v", err)
+	}
+}
@@ -0,0 +1,214 @@
+package validation
+
+import (
+	"errors"
+	"fmt"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+// Strictness controls how the configurable validation rules are enforced.
+type Strictness string
+
+// Strictness levels, set by validation.strictness.
+const (
+	// StrictnessStrict rejects writes that break a rule. This is the default.
+	StrictnessStrict Strictness = "strict"
+	// StrictnessWarn reports broken rules but lets the write proceed.
+	StrictnessWarn Strictness = "warn"
+	// StrictnessRelaxed skips the configurable rules; only the built-in
+	// invariants (title present, priority 0-4, ...) are checked.
+	StrictnessRelaxed Strictness = "relaxed"
+)
+
+// ParseStrictness returns the strictness named s. The empty string means
+// the default, StrictnessStrict.
+func ParseStrictness(s string) (Strictness, error) {
+	switch Strictness(strings.ToLower(strings.TrimSpace(s))) {
+	case "", StrictnessStrict:
+		return StrictnessStrict, nil
+	case StrictnessWarn:
+		return StrictnessWarn, nil
+	case StrictnessRelaxed:
+		return StrictnessRelaxed, nil
+	}
+	return "", fmt.Errorf("invalid validation.strictness %q (valid values: strict, warn, relaxed)", s)
+}
+
+// MaxTitleLength is the longest title the database stores. A configured
+// validation.title-max-length can only lower it.
+const MaxTitleLength = 500
+
+// RequirableFields lists the fields validation.required.<type> may name,
+// by their JSON field names.
+var RequirableFields = []string{
+	"description", "design", "acceptance_criteria", "notes", "assignee",
+	"labels", "severity", "estimated_minutes", "spec_id", "external_ref", "due_at",
+}
+
+// Rules are the validation limits configured for a project, checked on top
+// of the built-in invariants. The zero value checks nothing.
+type Rules struct {
+	Strictness Strictness
+	// TitleMaxLength is the longest allowed title; 0 means MaxTitleLength.
+	TitleMaxLength int
+	// Priorities lists the allowed priorities; empty allows 0-4.
+	Priorities []int
+	// Required maps an issue type to the fields its issues must set.
+	Required map[string][]string
+}
+
+// RulesFromConfig returns the rules set in config.yaml:
+//
+//	validation.strictness: strict | warn | relaxed
+//	validation.title-max-length: 120
+//	validation.priorities: 0,1,2,3
+//	validation.required.bug: description,severity
+//
+// An invalid strictness falls back to strict; invalid priorities and unknown
+// required fields are ignored ('bd config set' rejects them).
+func RulesFromConfig() Rules {
+	strictness, err := ParseStrictness(config.GetString("validation.strictness"))
+	if err != nil {
+		strictness = StrictnessStrict
+	}
+	rules := Rules{
+		Strictness:     strictness,
+		TitleMaxLength: config.GetInt("validation.title-max-length"),
+		Required:       map[string][]string{},
+	}
+	for _, entry := range config.GetStringSlice("validation.priorities") {
+		for _, part := range strings.Split(entry, ",") {
+			if p := ParsePriority(part); p >= 0 {
+				rules.Priorities = append(rules.Priorities, p)
+			}
+		}
+	}
+	for issueType, fields := range config.ValidationRequiredFields() {
+		for _, field := range fields {
+			if slices.Contains(RequirableFields, field) {
+				rules.Required[issueType] = append(rules.Required[issueType], field)
+			}
+		}
+	}
+	return rules
+}
+
+// Relaxed returns the rules with StrictnessRelaxed, the profile applied to
+// legacy data on import: records from older bd formats or other trackers
+// predate the project's rules and are brought in as they are.
+func (r Rules) Relaxed() Rules {
+	r.Strictness = StrictnessRelaxed
+	return r
+}
+
+// Violation is one rule an issue breaks.
+type Violation struct {
+	// Field is the JSON name of the field at fault.
+	Field   string
+	Message string
+}
+
+// Violations returns every rule issue breaks, regardless of strictness.
+func (r Rules) Violations(issue *types.Issue) []Violation {
+	var violations []Violation
+	if r.TitleMaxLength > 0 && len(issue.Title) > r.TitleMaxLength {
+		violations = append(violations, Violation{"title", fmt.Sprintf("title must be %d characters or less (got %d)", r.TitleMaxLength, len(issue.Title))})
+	}
+	if len(r.Priorities) > 0 && !slices.Contains(r.Priorities, issue.Priority) {
+		allowed := make([]string, len(r.Priorities))
+		for i, p := range r.Priorities {
+			allowed[i] = "P" + strconv.Itoa(p)
+		}
+		violations = append(violations, Violation{"priority", fmt.Sprintf("priority P%d is not allowed (allowed: %s)", issue.Priority, strings.Join(allowed, ", "))})
+	}
+	for _, field := range r.Required[string(issue.IssueType)] {
+		if !fieldIsSet(issue, field) {
+			violations = append(violations, Violation{field, fmt.Sprintf("%s is required for %s issues", field, issue.IssueType)})
+		}
+	}
+	return violations
+}
+
+// Check returns an error listing the rules issue breaks, or nil when it
+// breaks none or the rules are relaxed. Callers decide from r.Strictness
+// whether the error stops the write or is reported as a warning.
+func (r Rules) Check(issue *types.Issue) error {
+	if r.Strictness == StrictnessRelaxed {
+		return nil
+	}
+	return ViolationsError(r.Violations(issue))
+}
+
+// ViolationsError joins violations into one error, or returns nil for none.
+func ViolationsError(violations []Violation) error {
+	if len(violations) == 0 {
+		return nil
+	}
+	msgs := make([]string, len(violations))
+	for i, v := range violations {
+		msgs[i] = v.Message
+	}
+	return errors.New(strings.Join(msgs, "; "))
+}
+
+func fieldIsSet(issue *types.Issue, field string) bool {
+	switch field {
+	case "description":
+		return strings.TrimSpace(issue.Description) != ""
+	case "design":
+		return strings.TrimSpace(issue.Design) != ""
+	case "acceptance_criteria":
+		return strings.TrimSpace(issue.AcceptanceCriteria) != ""
+	case "notes":
+		return strings.TrimSpace(issue.Notes) != ""
+	case "assignee":
+		return issue.Assignee != ""
+	case "labels":
+		return len(issue.Labels) > 0
+	case "severity":
+		return issue.Severity != ""
+	case "estimated_minutes":
+		return issue.EstimatedMinutes != nil
+	case "spec_id":
+		return issue.SpecID != ""
+	case "external_ref":
+		return issue.ExternalRef != nil && *issue.ExternalRef != ""
+	case "due_at":
+		return issue.DueAt != nil
+	}
+	return true
+}
+
+// ValidateRuleConfig checks a value for one of the validation rule keys
+// before 'bd config set' writes it. Other keys are accepted unchecked.
+func ValidateRuleConfig(key, value string) error {
+	switch {
+	case key == "validation.strictness":
+		_, err := ParseStrictness(value)
+		return err
+	case key == "validation.title-max-length":
+		n, err := strconv.Atoi(strings.TrimSpace(value))
+		if err != nil || n < 1 || n > MaxTitleLength {
+			return fmt.Errorf("validation.title-max-length must be an integer from 1 to %d, got %q", MaxTitleLength, value)
+		}
+	case key == "validation.priorities":
+		for _, part := range strings.Split(value, ",") {
+			if _, err := ValidatePriority(part); err != nil {
+				return fmt.Errorf("validation.priorities: %w", err)
+			}
+		}
+	case strings.HasPrefix(key, "validation.required."):
+		for _, part := range strings.Split(value, ",") {
+			field := strings.TrimSpace(part)
+			if field != "" && !slices.Contains(RequirableFields, field) {
+				return fmt.Errorf("%s: unknown field %q (valid fields: %s)", key, field, strings.Join(RequirableFields, ", "))
+			}
+		}
+	}
+	return nil
+}
@@ -0,0 +1,120 @@
+package validation
+
+import (
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+
+	"github.com/steveyegge/beads/internal/config"
+	"github.com/steveyegge/beads/internal/types"
+)
+
+func TestRulesViolations(t *testing.T) {
+	rules := Rules{
+		Strictness:     StrictnessStrict,
+		TitleMaxLength: 10,
+		Priorities:     []int{0, 1, 2},
+		Required:       map[string][]string{"bug": {"description", "severity"}},
+	}
+
+	ok := &types.Issue{Title: "Crash", Priority: 1, IssueType: types.TypeBug, Description: "Stack trace", Severity: types.SeverityS1}
+	if err := rules.Check(ok); err != nil {
+		t.Errorf("Check(valid bug) = %v, want nil", err)
+	}
+
+	bad := &types.Issue{Title: "Crash on every login", Priority: 4, IssueType: types.TypeBug}
+	var fields []string
+	for _, v := range rules.Violations(bad) {
+		fields = append(fields, v.Field)
+	}
+	if want := []string{"title", "priority", "description", "severity"}; !slices.Equal(fields, want) {
+		t.Errorf("Violations fields = %v, want %v", fields, want)
+	}
+	err := rules.Check(bad)
+	if err == nil || !strings.Contains(err.Error(), "priority P4 is not allowed (allowed: P0, P1, P2)") {
+		t.Errorf("Check(bad bug) = %v", err)
+	}
+
+	// Required fields apply per type only.
+	task := &types.Issue{Title: "Chore", Priority: 2, IssueType: types.TypeTask}
+	if err := rules.Check(task); err != nil {
+		t.Errorf("Check(task) = %v, want nil", err)
+	}
+
+	if err := rules.Relaxed().Check(bad); err != nil {
+		t.Errorf("Relaxed().Check(bad bug) = %v, want nil", err)
+	}
+	if err := (Rules{}).Check(bad); err != nil {
+		t.Errorf("zero Rules Check = %v, want nil", err)
+	}
+}
+
+func TestParseStrictness(t *testing.T) {
+	for in, want := range map[string]Strictness{"": StrictnessStrict, "strict": StrictnessStrict, "WARN": StrictnessWarn, "relaxed": StrictnessRelaxed} {
+		if got, err := ParseStrictness(in); err != nil || got != want {
+			t.Errorf("ParseStrictness(%q) = %q, %v; want %q", in, got, err, want)
+		}
+	}
+	if _, err := ParseStrictness("lenient"); err == nil {
+		t.Error("ParseStrictness(lenient) succeeded, want error")
+	}
+}
+
+func TestValidateRuleConfig(t *testing.T) {
+	tests := []struct {
+		key, value string
+		wantErr    bool
+	}{
+		{"validation.strictness", "warn", false},
+		{"validation.strictness", "loose", true},
+		{"validation.title-max-length", "120", false},
+		{"validation.title-max-length", "0", true},
+		{"validation.title-max-length", "501", true},
+		{"validation.priorities", "0,1,P2", false},
+		{"validation.priorities", "0,high", true},
+		{"validation.required.bug", "description, severity", false},
+		{"validation.required.bug", "description,color", true},
+		{"validation.on-create", "anything", false},
+	}
+	for _, tt := range tests {
+		if err := ValidateRuleConfig(tt.key, tt.value); (err != nil) != tt.wantErr {
+			t.Errorf("ValidateRuleConfig(%q, %q) = %v, wantErr %v", tt.key, tt.value, err, tt.wantErr)
+		}
+	}
+}
+
+func TestRulesFromConfig(t *testing.T) {
+	tmpDir := t.TempDir()
+	t.Setenv("BEADS_DIR", "")
+	t.Setenv("BEADS_TEST_IGNORE_REPO_CONFIG", "1")
+	t.Setenv("HOME", filepath.Join(tmpDir, "home"))
+	t.Setenv("XDG_CONFIG_HOME", filepath.Join(tmpDir, "xdg"))
+	t.Chdir(tmpDir)
+
+	config.ResetForTesting()
+	t.Cleanup(config.ResetForTesting)
+	if err := config.Initialize(); err != nil {
+		t.Fatalf("config.Initialize: %v", err)
+	}
+
+	if rules := RulesFromConfig(); rules.Strictness != StrictnessStrict || rules.TitleMaxLength != 0 || len(rules.Priorities) != 0 || len(rules.Required) != 0 {
+		t.Errorf("default RulesFromConfig() = %+v, want strict with no rules", rules)
+	}
+
+	config.Set("validation.strictness", "warn")
+	config.Set("validation.title-max-length", 80)
+	config.Set("validation.priorities", "0,1,P2")
+	config.Set("validation.required.bug", "description,severity,color")
+
+	rules := RulesFromConfig()
+	if rules.Strictness != StrictnessWarn || rules.TitleMaxLength != 80 {
+		t.Errorf("RulesFromConfig() = %+v", rules)
+	}
+	if !slices.Equal(rules.Priorities, []int{0, 1, 2}) {
+		t.Errorf("Priorities = %v, want [0 1 2]", rules.Priorities)
+	}
+	if got := rules.Required["bug"]; !slices.Equal(got, []string{"description", "severity"}) {
+		t.Errorf("Required[bug] = %v, want [description severity] (unknown fields dropped)", got)
+	}
+}